@@ -0,0 +1,129 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::ad_line::{ADLine, ADLineBuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// A/D 라인 분석기 데이터
+#[derive(Debug)]
+pub struct ADLineAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// A/D 라인 지표
+    pub ad_line: ADLine,
+}
+
+impl<C: Candle> ADLineAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, ad_line: ADLine) -> ADLineAnalyzerData<C> {
+        ADLineAnalyzerData { candle, ad_line }
+    }
+}
+
+impl<C: Candle> GetCandle<C> for ADLineAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for ADLineAnalyzerData<C> {}
+
+/// A/D 라인 분석기
+#[derive(Debug)]
+pub struct ADLineAnalyzer<C: Candle> {
+    /// A/D 라인 빌더
+    pub adlinebuilder: ADLineBuilder<C>,
+    /// 분석 데이터 히스토리 (최신 데이터가 인덱스 0)
+    pub items: Vec<ADLineAnalyzerData<C>>,
+}
+
+impl<C: Candle> Display for ADLineAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.items.first() {
+            Some(first) => write!(f, "캔들: {}, {}", first.candle, first.ad_line),
+            None => write!(f, "데이터 없음"),
+        }
+    }
+}
+
+impl<C: Candle + 'static> ADLineAnalyzer<C> {
+    /// 새 A/D 라인 분석기 생성
+    pub fn new(storage: &CandleStore<C>) -> ADLineAnalyzer<C> {
+        let mut analyzer = ADLineAnalyzer {
+            adlinebuilder: ADLineBuilder::new(),
+            items: vec![],
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 최신 A/D 라인 값 반환
+    pub fn get_ad_line(&self) -> f64 {
+        self.items
+            .first()
+            .map(|data| data.ad_line.value)
+            .unwrap_or(0.0)
+    }
+
+    /// n개 연속 캔들에서 A/D 라인이 직전 캔들보다 상승했는지 확인
+    pub fn is_ad_line_rising(&self, n: usize, p: usize) -> bool {
+        self.is_ad_line_compared_with_previous(|current, previous| current > previous, n, p)
+    }
+
+    /// n개 연속 캔들에서 A/D 라인이 직전 캔들보다 하락했는지 확인
+    pub fn is_ad_line_falling(&self, n: usize, p: usize) -> bool {
+        self.is_ad_line_compared_with_previous(|current, previous| current < previous, n, p)
+    }
+
+    /// 강세 다이버전스 확인 (`lookback` 캔들 전보다 종가는 낮고 A/D 라인은 높음)
+    pub fn is_bullish_divergence(&self, lookback: usize, p: usize) -> bool {
+        match (self.items.get(p), self.items.get(p + lookback)) {
+            (Some(current), Some(past)) if lookback > 0 => {
+                current.candle.close_price() < past.candle.close_price()
+                    && current.ad_line.value > past.ad_line.value
+            }
+            _ => false,
+        }
+    }
+
+    /// 약세 다이버전스 확인 (`lookback` 캔들 전보다 종가는 높고 A/D 라인은 낮음)
+    pub fn is_bearish_divergence(&self, lookback: usize, p: usize) -> bool {
+        match (self.items.get(p), self.items.get(p + lookback)) {
+            (Some(current), Some(past)) if lookback > 0 => {
+                current.candle.close_price() > past.candle.close_price()
+                    && current.ad_line.value < past.ad_line.value
+            }
+            _ => false,
+        }
+    }
+
+    /// p부터 n개 캔들의 A/D 라인을 각각 직전 캔들 A/D 라인과 비교
+    fn is_ad_line_compared_with_previous(
+        &self,
+        compare: impl Fn(f64, f64) -> bool,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        if self.items.len() < p + n + 1 {
+            return false;
+        }
+
+        (p..p + n).all(|i| compare(self.items[i].ad_line.value, self.items[i + 1].ad_line.value))
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<ADLineAnalyzerData<C>, C> for ADLineAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> ADLineAnalyzerData<C> {
+        let ad_line = self.adlinebuilder.next(&candle);
+        ADLineAnalyzerData::new(candle, ad_line)
+    }
+
+    fn items(&self) -> &Vec<ADLineAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<ADLineAnalyzerData<C>> {
+        &mut self.items
+    }
+}
@@ -1,7 +1,27 @@
-use crate::{candle_store::CandleStore, indicator::TAs};
+use crate::candle_store::CandleStore;
+use crate::indicator::{IndicatorResult, IndicatorState, TAs};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 use trading_chart::Candle;
 
+/// 분석기 상태 스냅샷
+///
+/// 지표 빌더의 증분 계산 상태와 데이터 히스토리를 함께 담아,
+/// 복원한 분석기가 캔들 저장소를 다시 재생하지 않고 같은 값을 이어서 계산하도록 합니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyzerSnapshot {
+    /// 지표 빌더 상태 (분석기가 정한 순서)
+    pub builders: Vec<IndicatorState>,
+    /// 데이터 히스토리 (최신 데이터가 인덱스 0)
+    pub items: serde_json::Value,
+}
+
+/// 빌더 상태 개수가 분석기와 다를 때의 에러 메시지
+pub(crate) fn builder_count_mismatch(expected: usize, actual: usize) -> String {
+    format!("빌더 상태 수 불일치: 분석기 {expected}, 상태 {actual}")
+}
+
 /// 캔들 데이터에 접근하기 위한 트레이트
 pub trait GetCandle<C: Candle> {
     /// 캔들 데이터 참조 반환
@@ -154,6 +174,60 @@ pub trait AnalyzerOps<Data: AnalyzerDataOps<C>, C: Candle> {
         self.init(storage.get_ascending_items())
     }
 
+    /// 지표 빌더 상태 목록
+    ///
+    /// 상태 저장을 지원하지 않는 분석기는 에러를 반환합니다.
+    fn builder_states(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        Err(format!(
+            "{}는 상태 저장을 지원하지 않습니다",
+            std::any::type_name::<Self>()
+        ))
+    }
+
+    /// 지표 빌더 상태 복원
+    ///
+    /// # Arguments
+    /// * `states` - `builder_states`로 저장한 빌더 상태
+    fn restore_builder_states(&mut self, _states: &[IndicatorState]) -> IndicatorResult<()> {
+        Err(format!(
+            "{}는 상태 복원을 지원하지 않습니다",
+            std::any::type_name::<Self>()
+        ))
+    }
+
+    /// 분석기 상태 저장
+    ///
+    /// # Returns
+    /// * `IndicatorResult<AnalyzerSnapshot>` - 빌더 상태와 데이터 히스토리 또는 에러
+    fn snapshot(&self) -> IndicatorResult<AnalyzerSnapshot>
+    where
+        Data: Serialize,
+    {
+        Ok(AnalyzerSnapshot {
+            builders: self.builder_states()?,
+            items: serde_json::to_value(self.items())
+                .map_err(|e| format!("분석기 데이터 직렬화 실패: {e}"))?,
+        })
+    }
+
+    /// 저장한 상태로 분석기 복원
+    ///
+    /// 같은 설정으로 생성한 분석기에 적용해야 합니다.
+    /// 에러가 발생하면 일부 빌더만 복원되었을 수 있으므로 분석기를 다시 생성해야 합니다.
+    ///
+    /// # Arguments
+    /// * `snapshot` - `snapshot`으로 저장한 분석기 상태
+    fn restore(&mut self, snapshot: &AnalyzerSnapshot) -> IndicatorResult<()>
+    where
+        Data: DeserializeOwned,
+    {
+        let items = Vec::<Data>::deserialize(&snapshot.items)
+            .map_err(|e| format!("분석기 데이터 역직렬화 실패: {e}"))?;
+        self.restore_builder_states(&snapshot.builders)?;
+        *self.items_mut() = items;
+        Ok(())
+    }
+
     /// 특정 인덱스의 데이터에서 값 추출
     ///
     /// # Arguments
@@ -1,11 +1,13 @@
-use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle, builder_count_mismatch};
 use crate::candle_store::CandleStore;
 use crate::indicator::bband::{BollingerBands, BollingerBandsBuilder};
+use crate::indicator::{IndicatorResult, IndicatorState, TABuilder};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 볼린저 밴드 분석기 데이터
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct BBandAnalyzerData<C: Candle> {
     /// 현재 캔들 데이터
     pub candle: C,
@@ -628,4 +630,15 @@ impl<C: Candle> AnalyzerOps<BBandAnalyzerData<C>, C> for BBandAnalyzer<C> {
     fn items_mut(&mut self) -> &mut Vec<BBandAnalyzerData<C>> {
         &mut self.items
     }
+
+    fn builder_states(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        Ok(vec![self.bbandbuilder.snapshot()?])
+    }
+
+    fn restore_builder_states(&mut self, states: &[IndicatorState]) -> IndicatorResult<()> {
+        let [bband] = states else {
+            return Err(builder_count_mismatch(1, states.len()));
+        };
+        self.bbandbuilder.restore(bband)
+    }
 }
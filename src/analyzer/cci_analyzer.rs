@@ -0,0 +1,138 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::cci::{CCIs, CCIsBuilder, CCIsBuilderFactory};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// CCI 분석기 데이터
+#[derive(Debug)]
+pub struct CCIAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// CCI 지표 집합
+    pub ccis: CCIs,
+}
+
+impl<C: Candle> CCIAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, ccis: CCIs) -> CCIAnalyzerData<C> {
+        CCIAnalyzerData { candle, ccis }
+    }
+
+    /// 특정 기간의 CCI 값 반환
+    pub fn get_cci(&self, period: usize) -> f64 {
+        self.ccis.get(&period).value
+    }
+}
+
+impl<C: Candle> GetCandle<C> for CCIAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for CCIAnalyzerData<C> {}
+
+/// CCI 분석기
+#[derive(Debug)]
+pub struct CCIAnalyzer<C: Candle + 'static> {
+    /// CCI 빌더
+    pub ccisbuilder: CCIsBuilder<C>,
+    /// 분석 데이터 히스토리
+    pub items: Vec<CCIAnalyzerData<C>>,
+}
+
+impl<C: Candle + 'static> Display for CCIAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CCIAnalyzer {{ items: {} }}", self.items.len())
+    }
+}
+
+impl<C: Candle + 'static> CCIAnalyzer<C> {
+    /// 새 CCI 분석기 생성
+    pub fn new(periods: &[usize], storage: &CandleStore<C>) -> CCIAnalyzer<C> {
+        let ccisbuilder = CCIsBuilderFactory::build(periods);
+        let mut analyzer = CCIAnalyzer {
+            ccisbuilder,
+            items: Vec::new(),
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 저장소에서 초기 데이터 계산
+    pub fn init_from_storage(&mut self, storage: &CandleStore<C>) {
+        for candle in storage.get_ascending_items().iter() {
+            self.next(candle.clone());
+        }
+    }
+
+    /// n개 연속 데이터에서 CCI가 특정 값보다 큰지 확인
+    pub fn is_cci_greater_than(&self, period: usize, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_cci(period) > value, n, p)
+    }
+
+    /// n개 연속 데이터에서 CCI가 특정 값보다 작은지 확인
+    pub fn is_cci_less_than(&self, period: usize, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_cci(period) < value, n, p)
+    }
+
+    /// n개 연속 데이터에서 CCI가 구간(경계 포함) 안에 있는지 확인
+    pub fn is_cci_in_range(
+        &self,
+        period: usize,
+        lower: f64,
+        upper: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(
+            |data| {
+                let cci = data.get_cci(period);
+                lower <= cci && cci <= upper
+            },
+            n,
+            p,
+        )
+    }
+
+    /// CCI의 기준값 상향 돌파 신호 확인 (n개 연속 CCI > 기준값, 이전 m개는 아님)
+    pub fn is_cci_cross_above(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_cci(period) > value, n, m, p)
+    }
+
+    /// CCI의 기준값 하향 돌파 신호 확인 (n개 연속 CCI < 기준값, 이전 m개는 아님)
+    pub fn is_cci_cross_below(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_cci(period) < value, n, m, p)
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<CCIAnalyzerData<C>, C> for CCIAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> CCIAnalyzerData<C> {
+        let ccis = self.ccisbuilder.next(&candle);
+        CCIAnalyzerData::new(candle, ccis)
+    }
+
+    fn items(&self) -> &Vec<CCIAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<CCIAnalyzerData<C>> {
+        &mut self.items
+    }
+}
@@ -0,0 +1,138 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::cmf::{CMFs, CMFsBuilder, CMFsBuilderFactory};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// CMF 분석기 데이터
+#[derive(Debug)]
+pub struct CMFAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// CMF 지표 집합
+    pub cmfs: CMFs,
+}
+
+impl<C: Candle> CMFAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, cmfs: CMFs) -> CMFAnalyzerData<C> {
+        CMFAnalyzerData { candle, cmfs }
+    }
+
+    /// 특정 기간의 CMF 값 반환
+    pub fn get_cmf(&self, period: usize) -> f64 {
+        self.cmfs.get(&period).value
+    }
+}
+
+impl<C: Candle> GetCandle<C> for CMFAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for CMFAnalyzerData<C> {}
+
+/// CMF 분석기
+#[derive(Debug)]
+pub struct CMFAnalyzer<C: Candle + 'static> {
+    /// CMF 빌더
+    pub cmfsbuilder: CMFsBuilder<C>,
+    /// 분석 데이터 히스토리
+    pub items: Vec<CMFAnalyzerData<C>>,
+}
+
+impl<C: Candle + 'static> Display for CMFAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CMFAnalyzer {{ items: {} }}", self.items.len())
+    }
+}
+
+impl<C: Candle + 'static> CMFAnalyzer<C> {
+    /// 새 CMF 분석기 생성
+    pub fn new(periods: &[usize], storage: &CandleStore<C>) -> CMFAnalyzer<C> {
+        let cmfsbuilder = CMFsBuilderFactory::build(periods);
+        let mut analyzer = CMFAnalyzer {
+            cmfsbuilder,
+            items: Vec::new(),
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 저장소에서 초기 데이터 계산
+    pub fn init_from_storage(&mut self, storage: &CandleStore<C>) {
+        for candle in storage.get_ascending_items().iter() {
+            self.next(candle.clone());
+        }
+    }
+
+    /// n개 연속 데이터에서 CMF가 특정 값보다 큰지 확인
+    pub fn is_cmf_greater_than(&self, period: usize, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_cmf(period) > value, n, p)
+    }
+
+    /// n개 연속 데이터에서 CMF가 특정 값보다 작은지 확인
+    pub fn is_cmf_less_than(&self, period: usize, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_cmf(period) < value, n, p)
+    }
+
+    /// n개 연속 데이터에서 CMF가 구간(경계 포함) 안에 있는지 확인
+    pub fn is_cmf_in_range(
+        &self,
+        period: usize,
+        lower: f64,
+        upper: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(
+            |data| {
+                let value = data.get_cmf(period);
+                lower <= value && value <= upper
+            },
+            n,
+            p,
+        )
+    }
+
+    /// CMF의 기준값 상향 돌파 신호 확인 (n개 연속 > 기준값, 이전 m개는 아님)
+    pub fn is_cmf_cross_above(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_cmf(period) > value, n, m, p)
+    }
+
+    /// CMF의 기준값 하향 돌파 신호 확인 (n개 연속 < 기준값, 이전 m개는 아님)
+    pub fn is_cmf_cross_below(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_cmf(period) < value, n, m, p)
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<CMFAnalyzerData<C>, C> for CMFAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> CMFAnalyzerData<C> {
+        let cmfs = self.cmfsbuilder.next(&candle);
+        CMFAnalyzerData::new(candle, cmfs)
+    }
+
+    fn items(&self) -> &Vec<CMFAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<CMFAnalyzerData<C>> {
+        &mut self.items
+    }
+}
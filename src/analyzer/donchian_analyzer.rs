@@ -0,0 +1,149 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::donchian::{
+    DonchianChannel, DonchianChannels, DonchianChannelsBuilder, DonchianChannelsBuilderFactory,
+};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 돈치안 채널 분석기 데이터
+#[derive(Debug)]
+pub struct DonchianAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// 돈치안 채널 집합
+    pub donchian_channels: DonchianChannels,
+}
+
+impl<C: Candle> DonchianAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, donchian_channels: DonchianChannels) -> DonchianAnalyzerData<C> {
+        DonchianAnalyzerData {
+            candle,
+            donchian_channels,
+        }
+    }
+
+    /// 특정 기간의 돈치안 채널 반환
+    pub fn get_donchian(&self, period: usize) -> &DonchianChannel {
+        self.donchian_channels.get(&period)
+    }
+}
+
+impl<C: Candle> GetCandle<C> for DonchianAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for DonchianAnalyzerData<C> {}
+
+/// 돈치안 채널 분석기
+///
+/// 현재 캔들의 채널에는 현재 캔들의 고가/저가가 포함되므로,
+/// 돌파 여부는 직전 캔들까지의 채널과 비교합니다.
+#[derive(Debug)]
+pub struct DonchianAnalyzer<C: Candle + 'static> {
+    /// 돈치안 채널 빌더
+    pub donchianchannelsbuilder: DonchianChannelsBuilder<C>,
+    /// 분석 데이터 히스토리
+    pub items: Vec<DonchianAnalyzerData<C>>,
+}
+
+impl<C: Candle + 'static> Display for DonchianAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DonchianAnalyzer {{ items: {} }}", self.items.len())
+    }
+}
+
+impl<C: Candle + 'static> DonchianAnalyzer<C> {
+    /// 새 돈치안 채널 분석기 생성
+    pub fn new(periods: &[usize], storage: &CandleStore<C>) -> DonchianAnalyzer<C> {
+        let donchianchannelsbuilder = DonchianChannelsBuilderFactory::build(periods);
+        let mut analyzer = DonchianAnalyzer {
+            donchianchannelsbuilder,
+            items: Vec::new(),
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 저장소에서 초기 데이터 계산
+    pub fn init_from_storage(&mut self, storage: &CandleStore<C>) {
+        for candle in storage.get_ascending_items().iter() {
+            self.next(candle.clone());
+        }
+    }
+
+    /// n개 연속 데이터가 직전 캔들의 채널과 비교해 조건을 만족하는지 확인
+    fn is_all_against_previous(
+        &self,
+        period: usize,
+        is_fn: impl Fn(f64, &DonchianChannel) -> bool,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        if self.items.len() < n + p + 1 {
+            return false;
+        }
+
+        (p..p + n).all(|i| {
+            let close = self.items[i].candle.close_price();
+            is_fn(close, self.items[i + 1].get_donchian(period))
+        })
+    }
+
+    /// n개 연속 캔들의 종가가 직전 채널 상단을 넘어섰는지 확인 (상향 돌파)
+    pub fn is_breakout_up(&self, period: usize, n: usize, p: usize) -> bool {
+        self.is_all_against_previous(period, |close, channel| close > channel.upper(), n, p)
+    }
+
+    /// n개 연속 캔들의 종가가 직전 채널 하단 아래로 내려갔는지 확인 (하향 돌파)
+    pub fn is_breakout_down(&self, period: usize, n: usize, p: usize) -> bool {
+        self.is_all_against_previous(period, |close, channel| close < channel.lower(), n, p)
+    }
+
+    /// n개 연속 캔들의 종가가 직전 채널 안(경계 포함)에 머무는지 확인
+    pub fn is_inside_channel(&self, period: usize, n: usize, p: usize) -> bool {
+        self.is_all_against_previous(
+            period,
+            |close, channel| channel.lower() <= close && close <= channel.upper(),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 채널 중심선 위에 있는지 확인
+    pub fn is_above_middle(&self, period: usize, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.candle.close_price() > data.get_donchian(period).middle(),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 채널 중심선 아래에 있는지 확인
+    pub fn is_below_middle(&self, period: usize, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.candle.close_price() < data.get_donchian(period).middle(),
+            n,
+            p,
+        )
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<DonchianAnalyzerData<C>, C> for DonchianAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> DonchianAnalyzerData<C> {
+        let donchian_channels = self.donchianchannelsbuilder.next(&candle);
+        DonchianAnalyzerData::new(candle, donchian_channels)
+    }
+
+    fn items(&self) -> &Vec<DonchianAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<DonchianAnalyzerData<C>> {
+        &mut self.items
+    }
+}
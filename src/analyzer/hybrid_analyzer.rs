@@ -1,9 +1,11 @@
-use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle, builder_count_mismatch};
 use crate::candle_store::CandleStore;
 use crate::indicator::TABuilder;
-use crate::indicator::ma::{MA, MABuilderFactory, MAType};
+use crate::indicator::ma::{MA, MABuilderFactory, MAType, MAValue};
 use crate::indicator::macd::{MACD, MACDBuilder};
 use crate::indicator::rsi::{RSI, RSIBuilder};
+use crate::indicator::{IndicatorResult, IndicatorState};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
 
@@ -26,7 +28,7 @@ const VOLUME_FACTOR_ADJUSTMENT_THRESHOLD: f64 = 1.5;
 const SCORE_RANGE_MIN: f64 = 0.5;
 
 /// 하이브리드 분석기 데이터
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct HybridAnalyzerData<C: Candle> {
     /// 현재 캔들 데이터
     pub candle: C,
@@ -51,42 +53,8 @@ impl<C: Candle + Clone> HybridAnalyzerData<C> {
 
     /// 저장된 값으로 데이터 복제
     pub fn clone_with_stored_values(&self) -> HybridAnalyzerData<C> {
-        // Box<dyn MA>는 클론할 수 없으므로, MA 구현체의 값을 저장하고 새 객체 생성
-        let ma_period = self.ma.period();
-        let ma_value = self.ma.get();
-
-        // 값을 가지고 있는 간단한 MA 구현체
-        struct SimpleMA {
-            period: usize,
-            value: f64,
-        }
-
-        impl MA for SimpleMA {
-            fn period(&self) -> usize {
-                self.period
-            }
-
-            fn get(&self) -> f64 {
-                self.value
-            }
-        }
-
-        impl Display for SimpleMA {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "MA({}: {:.2})", self.period, self.value)
-            }
-        }
-
-        impl std::fmt::Debug for SimpleMA {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "SimpleMA({}: {:.2})", self.period, self.value)
-            }
-        }
-
-        let simple_ma = SimpleMA {
-            period: ma_period,
-            value: ma_value,
-        };
+        // Box<dyn MA>는 클론할 수 없으므로, MA 구현체의 값만 복사
+        let simple_ma = MAValue::of(self.ma.as_ref());
 
         HybridAnalyzerData {
             candle: self.candle.clone(),
@@ -1046,6 +1014,23 @@ impl<C: Candle + Clone> AnalyzerOps<HybridAnalyzerData<C>, C> for HybridAnalyzer
         data.clone_with_stored_values()
     }
 
+    fn builder_states(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        Ok(vec![
+            self.mabuilder.snapshot()?,
+            self.macdbuilder.snapshot()?,
+            self.rsibuilder.snapshot()?,
+        ])
+    }
+
+    fn restore_builder_states(&mut self, states: &[IndicatorState]) -> IndicatorResult<()> {
+        let [ma, macd, rsi] = states else {
+            return Err(builder_count_mismatch(3, states.len()));
+        };
+        self.mabuilder.restore(ma)?;
+        self.macdbuilder.restore(macd)?;
+        self.rsibuilder.restore(rsi)
+    }
+
     fn items(&self) -> &Vec<HybridAnalyzerData<C>> {
         &self.items
     }
@@ -0,0 +1,168 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::keltner::{KeltnerChannel, KeltnerChannelBuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 켈트너 채널 분석기 데이터
+#[derive(Debug)]
+pub struct KeltnerAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// 켈트너 채널
+    pub keltner: KeltnerChannel,
+}
+
+impl<C: Candle> KeltnerAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, keltner: KeltnerChannel) -> KeltnerAnalyzerData<C> {
+        KeltnerAnalyzerData { candle, keltner }
+    }
+}
+
+impl<C: Candle> GetCandle<C> for KeltnerAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for KeltnerAnalyzerData<C> {}
+
+/// 켈트너 채널 분석기
+#[derive(Debug)]
+pub struct KeltnerAnalyzer<C: Candle> {
+    /// 켈트너 채널 빌더
+    pub keltnerbuilder: KeltnerChannelBuilder<C>,
+    /// 분석 데이터 히스토리 (최신 데이터가 인덱스 0)
+    pub items: Vec<KeltnerAnalyzerData<C>>,
+}
+
+impl<C: Candle> Display for KeltnerAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.items.first() {
+            Some(first) => write!(
+                f,
+                "캔들: {}, 채널: {{상: {:.2}, 중: {:.2}, 하: {:.2}}}",
+                first.candle,
+                first.keltner.upper(),
+                first.keltner.middle(),
+                first.keltner.lower()
+            ),
+            None => write!(f, "데이터 없음"),
+        }
+    }
+}
+
+impl<C: Candle + 'static> KeltnerAnalyzer<C> {
+    /// 새 켈트너 채널 분석기 생성
+    pub fn new(
+        period: usize,
+        atr_period: usize,
+        multiplier: f64,
+        storage: &CandleStore<C>,
+    ) -> KeltnerAnalyzer<C> {
+        let keltnerbuilder = KeltnerChannelBuilder::new(period, atr_period, multiplier);
+        let mut analyzer = KeltnerAnalyzer {
+            keltnerbuilder,
+            items: vec![],
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 최신 켈트너 채널 값 반환 (하단, 중심선, 상단)
+    pub fn get_keltner(&self) -> (f64, f64, f64) {
+        match self.items.first() {
+            Some(data) => (
+                data.keltner.lower(),
+                data.keltner.middle(),
+                data.keltner.upper(),
+            ),
+            None => (0.0, 0.0, 0.0),
+        }
+    }
+
+    /// n개 연속 캔들의 종가가 상단 밴드 위에 있는지 확인
+    pub fn is_above_upper(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.candle.close_price() > data.keltner.upper(),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 하단 밴드 아래에 있는지 확인
+    pub fn is_below_lower(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.candle.close_price() < data.keltner.lower(),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 채널 안(경계 포함)에 있는지 확인
+    pub fn is_inside_channel(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| {
+                let close = data.candle.close_price();
+                data.keltner.lower() <= close && close <= data.keltner.upper()
+            },
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 중심선 위에 있는지 확인
+    pub fn is_above_middle(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.candle.close_price() > data.keltner.middle(),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 중심선 아래에 있는지 확인
+    pub fn is_below_middle(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.candle.close_price() < data.keltner.middle(),
+            n,
+            p,
+        )
+    }
+
+    /// 상단 밴드 상향 돌파 확인 (n개 연속 종가 > 상단, 이전 m개는 아님)
+    pub fn is_breakout_up(&self, n: usize, m: usize, p: usize) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| data.candle.close_price() > data.keltner.upper(),
+            n,
+            m,
+            p,
+        )
+    }
+
+    /// 하단 밴드 하향 돌파 확인 (n개 연속 종가 < 하단, 이전 m개는 아님)
+    pub fn is_breakout_down(&self, n: usize, m: usize, p: usize) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| data.candle.close_price() < data.keltner.lower(),
+            n,
+            m,
+            p,
+        )
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<KeltnerAnalyzerData<C>, C> for KeltnerAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> KeltnerAnalyzerData<C> {
+        let keltner = self.keltnerbuilder.next(&candle);
+        KeltnerAnalyzerData::new(candle, keltner)
+    }
+
+    fn items(&self) -> &Vec<KeltnerAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<KeltnerAnalyzerData<C>> {
+        &mut self.items
+    }
+}
@@ -1,11 +1,13 @@
 use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
 use crate::candle_store::CandleStore;
 use crate::indicator::ma::{MAType, MAs, MAsBuilder, MAsBuilderFactory};
+use crate::indicator::{IndicatorResult, IndicatorState};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// MA 전략 데이터
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MAAnalyzerData<C: Candle> {
     /// 현재 캔들 데이터
     pub candle: C,
@@ -180,4 +182,12 @@ impl<C: Candle> AnalyzerOps<MAAnalyzerData<C>, C> for MAAnalyzer<C> {
     fn items_mut(&mut self) -> &mut Vec<MAAnalyzerData<C>> {
         &mut self.items
     }
+
+    fn builder_states(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        self.masbuilder.snapshot()
+    }
+
+    fn restore_builder_states(&mut self, states: &[IndicatorState]) -> IndicatorResult<()> {
+        self.masbuilder.restore(states)
+    }
 }
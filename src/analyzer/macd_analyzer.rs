@@ -1,11 +1,13 @@
-use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle, builder_count_mismatch};
 use crate::candle_store::CandleStore;
 use crate::indicator::macd::{MACD, MACDBuilder};
+use crate::indicator::{IndicatorResult, IndicatorState, TABuilder};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// MACD 분석기 데이터
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MACDAnalyzerData<C: Candle> {
     /// 현재 캔들 데이터
     pub candle: C,
@@ -320,4 +322,15 @@ impl<C: Candle> AnalyzerOps<MACDAnalyzerData<C>, C> for MACDAnalyzer<C> {
     fn items_mut(&mut self) -> &mut Vec<MACDAnalyzerData<C>> {
         &mut self.items
     }
+
+    fn builder_states(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        Ok(vec![self.macdbuilder.snapshot()?])
+    }
+
+    fn restore_builder_states(&mut self, states: &[IndicatorState]) -> IndicatorResult<()> {
+        let [macd] = states else {
+            return Err(builder_count_mismatch(1, states.len()));
+        };
+        self.macdbuilder.restore(macd)
+    }
 }
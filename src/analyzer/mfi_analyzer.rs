@@ -0,0 +1,138 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::mfi::{MFIs, MFIsBuilder, MFIsBuilderFactory};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// MFI 분석기 데이터
+#[derive(Debug)]
+pub struct MFIAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// MFI 지표 집합
+    pub mfis: MFIs,
+}
+
+impl<C: Candle> MFIAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, mfis: MFIs) -> MFIAnalyzerData<C> {
+        MFIAnalyzerData { candle, mfis }
+    }
+
+    /// 특정 기간의 MFI 값 반환
+    pub fn get_mfi(&self, period: usize) -> f64 {
+        self.mfis.get(&period).value
+    }
+}
+
+impl<C: Candle> GetCandle<C> for MFIAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for MFIAnalyzerData<C> {}
+
+/// MFI 분석기
+#[derive(Debug)]
+pub struct MFIAnalyzer<C: Candle + 'static> {
+    /// MFI 빌더
+    pub mfisbuilder: MFIsBuilder<C>,
+    /// 분석 데이터 히스토리
+    pub items: Vec<MFIAnalyzerData<C>>,
+}
+
+impl<C: Candle + 'static> Display for MFIAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MFIAnalyzer {{ items: {} }}", self.items.len())
+    }
+}
+
+impl<C: Candle + 'static> MFIAnalyzer<C> {
+    /// 새 MFI 분석기 생성
+    pub fn new(periods: &[usize], storage: &CandleStore<C>) -> MFIAnalyzer<C> {
+        let mfisbuilder = MFIsBuilderFactory::build(periods);
+        let mut analyzer = MFIAnalyzer {
+            mfisbuilder,
+            items: Vec::new(),
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 저장소에서 초기 데이터 계산
+    pub fn init_from_storage(&mut self, storage: &CandleStore<C>) {
+        for candle in storage.get_ascending_items().iter() {
+            self.next(candle.clone());
+        }
+    }
+
+    /// n개 연속 데이터에서 MFI가 특정 값보다 큰지 확인
+    pub fn is_mfi_greater_than(&self, period: usize, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_mfi(period) > value, n, p)
+    }
+
+    /// n개 연속 데이터에서 MFI가 특정 값보다 작은지 확인
+    pub fn is_mfi_less_than(&self, period: usize, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_mfi(period) < value, n, p)
+    }
+
+    /// n개 연속 데이터에서 MFI가 구간(경계 포함) 안에 있는지 확인
+    pub fn is_mfi_in_range(
+        &self,
+        period: usize,
+        lower: f64,
+        upper: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(
+            |data| {
+                let value = data.get_mfi(period);
+                lower <= value && value <= upper
+            },
+            n,
+            p,
+        )
+    }
+
+    /// MFI의 기준값 상향 돌파 신호 확인 (n개 연속 > 기준값, 이전 m개는 아님)
+    pub fn is_mfi_cross_above(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_mfi(period) > value, n, m, p)
+    }
+
+    /// MFI의 기준값 하향 돌파 신호 확인 (n개 연속 < 기준값, 이전 m개는 아님)
+    pub fn is_mfi_cross_below(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_mfi(period) < value, n, m, p)
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<MFIAnalyzerData<C>, C> for MFIAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> MFIAnalyzerData<C> {
+        let mfis = self.mfisbuilder.next(&candle);
+        MFIAnalyzerData::new(candle, mfis)
+    }
+
+    fn items(&self) -> &Vec<MFIAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<MFIAnalyzerData<C>> {
+        &mut self.items
+    }
+}
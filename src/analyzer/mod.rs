@@ -13,39 +13,56 @@
 // - 개별 분석기들: 각 기술적 지표별 구현체
 // - hybrid_analyzer: 여러 지표를 결합한 고급 분석기
 
+pub mod ad_line_analyzer;
 pub mod adx_analyzer;
 pub mod atr_analyzer;
 pub mod base;
 pub mod bband_analyzer;
 pub mod candle_pattern_analyzer;
+pub mod cci_analyzer;
+pub mod cmf_analyzer;
+pub mod donchian_analyzer;
 pub mod hybrid_analyzer;
 pub mod ichimoku_analyzer;
+pub mod keltner_analyzer;
 pub mod ma_analyzer;
 pub mod macd_analyzer;
 pub mod market_structure_analyzer;
+pub mod mfi_analyzer;
 pub mod momentum_analyzer;
+pub mod obv_analyzer;
 pub mod orderbook_analyzer;
 pub mod price_action_analyzer;
 pub mod risk_management_analyzer;
 pub mod rsi_analyzer;
 pub mod signal_strength_analyzer;
 pub mod slope_analyzer;
+pub mod squeeze_analyzer;
+pub mod stoch_rsi_analyzer;
+pub mod stochastic_analyzer;
 pub mod supertrend_analyzer;
 pub mod support_resistance_analyzer;
 pub mod three_rsi_analyzer;
 pub mod volume_analyzer;
+pub mod volume_profile_analyzer;
 pub mod vwap_analyzer;
+pub mod williams_r_analyzer;
 
+pub use ad_line_analyzer::{ADLineAnalyzer, ADLineAnalyzerData};
 pub use adx_analyzer::{ADXAnalyzer, ADXAnalyzerData};
 pub use atr_analyzer::{ATRAnalyzer, ATRAnalyzerData};
-pub use base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+pub use base::{AnalyzerDataOps, AnalyzerOps, AnalyzerSnapshot, GetCandle};
 pub use bband_analyzer::{BBandAnalyzer, BBandAnalyzerData};
 pub use candle_pattern_analyzer::{
     CandlePatternAnalyzer, CandlePatternAnalyzerData, MultiCandlePattern, PatternAnalysis,
     PatternReliability, PatternSignal, SingleCandlePattern,
 };
+pub use cci_analyzer::{CCIAnalyzer, CCIAnalyzerData};
+pub use cmf_analyzer::{CMFAnalyzer, CMFAnalyzerData};
+pub use donchian_analyzer::{DonchianAnalyzer, DonchianAnalyzerData};
 pub use hybrid_analyzer::{HybridAnalyzer, HybridAnalyzerData};
 pub use ichimoku_analyzer::{IchimokuAnalyzer, IchimokuAnalyzerData};
+pub use keltner_analyzer::{KeltnerAnalyzer, KeltnerAnalyzerData};
 pub use ma_analyzer::{MAAnalyzer, MAAnalyzerData};
 pub use macd_analyzer::{MACDAnalyzer, MACDAnalyzerData};
 pub use market_structure_analyzer::{
@@ -53,10 +70,12 @@ pub use market_structure_analyzer::{
     MarketStructureAnalyzer, MarketStructureAnalyzerData, OrderBlock, OrderBlockType,
     StructureChange,
 };
+pub use mfi_analyzer::{MFIAnalyzer, MFIAnalyzerData};
 pub use momentum_analyzer::{
     DivergenceType, MomentumAnalysis, MomentumAnalyzer, MomentumAnalyzerData, MomentumDirection,
     MomentumDivergence, MomentumIndicators, MomentumState, OverBoughtOverSold,
 };
+pub use obv_analyzer::{OBVAnalyzer, OBVAnalyzerData};
 pub use orderbook_analyzer::{
     OrderBookAnalysisResult, OrderBookAnalyzerConfig, OrderBookDataPoint, OrderBookSignal,
     OrderBookTimeSeriesAnalyzer, OrderBookTrend,
@@ -76,10 +95,15 @@ pub use signal_strength_analyzer::{
 pub use slope_analyzer::{
     IndicatorType, SlopeAnalysis, SlopeAnalyzer, SlopeAnalyzerData, SlopeDirection,
 };
+pub use squeeze_analyzer::{SqueezeAnalyzer, SqueezeAnalyzerData};
+pub use stoch_rsi_analyzer::{StochRSIAnalyzer, StochRSIAnalyzerData};
+pub use stochastic_analyzer::{StochasticAnalyzer, StochasticAnalyzerData};
 pub use supertrend_analyzer::{SuperTrendAnalyzer, SuperTrendAnalyzerData};
 pub use support_resistance_analyzer::{
     LevelType, SupportResistanceAnalyzer, SupportResistanceAnalyzerData, SupportResistanceLevel,
 };
 pub use three_rsi_analyzer::{ThreeRSIAnalyzer, ThreeRSIAnalyzerData};
 pub use volume_analyzer::{VolumeAnalyzer, VolumeAnalyzerData};
-pub use vwap_analyzer::{VWAPAnalyzer, VWAPAnalyzerData};
+pub use volume_profile_analyzer::{VolumeProfileAnalyzer, VolumeProfileAnalyzerData};
+pub use vwap_analyzer::{VWAPAnalyzer, VWAPAnalyzerData, VWAPSwingAnchor};
+pub use williams_r_analyzer::{WilliamsRAnalyzer, WilliamsRAnalyzerData};
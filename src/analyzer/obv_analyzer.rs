@@ -0,0 +1,126 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::obv::{OBV, OBVBuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// OBV 분석기 데이터
+#[derive(Debug)]
+pub struct OBVAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// OBV 지표
+    pub obv: OBV,
+}
+
+impl<C: Candle> OBVAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, obv: OBV) -> OBVAnalyzerData<C> {
+        OBVAnalyzerData { candle, obv }
+    }
+}
+
+impl<C: Candle> GetCandle<C> for OBVAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for OBVAnalyzerData<C> {}
+
+/// OBV 분석기
+#[derive(Debug)]
+pub struct OBVAnalyzer<C: Candle> {
+    /// OBV 빌더
+    pub obvbuilder: OBVBuilder<C>,
+    /// 분석 데이터 히스토리 (최신 데이터가 인덱스 0)
+    pub items: Vec<OBVAnalyzerData<C>>,
+}
+
+impl<C: Candle> Display for OBVAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.items.first() {
+            Some(first) => write!(f, "캔들: {}, {}", first.candle, first.obv),
+            None => write!(f, "데이터 없음"),
+        }
+    }
+}
+
+impl<C: Candle + 'static> OBVAnalyzer<C> {
+    /// 새 OBV 분석기 생성
+    pub fn new(storage: &CandleStore<C>) -> OBVAnalyzer<C> {
+        let mut analyzer = OBVAnalyzer {
+            obvbuilder: OBVBuilder::new(),
+            items: vec![],
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 최신 OBV 값 반환
+    pub fn get_obv(&self) -> f64 {
+        self.items.first().map(|data| data.obv.value).unwrap_or(0.0)
+    }
+
+    /// n개 연속 캔들에서 OBV가 직전 캔들보다 상승했는지 확인
+    pub fn is_obv_rising(&self, n: usize, p: usize) -> bool {
+        self.is_obv_compared_with_previous(|current, previous| current > previous, n, p)
+    }
+
+    /// n개 연속 캔들에서 OBV가 직전 캔들보다 하락했는지 확인
+    pub fn is_obv_falling(&self, n: usize, p: usize) -> bool {
+        self.is_obv_compared_with_previous(|current, previous| current < previous, n, p)
+    }
+
+    /// 강세 다이버전스 확인 (`lookback` 캔들 전보다 종가는 낮고 OBV는 높음)
+    pub fn is_bullish_divergence(&self, lookback: usize, p: usize) -> bool {
+        match (self.items.get(p), self.items.get(p + lookback)) {
+            (Some(current), Some(past)) if lookback > 0 => {
+                current.candle.close_price() < past.candle.close_price()
+                    && current.obv.value > past.obv.value
+            }
+            _ => false,
+        }
+    }
+
+    /// 약세 다이버전스 확인 (`lookback` 캔들 전보다 종가는 높고 OBV는 낮음)
+    pub fn is_bearish_divergence(&self, lookback: usize, p: usize) -> bool {
+        match (self.items.get(p), self.items.get(p + lookback)) {
+            (Some(current), Some(past)) if lookback > 0 => {
+                current.candle.close_price() > past.candle.close_price()
+                    && current.obv.value < past.obv.value
+            }
+            _ => false,
+        }
+    }
+
+    /// p부터 n개 캔들의 OBV를 각각 직전 캔들 OBV와 비교
+    fn is_obv_compared_with_previous(
+        &self,
+        compare: impl Fn(f64, f64) -> bool,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        if self.items.len() < p + n + 1 {
+            return false;
+        }
+
+        (p..p + n).all(|i| compare(self.items[i].obv.value, self.items[i + 1].obv.value))
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<OBVAnalyzerData<C>, C> for OBVAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> OBVAnalyzerData<C> {
+        let obv = self.obvbuilder.next(&candle);
+        OBVAnalyzerData::new(candle, obv)
+    }
+
+    fn items(&self) -> &Vec<OBVAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<OBVAnalyzerData<C>> {
+        &mut self.items
+    }
+}
@@ -3,6 +3,13 @@ use crate::candle_store::CandleStore;
 use std::fmt::Display;
 use trading_chart::Candle;
 
+/// 기본 스윙 포인트 식별 강도 (주변 캔들 수)
+pub const DEFAULT_SWING_STRENGTH: usize = 2;
+/// 기본 추세 분석 기간
+pub const DEFAULT_TREND_PERIOD: usize = 14;
+/// 기본 모멘텀 계산 기간
+pub const DEFAULT_MOMENTUM_PERIOD: usize = 10;
+
 /// 캔들 패턴 타입
 #[derive(Debug, Clone, PartialEq)]
 pub enum CandlePattern {
@@ -367,7 +374,12 @@ impl<C: Candle + Clone + 'static> PriceActionAnalyzer<C> {
 
     /// 기본 설정으로 분석기 생성
     pub fn default(storage: &CandleStore<C>) -> PriceActionAnalyzer<C> {
-        Self::new(storage, 2, 14, 10)
+        Self::new(
+            storage,
+            DEFAULT_SWING_STRENGTH,
+            DEFAULT_TREND_PERIOD,
+            DEFAULT_MOMENTUM_PERIOD,
+        )
     }
 
     /// 캔들 패턴 식별
@@ -1,12 +1,14 @@
-use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle, builder_count_mismatch};
 use crate::candle_store::CandleStore;
 use crate::indicator::ma::{MAType, MAs, MAsBuilder, MAsBuilderFactory};
 use crate::indicator::rsi::{RSI, RSIBuilder};
+use crate::indicator::{IndicatorResult, IndicatorState, TABuilder};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// RSI 전략 데이터
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RSIAnalyzerData<C: Candle> {
     /// 현재 캔들 데이터
     pub candle: C,
@@ -184,4 +186,19 @@ impl<C: Candle> AnalyzerOps<RSIAnalyzerData<C>, C> for RSIAnalyzer<C> {
     fn items_mut(&mut self) -> &mut Vec<RSIAnalyzerData<C>> {
         &mut self.items
     }
+
+    fn builder_states(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        let mut states = vec![self.rsibuilder.snapshot()?];
+        states.extend(self.masbuilder.snapshot()?);
+        Ok(states)
+    }
+
+    fn restore_builder_states(&mut self, states: &[IndicatorState]) -> IndicatorResult<()> {
+        // RSI 빌더 상태 뒤에 이동평균 빌더 상태가 기간 순서대로 이어짐
+        let [rsi, mas @ ..] = states else {
+            return Err(builder_count_mismatch(1, states.len()));
+        };
+        self.rsibuilder.restore(rsi)?;
+        self.masbuilder.restore(mas)
+    }
 }
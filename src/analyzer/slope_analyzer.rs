@@ -1,7 +1,8 @@
 use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
-use crate::analyzer::{MAAnalyzer, MACDAnalyzer, RSIAnalyzer};
+use crate::analyzer::{MAAnalyzer, MACDAnalyzer, RSIAnalyzer, VWAPAnalyzer};
 use crate::candle_store::CandleStore;
 use crate::indicator::ma::MAType;
+use crate::indicator::vwap::VWAPParams;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
@@ -124,6 +125,8 @@ pub enum IndicatorType {
         slow_period: usize,
         signal_period: usize,
     },
+    /// VWAP (period가 0이면 모든 데이터 사용)
+    VWAP { period: usize },
 }
 
 /// 기울기 분석기 데이터
@@ -157,6 +160,7 @@ enum StoredAnalyzer<C: Candle> {
     MAAnalyzer(MAAnalyzer<C>),
     RSIAnalyzer(RSIAnalyzer<C>),
     MACDAnalyzer(MACDAnalyzer<C>),
+    VWAPAnalyzer(VWAPAnalyzer<C>),
 }
 
 /// 기울기 분석기
@@ -290,6 +294,11 @@ impl<C: Candle + 'static> SlopeAnalyzer<C> {
         )
     }
 
+    /// VWAP 기반 기울기 분석기 생성
+    pub fn for_vwap(storage: &CandleStore<C>, period: usize) -> Self {
+        Self::new(storage, IndicatorType::VWAP { period })
+    }
+
     /// 새 기울기 분석기 생성 (내부 메서드)
     fn new(storage: &CandleStore<C>, indicator_type: IndicatorType) -> Self {
         let mut analyzer = SlopeAnalyzer {
@@ -403,6 +412,15 @@ impl<C: Candle + 'static> SlopeAnalyzer<C> {
                 }
                 self.analyzer = StoredAnalyzer::MACDAnalyzer(macd_analyzer);
             }
+            IndicatorType::VWAP { period } => {
+                let vwap_analyzer = VWAPAnalyzer::new(&[VWAPParams::new(*period)], storage);
+                for data in vwap_analyzer.items.iter() {
+                    let value = data.vwaps.get_by_key_index(0).value;
+                    self.items
+                        .push(SlopeAnalyzerData::new(data.candle.clone(), value));
+                }
+                self.analyzer = StoredAnalyzer::VWAPAnalyzer(vwap_analyzer);
+            }
         }
     }
 
@@ -745,6 +763,14 @@ impl<C: Candle + 'static + Clone> AnalyzerOps<SlopeAnalyzerData<C>, C> for Slope
                     _ => 0.0,
                 }
             }
+            StoredAnalyzer::VWAPAnalyzer(vwap_analyzer) => {
+                vwap_analyzer.next(candle.clone());
+                vwap_analyzer
+                    .items
+                    .first()
+                    .map(|d| d.vwaps.get_by_key_index(0).value)
+                    .unwrap_or(0.0)
+            }
         };
         SlopeAnalyzerData::new(candle, value)
     }
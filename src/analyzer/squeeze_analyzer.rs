@@ -0,0 +1,117 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::squeeze::{Squeeze, SqueezeBuilder, SqueezeParams};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 스퀴즈 분석기 데이터
+#[derive(Debug)]
+pub struct SqueezeAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// 스퀴즈 지표
+    pub squeeze: Squeeze,
+}
+
+impl<C: Candle> SqueezeAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, squeeze: Squeeze) -> SqueezeAnalyzerData<C> {
+        SqueezeAnalyzerData { candle, squeeze }
+    }
+}
+
+impl<C: Candle> GetCandle<C> for SqueezeAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for SqueezeAnalyzerData<C> {}
+
+/// 스퀴즈 분석기
+#[derive(Debug)]
+pub struct SqueezeAnalyzer<C: Candle> {
+    /// 스퀴즈 빌더
+    pub squeezebuilder: SqueezeBuilder<C>,
+    /// 분석 데이터 히스토리 (최신 데이터가 인덱스 0)
+    pub items: Vec<SqueezeAnalyzerData<C>>,
+}
+
+impl<C: Candle> Display for SqueezeAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.items.first() {
+            Some(first) => write!(
+                f,
+                "캔들: {}, 스퀴즈: {}, 폭 비율: {:.2}",
+                first.candle,
+                if first.squeeze.is_on() { "on" } else { "off" },
+                first.squeeze.width_ratio()
+            ),
+            None => write!(f, "데이터 없음"),
+        }
+    }
+}
+
+impl<C: Candle + 'static> SqueezeAnalyzer<C> {
+    /// 새 스퀴즈 분석기 생성
+    pub fn new(params: SqueezeParams, storage: &CandleStore<C>) -> SqueezeAnalyzer<C> {
+        let squeezebuilder = SqueezeBuilder::new(params);
+        let mut analyzer = SqueezeAnalyzer {
+            squeezebuilder,
+            items: vec![],
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// n개 연속 데이터가 스퀴즈 상태인지 확인
+    pub fn is_squeeze_on(&self, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.squeeze.is_on(), n, p)
+    }
+
+    /// n개 연속 데이터가 스퀴즈 상태가 아닌지 확인
+    pub fn is_squeeze_off(&self, n: usize, p: usize) -> bool {
+        self.is_all(|data| !data.squeeze.is_on(), n, p)
+    }
+
+    /// 스퀴즈 해제 신호 확인 (n개 연속 해제 상태, 이전 m개는 스퀴즈 상태)
+    pub fn is_squeeze_fired(&self, n: usize, m: usize, p: usize) -> bool {
+        self.is_break_through_by_satisfying(|data| !data.squeeze.is_on(), n, m, p)
+    }
+
+    /// 상방 스퀴즈 해제 확인 (해제 구간의 종가가 볼린저 밴드 중심선 위)
+    pub fn is_squeeze_fired_up(&self, n: usize, m: usize, p: usize) -> bool {
+        self.is_squeeze_fired(n, m, p)
+            && self.is_all(
+                |data| data.candle.close_price() > data.squeeze.bband.middle(),
+                n,
+                p,
+            )
+    }
+
+    /// 하방 스퀴즈 해제 확인 (해제 구간의 종가가 볼린저 밴드 중심선 아래)
+    pub fn is_squeeze_fired_down(&self, n: usize, m: usize, p: usize) -> bool {
+        self.is_squeeze_fired(n, m, p)
+            && self.is_all(
+                |data| data.candle.close_price() < data.squeeze.bband.middle(),
+                n,
+                p,
+            )
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<SqueezeAnalyzerData<C>, C> for SqueezeAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> SqueezeAnalyzerData<C> {
+        let squeeze = self.squeezebuilder.next(&candle);
+        SqueezeAnalyzerData::new(candle, squeeze)
+    }
+
+    fn items(&self) -> &Vec<SqueezeAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<SqueezeAnalyzerData<C>> {
+        &mut self.items
+    }
+}
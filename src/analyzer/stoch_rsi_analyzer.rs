@@ -0,0 +1,198 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::stoch_rsi::{
+    StochRSI, StochRSIParams, StochRSIs, StochRSIsBuilder, StochRSIsBuilderFactory,
+};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 스토캐스틱 RSI 분석기 데이터
+#[derive(Debug)]
+pub struct StochRSIAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// 스토캐스틱 RSI 지표 집합
+    pub stoch_rsis: StochRSIs,
+}
+
+impl<C: Candle> StochRSIAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, stoch_rsis: StochRSIs) -> StochRSIAnalyzerData<C> {
+        StochRSIAnalyzerData { candle, stoch_rsis }
+    }
+
+    /// 특정 매개변수의 스토캐스틱 RSI 반환
+    pub fn get_stoch_rsi(&self, params: &StochRSIParams) -> &StochRSI {
+        self.stoch_rsis.get(params)
+    }
+}
+
+impl<C: Candle> GetCandle<C> for StochRSIAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for StochRSIAnalyzerData<C> {}
+
+/// 스토캐스틱 RSI 분석기
+#[derive(Debug)]
+pub struct StochRSIAnalyzer<C: Candle + 'static> {
+    /// 스토캐스틱 RSI 빌더
+    pub stochrsisbuilder: StochRSIsBuilder<C>,
+    /// 분석 데이터 히스토리
+    pub items: Vec<StochRSIAnalyzerData<C>>,
+}
+
+impl<C: Candle + 'static> Display for StochRSIAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StochRSIAnalyzer {{ items: {} }}", self.items.len())
+    }
+}
+
+impl<C: Candle + 'static> StochRSIAnalyzer<C> {
+    /// 새 스토캐스틱 RSI 분석기 생성
+    pub fn new(params: &[StochRSIParams], storage: &CandleStore<C>) -> StochRSIAnalyzer<C> {
+        let stochrsisbuilder = StochRSIsBuilderFactory::build(params);
+        let mut analyzer = StochRSIAnalyzer {
+            stochrsisbuilder,
+            items: Vec::new(),
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 저장소에서 초기 데이터 계산
+    pub fn init_from_storage(&mut self, storage: &CandleStore<C>) {
+        for candle in storage.get_ascending_items().iter() {
+            self.next(candle.clone());
+        }
+    }
+
+    /// n개 연속 데이터에서 %K가 특정 값보다 큰지 확인
+    pub fn is_k_greater_than(
+        &self,
+        params: &StochRSIParams,
+        value: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(|data| data.get_stoch_rsi(params).k > value, n, p)
+    }
+
+    /// n개 연속 데이터에서 %K가 특정 값보다 작은지 확인
+    pub fn is_k_less_than(&self, params: &StochRSIParams, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_stoch_rsi(params).k < value, n, p)
+    }
+
+    /// n개 연속 데이터에서 %K가 구간(경계 포함) 안에 있는지 확인
+    pub fn is_k_in_range(
+        &self,
+        params: &StochRSIParams,
+        lower: f64,
+        upper: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(
+            |data| {
+                let k = data.get_stoch_rsi(params).k;
+                lower <= k && k <= upper
+            },
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 데이터에서 %K가 %D 위에 있는지 확인
+    pub fn is_k_above_d(&self, params: &StochRSIParams, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_stoch_rsi(params).is_k_above_d(), n, p)
+    }
+
+    /// n개 연속 데이터에서 %K가 %D 아래에 있는지 확인
+    pub fn is_k_below_d(&self, params: &StochRSIParams, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| {
+                let stoch_rsi = data.get_stoch_rsi(params);
+                stoch_rsi.k < stoch_rsi.d
+            },
+            n,
+            p,
+        )
+    }
+
+    /// %K의 %D 상향 돌파 신호 확인 (n개 연속 %K > %D, 이전 m개는 아님)
+    pub fn is_k_cross_above_d(
+        &self,
+        params: &StochRSIParams,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| data.get_stoch_rsi(params).is_k_above_d(),
+            n,
+            m,
+            p,
+        )
+    }
+
+    /// %K의 %D 하향 돌파 신호 확인 (n개 연속 %K < %D, 이전 m개는 아님)
+    pub fn is_k_cross_below_d(
+        &self,
+        params: &StochRSIParams,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| {
+                let stoch_rsi = data.get_stoch_rsi(params);
+                stoch_rsi.k < stoch_rsi.d
+            },
+            n,
+            m,
+            p,
+        )
+    }
+
+    /// %K의 기준값 상향 돌파 신호 확인 (n개 연속 %K > 기준값, 이전 m개는 아님)
+    pub fn is_k_cross_above(
+        &self,
+        params: &StochRSIParams,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_stoch_rsi(params).k > value, n, m, p)
+    }
+
+    /// %K의 기준값 하향 돌파 신호 확인 (n개 연속 %K < 기준값, 이전 m개는 아님)
+    pub fn is_k_cross_below(
+        &self,
+        params: &StochRSIParams,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_stoch_rsi(params).k < value, n, m, p)
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<StochRSIAnalyzerData<C>, C> for StochRSIAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> StochRSIAnalyzerData<C> {
+        let stoch_rsis = self.stochrsisbuilder.next(&candle);
+        StochRSIAnalyzerData::new(candle, stoch_rsis)
+    }
+
+    fn items(&self) -> &Vec<StochRSIAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<StochRSIAnalyzerData<C>> {
+        &mut self.items
+    }
+}
@@ -0,0 +1,207 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::stochastic::{
+    Stochastic, StochasticParams, Stochastics, StochasticsBuilder, StochasticsBuilderFactory,
+};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 스토캐스틱 분석기 데이터
+#[derive(Debug)]
+pub struct StochasticAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// 스토캐스틱 지표 집합
+    pub stochastics: Stochastics,
+}
+
+impl<C: Candle> StochasticAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, stochastics: Stochastics) -> StochasticAnalyzerData<C> {
+        StochasticAnalyzerData {
+            candle,
+            stochastics,
+        }
+    }
+
+    /// 특정 매개변수의 스토캐스틱 반환
+    pub fn get_stochastic(&self, params: &StochasticParams) -> &Stochastic {
+        self.stochastics.get(params)
+    }
+}
+
+impl<C: Candle> GetCandle<C> for StochasticAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for StochasticAnalyzerData<C> {}
+
+/// 스토캐스틱 분석기
+#[derive(Debug)]
+pub struct StochasticAnalyzer<C: Candle + 'static> {
+    /// 스토캐스틱 빌더
+    pub stochasticsbuilder: StochasticsBuilder<C>,
+    /// 분석 데이터 히스토리
+    pub items: Vec<StochasticAnalyzerData<C>>,
+}
+
+impl<C: Candle + 'static> Display for StochasticAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StochasticAnalyzer {{ items: {} }}", self.items.len())
+    }
+}
+
+impl<C: Candle + 'static> StochasticAnalyzer<C> {
+    /// 새 스토캐스틱 분석기 생성
+    pub fn new(params: &[StochasticParams], storage: &CandleStore<C>) -> StochasticAnalyzer<C> {
+        let stochasticsbuilder = StochasticsBuilderFactory::build(params);
+        let mut analyzer = StochasticAnalyzer {
+            stochasticsbuilder,
+            items: Vec::new(),
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 저장소에서 초기 데이터 계산
+    pub fn init_from_storage(&mut self, storage: &CandleStore<C>) {
+        for candle in storage.get_ascending_items().iter() {
+            self.next(candle.clone());
+        }
+    }
+
+    /// n개 연속 데이터에서 %K가 특정 값보다 큰지 확인
+    pub fn is_k_greater_than(
+        &self,
+        params: &StochasticParams,
+        value: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(|data| data.get_stochastic(params).k > value, n, p)
+    }
+
+    /// n개 연속 데이터에서 %K가 특정 값보다 작은지 확인
+    pub fn is_k_less_than(
+        &self,
+        params: &StochasticParams,
+        value: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(|data| data.get_stochastic(params).k < value, n, p)
+    }
+
+    /// n개 연속 데이터에서 %K가 구간(경계 포함) 안에 있는지 확인
+    pub fn is_k_in_range(
+        &self,
+        params: &StochasticParams,
+        lower: f64,
+        upper: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(
+            |data| {
+                let k = data.get_stochastic(params).k;
+                lower <= k && k <= upper
+            },
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 데이터에서 %K가 %D 위에 있는지 확인
+    pub fn is_k_above_d(&self, params: &StochasticParams, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_stochastic(params).is_k_above_d(), n, p)
+    }
+
+    /// n개 연속 데이터에서 %K가 %D 아래에 있는지 확인
+    pub fn is_k_below_d(&self, params: &StochasticParams, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| {
+                let stochastic = data.get_stochastic(params);
+                stochastic.k < stochastic.d
+            },
+            n,
+            p,
+        )
+    }
+
+    /// %K의 %D 상향 돌파 신호 확인 (n개 연속 %K > %D, 이전 m개는 아님)
+    pub fn is_k_cross_above_d(
+        &self,
+        params: &StochasticParams,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| data.get_stochastic(params).is_k_above_d(),
+            n,
+            m,
+            p,
+        )
+    }
+
+    /// %K의 %D 하향 돌파 신호 확인 (n개 연속 %K < %D, 이전 m개는 아님)
+    pub fn is_k_cross_below_d(
+        &self,
+        params: &StochasticParams,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| {
+                let stochastic = data.get_stochastic(params);
+                stochastic.k < stochastic.d
+            },
+            n,
+            m,
+            p,
+        )
+    }
+
+    /// %K의 기준값 상향 돌파 신호 확인 (n개 연속 %K > 기준값, 이전 m개는 아님)
+    pub fn is_k_cross_above(
+        &self,
+        params: &StochasticParams,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_stochastic(params).k > value, n, m, p)
+    }
+
+    /// %K의 기준값 하향 돌파 신호 확인 (n개 연속 %K < 기준값, 이전 m개는 아님)
+    pub fn is_k_cross_below(
+        &self,
+        params: &StochasticParams,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_stochastic(params).k < value, n, m, p)
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<StochasticAnalyzerData<C>, C> for StochasticAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> StochasticAnalyzerData<C> {
+        let stochastics = self.stochasticsbuilder.next(&candle);
+        StochasticAnalyzerData::new(candle, stochastics)
+    }
+
+    fn items(&self) -> &Vec<StochasticAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<StochasticAnalyzerData<C>> {
+        &mut self.items
+    }
+}
@@ -1,5 +1,6 @@
 use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
 use crate::candle_store::CandleStore;
+use crate::indicator::volume_profile::{VolumeProfile, VolumeProfileBuilder};
 use std::fmt::Display;
 use trading_chart::Candle;
 
@@ -155,6 +156,8 @@ pub struct SupportResistanceAnalyzer<C: Candle> {
     pub touch_threshold: f64,
     /// 최소 터치 횟수
     pub min_touch_count: usize,
+    /// 추가 레벨 소스로 쓰는 볼륨 프로파일 빌더
+    pub volume_profile: Option<VolumeProfileBuilder<C>>,
 }
 
 impl<C: Candle> Display for SupportResistanceAnalyzer<C> {
@@ -187,6 +190,30 @@ impl<C: Candle + 'static> SupportResistanceAnalyzer<C> {
             lookback_period,
             touch_threshold,
             min_touch_count,
+            volume_profile: None,
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 볼륨 프로파일 레벨을 함께 쓰는 지지/저항 분석기 생성
+    ///
+    /// 피벗 레벨에 더해 `lookback_period` 구간 볼륨 프로파일의 POC, 밸류 에어리어 상단/하단,
+    /// HVN을 레벨로 추가합니다.
+    pub fn new_with_volume_profile(
+        storage: &CandleStore<C>,
+        lookback_period: usize,
+        touch_threshold: f64,
+        min_touch_count: usize,
+        bucket_count: usize,
+    ) -> SupportResistanceAnalyzer<C> {
+        let mut analyzer = SupportResistanceAnalyzer {
+            items: Vec::new(),
+            lookback_period,
+            touch_threshold,
+            min_touch_count,
+            volume_profile: Some(VolumeProfileBuilder::new(lookback_period, bucket_count)),
         };
 
         analyzer.init_from_storage(storage);
@@ -278,6 +305,62 @@ impl<C: Candle + 'static> SupportResistanceAnalyzer<C> {
         levels
     }
 
+    /// 볼륨 프로파일에서 지지/저항 레벨 추출
+    ///
+    /// 거래량이 몰린 가격 자체가 근거이므로 최소 터치 횟수는 적용하지 않고,
+    /// 구간 안에서 한 번도 거래되지 않은 가격만 제외합니다.
+    /// 가격 위치에 따라 지지/저항이 바뀌므로 레벨 타입은 `Both`입니다.
+    fn volume_profile_levels(
+        &self,
+        profile: &VolumeProfile,
+        candles: &[C],
+    ) -> Vec<SupportResistanceLevel> {
+        if profile.buckets.is_empty() {
+            return Vec::new();
+        }
+
+        let mut prices: Vec<f64> = Vec::new();
+        let candidates = [profile.poc, profile.value_area_high, profile.value_area_low];
+        for price in candidates
+            .into_iter()
+            .chain(profile.high_volume_nodes.iter().copied())
+        {
+            if !prices.contains(&price) {
+                prices.push(price);
+            }
+        }
+
+        let mut levels = Vec::new();
+        for price in prices {
+            let mut touch_count = 0;
+            let mut last_touch_index = 0;
+
+            for (j, candle) in candles.iter().enumerate() {
+                let is_touch = candle.low_price() - self.touch_threshold <= price
+                    && price <= candle.high_price() + self.touch_threshold;
+                if is_touch {
+                    touch_count += 1;
+                    last_touch_index = j;
+                }
+            }
+
+            if touch_count > 0 {
+                let confidence_score =
+                    self.calculate_confidence_score(touch_count, last_touch_index, candles.len());
+
+                levels.push(SupportResistanceLevel {
+                    price,
+                    touch_count,
+                    level_type: LevelType::Both,
+                    last_touch_index,
+                    confidence_score,
+                });
+            }
+        }
+
+        levels
+    }
+
     /// 신뢰도 점수 계산
     fn calculate_confidence_score(
         &self,
@@ -613,7 +696,11 @@ impl<C: Candle + 'static> AnalyzerOps<SupportResistanceAnalyzerData<C>, C>
         }
 
         // 지지/저항 레벨 식별
-        let levels = self.identify_levels(&recent_candles);
+        let mut levels = self.identify_levels(&recent_candles);
+        if let Some(builder) = self.volume_profile.as_mut() {
+            let profile = builder.next(&candle);
+            levels.extend(self.volume_profile_levels(&profile, &recent_candles));
+        }
 
         // 가장 가까운 지지/저항선 찾기
         let (nearest_support, nearest_resistance) =
@@ -1,14 +1,15 @@
-use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle, builder_count_mismatch};
 use crate::candle_store::CandleStore;
-use crate::indicator::TABuilder;
 use crate::indicator::adx::{ADX, ADXBuilder};
 use crate::indicator::ma::{MA, MABuilderFactory, MAType};
 use crate::indicator::rsi::{RSIs, RSIsBuilder, RSIsBuilderFactory};
+use crate::indicator::{IndicatorResult, IndicatorState, TABuilder};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// ThreeRSI 분석기 데이터
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ThreeRSIAnalyzerData<C: Candle> {
     /// 현재 캔들 데이터
     pub candle: C,
@@ -207,4 +208,21 @@ impl<C: Candle> AnalyzerOps<ThreeRSIAnalyzerData<C>, C> for ThreeRSIAnalyzer<C>
     fn items_mut(&mut self) -> &mut Vec<ThreeRSIAnalyzerData<C>> {
         &mut self.items
     }
+
+    fn builder_states(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        let mut states = self.rsisbuilder.snapshot()?;
+        states.push(self.mabuilder.snapshot()?);
+        states.push(self.adxbuilder.snapshot()?);
+        Ok(states)
+    }
+
+    fn restore_builder_states(&mut self, states: &[IndicatorState]) -> IndicatorResult<()> {
+        // RSI 빌더 상태들 뒤에 이동평균, ADX 빌더 상태가 이어짐
+        let [rsis @ .., ma, adx] = states else {
+            return Err(builder_count_mismatch(2, states.len()));
+        };
+        self.rsisbuilder.restore(rsis)?;
+        self.mabuilder.restore(ma)?;
+        self.adxbuilder.restore(adx)
+    }
 }
@@ -0,0 +1,163 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::volume_profile::{VolumeProfile, VolumeProfileBuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 볼륨 프로파일 분석기 데이터
+#[derive(Debug)]
+pub struct VolumeProfileAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// 현재 캔들까지의 볼륨 프로파일
+    pub profile: VolumeProfile,
+}
+
+impl<C: Candle> VolumeProfileAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, profile: VolumeProfile) -> VolumeProfileAnalyzerData<C> {
+        VolumeProfileAnalyzerData { candle, profile }
+    }
+}
+
+impl<C: Candle> GetCandle<C> for VolumeProfileAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for VolumeProfileAnalyzerData<C> {}
+
+/// 볼륨 프로파일 분석기
+#[derive(Debug)]
+pub struct VolumeProfileAnalyzer<C: Candle> {
+    /// 볼륨 프로파일 빌더
+    pub profilebuilder: VolumeProfileBuilder<C>,
+    /// 분석 데이터 히스토리 (최신 데이터가 인덱스 0)
+    pub items: Vec<VolumeProfileAnalyzerData<C>>,
+}
+
+impl<C: Candle> Display for VolumeProfileAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.items.first() {
+            Some(first) => write!(f, "캔들: {}, {}", first.candle, first.profile),
+            None => write!(f, "데이터 없음"),
+        }
+    }
+}
+
+impl<C: Candle + 'static> VolumeProfileAnalyzer<C> {
+    /// 새 볼륨 프로파일 분석기 생성
+    pub fn new(
+        period: usize,
+        bucket_count: usize,
+        storage: &CandleStore<C>,
+    ) -> VolumeProfileAnalyzer<C> {
+        let mut analyzer = VolumeProfileAnalyzer {
+            profilebuilder: VolumeProfileBuilder::new(period, bucket_count),
+            items: vec![],
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 최신 볼륨 프로파일 반환
+    pub fn get_profile(&self) -> Option<&VolumeProfile> {
+        self.items.first().map(|data| &data.profile)
+    }
+
+    /// 최신 POC 가격 반환
+    pub fn get_poc(&self) -> f64 {
+        self.items
+            .first()
+            .map(|data| data.profile.poc)
+            .unwrap_or(0.0)
+    }
+
+    /// n개 연속 캔들의 종가가 밸류 에어리어 위에 있는지 확인
+    pub fn is_above_value_area(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.profile.is_above_value_area(data.candle.close_price()),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 밸류 에어리어 아래에 있는지 확인
+    pub fn is_below_value_area(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.profile.is_below_value_area(data.candle.close_price()),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 밸류 에어리어 안(경계 포함)에 있는지 확인
+    pub fn is_inside_value_area(&self, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| data.profile.is_inside_value_area(data.candle.close_price()),
+            n,
+            p,
+        )
+    }
+
+    /// n개 연속 캔들의 종가가 POC 위에 있는지 확인
+    pub fn is_above_poc(&self, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.candle.close_price() > data.profile.poc, n, p)
+    }
+
+    /// n개 연속 캔들의 종가가 POC 아래에 있는지 확인
+    pub fn is_below_poc(&self, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.candle.close_price() < data.profile.poc, n, p)
+    }
+
+    /// n개 연속 캔들의 종가가 HVN 근처(거리 `threshold` 이내)에 있는지 확인
+    pub fn is_near_high_volume_node(&self, threshold: f64, n: usize, p: usize) -> bool {
+        self.is_all(
+            |data| {
+                data.profile
+                    .is_near_high_volume_node(data.candle.close_price(), threshold)
+            },
+            n,
+            p,
+        )
+    }
+
+    /// 밸류 에어리어 상향 이탈 확인 (n개 연속 종가 > 상단, 이전 m개는 아님)
+    pub fn is_value_area_breakout_up(&self, n: usize, m: usize, p: usize) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| data.profile.is_above_value_area(data.candle.close_price()),
+            n,
+            m,
+            p,
+        )
+    }
+
+    /// 밸류 에어리어 하향 이탈 확인 (n개 연속 종가 < 하단, 이전 m개는 아님)
+    pub fn is_value_area_breakout_down(&self, n: usize, m: usize, p: usize) -> bool {
+        self.is_break_through_by_satisfying(
+            |data| data.profile.is_below_value_area(data.candle.close_price()),
+            n,
+            m,
+            p,
+        )
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<VolumeProfileAnalyzerData<C>, C>
+    for VolumeProfileAnalyzer<C>
+{
+    fn next_data(&mut self, candle: C) -> VolumeProfileAnalyzerData<C> {
+        let profile = self.profilebuilder.next(&candle);
+        VolumeProfileAnalyzerData::new(candle, profile)
+    }
+
+    fn items(&self) -> &Vec<VolumeProfileAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<VolumeProfileAnalyzerData<C>> {
+        &mut self.items
+    }
+}
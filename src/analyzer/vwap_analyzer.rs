@@ -1,6 +1,10 @@
 use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::analyzer::price_action_analyzer::{
+    DEFAULT_MOMENTUM_PERIOD, DEFAULT_TREND_PERIOD, PriceActionAnalyzer, SwingType,
+};
 use crate::candle_store::CandleStore;
-use crate::indicator::vwap::{VWAPParams, VWAPs, VWAPsBuilder, VWAPsBuilderFactory};
+use crate::indicator::vwap::{VWAP, VWAPParams, VWAPs, VWAPsBuilder, VWAPsBuilderFactory};
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Display;
 use trading_chart::Candle;
 
@@ -76,6 +80,127 @@ impl<C: Candle> GetCandle<C> for VWAPAnalyzerData<C> {
 
 impl<C: Candle> AnalyzerDataOps<C> for VWAPAnalyzerData<C> {}
 
+/// 스윙 포인트 앵커
+///
+/// `PriceActionAnalyzer`가 찾은 최근 스윙 고점/저점 캔들부터 현재 캔들까지 VWAP을 누적합니다.
+/// 새 스윙 포인트가 확인될 때만 누적 합계를 그 캔들부터 다시 계산하고,
+/// 스윙 포인트가 확인되기 전에는 현재 캔들만으로 계산합니다.
+#[derive(Debug)]
+pub struct VWAPSwingAnchor<C: Candle> {
+    /// 앵커로 사용할 스윙 타입
+    pub swing_type: SwingType,
+    /// 스윙 포인트 분석기
+    pub price_action: PriceActionAnalyzer<C>,
+    /// 앵커 캔들이 현재 캔들로부터 몇 번째 이전 캔들인지 (확인 전이면 None)
+    anchor_index: Option<usize>,
+    /// 최근 캔들의 (typical_price, volume) 쌍 (오래된 캔들이 앞)
+    ///
+    /// 새 스윙 포인트는 좌우 `swing_strength`개 캔들이 모두 있어야 확인되므로
+    /// 항상 `swing_strength`번째 이전 캔들에서 확인되며, 그만큼만 보관합니다.
+    recent: VecDeque<(f64, f64)>,
+    /// 앵커 캔들부터 누적 (가격 * 거래량) 합계
+    cumulative_pv: f64,
+    /// 앵커 캔들부터 누적 (가격² * 거래량) 합계
+    cumulative_p2v: f64,
+    /// 앵커 캔들부터 누적 거래량 합계
+    cumulative_volume: f64,
+}
+
+impl<C: Candle + 'static> VWAPSwingAnchor<C> {
+    /// 새 스윙 포인트 앵커 생성
+    ///
+    /// 추세/모멘텀 기간은 스윙 포인트 식별에 쓰이지 않으므로 `PriceActionAnalyzer` 기본값을 사용합니다.
+    pub fn new(swing_type: SwingType, swing_strength: usize) -> VWAPSwingAnchor<C> {
+        VWAPSwingAnchor {
+            swing_type,
+            price_action: PriceActionAnalyzer {
+                items: vec![],
+                swing_strength,
+                trend_period: DEFAULT_TREND_PERIOD,
+                momentum_period: DEFAULT_MOMENTUM_PERIOD,
+            },
+            anchor_index: None,
+            recent: VecDeque::with_capacity(swing_strength + 1),
+            cumulative_pv: 0.0,
+            cumulative_p2v: 0.0,
+            cumulative_volume: 0.0,
+        }
+    }
+
+    /// 새 캔들을 반영하고 최근 스윙 포인트부터의 VWAP 반환
+    pub fn next(&mut self, candle: &C) -> VWAP {
+        self.price_action.next(candle.clone());
+
+        // 가격이나 거래량이 유효하지 않은 캔들은 VWAP에 반영하지 않음
+        let typical_price = (candle.high_price() + candle.low_price() + candle.close_price()) / 3.0;
+        let volume = candle.volume();
+        let value = if typical_price.is_finite() && volume.is_finite() {
+            (typical_price, volume)
+        } else {
+            (0.0, 0.0)
+        };
+        if self.recent.len() > self.price_action.swing_strength {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(value);
+
+        // 스윙 포인트 인덱스는 현재 캔들(인덱스 0)로부터 몇 번째 이전 캔들인지를 나타냄
+        let swing_index = self
+            .price_action
+            .items
+            .first()
+            .and_then(|data| match self.swing_type {
+                SwingType::High => data.get_recent_swing_high(),
+                SwingType::Low => data.get_recent_swing_low(),
+            })
+            .map(|point| point.index);
+        let anchor_index = self.anchor_index.map(|index| index + 1);
+
+        match (swing_index, anchor_index) {
+            // 새 스윙 포인트가 확인되면 그 캔들부터 다시 누적
+            (Some(swing), previous) if previous.is_none_or(|index| swing < index) => {
+                debug_assert!(swing < self.recent.len());
+                self.reset();
+                for index in self.recent.len().saturating_sub(swing + 1)..self.recent.len() {
+                    self.accumulate(self.recent[index]);
+                }
+                self.anchor_index = Some(swing);
+            }
+            // 앵커가 그대로이면 (스윙 포인트가 분석 범위를 벗어나도) 현재 캔들만 더함
+            (_, Some(index)) => {
+                self.accumulate(value);
+                self.anchor_index = Some(index);
+            }
+            // 스윙 포인트가 확인되기 전에는 현재 캔들만 사용
+            (_, None) => {
+                self.reset();
+                self.accumulate(value);
+            }
+        }
+
+        VWAP::from_sums(
+            VWAPParams::default(),
+            self.cumulative_pv,
+            self.cumulative_p2v,
+            self.cumulative_volume,
+        )
+    }
+
+    /// 누적 합계 초기화
+    fn reset(&mut self) {
+        self.cumulative_pv = 0.0;
+        self.cumulative_p2v = 0.0;
+        self.cumulative_volume = 0.0;
+    }
+
+    /// 누적 합계에 (typical_price, volume) 쌍 추가
+    fn accumulate(&mut self, (typical_price, volume): (f64, f64)) {
+        self.cumulative_pv += typical_price * volume;
+        self.cumulative_p2v += typical_price * typical_price * volume;
+        self.cumulative_volume += volume;
+    }
+}
+
 /// VWAP 전략 컨텍스트
 #[derive(Debug)]
 pub struct VWAPAnalyzer<C: Candle> {
@@ -83,6 +208,8 @@ pub struct VWAPAnalyzer<C: Candle> {
     pub vwapsbuilder: VWAPsBuilder<C>,
     /// 파라미터 리스트
     pub params: Vec<VWAPParams>,
+    /// 스윙 포인트 앵커 (있으면 빌더 대신 사용)
+    pub swing_anchor: Option<VWAPSwingAnchor<C>>,
     /// 전략 데이터 히스토리 (최신 데이터가 인덱스 0)
     pub items: Vec<VWAPAnalyzerData<C>>,
 }
@@ -103,6 +230,26 @@ impl<C: Candle + 'static> VWAPAnalyzer<C> {
         let mut ctx = VWAPAnalyzer {
             vwapsbuilder,
             params: params.to_vec(),
+            swing_anchor: None,
+            items: vec![],
+        };
+        ctx.init_from_storage(storage);
+        ctx
+    }
+
+    /// 최근 스윙 고점/저점에 앵커한 VWAP 전략 컨텍스트 생성
+    ///
+    /// VWAP은 `VWAPParams::default()` 키로 저장됩니다.
+    pub fn new_swing_anchored(
+        swing_type: SwingType,
+        swing_strength: usize,
+        storage: &CandleStore<C>,
+    ) -> VWAPAnalyzer<C> {
+        let params = vec![VWAPParams::default()];
+        let mut ctx = VWAPAnalyzer {
+            vwapsbuilder: VWAPsBuilderFactory::build::<C>(&params),
+            params,
+            swing_anchor: Some(VWAPSwingAnchor::new(swing_type, swing_strength)),
             items: vec![],
         };
         ctx.init_from_storage(storage);
@@ -419,9 +566,19 @@ impl<C: Candle + 'static> VWAPAnalyzer<C> {
     }
 }
 
-impl<C: Candle> AnalyzerOps<VWAPAnalyzerData<C>, C> for VWAPAnalyzer<C> {
+impl<C: Candle + 'static> AnalyzerOps<VWAPAnalyzerData<C>, C> for VWAPAnalyzer<C> {
     fn next_data(&mut self, candle: C) -> VWAPAnalyzerData<C> {
-        let vwaps = self.vwapsbuilder.next(&candle);
+        let vwaps = match &mut self.swing_anchor {
+            Some(anchor) => {
+                let vwap = anchor.next(&candle);
+                VWAPs::new(
+                    "vwaps".to_owned(),
+                    self.params.clone(),
+                    HashMap::from([(self.params[0], vwap)]),
+                )
+            }
+            None => self.vwapsbuilder.next(&candle),
+        };
         VWAPAnalyzerData::new(candle, vwaps)
     }
 
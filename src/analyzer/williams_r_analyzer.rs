@@ -0,0 +1,147 @@
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps, GetCandle};
+use crate::candle_store::CandleStore;
+use crate::indicator::williams_r::{WilliamsRs, WilliamsRsBuilder, WilliamsRsBuilderFactory};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// Williams %R 분석기 데이터
+#[derive(Debug)]
+pub struct WilliamsRAnalyzerData<C: Candle> {
+    /// 현재 캔들 데이터
+    pub candle: C,
+    /// Williams %R 지표 집합
+    pub williams_rs: WilliamsRs,
+}
+
+impl<C: Candle> WilliamsRAnalyzerData<C> {
+    /// 새 분석기 데이터 생성
+    pub fn new(candle: C, williams_rs: WilliamsRs) -> WilliamsRAnalyzerData<C> {
+        WilliamsRAnalyzerData {
+            candle,
+            williams_rs,
+        }
+    }
+
+    /// 특정 기간의 Williams %R 값 반환
+    pub fn get_williams_r(&self, period: usize) -> f64 {
+        self.williams_rs.get(&period).value
+    }
+}
+
+impl<C: Candle> GetCandle<C> for WilliamsRAnalyzerData<C> {
+    fn candle(&self) -> &C {
+        &self.candle
+    }
+}
+
+impl<C: Candle> AnalyzerDataOps<C> for WilliamsRAnalyzerData<C> {}
+
+/// Williams %R 분석기
+#[derive(Debug)]
+pub struct WilliamsRAnalyzer<C: Candle + 'static> {
+    /// Williams %R 빌더
+    pub williamsrsbuilder: WilliamsRsBuilder<C>,
+    /// 분석 데이터 히스토리
+    pub items: Vec<WilliamsRAnalyzerData<C>>,
+}
+
+impl<C: Candle + 'static> Display for WilliamsRAnalyzer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WilliamsRAnalyzer {{ items: {} }}", self.items.len())
+    }
+}
+
+impl<C: Candle + 'static> WilliamsRAnalyzer<C> {
+    /// 새 Williams %R 분석기 생성
+    pub fn new(periods: &[usize], storage: &CandleStore<C>) -> WilliamsRAnalyzer<C> {
+        let williamsrsbuilder = WilliamsRsBuilderFactory::build(periods);
+        let mut analyzer = WilliamsRAnalyzer {
+            williamsrsbuilder,
+            items: Vec::new(),
+        };
+
+        analyzer.init_from_storage(storage);
+        analyzer
+    }
+
+    /// 저장소에서 초기 데이터 계산
+    pub fn init_from_storage(&mut self, storage: &CandleStore<C>) {
+        for candle in storage.get_ascending_items().iter() {
+            self.next(candle.clone());
+        }
+    }
+
+    /// n개 연속 데이터에서 Williams %R이 특정 값보다 큰지 확인
+    pub fn is_williams_r_greater_than(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(|data| data.get_williams_r(period) > value, n, p)
+    }
+
+    /// n개 연속 데이터에서 Williams %R이 특정 값보다 작은지 확인
+    pub fn is_williams_r_less_than(&self, period: usize, value: f64, n: usize, p: usize) -> bool {
+        self.is_all(|data| data.get_williams_r(period) < value, n, p)
+    }
+
+    /// n개 연속 데이터에서 Williams %R이 구간(경계 포함) 안에 있는지 확인
+    pub fn is_williams_r_in_range(
+        &self,
+        period: usize,
+        lower: f64,
+        upper: f64,
+        n: usize,
+        p: usize,
+    ) -> bool {
+        self.is_all(
+            |data| {
+                let value = data.get_williams_r(period);
+                lower <= value && value <= upper
+            },
+            n,
+            p,
+        )
+    }
+
+    /// Williams %R의 기준값 상향 돌파 신호 확인 (n개 연속 > 기준값, 이전 m개는 아님)
+    pub fn is_williams_r_cross_above(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_williams_r(period) > value, n, m, p)
+    }
+
+    /// Williams %R의 기준값 하향 돌파 신호 확인 (n개 연속 < 기준값, 이전 m개는 아님)
+    pub fn is_williams_r_cross_below(
+        &self,
+        period: usize,
+        value: f64,
+        n: usize,
+        m: usize,
+        p: usize,
+    ) -> bool {
+        self.is_break_through_by_satisfying(|data| data.get_williams_r(period) < value, n, m, p)
+    }
+}
+
+impl<C: Candle + 'static> AnalyzerOps<WilliamsRAnalyzerData<C>, C> for WilliamsRAnalyzer<C> {
+    fn next_data(&mut self, candle: C) -> WilliamsRAnalyzerData<C> {
+        let williams_rs = self.williamsrsbuilder.next(&candle);
+        WilliamsRAnalyzerData::new(candle, williams_rs)
+    }
+
+    fn items(&self) -> &Vec<WilliamsRAnalyzerData<C>> {
+        &self.items
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<WilliamsRAnalyzerData<C>> {
+        &mut self.items
+    }
+}
@@ -0,0 +1,55 @@
+// 백테스트 오류
+// 전략 생성 오류는 StrategyError를 그대로 감싸므로,
+// 그리드 탐색이나 워크포워드 결과에서도 문제가 된 설정 키와 값을 확인할 수 있습니다.
+
+use crate::ConfigError;
+use crate::strategy::StrategyError;
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+/// 백테스트 실행 오류
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum BacktestError {
+    /// 백테스트 또는 워크포워드 설정 오류
+    #[error("{0}")]
+    Config(ConfigError),
+    /// 전략 생성 오류
+    #[error(transparent)]
+    Strategy(#[from] StrategyError),
+    /// 순위 산출에 필요한 거래 수 부족
+    #[error("거래 수 부족: {trades} < {min_trades}")]
+    InsufficientTrades { trades: usize, min_trades: usize },
+    /// 분석에 필요한 캔들 수 부족
+    #[error("캔들 수 부족: {candles} < {required}")]
+    InsufficientCandles { candles: usize, required: usize },
+    /// 구간에서 평가 가능한 설정 조합 없음
+    #[error("유효한 설정 조합 없음 (건너뜀 {skipped}개)")]
+    NoValidCombination { skipped: usize },
+}
+
+impl BacktestError {
+    /// 전략 생성 오류이면 해당 오류 반환
+    pub fn strategy_error(&self) -> Option<&StrategyError> {
+        match self {
+            BacktestError::Strategy(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// 설정 오류 변환 (전략 오류를 감싼 경우 그대로 꺼냄)
+impl From<ConfigError> for BacktestError {
+    fn from(err: ConfigError) -> Self {
+        match err {
+            ConfigError::Strategy(err) => BacktestError::Strategy(*err),
+            err => BacktestError::Config(err),
+        }
+    }
+}
+
+/// 보고서 JSON에는 오류 메시지 문자열로 기록
+impl Serialize for BacktestError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
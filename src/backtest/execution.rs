@@ -0,0 +1,602 @@
+// 체결 비용 모델
+// 시뮬레이션 체결 시 수수료, 슬리피지, 스프레드를 반영합니다.
+//
+// # 제공 모델
+// - FixedFeeModel: 체결 금액 대비 고정 bps 수수료
+// - MakerTakerFeeModel: 누적 거래대금 구간별 메이커/테이커 수수료
+// - FixedSlippageModel: 기준 가격 대비 고정 bps 슬리피지
+// - OrderBookSlippageModel: 호가창 스냅샷 기반 슬리피지 (OrderBookAnalyzer 사용)
+// - CombinedExecutionModel: 여러 모델을 순서대로 적용
+
+use crate::indicator::orderbook::{OrderBook, OrderBookAnalyzer};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// 1 bps (0.01%)를 비율로 변환하기 위한 상수
+const BPS: f64 = 10_000.0;
+
+/// 주문 방향
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    /// 매수 (롱 진입, 숏 청산)
+    Buy,
+    /// 매도 (롱 청산, 숏 진입)
+    Sell,
+}
+
+impl OrderSide {
+    /// 매수 주문인지 확인
+    pub fn is_buy(&self) -> bool {
+        matches!(self, OrderSide::Buy)
+    }
+
+    /// 불리한 방향으로 가격을 비율만큼 이동
+    ///
+    /// 매수는 가격을 올리고 매도는 가격을 내립니다.
+    fn adverse(&self, price: f64, rate: f64) -> f64 {
+        match self {
+            OrderSide::Buy => price * (1.0 + rate),
+            OrderSide::Sell => price * (1.0 - rate),
+        }
+    }
+}
+
+/// 유동성 제공 역할
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LiquidityRole {
+    /// 호가를 제공하는 지정가 체결
+    Maker,
+    /// 호가를 소비하는 시장가 체결
+    #[default]
+    Taker,
+}
+
+/// 체결 요청 정보
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillRequest {
+    /// 주문 방향
+    pub side: OrderSide,
+    /// 비용 반영 전 기준 가격 (시가 또는 종가)
+    pub price: f64,
+    /// 기준 가격 기준 주문 금액 (호가 통화)
+    pub notional: f64,
+    /// 체결 시각
+    pub datetime: DateTime<Utc>,
+    /// 이전 체결까지의 누적 거래대금
+    pub cumulative_notional: f64,
+}
+
+/// 시뮬레이션 체결 비용 모델
+///
+/// 기본 구현은 비용이 없는 이상적인 체결입니다.
+pub trait ExecutionModel: Send + Sync + Debug {
+    /// 슬리피지와 스프레드를 반영한 체결 가격
+    ///
+    /// # Arguments
+    /// * `request` - 체결 요청 정보
+    ///
+    /// # Returns
+    /// * `f64` - 실제 체결 가격
+    fn fill_price(&self, request: &FillRequest) -> f64 {
+        request.price
+    }
+
+    /// 체결 금액에 대한 수수료
+    ///
+    /// # Arguments
+    /// * `request` - 체결 요청 정보
+    /// * `fill_notional` - 실제 체결 가격 기준 체결 금액
+    ///
+    /// # Returns
+    /// * `f64` - 수수료 (호가 통화)
+    fn fee(&self, _request: &FillRequest, _fill_notional: f64) -> f64 {
+        0.0
+    }
+}
+
+/// 비용이 없는 이상적인 체결 모델
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoCostModel;
+
+impl ExecutionModel for NoCostModel {}
+
+/// 체결 금액 대비 고정 bps 수수료 모델
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FixedFeeModel {
+    /// 수수료 (bps)
+    pub fee_bps: f64,
+}
+
+impl FixedFeeModel {
+    /// 새 고정 수수료 모델 생성
+    ///
+    /// # Arguments
+    /// * `fee_bps` - 수수료 (bps, 0 이상)
+    ///
+    /// # Returns
+    /// * `Result<FixedFeeModel, String>` - 생성된 모델 또는 에러
+    pub fn new(fee_bps: f64) -> Result<FixedFeeModel, String> {
+        validate_bps("fee_bps", fee_bps)?;
+        Ok(FixedFeeModel { fee_bps })
+    }
+}
+
+impl ExecutionModel for FixedFeeModel {
+    fn fee(&self, _request: &FillRequest, fill_notional: f64) -> f64 {
+        fill_notional.abs() * self.fee_bps / BPS
+    }
+}
+
+/// 누적 거래대금 구간별 수수료
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FeeTier {
+    /// 구간이 적용되는 최소 누적 거래대금
+    pub min_volume: f64,
+    /// 메이커 수수료 (bps, 리베이트는 음수)
+    pub maker_bps: f64,
+    /// 테이커 수수료 (bps)
+    pub taker_bps: f64,
+}
+
+/// 메이커/테이커 구간 수수료 모델
+///
+/// 체결 직전까지의 누적 거래대금으로 구간을 선택합니다.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MakerTakerFeeModel {
+    /// 최소 누적 거래대금 오름차순으로 정렬된 수수료 구간
+    tiers: Vec<FeeTier>,
+    /// 시뮬레이션 체결에 적용할 역할
+    role: LiquidityRole,
+}
+
+impl MakerTakerFeeModel {
+    /// 단일 구간 메이커/테이커 수수료 모델 생성
+    ///
+    /// # Arguments
+    /// * `maker_bps` - 메이커 수수료 (bps)
+    /// * `taker_bps` - 테이커 수수료 (bps)
+    /// * `role` - 체결에 적용할 역할
+    ///
+    /// # Returns
+    /// * `Result<MakerTakerFeeModel, String>` - 생성된 모델 또는 에러
+    pub fn new(
+        maker_bps: f64,
+        taker_bps: f64,
+        role: LiquidityRole,
+    ) -> Result<MakerTakerFeeModel, String> {
+        Self::with_tiers(
+            vec![FeeTier {
+                min_volume: 0.0,
+                maker_bps,
+                taker_bps,
+            }],
+            role,
+        )
+    }
+
+    /// 거래대금 구간별 수수료 모델 생성
+    ///
+    /// # Arguments
+    /// * `tiers` - 수수료 구간 목록 (순서 무관)
+    /// * `role` - 체결에 적용할 역할
+    ///
+    /// # Returns
+    /// * `Result<MakerTakerFeeModel, String>` - 생성된 모델 또는 에러
+    pub fn with_tiers(
+        mut tiers: Vec<FeeTier>,
+        role: LiquidityRole,
+    ) -> Result<MakerTakerFeeModel, String> {
+        if tiers.is_empty() {
+            return Err("수수료 구간은 최소 1개 이상이어야 합니다".to_string());
+        }
+
+        for tier in &tiers {
+            if !tier.min_volume.is_finite() || tier.min_volume < 0.0 {
+                return Err("min_volume은 0 이상의 유한한 숫자여야 합니다".to_string());
+            }
+            // 메이커 리베이트를 허용하므로 음수 여부는 검사하지 않음
+            if !tier.maker_bps.is_finite() {
+                return Err("maker_bps는 유한한 숫자여야 합니다".to_string());
+            }
+            validate_bps("taker_bps", tier.taker_bps)?;
+        }
+
+        tiers.sort_by(|a, b| a.min_volume.total_cmp(&b.min_volume));
+        Ok(MakerTakerFeeModel { tiers, role })
+    }
+
+    /// 누적 거래대금에 해당하는 수수료 구간
+    pub fn tier_for(&self, cumulative_notional: f64) -> &FeeTier {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| cumulative_notional >= tier.min_volume)
+            .unwrap_or(&self.tiers[0])
+    }
+}
+
+impl ExecutionModel for MakerTakerFeeModel {
+    fn fee(&self, request: &FillRequest, fill_notional: f64) -> f64 {
+        let tier = self.tier_for(request.cumulative_notional);
+        let bps = match self.role {
+            LiquidityRole::Maker => tier.maker_bps,
+            LiquidityRole::Taker => tier.taker_bps,
+        };
+        fill_notional.abs() * bps / BPS
+    }
+}
+
+/// 기준 가격 대비 고정 bps 슬리피지 모델
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FixedSlippageModel {
+    /// 슬리피지 (bps)
+    pub slippage_bps: f64,
+}
+
+impl FixedSlippageModel {
+    /// 새 고정 슬리피지 모델 생성
+    ///
+    /// # Arguments
+    /// * `slippage_bps` - 슬리피지 (bps, 0 이상)
+    ///
+    /// # Returns
+    /// * `Result<FixedSlippageModel, String>` - 생성된 모델 또는 에러
+    pub fn new(slippage_bps: f64) -> Result<FixedSlippageModel, String> {
+        validate_bps("slippage_bps", slippage_bps)?;
+        Ok(FixedSlippageModel { slippage_bps })
+    }
+}
+
+impl ExecutionModel for FixedSlippageModel {
+    fn fill_price(&self, request: &FillRequest) -> f64 {
+        request.side.adverse(request.price, self.slippage_bps / BPS)
+    }
+}
+
+/// 호가창 스냅샷 기반 슬리피지 모델
+///
+/// 체결 시각 이전의 가장 최근 스냅샷에서 주문 금액만큼 호가를 소비했을 때의
+/// 가격 충격을 비율로 계산하여 기준 가격에 적용합니다. 스냅샷 가격대와 캔들
+/// 가격대가 달라도 비율만 사용하므로 그대로 적용할 수 있습니다.
+///
+/// 체결 시각 이전의 스냅샷이 없으면 미래 호가를 참조하지 않도록 가격 충격을
+/// 0으로 처리합니다. 주문 금액이 호가 잔량보다 크면 남은 금액은 가장 불리한
+/// 호가에서 `depth_penalty_bps`만큼 더 불리한 가격으로 체결된다고 가정합니다.
+#[derive(Debug, Clone)]
+pub struct OrderBookSlippageModel {
+    /// 호가창 분석기
+    analyzer: OrderBookAnalyzer,
+    /// 타임스탬프(밀리초) 오름차순으로 정렬된 호가창 스냅샷
+    snapshots: Vec<OrderBook>,
+    /// 중간가 대비 스프레드 비용 포함 여부
+    include_spread: bool,
+    /// 호가 잔량을 넘는 주문 금액에 적용할 추가 불리 가격 (bps)
+    depth_penalty_bps: f64,
+}
+
+impl OrderBookSlippageModel {
+    /// 새 호가창 슬리피지 모델 생성
+    ///
+    /// # Arguments
+    /// * `analyzer` - 호가창 분석기
+    /// * `snapshots` - 호가창 스냅샷 목록 (타임스탬프는 밀리초)
+    ///
+    /// # Returns
+    /// * `Result<OrderBookSlippageModel, String>` - 생성된 모델 또는 에러
+    pub fn new(
+        analyzer: OrderBookAnalyzer,
+        mut snapshots: Vec<OrderBook>,
+    ) -> Result<OrderBookSlippageModel, String> {
+        if snapshots.is_empty() {
+            return Err("호가창 스냅샷은 최소 1개 이상이어야 합니다".to_string());
+        }
+
+        snapshots.sort_by_key(|orderbook| orderbook.timestamp);
+        Ok(OrderBookSlippageModel {
+            analyzer,
+            snapshots,
+            include_spread: true,
+            depth_penalty_bps: 0.0,
+        })
+    }
+
+    /// 스프레드 비용 포함 여부 설정
+    ///
+    /// 포함하면 중간가 대비, 제외하면 최우선 호가 대비 평균 체결가로 가격 충격을
+    /// 계산합니다.
+    pub fn with_spread(mut self, include_spread: bool) -> Self {
+        self.include_spread = include_spread;
+        self
+    }
+
+    /// 호가 잔량 초과분 페널티 설정
+    ///
+    /// # Arguments
+    /// * `depth_penalty_bps` - 가장 불리한 호가 대비 추가 불리 가격 (bps, 0 이상)
+    ///
+    /// # Returns
+    /// * `Result<OrderBookSlippageModel, String>` - 설정된 모델 또는 에러
+    pub fn with_depth_penalty(
+        mut self,
+        depth_penalty_bps: f64,
+    ) -> Result<OrderBookSlippageModel, String> {
+        validate_bps("depth_penalty_bps", depth_penalty_bps)?;
+        self.depth_penalty_bps = depth_penalty_bps;
+        Ok(self)
+    }
+
+    /// 체결 시각에 사용할 호가창 스냅샷
+    ///
+    /// 체결 시각 이전(같은 시각 포함)의 스냅샷이 없으면 None을 반환합니다.
+    fn snapshot_at(&self, datetime: DateTime<Utc>) -> Option<&OrderBook> {
+        let millis = datetime.timestamp_millis();
+        let index = self
+            .snapshots
+            .partition_point(|orderbook| orderbook.timestamp <= millis);
+        index.checked_sub(1).map(|index| &self.snapshots[index])
+    }
+
+    /// 주문 금액에 대한 가격 충격 비율
+    ///
+    /// # Arguments
+    /// * `request` - 체결 요청 정보
+    ///
+    /// # Returns
+    /// * `f64` - 기준 가격 대비 불리한 방향의 가격 충격 비율
+    ///   (체결 시각 이전 스냅샷이 없으면 0)
+    pub fn impact_rate(&self, request: &FillRequest) -> f64 {
+        let Some(orderbook) = self.snapshot_at(request.datetime) else {
+            return 0.0;
+        };
+        let is_buy = request.side.is_buy();
+        let levels = if is_buy {
+            &orderbook.asks
+        } else {
+            &orderbook.bids
+        };
+
+        let reference_price = if self.include_spread {
+            orderbook.mid_price()
+        } else if is_buy {
+            orderbook.best_ask()
+        } else {
+            orderbook.best_bid()
+        };
+        let Some(reference_price) = reference_price.filter(|price| *price > 0.0) else {
+            return 0.0;
+        };
+        if request.notional <= 0.0 {
+            return 0.0;
+        }
+
+        let remaining = self
+            .analyzer
+            .unfilled_size(orderbook, request.notional, is_buy);
+        let filled = request.notional - remaining;
+        let mut total_quantity = 0.0;
+        if filled > 0.0 {
+            let vwap = self.analyzer.calculate_vwap(orderbook, filled, is_buy);
+            if vwap > 0.0 {
+                total_quantity += filled / vwap;
+            }
+        }
+
+        // 호가 잔량을 넘는 금액은 가장 불리한 호가에 페널티를 더한 가격으로 체결
+        if remaining > 0.0 {
+            let worst_price = levels
+                .iter()
+                .take_while(|level| level.price > 0.0 && level.quantity > 0.0)
+                .last()
+                .map(|level| level.price)
+                .unwrap_or(reference_price);
+            let penalty_price = request
+                .side
+                .adverse(worst_price, self.depth_penalty_bps / BPS)
+                .max(0.0);
+
+            total_quantity += remaining / penalty_price;
+        }
+
+        if total_quantity <= 0.0 {
+            return 0.0;
+        }
+
+        let avg_price = request.notional / total_quantity;
+        let rate = if is_buy {
+            (avg_price - reference_price) / reference_price
+        } else {
+            (reference_price - avg_price) / reference_price
+        };
+
+        if rate.is_finite() { rate.max(0.0) } else { 0.0 }
+    }
+}
+
+impl ExecutionModel for OrderBookSlippageModel {
+    fn fill_price(&self, request: &FillRequest) -> f64 {
+        request
+            .side
+            .adverse(request.price, self.impact_rate(request))
+    }
+}
+
+/// 여러 체결 모델을 순서대로 적용하는 모델
+///
+/// 체결 가격은 각 모델을 차례로 통과시켜 계산하고, 수수료는 모든 모델의 합입니다.
+#[derive(Debug, Default)]
+pub struct CombinedExecutionModel {
+    models: Vec<Box<dyn ExecutionModel>>,
+}
+
+impl CombinedExecutionModel {
+    /// 빈 결합 모델 생성
+    pub fn new() -> CombinedExecutionModel {
+        CombinedExecutionModel::default()
+    }
+
+    /// 모델 추가
+    pub fn with(mut self, model: impl ExecutionModel + 'static) -> Self {
+        self.models.push(Box::new(model));
+        self
+    }
+}
+
+impl ExecutionModel for CombinedExecutionModel {
+    fn fill_price(&self, request: &FillRequest) -> f64 {
+        self.models.iter().fold(request.price, |price, model| {
+            model.fill_price(&FillRequest { price, ..*request })
+        })
+    }
+
+    fn fee(&self, request: &FillRequest, fill_notional: f64) -> f64 {
+        self.models
+            .iter()
+            .map(|model| model.fee(request, fill_notional))
+            .sum()
+    }
+}
+
+/// bps 값이 0 이상의 유한한 숫자인지 검사
+fn validate_bps(name: &str, value: f64) -> Result<(), String> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(format!("{name}는 0 이상의 유한한 숫자여야 합니다"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(side: OrderSide, price: f64, notional: f64) -> FillRequest {
+        FillRequest {
+            side,
+            price,
+            notional,
+            datetime: DateTime::from_timestamp(0, 0).unwrap(),
+            cumulative_notional: 0.0,
+        }
+    }
+
+    fn orderbook() -> OrderBook {
+        OrderBook::from_tuples(
+            "TEST".to_string(),
+            vec![(99.0, 10.0), (98.0, 10.0)],
+            vec![(101.0, 10.0), (102.0, 10.0)],
+            0,
+        )
+    }
+
+    #[test]
+    fn test_fixed_fee_and_slippage() {
+        let fee = FixedFeeModel::new(10.0).unwrap();
+        let slippage = FixedSlippageModel::new(50.0).unwrap();
+
+        assert!((fee.fee(&request(OrderSide::Buy, 100.0, 1000.0), 1000.0) - 1.0).abs() < 1e-9);
+        assert!(
+            (slippage.fill_price(&request(OrderSide::Buy, 100.0, 1000.0)) - 100.5).abs() < 1e-9
+        );
+        assert!(
+            (slippage.fill_price(&request(OrderSide::Sell, 100.0, 1000.0)) - 99.5).abs() < 1e-9
+        );
+        assert!(FixedFeeModel::new(-1.0).is_err());
+    }
+
+    #[test]
+    fn test_maker_taker_tiers() {
+        let model = MakerTakerFeeModel::with_tiers(
+            vec![
+                FeeTier {
+                    min_volume: 1_000_000.0,
+                    maker_bps: -1.0,
+                    taker_bps: 4.0,
+                },
+                FeeTier {
+                    min_volume: 0.0,
+                    maker_bps: 2.0,
+                    taker_bps: 5.0,
+                },
+            ],
+            LiquidityRole::Taker,
+        )
+        .unwrap();
+
+        let mut req = request(OrderSide::Buy, 100.0, 10_000.0);
+        assert!((model.fee(&req, 10_000.0) - 5.0).abs() < 1e-9);
+
+        req.cumulative_notional = 2_000_000.0;
+        assert!((model.fee(&req, 10_000.0) - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_orderbook_slippage() {
+        let model = OrderBookSlippageModel::new(OrderBookAnalyzer::default(), vec![orderbook()])
+            .unwrap()
+            .with_spread(false);
+
+        // 최우선 호가 안에서 체결되면 슬리피지 없음
+        let small = request(OrderSide::Buy, 200.0, 500.0);
+        assert!((model.fill_price(&small) - 200.0).abs() < 1e-9);
+
+        // 호가 두 단계를 소비하면 불리한 방향으로 가격 이동
+        let large = request(OrderSide::Buy, 200.0, 2000.0);
+        assert!(model.fill_price(&large) > 200.0);
+        let sell = request(OrderSide::Sell, 200.0, 2000.0);
+        assert!(model.fill_price(&sell) < 200.0);
+
+        // 스프레드 포함 시 중간가 대비 반 스프레드만큼 추가 비용
+        let with_spread = model.clone().with_spread(true);
+        assert!((with_spread.fill_price(&small) - 202.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_orderbook_slippage_ignores_future_snapshot() {
+        let mut future = orderbook();
+        future.timestamp = 60_000;
+        let model = OrderBookSlippageModel::new(OrderBookAnalyzer::default(), vec![future])
+            .unwrap()
+            .with_spread(true);
+
+        // 체결 시각 이전 스냅샷이 없으면 가격 충격 없음
+        let early = request(OrderSide::Buy, 200.0, 2000.0);
+        assert_eq!(model.impact_rate(&early), 0.0);
+        assert!((model.fill_price(&early) - 200.0).abs() < 1e-9);
+
+        let mut late = early;
+        late.datetime = DateTime::from_timestamp(60, 0).unwrap();
+        assert!(model.impact_rate(&late) > 0.0);
+    }
+
+    #[test]
+    fn test_orderbook_slippage_penalizes_depth_overflow() {
+        let model = OrderBookSlippageModel::new(OrderBookAnalyzer::default(), vec![orderbook()])
+            .unwrap()
+            .with_spread(false);
+        let penalized = model.clone().with_depth_penalty(100.0).unwrap();
+
+        // 매수 호가 잔량은 1010 + 1020 = 2030
+        let full_depth = request(OrderSide::Buy, 100.0, 2030.0);
+        let overflow = request(OrderSide::Buy, 100.0, 4060.0);
+        assert!(model.impact_rate(&overflow) > model.impact_rate(&full_depth));
+
+        // 초과분 2030은 102 * 1.01에 체결
+        let quantity = 10.0 + 10.0 + 2030.0 / (102.0 * 1.01);
+        let expected = (4060.0 / quantity - 101.0) / 101.0;
+        assert!((penalized.impact_rate(&overflow) - expected).abs() < 1e-9);
+        assert!(penalized.impact_rate(&overflow) > model.impact_rate(&overflow));
+
+        assert!(model.clone().with_depth_penalty(-1.0).is_err());
+    }
+
+    #[test]
+    fn test_combined_model() {
+        let model = CombinedExecutionModel::new()
+            .with(FixedSlippageModel::new(100.0).unwrap())
+            .with(FixedFeeModel::new(10.0).unwrap());
+
+        let req = request(OrderSide::Buy, 100.0, 1000.0);
+        assert!((model.fill_price(&req) - 101.0).abs() < 1e-9);
+        assert!((model.fee(&req, 1000.0) - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,805 @@
+// 이벤트 기반 백테스트 모듈
+// Strategy 트레이트 구현체에 과거 캔들을 순서대로 재생하며 진입/청산 신호를 체결합니다.
+//
+// # 주요 기능
+// - StrategyFactory로 생성한 모든 전략을 동일한 재생 루프로 평가
+// - 롱/숏 포지션을 Strategy::position() 기준으로 처리
+// - 미래 참조를 막기 위해 기본적으로 신호 다음 캔들 시가에 체결
+// - 진입/청산 시각, 가격, 손익이 포함된 거래 로그와 자산 곡선 생성
+// - 실제 거래 기반 성과 보고서 (Sharpe, Sortino, Calmar, 최대 낙폭 등)
+// - 수수료, 슬리피지, 스프레드를 반영하는 교체 가능한 체결 모델
+// - 전략 설정 파라미터 그리드 탐색 및 지표 기준 순위 산출
+// - 인샘플/아웃오브샘플 워크포워드 검증과 효율 비율
+
+pub mod error;
+pub mod execution;
+pub mod optimizer;
+pub mod report;
+pub mod walk_forward;
+
+use crate::candle_store::CandleStore;
+use crate::model::PositionType;
+use crate::strategy::{Strategy, StrategyFactory, StrategyType};
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use chrono::{DateTime, Utc};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use trading_chart::Candle;
+
+pub use error::BacktestError;
+pub use execution::{
+    CombinedExecutionModel, ExecutionModel, FeeTier, FillRequest, FixedFeeModel,
+    FixedSlippageModel, LiquidityRole, MakerTakerFeeModel, NoCostModel, OrderBookSlippageModel,
+    OrderSide,
+};
+pub use optimizer::{
+    GridSearchOptimizer, OptimizationCandidate, OptimizationMetric, OptimizationResult,
+    ParameterGrid, SkippedCombination,
+};
+pub use report::PerformanceReport;
+pub use walk_forward::{
+    WalkForwardAnalyzer, WalkForwardConfig, WalkForwardFailure, WalkForwardResult,
+    WalkForwardWindow,
+};
+
+/// 신호 발생 후 체결 시점
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillTiming {
+    /// 신호가 발생한 캔들의 다음 캔들 시가에 체결 (미래 참조 없음)
+    #[default]
+    NextOpen,
+    /// 신호가 발생한 캔들의 종가에 체결
+    CurrentClose,
+}
+
+/// 백테스트 설정
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct BacktestConfig {
+    /// 초기 자본
+    pub initial_capital: f64,
+    /// 진입 시 사용할 자본 비율 (0 초과 1 이하)
+    pub position_size_ratio: f64,
+    /// 체결 시점
+    pub fill_timing: FillTiming,
+    /// 데이터 종료 시 보유 포지션을 마지막 종가로 청산할지 여부
+    ///
+    /// `FillTiming::NextOpen`에서 마지막 캔들에 발생한 청산 신호도 마지막 종가로
+    /// 체결합니다. 마지막 캔들의 진입 신호는 체결하지 않고 결과의 `unfilled_order`로 남깁니다.
+    pub close_at_end: bool,
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        BacktestConfig {
+            initial_capital: 10_000.0,
+            position_size_ratio: 1.0,
+            fill_timing: FillTiming::NextOpen,
+            close_at_end: true,
+        }
+    }
+}
+
+impl ConfigValidation for BacktestConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if !self.initial_capital.is_finite() || self.initial_capital <= 0.0 {
+            return Err(ConfigError::ValidationError(
+                "초기 자본은 0보다 큰 유한한 숫자여야 합니다".to_string(),
+            ));
+        }
+
+        if !self.position_size_ratio.is_finite()
+            || self.position_size_ratio <= 0.0
+            || self.position_size_ratio > 1.0
+        {
+            return Err(ConfigError::ValidationError(
+                "position_size_ratio는 0 초과 1 이하여야 합니다".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// 포지션 청산 사유
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitReason {
+    /// 전략의 청산 신호
+    Signal,
+    /// 데이터 종료로 인한 강제 청산
+    EndOfData,
+}
+
+/// 청산이 완료된 거래 기록
+#[derive(Serialize, Debug, Clone)]
+pub struct Trade {
+    /// 포지션 방향
+    pub position: PositionType,
+    /// 진입 시각
+    pub entry_time: DateTime<Utc>,
+    /// 진입 가격
+    pub entry_price: f64,
+    /// 청산 시각
+    pub exit_time: DateTime<Utc>,
+    /// 청산 가격
+    pub exit_price: f64,
+    /// 거래 수량
+    pub quantity: f64,
+    /// 진입과 청산에 지불한 수수료 합계
+    pub fees: f64,
+    /// 수수료를 차감한 실현 손익
+    pub pnl: f64,
+    /// 진입 금액 대비 수익률
+    pub return_rate: f64,
+    /// 청산 사유
+    pub exit_reason: ExitReason,
+}
+
+impl Trade {
+    /// 수익 거래인지 확인
+    pub fn is_win(&self) -> bool {
+        self.pnl > 0.0
+    }
+
+    /// 포지션 보유 시간
+    pub fn holding_duration(&self) -> chrono::Duration {
+        self.exit_time - self.entry_time
+    }
+}
+
+/// 보유 중인 포지션
+#[derive(Serialize, Debug, Clone)]
+pub struct OpenPosition {
+    /// 포지션 방향
+    pub position: PositionType,
+    /// 진입 시각
+    pub entry_time: DateTime<Utc>,
+    /// 진입 가격
+    pub entry_price: f64,
+    /// 거래 수량
+    pub quantity: f64,
+    /// 진입 시 지불한 수수료 (진입 시점에 자본에서 차감됨)
+    pub entry_fee: f64,
+}
+
+impl OpenPosition {
+    /// 주어진 가격 기준 미실현 손익 (수수료 제외)
+    ///
+    /// # Arguments
+    /// * `price` - 평가 가격
+    ///
+    /// # Returns
+    /// * `f64` - 미실현 손익
+    pub fn unrealized_pnl(&self, price: f64) -> f64 {
+        match self.position {
+            PositionType::Long => (price - self.entry_price) * self.quantity,
+            PositionType::Short => (self.entry_price - price) * self.quantity,
+        }
+    }
+}
+
+/// 자산 곡선의 한 시점
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct EquityPoint {
+    /// 캔들 시각
+    pub datetime: DateTime<Utc>,
+    /// 해당 캔들 종가 기준 평가 자산
+    pub equity: f64,
+}
+
+/// 백테스트 결과
+#[derive(Serialize, Debug, Clone)]
+pub struct BacktestResult {
+    /// 전략 타입
+    pub strategy_type: StrategyType,
+    /// 초기 자본
+    pub initial_capital: f64,
+    /// 최종 자본 (청산되지 않은 포지션의 손익은 제외)
+    pub final_capital: f64,
+    /// 청산된 거래 목록
+    pub trades: Vec<Trade>,
+    /// 캔들별 평가 자산
+    pub equity_curve: Vec<EquityPoint>,
+    /// 종료 시점에 보유 중인 포지션
+    pub open_position: Option<OpenPosition>,
+    /// 체결할 다음 캔들이 없어 체결되지 않은 마지막 캔들의 신호
+    pub unfilled_order: Option<PendingOrder>,
+}
+
+impl BacktestResult {
+    /// 초기 자본 대비 총 수익률
+    pub fn total_return(&self) -> f64 {
+        if self.initial_capital == 0.0 {
+            return 0.0;
+        }
+        (self.final_capital - self.initial_capital) / self.initial_capital
+    }
+}
+
+/// 다음 캔들에서 체결을 기다리는 주문
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingOrder {
+    /// 포지션 진입
+    Enter,
+    /// 포지션 청산
+    Exit,
+}
+
+/// 이벤트 기반 백테스트 엔진
+///
+/// 캔들을 시간 오름차순으로 `Strategy::next`에 전달하고, 각 캔들 종가 기준으로
+/// `should_enter`/`should_exit`를 평가하여 포지션을 열고 닫습니다.
+#[derive(Debug, Clone)]
+pub struct BacktestEngine {
+    config: BacktestConfig,
+    execution_model: Arc<dyn ExecutionModel>,
+}
+
+impl Default for BacktestEngine {
+    fn default() -> Self {
+        BacktestEngine {
+            config: BacktestConfig::default(),
+            execution_model: Arc::new(NoCostModel),
+        }
+    }
+}
+
+impl BacktestEngine {
+    /// 새 백테스트 엔진 생성
+    ///
+    /// # Arguments
+    /// * `config` - 백테스트 설정
+    ///
+    /// # Returns
+    /// * `Result<BacktestEngine, BacktestError>` - 생성된 엔진 또는 설정 에러
+    pub fn new(config: BacktestConfig) -> Result<BacktestEngine, BacktestError> {
+        config.validate()?;
+        Ok(BacktestEngine {
+            config,
+            ..BacktestEngine::default()
+        })
+    }
+
+    /// 체결 비용 모델 설정
+    ///
+    /// 기본값은 비용이 없는 `NoCostModel`입니다.
+    ///
+    /// # Arguments
+    /// * `model` - 수수료/슬리피지 모델
+    pub fn with_execution_model(mut self, model: impl ExecutionModel + 'static) -> Self {
+        self.execution_model = Arc::new(model);
+        self
+    }
+
+    /// 백테스트 설정 참조 반환
+    pub fn config(&self) -> &BacktestConfig {
+        &self.config
+    }
+
+    /// 체결 비용 모델 참조 반환
+    pub fn execution_model(&self) -> &dyn ExecutionModel {
+        self.execution_model.as_ref()
+    }
+
+    /// 전략 유형과 설정으로 전략을 생성한 뒤 백테스트 실행
+    ///
+    /// 앞쪽 `warmup`개의 캔들은 전략 초기화용 저장소로 사용되고, 나머지 캔들만 재생됩니다.
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 생성할 전략 유형
+    /// * `config` - 선택적 전략 설정 (HashMap 형태)
+    /// * `candles` - 과거 캔들 데이터
+    /// * `warmup` - 지표 초기화에 사용할 캔들 수
+    ///
+    /// # Returns
+    /// * `Result<BacktestResult, BacktestError>` - 백테스트 결과 또는 전략 생성 에러
+    pub fn run_strategy<C: Candle + 'static>(
+        &self,
+        strategy_type: StrategyType,
+        config: Option<HashMap<String, String>>,
+        candles: &[C],
+        warmup: usize,
+    ) -> Result<BacktestResult, BacktestError> {
+        let mut ascending = candles.to_vec();
+        ascending.sort_by_key(|candle| candle.datetime());
+
+        let warmup = warmup.min(ascending.len());
+        let replay = ascending.split_off(warmup);
+        let storage = CandleStore::new(ascending, warmup.max(1), false);
+
+        let mut strategy = StrategyFactory::build(strategy_type, &storage, config)?;
+        Ok(self.run(strategy.as_mut(), &replay))
+    }
+
+    /// 전략에 캔들을 재생하여 백테스트 실행
+    ///
+    /// 입력 캔들은 시간 순서와 관계없이 전달할 수 있으며 내부에서 오름차순으로 정렬됩니다.
+    /// `FillTiming::NextOpen`에서 마지막 캔들에 발생한 신호는 체결할 다음 캔들이 없으므로
+    /// `BacktestResult::unfilled_order`로 남깁니다. 단, `close_at_end`이면 청산 신호는
+    /// 마지막 종가로 체결합니다.
+    ///
+    /// # Arguments
+    /// * `strategy` - 평가할 전략
+    /// * `candles` - 재생할 캔들 데이터
+    ///
+    /// # Returns
+    /// * `BacktestResult` - 거래 로그와 자산 곡선
+    pub fn run<C: Candle>(&self, strategy: &mut dyn Strategy<C>, candles: &[C]) -> BacktestResult {
+        let mut ordered: Vec<&C> = candles.iter().collect();
+        ordered.sort_by_key(|candle| candle.datetime());
+
+        let position_type = strategy.position();
+        let strategy_type = strategy.name();
+        info!("백테스트 시작: {strategy_type} ({}개 캔들)", ordered.len());
+
+        let mut state =
+            BacktestState::new(self.config.initial_capital, self.execution_model.as_ref());
+        let mut pending: Option<PendingOrder> = None;
+        let mut equity_curve = Vec::with_capacity(ordered.len());
+
+        for candle in ordered.iter().copied() {
+            // 이전 캔들에서 발생한 신호는 현재 캔들 시가에 체결
+            if let Some(order) = pending.take() {
+                state.execute(
+                    order,
+                    position_type,
+                    candle.open_price(),
+                    candle.datetime(),
+                    &self.config,
+                );
+            }
+
+            strategy.next(candle.clone());
+
+            let current_price = candle.close_price();
+            let signal = if state.open_position.is_none() {
+                strategy
+                    .should_enter(current_price)
+                    .then_some(PendingOrder::Enter)
+            } else {
+                strategy
+                    .should_exit(current_price)
+                    .then_some(PendingOrder::Exit)
+            };
+
+            if let Some(order) = signal {
+                debug!(
+                    "{} 신호 발생: {order:?} @ {current_price}",
+                    candle.datetime()
+                );
+                match self.config.fill_timing {
+                    FillTiming::NextOpen => pending = Some(order),
+                    FillTiming::CurrentClose => state.execute(
+                        order,
+                        position_type,
+                        current_price,
+                        candle.datetime(),
+                        &self.config,
+                    ),
+                }
+            }
+
+            equity_curve.push(EquityPoint {
+                datetime: candle.datetime(),
+                equity: state.equity(current_price),
+            });
+        }
+
+        if self.config.close_at_end
+            && let Some(last) = ordered.last()
+        {
+            // 마지막 캔들의 청산 신호는 다음 시가 대신 마지막 종가로 체결
+            if pending == Some(PendingOrder::Exit) {
+                pending = None;
+                state.close(last.close_price(), last.datetime(), ExitReason::Signal);
+            }
+            state.close(last.close_price(), last.datetime(), ExitReason::EndOfData);
+
+            if let Some(point) = equity_curve.last_mut() {
+                point.equity = state.capital;
+            }
+        }
+
+        info!(
+            "백테스트 종료: {strategy_type} - 거래 {}회, 최종 자본 {:.2}",
+            state.trades.len(),
+            state.capital
+        );
+
+        BacktestResult {
+            strategy_type,
+            initial_capital: self.config.initial_capital,
+            final_capital: state.capital,
+            trades: state.trades,
+            equity_curve,
+            open_position: state.open_position,
+            unfilled_order: pending,
+        }
+    }
+}
+
+/// 재생 중 자본과 포지션 상태
+#[derive(Debug)]
+struct BacktestState<'a> {
+    capital: f64,
+    open_position: Option<OpenPosition>,
+    trades: Vec<Trade>,
+    execution_model: &'a dyn ExecutionModel,
+    /// 수수료 구간 계산용 누적 거래대금
+    traded_notional: f64,
+}
+
+impl<'a> BacktestState<'a> {
+    fn new(capital: f64, execution_model: &'a dyn ExecutionModel) -> BacktestState<'a> {
+        BacktestState {
+            capital,
+            open_position: None,
+            trades: Vec::new(),
+            execution_model,
+            traded_notional: 0.0,
+        }
+    }
+
+    /// 주어진 가격 기준 평가 자산
+    fn equity(&self, price: f64) -> f64 {
+        let unrealized = self
+            .open_position
+            .as_ref()
+            .map(|open| open.unrealized_pnl(price))
+            .unwrap_or(0.0);
+        self.capital + unrealized
+    }
+
+    /// 주문을 주어진 가격으로 체결
+    fn execute(
+        &mut self,
+        order: PendingOrder,
+        position_type: PositionType,
+        price: f64,
+        datetime: DateTime<Utc>,
+        config: &BacktestConfig,
+    ) {
+        if !price.is_finite() || price <= 0.0 {
+            debug!("유효하지 않은 체결 가격으로 주문 무시: {price}");
+            return;
+        }
+
+        match order {
+            PendingOrder::Enter => {
+                if self.open_position.is_some() || self.capital <= 0.0 {
+                    return;
+                }
+
+                let notional = self.capital * config.position_size_ratio;
+                let side = match position_type {
+                    PositionType::Long => OrderSide::Buy,
+                    PositionType::Short => OrderSide::Sell,
+                };
+                let Some((request, fill_price)) = self.fill(side, price, notional, datetime) else {
+                    return;
+                };
+
+                // 진입 금액을 체결 가격으로 나누어 수량 결정
+                let quantity = notional / fill_price;
+                let fee = self.charge(&request, notional);
+                self.capital -= fee;
+                self.open_position = Some(OpenPosition {
+                    position: position_type,
+                    entry_time: datetime,
+                    entry_price: fill_price,
+                    quantity,
+                    entry_fee: fee,
+                });
+            }
+            PendingOrder::Exit => self.close(price, datetime, ExitReason::Signal),
+        }
+    }
+
+    /// 보유 포지션이 있으면 청산하고 거래 기록 추가
+    fn close(&mut self, price: f64, datetime: DateTime<Utc>, exit_reason: ExitReason) {
+        let Some(open) = self.open_position.as_ref() else {
+            return;
+        };
+
+        let side = match open.position {
+            PositionType::Long => OrderSide::Sell,
+            PositionType::Short => OrderSide::Buy,
+        };
+        let quantity = open.quantity;
+        let Some((request, fill_price)) = self.fill(side, price, quantity * price, datetime) else {
+            return;
+        };
+        let exit_fee = self.charge(&request, quantity * fill_price);
+
+        if let Some(open) = self.open_position.take() {
+            let entry_fee = open.entry_fee;
+            let trade = close_position(open, fill_price, datetime, exit_reason, exit_fee);
+            // 진입 수수료는 진입 시점에 이미 차감됨
+            self.capital += trade.pnl + entry_fee;
+            self.trades.push(trade);
+        }
+    }
+
+    /// 체결 모델을 적용하여 체결 요청과 체결 가격 계산
+    ///
+    /// 체결 가격이 유효하지 않으면 `None`을 반환합니다.
+    fn fill(
+        &self,
+        side: OrderSide,
+        price: f64,
+        notional: f64,
+        datetime: DateTime<Utc>,
+    ) -> Option<(FillRequest, f64)> {
+        let request = FillRequest {
+            side,
+            price,
+            notional,
+            datetime,
+            cumulative_notional: self.traded_notional,
+        };
+
+        let fill_price = self.execution_model.fill_price(&request);
+        if !fill_price.is_finite() || fill_price <= 0.0 {
+            debug!("체결 모델이 유효하지 않은 가격을 반환하여 주문 무시: {fill_price}");
+            return None;
+        }
+
+        Some((request, fill_price))
+    }
+
+    /// 체결 금액에 대한 수수료를 계산하고 누적 거래대금 갱신
+    fn charge(&mut self, request: &FillRequest, fill_notional: f64) -> f64 {
+        let fee = self.execution_model.fee(request, fill_notional);
+        self.traded_notional += fill_notional.abs();
+        fee
+    }
+}
+
+/// 보유 포지션을 주어진 가격으로 청산하여 거래 기록 생성
+fn close_position(
+    open: OpenPosition,
+    price: f64,
+    datetime: DateTime<Utc>,
+    exit_reason: ExitReason,
+    exit_fee: f64,
+) -> Trade {
+    let fees = open.entry_fee + exit_fee;
+    let pnl = open.unrealized_pnl(price) - fees;
+    let notional = open.entry_price * open.quantity;
+    let return_rate = if notional == 0.0 { 0.0 } else { pnl / notional };
+
+    Trade {
+        position: open.position,
+        entry_time: open.entry_time,
+        entry_price: open.entry_price,
+        exit_time: datetime,
+        exit_price: price,
+        quantity: open.quantity,
+        fees,
+        pnl,
+        return_rate,
+        exit_reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+    use std::fmt::Display;
+
+    /// 종가가 진입 기준 이상이면 진입하고 청산 기준 이하이면 청산하는 테스트 전략
+    struct ThresholdStrategy {
+        position: PositionType,
+        enter_at: f64,
+        exit_at: f64,
+    }
+
+    impl Display for ThresholdStrategy {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ThresholdStrategy")
+        }
+    }
+
+    impl Strategy<TestCandle> for ThresholdStrategy {
+        fn next(&mut self, _candle: TestCandle) {}
+
+        fn should_enter(&self, current_price: f64) -> bool {
+            match self.position {
+                PositionType::Long => current_price >= self.enter_at,
+                PositionType::Short => current_price <= self.enter_at,
+            }
+        }
+
+        fn should_exit(&self, current_price: f64) -> bool {
+            match self.position {
+                PositionType::Long => current_price <= self.exit_at,
+                PositionType::Short => current_price >= self.exit_at,
+            }
+        }
+
+        fn position(&self) -> PositionType {
+            self.position
+        }
+
+        fn name(&self) -> StrategyType {
+            StrategyType::Dummy
+        }
+    }
+
+    fn candle(timestamp: i64, open: f64, close: f64) -> TestCandle {
+        TestCandle {
+            timestamp,
+            open,
+            high: open.max(close),
+            low: open.min(close),
+            close,
+            volume: 1000.0,
+        }
+    }
+
+    fn candles() -> Vec<TestCandle> {
+        vec![
+            candle(1, 100.0, 100.0),
+            candle(2, 100.0, 110.0),
+            candle(3, 112.0, 120.0),
+            candle(4, 118.0, 90.0),
+            candle(5, 95.0, 95.0),
+        ]
+    }
+
+    #[test]
+    fn test_next_open_fill_avoids_look_ahead() {
+        let engine = BacktestEngine::default();
+        let mut strategy = ThresholdStrategy {
+            position: PositionType::Long,
+            enter_at: 110.0,
+            exit_at: 90.0,
+        };
+
+        let result = engine.run(&mut strategy, &candles());
+
+        assert_eq!(result.trades.len(), 1);
+        let trade = &result.trades[0];
+        // 2번 캔들 종가에서 진입 신호 → 3번 캔들 시가에 체결
+        assert_eq!(trade.entry_price, 112.0);
+        assert_eq!(trade.entry_time.timestamp(), 3);
+        // 4번 캔들 종가에서 청산 신호 → 5번 캔들 시가에 체결
+        assert_eq!(trade.exit_price, 95.0);
+        assert_eq!(trade.exit_time.timestamp(), 5);
+        assert_eq!(trade.exit_reason, ExitReason::Signal);
+        assert!(trade.pnl < 0.0);
+        assert_eq!(result.equity_curve.len(), 5);
+    }
+
+    #[test]
+    fn test_current_close_fill_and_short_pnl() {
+        let engine = BacktestEngine::new(BacktestConfig {
+            fill_timing: FillTiming::CurrentClose,
+            ..BacktestConfig::default()
+        })
+        .unwrap();
+        let mut strategy = ThresholdStrategy {
+            position: PositionType::Short,
+            enter_at: 100.0,
+            exit_at: 120.0,
+        };
+
+        let result = engine.run(&mut strategy, &candles());
+
+        assert_eq!(result.trades[0].entry_price, 100.0);
+        assert_eq!(result.trades[0].exit_price, 120.0);
+        assert_eq!(result.trades[0].position, PositionType::Short);
+        assert!(result.trades[0].pnl < 0.0);
+    }
+
+    #[test]
+    fn test_open_position_closed_at_end_of_data() {
+        let engine = BacktestEngine::default();
+        let mut strategy = ThresholdStrategy {
+            position: PositionType::Long,
+            enter_at: 100.0,
+            exit_at: 0.0,
+        };
+
+        let result = engine.run(&mut strategy, &candles());
+
+        assert!(result.open_position.is_none());
+        let trade = result.trades.last().unwrap();
+        assert_eq!(trade.exit_reason, ExitReason::EndOfData);
+        assert_eq!(trade.exit_price, 95.0);
+        assert_eq!(
+            result.equity_curve.last().unwrap().equity,
+            result.final_capital
+        );
+    }
+
+    #[test]
+    fn test_next_open_signal_on_last_candle() {
+        let candles = vec![
+            candle(1, 100.0, 100.0),
+            candle(2, 100.0, 110.0),
+            candle(3, 112.0, 120.0),
+            candle(4, 118.0, 115.0),
+            candle(5, 114.0, 90.0),
+        ];
+        let strategy = || ThresholdStrategy {
+            position: PositionType::Long,
+            enter_at: 110.0,
+            exit_at: 90.0,
+        };
+
+        // 마지막 캔들의 청산 신호는 마지막 종가로 체결
+        let result = BacktestEngine::default().run(&mut strategy(), &candles);
+        let trade = result.trades.last().unwrap();
+        assert_eq!(trade.exit_price, 90.0);
+        assert_eq!(trade.exit_time.timestamp(), 5);
+        assert_eq!(trade.exit_reason, ExitReason::Signal);
+        assert!(result.unfilled_order.is_none());
+
+        // 종료 시 청산하지 않으면 체결되지 않은 신호로 남김
+        let engine = BacktestEngine::new(BacktestConfig {
+            close_at_end: false,
+            ..BacktestConfig::default()
+        })
+        .unwrap();
+        let result = engine.run(&mut strategy(), &candles);
+        assert!(result.trades.is_empty());
+        assert!(result.open_position.is_some());
+        assert_eq!(result.unfilled_order, Some(PendingOrder::Exit));
+
+        // 마지막 캔들의 진입 신호는 체결하지 않음
+        let result = BacktestEngine::default().run(&mut strategy(), &candles[..2]);
+        assert!(result.trades.is_empty());
+        assert!(result.open_position.is_none());
+        assert_eq!(result.unfilled_order, Some(PendingOrder::Enter));
+    }
+
+    #[test]
+    fn test_execution_model_applies_fees_and_slippage() {
+        let engine = BacktestEngine::new(BacktestConfig {
+            fill_timing: FillTiming::CurrentClose,
+            ..BacktestConfig::default()
+        })
+        .unwrap()
+        .with_execution_model(
+            CombinedExecutionModel::new()
+                .with(FixedSlippageModel::new(100.0).unwrap())
+                .with(FixedFeeModel::new(10.0).unwrap()),
+        );
+        let mut strategy = ThresholdStrategy {
+            position: PositionType::Long,
+            enter_at: 110.0,
+            exit_at: 90.0,
+        };
+
+        let result = engine.run(&mut strategy, &candles());
+        let trade = &result.trades[0];
+
+        // 매수는 1% 높게, 매도는 1% 낮게 체결
+        assert!((trade.entry_price - 111.1).abs() < 1e-9);
+        assert!((trade.exit_price - 89.1).abs() < 1e-9);
+
+        let entry_fee = 10_000.0 * 0.001;
+        let exit_fee = trade.quantity * trade.exit_price * 0.001;
+        assert!((trade.fees - (entry_fee + exit_fee)).abs() < 1e-9);
+
+        let gross = (trade.exit_price - trade.entry_price) * trade.quantity;
+        assert!((trade.pnl - (gross - trade.fees)).abs() < 1e-9);
+        assert!((result.final_capital - (10_000.0 + trade.pnl)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_backtest_config_validation() {
+        let config = BacktestConfig {
+            position_size_ratio: 1.5,
+            ..BacktestConfig::default()
+        };
+        assert!(matches!(
+            BacktestEngine::new(config),
+            Err(BacktestError::Config(_))
+        ));
+    }
+}
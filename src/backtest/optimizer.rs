@@ -0,0 +1,477 @@
+// 전략 파라미터 그리드 탐색
+// new_with_config가 받는 HashMap 설정 키의 후보 값 조합을 모두 백테스트하고
+// 선택한 성과 지표로 순위를 매깁니다.
+
+use super::{BacktestEngine, BacktestError, PerformanceReport};
+use crate::strategy::StrategyType;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 설정 키별 후보 값 목록
+///
+/// 후보 값은 `new_with_config`에 그대로 전달되는 문자열이므로
+/// `ma_periods`처럼 쉼표로 구분된 목록도 하나의 후보로 지정할 수 있습니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParameterGrid {
+    /// 모든 조합에 공통으로 적용할 고정 설정
+    base: HashMap<String, String>,
+    /// 추가 순서대로 유지되는 (키, 후보 값 목록)
+    params: Vec<(String, Vec<String>)>,
+}
+
+impl ParameterGrid {
+    /// 빈 파라미터 그리드 생성
+    pub fn new() -> ParameterGrid {
+        ParameterGrid::default()
+    }
+
+    /// 모든 조합에 공통으로 적용할 고정 설정 지정
+    ///
+    /// 같은 키가 후보 값 목록에도 있으면 후보 값이 우선합니다.
+    pub fn with_base(mut self, base: HashMap<String, String>) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// 키의 후보 값 목록 추가 (같은 키가 있으면 교체)
+    ///
+    /// # Arguments
+    /// * `key` - 설정 키 (예: `rsi_period`)
+    /// * `values` - 후보 값 목록
+    pub fn add<K, I, V>(mut self, key: K, values: I) -> Self
+    where
+        K: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: ToString,
+    {
+        let key = key.into();
+        let values: Vec<String> = values.into_iter().map(|v| v.to_string()).collect();
+
+        match self.params.iter_mut().find(|(name, _)| *name == key) {
+            Some((_, existing)) => *existing = values,
+            None => self.params.push((key, values)),
+        }
+        self
+    }
+
+    /// 정수 범위를 후보 값으로 추가
+    ///
+    /// # Arguments
+    /// * `key` - 설정 키
+    /// * `start` - 시작 값 (포함)
+    /// * `end` - 끝 값 (포함)
+    /// * `step` - 증가 폭 (0이면 1로 처리)
+    pub fn add_usize_range(
+        self,
+        key: impl Into<String>,
+        start: usize,
+        end: usize,
+        step: usize,
+    ) -> Self {
+        let values: Vec<usize> = (start..=end).step_by(step.max(1)).collect();
+        self.add(key, values)
+    }
+
+    /// 실수 범위를 후보 값으로 추가
+    ///
+    /// 부동소수점 누적 오차를 피하기 위해 `start + step * i` 형태로 계산합니다.
+    ///
+    /// # Arguments
+    /// * `key` - 설정 키
+    /// * `start` - 시작 값 (포함)
+    /// * `end` - 끝 값 (포함)
+    /// * `step` - 증가 폭 (0 이하이면 시작 값만 사용)
+    pub fn add_f64_range(self, key: impl Into<String>, start: f64, end: f64, step: f64) -> Self {
+        let mut values = Vec::new();
+        if step > 0.0 && step.is_finite() {
+            let mut index = 0;
+            loop {
+                let value = start + step * index as f64;
+                // 끝 값이 누적 오차로 빠지지 않도록 작은 허용 오차 적용
+                if value > end + step * 1e-9 {
+                    break;
+                }
+                values.push(value);
+                index += 1;
+            }
+        } else {
+            values.push(start);
+        }
+        self.add(key, values)
+    }
+
+    /// 전체 조합 수
+    pub fn combination_count(&self) -> usize {
+        self.params.iter().map(|(_, values)| values.len()).product()
+    }
+
+    /// 모든 설정 조합 생성
+    ///
+    /// 후보 값이 하나도 없는 키가 있으면 빈 목록을 반환합니다.
+    pub fn combinations(&self) -> Vec<HashMap<String, String>> {
+        let total = self.combination_count();
+        let mut result = Vec::with_capacity(total);
+        let mut indices = vec![0usize; self.params.len()];
+
+        for _ in 0..total {
+            let mut combination = self.base.clone();
+            for ((key, values), index) in self.params.iter().zip(&indices) {
+                combination.insert(key.clone(), values[*index].clone());
+            }
+            result.push(combination);
+
+            // 마지막 키부터 증가시키는 자리올림 방식
+            for (position, (_, values)) in self.params.iter().enumerate().rev() {
+                indices[position] += 1;
+                if indices[position] < values.len() {
+                    break;
+                }
+                indices[position] = 0;
+            }
+        }
+
+        result
+    }
+}
+
+/// 조합 순위를 매길 성과 지표
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OptimizationMetric {
+    /// 총 수익률
+    #[default]
+    TotalReturn,
+    /// 연평균 복리 수익률
+    Cagr,
+    /// 샤프 비율
+    SharpeRatio,
+    /// 소르티노 비율
+    SortinoRatio,
+    /// 칼마 비율
+    CalmarRatio,
+    /// 이익 팩터
+    ProfitFactor,
+    /// 거래당 평균 손익
+    Expectancy,
+    /// 승률
+    WinRate,
+    /// 최대 낙폭 (작을수록 좋음)
+    MaxDrawdown,
+}
+
+impl OptimizationMetric {
+    /// 보고서에서 지표 값 추출
+    ///
+    /// 정의되지 않은 비율(손실 없이 수익)은 가장 좋은 값인 무한대로 비교합니다.
+    pub fn value(&self, report: &PerformanceReport) -> f64 {
+        match self {
+            OptimizationMetric::TotalReturn => report.total_return,
+            OptimizationMetric::Cagr => report.cagr,
+            OptimizationMetric::SharpeRatio => report.sharpe_ratio,
+            OptimizationMetric::SortinoRatio => report.sortino_ratio.unwrap_or(f64::INFINITY),
+            OptimizationMetric::CalmarRatio => report.calmar_ratio.unwrap_or(f64::INFINITY),
+            OptimizationMetric::ProfitFactor => report.profit_factor.unwrap_or(f64::INFINITY),
+            OptimizationMetric::Expectancy => report.expectancy,
+            OptimizationMetric::WinRate => report.win_rate,
+            OptimizationMetric::MaxDrawdown => report.max_drawdown,
+        }
+    }
+
+    /// 클수록 좋은 방향으로 정규화한 점수
+    ///
+    /// NaN은 가장 낮은 점수로 처리합니다.
+    pub fn score(&self, report: &PerformanceReport) -> f64 {
+        let value = self.value(report);
+        if value.is_nan() {
+            return f64::NEG_INFINITY;
+        }
+
+        match self {
+            OptimizationMetric::MaxDrawdown => -value,
+            _ => value,
+        }
+    }
+}
+
+impl Display for OptimizationMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimizationMetric::TotalReturn => write!(f, "total_return"),
+            OptimizationMetric::Cagr => write!(f, "cagr"),
+            OptimizationMetric::SharpeRatio => write!(f, "sharpe_ratio"),
+            OptimizationMetric::SortinoRatio => write!(f, "sortino_ratio"),
+            OptimizationMetric::CalmarRatio => write!(f, "calmar_ratio"),
+            OptimizationMetric::ProfitFactor => write!(f, "profit_factor"),
+            OptimizationMetric::Expectancy => write!(f, "expectancy"),
+            OptimizationMetric::WinRate => write!(f, "win_rate"),
+            OptimizationMetric::MaxDrawdown => write!(f, "max_drawdown"),
+        }
+    }
+}
+
+/// 평가가 완료된 설정 조합
+#[derive(Serialize, Debug, Clone)]
+pub struct OptimizationCandidate {
+    /// 전략 설정
+    pub params: HashMap<String, String>,
+    /// 성과 보고서
+    pub report: PerformanceReport,
+    /// 순위 점수 (클수록 좋음)
+    pub score: f64,
+}
+
+/// 전략 생성 또는 검증에 실패하여 건너뛴 설정 조합
+#[derive(Serialize, Debug, Clone)]
+pub struct SkippedCombination {
+    /// 전략 설정
+    pub params: HashMap<String, String>,
+    /// 실패 사유
+    pub reason: BacktestError,
+}
+
+/// 그리드 탐색 결과
+#[derive(Serialize, Debug, Clone)]
+pub struct OptimizationResult {
+    /// 전략 타입
+    pub strategy_type: StrategyType,
+    /// 순위 기준 지표
+    pub metric: OptimizationMetric,
+    /// 점수 내림차순으로 정렬된 조합
+    pub ranked: Vec<OptimizationCandidate>,
+    /// 건너뛴 조합
+    pub skipped: Vec<SkippedCombination>,
+}
+
+impl OptimizationResult {
+    /// 가장 점수가 높은 조합
+    pub fn best(&self) -> Option<&OptimizationCandidate> {
+        self.ranked.first()
+    }
+}
+
+/// 파라미터 그리드 탐색기
+#[derive(Debug, Clone, Default)]
+pub struct GridSearchOptimizer {
+    engine: BacktestEngine,
+    metric: OptimizationMetric,
+    warmup: usize,
+    min_trades: usize,
+}
+
+impl GridSearchOptimizer {
+    /// 새 그리드 탐색기 생성
+    ///
+    /// # Arguments
+    /// * `engine` - 각 조합을 평가할 백테스트 엔진
+    /// * `metric` - 순위 기준 지표
+    /// * `warmup` - 지표 초기화에 사용할 캔들 수
+    pub fn new(
+        engine: BacktestEngine,
+        metric: OptimizationMetric,
+        warmup: usize,
+    ) -> GridSearchOptimizer {
+        GridSearchOptimizer {
+            engine,
+            metric,
+            warmup,
+            min_trades: 0,
+        }
+    }
+
+    /// 순위에 포함되기 위한 최소 거래 수 설정
+    ///
+    /// 거래 수가 부족한 조합은 건너뛴 조합으로 보고됩니다.
+    pub fn with_min_trades(mut self, min_trades: usize) -> Self {
+        self.min_trades = min_trades;
+        self
+    }
+
+    /// 조합 평가에 사용하는 백테스트 엔진
+    pub fn engine(&self) -> &BacktestEngine {
+        &self.engine
+    }
+
+    /// 순위 기준 지표
+    pub fn metric(&self) -> OptimizationMetric {
+        self.metric
+    }
+
+    /// 지표 초기화에 사용하는 캔들 수
+    pub fn warmup(&self) -> usize {
+        self.warmup
+    }
+
+    /// 모든 조합을 백테스트하고 순위 산출
+    ///
+    /// 전략 생성에 실패한 조합은 전체 실행을 중단하지 않고 `skipped`에 기록됩니다.
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 탐색할 전략 유형
+    /// * `grid` - 설정 후보 그리드
+    /// * `candles` - 과거 캔들 데이터
+    ///
+    /// # Returns
+    /// * `OptimizationResult` - 순위와 건너뛴 조합 목록
+    pub fn run<C: Candle + 'static>(
+        &self,
+        strategy_type: StrategyType,
+        grid: &ParameterGrid,
+        candles: &[C],
+    ) -> OptimizationResult {
+        let combinations = grid.combinations();
+        info!(
+            "그리드 탐색 시작: {strategy_type} - {}개 조합, 기준 지표 {}",
+            combinations.len(),
+            self.metric
+        );
+
+        let mut ranked = Vec::new();
+        let mut skipped = Vec::new();
+
+        for params in combinations {
+            let config = (!params.is_empty()).then(|| params.clone());
+            match self
+                .engine
+                .run_strategy(strategy_type, config, candles, self.warmup)
+            {
+                Ok(result) => {
+                    let report = result.report();
+                    if report.total_trades < self.min_trades {
+                        skipped.push(SkippedCombination {
+                            params,
+                            reason: BacktestError::InsufficientTrades {
+                                trades: report.total_trades,
+                                min_trades: self.min_trades,
+                            },
+                        });
+                        continue;
+                    }
+
+                    let score = self.metric.score(&report);
+                    ranked.push(OptimizationCandidate {
+                        params,
+                        report,
+                        score,
+                    });
+                }
+                Err(reason) => {
+                    debug!("조합 건너뜀: {params:?} - {reason}");
+                    skipped.push(SkippedCombination { params, reason });
+                }
+            }
+        }
+
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        info!(
+            "그리드 탐색 종료: {strategy_type} - 평가 {}개, 건너뜀 {}개",
+            ranked.len(),
+            skipped.len()
+        );
+
+        OptimizationResult {
+            strategy_type,
+            metric: self.metric,
+            ranked,
+            skipped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn rsi_base() -> HashMap<String, String> {
+        HashMap::from([
+            ("rsi_count".to_string(), "1".to_string()),
+            ("ma".to_string(), "sma".to_string()),
+            ("ma_periods".to_string(), "5,10".to_string()),
+        ])
+    }
+
+    fn candles() -> Vec<TestCandle> {
+        (0..120)
+            .map(|i| {
+                let close = 100.0 + (i as f64 * 0.3).sin() * 10.0;
+                TestCandle {
+                    timestamp: i * 60,
+                    open: close - 0.5,
+                    high: close + 1.0,
+                    low: close - 1.0,
+                    close,
+                    volume: 1000.0,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_grid_combinations() {
+        let grid = ParameterGrid::new()
+            .with_base(rsi_base())
+            .add_usize_range("rsi_period", 10, 14, 2)
+            .add("ma_periods", ["5,20", "10,50"]);
+
+        assert_eq!(grid.combination_count(), 6);
+        let combinations = grid.combinations();
+        assert_eq!(combinations.len(), 6);
+        // 후보 값이 고정 설정보다 우선
+        assert!(combinations.iter().all(|c| c["ma_periods"] != "5,10"));
+        assert!(combinations.iter().all(|c| c["rsi_count"] == "1"));
+
+        let range = ParameterGrid::new().add_f64_range("rsi_lower", 20.0, 30.0, 2.5);
+        assert_eq!(range.combination_count(), 5);
+    }
+
+    #[test]
+    fn test_invalid_combinations_are_skipped() {
+        let grid = ParameterGrid::new()
+            .with_base(rsi_base())
+            .add("rsi_period", [14])
+            .add("rsi_lower", [30.0, 80.0])
+            .add("rsi_upper", [70.0]);
+
+        let optimizer = GridSearchOptimizer::new(
+            BacktestEngine::default(),
+            OptimizationMetric::TotalReturn,
+            30,
+        );
+        let result = optimizer.run(StrategyType::RSI, &grid, &candles());
+
+        assert_eq!(result.ranked.len(), 1);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].params["rsi_lower"], "80");
+        let err = result.skipped[0].reason.strategy_error().unwrap();
+        assert_eq!(err.key(), Some("rsi_lower"));
+        assert_eq!(err.value(), Some("80"));
+        assert_eq!(result.best().unwrap().params["rsi_lower"], "30");
+    }
+
+    #[test]
+    fn test_ranking_order() {
+        let grid = ParameterGrid::new()
+            .with_base(rsi_base())
+            .add("rsi_period", [6, 10, 14])
+            .add("rsi_lower", [30.0, 40.0])
+            .add("rsi_upper", [70.0]);
+
+        let optimizer = GridSearchOptimizer::new(
+            BacktestEngine::default(),
+            OptimizationMetric::MaxDrawdown,
+            30,
+        );
+        let result = optimizer.run(StrategyType::RSI, &grid, &candles());
+
+        assert_eq!(result.ranked.len() + result.skipped.len(), 6);
+        assert!(
+            result
+                .ranked
+                .windows(2)
+                .all(|pair| pair[0].report.max_drawdown <= pair[1].report.max_drawdown)
+        );
+    }
+}
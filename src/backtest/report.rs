@@ -0,0 +1,397 @@
+use super::{BacktestResult, EquityPoint, Trade};
+use serde::{Deserialize, Serialize};
+
+/// 1년의 초 단위 길이 (연율화 계산용)
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// 자산 곡선 간격을 추정할 수 없을 때 사용하는 연간 기간 수 (일봉 기준)
+const DEFAULT_PERIODS_PER_YEAR: f64 = 365.0;
+
+/// 실제 체결된 거래와 자산 곡선으로 계산한 성과 보고서
+///
+/// 분모가 0인 비율 지표는 분자가 양수이면 정의되지 않으므로 `None`, 그 외에는 0.0으로 표시합니다.
+/// 무한대 대신 `None`을 쓰므로 JSON으로 저장한 보고서를 그대로 다시 읽을 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceReport {
+    /// 초기 자본
+    pub initial_capital: f64,
+    /// 최종 평가 자산
+    pub final_equity: f64,
+    /// 총 수익률 (0.1 = 10%)
+    pub total_return: f64,
+    /// 연평균 복리 수익률
+    pub cagr: f64,
+    /// 연율화 샤프 비율 (무위험 수익률 0 기준)
+    pub sharpe_ratio: f64,
+    /// 연율화 소르티노 비율 (하방 변동성 기준, 손실 캔들 없이 수익이면 `None`)
+    pub sortino_ratio: Option<f64>,
+    /// 칼마 비율 (CAGR / 최대 낙폭, 낙폭 없이 수익이면 `None`)
+    pub calmar_ratio: Option<f64>,
+    /// 최대 낙폭 비율 (0.2 = 20%)
+    pub max_drawdown: f64,
+    /// 고점 대비 손실 구간이 가장 길게 지속된 캔들 수
+    pub max_drawdown_duration_bars: usize,
+    /// 고점 대비 손실 구간이 가장 길게 지속된 시간 (초)
+    pub max_drawdown_duration_secs: i64,
+    /// 총 이익 / 총 손실 (손실 거래 없이 수익이면 `None`)
+    pub profit_factor: Option<f64>,
+    /// 거래당 평균 손익
+    pub expectancy: f64,
+    /// 거래당 평균 수익률
+    pub average_return_rate: f64,
+    /// 승률 (0.0 ~ 1.0)
+    pub win_rate: f64,
+    /// 평균 보유 시간 (초)
+    pub average_holding_secs: f64,
+    /// 총 거래 수
+    pub total_trades: usize,
+    /// 수익 거래 수
+    pub winning_trades: usize,
+    /// 손실 거래 수
+    pub losing_trades: usize,
+}
+
+impl PerformanceReport {
+    /// 거래 목록과 자산 곡선으로 성과 보고서 생성
+    ///
+    /// # Arguments
+    /// * `trades` - 청산된 거래 목록
+    /// * `equity_curve` - 시간 오름차순 자산 곡선
+    /// * `initial_capital` - 초기 자본
+    ///
+    /// # Returns
+    /// * `PerformanceReport` - 계산된 성과 보고서
+    pub fn new(
+        trades: &[Trade],
+        equity_curve: &[EquityPoint],
+        initial_capital: f64,
+    ) -> PerformanceReport {
+        let final_equity = equity_curve
+            .last()
+            .map(|point| point.equity)
+            .unwrap_or(initial_capital);
+        let total_return = ratio_change(initial_capital, final_equity);
+
+        let returns = period_returns(equity_curve, initial_capital);
+        let periods_per_year = estimate_periods_per_year(equity_curve);
+        let cagr = calculate_cagr(equity_curve, initial_capital, final_equity);
+        let (max_drawdown, max_drawdown_duration_bars, max_drawdown_duration_secs) =
+            calculate_drawdown(equity_curve, initial_capital);
+
+        let total_trades = trades.len();
+        let winning_trades = trades.iter().filter(|trade| trade.pnl > 0.0).count();
+        let losing_trades = trades.iter().filter(|trade| trade.pnl < 0.0).count();
+        let gross_profit: f64 = trades.iter().map(|trade| trade.pnl.max(0.0)).sum();
+        let gross_loss: f64 = trades.iter().map(|trade| (-trade.pnl).max(0.0)).sum();
+
+        PerformanceReport {
+            initial_capital,
+            final_equity,
+            total_return,
+            cagr,
+            sharpe_ratio: calculate_sharpe(&returns, periods_per_year),
+            sortino_ratio: calculate_sortino(&returns, periods_per_year),
+            calmar_ratio: safe_ratio(cagr, max_drawdown),
+            max_drawdown,
+            max_drawdown_duration_bars,
+            max_drawdown_duration_secs,
+            profit_factor: safe_ratio(gross_profit, gross_loss),
+            expectancy: mean(trades.iter().map(|trade| trade.pnl)),
+            average_return_rate: mean(trades.iter().map(|trade| trade.return_rate)),
+            win_rate: if total_trades > 0 {
+                winning_trades as f64 / total_trades as f64
+            } else {
+                0.0
+            },
+            average_holding_secs: mean(
+                trades
+                    .iter()
+                    .map(|trade| trade.holding_duration().num_seconds() as f64),
+            ),
+            total_trades,
+            winning_trades,
+            losing_trades,
+        }
+    }
+
+    /// 백테스트 결과로 성과 보고서 생성
+    ///
+    /// # Arguments
+    /// * `result` - 백테스트 결과
+    ///
+    /// # Returns
+    /// * `PerformanceReport` - 계산된 성과 보고서
+    pub fn from_result(result: &BacktestResult) -> PerformanceReport {
+        Self::new(&result.trades, &result.equity_curve, result.initial_capital)
+    }
+}
+
+impl BacktestResult {
+    /// 백테스트 결과의 성과 보고서 생성
+    pub fn report(&self) -> PerformanceReport {
+        PerformanceReport::from_result(self)
+    }
+}
+
+fn ratio_change(from: f64, to: f64) -> f64 {
+    if from == 0.0 { 0.0 } else { to / from - 1.0 }
+}
+
+/// 분모가 0이고 분자가 양수이면 무한대이므로 `None`
+fn safe_ratio(numerator: f64, denominator: f64) -> Option<f64> {
+    if denominator > 0.0 {
+        Some(numerator / denominator)
+    } else if numerator > 0.0 {
+        None
+    } else {
+        Some(0.0)
+    }
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), value| {
+        (sum + value, count + 1)
+    });
+    if count == 0 { 0.0 } else { sum / count as f64 }
+}
+
+/// 초기 자본을 시작점으로 한 캔들별 수익률
+fn period_returns(equity_curve: &[EquityPoint], initial_capital: f64) -> Vec<f64> {
+    let mut previous = initial_capital;
+    equity_curve
+        .iter()
+        .map(|point| {
+            let value = ratio_change(previous, point.equity);
+            previous = point.equity;
+            value
+        })
+        .collect()
+}
+
+/// 자산 곡선의 평균 캔들 간격으로 연간 기간 수 추정
+fn estimate_periods_per_year(equity_curve: &[EquityPoint]) -> f64 {
+    let (Some(first), Some(last)) = (equity_curve.first(), equity_curve.last()) else {
+        return DEFAULT_PERIODS_PER_YEAR;
+    };
+
+    let span_secs = (last.datetime - first.datetime).num_seconds() as f64;
+    if equity_curve.len() < 2 || span_secs <= 0.0 {
+        return DEFAULT_PERIODS_PER_YEAR;
+    }
+
+    let average_interval = span_secs / (equity_curve.len() - 1) as f64;
+    SECONDS_PER_YEAR / average_interval
+}
+
+fn calculate_cagr(equity_curve: &[EquityPoint], initial_capital: f64, final_equity: f64) -> f64 {
+    let (Some(first), Some(last)) = (equity_curve.first(), equity_curve.last()) else {
+        return 0.0;
+    };
+
+    let years = (last.datetime - first.datetime).num_seconds() as f64 / SECONDS_PER_YEAR;
+    if years <= 0.0 || initial_capital <= 0.0 {
+        return 0.0;
+    }
+
+    if final_equity <= 0.0 {
+        return -1.0;
+    }
+
+    (final_equity / initial_capital).powf(1.0 / years) - 1.0
+}
+
+/// 최대 낙폭과 가장 긴 손실 구간 길이 (캔들 수, 초) 계산
+fn calculate_drawdown(equity_curve: &[EquityPoint], initial_capital: f64) -> (f64, usize, i64) {
+    let mut peak = initial_capital;
+    let mut peak_index: Option<usize> = None;
+    let mut max_drawdown = 0.0;
+    let mut max_bars = 0;
+    let mut max_secs = 0;
+
+    for (index, point) in equity_curve.iter().enumerate() {
+        if point.equity >= peak {
+            peak = point.equity;
+            peak_index = Some(index);
+            continue;
+        }
+
+        if peak > 0.0 {
+            max_drawdown = f64::max(max_drawdown, (peak - point.equity) / peak);
+        }
+
+        let (bars, secs) = match peak_index {
+            Some(start) => (
+                index - start,
+                (point.datetime - equity_curve[start].datetime).num_seconds(),
+            ),
+            None => (
+                index + 1,
+                (point.datetime - equity_curve[0].datetime).num_seconds(),
+            ),
+        };
+        max_bars = max_bars.max(bars);
+        max_secs = max_secs.max(secs);
+    }
+
+    (max_drawdown, max_bars, max_secs)
+}
+
+fn standard_deviation(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let average = mean(values.iter().copied());
+    let variance = values
+        .iter()
+        .map(|value| (value - average).powi(2))
+        .sum::<f64>()
+        / (values.len() - 1) as f64;
+    variance.sqrt()
+}
+
+fn calculate_sharpe(returns: &[f64], periods_per_year: f64) -> f64 {
+    let deviation = standard_deviation(returns);
+    if deviation == 0.0 {
+        return 0.0;
+    }
+
+    mean(returns.iter().copied()) / deviation * periods_per_year.sqrt()
+}
+
+fn calculate_sortino(returns: &[f64], periods_per_year: f64) -> Option<f64> {
+    if returns.is_empty() {
+        return Some(0.0);
+    }
+
+    let downside_deviation = (returns
+        .iter()
+        .map(|value| value.min(0.0).powi(2))
+        .sum::<f64>()
+        / returns.len() as f64)
+        .sqrt();
+
+    safe_ratio(mean(returns.iter().copied()), downside_deviation)
+        .map(|ratio| ratio * periods_per_year.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::ExitReason;
+    use crate::model::PositionType;
+    use chrono::{DateTime, Utc};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn at(timestamp: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(timestamp, 0).unwrap()
+    }
+
+    fn trade(entry: i64, exit: i64, pnl: f64) -> Trade {
+        Trade {
+            position: PositionType::Long,
+            entry_time: at(entry),
+            entry_price: 100.0,
+            exit_time: at(exit),
+            exit_price: 100.0 + pnl,
+            quantity: 1.0,
+            fees: 0.0,
+            pnl,
+            return_rate: pnl / 100.0,
+            exit_reason: ExitReason::Signal,
+        }
+    }
+
+    fn curve(values: &[f64]) -> Vec<EquityPoint> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, equity)| EquityPoint {
+                datetime: at(index as i64 * DAY),
+                equity: *equity,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_trade_statistics() {
+        let trades = vec![
+            trade(0, DAY, 20.0),
+            trade(DAY, 3 * DAY, -10.0),
+            trade(3 * DAY, 4 * DAY, 5.0),
+        ];
+        let report = PerformanceReport::new(&trades, &curve(&[100.0, 115.0]), 100.0);
+
+        assert_eq!(report.total_trades, 3);
+        assert_eq!(report.winning_trades, 2);
+        assert_eq!(report.losing_trades, 1);
+        assert!((report.win_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert!((report.profit_factor.unwrap() - 2.5).abs() < 1e-9);
+        assert!((report.expectancy - 5.0).abs() < 1e-9);
+        assert!((report.average_holding_secs - (4.0 * DAY as f64 / 3.0)).abs() < 1e-6);
+        assert!((report.total_return - 0.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_max_drawdown_and_duration() {
+        let equity = curve(&[100.0, 120.0, 90.0, 100.0, 130.0, 117.0]);
+        let report = PerformanceReport::new(&[], &equity, 100.0);
+
+        assert!((report.max_drawdown - 0.25).abs() < 1e-9);
+        assert_eq!(report.max_drawdown_duration_bars, 2);
+        assert_eq!(report.max_drawdown_duration_secs, 2 * DAY);
+        assert!(report.calmar_ratio.unwrap().is_finite());
+    }
+
+    #[test]
+    fn test_ratios_for_flat_and_lossless_curves() {
+        let flat = PerformanceReport::new(&[], &curve(&[100.0, 100.0, 100.0]), 100.0);
+        assert_eq!(flat.sharpe_ratio, 0.0);
+        assert_eq!(flat.sortino_ratio, Some(0.0));
+        assert_eq!(flat.profit_factor, Some(0.0));
+
+        let rising = PerformanceReport::new(
+            &[trade(0, DAY, 10.0)],
+            &curve(&[100.0, 105.0, 110.0]),
+            100.0,
+        );
+        assert!(rising.sharpe_ratio > 0.0);
+        assert_eq!(rising.sortino_ratio, None);
+        assert_eq!(rising.profit_factor, None);
+        assert_eq!(rising.calmar_ratio, None);
+        assert!(rising.cagr > 0.0);
+    }
+
+    #[test]
+    fn test_report_serializes_to_json() {
+        let report = PerformanceReport::new(&[], &curve(&[100.0, 110.0]), 100.0);
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"sharpe_ratio\""));
+        assert!(json.contains("\"max_drawdown_duration_bars\""));
+
+        let restored: PerformanceReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.total_trades, 0);
+        assert_eq!(restored.profit_factor, Some(0.0));
+    }
+
+    #[test]
+    fn test_all_winners_report_round_trips_through_json() {
+        let trades = vec![trade(0, DAY, 10.0), trade(DAY, 2 * DAY, 5.0)];
+        let report = PerformanceReport::new(&trades, &curve(&[100.0, 110.0, 115.0]), 100.0);
+        assert_eq!(report.losing_trades, 0);
+        assert_eq!(report.profit_factor, None);
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"profit_factor\":null"));
+
+        // 기본 serde_json 실수 파싱은 마지막 자리 반올림이 다를 수 있어 값은 오차로 비교
+        let restored: PerformanceReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.profit_factor, None);
+        assert_eq!(restored.sortino_ratio, None);
+        assert_eq!(restored.calmar_ratio, None);
+        assert_eq!(restored.winning_trades, 2);
+        assert!((restored.sharpe_ratio - report.sharpe_ratio).abs() < 1e-9);
+        assert!((restored.total_return - report.total_return).abs() < 1e-12);
+    }
+}
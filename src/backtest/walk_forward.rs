@@ -0,0 +1,522 @@
+// 워크포워드 분석
+// 캔들 이력을 인샘플/아웃오브샘플 구간으로 나누어 인샘플에서 고른 최적 설정을
+// 바로 다음 아웃오브샘플 구간에서 평가합니다.
+
+use super::optimizer::{GridSearchOptimizer, ParameterGrid};
+use super::{BacktestError, EquityPoint, PerformanceReport, Trade};
+use crate::strategy::StrategyType;
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use trading_chart::Candle;
+
+/// 워크포워드 구간 설정 (단위: 캔들 수)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct WalkForwardConfig {
+    /// 인샘플 구간 길이 (탐색기의 warmup 포함)
+    pub in_sample: usize,
+    /// 아웃오브샘플 구간 길이
+    pub out_of_sample: usize,
+    /// 다음 구간으로 이동할 캔들 수 (None이면 out_of_sample과 동일)
+    ///
+    /// 아웃오브샘플 구간이 겹치지 않도록 out_of_sample 이상이어야 합니다.
+    pub step: Option<usize>,
+    /// 인샘플 시작점을 처음 캔들로 고정할지 여부 (확장 윈도우)
+    pub anchored: bool,
+}
+
+impl Default for WalkForwardConfig {
+    fn default() -> Self {
+        WalkForwardConfig {
+            in_sample: 500,
+            out_of_sample: 100,
+            step: None,
+            anchored: false,
+        }
+    }
+}
+
+impl WalkForwardConfig {
+    /// 실제 이동 폭
+    pub fn step(&self) -> usize {
+        self.step.unwrap_or(self.out_of_sample)
+    }
+}
+
+impl ConfigValidation for WalkForwardConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if self.in_sample == 0 {
+            return Err(ConfigError::ValidationError(
+                "in_sample은 0보다 커야 합니다".to_string(),
+            ));
+        }
+
+        if self.out_of_sample == 0 {
+            return Err(ConfigError::ValidationError(
+                "out_of_sample은 0보다 커야 합니다".to_string(),
+            ));
+        }
+
+        if self.step == Some(0) {
+            return Err(ConfigError::ValidationError(
+                "step은 0보다 커야 합니다".to_string(),
+            ));
+        }
+
+        // 겹치는 아웃오브샘플 구간은 이어 붙인 자산 곡선에서 같은 캔들을 두 번 복리 계산함
+        if self.step() < self.out_of_sample {
+            return Err(ConfigError::ValidationError(format!(
+                "step({})은 out_of_sample({}) 이상이어야 합니다",
+                self.step(),
+                self.out_of_sample
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// 한 워크포워드 구간의 평가 결과
+#[derive(Serialize, Debug, Clone)]
+pub struct WalkForwardWindow {
+    /// 구간 번호 (0부터 시작)
+    pub index: usize,
+    /// 인샘플 시작 시각
+    pub in_sample_start: DateTime<Utc>,
+    /// 인샘플 종료 시각
+    pub in_sample_end: DateTime<Utc>,
+    /// 아웃오브샘플 시작 시각
+    pub out_of_sample_start: DateTime<Utc>,
+    /// 아웃오브샘플 종료 시각
+    pub out_of_sample_end: DateTime<Utc>,
+    /// 인샘플에서 선택된 설정
+    pub params: HashMap<String, String>,
+    /// 인샘플 성과
+    pub in_sample_report: PerformanceReport,
+    /// 아웃오브샘플 성과
+    pub out_of_sample_report: PerformanceReport,
+    /// 인샘플에서 건너뛴 조합 수
+    pub skipped_combinations: usize,
+    /// 구간 효율 (캔들당 OOS 수익률 / 캔들당 IS 수익률)
+    pub efficiency: Option<f64>,
+}
+
+/// 평가하지 못한 워크포워드 구간
+#[derive(Serialize, Debug, Clone)]
+pub struct WalkForwardFailure {
+    /// 구간 번호
+    pub index: usize,
+    /// 실패 사유
+    pub reason: BacktestError,
+}
+
+/// 워크포워드 분석 결과
+#[derive(Serialize, Debug, Clone)]
+pub struct WalkForwardResult {
+    /// 전략 타입
+    pub strategy_type: StrategyType,
+    /// 평가된 구간 목록
+    pub windows: Vec<WalkForwardWindow>,
+    /// 평가하지 못한 구간 목록
+    pub failures: Vec<WalkForwardFailure>,
+    /// 아웃오브샘플 구간을 복리로 이어 붙인 자산 곡선
+    pub out_of_sample_equity: Vec<EquityPoint>,
+    /// 이어 붙인 자산 곡선 규모에 맞춘 아웃오브샘플 거래 목록
+    pub out_of_sample_trades: Vec<Trade>,
+    /// 이어 붙인 아웃오브샘플 성과
+    pub out_of_sample_report: PerformanceReport,
+    /// 전체 효율 비율 (캔들당 OOS 수익률 / 캔들당 IS 수익률)
+    ///
+    /// 인샘플 수익률이 0 이하이면 정의되지 않으므로 `None`입니다.
+    pub efficiency_ratio: Option<f64>,
+}
+
+/// 워크포워드 분석기
+#[derive(Debug, Clone)]
+pub struct WalkForwardAnalyzer {
+    optimizer: GridSearchOptimizer,
+    config: WalkForwardConfig,
+}
+
+impl WalkForwardAnalyzer {
+    /// 새 워크포워드 분석기 생성
+    ///
+    /// # Arguments
+    /// * `optimizer` - 인샘플 구간에서 사용할 그리드 탐색기
+    /// * `config` - 구간 설정
+    ///
+    /// # Returns
+    /// * `Result<WalkForwardAnalyzer, BacktestError>` - 생성된 분석기 또는 설정 에러
+    pub fn new(
+        optimizer: GridSearchOptimizer,
+        config: WalkForwardConfig,
+    ) -> Result<WalkForwardAnalyzer, BacktestError> {
+        config.validate()?;
+
+        if config.in_sample <= optimizer.warmup() {
+            return Err(BacktestError::Config(ConfigError::ValidationError(
+                format!(
+                    "in_sample({})은 warmup({})보다 커야 합니다",
+                    config.in_sample,
+                    optimizer.warmup()
+                ),
+            )));
+        }
+
+        Ok(WalkForwardAnalyzer { optimizer, config })
+    }
+
+    /// 구간 설정 참조 반환
+    pub fn config(&self) -> &WalkForwardConfig {
+        &self.config
+    }
+
+    /// 워크포워드 분석 실행
+    ///
+    /// 각 구간의 아웃오브샘플 평가는 직전 인샘플의 마지막 warmup 캔들로 지표를
+    /// 초기화하므로 아웃오브샘플 데이터가 설정 선택에 사용되지 않습니다.
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 분석할 전략 유형
+    /// * `grid` - 설정 후보 그리드
+    /// * `candles` - 과거 캔들 데이터
+    ///
+    /// # Returns
+    /// * `Result<WalkForwardResult, BacktestError>` - 분석 결과 또는 데이터 부족 에러
+    pub fn run<C: Candle + 'static>(
+        &self,
+        strategy_type: StrategyType,
+        grid: &ParameterGrid,
+        candles: &[C],
+    ) -> Result<WalkForwardResult, BacktestError> {
+        let mut ordered = candles.to_vec();
+        ordered.sort_by_key(|candle| candle.datetime());
+
+        let in_sample = self.config.in_sample;
+        let out_of_sample = self.config.out_of_sample;
+        if ordered.len() < in_sample + out_of_sample {
+            return Err(BacktestError::InsufficientCandles {
+                candles: ordered.len(),
+                required: in_sample + out_of_sample,
+            });
+        }
+
+        let warmup = self.optimizer.warmup();
+        let engine = self.optimizer.engine();
+        let initial_capital = engine.config().initial_capital;
+
+        let mut windows = Vec::new();
+        let mut failures = Vec::new();
+        let mut stitched = Stitched::new(initial_capital);
+        let mut in_sample_totals = (0.0, 0usize);
+        let mut out_of_sample_totals = (0.0, 0usize);
+
+        let mut offset = 0;
+        let mut index = 0;
+        while offset + in_sample + out_of_sample <= ordered.len() {
+            let is_start = if self.config.anchored { 0 } else { offset };
+            let is_end = offset + in_sample;
+            let oos_end = is_end + out_of_sample;
+
+            let optimization = self
+                .optimizer
+                .run(strategy_type, grid, &ordered[is_start..is_end]);
+
+            let Some(best) = optimization.best() else {
+                warn!("워크포워드 구간 {index}: 유효한 설정 조합 없음");
+                failures.push(WalkForwardFailure {
+                    index,
+                    reason: BacktestError::NoValidCombination {
+                        skipped: optimization.skipped.len(),
+                    },
+                });
+                offset += self.config.step();
+                index += 1;
+                continue;
+            };
+
+            let config = (!best.params.is_empty()).then(|| best.params.clone());
+            let result = match engine.run_strategy(
+                strategy_type,
+                config,
+                &ordered[is_end - warmup..oos_end],
+                warmup,
+            ) {
+                Ok(result) => result,
+                Err(reason) => {
+                    failures.push(WalkForwardFailure { index, reason });
+                    offset += self.config.step();
+                    index += 1;
+                    continue;
+                }
+            };
+
+            let out_of_sample_report = result.report();
+            let is_bars = is_end - is_start - warmup;
+            let efficiency = efficiency(
+                best.report.total_return,
+                is_bars,
+                out_of_sample_report.total_return,
+                out_of_sample,
+            );
+
+            in_sample_totals.0 += best.report.total_return;
+            in_sample_totals.1 += is_bars;
+            out_of_sample_totals.0 += out_of_sample_report.total_return;
+            out_of_sample_totals.1 += out_of_sample;
+
+            stitched.append(&result.equity_curve, &result.trades, initial_capital);
+
+            windows.push(WalkForwardWindow {
+                index,
+                in_sample_start: ordered[is_start].datetime(),
+                in_sample_end: ordered[is_end - 1].datetime(),
+                out_of_sample_start: ordered[is_end].datetime(),
+                out_of_sample_end: ordered[oos_end - 1].datetime(),
+                params: best.params.clone(),
+                in_sample_report: best.report.clone(),
+                out_of_sample_report,
+                skipped_combinations: optimization.skipped.len(),
+                efficiency,
+            });
+
+            offset += self.config.step();
+            index += 1;
+        }
+
+        info!(
+            "워크포워드 종료: {strategy_type} - 평가 구간 {}개, 실패 구간 {}개",
+            windows.len(),
+            failures.len()
+        );
+
+        let out_of_sample_report =
+            PerformanceReport::new(&stitched.trades, &stitched.equity, initial_capital);
+
+        Ok(WalkForwardResult {
+            strategy_type,
+            windows,
+            failures,
+            out_of_sample_equity: stitched.equity,
+            out_of_sample_trades: stitched.trades,
+            out_of_sample_report,
+            efficiency_ratio: efficiency(
+                in_sample_totals.0,
+                in_sample_totals.1,
+                out_of_sample_totals.0,
+                out_of_sample_totals.1,
+            ),
+        })
+    }
+}
+
+/// 아웃오브샘플 구간을 복리로 이어 붙인 자산 곡선과 거래 목록
+struct Stitched {
+    capital: f64,
+    equity: Vec<EquityPoint>,
+    trades: Vec<Trade>,
+}
+
+impl Stitched {
+    fn new(capital: f64) -> Stitched {
+        Stitched {
+            capital,
+            equity: Vec::new(),
+            trades: Vec::new(),
+        }
+    }
+
+    /// 구간 결과를 현재 자본 규모로 환산하여 추가
+    fn append(&mut self, equity: &[EquityPoint], trades: &[Trade], initial_capital: f64) {
+        let scale = self.capital / initial_capital;
+
+        self.equity.extend(equity.iter().map(|point| EquityPoint {
+            datetime: point.datetime,
+            equity: point.equity * scale,
+        }));
+
+        self.trades.extend(trades.iter().map(|trade| Trade {
+            quantity: trade.quantity * scale,
+            fees: trade.fees * scale,
+            pnl: trade.pnl * scale,
+            ..trade.clone()
+        }));
+
+        if let Some(last) = self.equity.last() {
+            self.capital = last.equity;
+        }
+    }
+}
+
+/// 캔들당 수익률 기준 효율 비율
+fn efficiency(
+    in_sample_return: f64,
+    in_sample_bars: usize,
+    out_of_sample_return: f64,
+    out_of_sample_bars: usize,
+) -> Option<f64> {
+    if in_sample_bars == 0 || out_of_sample_bars == 0 || in_sample_return <= 0.0 {
+        return None;
+    }
+
+    let in_sample_rate = in_sample_return / in_sample_bars as f64;
+    let out_of_sample_rate = out_of_sample_return / out_of_sample_bars as f64;
+    Some(out_of_sample_rate / in_sample_rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::{BacktestEngine, OptimizationMetric};
+    use crate::tests::TestCandle;
+
+    fn candles(count: i64) -> Vec<TestCandle> {
+        (0..count)
+            .map(|i| {
+                let close = 100.0 + (i as f64 * 0.3).sin() * 10.0 + i as f64 * 0.05;
+                TestCandle {
+                    timestamp: i * 60,
+                    open: close - 0.5,
+                    high: close + 1.0,
+                    low: close - 1.0,
+                    close,
+                    volume: 1000.0,
+                }
+            })
+            .collect()
+    }
+
+    fn grid() -> ParameterGrid {
+        ParameterGrid::new()
+            .with_base(HashMap::from([
+                ("rsi_count".to_string(), "1".to_string()),
+                ("ma".to_string(), "sma".to_string()),
+                ("ma_periods".to_string(), "5,10".to_string()),
+                ("rsi_upper".to_string(), "70".to_string()),
+            ]))
+            .add("rsi_period", [6, 14])
+            .add("rsi_lower", [30, 40])
+    }
+
+    fn optimizer() -> GridSearchOptimizer {
+        GridSearchOptimizer::new(
+            BacktestEngine::default(),
+            OptimizationMetric::TotalReturn,
+            20,
+        )
+    }
+
+    fn analyzer(anchored: bool) -> WalkForwardAnalyzer {
+        WalkForwardAnalyzer::new(
+            optimizer(),
+            WalkForwardConfig {
+                in_sample: 60,
+                out_of_sample: 20,
+                step: None,
+                anchored,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rolling_windows_and_stitched_equity() {
+        let result = analyzer(false)
+            .run(StrategyType::RSI, &grid(), &candles(140))
+            .unwrap();
+
+        // 140개 캔들에서 인샘플 60, 아웃오브샘플 20, 간격 20이면 4개 구간
+        assert!(result.failures.is_empty(), "{:?}", result.failures);
+        assert_eq!(result.windows.len(), 4);
+        assert_eq!(result.out_of_sample_equity.len(), 4 * 20);
+
+        // 구간마다 20개 캔들(1분 간격)씩 이동하고, 아웃오브샘플은 인샘플 바로 다음 캔들부터 시작
+        for (i, window) in result.windows.iter().enumerate() {
+            let start = i as i64 * 20;
+            assert_eq!(window.index, i);
+            assert_eq!(window.in_sample_start.timestamp(), start * 60);
+            assert_eq!(window.in_sample_end.timestamp(), (start + 59) * 60);
+            assert_eq!(window.out_of_sample_start.timestamp(), (start + 60) * 60);
+            assert_eq!(window.out_of_sample_end.timestamp(), (start + 79) * 60);
+        }
+        assert!(
+            result
+                .out_of_sample_equity
+                .windows(2)
+                .all(|pair| pair[0].datetime < pair[1].datetime)
+        );
+    }
+
+    #[test]
+    fn test_anchored_windows_start_at_first_candle() {
+        let result = analyzer(true)
+            .run(StrategyType::RSI, &grid(), &candles(140))
+            .unwrap();
+
+        assert!(
+            result
+                .windows
+                .iter()
+                .all(|window| window.in_sample_start.timestamp() == 0)
+        );
+    }
+
+    #[test]
+    fn test_step_shorter_than_out_of_sample_rejected() {
+        let overlapping = WalkForwardConfig {
+            in_sample: 60,
+            out_of_sample: 20,
+            step: Some(10),
+            anchored: false,
+        };
+        assert!(overlapping.validate().is_err());
+        assert!(WalkForwardAnalyzer::new(optimizer(), overlapping).is_err());
+    }
+
+    #[test]
+    fn test_step_longer_than_out_of_sample_skips_candles() {
+        let analyzer = WalkForwardAnalyzer::new(
+            optimizer(),
+            WalkForwardConfig {
+                in_sample: 60,
+                out_of_sample: 20,
+                step: Some(30),
+                anchored: false,
+            },
+        )
+        .unwrap();
+        let result = analyzer
+            .run(StrategyType::RSI, &grid(), &candles(140))
+            .unwrap();
+
+        // 오프셋 0, 30, 60 → 아웃오브샘플 [60, 80), [90, 110), [120, 140)
+        assert_eq!(result.windows.len() + result.failures.len(), 3);
+        assert_eq!(result.out_of_sample_equity.len(), result.windows.len() * 20);
+        assert!(
+            result
+                .out_of_sample_equity
+                .windows(2)
+                .all(|pair| pair[0].datetime < pair[1].datetime)
+        );
+        if let [first, second, ..] = result.windows.as_slice() {
+            assert!(first.out_of_sample_end < second.out_of_sample_start);
+        }
+    }
+
+    #[test]
+    fn test_insufficient_candles() {
+        assert!(matches!(
+            analyzer(false).run(StrategyType::RSI, &grid(), &candles(50)),
+            Err(BacktestError::InsufficientCandles { candles: 50, .. })
+        ));
+    }
+
+    #[test]
+    fn test_efficiency_ratio() {
+        assert_eq!(efficiency(0.2, 100, 0.05, 50), Some(0.5));
+        assert_eq!(efficiency(-0.1, 100, 0.05, 50), None);
+    }
+}
@@ -70,6 +70,27 @@ where
         }
     }
 
+    /// 같은 타임스탬프의 데이터가 있으면 교체하고, 없으면 `add`와 같이 삽입합니다.
+    ///
+    /// 아직 마감되지 않은 캔들을 실시간으로 갱신할 때 사용합니다.
+    ///
+    /// # Arguments
+    /// * `data` - 교체하거나 삽입할 데이터
+    pub fn upsert(&mut self, data: T) {
+        match self
+            .items
+            .binary_search_by(|item| data.datetime().cmp(&item.datetime()))
+        {
+            Ok(idx) => self.items[idx] = data,
+            Err(idx) => {
+                self.items.insert(idx, data);
+                if self.items.len() > self.max_size {
+                    self.items.truncate(self.max_size);
+                }
+            }
+        }
+    }
+
     /// 저장소에 있는 아이템 수를 반환합니다.
     ///
     /// # Returns
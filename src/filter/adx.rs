@@ -1,15 +1,73 @@
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{ADXFilterType, ADXParams, FilterError, Result, utils};
 use crate::analyzer::adx_analyzer::ADXAnalyzer;
-use crate::candle_store::CandleStore;
 use trading_chart::Candle;
 
+/// 필터 평가에 필요한 최소 캔들 수 (ADX 계산에 필요한 최소 기간 + 연속성)
+pub(crate) fn required_candles(params: &ADXParams) -> usize {
+    params.period * 2 + params.consecutive_n
+}
+
+/// 확인한 오프셋별 ADX, +DI, -DI 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &ADXParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &adx_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let adx = data.adxs.get(&params.period);
+            [
+                ("adx", adx.adx),
+                ("plus_di", adx.plus_di),
+                ("minus_di", adx.minus_di),
+            ]
+        },
+    )
+}
+
+fn adx_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &ADXParams,
+) -> CachedRef<'a, ADXAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(params.period.to_string(), || {
+        ADXAnalyzer::new(&[params.period], candle_store)
+    })
+}
+
+/// 통과한 ADX 필터의 강도 (p번째 ADX가 임계값에서 벗어난 정도를 임계값으로 나눈 값)
+///
+/// 임계값 기준이 아닌 타입은 None을 반환합니다.
+pub(crate) fn score_adx<C: Candle + 'static>(
+    params: &ADXParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let adx = adx_analyzer(source, params)
+        .items
+        .get(params.p)?
+        .get_adx(params.period);
+
+    match params.filter_type {
+        ADXFilterType::AboveThreshold => excess_ratio(adx - params.threshold, params.threshold),
+        ADXFilterType::BelowThreshold => excess_ratio(params.threshold - adx, params.threshold),
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 ADX 필터 적용
-pub(crate) fn filter_adx<C: Candle + 'static>(
+pub(crate) fn filter_adx<'a, C: Candle + 'static>(
     coin: &str,
     params: &ADXParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     _current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     log::debug!(
         "ADX 필터 적용 - 기간: {}, 임계값: {}, 타입: {:?}, 연속성: {}",
         params.period,
@@ -25,7 +83,7 @@ pub(crate) fn filter_adx<C: Candle + 'static>(
     }
 
     // 필터링할 코인 식별
-    let required_length = params.period * 2 + params.consecutive_n; // ADX 계산에 필요한 최소 기간 + 연속성
+    let required_length = required_candles(params);
 
     // 경계 조건 체크
     if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
@@ -33,8 +91,7 @@ pub(crate) fn filter_adx<C: Candle + 'static>(
     }
 
     // ADXAnalyzer 생성 (trading-strategy의 analyzer 사용)
-    let adx_periods = vec![params.period];
-    let analyzer = ADXAnalyzer::new(&adx_periods, candle_store);
+    let analyzer = adx_analyzer(source, params);
 
     // analyzer에서 ADX 값 가져오기
     let adx = analyzer.get_adx(params.period);
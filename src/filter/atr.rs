@@ -1,17 +1,64 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{ATRFilterType, ATRParams, utils};
 use crate::analyzer::atr_analyzer::ATRAnalyzer;
-use crate::candle_store::CandleStore;
 use trading_chart::Candle;
 
 /// ATR 필터 함수
-pub(crate) fn filter_atr<C: Candle + 'static>(
+pub(crate) fn filter_atr<'a, C: Candle + 'static>(
     symbol: &str,
     params: &ATRParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
-    ATRFilter::matches_filter(symbol, candle_store, params, current_price)
+    ATRFilter::matches_filter(symbol, source, params, current_price)
+}
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &ATRParams) -> usize {
+    params.period.max(params.consecutive_n)
+}
+
+/// 확인한 오프셋별 ATR 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &ATRParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &atr_analyzer(source, params.period).items,
+        params.p..params.p + params.consecutive_n,
+        |data| [("atr", data.get_atr(params.period))],
+    )
+}
+
+fn atr_analyzer<C: Candle + 'static>(
+    source: FilterSource<'_, C>,
+    period: usize,
+) -> CachedRef<'_, ATRAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(period.to_string(), || {
+        ATRAnalyzer::new(&[period], candle_store)
+    })
+}
+
+/// 통과한 ATR 필터의 강도 (p번째 ATR이 임계값을 넘어선 정도를 임계값으로 나눈 값)
+///
+/// 임계값 기준이 아닌 타입은 None을 반환합니다.
+pub(crate) fn score_atr<C: Candle + 'static>(
+    params: &ATRParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let atr = atr_analyzer(source, params.period)
+        .items
+        .get(params.p)?
+        .get_atr(params.period);
+
+    match params.filter_type {
+        ATRFilterType::AboveThreshold => excess_ratio(atr - params.threshold, params.threshold),
+        _ => None,
+    }
 }
 
 /// ATR 필터 구조체
@@ -19,12 +66,15 @@ pub struct ATRFilter;
 
 impl ATRFilter {
     /// ATR 필터 확인
-    pub(crate) fn matches_filter<C: Candle + 'static>(
+    pub(crate) fn matches_filter<'a, C: Candle + 'static>(
         _symbol: &str,
-        candle_store: &CandleStore<C>,
+        source: impl Into<FilterSource<'a, C>>,
         params: &ATRParams,
         _current_price: f64,
     ) -> Result<bool> {
+        let source = source.into();
+        let candle_store = source.candle_store();
+
         let period = params.period;
         let threshold = params.threshold;
         let filter_type = params.filter_type;
@@ -35,12 +85,12 @@ impl ATRFilter {
         utils::validate_period(period, "ATR")?;
 
         // 경계 조건 체크
-        let required_length = period.max(consecutive_n);
+        let required_length = required_candles(params);
         if !utils::check_sufficient_candles(candle_store.len(), required_length, _symbol) {
             return Ok(false);
         }
         // analyzer는 이미 init_from_storage로 초기화되었으므로 추가 처리 불필요
-        let analyzer = ATRAnalyzer::new(&[period], candle_store);
+        let analyzer = atr_analyzer(source, period);
 
         use crate::analyzer::base::AnalyzerOps;
 
@@ -1,17 +1,93 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{BollingerBandFilterType, BollingerBandParams, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::bband_analyzer::BBandAnalyzer;
-use crate::candle_store::CandleStore;
 use trading_chart::Candle;
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &BollingerBandParams) -> usize {
+    params.period
+}
+
+/// 확인한 오프셋별 볼린저 밴드 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &BollingerBandParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &bband_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            [
+                ("lower", data.bband.lower()),
+                ("middle", data.bband.middle()),
+                ("upper", data.bband.upper()),
+            ]
+        },
+    )
+}
+
+fn bband_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &BollingerBandParams,
+) -> CachedRef<'a, BBandAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(format!("{}:{}", params.period, params.dev_mult), || {
+        BBandAnalyzer::new(params.period, params.dev_mult, candle_store)
+    })
+}
+
+/// 통과한 볼린저 밴드 필터의 강도 (현재가가 p번째 밴드 기준선을 넘어선 정도)
+///
+/// 밴드 돌파는 밴드 폭, 중간선 기준은 중간선과 상/하단 사이 폭으로 정규화합니다.
+/// 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_bollinger_band<C: Candle + 'static>(
+    params: &BollingerBandParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Option<f64> {
+    let analyzer = bband_analyzer(source, params);
+    let bband = &analyzer.items.get(params.p)?.bband;
+    let (lower, middle, upper) = (bband.lower(), bband.middle(), bband.upper());
+
+    match params.filter_type {
+        BollingerBandFilterType::AboveUpperBand => {
+            excess_ratio(current_price - upper, upper - lower)
+        }
+        BollingerBandFilterType::BelowLowerBand => {
+            excess_ratio(lower - current_price, upper - lower)
+        }
+        BollingerBandFilterType::OutsideBand => excess_ratio(
+            (current_price - upper).max(lower - current_price),
+            upper - lower,
+        ),
+        BollingerBandFilterType::InsideBand => excess_ratio(
+            (upper - middle) - (current_price - middle).abs(),
+            upper - middle,
+        ),
+        BollingerBandFilterType::AboveMiddleBand => {
+            excess_ratio(current_price - middle, upper - middle)
+        }
+        BollingerBandFilterType::BelowMiddleBand => {
+            excess_ratio(middle - current_price, middle - lower)
+        }
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 볼린저 밴드 필터 적용
-pub(crate) fn filter_bollinger_band<C: Candle + 'static>(
+pub(crate) fn filter_bollinger_band<'a, C: Candle + 'static>(
     coin: &str,
     params: &BollingerBandParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     log::debug!(
         "볼린저 밴드 필터 적용 - 기간: {}, 편차 배수: {}, 타입: {:?}, 연속성: {}",
         params.period,
@@ -24,12 +100,12 @@ pub(crate) fn filter_bollinger_band<C: Candle + 'static>(
     utils::validate_period(params.period, "BollingerBand")?;
 
     // 경계 조건 체크
-    if !utils::check_sufficient_candles(candle_store.len(), params.period, coin) {
+    if !utils::check_sufficient_candles(candle_store.len(), required_candles(params), coin) {
         return Ok(false);
     }
 
     // BBandAnalyzer 생성
-    let analyzer = BBandAnalyzer::new(params.period, params.dev_mult, candle_store);
+    let analyzer = bband_analyzer(source, params);
 
     // 기존 볼린저 밴드 계산 결과도 가져옴 (로깅용)
     let (lower, middle, upper) = analyzer.get_bband();
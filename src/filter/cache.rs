@@ -0,0 +1,241 @@
+// 필터 지표 캐시
+// TechnicalFilterContext가 필터 파라미터별로 분석기를 보관하고, 캔들이 추가되면
+// 보관 중인 분석기에 캔들 하나만 반영합니다. 같은 필터를 반복 평가할 때
+// 전체 히스토리로 지표를 다시 계산하지 않도록 하기 위함입니다.
+
+use super::timeframe::TimeframeStores;
+use crate::analyzer::adx_analyzer::ADXAnalyzer;
+use crate::analyzer::atr_analyzer::ATRAnalyzer;
+use crate::analyzer::base::AnalyzerOps;
+use crate::analyzer::bband_analyzer::BBandAnalyzer;
+use crate::analyzer::cci_analyzer::CCIAnalyzer;
+use crate::analyzer::donchian_analyzer::DonchianAnalyzer;
+use crate::analyzer::ichimoku_analyzer::IchimokuAnalyzer;
+use crate::analyzer::keltner_analyzer::KeltnerAnalyzer;
+use crate::analyzer::ma_analyzer::MAAnalyzer;
+use crate::analyzer::macd_analyzer::MACDAnalyzer;
+use crate::analyzer::momentum_analyzer::MomentumAnalyzer;
+use crate::analyzer::rsi_analyzer::RSIAnalyzer;
+use crate::analyzer::slope_analyzer::SlopeAnalyzer;
+use crate::analyzer::squeeze_analyzer::SqueezeAnalyzer;
+use crate::analyzer::stoch_rsi_analyzer::StochRSIAnalyzer;
+use crate::analyzer::stochastic_analyzer::StochasticAnalyzer;
+use crate::analyzer::supertrend_analyzer::SuperTrendAnalyzer;
+use crate::analyzer::three_rsi_analyzer::ThreeRSIAnalyzer;
+use crate::analyzer::volume_analyzer::VolumeAnalyzer;
+use crate::analyzer::vwap_analyzer::VWAPAnalyzer;
+use crate::analyzer::williams_r_analyzer::WilliamsRAnalyzer;
+use crate::candle_store::CandleStore;
+use std::any::{Any, TypeId};
+use std::cell::{Ref, RefCell};
+use std::collections::HashMap;
+use std::ops::Deref;
+use trading_chart::{Candle, CandleInterval};
+
+/// 캔들 하나씩 증분 갱신할 수 있는 캐시 항목
+///
+/// 캐시를 가진 컨텍스트와 스크리너를 다른 스레드로 옮길 수 있도록 `Send`를 요구합니다.
+pub(crate) trait CachedIndicator<C: Candle>: Send + 'static {
+    /// 새 캔들 반영
+    fn update(&mut self, candle: C);
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+macro_rules! impl_cached_analyzer {
+    ($($analyzer:ident),+ $(,)?) => {
+        $(
+            impl<C: Candle + 'static> CachedIndicator<C> for $analyzer<C> {
+                fn update(&mut self, candle: C) {
+                    self.next(candle);
+                }
+
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_cached_analyzer!(
+    ADXAnalyzer,
+    ATRAnalyzer,
+    BBandAnalyzer,
+    CCIAnalyzer,
+    DonchianAnalyzer,
+    IchimokuAnalyzer,
+    KeltnerAnalyzer,
+    MAAnalyzer,
+    MACDAnalyzer,
+    MomentumAnalyzer,
+    RSIAnalyzer,
+    SlopeAnalyzer,
+    SqueezeAnalyzer,
+    StochRSIAnalyzer,
+    StochasticAnalyzer,
+    SuperTrendAnalyzer,
+    ThreeRSIAnalyzer,
+    VolumeAnalyzer,
+    VWAPAnalyzer,
+    WilliamsRAnalyzer,
+);
+
+/// 필터 파라미터별 지표 캐시
+///
+/// 분석기 타입과 파라미터 키로 항목을 구분합니다. 항목은 처음 평가할 때 만들어지고,
+/// 이후에는 `update`로 추가된 캔들만 반영합니다.
+pub(crate) struct IndicatorCache<C: Candle + 'static> {
+    entries: RefCell<HashMap<(TypeId, String), Box<dyn CachedIndicator<C>>>>,
+}
+
+impl<C: Candle + 'static> IndicatorCache<C> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// 최신 캔들 뒤에 추가된 캔들을 모든 항목에 반영
+    pub(crate) fn update(&mut self, candle: &C) {
+        for entry in self.entries.get_mut().values_mut() {
+            entry.update(candle.clone());
+        }
+    }
+
+    /// 캔들 저장소에 캔들을 넣고 캐시를 맞춤 (같은 시각의 캔들은 교체)
+    ///
+    /// 최신 캔들 뒤에 추가되면 캐시된 지표에 캔들 하나만 반영합니다.
+    /// 마감 전 캔들 교체나 과거 캔들 삽입은 캐시를 비우고 다음 평가에서 다시 계산합니다.
+    pub(crate) fn upsert(&mut self, candle_store: &mut CandleStore<C>, candle: C) {
+        let appended = candle_store
+            .first()
+            .is_none_or(|latest| candle.datetime() > latest.datetime());
+
+        candle_store.upsert(candle.clone());
+        if appended {
+            self.update(&candle);
+        } else {
+            self.clear();
+        }
+    }
+
+    /// 모든 항목 제거 (다음 평가에서 다시 생성)
+    pub(crate) fn clear(&mut self) {
+        self.entries.get_mut().clear();
+    }
+
+    /// 보관 중인 항목 수
+    pub(crate) fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    fn get_or_build<A: CachedIndicator<C>>(
+        &self,
+        key: String,
+        build: impl FnOnce() -> A,
+    ) -> CachedRef<'_, A> {
+        let key = (TypeId::of::<A>(), key);
+
+        if !self.entries.borrow().contains_key(&key) {
+            let analyzer = build();
+            // 다른 항목을 빌려 쓰는 중이면 캐시하지 않고 그대로 사용
+            let Ok(mut entries) = self.entries.try_borrow_mut() else {
+                return CachedRef::Built(analyzer);
+            };
+            entries.insert(key.clone(), Box::new(analyzer));
+        }
+
+        CachedRef::Cached(Ref::map(self.entries.borrow(), |entries| {
+            entries[&key]
+                .as_any()
+                .downcast_ref::<A>()
+                .expect("캐시 키에 분석기 타입이 포함되어 있어 항상 같은 타입이어야 함")
+        }))
+    }
+}
+
+/// 캐시에서 빌려온 분석기 또는 캐시 없이 생성한 분석기
+pub(crate) enum CachedRef<'a, A> {
+    Built(A),
+    Cached(Ref<'a, A>),
+}
+
+impl<A> Deref for CachedRef<'_, A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        match self {
+            CachedRef::Built(analyzer) => analyzer,
+            CachedRef::Cached(analyzer) => analyzer,
+        }
+    }
+}
+
+/// 필터가 지표를 계산할 캔들 저장소와 (있다면) 지표 캐시
+///
+/// 캔들 저장소 참조에서 바로 변환되므로 캐시 없이 필터 함수를 호출할 수도 있습니다.
+pub(crate) struct FilterSource<'a, C: Candle + 'static> {
+    candle_store: &'a CandleStore<C>,
+    cache: Option<&'a IndicatorCache<C>>,
+    timeframes: Option<&'a TimeframeStores<C>>,
+}
+
+impl<C: Candle + 'static> Clone for FilterSource<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Candle + 'static> Copy for FilterSource<'_, C> {}
+
+impl<'a, C: Candle + 'static> From<&'a CandleStore<C>> for FilterSource<'a, C> {
+    fn from(candle_store: &'a CandleStore<C>) -> Self {
+        Self {
+            candle_store,
+            cache: None,
+            timeframes: None,
+        }
+    }
+}
+
+impl<'a, C: Candle + 'static> FilterSource<'a, C> {
+    pub(crate) fn cached(candle_store: &'a CandleStore<C>, cache: &'a IndicatorCache<C>) -> Self {
+        Self {
+            candle_store,
+            cache: Some(cache),
+            timeframes: None,
+        }
+    }
+
+    /// 캔들 간격별 저장소 연결 (`interval`을 지정한 필터용)
+    pub(crate) fn with_timeframes(self, timeframes: &'a TimeframeStores<C>) -> Self {
+        Self {
+            timeframes: Some(timeframes),
+            ..self
+        }
+    }
+
+    /// 캔들 간격에 해당하는 저장소와 캐시 (연결된 간격별 저장소가 없으면 None)
+    pub(crate) fn timeframe(&self, interval: &CandleInterval) -> Option<FilterSource<'a, C>> {
+        self.timeframes?.source(interval)
+    }
+
+    pub(crate) fn candle_store(&self) -> &'a CandleStore<C> {
+        self.candle_store
+    }
+
+    /// 파라미터 키에 해당하는 분석기 (캐시가 없거나 처음이면 생성)
+    ///
+    /// 키는 분석기 생성에 쓰인 파라미터를 모두 담아야 합니다.
+    pub(crate) fn analyzer<A: CachedIndicator<C>>(
+        &self,
+        key: String,
+        build: impl FnOnce() -> A,
+    ) -> CachedRef<'a, A> {
+        match self.cache {
+            Some(cache) => cache.get_or_build(key, build),
+            None => CachedRef::Built(build()),
+        }
+    }
+}
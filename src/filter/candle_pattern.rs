@@ -1,7 +1,9 @@
+use super::report::OffsetValues;
 use super::{CandlePatternFilterType, CandlePatternParams, FilterError, Result, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::candle_pattern_analyzer::CandlePatternAnalyzer;
 use crate::candle_store::CandleStore;
+use std::collections::BTreeMap;
 use trading_chart::Candle;
 
 /// CandlePattern 필터 함수
@@ -14,6 +16,48 @@ pub(crate) fn filter_candle_pattern<C: Candle + Clone + 'static>(
     CandlePatternFilter::matches_filter(symbol, candle_store, params, current_price)
 }
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &CandlePatternParams) -> usize {
+    params.pattern_history_length.max(params.consecutive_n)
+}
+
+/// 확인한 오프셋별 감지된 패턴과 신뢰도, 강도
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &CandlePatternParams,
+    candle_store: &CandleStore<C>,
+) -> Vec<OffsetValues> {
+    let analyzer = CandlePatternAnalyzer::new(
+        candle_store,
+        params.min_body_ratio,
+        params.min_shadow_ratio,
+        params.pattern_history_length,
+    );
+
+    (params.p..params.p + params.consecutive_n)
+        .filter_map(|offset| {
+            let analysis = &analyzer.items.get(offset)?.pattern_analysis;
+            Some(OffsetValues {
+                offset,
+                values: BTreeMap::from([
+                    ("confidence_score".to_string(), analysis.confidence_score),
+                    ("pattern_strength".to_string(), analysis.pattern_strength),
+                ]),
+                labels: BTreeMap::from([
+                    (
+                        "single_pattern".to_string(),
+                        format!("{:?}", analysis.single_pattern),
+                    ),
+                    (
+                        "multi_pattern".to_string(),
+                        format!("{:?}", analysis.multi_pattern),
+                    ),
+                    ("signal".to_string(), format!("{:?}", analysis.signal)),
+                ]),
+            })
+        })
+        .collect()
+}
+
 /// CandlePattern 필터 구조체
 pub struct CandlePatternFilter;
 
@@ -38,7 +82,7 @@ impl CandlePatternFilter {
         }
 
         // 경계 조건 체크
-        let required_length = pattern_history_length.max(consecutive_n);
+        let required_length = required_candles(params);
         if !utils::check_sufficient_candles(candle_store.len(), required_length, _symbol) {
             return Ok(false);
         }
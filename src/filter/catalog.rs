@@ -0,0 +1,860 @@
+// 필터 카탈로그
+// 기본 필터마다 설정 파일의 `type` 값, 파라미터(기본값, 검증 범위), `filter_type` 목록(정수 인덱스)을
+// 코드에서 조회하고 JSON Schema로 내보냅니다. 웹 설정 편집기가 폼을 만들고
+// `matches_filters`를 호출하기 전에 설정을 검증하는 용도입니다.
+// 기본값과 키 목록은 각 파라미터 구조체의 `Default`를 직렬화해서 얻으므로 따로 관리하지 않고,
+// 검증 범위는 `TechnicalFilterConfig::validate`와 같은 내용을 아래 `rules`에 적습니다.
+
+use super::{
+    ADXFilterType, ADXParams, ATRFilterType, ATRParams, BollingerBandFilterType,
+    BollingerBandParams, CCIFilterType, CCIParams, CandlePatternFilterType, CandlePatternParams,
+    CompareFilterType, CompareParams, CopysFilterType, CopysParams, DonchianFilterType,
+    DonchianParams, IchimokuFilterType, IchimokuParams, KeltnerFilterType, KeltnerParams,
+    MACDFilterType, MACDParams, MomentumFilterType, MomentumParams, MovingAverageFilterType,
+    MovingAverageParams, PriceReferenceGapFilterType, PriceReferenceGapParams, RSIFilterType,
+    RSIParams, SlopeFilterType, SlopeParams, SqueezeFilterType, SqueezeParams, StochRSIFilterType,
+    StochRSIParams, StochasticFilterType, StochasticParams, SuperTrendFilterType, SuperTrendParams,
+    SupportResistanceFilterType, SupportResistanceParams, TechnicalFilterConfig,
+    TechnicalFilterType, ThreeRSIFilterType, ThreeRSIParams, VWAPFilterType, VWAPParams,
+    VolumeFilterType, VolumeParams, WilliamsRFilterType, WilliamsRParams,
+};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::fmt::Display;
+
+/// 파라미터 값 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamKind {
+    /// 0 이상 정수
+    Integer,
+    /// 실수
+    Number,
+    /// 참/거짓
+    Boolean,
+    /// 문자열
+    String,
+    /// 0 이상 정수 목록
+    IntegerList,
+    /// `type` 태그를 가진 중첩 설정 (`indicator_type`, `reference_source`, `anchor`)
+    Object,
+    /// 숫자(상수) 또는 지표 설정 (`COMPARE`의 `lhs`, `rhs`)
+    Operand,
+    /// 필터 세부 타입 (이름 또는 정수 인덱스)
+    FilterType,
+}
+
+/// `TechnicalFilterConfig::validate`가 확인하는 값 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamRange {
+    /// 1 이상 (목록이면 비어 있지 않고 모든 값이 1 이상)
+    AtLeastOne,
+    /// 0 이상 유한한 수
+    NonNegative,
+    /// 0보다 큰 유한한 수
+    Positive,
+    /// 0 이상 100 이하
+    Percentage,
+    /// 0 이상 1 이하
+    Ratio,
+    /// -100 이상 0 이하
+    NegativePercentage,
+}
+
+impl ParamRange {
+    /// 최솟값
+    pub fn minimum(&self) -> f64 {
+        match self {
+            ParamRange::AtLeastOne => 1.0,
+            ParamRange::NegativePercentage => -100.0,
+            _ => 0.0,
+        }
+    }
+
+    /// 최솟값 자체는 허용하지 않는지 여부
+    pub fn exclusive_minimum(&self) -> bool {
+        matches!(self, ParamRange::Positive)
+    }
+
+    /// 최댓값 (없으면 None)
+    pub fn maximum(&self) -> Option<f64> {
+        match self {
+            ParamRange::Percentage => Some(100.0),
+            ParamRange::Ratio => Some(1.0),
+            ParamRange::NegativePercentage => Some(0.0),
+            _ => None,
+        }
+    }
+}
+
+/// 파라미터 설명
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParamSpec {
+    /// 설정 키
+    pub name: String,
+    /// 값 종류
+    pub kind: ParamKind,
+    /// 생략했을 때 사용되는 값
+    pub default: Value,
+    /// 검증 범위 (없으면 값 종류 외 제약 없음)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<ParamRange>,
+    /// `null` 허용 여부 (생략하면 필터 타입별 기본 동작)
+    pub nullable: bool,
+}
+
+/// 필터 세부 타입과 정수 인덱스
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FilterTypeVariant {
+    /// `filter_type`에 정수로 넣을 때의 값 (선언 순서, 0부터)
+    pub index: usize,
+    /// `filter_type`에 문자열로 넣을 때의 값
+    pub name: String,
+}
+
+/// 기간 순서 제약 (`shorter < longer`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PeriodOrder {
+    pub shorter: String,
+    pub longer: String,
+}
+
+/// 필터 하나의 설명
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterSpec {
+    /// 필터 종류
+    pub filter: TechnicalFilterType,
+    /// 설정 파일의 `type` 값 (예: `BOLLINGER_BAND`)
+    pub config_type: String,
+    /// 파라미터 목록 (`type` 제외, 키 이름순)
+    pub params: Vec<ParamSpec>,
+    /// `filter_type` 목록 (정수 인덱스순)
+    pub filter_types: Vec<FilterTypeVariant>,
+    /// 기간 순서 제약
+    pub period_orders: Vec<PeriodOrder>,
+}
+
+impl FilterSpec {
+    /// 이름에 해당하는 파라미터
+    pub fn param(&self, name: &str) -> Option<&ParamSpec> {
+        self.params.iter().find(|param| param.name == name)
+    }
+
+    fn json_schema(&self) -> Value {
+        let mut properties = serde_json::Map::new();
+        properties.insert("type".to_string(), json!({ "const": self.config_type }));
+        properties.insert(
+            "interval".to_string(),
+            json!({
+                "type": "string",
+                "description": "캔들 간격 (CandleInterval 문자열, 예: 1h)",
+            }),
+        );
+        for param in &self.params {
+            properties.insert(param.name.clone(), param_schema(param, &self.filter_types));
+        }
+
+        let mut schema = json!({
+            "title": self.config_type,
+            "type": "object",
+            "properties": properties,
+            "required": ["type"],
+            "additionalProperties": false,
+        });
+        if !self.period_orders.is_empty() {
+            let orders: Vec<String> = self
+                .period_orders
+                .iter()
+                .map(|order| format!("{} < {}", order.shorter, order.longer))
+                .collect();
+            schema["description"] = json!(format!("기간 순서: {}", orders.join(", ")));
+        }
+        schema
+    }
+}
+
+/// 기본 필터 카탈로그
+///
+/// `CustomFilterRegistry`에 등록한 사용자 정의 필터는 파라미터를 알 수 없으므로 포함하지 않습니다.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterCatalog {
+    /// 필터 목록 (`TechnicalFilterType` 선언 순서)
+    pub filters: Vec<FilterSpec>,
+}
+
+impl FilterCatalog {
+    pub fn new() -> Self {
+        Self {
+            filters: BUILT_IN_FILTERS
+                .into_iter()
+                .filter_map(filter_spec)
+                .collect(),
+        }
+    }
+
+    /// 필터 종류에 해당하는 설명
+    pub fn filter(&self, filter: &TechnicalFilterType) -> Option<&FilterSpec> {
+        self.filters.iter().find(|spec| spec.filter == *filter)
+    }
+
+    /// `TechnicalFilterConfig` 한 항목의 JSON Schema (draft 2020-12)
+    ///
+    /// 기간 순서 제약은 스키마로 표현할 수 없어 필터별 `description`에 적습니다.
+    pub fn json_schema(&self) -> Value {
+        let filters: Vec<Value> = self.filters.iter().map(FilterSpec::json_schema).collect();
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "TechnicalFilterConfig",
+            "oneOf": filters,
+        })
+    }
+}
+
+impl Default for FilterCatalog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const BUILT_IN_FILTERS: [TechnicalFilterType; 25] = [
+    TechnicalFilterType::RSI,
+    TechnicalFilterType::MACD,
+    TechnicalFilterType::BollingerBand,
+    TechnicalFilterType::ADX,
+    TechnicalFilterType::MovingAverage,
+    TechnicalFilterType::Ichimoku,
+    TechnicalFilterType::VWAP,
+    TechnicalFilterType::PriceReferenceGap,
+    TechnicalFilterType::Copys,
+    TechnicalFilterType::ATR,
+    TechnicalFilterType::SuperTrend,
+    TechnicalFilterType::Volume,
+    TechnicalFilterType::ThreeRSI,
+    TechnicalFilterType::CandlePattern,
+    TechnicalFilterType::SupportResistance,
+    TechnicalFilterType::Momentum,
+    TechnicalFilterType::Slope,
+    TechnicalFilterType::Compare,
+    TechnicalFilterType::Stochastic,
+    TechnicalFilterType::StochRSI,
+    TechnicalFilterType::WilliamsR,
+    TechnicalFilterType::CCI,
+    TechnicalFilterType::Keltner,
+    TechnicalFilterType::Donchian,
+    TechnicalFilterType::Squeeze,
+];
+
+/// 필터별 기본 설정과 검증 규칙 (`TechnicalFilterConfig::validate`와 같은 내용)
+struct FilterRules {
+    config: TechnicalFilterConfig,
+    filter_types: Vec<String>,
+    ranges: &'static [(&'static str, ParamRange)],
+    /// 기본값으로 종류를 알 수 없는 파라미터 (`null` 기본값, 숫자/지표 겸용)
+    kinds: &'static [(&'static str, ParamKind)],
+    period_orders: &'static [(&'static str, &'static str)],
+}
+
+fn names<T: Display>(variants: &[T]) -> Vec<String> {
+    variants.iter().map(ToString::to_string).collect()
+}
+
+fn rules(filter: &TechnicalFilterType) -> Option<FilterRules> {
+    use ParamRange::*;
+
+    let rules = match filter {
+        TechnicalFilterType::RSI => FilterRules {
+            config: TechnicalFilterConfig::RSI(RSIParams::default()),
+            filter_types: names(RSIFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("oversold", Percentage),
+                ("overbought", Percentage),
+                ("consecutive_n", AtLeastOne),
+                ("sideways_threshold", NonNegative),
+                ("momentum_threshold", NonNegative),
+                ("cross_threshold", Percentage),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::MACD => FilterRules {
+            config: TechnicalFilterConfig::MACD(MACDParams::default()),
+            filter_types: names(MACDFilterType::VARIANTS),
+            ranges: &[
+                ("fast_period", AtLeastOne),
+                ("slow_period", AtLeastOne),
+                ("signal_period", AtLeastOne),
+                ("consecutive_n", AtLeastOne),
+                ("overbought_threshold", NonNegative),
+                ("oversold_threshold", NonNegative),
+                ("sideways_threshold", NonNegative),
+            ],
+            kinds: &[],
+            period_orders: &[("fast_period", "slow_period")],
+        },
+        TechnicalFilterType::BollingerBand => FilterRules {
+            config: TechnicalFilterConfig::BollingerBand(BollingerBandParams::default()),
+            filter_types: names(BollingerBandFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("dev_mult", Positive),
+                ("consecutive_n", AtLeastOne),
+                ("squeeze_threshold", NonNegative),
+                ("medium_threshold", NonNegative),
+                ("large_threshold", NonNegative),
+                ("squeeze_breakout_period", AtLeastOne),
+                ("enhanced_narrowing_period", AtLeastOne),
+                ("enhanced_squeeze_period", AtLeastOne),
+                ("upper_touch_threshold", Positive),
+                ("lower_touch_threshold", Positive),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::ADX => FilterRules {
+            config: TechnicalFilterConfig::ADX(ADXParams::default()),
+            filter_types: names(ADXFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("threshold", Percentage),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::MovingAverage => FilterRules {
+            config: TechnicalFilterConfig::MovingAverage(MovingAverageParams::default()),
+            filter_types: names(MovingAverageFilterType::VARIANTS),
+            ranges: &[
+                ("periods", AtLeastOne),
+                ("consecutive_n", AtLeastOne),
+                ("sideways_threshold", NonNegative),
+                ("crossover_threshold", NonNegative),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Ichimoku => FilterRules {
+            config: TechnicalFilterConfig::Ichimoku(IchimokuParams::default()),
+            filter_types: names(IchimokuFilterType::VARIANTS),
+            ranges: &[
+                ("tenkan_period", AtLeastOne),
+                ("kijun_period", AtLeastOne),
+                ("senkou_span_b_period", AtLeastOne),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[
+                ("tenkan_period", "kijun_period"),
+                ("kijun_period", "senkou_span_b_period"),
+            ],
+        },
+        TechnicalFilterType::VWAP => FilterRules {
+            config: TechnicalFilterConfig::VWAP(VWAPParams::default()),
+            filter_types: names(VWAPFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("consecutive_n", AtLeastOne),
+                ("threshold", NonNegative),
+                ("band_multiplier", Positive),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::PriceReferenceGap => FilterRules {
+            config: TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams::default()),
+            filter_types: names(PriceReferenceGapFilterType::VARIANTS),
+            ranges: &[("consecutive_n", AtLeastOne), ("gap_threshold", Ratio)],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Copys => FilterRules {
+            config: TechnicalFilterConfig::Copys(CopysParams::default()),
+            filter_types: names(CopysFilterType::VARIANTS),
+            ranges: &[
+                ("rsi_period", AtLeastOne),
+                ("rsi_upper", Percentage),
+                ("rsi_lower", Percentage),
+                ("consecutive_n", AtLeastOne),
+                ("bband_period", AtLeastOne),
+                ("bband_multiplier", Positive),
+                ("ma_periods", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::ATR => FilterRules {
+            config: TechnicalFilterConfig::ATR(ATRParams::default()),
+            filter_types: names(ATRFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("threshold", NonNegative),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::SuperTrend => FilterRules {
+            config: TechnicalFilterConfig::SuperTrend(SuperTrendParams::default()),
+            filter_types: names(SuperTrendFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("multiplier", Positive),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Volume => FilterRules {
+            config: TechnicalFilterConfig::Volume(VolumeParams::default()),
+            filter_types: names(VolumeFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("threshold", Positive),
+                ("consecutive_n", AtLeastOne),
+                ("stable_min_threshold", NonNegative),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::ThreeRSI => FilterRules {
+            config: TechnicalFilterConfig::ThreeRSI(ThreeRSIParams::default()),
+            filter_types: names(ThreeRSIFilterType::VARIANTS),
+            ranges: &[
+                ("rsi_periods", AtLeastOne),
+                ("ma_period", AtLeastOne),
+                ("adx_period", AtLeastOne),
+                ("consecutive_n", AtLeastOne),
+                ("cross_threshold", Percentage),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::CandlePattern => FilterRules {
+            config: TechnicalFilterConfig::CandlePattern(CandlePatternParams::default()),
+            filter_types: names(CandlePatternFilterType::VARIANTS),
+            ranges: &[
+                ("min_body_ratio", NonNegative),
+                ("min_shadow_ratio", NonNegative),
+                ("pattern_history_length", AtLeastOne),
+                ("threshold", NonNegative),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::SupportResistance => FilterRules {
+            config: TechnicalFilterConfig::SupportResistance(SupportResistanceParams::default()),
+            filter_types: names(SupportResistanceFilterType::VARIANTS),
+            ranges: &[
+                ("lookback_period", AtLeastOne),
+                ("touch_threshold", NonNegative),
+                ("min_touch_count", AtLeastOne),
+                ("threshold", NonNegative),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Momentum => FilterRules {
+            config: TechnicalFilterConfig::Momentum(MomentumParams::default()),
+            filter_types: names(MomentumFilterType::VARIANTS),
+            ranges: &[
+                ("rsi_period", AtLeastOne),
+                ("stoch_period", AtLeastOne),
+                ("williams_period", AtLeastOne),
+                ("roc_period", AtLeastOne),
+                ("cci_period", AtLeastOne),
+                ("momentum_period", AtLeastOne),
+                ("history_length", AtLeastOne),
+                ("threshold", NonNegative),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Slope => FilterRules {
+            config: TechnicalFilterConfig::Slope(SlopeParams::default()),
+            filter_types: names(SlopeFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("consecutive_n", AtLeastOne),
+                ("strength_threshold", NonNegative),
+                ("r_squared_threshold", Ratio),
+                ("short_period", AtLeastOne),
+            ],
+            kinds: &[
+                ("use_linear_regression", ParamKind::Boolean),
+                ("strength_threshold", ParamKind::Number),
+                ("r_squared_threshold", ParamKind::Number),
+                ("short_period", ParamKind::Integer),
+            ],
+            period_orders: &[("short_period", "period")],
+        },
+        TechnicalFilterType::Compare => FilterRules {
+            config: TechnicalFilterConfig::Compare(CompareParams::default()),
+            filter_types: names(CompareFilterType::VARIANTS),
+            ranges: &[("consecutive_n", AtLeastOne)],
+            kinds: &[("lhs", ParamKind::Operand), ("rhs", ParamKind::Operand)],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Stochastic => FilterRules {
+            config: TechnicalFilterConfig::Stochastic(StochasticParams::default()),
+            filter_types: names(StochasticFilterType::VARIANTS),
+            ranges: &[
+                ("k_period", AtLeastOne),
+                ("d_period", AtLeastOne),
+                ("overbought", Percentage),
+                ("oversold", Percentage),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::StochRSI => FilterRules {
+            config: TechnicalFilterConfig::StochRSI(StochRSIParams::default()),
+            filter_types: names(StochRSIFilterType::VARIANTS),
+            ranges: &[
+                ("rsi_period", AtLeastOne),
+                ("stoch_period", AtLeastOne),
+                ("k_period", AtLeastOne),
+                ("d_period", AtLeastOne),
+                ("overbought", Percentage),
+                ("oversold", Percentage),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::WilliamsR => FilterRules {
+            config: TechnicalFilterConfig::WilliamsR(WilliamsRParams::default()),
+            filter_types: names(WilliamsRFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("overbought", NegativePercentage),
+                ("oversold", NegativePercentage),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::CCI => FilterRules {
+            config: TechnicalFilterConfig::CCI(CCIParams::default()),
+            filter_types: names(CCIFilterType::VARIANTS),
+            ranges: &[("period", AtLeastOne), ("consecutive_n", AtLeastOne)],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Keltner => FilterRules {
+            config: TechnicalFilterConfig::Keltner(KeltnerParams::default()),
+            filter_types: names(KeltnerFilterType::VARIANTS),
+            ranges: &[
+                ("period", AtLeastOne),
+                ("atr_period", AtLeastOne),
+                ("multiplier", Positive),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Donchian => FilterRules {
+            config: TechnicalFilterConfig::Donchian(DonchianParams::default()),
+            filter_types: names(DonchianFilterType::VARIANTS),
+            ranges: &[("period", AtLeastOne), ("consecutive_n", AtLeastOne)],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Squeeze => FilterRules {
+            config: TechnicalFilterConfig::Squeeze(SqueezeParams::default()),
+            filter_types: names(SqueezeFilterType::VARIANTS),
+            ranges: &[
+                ("bb_period", AtLeastOne),
+                ("bb_multiplier", Positive),
+                ("kc_period", AtLeastOne),
+                ("kc_atr_period", AtLeastOne),
+                ("kc_multiplier", Positive),
+                ("consecutive_n", AtLeastOne),
+            ],
+            kinds: &[],
+            period_orders: &[],
+        },
+        TechnicalFilterType::Custom(_) => return None,
+    };
+
+    Some(rules)
+}
+
+/// 기본값으로 값 종류 추정 (`null`이면 알 수 없음)
+fn kind_of(name: &str, default: &Value) -> Option<ParamKind> {
+    if name == "filter_type" {
+        return Some(ParamKind::FilterType);
+    }
+
+    match default {
+        Value::Null => None,
+        Value::Bool(_) => Some(ParamKind::Boolean),
+        Value::Number(number) if number.is_u64() => Some(ParamKind::Integer),
+        Value::Number(_) => Some(ParamKind::Number),
+        Value::String(_) => Some(ParamKind::String),
+        Value::Array(_) => Some(ParamKind::IntegerList),
+        Value::Object(_) => Some(ParamKind::Object),
+    }
+}
+
+fn filter_spec(filter: TechnicalFilterType) -> Option<FilterSpec> {
+    let rules = rules(&filter)?;
+    let Ok(Value::Object(mut defaults)) = serde_json::to_value(&rules.config) else {
+        return None;
+    };
+    let config_type = defaults.remove("type")?.as_str()?.to_string();
+
+    let lookup = |table: &[(&str, ParamKind)], name: &str| {
+        table
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, kind)| *kind)
+    };
+    let params = defaults
+        .into_iter()
+        .map(|(name, default)| ParamSpec {
+            kind: lookup(rules.kinds, &name)
+                .or_else(|| kind_of(&name, &default))
+                .unwrap_or(ParamKind::Object),
+            range: rules
+                .ranges
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, range)| *range),
+            nullable: default.is_null(),
+            name,
+            default,
+        })
+        .collect();
+
+    Some(FilterSpec {
+        filter,
+        config_type,
+        params,
+        filter_types: rules
+            .filter_types
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| FilterTypeVariant { index, name })
+            .collect(),
+        period_orders: rules
+            .period_orders
+            .iter()
+            .map(|(shorter, longer)| PeriodOrder {
+                shorter: shorter.to_string(),
+                longer: longer.to_string(),
+            })
+            .collect(),
+    })
+}
+
+fn param_schema(param: &ParamSpec, filter_types: &[FilterTypeVariant]) -> Value {
+    let mut schema = match param.kind {
+        ParamKind::Integer => json!({ "type": "integer", "minimum": 0 }),
+        ParamKind::Number => json!({ "type": "number" }),
+        ParamKind::Boolean => json!({ "type": "boolean" }),
+        ParamKind::String => json!({ "type": "string" }),
+        ParamKind::IntegerList => {
+            json!({ "type": "array", "items": { "type": "integer", "minimum": 0 } })
+        }
+        ParamKind::Object => json!({ "type": "object", "required": ["type"] }),
+        ParamKind::Operand => json!({ "type": ["number", "object"] }),
+        ParamKind::FilterType => {
+            let variant_names: Vec<&str> = filter_types
+                .iter()
+                .map(|variant| variant.name.as_str())
+                .collect();
+            json!({
+                "oneOf": [
+                    { "type": "string", "enum": variant_names },
+                    { "type": "integer", "minimum": 0, "maximum": filter_types.len().saturating_sub(1) },
+                ]
+            })
+        }
+    };
+
+    if let Some(range) = param.range {
+        let target = if param.kind == ParamKind::IntegerList {
+            schema["minItems"] = json!(1);
+            &mut schema["items"]
+        } else {
+            &mut schema
+        };
+        if range.exclusive_minimum() {
+            target["exclusiveMinimum"] = json!(range.minimum());
+        } else {
+            target["minimum"] = json!(range.minimum());
+        }
+        if let Some(maximum) = range.maximum() {
+            target["maximum"] = json!(maximum);
+        }
+    }
+
+    if param.nullable
+        && let Some(kind) = schema["type"].as_str().map(str::to_string)
+    {
+        schema["type"] = json!([kind, "null"]);
+    }
+    schema["default"] = param.default.clone();
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    // 범위를 벗어나는 값 (목록이면 항목 값)
+    fn out_of_range(range: ParamRange, kind: ParamKind) -> Value {
+        let value = match range {
+            ParamRange::AtLeastOne => json!(0),
+            ParamRange::NonNegative => json!(-1.0),
+            ParamRange::Positive => json!(0.0),
+            ParamRange::Percentage => json!(100.5),
+            ParamRange::Ratio => json!(1.5),
+            ParamRange::NegativePercentage => json!(0.5),
+        };
+        if kind == ParamKind::IntegerList {
+            json!([value])
+        } else {
+            value
+        }
+    }
+
+    fn default_config(spec: &FilterSpec) -> serde_json::Map<String, Value> {
+        let mut config: serde_json::Map<String, Value> = spec
+            .params
+            .iter()
+            .map(|param| (param.name.clone(), param.default.clone()))
+            .collect();
+        config.insert("type".to_string(), json!(spec.config_type));
+        config
+    }
+
+    #[test]
+    fn test_catalog_lists_every_filter_type_with_index() {
+        let catalog = FilterCatalog::new();
+        assert_eq!(catalog.filters.len(), BUILT_IN_FILTERS.len());
+        assert_eq!(
+            catalog
+                .filters
+                .iter()
+                .map(|spec| spec.filter_types.len())
+                .sum::<usize>(),
+            380
+        );
+
+        let bollinger = catalog.filter(&TechnicalFilterType::BollingerBand).unwrap();
+        assert_eq!(bollinger.config_type, "BOLLINGER_BAND");
+        assert_eq!(bollinger.param("period").unwrap().default, json!(20));
+
+        // 기본값은 검증을 통과하고, 정수 인덱스는 같은 이름의 타입으로 읽힘
+        for spec in &catalog.filters {
+            let config: TechnicalFilterConfig =
+                serde_json::from_value(Value::Object(default_config(spec))).unwrap();
+            assert!(
+                config.validate::<TestCandle>().is_ok(),
+                "{}",
+                spec.config_type
+            );
+
+            for variant in &spec.filter_types {
+                let mut value = default_config(spec);
+                value.insert("filter_type".to_string(), json!(variant.index));
+                let config: TechnicalFilterConfig =
+                    serde_json::from_value(Value::Object(value)).unwrap();
+                let parsed = serde_json::to_value(&config).unwrap();
+                assert_eq!(parsed["filter_type"], json!(variant.name));
+            }
+        }
+    }
+
+    #[test]
+    fn test_catalog_ranges_match_validate() {
+        for spec in FilterCatalog::new().filters {
+            let rules = rules(&spec.filter).unwrap();
+            let ranged: Vec<&ParamSpec> = spec
+                .params
+                .iter()
+                .filter(|param| param.range.is_some())
+                .collect();
+            assert_eq!(ranged.len(), rules.ranges.len(), "{}", spec.config_type);
+            assert!(
+                spec.params
+                    .iter()
+                    .all(|param| param.kind != ParamKind::Object || !param.nullable)
+            );
+
+            for param in ranged {
+                let mut value = default_config(&spec);
+                value.insert(
+                    param.name.clone(),
+                    out_of_range(param.range.unwrap(), param.kind),
+                );
+                let config: TechnicalFilterConfig =
+                    serde_json::from_value(Value::Object(value)).unwrap();
+                assert!(
+                    config.validate::<TestCandle>().is_err(),
+                    "{} {}",
+                    spec.config_type,
+                    param.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_schema_describes_params() {
+        let schema = FilterCatalog::new().json_schema();
+        assert_eq!(schema["oneOf"].as_array().unwrap().len(), 25);
+
+        let rsi = &schema["oneOf"][0];
+        assert_eq!(rsi["properties"]["type"]["const"], "RSI");
+        assert_eq!(rsi["properties"]["period"]["minimum"], json!(1.0));
+        assert_eq!(rsi["properties"]["period"]["default"], json!(14));
+        assert_eq!(rsi["properties"]["oversold"]["maximum"], json!(100.0));
+        assert_eq!(
+            rsi["properties"]["filter_type"]["oneOf"][1]["maximum"],
+            json!(22)
+        );
+        assert_eq!(rsi["additionalProperties"], json!(false));
+
+        let slope = &schema["oneOf"][16];
+        assert_eq!(
+            slope["properties"]["strength_threshold"]["type"],
+            json!(["number", "null"])
+        );
+        assert_eq!(slope["description"], "기간 순서: short_period < period");
+
+        let williams_r = &schema["oneOf"][20];
+        assert_eq!(williams_r["properties"]["type"]["const"], "WILLIAMS_R");
+        assert_eq!(
+            williams_r["properties"]["oversold"]["minimum"],
+            json!(-100.0)
+        );
+        assert_eq!(williams_r["properties"]["oversold"]["maximum"], json!(0.0));
+
+        let squeeze = &schema["oneOf"][24];
+        assert_eq!(squeeze["properties"]["type"]["const"], "SQUEEZE");
+        assert_eq!(
+            squeeze["properties"]["kc_multiplier"]["exclusiveMinimum"],
+            json!(0.0)
+        );
+        assert_eq!(
+            squeeze["properties"]["kc_multiplier"]["default"],
+            json!(1.5)
+        );
+
+        let moving_average = &schema["oneOf"][4];
+        assert_eq!(moving_average["properties"]["periods"]["minItems"], 1);
+        assert_eq!(
+            moving_average["properties"]["periods"]["items"]["minimum"],
+            json!(1.0)
+        );
+    }
+}
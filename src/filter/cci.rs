@@ -0,0 +1,164 @@
+use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{CCIFilterType, CCIParams, utils};
+use crate::analyzer::cci_analyzer::CCIAnalyzer;
+use trading_chart::Candle;
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &CCIParams) -> usize {
+    params.period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 CCI 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &CCIParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &cci_analyzer(source, params.period).items,
+        params.p..params.p + params.consecutive_n,
+        |data| [("cci", data.get_cci(params.period))],
+    )
+}
+
+fn cci_analyzer<C: Candle + 'static>(
+    source: FilterSource<'_, C>,
+    period: usize,
+) -> CachedRef<'_, CCIAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(period.to_string(), || {
+        CCIAnalyzer::new(&[period], candle_store)
+    })
+}
+
+/// 통과한 CCI 필터의 강도 (p번째 CCI가 기준선을 넘어선 정도)
+///
+/// CCI는 상하한이 없으므로 과매수/과매도는 기준선 크기만큼 더 벗어나면 1,
+/// 정상 범위는 가까운 기준선까지의 거리를 범위 절반으로 정규화합니다.
+/// 교차 타입은 None을 반환합니다.
+pub(crate) fn score_cci<C: Candle + 'static>(
+    params: &CCIParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let cci = cci_analyzer(source, params.period)
+        .items
+        .get(params.p)?
+        .get_cci(params.period);
+
+    match params.filter_type {
+        CCIFilterType::Overbought => excess_ratio(cci - params.overbought, params.overbought.abs()),
+        CCIFilterType::Oversold => excess_ratio(params.oversold - cci, params.oversold.abs()),
+        CCIFilterType::NormalRange => excess_ratio(
+            (cci - params.oversold).min(params.overbought - cci),
+            (params.overbought - params.oversold) / 2.0,
+        ),
+        _ => None,
+    }
+}
+
+/// 개별 코인에 대한 CCI 필터 적용
+pub(crate) fn filter_cci<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &CCIParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "CCI 필터 적용 - 기간: {}, 과매수: {:.2}, 과매도: {:.2}, 타입: {:?}, 연속성: {}",
+        params.period,
+        params.overbought,
+        params.oversold,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    utils::validate_period(params.period, "CCI")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let analyzer = cci_analyzer(source, params.period);
+    let (period, n, p) = (params.period, params.consecutive_n, params.p);
+
+    let result = match params.filter_type {
+        CCIFilterType::Overbought => analyzer.is_cci_greater_than(period, params.overbought, n, p),
+        CCIFilterType::Oversold => analyzer.is_cci_less_than(period, params.oversold, n, p),
+        CCIFilterType::NormalRange => {
+            analyzer.is_cci_in_range(period, params.oversold, params.overbought, n, p)
+        }
+        CCIFilterType::CrossAboveOversold => {
+            analyzer.is_cci_cross_above(period, params.oversold, n, 1, p)
+        }
+        CCIFilterType::CrossBelowOverbought => {
+            analyzer.is_cci_cross_below(period, params.overbought, n, 1, p)
+        }
+        CCIFilterType::CrossAboveZero => analyzer.is_cci_cross_above(period, 0.0, n, 1, p),
+        CCIFilterType::CrossBelowZero => analyzer.is_cci_cross_below(period, 0.0, n, 1, p),
+    };
+
+    log::debug!("코인 {coin} CCI 필터 결과: {result}");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn candles_from_closes(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: *close,
+                low: *close,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    fn params(filter_type: CCIFilterType) -> CCIParams {
+        CCIParams {
+            period: 4,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cci_overbought_and_oversold() {
+        let candles = candles_from_closes(&[100.0, 100.0, 100.0, 100.0, 110.0]);
+        let candle_store = utils::create_candle_store(&candles);
+        let overbought = params(CCIFilterType::Overbought);
+        assert!(filter_cci("TEST", &overbought, &candle_store, 110.0).unwrap());
+
+        let candles = candles_from_closes(&[100.0, 100.0, 100.0, 100.0, 90.0]);
+        let candle_store = utils::create_candle_store(&candles);
+        let oversold = params(CCIFilterType::Oversold);
+        assert!(filter_cci("TEST", &oversold, &candle_store, 90.0).unwrap());
+        assert!(!filter_cci("TEST", &overbought, &candle_store, 90.0).unwrap());
+    }
+
+    #[test]
+    fn test_cci_cross_above_zero() {
+        let candles = candles_from_closes(&[104.0, 102.0, 100.0, 98.0, 104.0]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        let cross = params(CCIFilterType::CrossAboveZero);
+        assert!(filter_cci("TEST", &cross, &candle_store, 104.0).unwrap());
+        let cross_below = params(CCIFilterType::CrossBelowZero);
+        assert!(!filter_cci("TEST", &cross_below, &candle_store, 104.0).unwrap());
+    }
+}
@@ -0,0 +1,316 @@
+// 비교 필터
+// 두 지표 값(또는 상수)을 `lhs op rhs` 로 비교하거나 교차 여부를 확인합니다.
+// 지표 값은 기울기 필터와 같은 `IndicatorType` 시계열을 사용하므로 캐시 항목도 공유합니다.
+
+use super::Result;
+use super::cache::FilterSource;
+use super::report::OffsetValues;
+use super::slope::indicator_analyzer;
+use super::{CompareFilterType, CompareOperand, CompareParams, utils};
+use crate::analyzer::IndicatorType;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use trading_chart::Candle;
+
+/// 피연산자 지표 계산에 필요한 캔들 수 (상수는 1)
+fn operand_period(operand: &CompareOperand) -> usize {
+    match operand {
+        CompareOperand::Constant(_) => 1,
+        CompareOperand::Indicator(indicator_type) => match indicator_type {
+            IndicatorType::ClosePrice | IndicatorType::HighPrice | IndicatorType::LowPrice => 1,
+            IndicatorType::MovingAverage { period, .. } | IndicatorType::RSI { period, .. } => {
+                *period
+            }
+            IndicatorType::MACD {
+                slow_period,
+                signal_period,
+                ..
+            }
+            | IndicatorType::MACDLine {
+                slow_period,
+                signal_period,
+                ..
+            }
+            | IndicatorType::MACDSignalLine {
+                slow_period,
+                signal_period,
+                ..
+            }
+            | IndicatorType::MACDHistogram {
+                slow_period,
+                signal_period,
+                ..
+            } => slow_period + signal_period,
+            IndicatorType::VWAP { period } => (*period).max(1),
+        },
+    }
+}
+
+/// 판단에 사용하는 연속 캔들 수 (교차는 현재/이전 두 캔들)
+fn lookback(params: &CompareParams) -> usize {
+    match params.filter_type {
+        CompareFilterType::CrossAbove | CompareFilterType::CrossBelow => 2,
+        _ => params.consecutive_n,
+    }
+}
+
+fn offsets(params: &CompareParams) -> Range<usize> {
+    params.p..params.p + lookback(params)
+}
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &CompareParams) -> usize {
+    operand_period(&params.lhs).max(operand_period(&params.rhs)) + params.p + lookback(params)
+}
+
+/// 피연산자 파라미터 검증
+pub(crate) fn validate_operand(operand: &CompareOperand, param_name: &str) -> Result<()> {
+    let CompareOperand::Indicator(indicator_type) = operand else {
+        return Ok(());
+    };
+
+    match indicator_type {
+        IndicatorType::MovingAverage { period, .. } | IndicatorType::RSI { period, .. } => {
+            utils::validate_period(*period, &format!("{param_name} period"))
+        }
+        IndicatorType::MACD {
+            fast_period,
+            slow_period,
+            signal_period,
+        }
+        | IndicatorType::MACDLine {
+            fast_period,
+            slow_period,
+            signal_period,
+        }
+        | IndicatorType::MACDSignalLine {
+            fast_period,
+            slow_period,
+            signal_period,
+        }
+        | IndicatorType::MACDHistogram {
+            fast_period,
+            slow_period,
+            signal_period,
+        } => {
+            utils::validate_period(*fast_period, &format!("{param_name} fast_period"))?;
+            utils::validate_period(*slow_period, &format!("{param_name} slow_period"))?;
+            utils::validate_period(*signal_period, &format!("{param_name} signal_period"))?;
+            utils::validate_period_order(
+                *fast_period,
+                "fast_period",
+                *slow_period,
+                "slow_period",
+                param_name,
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 오프셋 범위의 피연산자 값 (계산된 항목이 부족하면 앞부분만 반환)
+fn operand_values<C: Candle + 'static>(
+    source: FilterSource<'_, C>,
+    operand: &CompareOperand,
+    offsets: Range<usize>,
+) -> Vec<f64> {
+    match operand {
+        CompareOperand::Constant(value) => offsets.map(|_| *value).collect(),
+        CompareOperand::Indicator(indicator_type) => indicator_analyzer(source, indicator_type)
+            .items
+            .iter()
+            .skip(offsets.start)
+            .take(offsets.len())
+            .map(|data| data.value)
+            .collect(),
+    }
+}
+
+/// 확인한 오프셋별 좌변/우변 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &CompareParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    let offsets = offsets(params);
+    let lhs = operand_values(source, &params.lhs, offsets.clone());
+    let rhs = operand_values(source, &params.rhs, offsets.clone());
+
+    offsets
+        .zip(lhs.into_iter().zip(rhs))
+        .map(|(offset, (lhs, rhs))| OffsetValues {
+            offset,
+            values: [("lhs".to_string(), lhs), ("rhs".to_string(), rhs)]
+                .into_iter()
+                .collect(),
+            labels: BTreeMap::new(),
+        })
+        .collect()
+}
+
+/// 개별 코인에 대한 비교 필터 적용
+pub(crate) fn filter_compare<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &CompareParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "비교 필터 적용 - 좌변: {:?}, 우변: {:?}, 타입: {:?}, 연속성: {}",
+        params.lhs,
+        params.rhs,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    validate_operand(&params.lhs, "Compare lhs")?;
+    validate_operand(&params.rhs, "Compare rhs")?;
+    utils::validate_consecutive_n(params.consecutive_n, "Compare consecutive_n")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let lookback = lookback(params);
+    let lhs = operand_values(source, &params.lhs, offsets(params));
+    let rhs = operand_values(source, &params.rhs, offsets(params));
+    if lhs.len() < lookback || rhs.len() < lookback {
+        return Ok(false);
+    }
+
+    // 값은 최신순이므로 교차는 [0]이 현재, [1]이 이전 캔들
+    let pairs = || lhs.iter().zip(rhs.iter());
+    let result = match params.filter_type {
+        CompareFilterType::Greater => pairs().all(|(lhs, rhs)| lhs > rhs),
+        CompareFilterType::GreaterOrEqual => pairs().all(|(lhs, rhs)| lhs >= rhs),
+        CompareFilterType::Less => pairs().all(|(lhs, rhs)| lhs < rhs),
+        CompareFilterType::LessOrEqual => pairs().all(|(lhs, rhs)| lhs <= rhs),
+        CompareFilterType::CrossAbove => lhs[0] > rhs[0] && lhs[1] <= rhs[1],
+        CompareFilterType::CrossBelow => lhs[0] < rhs[0] && lhs[1] >= rhs[1],
+    };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{TechnicalFilter, TechnicalFilterConfig};
+    use crate::indicator::ma::MAType;
+    use crate::tests::{TestCandle, test_candles};
+    use serde::Deserialize;
+
+    fn sma(period: usize) -> CompareOperand {
+        CompareOperand::Indicator(IndicatorType::MovingAverage {
+            ma_type: MAType::SMA,
+            period,
+        })
+    }
+
+    #[test]
+    fn test_compare_close_crosses_above_sma() {
+        // 이전 캔들: 종가 9 <= SMA(3) 9.67, 현재 캔들: 종가 12 > SMA(3) 10.33
+        let candles = test_candles(&[10.0, 10.0, 10.0, 10.0, 9.0, 12.0]);
+        let candle_store = utils::create_candle_store(&candles);
+        let mut params = CompareParams {
+            lhs: CompareOperand::Indicator(IndicatorType::ClosePrice),
+            rhs: sma(3),
+            filter_type: CompareFilterType::CrossAbove,
+            ..Default::default()
+        };
+        assert_eq!(required_candles(&params), 5);
+        assert!(filter_compare("TEST/USDT", &params, &candle_store, 12.0).unwrap());
+
+        params.filter_type = CompareFilterType::CrossBelow;
+        assert!(!filter_compare("TEST/USDT", &params, &candle_store, 12.0).unwrap());
+
+        // 한 캔들 전에는 교차가 없음
+        params.filter_type = CompareFilterType::CrossAbove;
+        params.p = 1;
+        assert!(!filter_compare("TEST/USDT", &params, &candle_store, 12.0).unwrap());
+    }
+
+    #[test]
+    fn test_compare_with_constant_checks_consecutive_candles() {
+        let params = CompareParams {
+            lhs: CompareOperand::Indicator(IndicatorType::ClosePrice),
+            rhs: CompareOperand::Constant(100.0),
+            filter_type: CompareFilterType::Greater,
+            consecutive_n: 2,
+            p: 0,
+        };
+
+        let above = utils::create_candle_store(&test_candles(&[98.0, 101.0, 102.0]));
+        let crossed = utils::create_candle_store(&test_candles(&[98.0, 99.0, 102.0]));
+        assert!(filter_compare("TEST/USDT", &params, &above, 102.0).unwrap());
+        assert!(!filter_compare("TEST/USDT", &params, &crossed, 102.0).unwrap());
+
+        let values = report_values(&params, (&crossed).into());
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[1].values["lhs"], 99.0);
+        assert_eq!(values[1].values["rhs"], 100.0);
+    }
+
+    #[test]
+    fn test_compare_loads_from_toml() {
+        #[derive(Deserialize)]
+        struct FiltersConfig {
+            filters: Vec<TechnicalFilterConfig>,
+        }
+
+        let config: FiltersConfig = toml::from_str(
+            r#"
+[[filters]]
+type = "COMPARE"
+lhs = { type = "MovingAverage", ma_type = "EMA", period = 20 }
+filter_type = "CrossAbove"
+rhs = { type = "VWAP", period = 50 }
+
+[[filters]]
+type = "COMPARE"
+lhs = { type = "RSI", period = 7, ma_type = "SMA", ma_periods = [7] }
+filter_type = "Greater"
+rhs = 70
+"#,
+        )
+        .unwrap();
+
+        let TechnicalFilterConfig::Compare(cross) = &config.filters[0] else {
+            panic!("비교 필터로 읽어야 함");
+        };
+        assert_eq!(
+            cross.rhs,
+            CompareOperand::Indicator(IndicatorType::VWAP { period: 50 })
+        );
+        assert_eq!(required_candles(cross), 52);
+
+        let TechnicalFilterConfig::Compare(threshold) = &config.filters[1] else {
+            panic!("비교 필터로 읽어야 함");
+        };
+        assert_eq!(threshold.rhs, CompareOperand::Constant(70.0));
+        assert!(
+            config
+                .filters
+                .iter()
+                .all(|filter| filter.validate::<TestCandle>().is_ok())
+        );
+
+        // 캔들이 부족하면 통과시키지 않음
+        let candles = test_candles(&[100.0; 10]);
+        assert!(
+            !TechnicalFilter::matches_filter("TEST/USDT", &config.filters[0], &candles, 100.0)
+                .unwrap()
+        );
+
+        let invalid = TechnicalFilterConfig::Compare(CompareParams {
+            lhs: sma(0),
+            ..Default::default()
+        });
+        assert!(invalid.validate::<TestCandle>().is_err());
+    }
+}
@@ -1,9 +1,11 @@
 use super::Result;
+use super::report::{OffsetValues, offset_values};
 use std::fmt;
 use trading_chart::Candle;
 
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::bband_analyzer::BBandAnalyzer;
+use crate::analyzer::rsi_analyzer::RSIAnalyzer;
 use crate::candle_store::CandleStore;
 use crate::indicator::ma::MAType;
 use crate::strategy::copys_common::{
@@ -17,6 +19,49 @@ fn default_copys_ma_periods() -> Vec<usize> {
     vec![5, 20, 60, 120, 200, 240]
 }
 
+/// 필터에 사용할 이동평균 기간 (설정하지 않으면 기본 기간)
+fn ma_periods(params: &CopysParams) -> Vec<usize> {
+    if params.ma_periods.is_empty() {
+        default_copys_ma_periods()
+    } else {
+        params.ma_periods.clone()
+    }
+}
+
+/// 확인한 오프셋별 RSI, 이동평균, 볼린저 밴드 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &CopysParams,
+    candle_store: &CandleStore<C>,
+) -> Vec<OffsetValues> {
+    let ma_periods = ma_periods(params);
+    let rsi_analyzer = RSIAnalyzer::new(params.rsi_period, &MAType::EMA, &ma_periods, candle_store);
+    let bband_analyzer =
+        BBandAnalyzer::new(params.bband_period, params.bband_multiplier, candle_store);
+
+    let mut values = offset_values(
+        &rsi_analyzer.items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            ma_periods
+                .iter()
+                .map(|period| (format!("ma_{period}"), data.mas.get(period).get()))
+                .chain([("rsi".to_string(), data.rsi.value())])
+                .collect::<Vec<_>>()
+        },
+    );
+    for entry in &mut values {
+        if let Some(data) = bband_analyzer.items.get(entry.offset) {
+            entry
+                .values
+                .insert("bband_upper".to_string(), data.bband.upper());
+            entry
+                .values
+                .insert("bband_lower".to_string(), data.bband.lower());
+        }
+    }
+    values
+}
+
 /// CopyS 모의 전략 (필터 사용을 위한 임시 객체)
 struct CopysFilter<C: Candle> {
     ctx: CopysStrategyContext<C>,
@@ -131,11 +176,7 @@ pub(crate) fn filter_copys<C: Candle + 'static>(
 
     // MAType 설정
     let ma_type = MAType::EMA;
-    let ma_periods = if params.ma_periods.is_empty() {
-        default_copys_ma_periods()
-    } else {
-        params.ma_periods.clone()
-    };
+    let ma_periods = ma_periods(params);
 
     // 전략 컨텍스트 생성 (CandleStore 재사용)
     let ctx = match create_strategy_context_for_filter_with_store(
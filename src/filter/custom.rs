@@ -0,0 +1,487 @@
+// 사용자 정의 필터
+// 크레이트를 수정하지 않고 외부 필터(펀딩비, 온체인 지표 등)를 `type` 문자열로 등록합니다.
+// 등록된 필터는 기본 필터와 같은 `[[filters]]` 설정으로 읽고
+// `TechnicalFilter::matches_filters`에서 기본 필터와 함께 평가합니다.
+
+use super::report::{OffsetValues, offset_values};
+use super::{FilterError, FilterGroup, Result, TechnicalFilterType, utils};
+use crate::candle_store::CandleStore;
+use serde::de::DeserializeOwned;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock, RwLock};
+use trading_chart::Candle;
+
+/// 사용자 정의 필터 설정
+///
+/// 설정 파일의 `type` 값과 나머지 키를 그대로 보관합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomFilterConfig {
+    /// 등록된 필터 타입 (`type` 값)
+    pub filter_type: String,
+    /// `type`을 제외한 설정 값
+    pub params: Map<String, Value>,
+}
+
+impl CustomFilterConfig {
+    pub fn new(filter_type: impl Into<String>, params: Map<String, Value>) -> Self {
+        Self {
+            filter_type: filter_type.into(),
+            params,
+        }
+    }
+
+    /// 설정 값을 필터 파라미터 구조체로 변환
+    pub fn params<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_value(Value::Object(self.params.clone())).map_err(|e| {
+            FilterError::InvalidCustomFilterParams {
+                filter_type: self.filter_type.clone(),
+                message: e.to_string(),
+            }
+        })
+    }
+
+    /// 확인하는 오프셋 범위 (`p..p+consecutive_n`)
+    ///
+    /// 기본 필터와 같은 `p`, `consecutive_n` 설정 값을 사용하며, 없으면 각각 0과 1입니다.
+    pub fn offsets(&self) -> Range<usize> {
+        let setting = |key: &str, default: usize| {
+            self.params
+                .get(key)
+                .and_then(Value::as_u64)
+                .map_or(default, |value| value as usize)
+        };
+        let p = setting("p", 0);
+        p..p + setting("consecutive_n", 1)
+    }
+}
+
+/// `type`과 설정 값을 한 객체로 직렬화 (설정 파일과 같은 형태)
+impl Serialize for CustomFilterConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.params.len() + 1))?;
+        map.serialize_entry("type", &self.filter_type)?;
+        for (key, value) in self.params.iter().filter(|(key, _)| *key != "type") {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+/// 사용자 정의 필터
+///
+/// 캔들 타입별로 등록하며, 등록한 캔들 타입으로 평가할 때만 사용됩니다.
+pub trait CustomFilter<C: Candle>: Send + Sync {
+    /// 파라미터 검증 (기본값: 검증 없음)
+    fn validate(&self, _config: &CustomFilterConfig) -> Result<()> {
+        Ok(())
+    }
+
+    /// 평가에 필요한 최소 캔들 수 (기본값: 확인하는 오프셋 범위의 끝)
+    ///
+    /// 보유 캔들 수가 이보다 적으면 `matches_filter`를 호출하지 않고 통과시키지 않습니다.
+    fn required_candles(&self, config: &CustomFilterConfig) -> usize {
+        config.offsets().end
+    }
+
+    /// 필터 적용
+    fn matches_filter(
+        &self,
+        symbol: &str,
+        config: &CustomFilterConfig,
+        candle_store: &CandleStore<C>,
+        current_price: f64,
+    ) -> Result<bool>;
+
+    /// 평가 리포트에 남길 오프셋별 값 (기본값: 확인하는 오프셋별 종가)
+    fn report_values(
+        &self,
+        config: &CustomFilterConfig,
+        candle_store: &CandleStore<C>,
+        _current_price: f64,
+    ) -> Vec<OffsetValues> {
+        offset_values(candle_store.items(), config.offsets(), |candle| {
+            [("close", candle.close_price())]
+        })
+    }
+}
+
+type Registry = HashMap<(String, TypeId), Box<dyn Any + Send + Sync>>;
+
+fn registry() -> &'static RwLock<Registry> {
+    static REGISTRY: OnceLock<RwLock<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// 사용자 정의 필터 등록소
+///
+/// 프로세스 전역에서 공유합니다. 설정 파일을 읽기 전에 등록해야 `type` 값을 인식합니다.
+pub struct CustomFilterRegistry;
+
+impl CustomFilterRegistry {
+    /// 필터 등록 (같은 타입과 캔들 타입으로 등록된 필터는 교체)
+    ///
+    /// 기본 필터나 조건식 그룹의 `type` 값과 같은 이름은 등록할 수 없습니다.
+    pub fn register<C: Candle + 'static>(
+        filter_type: impl Into<String>,
+        filter: impl CustomFilter<C> + 'static,
+    ) -> Result<()> {
+        let filter_type = filter_type.into();
+        if filter_type.is_empty()
+            || TechnicalFilterType::from_str(&filter_type).is_ok()
+            || FilterGroup::TYPES.contains(&filter_type.as_str())
+        {
+            return Err(FilterError::ReservedCustomFilterType { filter_type });
+        }
+
+        let filter: Arc<dyn CustomFilter<C>> = Arc::new(filter);
+        registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert((filter_type, TypeId::of::<C>()), Box::new(filter));
+        Ok(())
+    }
+
+    /// 필터 등록 해제
+    pub fn unregister<C: Candle + 'static>(filter_type: &str) -> bool {
+        registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&(filter_type.to_string(), TypeId::of::<C>()))
+            .is_some()
+    }
+
+    /// 캔들 타입 `C`로 등록된 타입인지 확인
+    pub fn is_registered<C: Candle + 'static>(filter_type: &str) -> bool {
+        registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains_key(&(filter_type.to_string(), TypeId::of::<C>()))
+    }
+
+    /// 캔들 타입과 관계없이 등록된 타입인지 확인
+    ///
+    /// 설정을 읽을 때는 캔들 타입을 알 수 없으므로 `type` 값 인식에만 사용하고,
+    /// 캔들 타입별 등록 여부는 `TechnicalFilterConfig::validate`에서 확인합니다.
+    pub(crate) fn is_registered_any(filter_type: &str) -> bool {
+        registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .any(|(registered, _)| registered == filter_type)
+    }
+
+    fn get<C: Candle + 'static>(filter_type: &str) -> Option<Arc<dyn CustomFilter<C>>> {
+        registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&(filter_type.to_string(), TypeId::of::<C>()))
+            .and_then(|filter| filter.downcast_ref::<Arc<dyn CustomFilter<C>>>())
+            .cloned()
+    }
+}
+
+/// 사용자 정의 필터 적용
+pub(crate) fn filter_custom<C: Candle + 'static>(
+    symbol: &str,
+    config: &CustomFilterConfig,
+    candle_store: &CandleStore<C>,
+    current_price: f64,
+) -> Result<bool> {
+    let filter = CustomFilterRegistry::get::<C>(&config.filter_type).ok_or_else(|| {
+        FilterError::UnregisteredCustomFilter {
+            filter_type: config.filter_type.clone(),
+        }
+    })?;
+
+    filter.validate(config)?;
+    if !utils::check_sufficient_candles(candle_store.len(), filter.required_candles(config), symbol)
+    {
+        return Ok(false);
+    }
+    filter.matches_filter(symbol, config, candle_store, current_price)
+}
+
+/// 사용자 정의 필터의 최소 캔들 수 (등록되지 않은 타입은 0)
+pub(crate) fn required_candles<C: Candle + 'static>(config: &CustomFilterConfig) -> usize {
+    CustomFilterRegistry::get::<C>(&config.filter_type)
+        .map_or(0, |filter| filter.required_candles(config))
+}
+
+/// 사용자 정의 필터의 리포트 값 (등록되지 않은 타입은 빈 목록)
+pub(crate) fn report_values<C: Candle + 'static>(
+    config: &CustomFilterConfig,
+    candle_store: &CandleStore<C>,
+    current_price: f64,
+) -> Vec<OffsetValues> {
+    CustomFilterRegistry::get::<C>(&config.filter_type)
+        .map(|filter| filter.report_values(config, candle_store, current_price))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{TechnicalFilter, TechnicalFilterConfig};
+    use crate::tests::{TestCandle, test_candles};
+    use chrono::{DateTime, Utc};
+    use serde::Deserialize;
+    use trading_chart::CandleInterval;
+
+    #[derive(Deserialize)]
+    struct FiltersConfig {
+        filters: Vec<TechnicalFilterConfig>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct CloseAboveParams {
+        min_close: f64,
+    }
+
+    // 등록한 캔들 타입과 구분하기 위한 다른 캔들 타입
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct OtherCandle(TestCandle);
+
+    impl std::fmt::Display for OtherCandle {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Other{}", self.0)
+        }
+    }
+
+    impl Candle for OtherCandle {
+        fn open_price(&self) -> f64 {
+            self.0.open_price()
+        }
+        fn high_price(&self) -> f64 {
+            self.0.high_price()
+        }
+        fn low_price(&self) -> f64 {
+            self.0.low_price()
+        }
+        fn close_price(&self) -> f64 {
+            self.0.close_price()
+        }
+        fn market(&self) -> &str {
+            self.0.market()
+        }
+        fn datetime(&self) -> DateTime<Utc> {
+            self.0.datetime()
+        }
+        fn interval(&self) -> &CandleInterval {
+            self.0.interval()
+        }
+        fn volume(&self) -> f64 {
+            self.0.volume()
+        }
+        fn quote_volume(&self) -> f64 {
+            self.0.quote_volume()
+        }
+        fn trade_count(&self) -> Option<u64> {
+            self.0.trade_count()
+        }
+    }
+
+    // 최신 캔들 종가가 min_close 이상인지 확인하는 필터
+    struct CloseAbove;
+
+    impl CustomFilter<TestCandle> for CloseAbove {
+        fn validate(&self, config: &CustomFilterConfig) -> Result<()> {
+            config.params::<CloseAboveParams>().map(|_| ())
+        }
+
+        fn matches_filter(
+            &self,
+            _symbol: &str,
+            config: &CustomFilterConfig,
+            candle_store: &CandleStore<TestCandle>,
+            _current_price: f64,
+        ) -> Result<bool> {
+            let params: CloseAboveParams = config.params()?;
+            Ok(candle_store
+                .first()
+                .is_some_and(|candle| candle.close >= params.min_close))
+        }
+    }
+
+    #[test]
+    fn test_custom_filter_loads_from_toml_and_evaluates_with_builtins() {
+        CustomFilterRegistry::register("TEST_CLOSE_ABOVE", CloseAbove).unwrap();
+
+        let config: FiltersConfig = toml::from_str(
+            r#"
+[[filters]]
+type = "TEST_CLOSE_ABOVE"
+min_close = 105.0
+
+[[filters]]
+type = "PRICE_REFERENCE_GAP"
+filter_type = "GapAboveReferenceThreshold"
+gap_threshold = 0.05
+reference_source = { type = "MOVING_AVERAGE", ma_type = "SMA", period = 3 }
+"#,
+        )
+        .unwrap();
+
+        let TechnicalFilterConfig::Custom(custom) = &config.filters[0] else {
+            panic!("사용자 정의 필터로 읽어야 함");
+        };
+        assert_eq!(custom.filter_type, "TEST_CLOSE_ABOVE");
+        assert_eq!(
+            config.filters[0].filter_type().to_string(),
+            "TEST_CLOSE_ABOVE"
+        );
+
+        let rising = test_candles(&[100.0, 100.0, 110.0]);
+        let falling = test_candles(&[110.0, 110.0, 100.0]);
+        assert!(
+            TechnicalFilter::matches_filters("TEST/USDT", &config.filters, &rising, 110.0).unwrap()
+        );
+        assert!(
+            !TechnicalFilter::matches_filters("TEST/USDT", &config.filters, &falling, 100.0)
+                .unwrap()
+        );
+
+        // 설정 파일과 같은 형태로 직렬화
+        let json = serde_json::to_value(&config.filters[0]).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "TEST_CLOSE_ABOVE", "min_close": 105.0})
+        );
+
+        assert!(CustomFilterRegistry::unregister::<TestCandle>(
+            "TEST_CLOSE_ABOVE"
+        ));
+    }
+
+    // 항상 통과하는 필터 (리포트 기본 구현 확인용)
+    struct AlwaysPass;
+
+    impl CustomFilter<TestCandle> for AlwaysPass {
+        fn matches_filter(
+            &self,
+            _symbol: &str,
+            _config: &CustomFilterConfig,
+            _candle_store: &CandleStore<TestCandle>,
+            _current_price: f64,
+        ) -> Result<bool> {
+            Ok(true)
+        }
+    }
+
+    #[test]
+    fn test_custom_filter_report_covers_checked_offsets() {
+        CustomFilterRegistry::register("TEST_ALWAYS_PASS_REPORT", AlwaysPass).unwrap();
+
+        let filter: TechnicalFilterConfig = serde_json::from_str(
+            r#"{"type": "TEST_ALWAYS_PASS_REPORT", "p": 1, "consecutive_n": 2}"#,
+        )
+        .unwrap();
+        let candles = test_candles(&[100.0, 101.0, 102.0, 103.0]);
+        let report = TechnicalFilter::matches_filters_with_report(
+            "TEST/USDT",
+            &[filter.clone()],
+            &candles,
+            103.0,
+        );
+
+        let offsets = &report.filters[0].offsets;
+        assert_eq!(
+            offsets.iter().map(|o| o.offset).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(offsets[0].values["close"], 102.0);
+        assert_eq!(offsets[1].values["close"], 101.0);
+        assert_eq!(report.filters[0].required_candles, 3);
+
+        // 확인하는 오프셋까지 캔들이 없으면 통과시키지 않음
+        let short = test_candles(&[100.0, 101.0]);
+        assert!(!TechnicalFilter::matches_filter("TEST/USDT", &filter, &short, 101.0).unwrap());
+
+        assert!(CustomFilterRegistry::unregister::<TestCandle>(
+            "TEST_ALWAYS_PASS_REPORT"
+        ));
+    }
+
+    #[test]
+    fn test_custom_filter_reports_invalid_params() {
+        CustomFilterRegistry::register("TEST_CLOSE_ABOVE_PARAMS", CloseAbove).unwrap();
+
+        let filter: TechnicalFilterConfig =
+            serde_json::from_str(r#"{"type": "TEST_CLOSE_ABOVE_PARAMS", "min_closee": 1.0}"#)
+                .unwrap();
+        let candles = test_candles(&[100.0]);
+
+        let err =
+            TechnicalFilter::matches_filter("TEST/USDT", &filter, &candles, 100.0).unwrap_err();
+        assert!(
+            matches!(err, FilterError::InvalidCustomFilterParams { .. }),
+            "{err}"
+        );
+
+        assert!(CustomFilterRegistry::unregister::<TestCandle>(
+            "TEST_CLOSE_ABOVE_PARAMS"
+        ));
+    }
+
+    #[test]
+    fn test_custom_filter_is_registered_per_candle_type() {
+        CustomFilterRegistry::register("TEST_CANDLE_TYPE", CloseAbove).unwrap();
+
+        // 설정은 읽히지만 다른 캔들 타입으로는 검증과 평가가 실패
+        let filter: TechnicalFilterConfig = serde_json::from_str(
+            r#"{"type": "TEST_CANDLE_TYPE", "min_close": 1.0, "consecutive_n": 2}"#,
+        )
+        .unwrap();
+        assert!(CustomFilterRegistry::is_registered::<TestCandle>(
+            "TEST_CANDLE_TYPE"
+        ));
+        assert!(!CustomFilterRegistry::is_registered::<OtherCandle>(
+            "TEST_CANDLE_TYPE"
+        ));
+        assert!(filter.validate::<TestCandle>().is_ok());
+        assert!(matches!(
+            filter.validate::<OtherCandle>(),
+            Err(FilterError::UnregisteredCustomFilter { .. })
+        ));
+        assert_eq!(filter.required_candles::<TestCandle>(), 2);
+        assert_eq!(filter.required_candles::<OtherCandle>(), 0);
+
+        let other: Vec<OtherCandle> = test_candles(&[100.0, 100.0])
+            .into_iter()
+            .map(OtherCandle)
+            .collect();
+        assert!(TechnicalFilter::matches_filter("TEST/USDT", &filter, &other, 100.0).is_err());
+
+        assert!(CustomFilterRegistry::unregister::<TestCandle>(
+            "TEST_CANDLE_TYPE"
+        ));
+    }
+
+    #[test]
+    fn test_custom_filter_registry_rejects_reserved_and_unknown_types() {
+        assert!(CustomFilterRegistry::register("RSI", CloseAbove).is_err());
+        assert!(CustomFilterRegistry::register("ANY", CloseAbove).is_err());
+
+        // 등록되지 않은 타입은 기존과 같이 알 수 없는 필터 타입 오류
+        assert!(
+            serde_json::from_str::<TechnicalFilterConfig>(r#"{"type": "TEST_NOT_REGISTERED"}"#)
+                .is_err()
+        );
+
+        // 등록 해제 후 평가하면 오류
+        CustomFilterRegistry::register("TEST_UNREGISTERED_LATER", CloseAbove).unwrap();
+        let filter: TechnicalFilterConfig =
+            serde_json::from_str(r#"{"type": "TEST_UNREGISTERED_LATER", "min_close": 1.0}"#)
+                .unwrap();
+        assert!(CustomFilterRegistry::unregister::<TestCandle>(
+            "TEST_UNREGISTERED_LATER"
+        ));
+        assert!(filter.validate::<TestCandle>().is_err());
+    }
+}
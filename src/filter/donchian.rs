@@ -0,0 +1,159 @@
+use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{DonchianFilterType, DonchianParams, utils};
+use crate::analyzer::donchian_analyzer::DonchianAnalyzer;
+use trading_chart::Candle;
+
+/// 필터 평가에 필요한 최소 캔들 수
+///
+/// 돌파는 직전 캔들까지의 채널과 비교하므로 채널 기간에 연속 캔들 수를 더합니다.
+pub(crate) fn required_candles(params: &DonchianParams) -> usize {
+    params.period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 돈치안 채널 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &DonchianParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &donchian_analyzer(source, params.period).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let channel = data.get_donchian(params.period);
+            [
+                ("lower", channel.lower()),
+                ("middle", channel.middle()),
+                ("upper", channel.upper()),
+            ]
+        },
+    )
+}
+
+fn donchian_analyzer<C: Candle + 'static>(
+    source: FilterSource<'_, C>,
+    period: usize,
+) -> CachedRef<'_, DonchianAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(period.to_string(), || {
+        DonchianAnalyzer::new(&[period], candle_store)
+    })
+}
+
+/// 통과한 돈치안 채널 필터의 강도 (p번째 종가가 채널 기준선을 넘어선 정도)
+///
+/// 돌파와 채널 내부는 직전 채널 폭, 중심선 기준은 현재 채널의 중심선과
+/// 상/하단 사이 폭으로 정규화합니다.
+pub(crate) fn score_donchian<C: Candle + 'static>(
+    params: &DonchianParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let analyzer = donchian_analyzer(source, params.period);
+    let data = analyzer.items.get(params.p)?;
+    let close = data.candle.close_price();
+    let channel = data.get_donchian(params.period);
+    let previous = analyzer
+        .items
+        .get(params.p + 1)?
+        .get_donchian(params.period);
+    let previous_width = previous.upper() - previous.lower();
+
+    match params.filter_type {
+        DonchianFilterType::BreakoutUp => excess_ratio(close - previous.upper(), previous_width),
+        DonchianFilterType::BreakoutDown => excess_ratio(previous.lower() - close, previous_width),
+        DonchianFilterType::InsideChannel => excess_ratio(
+            (close - previous.lower()).min(previous.upper() - close),
+            previous_width / 2.0,
+        ),
+        DonchianFilterType::AboveMiddle => {
+            excess_ratio(close - channel.middle(), channel.upper() - channel.middle())
+        }
+        DonchianFilterType::BelowMiddle => {
+            excess_ratio(channel.middle() - close, channel.middle() - channel.lower())
+        }
+    }
+}
+
+/// 개별 코인에 대한 Donchian 필터 적용
+pub(crate) fn filter_donchian<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &DonchianParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "Donchian 필터 적용 - 기간: {}, 타입: {:?}, 연속성: {}",
+        params.period,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    utils::validate_period(params.period, "Donchian")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let analyzer = donchian_analyzer(source, params.period);
+    let (period, n, p) = (params.period, params.consecutive_n, params.p);
+
+    let result = match params.filter_type {
+        DonchianFilterType::BreakoutUp => analyzer.is_breakout_up(period, n, p),
+        DonchianFilterType::BreakoutDown => analyzer.is_breakout_down(period, n, p),
+        DonchianFilterType::InsideChannel => analyzer.is_inside_channel(period, n, p),
+        DonchianFilterType::AboveMiddle => analyzer.is_above_middle(period, n, p),
+        DonchianFilterType::BelowMiddle => analyzer.is_below_middle(period, n, p),
+    };
+
+    log::debug!("코인 {coin} Donchian 필터 결과: {result}");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_candles;
+
+    fn params(filter_type: DonchianFilterType) -> DonchianParams {
+        DonchianParams {
+            period: 3,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_donchian_breakout_up() {
+        // 직전 채널 상단 102 위에서 종가 106으로 마감
+        let candles = test_candles(&[100.0, 101.0, 100.0, 101.0, 100.0, 106.0]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        let breakout = params(DonchianFilterType::BreakoutUp);
+        assert!(filter_donchian("TEST", &breakout, &candle_store, 106.0).unwrap());
+        let breakdown = params(DonchianFilterType::BreakoutDown);
+        assert!(!filter_donchian("TEST", &breakdown, &candle_store, 106.0).unwrap());
+        let inside = params(DonchianFilterType::InsideChannel);
+        assert!(!filter_donchian("TEST", &inside, &candle_store, 106.0).unwrap());
+        let above_middle = params(DonchianFilterType::AboveMiddle);
+        assert!(filter_donchian("TEST", &above_middle, &candle_store, 106.0).unwrap());
+    }
+
+    #[test]
+    fn test_donchian_inside_channel() {
+        let candles = test_candles(&[100.0, 101.0, 100.0, 101.0, 100.0, 100.5]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        let inside = params(DonchianFilterType::InsideChannel);
+        assert!(filter_donchian("TEST", &inside, &candle_store, 100.5).unwrap());
+        let breakout = params(DonchianFilterType::BreakoutUp);
+        assert!(!filter_donchian("TEST", &breakout, &candle_store, 100.5).unwrap());
+    }
+}
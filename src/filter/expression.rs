@@ -0,0 +1,438 @@
+// 필터 조건식
+// 기술적 필터를 ALL / ANY / NOT / AT_LEAST 로 중첩 결합합니다.
+// 설정 파일에서는 기존 `[[filters]]` 항목과 같은 자리에 `type = "ANY"` 처럼 작성하고,
+// 하위 조건은 `[[filters.filters]]` 로 이어 씁니다.
+
+use super::cache::FilterSource;
+use super::{FilterError, Result, TechnicalFilter, TechnicalFilterConfig};
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use trading_chart::Candle;
+
+/// 필터 조건식
+///
+/// 단일 필터 또는 하위 조건식을 결합한 그룹입니다.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum FilterExpression {
+    /// 단일 기술적 필터
+    Filter(TechnicalFilterConfig),
+    /// 하위 조건식 결합
+    Group(FilterGroup),
+}
+
+/// 하위 조건식 결합 방식
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE", deny_unknown_fields)]
+pub enum FilterGroup {
+    /// 모든 하위 조건 만족
+    All { filters: Vec<FilterExpression> },
+    /// 하위 조건 중 하나 이상 만족
+    Any { filters: Vec<FilterExpression> },
+    /// 하위 조건 불만족
+    Not { filter: Box<FilterExpression> },
+    /// 하위 조건 중 count개 이상 만족
+    AtLeast {
+        count: usize,
+        filters: Vec<FilterExpression>,
+    },
+}
+
+impl FilterGroup {
+    /// 그룹을 나타내는 `type` 값
+    pub(crate) const TYPES: [&'static str; 4] = ["ALL", "ANY", "NOT", "AT_LEAST"];
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            FilterGroup::All { .. } => "ALL",
+            FilterGroup::Any { .. } => "ANY",
+            FilterGroup::Not { .. } => "NOT",
+            FilterGroup::AtLeast { .. } => "AT_LEAST",
+        }
+    }
+}
+
+impl FilterExpression {
+    /// 모든 하위 조건을 만족하는 조건식
+    pub fn all(filters: Vec<FilterExpression>) -> FilterExpression {
+        FilterExpression::Group(FilterGroup::All { filters })
+    }
+
+    /// 하위 조건 중 하나 이상을 만족하는 조건식
+    pub fn any(filters: Vec<FilterExpression>) -> FilterExpression {
+        FilterExpression::Group(FilterGroup::Any { filters })
+    }
+
+    /// 하위 조건을 부정하는 조건식
+    pub fn not(filter: impl Into<FilterExpression>) -> FilterExpression {
+        FilterExpression::Group(FilterGroup::Not {
+            filter: Box::new(filter.into()),
+        })
+    }
+
+    /// 하위 조건 중 count개 이상을 만족하는 조건식
+    pub fn at_least(count: usize, filters: Vec<FilterExpression>) -> FilterExpression {
+        FilterExpression::Group(FilterGroup::AtLeast { count, filters })
+    }
+
+    /// 조건식 구조와 포함된 모든 필터 파라미터 검증
+    pub fn validate<C: Candle + 'static>(&self) -> Result<()> {
+        match self {
+            FilterExpression::Filter(filter) => filter.validate::<C>(),
+            FilterExpression::Group(group) => {
+                let filters = match group {
+                    FilterGroup::Not { filter } => return filter.validate::<C>(),
+                    FilterGroup::All { filters }
+                    | FilterGroup::Any { filters }
+                    | FilterGroup::AtLeast { filters, .. } => filters,
+                };
+
+                super::utils::validate_non_empty_list(
+                    filters,
+                    &format!("{} filters", group.type_name()),
+                )?;
+                if let FilterGroup::AtLeast { count, .. } = group
+                    && (*count == 0 || *count > filters.len())
+                {
+                    return Err(FilterError::InvalidAtLeastCount {
+                        count: *count,
+                        len: filters.len(),
+                    });
+                }
+
+                filters.iter().try_for_each(FilterExpression::validate::<C>)
+            }
+        }
+    }
+
+    /// 조건식 평가 (내부 헬퍼 함수, CandleStore 재사용)
+    ///
+    /// 결과가 정해지면 남은 하위 조건은 평가하지 않습니다.
+    /// 필터 적용 오류는 NOT에서 통과로 뒤집히지 않도록 false로 바꾸지 않고 그대로 반환합니다.
+    pub(crate) fn matches<C: Candle + 'static>(
+        &self,
+        symbol: &str,
+        source: FilterSource<'_, C>,
+        current_price: f64,
+    ) -> Result<bool> {
+        let group = match self {
+            FilterExpression::Filter(filter) => {
+                return TechnicalFilter::matches_filter_internal(
+                    symbol,
+                    filter,
+                    source,
+                    current_price,
+                );
+            }
+            FilterExpression::Group(group) => group,
+        };
+
+        let result = match group {
+            FilterGroup::All { filters } => {
+                let mut result = true;
+                for filter in filters {
+                    if !filter.matches(symbol, source, current_price)? {
+                        result = false;
+                        break;
+                    }
+                }
+                result
+            }
+            FilterGroup::Any { filters } => {
+                let mut result = false;
+                for filter in filters {
+                    if filter.matches(symbol, source, current_price)? {
+                        result = true;
+                        break;
+                    }
+                }
+                result
+            }
+            FilterGroup::Not { filter } => !filter.matches(symbol, source, current_price)?,
+            FilterGroup::AtLeast { count, filters } => {
+                let mut matched = 0;
+                for (i, filter) in filters.iter().enumerate() {
+                    // 남은 조건을 모두 만족해도 count에 못 미치면 중단
+                    if matched + (filters.len() - i) < *count {
+                        break;
+                    }
+                    if filter.matches(symbol, source, current_price)? {
+                        matched += 1;
+                        if matched >= *count {
+                            break;
+                        }
+                    }
+                }
+                matched >= *count
+            }
+        };
+
+        log::debug!("코인 {symbol} 조건식 {self} 결과: {result}");
+        Ok(result)
+    }
+}
+
+impl From<TechnicalFilterConfig> for FilterExpression {
+    fn from(filter: TechnicalFilterConfig) -> Self {
+        FilterExpression::Filter(filter)
+    }
+}
+
+/// `type` 값으로 그룹과 단일 필터를 구분해 역직렬화
+///
+/// 단일 필터의 파싱 오류(알 수 없는 키, 필터 타입 등)를 그대로 전달하기 위해
+/// untagged 대신 `type` 값을 먼저 확인합니다.
+impl<'de> Deserialize<'de> for FilterExpression {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let is_group = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|kind| FilterGroup::TYPES.contains(&kind));
+
+        let result = if is_group {
+            serde_json::from_value(value).map(FilterExpression::Group)
+        } else {
+            serde_json::from_value(value).map(FilterExpression::Filter)
+        };
+        result.map_err(de::Error::custom)
+    }
+}
+
+impl fmt::Display for FilterExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group = match self {
+            FilterExpression::Filter(filter) => return write!(f, "{}", filter.filter_type()),
+            FilterExpression::Group(group) => group,
+        };
+
+        write!(f, "{}", group.type_name())?;
+        let filters = match group {
+            FilterGroup::Not { filter } => return write!(f, "({filter})"),
+            FilterGroup::AtLeast { count, filters } => {
+                write!(f, "{count}")?;
+                filters
+            }
+            FilterGroup::All { filters } | FilterGroup::Any { filters } => filters,
+        };
+
+        write!(f, "(")?;
+        for (i, filter) in filters.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{filter}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{
+        BollingerBandFilterType, PriceReferenceGapFilterType, PriceReferenceGapParams,
+        PriceReferenceSource, RSIFilterType, VolumeFilterType,
+    };
+    use crate::tests::TestCandle;
+
+    #[derive(Deserialize)]
+    struct ScreenerConfig {
+        filters: Vec<FilterExpression>,
+    }
+
+    fn test_candles() -> Vec<TestCandle> {
+        crate::tests::test_candles(&[100.0, 100.0, 100.0, 130.0])
+    }
+
+    // SMA(3) 기준가 대비 괴리율이 gap_threshold 이상인지 확인하는 필터
+    fn gap_filter(gap_threshold: f64) -> FilterExpression {
+        TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+            reference_source: PriceReferenceSource::MovingAverage {
+                ma_type: crate::indicator::ma::MAType::SMA,
+                period: 3,
+            },
+            filter_type: PriceReferenceGapFilterType::GapAboveThreshold,
+            gap_threshold,
+            consecutive_n: 1,
+            p: 0,
+        })
+        .into()
+    }
+
+    #[test]
+    fn test_expression_deserializes_nested_groups_from_toml() {
+        let config: ScreenerConfig = toml::from_str(
+            r#"
+[[filters]]
+type = "MOVING_AVERAGE"
+periods = [5, 20]
+filter_type = "PriceAboveFirstMA"
+
+# RSI 과매도 OR (볼린저 하단 이탈 AND 거래량 급증)
+[[filters]]
+type = "ANY"
+
+[[filters.filters]]
+type = "RSI"
+filter_type = "Oversold"
+
+[[filters.filters]]
+type = "ALL"
+
+[[filters.filters.filters]]
+type = "BOLLINGER_BAND"
+filter_type = "BelowLowerBand"
+
+[[filters.filters.filters]]
+type = "VOLUME"
+filter_type = "VolumeSurge"
+
+[[filters]]
+type = "NOT"
+filter = { type = "ADX", filter_type = "AboveThreshold" }
+
+[[filters]]
+type = "AT_LEAST"
+count = 1
+filters = [{ type = "ATR", filter_type = "HighVolatility" }]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.filters.len(), 4);
+        assert!(matches!(config.filters[0], FilterExpression::Filter(_)));
+
+        let FilterExpression::Group(FilterGroup::Any { filters }) = &config.filters[1] else {
+            panic!("ANY 그룹 파싱 실패");
+        };
+        assert!(matches!(
+            &filters[0],
+            FilterExpression::Filter(TechnicalFilterConfig::RSI(params))
+                if params.filter_type == RSIFilterType::Oversold
+        ));
+        let FilterExpression::Group(FilterGroup::All { filters }) = &filters[1] else {
+            panic!("중첩 ALL 그룹 파싱 실패");
+        };
+        assert!(matches!(
+            &filters[0],
+            FilterExpression::Filter(TechnicalFilterConfig::BollingerBand(params))
+                if params.filter_type == BollingerBandFilterType::BelowLowerBand
+        ));
+        assert!(matches!(
+            &filters[1],
+            FilterExpression::Filter(TechnicalFilterConfig::Volume(params))
+                if params.filter_type == VolumeFilterType::VolumeSurge
+        ));
+
+        assert_eq!(
+            config.filters[1].to_string(),
+            "ANY(RSI, ALL(볼린저밴드, Volume))"
+        );
+        assert_eq!(config.filters[2].to_string(), "NOT(ADX)");
+        assert_eq!(config.filters[3].to_string(), "AT_LEAST1(ATR)");
+        for filter in &config.filters {
+            filter.validate::<TestCandle>().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_expression_json_round_trip() {
+        let expression = FilterExpression::any(vec![
+            gap_filter(0.5),
+            FilterExpression::not(FilterExpression::all(vec![gap_filter(0.1)])),
+        ]);
+
+        let json = serde_json::to_value(&expression).unwrap();
+        assert_eq!(json["type"], "ANY");
+        assert_eq!(json["filters"][0]["type"], "PRICE_REFERENCE_GAP");
+        assert_eq!(json["filters"][1]["filter"]["type"], "ALL");
+
+        let restored: FilterExpression = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.to_string(), expression.to_string());
+    }
+
+    #[test]
+    fn test_expression_reports_leaf_parse_errors() {
+        let err = serde_json::from_str::<FilterExpression>(
+            r#"{ "type": "ANY", "filters": [{ "type": "RSI", "filter_type": "Oversol" }] }"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Oversol"), "{err}");
+
+        let err = serde_json::from_str::<FilterExpression>(r#"{ "type": "NOT", "filters": [] }"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("filters"), "{err}");
+    }
+
+    #[test]
+    fn test_expression_evaluates_groups() {
+        let candle_store = crate::filter::utils::create_candle_store(&test_candles());
+        let matches = |expression: FilterExpression| {
+            expression.validate::<TestCandle>().unwrap();
+            expression
+                .matches("TEST/USDT", (&candle_store).into(), 130.0)
+                .unwrap()
+        };
+
+        // 현재가 130은 SMA(3) 110 대비 약 18% 위
+        assert!(matches(gap_filter(0.1)));
+        assert!(!matches(gap_filter(0.5)));
+
+        assert!(!matches(FilterExpression::all(vec![
+            gap_filter(0.1),
+            gap_filter(0.5)
+        ])));
+        assert!(matches(FilterExpression::any(vec![
+            gap_filter(0.5),
+            gap_filter(0.1)
+        ])));
+        assert!(matches(FilterExpression::not(gap_filter(0.5))));
+        assert!(matches(FilterExpression::at_least(
+            2,
+            vec![
+                gap_filter(0.1),
+                gap_filter(0.5),
+                FilterExpression::not(gap_filter(0.5)),
+            ],
+        )));
+        assert!(!matches(FilterExpression::at_least(
+            2,
+            vec![gap_filter(0.1), gap_filter(0.5), gap_filter(0.4)],
+        )));
+    }
+
+    #[test]
+    fn test_expression_validate_rejects_invalid_groups() {
+        assert!(matches!(
+            FilterExpression::any(Vec::new()).validate::<TestCandle>(),
+            Err(FilterError::InvalidEmptyList { .. })
+        ));
+        assert!(matches!(
+            FilterExpression::at_least(3, vec![gap_filter(0.1), gap_filter(0.5)])
+                .validate::<TestCandle>(),
+            Err(FilterError::InvalidAtLeastCount { count: 3, len: 2 })
+        ));
+        assert!(matches!(
+            FilterExpression::not(FilterExpression::all(vec![gap_filter(-1.0)]))
+                .validate::<TestCandle>(),
+            Err(FilterError::InvalidRatioThreshold { .. })
+        ));
+    }
+
+    #[test]
+    fn test_expression_propagates_filter_errors_through_not() {
+        let candle_store = crate::filter::utils::create_candle_store(&test_candles());
+        let invalid = FilterExpression::not(gap_filter(-1.0));
+
+        assert!(
+            invalid
+                .matches("TEST/USDT", (&candle_store).into(), 130.0)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,365 @@
+// 필터 적중률 평가
+// 과거 캔들을 시간 순서대로 재생하며 필터 목록이 통과한 캔들을 모두 기록하고,
+// 신호 이후 지정한 캔들 수(horizon)만큼의 선행 수익률로 적중률, 평균/중앙값, MAE/MFE를 집계합니다.
+// 어떤 필터 타입이 어떤 시장에서 우위가 있는지 정량적으로 비교하기 위함입니다.
+
+use super::{Result, TechnicalFilterConfig, TechnicalFilterContext, utils};
+use crate::model::PositionType;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use trading_chart::Candle;
+
+/// 적중률 평가 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitRateConfig {
+    /// 선행 수익률을 측정할 캔들 수 목록 (기본값: [1, 5, 20])
+    pub horizons: Vec<usize>,
+    /// 신호 방향 (기본값: Long, Short이면 하락을 수익으로 계산)
+    pub position: PositionType,
+}
+
+impl Default for HitRateConfig {
+    fn default() -> Self {
+        Self {
+            horizons: vec![1, 5, 20],
+            position: PositionType::Long,
+        }
+    }
+}
+
+impl HitRateConfig {
+    /// 측정 구간 검증 (비어 있지 않고 모두 0보다 커야 함)
+    pub fn validate(&self) -> Result<()> {
+        utils::validate_non_empty_list(&self.horizons, "hit rate horizons")?;
+        for horizon in &self.horizons {
+            utils::validate_period(*horizon, "hit rate horizon")?;
+        }
+        Ok(())
+    }
+}
+
+/// 신호 이후 한 구간의 성과
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ForwardReturn {
+    /// 신호 이후 캔들 수
+    pub horizon: usize,
+    /// 신호 캔들 종가 대비 horizon번째 캔들 종가 수익률
+    pub return_rate: f64,
+    /// 구간 내 최대 역행폭 (MAE, 가장 불리한 고가/저가 기준 수익률)
+    pub mae: f64,
+    /// 구간 내 최대 순행폭 (MFE, 가장 유리한 고가/저가 기준 수익률)
+    pub mfe: f64,
+}
+
+/// 필터가 통과한 캔들
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterSignal {
+    /// 캔들 시각
+    pub datetime: DateTime<Utc>,
+    /// 신호 가격 (캔들 종가)
+    pub price: f64,
+    /// 구간별 성과 (이후 캔들이 부족한 구간은 제외)
+    pub forward: Vec<ForwardReturn>,
+}
+
+/// 구간별 성과 통계
+///
+/// 집계한 신호가 없으면 통계 값은 모두 0입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HorizonStats {
+    /// 신호 이후 캔들 수
+    pub horizon: usize,
+    /// 집계한 신호 수 (이후 캔들이 부족한 신호 제외)
+    pub samples: usize,
+    /// 수익률이 양수인 신호 비율 (0..1)
+    pub hit_rate: f64,
+    /// 평균 수익률
+    pub mean_return: f64,
+    /// 수익률 중앙값
+    pub median_return: f64,
+    /// 평균 MAE
+    pub mean_mae: f64,
+    /// 평균 MFE
+    pub mean_mfe: f64,
+}
+
+/// 필터 적중률 평가 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HitRateReport {
+    /// 심볼
+    pub symbol: String,
+    /// 신호 방향
+    pub position: PositionType,
+    /// 평가한 캔들 수
+    pub evaluated_candles: usize,
+    /// 필터가 통과한 캔들 (시간 오름차순)
+    pub signals: Vec<FilterSignal>,
+    /// 구간별 통계 (설정 순서)
+    pub horizons: Vec<HorizonStats>,
+}
+
+impl HitRateReport {
+    /// 평가한 캔들 중 신호 비율
+    pub fn signal_rate(&self) -> f64 {
+        if self.evaluated_candles == 0 {
+            return 0.0;
+        }
+        self.signals.len() as f64 / self.evaluated_candles as f64
+    }
+
+    /// 구간의 통계
+    pub fn horizon(&self, horizon: usize) -> Option<&HorizonStats> {
+        self.horizons.iter().find(|stats| stats.horizon == horizon)
+    }
+}
+
+/// 필터 적중률 평가 (내부 헬퍼 함수)
+///
+/// 캔들을 하나씩 추가하며 평가하므로 지표는 컨텍스트 캐시에 증분 반영됩니다.
+pub(crate) fn evaluate_hit_rate<C: Candle + 'static>(
+    symbol: &str,
+    filters: &[TechnicalFilterConfig],
+    candles: &[C],
+    config: &HitRateConfig,
+) -> Result<HitRateReport> {
+    config.validate()?;
+    // 설정 오류는 캔들마다 반복 평가하지 않고 한 번만 반환
+    for filter in filters {
+        filter.validate::<C>()?;
+    }
+
+    let mut ordered = candles.to_vec();
+    ordered.sort_by_key(|candle| candle.datetime());
+
+    let mut context = TechnicalFilterContext::from_candles(Vec::new(), ordered.len().max(1), false);
+    let mut signals = Vec::new();
+
+    for (index, candle) in ordered.iter().enumerate() {
+        context.update(candle.clone());
+
+        let price = candle.close_price();
+        if context.matches_filters(symbol, filters, price)? {
+            signals.push(FilterSignal {
+                datetime: candle.datetime(),
+                price,
+                forward: forward_returns(&ordered, index, config),
+            });
+        }
+    }
+
+    log::debug!(
+        "코인 {} 적중률 평가: 캔들 {}개 중 신호 {}개",
+        symbol,
+        ordered.len(),
+        signals.len()
+    );
+
+    Ok(HitRateReport {
+        symbol: symbol.to_string(),
+        position: config.position,
+        evaluated_candles: ordered.len(),
+        horizons: config
+            .horizons
+            .iter()
+            .map(|&horizon| horizon_stats(&signals, horizon))
+            .collect(),
+        signals,
+    })
+}
+
+fn forward_returns<C: Candle>(
+    candles: &[C],
+    index: usize,
+    config: &HitRateConfig,
+) -> Vec<ForwardReturn> {
+    let entry = candles[index].close_price();
+    if entry <= 0.0 {
+        return Vec::new();
+    }
+
+    let direction = match config.position {
+        PositionType::Long => 1.0,
+        PositionType::Short => -1.0,
+    };
+    let rate = |price: f64| direction * (price - entry) / entry;
+
+    config
+        .horizons
+        .iter()
+        .filter_map(|&horizon| {
+            let window = candles.get(index + 1..=index + horizon)?;
+            let exit = window.last()?.close_price();
+            let highest = window
+                .iter()
+                .map(|candle| candle.high_price())
+                .fold(f64::MIN, f64::max);
+            let lowest = window
+                .iter()
+                .map(|candle| candle.low_price())
+                .fold(f64::MAX, f64::min);
+            let (favorable, adverse) = match config.position {
+                PositionType::Long => (highest, lowest),
+                PositionType::Short => (lowest, highest),
+            };
+
+            Some(ForwardReturn {
+                horizon,
+                return_rate: rate(exit),
+                mae: rate(adverse),
+                mfe: rate(favorable),
+            })
+        })
+        .collect()
+}
+
+fn horizon_stats(signals: &[FilterSignal], horizon: usize) -> HorizonStats {
+    let forward: Vec<&ForwardReturn> = signals
+        .iter()
+        .filter_map(|signal| signal.forward.iter().find(|f| f.horizon == horizon))
+        .collect();
+
+    let samples = forward.len();
+    if samples == 0 {
+        return HorizonStats {
+            horizon,
+            samples,
+            hit_rate: 0.0,
+            mean_return: 0.0,
+            median_return: 0.0,
+            mean_mae: 0.0,
+            mean_mfe: 0.0,
+        };
+    }
+
+    let mean = |value: fn(&ForwardReturn) -> f64| {
+        forward.iter().map(|&f| value(f)).sum::<f64>() / samples as f64
+    };
+
+    let mut returns: Vec<f64> = forward.iter().map(|f| f.return_rate).collect();
+    returns.sort_by(f64::total_cmp);
+    let median_return = if samples.is_multiple_of(2) {
+        (returns[samples / 2 - 1] + returns[samples / 2]) / 2.0
+    } else {
+        returns[samples / 2]
+    };
+
+    HorizonStats {
+        horizon,
+        samples,
+        hit_rate: returns.iter().filter(|&&r| r > 0.0).count() as f64 / samples as f64,
+        mean_return: mean(|f| f.return_rate),
+        median_return,
+        mean_mae: mean(|f| f.mae),
+        mean_mfe: mean(|f| f.mfe),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{
+        PriceReferenceGapFilterType, PriceReferenceGapParams, PriceReferenceSource, TechnicalFilter,
+    };
+    use crate::tests::test_candles;
+
+    fn config(horizons: &[usize], position: PositionType) -> HitRateConfig {
+        HitRateConfig {
+            horizons: horizons.to_vec(),
+            position,
+        }
+    }
+
+    #[test]
+    fn test_hit_rate_records_signal_and_forward_excursions() {
+        // SMA(3) 대비 10% 이상 급등한 130 캔들에서만 신호 발생
+        let candles = test_candles(&[100.0, 100.0, 100.0, 100.0, 130.0, 120.0, 110.0]);
+        let filter = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+            reference_source: PriceReferenceSource::MovingAverage {
+                ma_type: crate::indicator::ma::MAType::SMA,
+                period: 3,
+            },
+            filter_type: PriceReferenceGapFilterType::GapAboveReferenceThreshold,
+            gap_threshold: 0.1,
+            consecutive_n: 1,
+            p: 0,
+        });
+
+        let report = TechnicalFilter::evaluate_hit_rate(
+            "TEST/USDT",
+            &[filter],
+            &candles,
+            &config(&[1, 2, 5], PositionType::Long),
+        )
+        .unwrap();
+
+        assert_eq!(report.evaluated_candles, 7);
+        assert_eq!(report.signals.len(), 1);
+        assert_eq!(report.signals[0].price, 130.0);
+
+        let two_bars = report.horizon(2).unwrap();
+        assert_eq!(two_bars.samples, 1);
+        assert_eq!(two_bars.hit_rate, 0.0);
+        assert!((two_bars.mean_return - (110.0 / 130.0 - 1.0)).abs() < 1e-9);
+        // 이후 2개 캔들의 저가 109, 고가 121
+        assert!((two_bars.mean_mae - (109.0 / 130.0 - 1.0)).abs() < 1e-9);
+        assert!((two_bars.mean_mfe - (121.0 / 130.0 - 1.0)).abs() < 1e-9);
+
+        // 이후 캔들이 부족한 구간은 집계하지 않음
+        assert_eq!(report.horizon(5).unwrap().samples, 0);
+    }
+
+    #[test]
+    fn test_hit_rate_without_filters_measures_baseline_by_position() {
+        let candles = test_candles(&[100.0, 102.0, 101.0, 104.0, 106.0]);
+
+        let long = TechnicalFilter::evaluate_hit_rate(
+            "TEST/USDT",
+            &[],
+            &candles,
+            &config(&[1], PositionType::Long),
+        )
+        .unwrap();
+        let short = TechnicalFilter::evaluate_hit_rate(
+            "TEST/USDT",
+            &[],
+            &candles,
+            &config(&[1], PositionType::Short),
+        )
+        .unwrap();
+
+        // 필터가 없으면 모든 캔들이 신호, 마지막 캔들은 이후 캔들이 없어 제외
+        assert_eq!(long.signal_rate(), 1.0);
+        let long_stats = long.horizon(1).unwrap();
+        let short_stats = short.horizon(1).unwrap();
+        assert_eq!(long_stats.samples, 4);
+        assert_eq!(long_stats.hit_rate, 0.75);
+        assert_eq!(short_stats.hit_rate, 0.25);
+        assert!((long_stats.mean_return + short_stats.mean_return).abs() < 1e-12);
+        // 수익률 -0.98%, 1.92%, 2%, 2.97% 중 가운데 두 값의 평균
+        assert!((long_stats.median_return - (106.0 / 104.0 - 1.0 + 0.02) / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hit_rate_rejects_invalid_horizon() {
+        let candles = test_candles(&[100.0, 101.0]);
+
+        assert!(
+            TechnicalFilter::evaluate_hit_rate(
+                "TEST/USDT",
+                &[],
+                &candles,
+                &config(&[0], PositionType::Long)
+            )
+            .is_err()
+        );
+        assert!(
+            TechnicalFilter::evaluate_hit_rate(
+                "TEST/USDT",
+                &[],
+                &candles,
+                &config(&[], PositionType::Long)
+            )
+            .is_err()
+        );
+    }
+}
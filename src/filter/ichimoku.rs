@@ -1,8 +1,10 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{IchimokuFilterType, IchimokuParams, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::ichimoku_analyzer::IchimokuAnalyzer;
-use crate::candle_store::CandleStore;
 use crate::indicator::ichimoku::IchimokuParams as IndicatorIchimokuParams;
 use trading_chart::Candle;
 
@@ -15,13 +17,101 @@ pub struct IchimokuValues {
     pub senkou_span_b: f64, // 선행스팬 B
 }
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &IchimokuParams) -> usize {
+    params.senkou_span_b_period + params.kijun_period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 전환선, 기준선, 선행스팬 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &IchimokuParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    let ichimoku_params = indicator_params(params);
+
+    offset_values(
+        &ichimoku_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let ichimoku = data.ichimokus.get(&ichimoku_params);
+            [
+                ("tenkan", ichimoku.tenkan),
+                ("kijun", ichimoku.kijun),
+                ("senkou_span_a", ichimoku.senkou_span_a),
+                ("senkou_span_b", ichimoku.senkou_span_b),
+            ]
+        },
+    )
+}
+
+fn indicator_params(params: &IchimokuParams) -> IndicatorIchimokuParams {
+    IndicatorIchimokuParams {
+        tenkan_period: params.tenkan_period,
+        kijun_period: params.kijun_period,
+        senkou_period: params.senkou_span_b_period,
+    }
+}
+
+fn ichimoku_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &IchimokuParams,
+) -> CachedRef<'a, IchimokuAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(
+        format!(
+            "{}:{}:{}",
+            params.tenkan_period, params.kijun_period, params.senkou_span_b_period
+        ),
+        || IchimokuAnalyzer::new(&[indicator_params(params)], candle_store),
+    )
+}
+
+/// 통과한 이치모쿠 필터의 강도 (현재가가 p번째 구름대 경계를 벗어난 정도)
+///
+/// 구름대 두께로 정규화합니다.
+/// 전환선/기준선 비교, 교차, 구름대 변화 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_ichimoku<C: Candle + 'static>(
+    params: &IchimokuParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Option<f64> {
+    let ichimoku_params = indicator_params(params);
+    let analyzer = ichimoku_analyzer(source, params);
+    let ichimoku = analyzer
+        .items
+        .get(params.p)?
+        .ichimokus
+        .get(&ichimoku_params);
+    let cloud_top = ichimoku.senkou_span_a.max(ichimoku.senkou_span_b);
+    let cloud_bottom = ichimoku.senkou_span_a.min(ichimoku.senkou_span_b);
+    let thickness = cloud_top - cloud_bottom;
+
+    match params.filter_type {
+        IchimokuFilterType::PriceAboveCloud
+        | IchimokuFilterType::BuySignal
+        | IchimokuFilterType::StrongBuySignal
+        | IchimokuFilterType::PerfectAlignment => {
+            excess_ratio(current_price - cloud_top, thickness)
+        }
+        IchimokuFilterType::PriceBelowCloud
+        | IchimokuFilterType::SellSignal
+        | IchimokuFilterType::PerfectReverseAlignment => {
+            excess_ratio(cloud_bottom - current_price, thickness)
+        }
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 이치모쿠 필터 적용
-pub(crate) fn filter_ichimoku<C: Candle + 'static>(
+pub(crate) fn filter_ichimoku<'a, C: Candle + 'static>(
     coin: &str,
     params: &IchimokuParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     log::debug!(
         "이치모쿠 필터 적용 - 전환선: {}, 기준선: {}, 선행스팬B: {}, 타입: {:?}, 연속성: {}",
         params.tenkan_period,
@@ -37,21 +127,16 @@ pub(crate) fn filter_ichimoku<C: Candle + 'static>(
     utils::validate_period(params.senkou_span_b_period, "Ichimoku senkou_span_b_period")?;
 
     // 필터링 로직
-    let required_length = params.senkou_span_b_period + params.kijun_period + params.consecutive_n; // 데이터 필요량
+    let required_length = required_candles(params);
     if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
         return Ok(false);
     }
 
     // IchimokuParams 생성
-    let ichimoku_params = IndicatorIchimokuParams {
-        tenkan_period: params.tenkan_period,
-        kijun_period: params.kijun_period,
-        senkou_period: params.senkou_span_b_period,
-    };
-    let indicator_params = vec![ichimoku_params];
+    let ichimoku_params = indicator_params(params);
 
     // IchimokuAnalyzer 생성
-    let analyzer = IchimokuAnalyzer::new(&indicator_params, candle_store);
+    let analyzer = ichimoku_analyzer(source, params);
 
     log::debug!("코인 {coin} 이치모쿠 분석기 생성 완료");
 
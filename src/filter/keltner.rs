@@ -0,0 +1,175 @@
+use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{KeltnerFilterType, KeltnerParams, utils};
+use crate::analyzer::keltner_analyzer::KeltnerAnalyzer;
+use trading_chart::Candle;
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &KeltnerParams) -> usize {
+    params.period.max(params.atr_period + 1) + params.consecutive_n
+}
+
+/// 확인한 오프셋별 켈트너 채널 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &KeltnerParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &keltner_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            [
+                ("lower", data.keltner.lower()),
+                ("middle", data.keltner.middle()),
+                ("upper", data.keltner.upper()),
+            ]
+        },
+    )
+}
+
+fn keltner_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &KeltnerParams,
+) -> CachedRef<'a, KeltnerAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(
+        format!(
+            "{}:{}:{}",
+            params.period, params.atr_period, params.multiplier
+        ),
+        || {
+            KeltnerAnalyzer::new(
+                params.period,
+                params.atr_period,
+                params.multiplier,
+                candle_store,
+            )
+        },
+    )
+}
+
+/// 통과한 켈트너 채널 필터의 강도 (p번째 종가가 채널 기준선을 넘어선 정도)
+///
+/// 채널 이탈은 채널 폭, 중심선 기준은 중심선과 상/하단 사이 폭으로 정규화합니다.
+/// 돌파 타입은 이탈한 정도를 같은 방식으로 계산합니다.
+pub(crate) fn score_keltner<C: Candle + 'static>(
+    params: &KeltnerParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let analyzer = keltner_analyzer(source, params);
+    let data = analyzer.items.get(params.p)?;
+    let close = data.candle.close_price();
+    let (lower, middle, upper) = (
+        data.keltner.lower(),
+        data.keltner.middle(),
+        data.keltner.upper(),
+    );
+
+    match params.filter_type {
+        KeltnerFilterType::AboveUpper | KeltnerFilterType::BreakoutUp => {
+            excess_ratio(close - upper, upper - lower)
+        }
+        KeltnerFilterType::BelowLower | KeltnerFilterType::BreakoutDown => {
+            excess_ratio(lower - close, upper - lower)
+        }
+        KeltnerFilterType::InsideChannel => {
+            excess_ratio((upper - middle) - (close - middle).abs(), upper - middle)
+        }
+        KeltnerFilterType::AboveMiddle => excess_ratio(close - middle, upper - middle),
+        KeltnerFilterType::BelowMiddle => excess_ratio(middle - close, middle - lower),
+    }
+}
+
+/// 개별 코인에 대한 Keltner 필터 적용
+pub(crate) fn filter_keltner<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &KeltnerParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "Keltner 필터 적용 - 기간: {}, ATR 기간: {}, 승수: {}, 타입: {:?}, 연속성: {}",
+        params.period,
+        params.atr_period,
+        params.multiplier,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    utils::validate_period(params.period, "Keltner")?;
+    utils::validate_period(params.atr_period, "Keltner atr_period")?;
+    utils::validate_positive_number(params.multiplier, "Keltner multiplier")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let analyzer = keltner_analyzer(source, params);
+    let (n, p) = (params.consecutive_n, params.p);
+
+    let (lower, middle, upper) = analyzer.get_keltner();
+    log::debug!("코인 {coin} 켈트너 채널 - 상단: {upper:.2}, 중간: {middle:.2}, 하단: {lower:.2}");
+
+    let result = match params.filter_type {
+        KeltnerFilterType::AboveUpper => analyzer.is_above_upper(n, p),
+        KeltnerFilterType::BelowLower => analyzer.is_below_lower(n, p),
+        KeltnerFilterType::InsideChannel => analyzer.is_inside_channel(n, p),
+        KeltnerFilterType::AboveMiddle => analyzer.is_above_middle(n, p),
+        KeltnerFilterType::BelowMiddle => analyzer.is_below_middle(n, p),
+        KeltnerFilterType::BreakoutUp => analyzer.is_breakout_up(n, 1, p),
+        KeltnerFilterType::BreakoutDown => analyzer.is_breakout_down(n, 1, p),
+    };
+
+    log::debug!("코인 {coin} Keltner 필터 결과: {result}");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{TestCandle, test_candle};
+
+    fn params(filter_type: KeltnerFilterType) -> KeltnerParams {
+        KeltnerParams {
+            period: 10,
+            atr_period: 10,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_keltner_breakout_up() {
+        // 횡보(EMA 100, ATR 2) 후 종가 110 장대양봉
+        // EMA = 100 + 10 * 2/11 ≈ 101.82, ATR = (2 * 9 + 11) / 10 = 2.9 → 상단 ≈ 107.62
+        let mut candles: Vec<TestCandle> = (1..=14).map(|i| test_candle(i, 100.0)).collect();
+        candles.push(test_candle(15, 110.0));
+        let candle_store = utils::create_candle_store(&candles);
+
+        let breakout = params(KeltnerFilterType::BreakoutUp);
+        assert!(filter_keltner("TEST", &breakout, &candle_store, 110.0).unwrap());
+        let above = params(KeltnerFilterType::AboveUpper);
+        assert!(filter_keltner("TEST", &above, &candle_store, 110.0).unwrap());
+        let inside = params(KeltnerFilterType::InsideChannel);
+        assert!(!filter_keltner("TEST", &inside, &candle_store, 110.0).unwrap());
+        let breakdown = params(KeltnerFilterType::BreakoutDown);
+        assert!(!filter_keltner("TEST", &breakdown, &candle_store, 110.0).unwrap());
+    }
+
+    #[test]
+    fn test_keltner_insufficient_candles() {
+        let candles: Vec<TestCandle> = (1..=5).map(|i| test_candle(i, 100.0)).collect();
+        let candle_store = utils::create_candle_store(&candles);
+        let params = params(KeltnerFilterType::InsideChannel);
+
+        assert!(!filter_keltner("TEST", &params, &candle_store, 100.0).unwrap());
+    }
+}
@@ -1,17 +1,105 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{MACDFilterType, MACDParams, utils};
 use crate::analyzer::AnalyzerOps;
 use crate::analyzer::macd_analyzer::MACDAnalyzer;
-use crate::candle_store::CandleStore;
 use trading_chart::Candle;
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &MACDParams) -> usize {
+    params.slow_period + params.signal_period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 MACD, 시그널, 히스토그램 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &MACDParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &macd_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            [
+                ("macd", data.macd.macd_line),
+                ("signal", data.macd.signal_line),
+                ("histogram", data.macd.histogram),
+            ]
+        },
+    )
+}
+
+fn macd_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &MACDParams,
+) -> CachedRef<'a, MACDAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(
+        format!(
+            "{}:{}:{}",
+            params.fast_period, params.slow_period, params.signal_period
+        ),
+        || {
+            MACDAnalyzer::new(
+                params.fast_period,
+                params.slow_period,
+                params.signal_period,
+                candle_store,
+            )
+        },
+    )
+}
+
+/// 통과한 MACD 필터의 강도 (p번째 MACD 값이 기준선을 넘어선 정도)
+///
+/// MACD 차이는 현재가에 과매수/과매도 비율을 곱한 값으로 정규화합니다.
+/// 교차와 추세 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_macd<C: Candle + 'static>(
+    params: &MACDParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Option<f64> {
+    let analyzer = macd_analyzer(source, params);
+    let macd = &analyzer.items.get(params.p)?.macd;
+    let upper_range = current_price * params.overbought_threshold;
+    let lower_range = current_price * params.oversold_threshold;
+
+    match params.filter_type {
+        MACDFilterType::MacdAboveSignal => {
+            excess_ratio(macd.macd_line - macd.signal_line, upper_range)
+        }
+        MACDFilterType::MacdBelowSignal => {
+            excess_ratio(macd.signal_line - macd.macd_line, lower_range)
+        }
+        MACDFilterType::HistogramAboveThreshold => {
+            excess_ratio(macd.histogram - params.threshold, upper_range)
+        }
+        MACDFilterType::HistogramBelowThreshold => {
+            excess_ratio(params.threshold - macd.histogram, lower_range)
+        }
+        MACDFilterType::StrongUptrend => {
+            excess_ratio(macd.macd_line.min(macd.signal_line), upper_range)
+        }
+        MACDFilterType::StrongDowntrend => {
+            excess_ratio(-macd.macd_line.max(macd.signal_line), lower_range)
+        }
+        MACDFilterType::Overbought => excess_ratio(macd.macd_line - upper_range, upper_range),
+        MACDFilterType::Oversold => excess_ratio(-macd.macd_line - lower_range, lower_range),
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 MACD 필터 적용
-pub(crate) fn filter_macd<C: Candle + 'static>(
+pub(crate) fn filter_macd<'a, C: Candle + 'static>(
     coin: &str,
     params: &MACDParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     log::debug!(
         "MACD 필터 적용 - 빠른 기간: {}, 느린 기간: {}, 시그널 기간: {}, 타입: {:?}, 연속성: {}",
         params.fast_period,
@@ -27,18 +115,13 @@ pub(crate) fn filter_macd<C: Candle + 'static>(
     utils::validate_period(params.signal_period, "MACD signal_period")?;
 
     // 필터링 로직
-    let required_length = params.slow_period + params.signal_period + params.consecutive_n; // 최소 필요 캔들 수
+    let required_length = required_candles(params);
     if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
         return Ok(false);
     }
 
     // MACDAnalyzer 생성
-    let analyzer = MACDAnalyzer::new(
-        params.fast_period,
-        params.slow_period,
-        params.signal_period,
-        candle_store,
-    );
+    let analyzer = macd_analyzer(source, params);
 
     log::debug!("코인 {coin} MACD 분석기 생성 완료");
 
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 use thiserror::Error;
-use trading_chart::Candle;
+use trading_chart::{Candle, CandleInterval};
 
 pub type Result<T> = std::result::Result<T, FilterError>;
 
@@ -17,6 +17,8 @@ pub enum FilterError {
     InvalidPercentageThreshold { param_name: String },
     #[error("{param_name} 파라미터 오류: threshold는 0에서 1 사이여야 합니다")]
     InvalidRatioThreshold { param_name: String },
+    #[error("{param_name} 파라미터 오류: threshold는 -100에서 0 사이여야 합니다")]
+    InvalidNegativePercentageThreshold { param_name: String },
     #[error("{param_name} 파라미터 오류: 값은 유한한 0 이상 숫자여야 합니다")]
     InvalidNonNegativeNumber { param_name: String },
     #[error("{param_name} 파라미터 오류: 값은 유한한 양수여야 합니다")]
@@ -35,12 +37,12 @@ pub enum FilterError {
         right_name: String,
         right: usize,
     },
+    #[error("AT_LEAST 파라미터 오류: count({count})는 1에서 하위 조건 수({len}) 사이여야 합니다")]
+    InvalidAtLeastCount { count: usize, len: usize },
     #[error("SupportResistance 파라미터 오류: min_touch_count는 0보다 커야 합니다")]
     InvalidSupportResistanceMinTouchCount,
     #[error("CandlePattern 파라미터 오류: pattern_history_length는 0보다 커야 합니다")]
     InvalidCandlePatternHistoryLength,
-    #[error("PriceReferenceGap 파라미터 오류: 지원하지 않는 이동평균 타입입니다: {ma_type}")]
-    UnsupportedPriceReferenceGapMaType { ma_type: String },
     #[error("알 수 없는 필터 타입: {input}")]
     UnknownTechnicalFilterType { input: String },
     #[error("알 수 없는 RSI 필터 타입: {input}")]
@@ -77,6 +79,35 @@ pub enum FilterError {
     UnknownMomentumFilterType { input: String },
     #[error("알 수 없는 Slope 필터 타입: {input}")]
     UnknownSlopeFilterType { input: String },
+    #[error("알 수 없는 Compare 필터 타입: {input}")]
+    UnknownCompareFilterType { input: String },
+    #[error("알 수 없는 Stochastic 필터 타입: {input}")]
+    UnknownStochasticFilterType { input: String },
+    #[error("알 수 없는 StochRSI 필터 타입: {input}")]
+    UnknownStochRsiFilterType { input: String },
+    #[error("알 수 없는 WilliamsR 필터 타입: {input}")]
+    UnknownWilliamsRFilterType { input: String },
+    #[error("알 수 없는 CCI 필터 타입: {input}")]
+    UnknownCciFilterType { input: String },
+    #[error("알 수 없는 Keltner 필터 타입: {input}")]
+    UnknownKeltnerFilterType { input: String },
+    #[error("알 수 없는 Donchian 필터 타입: {input}")]
+    UnknownDonchianFilterType { input: String },
+    #[error("알 수 없는 Squeeze 필터 타입: {input}")]
+    UnknownSqueezeFilterType { input: String },
+    #[error("알 수 없는 캔들 간격: {input}")]
+    UnknownCandleInterval { input: String },
+    #[error("등록되지 않은 사용자 정의 필터 타입: {filter_type}")]
+    UnregisteredCustomFilter { filter_type: String },
+    #[error("사용자 정의 필터 타입으로 사용할 수 없는 이름입니다: {filter_type}")]
+    ReservedCustomFilterType { filter_type: String },
+    #[error("{filter_type} 파라미터 오류: {message}")]
+    InvalidCustomFilterParams {
+        filter_type: String,
+        message: String,
+    },
+    #[error("{filter_type} 필터는 강도를 계산할 수 없어 가중 필터로 사용할 수 없습니다")]
+    UnscorableFilterType { filter_type: String },
 }
 
 // 공통 deserializer 매크로
@@ -150,10 +181,11 @@ macro_rules! impl_filter_type_display {
 macro_rules! impl_filter_type_fromstr {
     ($type:ident, $error_variant:ident, parse_i32, [$($variant:ident),+ $(,)?]) => {
         impl $type {
-            fn from_index(index: usize) -> Result<Self> {
-                const VARIANTS: &[$type] = &[$($type::$variant),+];
+            /// 모든 필터 타입 (선언 순서 = 정수 인덱스 순서)
+            pub const VARIANTS: &'static [$type] = &[$($type::$variant),+];
 
-                VARIANTS
+            fn from_index(index: usize) -> Result<Self> {
+                Self::VARIANTS
                     .get(index)
                     .copied()
                     .ok_or(FilterError::$error_variant {
@@ -181,10 +213,11 @@ macro_rules! impl_filter_type_fromstr {
     };
     ($type:ident, $error_variant:ident, no_parse_i32, [$($variant:ident),+ $(,)?]) => {
         impl $type {
-            fn from_index(index: usize) -> Result<Self> {
-                const VARIANTS: &[$type] = &[$($type::$variant),+];
+            /// 모든 필터 타입 (선언 순서 = 정수 인덱스 순서)
+            pub const VARIANTS: &'static [$type] = &[$($type::$variant),+];
 
-                VARIANTS
+            fn from_index(index: usize) -> Result<Self> {
+                Self::VARIANTS
                     .get(index)
                     .copied()
                     .ok_or(FilterError::$error_variant {
@@ -216,20 +249,37 @@ macro_rules! impl_filter_type_fromstr {
 mod adx;
 mod atr;
 mod bollinger_band;
+mod cache;
 mod candle_pattern;
+mod catalog;
+mod cci;
+mod compare;
 mod copys;
+mod custom;
+mod donchian;
+mod expression;
+mod hit_rate;
 mod ichimoku;
+mod keltner;
 mod macd;
 mod momentum;
 mod moving_average;
 mod price_reference_gap;
+mod report;
 mod rsi;
+mod score;
+mod screener;
 mod slope;
+mod squeeze;
+mod stoch_rsi;
+mod stochastic;
 mod supertrend;
 mod support_resistance;
 mod three_rsi;
+mod timeframe;
 mod volume;
 mod vwap;
+mod williams_r;
 
 /// 필터 공통 유틸리티 함수
 pub mod utils {
@@ -272,6 +322,16 @@ pub mod utils {
         Ok(())
     }
 
+    /// 음수 퍼센트 기준 임계값 검증 (-100-0 범위)
+    pub fn validate_negative_percentage_threshold(threshold: f64, param_name: &str) -> Result<()> {
+        if !(-100.0..=0.0).contains(&threshold) {
+            return Err(FilterError::InvalidNegativePercentageThreshold {
+                param_name: param_name.to_string(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn validate_non_negative_number(value: f64, param_name: &str) -> Result<()> {
         if !value.is_finite() || value < 0.0 {
             return Err(FilterError::InvalidNonNegativeNumber {
@@ -382,6 +442,24 @@ pub enum TechnicalFilterType {
     Momentum,
     /// Slope 기반 필터 (기울기)
     Slope,
+    /// Compare 기반 필터 (지표 간 비교/교차)
+    Compare,
+    /// Stochastic 기반 필터 (과매수/과매도, %K/%D 교차)
+    Stochastic,
+    /// StochRSI 기반 필터 (RSI의 스토캐스틱)
+    StochRSI,
+    /// WilliamsR 기반 필터 (과매수/과매도)
+    WilliamsR,
+    /// CCI 기반 필터 (평균 이탈)
+    CCI,
+    /// Keltner 기반 필터 (켈트너 채널 돌파)
+    Keltner,
+    /// Donchian 기반 필터 (돈치안 채널 돌파)
+    Donchian,
+    /// Squeeze 기반 필터 (변동성 압축/해제)
+    Squeeze,
+    /// 사용자 정의 필터 (등록된 `type` 값)
+    Custom(String),
 }
 
 impl fmt::Display for TechnicalFilterType {
@@ -404,6 +482,15 @@ impl fmt::Display for TechnicalFilterType {
             TechnicalFilterType::SupportResistance => write!(f, "SupportResistance"),
             TechnicalFilterType::Momentum => write!(f, "Momentum"),
             TechnicalFilterType::Slope => write!(f, "Slope"),
+            TechnicalFilterType::Compare => write!(f, "Compare"),
+            TechnicalFilterType::Stochastic => write!(f, "Stochastic"),
+            TechnicalFilterType::StochRSI => write!(f, "StochRSI"),
+            TechnicalFilterType::WilliamsR => write!(f, "WilliamsR"),
+            TechnicalFilterType::CCI => write!(f, "CCI"),
+            TechnicalFilterType::Keltner => write!(f, "Keltner"),
+            TechnicalFilterType::Donchian => write!(f, "Donchian"),
+            TechnicalFilterType::Squeeze => write!(f, "Squeeze"),
+            TechnicalFilterType::Custom(filter_type) => write!(f, "{filter_type}"),
         }
     }
 }
@@ -432,6 +519,14 @@ impl FromStr for TechnicalFilterType {
             "SUPPORTRESISTANCE" => Ok(TechnicalFilterType::SupportResistance),
             "MOMENTUM" => Ok(TechnicalFilterType::Momentum),
             "SLOPE" => Ok(TechnicalFilterType::Slope),
+            "COMPARE" => Ok(TechnicalFilterType::Compare),
+            "STOCHASTIC" => Ok(TechnicalFilterType::Stochastic),
+            "STOCHRSI" | "STOCH_RSI" => Ok(TechnicalFilterType::StochRSI),
+            "WILLIAMSR" | "WILLIAMS_R" => Ok(TechnicalFilterType::WilliamsR),
+            "CCI" => Ok(TechnicalFilterType::CCI),
+            "KELTNER" => Ok(TechnicalFilterType::Keltner),
+            "DONCHIAN" => Ok(TechnicalFilterType::Donchian),
+            "SQUEEZE" => Ok(TechnicalFilterType::Squeeze),
             _ => Err(FilterError::UnknownTechnicalFilterType {
                 input: s.to_string(),
             }),
@@ -836,6 +931,9 @@ pub enum VWAPFilterType {
     StrongDowntrend,
     TrendStrengthening,
     TrendWeakening,
+    PriceAboveUpperBand,
+    PriceBelowLowerBand,
+    PriceInsideBands,
 }
 
 impl_filter_type_fromstr!(
@@ -855,6 +953,9 @@ impl_filter_type_fromstr!(
         StrongDowntrend,
         TrendStrengthening,
         TrendWeakening,
+        PriceAboveUpperBand,
+        PriceBelowLowerBand,
+        PriceInsideBands,
     ]
 );
 
@@ -1553,6 +1654,8 @@ impl Default for ADXParams {
 pub struct MovingAverageParams {
     /// 이동평균 기간 목록
     pub periods: Vec<usize>,
+    /// 이동평균 유형 (기본값: SMA)
+    pub ma_type: crate::indicator::ma::MAType,
     /// 필터 유형
     pub filter_type: MovingAverageFilterType,
     /// 연속 캔들 수 (기본값: 1)
@@ -1577,6 +1680,7 @@ impl Default for MovingAverageParams {
     fn default() -> Self {
         Self {
             periods: vec![5, 20],
+            ma_type: crate::indicator::ma::MAType::SMA,
             filter_type: MovingAverageFilterType::PriceAboveFirstMA,
             consecutive_n: 1,
             p: 0,
@@ -1617,18 +1721,62 @@ impl Default for IchimokuParams {
     }
 }
 
+/// VWAP 누적 기준 (앵커)
+///
+/// 세션 경계(일/주/월)는 캔들 시각 기준 UTC로 판단합니다.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", deny_unknown_fields)]
+pub enum VWAPAnchorMode {
+    /// 최근 period개 캔들 (기본값)
+    #[default]
+    #[serde(rename = "ROLLING")]
+    Rolling,
+    /// UTC 날짜가 바뀔 때마다 초기화 (세션 VWAP)
+    #[serde(rename = "DAY")]
+    Day,
+    /// UTC 주(월요일 시작)가 바뀔 때마다 초기화
+    #[serde(rename = "WEEK")]
+    Week,
+    /// UTC 월이 바뀔 때마다 초기화
+    #[serde(rename = "MONTH")]
+    Month,
+    /// 지정한 시각(Unix 초)부터 누적
+    #[serde(rename = "TIMESTAMP")]
+    Timestamp { timestamp: i64 },
+    /// 최근 스윙 고점부터 누적
+    #[serde(rename = "SWING_HIGH")]
+    SwingHigh {
+        #[serde(default = "default_vwap_swing_strength")]
+        swing_strength: usize,
+    },
+    /// 최근 스윙 저점부터 누적
+    #[serde(rename = "SWING_LOW")]
+    SwingLow {
+        #[serde(default = "default_vwap_swing_strength")]
+        swing_strength: usize,
+    },
+}
+
+fn default_vwap_swing_strength() -> usize {
+    2
+}
+
 /// VWAP 필터 파라미터
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct VWAPParams {
-    /// VWAP 계산 기간 (기본값: 20)
+    /// VWAP 계산 기간 (기본값: 20, 롤링 앵커에서만 사용)
     pub period: usize,
+    /// 누적 기준 (기본값: 롤링)
+    pub anchor: VWAPAnchorMode,
     /// 필터 유형
     pub filter_type: VWAPFilterType,
     /// 연속 캔들 수 (기본값: 1)
     pub consecutive_n: usize,
     /// 임계값 (기본값: 0.05 - 5%)
     pub threshold: f64,
+    /// 밴드 표준편차 배수 (기본값: 2.0)
+    pub band_multiplier: f64,
     /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
     pub p: usize,
 }
@@ -1637,9 +1785,11 @@ impl Default for VWAPParams {
     fn default() -> Self {
         Self {
             period: 20,
+            anchor: VWAPAnchorMode::Rolling,
             filter_type: VWAPFilterType::PriceAboveVWAP,
             consecutive_n: 1,
             threshold: 0.05,
+            band_multiplier: 2.0,
             p: 0,
         }
     }
@@ -1654,7 +1804,13 @@ pub enum PriceReferenceSource {
         period: usize,
     },
     #[serde(rename = "VWAP")]
-    VWAP { period: usize },
+    VWAP {
+        /// 롤링 기간 (롤링 앵커에서만 사용)
+        #[serde(default)]
+        period: usize,
+        #[serde(default)]
+        anchor: VWAPAnchorMode,
+    },
     #[serde(rename = "HIGHEST_HIGH")]
     HighestHigh {
         lookback_period: usize,
@@ -2051,6 +2207,14 @@ impl_filter_type_display!(
     SupportResistanceFilterType,
     MomentumFilterType,
     SlopeFilterType,
+    CompareFilterType,
+    StochasticFilterType,
+    StochRSIFilterType,
+    WilliamsRFilterType,
+    CCIFilterType,
+    KeltnerFilterType,
+    DonchianFilterType,
+    SqueezeFilterType,
 );
 
 /// Slope 필터 파라미터
@@ -2093,9 +2257,570 @@ impl Default for SlopeParams {
     }
 }
 
+/// Compare 필터 피연산자
+///
+/// 숫자는 상수로, 테이블은 `IndicatorType` 지표로 읽습니다 (예: `70`, `{ type = "ClosePrice" }`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CompareOperand {
+    /// 상수
+    Constant(f64),
+    /// 지표 값
+    Indicator(crate::analyzer::IndicatorType),
+}
+
+/// Compare 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum CompareFilterType {
+    /// lhs > rhs
+    Greater,
+    /// lhs >= rhs
+    GreaterOrEqual,
+    /// lhs < rhs
+    Less,
+    /// lhs <= rhs
+    LessOrEqual,
+    /// lhs가 rhs를 상향 돌파
+    CrossAbove,
+    /// lhs가 rhs를 하향 돌파
+    CrossBelow,
+}
+
+impl_filter_type_fromstr!(
+    CompareFilterType,
+    UnknownCompareFilterType,
+    no_parse_i32,
+    [
+        Greater,
+        GreaterOrEqual,
+        Less,
+        LessOrEqual,
+        CrossAbove,
+        CrossBelow,
+    ]
+);
+
+impl_filter_type_deserialize!(CompareFilterType, CompareFilterTypeVisitor, "Compare");
+
+/// Compare 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompareParams {
+    /// 좌변 (기본값: 종가)
+    pub lhs: CompareOperand,
+    /// 우변 (기본값: 0)
+    pub rhs: CompareOperand,
+    /// 필터 유형
+    pub filter_type: CompareFilterType,
+    /// 연속 캔들 수 (기본값: 1, 교차 타입은 사용하지 않음)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for CompareParams {
+    fn default() -> Self {
+        Self {
+            lhs: CompareOperand::Indicator(crate::analyzer::IndicatorType::ClosePrice),
+            rhs: CompareOperand::Constant(0.0),
+            filter_type: CompareFilterType::Greater,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
+/// Stochastic 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum StochasticFilterType {
+    /// %K > overbought
+    Overbought,
+    /// %K < oversold
+    Oversold,
+    /// oversold <= %K <= overbought
+    NormalRange,
+    /// %K > %D
+    KAboveD,
+    /// %K < %D
+    KBelowD,
+    /// %K가 %D를 상향 돌파
+    KCrossAboveD,
+    /// %K가 %D를 하향 돌파
+    KCrossBelowD,
+    /// %K가 oversold를 상향 돌파
+    CrossAboveOversold,
+    /// %K가 overbought를 하향 돌파
+    CrossBelowOverbought,
+}
+
+impl_filter_type_fromstr!(
+    StochasticFilterType,
+    UnknownStochasticFilterType,
+    parse_i32,
+    [
+        Overbought,
+        Oversold,
+        NormalRange,
+        KAboveD,
+        KBelowD,
+        KCrossAboveD,
+        KCrossBelowD,
+        CrossAboveOversold,
+        CrossBelowOverbought,
+    ]
+);
+
+impl_filter_type_deserialize!(
+    StochasticFilterType,
+    StochasticFilterTypeVisitor,
+    "Stochastic"
+);
+
+/// Stochastic 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StochasticParams {
+    /// %K 계산 기간 (기본값: 14)
+    pub k_period: usize,
+    /// %D 계산 기간 (기본값: 3)
+    pub d_period: usize,
+    /// 과매수 기준점 (기본값: 80)
+    pub overbought: f64,
+    /// 과매도 기준점 (기본값: 20)
+    pub oversold: f64,
+    /// 필터 유형
+    pub filter_type: StochasticFilterType,
+    /// 연속 캔들 수 (기본값: 1)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for StochasticParams {
+    fn default() -> Self {
+        Self {
+            k_period: 14,
+            d_period: 3,
+            overbought: 80.0,
+            oversold: 20.0,
+            filter_type: StochasticFilterType::Overbought,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
+/// StochRSI 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum StochRSIFilterType {
+    /// %K > overbought
+    Overbought,
+    /// %K < oversold
+    Oversold,
+    /// oversold <= %K <= overbought
+    NormalRange,
+    /// %K > %D
+    KAboveD,
+    /// %K < %D
+    KBelowD,
+    /// %K가 %D를 상향 돌파
+    KCrossAboveD,
+    /// %K가 %D를 하향 돌파
+    KCrossBelowD,
+    /// %K가 oversold를 상향 돌파
+    CrossAboveOversold,
+    /// %K가 overbought를 하향 돌파
+    CrossBelowOverbought,
+}
+
+impl_filter_type_fromstr!(
+    StochRSIFilterType,
+    UnknownStochRsiFilterType,
+    parse_i32,
+    [
+        Overbought,
+        Oversold,
+        NormalRange,
+        KAboveD,
+        KBelowD,
+        KCrossAboveD,
+        KCrossBelowD,
+        CrossAboveOversold,
+        CrossBelowOverbought,
+    ]
+);
+
+impl_filter_type_deserialize!(StochRSIFilterType, StochRSIFilterTypeVisitor, "StochRSI");
+
+/// StochRSI 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StochRSIParams {
+    /// RSI 계산 기간 (기본값: 14)
+    pub rsi_period: usize,
+    /// RSI 최고/최저 구간 기간 (기본값: 14)
+    pub stoch_period: usize,
+    /// %K 평활 기간 (기본값: 3)
+    pub k_period: usize,
+    /// %D 계산 기간 (기본값: 3)
+    pub d_period: usize,
+    /// 과매수 기준점 (기본값: 80)
+    pub overbought: f64,
+    /// 과매도 기준점 (기본값: 20)
+    pub oversold: f64,
+    /// 필터 유형
+    pub filter_type: StochRSIFilterType,
+    /// 연속 캔들 수 (기본값: 1)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for StochRSIParams {
+    fn default() -> Self {
+        Self {
+            rsi_period: 14,
+            stoch_period: 14,
+            k_period: 3,
+            d_period: 3,
+            overbought: 80.0,
+            oversold: 20.0,
+            filter_type: StochRSIFilterType::Overbought,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
+/// WilliamsR 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum WilliamsRFilterType {
+    /// %R > overbought
+    Overbought,
+    /// %R < oversold
+    Oversold,
+    /// oversold <= %R <= overbought
+    NormalRange,
+    /// %R이 oversold를 상향 돌파
+    CrossAboveOversold,
+    /// %R이 overbought를 하향 돌파
+    CrossBelowOverbought,
+    /// %R이 중심선(-50)을 상향 돌파
+    CrossAboveMidline,
+    /// %R이 중심선(-50)을 하향 돌파
+    CrossBelowMidline,
+}
+
+impl_filter_type_fromstr!(
+    WilliamsRFilterType,
+    UnknownWilliamsRFilterType,
+    parse_i32,
+    [
+        Overbought,
+        Oversold,
+        NormalRange,
+        CrossAboveOversold,
+        CrossBelowOverbought,
+        CrossAboveMidline,
+        CrossBelowMidline,
+    ]
+);
+
+impl_filter_type_deserialize!(WilliamsRFilterType, WilliamsRFilterTypeVisitor, "WilliamsR");
+
+/// WilliamsR 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WilliamsRParams {
+    /// Williams %R 계산 기간 (기본값: 14)
+    pub period: usize,
+    /// 과매수 기준점 (기본값: -20)
+    pub overbought: f64,
+    /// 과매도 기준점 (기본값: -80)
+    pub oversold: f64,
+    /// 필터 유형
+    pub filter_type: WilliamsRFilterType,
+    /// 연속 캔들 수 (기본값: 1)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for WilliamsRParams {
+    fn default() -> Self {
+        Self {
+            period: 14,
+            overbought: -20.0,
+            oversold: -80.0,
+            filter_type: WilliamsRFilterType::Overbought,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
+/// CCI 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum CCIFilterType {
+    /// CCI > overbought
+    Overbought,
+    /// CCI < oversold
+    Oversold,
+    /// oversold <= CCI <= overbought
+    NormalRange,
+    /// CCI가 oversold를 상향 돌파
+    CrossAboveOversold,
+    /// CCI가 overbought를 하향 돌파
+    CrossBelowOverbought,
+    /// CCI가 0을 상향 돌파
+    CrossAboveZero,
+    /// CCI가 0을 하향 돌파
+    CrossBelowZero,
+}
+
+impl_filter_type_fromstr!(
+    CCIFilterType,
+    UnknownCciFilterType,
+    parse_i32,
+    [
+        Overbought,
+        Oversold,
+        NormalRange,
+        CrossAboveOversold,
+        CrossBelowOverbought,
+        CrossAboveZero,
+        CrossBelowZero,
+    ]
+);
+
+impl_filter_type_deserialize!(CCIFilterType, CCIFilterTypeVisitor, "CCI");
+
+/// CCI 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CCIParams {
+    /// CCI 계산 기간 (기본값: 20)
+    pub period: usize,
+    /// 과매수 기준점 (기본값: 100)
+    pub overbought: f64,
+    /// 과매도 기준점 (기본값: -100)
+    pub oversold: f64,
+    /// 필터 유형
+    pub filter_type: CCIFilterType,
+    /// 연속 캔들 수 (기본값: 1)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for CCIParams {
+    fn default() -> Self {
+        Self {
+            period: 20,
+            overbought: 100.0,
+            oversold: -100.0,
+            filter_type: CCIFilterType::Overbought,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
+/// Keltner 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum KeltnerFilterType {
+    /// 종가 > 상단 밴드
+    AboveUpper,
+    /// 종가 < 하단 밴드
+    BelowLower,
+    /// 하단 <= 종가 <= 상단
+    InsideChannel,
+    /// 종가 > 중심선
+    AboveMiddle,
+    /// 종가 < 중심선
+    BelowMiddle,
+    /// 종가가 상단 밴드를 상향 돌파
+    BreakoutUp,
+    /// 종가가 하단 밴드를 하향 돌파
+    BreakoutDown,
+}
+
+impl_filter_type_fromstr!(
+    KeltnerFilterType,
+    UnknownKeltnerFilterType,
+    parse_i32,
+    [
+        AboveUpper,
+        BelowLower,
+        InsideChannel,
+        AboveMiddle,
+        BelowMiddle,
+        BreakoutUp,
+        BreakoutDown,
+    ]
+);
+
+impl_filter_type_deserialize!(KeltnerFilterType, KeltnerFilterTypeVisitor, "Keltner");
+
+/// Keltner 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeltnerParams {
+    /// 중심선 EMA 기간 (기본값: 20)
+    pub period: usize,
+    /// ATR 기간 (기본값: 10)
+    pub atr_period: usize,
+    /// ATR 승수 (기본값: 2.0)
+    pub multiplier: f64,
+    /// 필터 유형
+    pub filter_type: KeltnerFilterType,
+    /// 연속 캔들 수 (기본값: 1)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for KeltnerParams {
+    fn default() -> Self {
+        Self {
+            period: 20,
+            atr_period: 10,
+            multiplier: 2.0,
+            filter_type: KeltnerFilterType::BreakoutUp,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
+/// Donchian 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum DonchianFilterType {
+    /// 종가 > 직전 채널 상단
+    BreakoutUp,
+    /// 종가 < 직전 채널 하단
+    BreakoutDown,
+    /// 직전 채널 하단 <= 종가 <= 직전 채널 상단
+    InsideChannel,
+    /// 종가 > 채널 중심선
+    AboveMiddle,
+    /// 종가 < 채널 중심선
+    BelowMiddle,
+}
+
+impl_filter_type_fromstr!(
+    DonchianFilterType,
+    UnknownDonchianFilterType,
+    parse_i32,
+    [
+        BreakoutUp,
+        BreakoutDown,
+        InsideChannel,
+        AboveMiddle,
+        BelowMiddle,
+    ]
+);
+
+impl_filter_type_deserialize!(DonchianFilterType, DonchianFilterTypeVisitor, "Donchian");
+
+/// Donchian 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DonchianParams {
+    /// 채널 계산 기간 (기본값: 20)
+    pub period: usize,
+    /// 필터 유형
+    pub filter_type: DonchianFilterType,
+    /// 연속 캔들 수 (기본값: 1)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for DonchianParams {
+    fn default() -> Self {
+        Self {
+            period: 20,
+            filter_type: DonchianFilterType::BreakoutUp,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
+/// Squeeze 필터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SqueezeFilterType {
+    /// 볼린저 밴드가 켈트너 채널 안쪽 (스퀴즈 상태)
+    SqueezeOn,
+    /// 볼린저 밴드가 켈트너 채널 밖으로 나옴 (스퀴즈 아님)
+    SqueezeOff,
+    /// 스퀴즈 상태에서 막 해제됨
+    SqueezeFired,
+    /// 스퀴즈 해제 + 종가 > 볼린저 밴드 중심선
+    SqueezeFiredUp,
+    /// 스퀴즈 해제 + 종가 < 볼린저 밴드 중심선
+    SqueezeFiredDown,
+}
+
+impl_filter_type_fromstr!(
+    SqueezeFilterType,
+    UnknownSqueezeFilterType,
+    parse_i32,
+    [
+        SqueezeOn,
+        SqueezeOff,
+        SqueezeFired,
+        SqueezeFiredUp,
+        SqueezeFiredDown,
+    ]
+);
+
+impl_filter_type_deserialize!(SqueezeFilterType, SqueezeFilterTypeVisitor, "Squeeze");
+
+/// Squeeze 필터 파라미터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SqueezeParams {
+    /// 볼린저 밴드 기간 (기본값: 20)
+    pub bb_period: usize,
+    /// 볼린저 밴드 표준편차 승수 (기본값: 2.0)
+    pub bb_multiplier: f64,
+    /// 켈트너 채널 EMA 기간 (기본값: 20)
+    pub kc_period: usize,
+    /// 켈트너 채널 ATR 기간 (기본값: 20)
+    pub kc_atr_period: usize,
+    /// 켈트너 채널 ATR 승수 (기본값: 1.5)
+    pub kc_multiplier: f64,
+    /// 필터 유형
+    pub filter_type: SqueezeFilterType,
+    /// 연속 캔들 수 (기본값: 1)
+    pub consecutive_n: usize,
+    /// 과거 시점 확인을 위한 오프셋 (기본값: 0)
+    pub p: usize,
+}
+
+impl Default for SqueezeParams {
+    fn default() -> Self {
+        Self {
+            bb_period: 20,
+            bb_multiplier: 2.0,
+            kc_period: 20,
+            kc_atr_period: 20,
+            kc_multiplier: 1.5,
+            filter_type: SqueezeFilterType::SqueezeOn,
+            consecutive_n: 1,
+            p: 0,
+        }
+    }
+}
+
 /// 기술적 필터 설정
+///
+/// 직렬화는 아래 `Serialize`/`Deserialize` 구현에서 사용자 정의 필터를 먼저 처리하고,
+/// 기본 필터는 derive로 생성된 함수(`remote = "Self"`)에 맡깁니다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", deny_unknown_fields)]
+#[serde(remote = "Self", tag = "type", deny_unknown_fields)]
 pub enum TechnicalFilterConfig {
     /// RSI 필터 설정
     RSI(RSIParams),
@@ -2143,6 +2868,84 @@ pub enum TechnicalFilterConfig {
     /// Slope 필터 설정
     #[serde(rename = "SLOPE")]
     Slope(SlopeParams),
+    /// Compare 필터 설정
+    #[serde(rename = "COMPARE")]
+    Compare(CompareParams),
+    /// Stochastic 필터 설정
+    #[serde(rename = "STOCHASTIC")]
+    Stochastic(StochasticParams),
+    /// StochRSI 필터 설정
+    #[serde(rename = "STOCH_RSI")]
+    StochRSI(StochRSIParams),
+    /// WilliamsR 필터 설정
+    #[serde(rename = "WILLIAMS_R")]
+    WilliamsR(WilliamsRParams),
+    /// CCI 필터 설정
+    #[serde(rename = "CCI")]
+    CCI(CCIParams),
+    /// Keltner 필터 설정
+    #[serde(rename = "KELTNER")]
+    Keltner(KeltnerParams),
+    /// Donchian 필터 설정
+    #[serde(rename = "DONCHIAN")]
+    Donchian(DonchianParams),
+    /// Squeeze 필터 설정
+    #[serde(rename = "SQUEEZE")]
+    Squeeze(SqueezeParams),
+    /// 사용자 정의 필터 설정 (`CustomFilterRegistry`에 등록된 `type`)
+    #[serde(skip)]
+    Custom(CustomFilterConfig),
+    /// 캔들 간격을 지정한 필터 설정 (필터 항목의 `interval` 키)
+    #[serde(skip)]
+    Timeframe(TimeframeFilterConfig),
+}
+
+impl Serialize for TechnicalFilterConfig {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Custom(config) => config.serialize(serializer),
+            Self::Timeframe(config) => config.serialize(serializer),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+
+/// `interval` 키가 있으면 나머지 키로 읽은 필터를 간격 필터로 감싸고,
+/// 등록된 사용자 정의 필터 `type`이면 나머지 키를 그대로 보관하고, 아니면 기본 필터로 파싱
+impl<'de> Deserialize<'de> for TechnicalFilterConfig {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if let serde_json::Value::Object(mut params) = value {
+            if let Some(interval) = params.remove("interval") {
+                let interval = String::deserialize(interval).map_err(de::Error::custom)?;
+                let filter = <Self as Deserialize>::deserialize(serde_json::Value::Object(params))
+                    .map_err(de::Error::custom)?;
+                let config = TimeframeFilterConfig::new(interval, filter);
+                config.candle_interval().map_err(de::Error::custom)?;
+                return Ok(Self::Timeframe(config));
+            }
+
+            let custom_type = params
+                .get("type")
+                .and_then(serde_json::Value::as_str)
+                .filter(|kind| CustomFilterRegistry::is_registered_any(kind))
+                .map(str::to_string);
+
+            if let Some(filter_type) = custom_type {
+                params.remove("type");
+                return Ok(Self::Custom(CustomFilterConfig::new(filter_type, params)));
+            }
+            return Self::deserialize(serde_json::Value::Object(params)).map_err(de::Error::custom);
+        }
+
+        Self::deserialize(value).map_err(de::Error::custom)
+    }
 }
 
 impl TechnicalFilterConfig {
@@ -2165,10 +2968,61 @@ impl TechnicalFilterConfig {
             Self::SupportResistance(_) => TechnicalFilterType::SupportResistance,
             Self::Momentum(_) => TechnicalFilterType::Momentum,
             Self::Slope(_) => TechnicalFilterType::Slope,
+            Self::Compare(_) => TechnicalFilterType::Compare,
+            Self::Stochastic(_) => TechnicalFilterType::Stochastic,
+            Self::StochRSI(_) => TechnicalFilterType::StochRSI,
+            Self::WilliamsR(_) => TechnicalFilterType::WilliamsR,
+            Self::CCI(_) => TechnicalFilterType::CCI,
+            Self::Keltner(_) => TechnicalFilterType::Keltner,
+            Self::Donchian(_) => TechnicalFilterType::Donchian,
+            Self::Squeeze(_) => TechnicalFilterType::Squeeze,
+            Self::Custom(config) => TechnicalFilterType::Custom(config.filter_type.clone()),
+            Self::Timeframe(config) => config.filter.filter_type(),
+        }
+    }
+
+    /// 필터 평가에 필요한 최소 캔들 수
+    ///
+    /// 보유 캔들 수가 이보다 적으면 필터는 지표를 계산하지 않고 통과시키지 않습니다.
+    /// 사용자 정의 필터는 캔들 타입 `C`로 등록된 필터의 값을 사용합니다.
+    pub fn required_candles<C: Candle + 'static>(&self) -> usize {
+        match self {
+            Self::RSI(params) => rsi::required_candles(params),
+            Self::MACD(params) => macd::required_candles(params),
+            Self::BollingerBand(params) => bollinger_band::required_candles(params),
+            Self::ADX(params) => adx::required_candles(params),
+            Self::MovingAverage(params) => moving_average::required_candles(params),
+            Self::PriceReferenceGap(params) => price_reference_gap::required_candles(params),
+            Self::Ichimoku(params) => ichimoku::required_candles(params),
+            Self::VWAP(params) => vwap::required_candles(params),
+            // CopyS 필터는 캔들 수를 확인하지 않음
+            Self::Copys(_) => 0,
+            Self::ATR(params) => atr::required_candles(params),
+            Self::SuperTrend(params) => supertrend::required_candles(params),
+            Self::Volume(params) => volume::required_candles(params),
+            Self::ThreeRSI(params) => three_rsi::required_candles(params),
+            Self::CandlePattern(params) => candle_pattern::required_candles(params),
+            Self::SupportResistance(params) => support_resistance::required_candles(params),
+            Self::Momentum(params) => momentum::required_candles(params),
+            Self::Slope(params) => slope::required_candles(params),
+            Self::Compare(params) => compare::required_candles(params),
+            Self::Stochastic(params) => stochastic::required_candles(params),
+            Self::StochRSI(params) => stoch_rsi::required_candles(params),
+            Self::WilliamsR(params) => williams_r::required_candles(params),
+            Self::CCI(params) => cci::required_candles(params),
+            Self::Keltner(params) => keltner::required_candles(params),
+            Self::Donchian(params) => donchian::required_candles(params),
+            Self::Squeeze(params) => squeeze::required_candles(params),
+            Self::Custom(config) => custom::required_candles::<C>(config),
+            // 지정한 간격의 캔들 수 기준
+            Self::Timeframe(config) => config.filter.required_candles::<C>(),
         }
     }
 
-    pub fn validate(&self) -> Result<()> {
+    /// 필터 파라미터 검증
+    ///
+    /// 사용자 정의 필터는 캔들 타입 `C`로 등록되어 있는지 확인합니다.
+    pub fn validate<C: Candle + 'static>(&self) -> Result<()> {
         match self {
             Self::RSI(params) => {
                 utils::validate_period(params.period, "RSI")?;
@@ -2283,19 +3137,14 @@ impl TechnicalFilterConfig {
                 )?;
 
                 match &params.reference_source {
-                    PriceReferenceSource::MovingAverage { ma_type, period } => {
-                        utils::validate_period(*period, "PriceReferenceGap moving_average period")?;
-
-                        match ma_type {
-                            crate::indicator::ma::MAType::EMA
-                            | crate::indicator::ma::MAType::SMA => Ok(()),
-                            _ => Err(FilterError::UnsupportedPriceReferenceGapMaType {
-                                ma_type: ma_type.to_string(),
-                            }),
-                        }
+                    PriceReferenceSource::MovingAverage { period, .. } => {
+                        utils::validate_period(*period, "PriceReferenceGap moving_average period")
                     }
-                    PriceReferenceSource::VWAP { period } => {
-                        utils::validate_period(*period, "PriceReferenceGap VWAP period")
+                    PriceReferenceSource::VWAP { period, anchor } => {
+                        if *anchor == VWAPAnchorMode::Rolling {
+                            utils::validate_period(*period, "PriceReferenceGap VWAP period")?;
+                        }
+                        vwap::validate_anchor(anchor, "PriceReferenceGap VWAP")
                     }
                     PriceReferenceSource::HighestHigh {
                         lookback_period, ..
@@ -2336,8 +3185,10 @@ impl TechnicalFilterConfig {
             }
             Self::VWAP(params) => {
                 utils::validate_period(params.period, "VWAP")?;
+                vwap::validate_anchor(&params.anchor, "VWAP")?;
                 utils::validate_consecutive_n(params.consecutive_n, "VWAP consecutive_n")?;
-                utils::validate_non_negative_number(params.threshold, "VWAP threshold")
+                utils::validate_non_negative_number(params.threshold, "VWAP threshold")?;
+                utils::validate_positive_number(params.band_multiplier, "VWAP band_multiplier")
             }
             Self::Copys(params) => {
                 utils::validate_period(params.rsi_period, "Copys rsi_period")?;
@@ -2464,30 +3315,157 @@ impl TechnicalFilterConfig {
 
                 Ok(())
             }
+            Self::Compare(params) => {
+                compare::validate_operand(&params.lhs, "Compare lhs")?;
+                compare::validate_operand(&params.rhs, "Compare rhs")?;
+                utils::validate_consecutive_n(params.consecutive_n, "Compare consecutive_n")
+            }
+            Self::Stochastic(params) => {
+                utils::validate_period(params.k_period, "Stochastic k_period")?;
+                utils::validate_period(params.d_period, "Stochastic d_period")?;
+                utils::validate_percentage_threshold(params.overbought, "Stochastic overbought")?;
+                utils::validate_percentage_threshold(params.oversold, "Stochastic oversold")?;
+                utils::validate_consecutive_n(params.consecutive_n, "Stochastic consecutive_n")
+            }
+            Self::StochRSI(params) => {
+                utils::validate_period(params.rsi_period, "StochRSI rsi_period")?;
+                utils::validate_period(params.stoch_period, "StochRSI stoch_period")?;
+                utils::validate_period(params.k_period, "StochRSI k_period")?;
+                utils::validate_period(params.d_period, "StochRSI d_period")?;
+                utils::validate_percentage_threshold(params.overbought, "StochRSI overbought")?;
+                utils::validate_percentage_threshold(params.oversold, "StochRSI oversold")?;
+                utils::validate_consecutive_n(params.consecutive_n, "StochRSI consecutive_n")
+            }
+            Self::WilliamsR(params) => {
+                utils::validate_period(params.period, "WilliamsR")?;
+                utils::validate_negative_percentage_threshold(
+                    params.overbought,
+                    "WilliamsR overbought",
+                )?;
+                utils::validate_negative_percentage_threshold(
+                    params.oversold,
+                    "WilliamsR oversold",
+                )?;
+                utils::validate_consecutive_n(params.consecutive_n, "WilliamsR consecutive_n")
+            }
+            Self::CCI(params) => {
+                utils::validate_period(params.period, "CCI")?;
+                utils::validate_consecutive_n(params.consecutive_n, "CCI consecutive_n")
+            }
+            Self::Keltner(params) => {
+                utils::validate_period(params.period, "Keltner")?;
+                utils::validate_period(params.atr_period, "Keltner atr_period")?;
+                utils::validate_positive_number(params.multiplier, "Keltner multiplier")?;
+                utils::validate_consecutive_n(params.consecutive_n, "Keltner consecutive_n")
+            }
+            Self::Donchian(params) => {
+                utils::validate_period(params.period, "Donchian")?;
+                utils::validate_consecutive_n(params.consecutive_n, "Donchian consecutive_n")
+            }
+            Self::Squeeze(params) => {
+                utils::validate_period(params.bb_period, "Squeeze bb_period")?;
+                utils::validate_positive_number(params.bb_multiplier, "Squeeze bb_multiplier")?;
+                utils::validate_period(params.kc_period, "Squeeze kc_period")?;
+                utils::validate_period(params.kc_atr_period, "Squeeze kc_atr_period")?;
+                utils::validate_positive_number(params.kc_multiplier, "Squeeze kc_multiplier")?;
+                utils::validate_consecutive_n(params.consecutive_n, "Squeeze consecutive_n")
+            }
+            // 파라미터는 평가 시 등록된 필터의 `validate`로 검증
+            Self::Custom(config) => {
+                if CustomFilterRegistry::is_registered::<C>(&config.filter_type) {
+                    Ok(())
+                } else {
+                    Err(FilterError::UnregisteredCustomFilter {
+                        filter_type: config.filter_type.clone(),
+                    })
+                }
+            }
+            Self::Timeframe(config) => {
+                config.candle_interval()?;
+                config.filter.validate::<C>()
+            }
         }
     }
 }
 
 // Filter functions are now pub(crate) and accessed through TechnicalFilter::matches_filter
+use cache::FilterSource;
+pub use catalog::{
+    FilterCatalog, FilterSpec, FilterTypeVariant, ParamKind, ParamRange, ParamSpec, PeriodOrder,
+};
+pub use custom::{CustomFilter, CustomFilterConfig, CustomFilterRegistry};
+pub use expression::{FilterExpression, FilterGroup};
+pub use hit_rate::{FilterSignal, ForwardReturn, HitRateConfig, HitRateReport, HorizonStats};
 pub use ichimoku::IchimokuValues;
+pub use report::{FilterReport, FiltersReport, OffsetValues};
+pub use score::WeightedFilter;
+pub use screener::{
+    FilterOutcome, FilterScore, Screener, ScreenerResult, SymbolScore, SymbolScreening,
+};
+pub use timeframe::TimeframeFilterConfig;
+use timeframe::TimeframeStores;
 
 /// 기술적 지표 필터링 적용
 pub struct TechnicalFilter;
 
 /// 캔들 기반 필터 상태를 재사용하면서 현재가만 바꿔 평가하는 컨텍스트
+///
+/// 캔들은 간격별 저장소에 나눠 보관하므로 시각이 같은 1시간봉과 일봉도 서로 덮어쓰지 않습니다.
+/// `interval`을 지정하지 않은 필터는 기준 간격(기본값: 첫 캔들의 간격)의 캔들로 평가합니다.
 pub struct TechnicalFilterContext<C: Candle + 'static> {
-    candle_store: crate::candle_store::CandleStore<C>,
+    timeframes: TimeframeStores<C>,
 }
 
 impl<C: Candle + 'static> TechnicalFilterContext<C> {
     pub fn new(candles: &[C]) -> Self {
+        Self::from_candles(candles.to_vec(), candles.len() * 2, false)
+    }
+
+    /// 캔들 목록으로 생성 (간격별로 나눈 뒤 저장소 크기 제한과 중복 제거 적용)
+    pub fn from_candles(candles: Vec<C>, max_size: usize, use_duplicated_filter: bool) -> Self {
         Self {
-            candle_store: utils::create_candle_store(candles),
+            timeframes: TimeframeStores::new(candles, max_size, use_duplicated_filter),
         }
     }
 
+    /// 캔들 저장소로 생성
+    ///
+    /// 저장소는 시각만으로 중복을 제거하므로, 간격이 다른 캔들을 함께 넣을 때는 `from_candles`를 사용합니다.
     pub fn from_candle_store(candle_store: crate::candle_store::CandleStore<C>) -> Self {
-        Self { candle_store }
+        Self::from_candles(
+            candle_store.items().to_vec(),
+            candle_store.max_size,
+            candle_store.use_duplicated_filter,
+        )
+    }
+
+    /// `interval`을 지정하지 않은 필터를 평가할 캔들 간격 지정
+    pub fn with_base_interval(mut self, interval: CandleInterval) -> Self {
+        self.timeframes.set_base_interval(interval);
+        self
+    }
+
+    /// `interval`을 지정하지 않은 필터를 평가하는 캔들 간격 (캔들이 없으면 None)
+    pub fn base_interval(&self) -> Option<&CandleInterval> {
+        self.timeframes.base_interval()
+    }
+
+    /// 캔들 증분 추가 (같은 간격, 같은 시각의 캔들은 교체)
+    ///
+    /// 캔들 간격의 저장소에만 반영하며, 최신 캔들 뒤에 추가되면 캐시된 지표에 캔들 하나만 반영합니다.
+    /// 마감 전 캔들 교체나 과거 캔들 삽입은 그 간격의 캐시만 비우고 다음 평가에서 다시 계산합니다.
+    pub fn update(&mut self, candle: C) {
+        self.timeframes.update(candle);
+    }
+
+    /// 캐시된 지표 수 (필터 파라미터 조합별, 간격별 캐시 포함)
+    pub fn cached_indicators(&self) -> usize {
+        self.timeframes.cached_indicators()
+    }
+
+    /// 기준 간격의 캔들 저장소
+    pub fn candle_store(&self) -> &crate::candle_store::CandleStore<C> {
+        self.timeframes.base_candle_store()
     }
 
     pub fn matches_filter(
@@ -2496,7 +3474,7 @@ impl<C: Candle + 'static> TechnicalFilterContext<C> {
         filter: &TechnicalFilterConfig,
         current_price: f64,
     ) -> Result<bool> {
-        TechnicalFilter::matches_filter_internal(symbol, filter, &self.candle_store, current_price)
+        TechnicalFilter::matches_filter_internal(symbol, filter, self.source(), current_price)
     }
 
     pub fn matches_filters(
@@ -2505,13 +3483,56 @@ impl<C: Candle + 'static> TechnicalFilterContext<C> {
         filters: &[TechnicalFilterConfig],
         current_price: f64,
     ) -> Result<bool> {
-        TechnicalFilter::matches_filters_internal(
+        TechnicalFilter::matches_filters_internal(symbol, filters, self.source(), current_price)
+    }
+
+    pub fn matches_expression(
+        &self,
+        symbol: &str,
+        expression: &FilterExpression,
+        current_price: f64,
+    ) -> Result<bool> {
+        TechnicalFilter::matches_expression_internal(
             symbol,
-            filters,
-            &self.candle_store,
+            expression,
+            self.source(),
             current_price,
         )
     }
+
+    /// 필터 점수 (0..1, 통과하지 못하면 0)
+    pub fn score_filter(
+        &self,
+        symbol: &str,
+        filter: &TechnicalFilterConfig,
+        current_price: f64,
+    ) -> Result<f64> {
+        score::score_filter(symbol, filter, self.source(), current_price)
+    }
+
+    /// 가중 평균 필터 점수 (0..1)
+    pub fn score_filters(
+        &self,
+        symbol: &str,
+        filters: &[WeightedFilter],
+        current_price: f64,
+    ) -> Result<f64> {
+        score::score_filters(symbol, filters, self.source(), current_price)
+    }
+
+    /// 필터별 평가 리포트 (모든 필터를 평가)
+    pub fn matches_filters_with_report(
+        &self,
+        symbol: &str,
+        filters: &[TechnicalFilterConfig],
+        current_price: f64,
+    ) -> FiltersReport {
+        report::filters_report(symbol, filters, self.source(), current_price)
+    }
+
+    fn source(&self) -> FilterSource<'_, C> {
+        self.timeframes.base_source()
+    }
 }
 
 impl TechnicalFilter {
@@ -2522,9 +3543,8 @@ impl TechnicalFilter {
         candles: &[C],
         current_price: f64,
     ) -> Result<bool> {
-        // CandleStore를 생성하고 내부 matches_filter_internal 사용
-        let candle_store = utils::create_candle_store(candles);
-        Self::matches_filter_internal(symbol, filter, &candle_store, current_price)
+        // 간격별 저장소를 만들고 컨텍스트로 평가
+        TechnicalFilterContext::new(candles).matches_filter(symbol, filter, current_price)
     }
 
     /// 개별 코인에 여러 기술적 필터 적용
@@ -2534,15 +3554,98 @@ impl TechnicalFilter {
         candles: &[C],
         current_price: f64,
     ) -> Result<bool> {
-        // CandleStore를 한 번만 생성하여 재사용
-        let candle_store = utils::create_candle_store(candles);
-        Self::matches_filters_internal(symbol, filters, &candle_store, current_price)
+        // 간격별 저장소를 한 번만 생성하여 재사용
+        TechnicalFilterContext::new(candles).matches_filters(symbol, filters, current_price)
+    }
+
+    /// 개별 코인에 필터 조건식 적용
+    ///
+    /// `matches_filters`와 달리 필터 적용 오류를 false로 바꾸지 않고 그대로 반환합니다.
+    pub fn matches_expression<C: Candle + 'static>(
+        symbol: &str,
+        expression: &FilterExpression,
+        candles: &[C],
+        current_price: f64,
+    ) -> Result<bool> {
+        TechnicalFilterContext::new(candles).matches_expression(symbol, expression, current_price)
+    }
+
+    /// 개별 코인에 대한 필터 점수 계산
+    ///
+    /// 통과하지 못한 필터는 0, 통과한 필터는 기준선을 넘어선 정도를 0..1로 정규화한 값을 반환합니다.
+    /// (예: RSI가 과매도 기준 아래로 들어간 깊이, 현재가가 볼린저 밴드를 벗어난 폭, ADX가 임계값을 넘어선 정도)
+    /// 교차처럼 한 시점의 사건을 판정하는 세부 타입은 통과 시 1을 반환하고,
+    /// 강도를 정의할 수 없는 필터 종류(CopyS, 캔들 패턴, 지지/저항, 지표 비교, 사용자 정의)는
+    /// `FilterError::UnscorableFilterType` 에러를 반환합니다.
+    pub fn score_filter<C: Candle + 'static>(
+        symbol: &str,
+        filter: &TechnicalFilterConfig,
+        candles: &[C],
+        current_price: f64,
+    ) -> Result<f64> {
+        TechnicalFilterContext::new(candles).score_filter(symbol, filter, current_price)
+    }
+
+    /// 개별 코인에 대한 가중 평균 필터 점수 계산
+    ///
+    /// 필터별 점수를 가중치로 평균한 0..1 값을 반환합니다. 필터 적용 오류는 해당 필터 점수를 0으로 처리합니다.
+    /// 강도를 정의할 수 없는 필터 종류가 있으면 에러를 반환합니다.
+    pub fn score_filters<C: Candle + 'static>(
+        symbol: &str,
+        filters: &[WeightedFilter],
+        candles: &[C],
+        current_price: f64,
+    ) -> Result<f64> {
+        TechnicalFilterContext::new(candles).score_filters(symbol, filters, current_price)
+    }
+
+    /// 개별 코인에 여러 기술적 필터를 적용하고 평가 근거를 리포트로 반환
+    ///
+    /// 필터마다 확인한 오프셋(`p..p+consecutive_n`)별 지표 값, 비교한 임계값,
+    /// 캔들 수 충족 여부와 최종 판정을 담습니다. 리포트는 JSON으로 직렬화할 수 있습니다.
+    /// `matches_filters`와 같은 판정을 내리지만, 탈락한 필터 뒤의 필터도 모두 평가합니다.
+    pub fn matches_filters_with_report<C: Candle + 'static>(
+        symbol: &str,
+        filters: &[TechnicalFilterConfig],
+        candles: &[C],
+        current_price: f64,
+    ) -> FiltersReport {
+        TechnicalFilterContext::new(candles).matches_filters_with_report(
+            symbol,
+            filters,
+            current_price,
+        )
+    }
+
+    /// 과거 캔들에서 필터 목록의 적중률 평가
+    ///
+    /// 캔들을 시간 순서대로 재생하며 모든 필터(AND)를 통과한 캔들을 신호로 기록하고,
+    /// 신호 캔들 종가 기준으로 `horizons` 캔들 뒤의 수익률과 구간 내 MAE/MFE를 집계합니다.
+    /// 필터 목록이 비어 있으면 모든 캔들이 신호가 되므로 비교 기준선으로 사용할 수 있습니다.
+    pub fn evaluate_hit_rate<C: Candle + 'static>(
+        symbol: &str,
+        filters: &[TechnicalFilterConfig],
+        candles: &[C],
+        config: &HitRateConfig,
+    ) -> Result<HitRateReport> {
+        hit_rate::evaluate_hit_rate(symbol, filters, candles, config)
+    }
+
+    fn matches_expression_internal<C: Candle + 'static>(
+        symbol: &str,
+        expression: &FilterExpression,
+        source: FilterSource<'_, C>,
+        current_price: f64,
+    ) -> Result<bool> {
+        // 하위 필터를 평가하기 전에 조건식 구조 오류부터 확인
+        expression.validate::<C>()?;
+        expression.matches(symbol, source, current_price)
     }
 
     fn matches_filters_internal<C: Candle + 'static>(
         symbol: &str,
         filters: &[TechnicalFilterConfig],
-        candle_store: &crate::candle_store::CandleStore<C>,
+        source: FilterSource<'_, C>,
         current_price: f64,
     ) -> Result<bool> {
         for filter in filters {
@@ -2553,7 +3656,7 @@ impl TechnicalFilter {
             );
 
             // 각 필터 적용 결과 확인 (CandleStore 재사용)
-            match Self::matches_filter_internal(symbol, filter, candle_store, current_price) {
+            match Self::matches_filter_internal(symbol, filter, source, current_price) {
                 Ok(true) => {
                     // 필터 통과, 다음 필터로 진행
                     log::debug!("코인 {} 필터 {} 통과", symbol, filter.filter_type());
@@ -2586,77 +3689,111 @@ impl TechnicalFilter {
     fn matches_filter_internal<C: Candle + 'static>(
         symbol: &str,
         filter: &TechnicalFilterConfig,
-        candle_store: &crate::candle_store::CandleStore<C>,
+        source: FilterSource<'_, C>,
         current_price: f64,
     ) -> Result<bool> {
-        filter.validate()?;
+        filter.validate::<C>()?;
 
         match filter {
             TechnicalFilterConfig::RSI(params) => {
-                rsi::filter_rsi(symbol, params, candle_store, current_price)
+                rsi::filter_rsi(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::MACD(params) => {
-                macd::filter_macd(symbol, params, candle_store, current_price)
+                macd::filter_macd(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::BollingerBand(params) => {
-                bollinger_band::filter_bollinger_band(symbol, params, candle_store, current_price)
+                bollinger_band::filter_bollinger_band(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::ADX(params) => {
-                adx::filter_adx(symbol, params, candle_store, current_price)
+                adx::filter_adx(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::MovingAverage(params) => {
-                moving_average::filter_moving_average(symbol, params, candle_store, current_price)
+                moving_average::filter_moving_average(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::PriceReferenceGap(params) => {
                 price_reference_gap::filter_price_reference_gap(
                     symbol,
                     params,
-                    candle_store,
+                    source,
                     current_price,
                 )
             }
             TechnicalFilterConfig::Ichimoku(params) => {
-                ichimoku::filter_ichimoku(symbol, params, candle_store, current_price)
+                ichimoku::filter_ichimoku(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::VWAP(params) => {
-                vwap::filter_vwap(symbol, params, candle_store, current_price)
+                vwap::filter_vwap(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::Copys(params) => {
-                copys::filter_copys(symbol, params, candle_store, current_price)
+                copys::filter_copys(symbol, params, source.candle_store(), current_price)
             }
             TechnicalFilterConfig::ATR(params) => {
-                atr::filter_atr(symbol, params, candle_store, current_price)
+                atr::filter_atr(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::SuperTrend(params) => {
-                supertrend::filter_supertrend(symbol, params, candle_store, current_price)
+                supertrend::filter_supertrend(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::Volume(params) => {
-                volume::filter_volume(symbol, params, candle_store, current_price)
-            }
-            TechnicalFilterConfig::ThreeRSI(params) => {
-                let ma_type = match params.ma_type.as_str() {
-                    "EMA" => crate::indicator::ma::MAType::EMA,
-                    "WMA" => crate::indicator::ma::MAType::WMA,
-                    _ => crate::indicator::ma::MAType::SMA,
-                };
-                three_rsi::filter_three_rsi(symbol, params, candle_store, current_price, ma_type)
-            }
-            TechnicalFilterConfig::CandlePattern(params) => {
-                candle_pattern::filter_candle_pattern(symbol, params, candle_store, current_price)
+                volume::filter_volume(symbol, params, source, current_price)
             }
+            TechnicalFilterConfig::ThreeRSI(params) => three_rsi::filter_three_rsi(
+                symbol,
+                params,
+                source,
+                current_price,
+                three_rsi::ma_type(params),
+            ),
+            TechnicalFilterConfig::CandlePattern(params) => candle_pattern::filter_candle_pattern(
+                symbol,
+                params,
+                source.candle_store(),
+                current_price,
+            ),
             TechnicalFilterConfig::SupportResistance(params) => {
                 support_resistance::filter_support_resistance(
                     symbol,
                     params,
-                    candle_store,
+                    source.candle_store(),
                     current_price,
                 )
             }
             TechnicalFilterConfig::Momentum(params) => {
-                momentum::filter_momentum(symbol, params, candle_store, current_price)
+                momentum::filter_momentum(symbol, params, source, current_price)
             }
             TechnicalFilterConfig::Slope(params) => {
-                slope::filter_slope(symbol, params, candle_store, current_price)
+                slope::filter_slope(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::Compare(params) => {
+                compare::filter_compare(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::Stochastic(params) => {
+                stochastic::filter_stochastic(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::StochRSI(params) => {
+                stoch_rsi::filter_stoch_rsi(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::WilliamsR(params) => {
+                williams_r::filter_williams_r(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::CCI(params) => {
+                cci::filter_cci(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::Keltner(params) => {
+                keltner::filter_keltner(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::Donchian(params) => {
+                donchian::filter_donchian(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::Squeeze(params) => {
+                squeeze::filter_squeeze(symbol, params, source, current_price)
+            }
+            TechnicalFilterConfig::Custom(config) => {
+                custom::filter_custom(symbol, config, source.candle_store(), current_price)
+            }
+            TechnicalFilterConfig::Timeframe(config) => {
+                timeframe::with_source(config, source, |filter, source| {
+                    Self::matches_filter_internal(symbol, filter, source, current_price)
+                })?
             }
         }
     }
@@ -2667,7 +3804,8 @@ mod tests {
     use super::*;
     use crate::tests::TestCandle;
 
-    fn test_candle(timestamp: i64, close: f64, high: f64, low: f64) -> TestCandle {
+    /// 고가와 저가를 지정한 테스트 캔들 (시가는 종가와 같음)
+    fn test_candle_hl(timestamp: i64, close: f64, high: f64, low: f64) -> TestCandle {
         TestCandle {
             timestamp,
             open: close,
@@ -2814,6 +3952,7 @@ mod tests {
             consecutive_n,
             threshold,
             p: 0,
+            ..Default::default()
         })
     }
 
@@ -2980,7 +4119,10 @@ mod tests {
         }
 
         let price_gap_filter = create_price_reference_gap_filter(
-            PriceReferenceSource::VWAP { period: 20 },
+            PriceReferenceSource::VWAP {
+                period: 20,
+                anchor: VWAPAnchorMode::Rolling,
+            },
             PriceReferenceGapFilterType::GapAboveThreshold,
             0.03,
             2,
@@ -2988,7 +4130,10 @@ mod tests {
         if let TechnicalFilterConfig::PriceReferenceGap(params) = price_gap_filter {
             assert_eq!(
                 params.reference_source,
-                PriceReferenceSource::VWAP { period: 20 }
+                PriceReferenceSource::VWAP {
+                    period: 20,
+                    anchor: VWAPAnchorMode::Rolling,
+                }
             );
             assert_eq!(
                 params.filter_type,
@@ -3008,48 +4153,50 @@ mod tests {
             period: 0,
             ..RSIParams::default()
         });
-        assert!(invalid_rsi.validate().is_err());
+        assert!(invalid_rsi.validate::<TestCandle>().is_err());
 
         let invalid_rsi_cross_threshold = TechnicalFilterConfig::RSI(RSIParams {
             cross_threshold: 101.0,
             ..RSIParams::default()
         });
-        assert!(invalid_rsi_cross_threshold.validate().is_err());
+        assert!(
+            invalid_rsi_cross_threshold
+                .validate::<TestCandle>()
+                .is_err()
+        );
 
         let invalid_adx = TechnicalFilterConfig::ADX(ADXParams {
             threshold: 101.0,
             ..ADXParams::default()
         });
-        assert!(invalid_adx.validate().is_err());
+        assert!(invalid_adx.validate::<TestCandle>().is_err());
 
         let invalid_copys = TechnicalFilterConfig::Copys(CopysParams {
             rsi_period: 0,
             ..CopysParams::default()
         });
-        assert!(invalid_copys.validate().is_err());
+        assert!(invalid_copys.validate::<TestCandle>().is_err());
 
         let invalid_three_rsi_cross_threshold = TechnicalFilterConfig::ThreeRSI(ThreeRSIParams {
             cross_threshold: -1.0,
             ..ThreeRSIParams::default()
         });
-        assert!(invalid_three_rsi_cross_threshold.validate().is_err());
+        assert!(
+            invalid_three_rsi_cross_threshold
+                .validate::<TestCandle>()
+                .is_err()
+        );
 
         let invalid_price_gap_threshold =
             TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
                 gap_threshold: 1.5,
                 ..PriceReferenceGapParams::default()
             });
-        assert!(invalid_price_gap_threshold.validate().is_err());
-
-        let invalid_price_gap_ma_type =
-            TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
-                reference_source: PriceReferenceSource::MovingAverage {
-                    ma_type: crate::indicator::ma::MAType::WMA,
-                    period: 20,
-                },
-                ..PriceReferenceGapParams::default()
-            });
-        assert!(invalid_price_gap_ma_type.validate().is_err());
+        assert!(
+            invalid_price_gap_threshold
+                .validate::<TestCandle>()
+                .is_err()
+        );
     }
 
     #[test]
@@ -3190,6 +4337,48 @@ mod tests {
         assert_eq!(params.p, 0);
     }
 
+    #[test]
+    fn test_vwap_anchor_deserialize() {
+        // anchor를 생략하면 기존 롤링 VWAP
+        let params: VWAPParams = serde_json::from_str(r#"{"period":30}"#).unwrap();
+        assert_eq!(params.anchor, VWAPAnchorMode::Rolling);
+        assert_eq!(params.band_multiplier, 2.0);
+
+        let params: VWAPParams = serde_json::from_str(
+            r#"{"anchor":{"type":"DAY"},"filter_type":"PriceAboveUpperBand"}"#,
+        )
+        .unwrap();
+        assert_eq!(params.anchor, VWAPAnchorMode::Day);
+        assert_eq!(params.filter_type, VWAPFilterType::PriceAboveUpperBand);
+
+        let params: VWAPParams =
+            serde_json::from_str(r#"{"anchor":{"type":"SWING_LOW"}}"#).unwrap();
+        assert_eq!(
+            params.anchor,
+            VWAPAnchorMode::SwingLow { swing_strength: 2 }
+        );
+
+        let source: PriceReferenceSource = serde_json::from_str(
+            r#"{"type":"VWAP","anchor":{"type":"TIMESTAMP","timestamp":1700000000}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            source,
+            PriceReferenceSource::VWAP {
+                period: 0,
+                anchor: VWAPAnchorMode::Timestamp {
+                    timestamp: 1_700_000_000
+                },
+            }
+        );
+
+        let invalid = TechnicalFilterConfig::VWAP(VWAPParams {
+            anchor: VWAPAnchorMode::SwingHigh { swing_strength: 0 },
+            ..VWAPParams::default()
+        });
+        assert!(invalid.validate::<TestCandle>().is_err());
+    }
+
     #[test]
     fn test_three_rsi_params_deserialize_uses_defaults_for_missing_fields() {
         let params: ThreeRSIParams =
@@ -3387,7 +4576,10 @@ lookback_period = 5
             TechnicalFilterConfig::PriceReferenceGap(params) => {
                 assert_eq!(
                     params.reference_source,
-                    PriceReferenceSource::VWAP { period: 14 }
+                    PriceReferenceSource::VWAP {
+                        period: 14,
+                        anchor: VWAPAnchorMode::Rolling,
+                    }
                 );
                 assert_eq!(
                     params.filter_type,
@@ -3468,10 +4660,13 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
     #[test]
     fn test_price_reference_gap_validate_rejects_zero_period_and_negative_gap() {
         let zero_period = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
-            reference_source: PriceReferenceSource::VWAP { period: 0 },
+            reference_source: PriceReferenceSource::VWAP {
+                period: 0,
+                anchor: VWAPAnchorMode::Rolling,
+            },
             ..PriceReferenceGapParams::default()
         });
-        assert!(zero_period.validate().is_err());
+        assert!(zero_period.validate::<TestCandle>().is_err());
 
         let zero_highest_high_lookback =
             TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
@@ -3481,7 +4676,7 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
                 },
                 ..PriceReferenceGapParams::default()
             });
-        assert!(zero_highest_high_lookback.validate().is_err());
+        assert!(zero_highest_high_lookback.validate::<TestCandle>().is_err());
 
         let zero_lowest_low_lookback =
             TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
@@ -3491,33 +4686,43 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
                 },
                 ..PriceReferenceGapParams::default()
             });
-        assert!(zero_lowest_low_lookback.validate().is_err());
+        assert!(zero_lowest_low_lookback.validate::<TestCandle>().is_err());
 
         let zero_consecutive_n =
             TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
                 consecutive_n: 0,
                 ..PriceReferenceGapParams::default()
             });
-        assert!(zero_consecutive_n.validate().is_err());
+        assert!(zero_consecutive_n.validate::<TestCandle>().is_err());
 
         let negative_gap = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
             gap_threshold: -0.01,
             ..PriceReferenceGapParams::default()
         });
-        assert!(negative_gap.validate().is_err());
+        assert!(negative_gap.validate::<TestCandle>().is_err());
     }
 
     #[test]
-    fn test_price_reference_gap_validate_rejects_wma_when_scope_is_ema_sma_only() {
-        let filter = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+    fn test_price_reference_gap_validate_accepts_every_ma_type() {
+        for ma_type in crate::indicator::ma::MAType::ALL {
+            let filter = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+                reference_source: PriceReferenceSource::MovingAverage {
+                    ma_type,
+                    period: 20,
+                },
+                ..PriceReferenceGapParams::default()
+            });
+            assert!(filter.validate::<TestCandle>().is_ok(), "{ma_type}");
+        }
+
+        let zero_period = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
             reference_source: PriceReferenceSource::MovingAverage {
-                ma_type: crate::indicator::ma::MAType::WMA,
-                period: 20,
+                ma_type: crate::indicator::ma::MAType::HMA,
+                period: 0,
             },
             ..PriceReferenceGapParams::default()
         });
-
-        assert!(filter.validate().is_err());
+        assert!(zero_period.validate::<TestCandle>().is_err());
     }
 
     #[test]
@@ -3527,7 +4732,7 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
             ..MovingAverageParams::default()
         });
         assert!(matches!(
-            empty_periods.validate(),
+            empty_periods.validate::<TestCandle>(),
             Err(FilterError::InvalidEmptyList { .. })
         ));
 
@@ -3537,7 +4742,7 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
             ..MACDParams::default()
         });
         assert!(matches!(
-            invalid_period_order.validate(),
+            invalid_period_order.validate::<TestCandle>(),
             Err(FilterError::InvalidPeriodOrder { .. })
         ));
 
@@ -3546,7 +4751,7 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
             ..BollingerBandParams::default()
         });
         assert!(matches!(
-            invalid_positive.validate(),
+            invalid_positive.validate::<TestCandle>(),
             Err(FilterError::InvalidPositiveNumber { .. })
         ));
 
@@ -3555,7 +4760,7 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
             ..RSIParams::default()
         });
         assert!(matches!(
-            invalid_non_negative.validate(),
+            invalid_non_negative.validate::<TestCandle>(),
             Err(FilterError::InvalidNonNegativeNumber { .. })
         ));
 
@@ -3565,7 +4770,7 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
                 ..PriceReferenceGapParams::default()
             });
         assert!(matches!(
-            invalid_consecutive_n.validate(),
+            invalid_consecutive_n.validate::<TestCandle>(),
             Err(FilterError::InvalidConsecutiveN { .. })
         ));
     }
@@ -3573,10 +4778,10 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
     #[test]
     fn test_technical_filter_matches_price_reference_gap() {
         let candles = vec![
-            test_candle(1, 100.0, 101.0, 99.0),
-            test_candle(2, 100.0, 101.0, 99.0),
-            test_candle(3, 100.0, 101.0, 99.0),
-            test_candle(4, 130.0, 131.0, 129.0),
+            test_candle_hl(1, 100.0, 101.0, 99.0),
+            test_candle_hl(2, 100.0, 101.0, 99.0),
+            test_candle_hl(3, 100.0, 101.0, 99.0),
+            test_candle_hl(4, 130.0, 131.0, 129.0),
         ];
         let filter = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
             reference_source: PriceReferenceSource::MovingAverage {
@@ -3598,10 +4803,10 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
     #[test]
     fn test_technical_filter_context_reuses_candle_state_with_external_current_price() {
         let candles = vec![
-            test_candle(1, 100.0, 101.0, 99.0),
-            test_candle(2, 100.0, 101.0, 99.0),
-            test_candle(3, 100.0, 101.0, 99.0),
-            test_candle(4, 100.0, 101.0, 99.0),
+            test_candle_hl(1, 100.0, 101.0, 99.0),
+            test_candle_hl(2, 100.0, 101.0, 99.0),
+            test_candle_hl(3, 100.0, 101.0, 99.0),
+            test_candle_hl(4, 100.0, 101.0, 99.0),
         ];
         let filter = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
             reference_source: PriceReferenceSource::MovingAverage {
@@ -3619,13 +4824,105 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
         assert!(context.matches_filter("TEST/USDT", &filter, 130.0).unwrap());
     }
 
+    fn wave_candles(count: usize) -> Vec<TestCandle> {
+        (0..count)
+            .map(|i| {
+                let close = 100.0 + (i as f64 * 0.7).sin() * 10.0 + i as f64 * 0.1;
+                test_candle_hl(i as i64 + 1, close, close + 1.0, close - 1.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_technical_filter_context_cached_indicators_match_full_recompute() {
+        let candles = wave_candles(60);
+        let filters = [
+            TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+                reference_source: PriceReferenceSource::MovingAverage {
+                    ma_type: crate::indicator::ma::MAType::SMA,
+                    period: 5,
+                },
+                filter_type: PriceReferenceGapFilterType::GapAboveReferenceThreshold,
+                gap_threshold: 0.0,
+                consecutive_n: 2,
+                p: 0,
+            }),
+            create_rsi_filter(14, 30.0, 70.0, RSIFilterType::Above50, 1),
+        ];
+        let mut context = TechnicalFilterContext::new(&candles[..30]);
+        let mut passed = [0; 2];
+
+        for n in 30..=candles.len() {
+            if n > 30 {
+                context.update(candles[n - 1].clone());
+            }
+            let current_price = candles[n - 1].close;
+
+            for (i, filter) in filters.iter().enumerate() {
+                let cached = context
+                    .matches_filter("TEST/USDT", filter, current_price)
+                    .unwrap();
+                let recomputed = TechnicalFilter::matches_filter(
+                    "TEST/USDT",
+                    filter,
+                    &candles[..n],
+                    current_price,
+                )
+                .unwrap();
+
+                assert_eq!(cached, recomputed, "filter {i} at candle {n}");
+                passed[i] += usize::from(cached);
+            }
+        }
+
+        // 통과/불통과가 모두 나와야 비교가 의미 있음
+        for count in passed {
+            assert!(count > 0 && count < 31);
+        }
+        assert_eq!(context.cached_indicators(), filters.len());
+    }
+
+    #[test]
+    fn test_technical_filter_context_cache_is_keyed_by_params_and_cleared_on_replace() {
+        let candles = wave_candles(30);
+        let mut context = TechnicalFilterContext::new(&candles);
+        let rsi_14 = create_rsi_filter(14, 30.0, 70.0, RSIFilterType::Above50, 1);
+        let rsi_14_other_type = create_rsi_filter(14, 30.0, 70.0, RSIFilterType::Below50, 1);
+        let rsi_7 = create_rsi_filter(7, 30.0, 70.0, RSIFilterType::Above50, 1);
+
+        context.matches_filter("TEST/USDT", &rsi_14, 100.0).unwrap();
+        context
+            .matches_filter("TEST/USDT", &rsi_14_other_type, 100.0)
+            .unwrap();
+        assert_eq!(context.cached_indicators(), 1);
+
+        context.matches_filter("TEST/USDT", &rsi_7, 100.0).unwrap();
+        assert_eq!(context.cached_indicators(), 2);
+
+        // 마감 전 캔들 교체는 캐시를 비우고 다음 평가에서 다시 계산
+        let mut replaced = candles[29].clone();
+        replaced.close += 5.0;
+        context.update(replaced.clone());
+        assert_eq!(context.cached_indicators(), 0);
+
+        let mut expected = candles.clone();
+        expected[29] = replaced;
+        for filter in [&rsi_14, &rsi_7] {
+            assert_eq!(
+                context.matches_filter("TEST/USDT", filter, 100.0).unwrap(),
+                TechnicalFilter::matches_filter("TEST/USDT", filter, &expected, 100.0).unwrap()
+            );
+        }
+        assert_eq!(context.cached_indicators(), 2);
+    }
+
     #[test]
     fn test_technical_filter_matches_directional_price_reference_gap() {
         let candles = vec![
-            test_candle(1, 100.0, 101.0, 99.0),
-            test_candle(2, 100.0, 101.0, 99.0),
-            test_candle(3, 100.0, 101.0, 99.0),
-            test_candle(4, 70.0, 71.0, 69.0),
+            test_candle_hl(1, 100.0, 101.0, 99.0),
+            test_candle_hl(2, 100.0, 101.0, 99.0),
+            test_candle_hl(3, 100.0, 101.0, 99.0),
+            test_candle_hl(4, 70.0, 71.0, 69.0),
         ];
         let filter = TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
             reference_source: PriceReferenceSource::MovingAverage {
@@ -3647,9 +4944,9 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
     fn test_technical_filter_matches_zero_threshold_directional_price_reference_gap_includes_equality()
      {
         let candles = vec![
-            test_candle(1, 100.0, 101.0, 99.0),
-            test_candle(2, 100.0, 101.0, 99.0),
-            test_candle(3, 100.0, 101.0, 99.0),
+            test_candle_hl(1, 100.0, 101.0, 99.0),
+            test_candle_hl(2, 100.0, 101.0, 99.0),
+            test_candle_hl(3, 100.0, 101.0, 99.0),
         ];
         let above_or_equal_filter =
             TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
@@ -3688,26 +4985,26 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
     #[test]
     fn test_technical_filter_matches_multiple_price_reference_gap_filters() {
         let candles = vec![
-            test_candle(1, 100.0, 101.0, 99.0),
-            test_candle(2, 100.0, 101.0, 99.0),
-            test_candle(3, 100.0, 101.0, 99.0),
-            test_candle(4, 100.0, 101.0, 99.0),
-            test_candle(5, 100.0, 101.0, 99.0),
-            test_candle(6, 100.0, 101.0, 99.0),
-            test_candle(7, 100.0, 101.0, 99.0),
-            test_candle(8, 100.0, 101.0, 99.0),
-            test_candle(9, 100.0, 101.0, 99.0),
-            test_candle(10, 100.0, 101.0, 99.0),
-            test_candle(11, 100.0, 101.0, 99.0),
-            test_candle(12, 100.0, 101.0, 99.0),
-            test_candle(13, 100.0, 101.0, 99.0),
-            test_candle(14, 100.0, 101.0, 99.0),
-            test_candle(15, 100.0, 101.0, 99.0),
-            test_candle(16, 100.0, 101.0, 99.0),
-            test_candle(17, 100.0, 101.0, 99.0),
-            test_candle(18, 100.0, 101.0, 99.0),
-            test_candle(19, 100.0, 101.0, 99.0),
-            test_candle(20, 101.0, 102.0, 100.0),
+            test_candle_hl(1, 100.0, 101.0, 99.0),
+            test_candle_hl(2, 100.0, 101.0, 99.0),
+            test_candle_hl(3, 100.0, 101.0, 99.0),
+            test_candle_hl(4, 100.0, 101.0, 99.0),
+            test_candle_hl(5, 100.0, 101.0, 99.0),
+            test_candle_hl(6, 100.0, 101.0, 99.0),
+            test_candle_hl(7, 100.0, 101.0, 99.0),
+            test_candle_hl(8, 100.0, 101.0, 99.0),
+            test_candle_hl(9, 100.0, 101.0, 99.0),
+            test_candle_hl(10, 100.0, 101.0, 99.0),
+            test_candle_hl(11, 100.0, 101.0, 99.0),
+            test_candle_hl(12, 100.0, 101.0, 99.0),
+            test_candle_hl(13, 100.0, 101.0, 99.0),
+            test_candle_hl(14, 100.0, 101.0, 99.0),
+            test_candle_hl(15, 100.0, 101.0, 99.0),
+            test_candle_hl(16, 100.0, 101.0, 99.0),
+            test_candle_hl(17, 100.0, 101.0, 99.0),
+            test_candle_hl(18, 100.0, 101.0, 99.0),
+            test_candle_hl(19, 100.0, 101.0, 99.0),
+            test_candle_hl(20, 101.0, 102.0, 100.0),
         ];
         let filters = vec![
             TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
@@ -3742,26 +5039,26 @@ reference_source = { type = "HIGHEST_HIGH", lookback_period = 20, include_curren
     fn test_technical_filter_matches_multiple_price_reference_gap_filters_rejects_out_of_range_gap()
     {
         let candles = vec![
-            test_candle(1, 100.0, 101.0, 99.0),
-            test_candle(2, 100.0, 101.0, 99.0),
-            test_candle(3, 100.0, 101.0, 99.0),
-            test_candle(4, 100.0, 101.0, 99.0),
-            test_candle(5, 100.0, 101.0, 99.0),
-            test_candle(6, 100.0, 101.0, 99.0),
-            test_candle(7, 100.0, 101.0, 99.0),
-            test_candle(8, 100.0, 101.0, 99.0),
-            test_candle(9, 100.0, 101.0, 99.0),
-            test_candle(10, 100.0, 101.0, 99.0),
-            test_candle(11, 100.0, 101.0, 99.0),
-            test_candle(12, 100.0, 101.0, 99.0),
-            test_candle(13, 100.0, 101.0, 99.0),
-            test_candle(14, 100.0, 101.0, 99.0),
-            test_candle(15, 100.0, 101.0, 99.0),
-            test_candle(16, 100.0, 101.0, 99.0),
-            test_candle(17, 100.0, 101.0, 99.0),
-            test_candle(18, 100.0, 101.0, 99.0),
-            test_candle(19, 100.0, 101.0, 99.0),
-            test_candle(20, 103.0, 104.0, 102.0),
+            test_candle_hl(1, 100.0, 101.0, 99.0),
+            test_candle_hl(2, 100.0, 101.0, 99.0),
+            test_candle_hl(3, 100.0, 101.0, 99.0),
+            test_candle_hl(4, 100.0, 101.0, 99.0),
+            test_candle_hl(5, 100.0, 101.0, 99.0),
+            test_candle_hl(6, 100.0, 101.0, 99.0),
+            test_candle_hl(7, 100.0, 101.0, 99.0),
+            test_candle_hl(8, 100.0, 101.0, 99.0),
+            test_candle_hl(9, 100.0, 101.0, 99.0),
+            test_candle_hl(10, 100.0, 101.0, 99.0),
+            test_candle_hl(11, 100.0, 101.0, 99.0),
+            test_candle_hl(12, 100.0, 101.0, 99.0),
+            test_candle_hl(13, 100.0, 101.0, 99.0),
+            test_candle_hl(14, 100.0, 101.0, 99.0),
+            test_candle_hl(15, 100.0, 101.0, 99.0),
+            test_candle_hl(16, 100.0, 101.0, 99.0),
+            test_candle_hl(17, 100.0, 101.0, 99.0),
+            test_candle_hl(18, 100.0, 101.0, 99.0),
+            test_candle_hl(19, 100.0, 101.0, 99.0),
+            test_candle_hl(20, 103.0, 104.0, 102.0),
         ];
         let filters = vec![
             TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
@@ -1,18 +1,115 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{MomentumFilterType, MomentumParams, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::momentum_analyzer::MomentumAnalyzer;
-use crate::candle_store::CandleStore;
 use trading_chart::Candle;
 
 /// Momentum 필터 함수
-pub(crate) fn filter_momentum<C: Candle + Clone + 'static>(
+pub(crate) fn filter_momentum<'a, C: Candle + Clone + 'static>(
     symbol: &str,
     params: &MomentumParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
-    MomentumFilter::matches_filter(symbol, candle_store, params, current_price)
+    MomentumFilter::matches_filter(symbol, source, params, current_price)
+}
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &MomentumParams) -> usize {
+    params.history_length.max(params.consecutive_n)
+}
+
+/// 확인한 오프셋별 모멘텀 지표 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &MomentumParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &momentum_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let indicators = &data.momentum_indicators;
+            [
+                ("rsi", indicators.rsi),
+                ("stoch_k", indicators.stoch_k),
+                ("stoch_d", indicators.stoch_d),
+                ("williams_r", indicators.williams_r),
+                ("roc", indicators.roc),
+                ("cci", indicators.cci),
+                ("momentum", indicators.momentum),
+            ]
+        },
+    )
+}
+
+fn momentum_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &MomentumParams,
+) -> CachedRef<'a, MomentumAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(
+        format!(
+            "{}:{}:{}:{}:{}:{}:{}",
+            params.rsi_period,
+            params.stoch_period,
+            params.williams_period,
+            params.roc_period,
+            params.cci_period,
+            params.momentum_period,
+            params.history_length
+        ),
+        || {
+            MomentumAnalyzer::new(
+                candle_store,
+                crate::analyzer::momentum_analyzer::MomentumAnalyzerParams {
+                    rsi_period: params.rsi_period,
+                    stoch_period: params.stoch_period,
+                    williams_period: params.williams_period,
+                    roc_period: params.roc_period,
+                    cci_period: params.cci_period,
+                    momentum_period: params.momentum_period,
+                    history_length: params.history_length,
+                },
+            )
+        },
+    )
+}
+
+/// 강한 모멘텀으로 판단하는 종합 모멘텀 강도 기준
+const STRONG_MOMENTUM_STRENGTH: f64 = 0.7;
+
+/// 통과한 Momentum 필터의 강도 (p번째 종합 모멘텀 강도 기준)
+///
+/// 강한 모멘텀 타입은 강도가 기준(0.7)을 넘어선 정도, 과매수/과매도 타입은
+/// 지표들이 중립에서 벗어난 정도를 나타내는 종합 모멘텀 강도(0..1)입니다.
+/// 가속, 다이버전스, 반전 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_momentum<C: Candle + 'static>(
+    params: &MomentumParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let strength = momentum_analyzer(source, params)
+        .items
+        .get(params.p)?
+        .momentum_analysis
+        .momentum_strength;
+
+    match params.filter_type {
+        MomentumFilterType::StrongPositiveMomentum
+        | MomentumFilterType::StrongNegativeMomentum
+        | MomentumFilterType::MomentumSurge
+        | MomentumFilterType::MomentumCrash => excess_ratio(
+            strength - STRONG_MOMENTUM_STRENGTH,
+            1.0 - STRONG_MOMENTUM_STRENGTH,
+        ),
+        MomentumFilterType::Overbought
+        | MomentumFilterType::Oversold
+        | MomentumFilterType::MomentumSupportTest
+        | MomentumFilterType::MomentumResistanceTest => excess_ratio(strength, 1.0),
+        _ => None,
+    }
 }
 
 /// Momentum 필터 구조체
@@ -20,19 +117,21 @@ pub struct MomentumFilter;
 
 impl MomentumFilter {
     /// Momentum 필터 확인 (내부 헬퍼 함수, CandleStore 재사용)
-    pub(crate) fn matches_filter<C: Candle + Clone + 'static>(
+    pub(crate) fn matches_filter<'a, C: Candle + Clone + 'static>(
         _symbol: &str,
-        candle_store: &CandleStore<C>,
+        source: impl Into<FilterSource<'a, C>>,
         params: &MomentumParams,
         _current_price: f64,
     ) -> Result<bool> {
+        let source = source.into();
+        let candle_store = source.candle_store();
+
         let rsi_period = params.rsi_period;
         let stoch_period = params.stoch_period;
         let williams_period = params.williams_period;
         let roc_period = params.roc_period;
         let cci_period = params.cci_period;
         let momentum_period = params.momentum_period;
-        let history_length = params.history_length;
         let _threshold = params.threshold;
         let filter_type = params.filter_type;
         let consecutive_n = params.consecutive_n;
@@ -46,23 +145,12 @@ impl MomentumFilter {
         utils::validate_period(momentum_period, "Momentum momentum_period")?;
 
         // 경계 조건 체크
-        let required_length = history_length.max(consecutive_n);
+        let required_length = required_candles(params);
         if !utils::check_sufficient_candles(candle_store.len(), required_length, _symbol) {
             return Ok(false);
         }
         // analyzer는 이미 init_from_storage로 초기화되었으므로 추가 처리 불필요
-        let analyzer = MomentumAnalyzer::new(
-            candle_store,
-            crate::analyzer::momentum_analyzer::MomentumAnalyzerParams {
-                rsi_period,
-                stoch_period,
-                williams_period,
-                roc_period,
-                cci_period,
-                momentum_period,
-                history_length,
-            },
-        );
+        let analyzer = momentum_analyzer(source, params);
 
         // analyzer 메서드들이 이미 consecutive_n을 처리하므로 직접 호출
         let result = match filter_type {
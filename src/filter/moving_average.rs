@@ -1,18 +1,100 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{MovingAverageFilterType, MovingAverageParams, utils};
 use crate::analyzer::AnalyzerOps;
 use crate::analyzer::ma_analyzer::MAAnalyzer;
-use crate::candle_store::CandleStore;
-use crate::indicator::ma::MAType;
 use trading_chart::Candle;
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &MovingAverageParams) -> usize {
+    params.periods.iter().max().copied().unwrap_or(1)
+}
+
+/// 확인한 오프셋별 이동평균 값 (`ma_{기간}`)
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &MovingAverageParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &ma_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            params
+                .periods
+                .iter()
+                .map(|period| (format!("ma_{period}"), data.mas.get(period).get()))
+                .collect::<Vec<_>>()
+        },
+    )
+}
+
+fn ma_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &MovingAverageParams,
+) -> CachedRef<'a, MAAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(format!("{:?}:{:?}", params.ma_type, params.periods), || {
+        MAAnalyzer::new(&params.ma_type, &params.periods, candle_store)
+    })
+}
+
+/// 통과한 이동평균선 필터의 강도 (p번째 이동평균 대비 괴리율)
+///
+/// 괴리율은 횡보 판단 임계값(`sideways_threshold`)을 범위로 정규화합니다.
+/// 배열, 교차, 수렴/발산 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_moving_average<C: Candle + 'static>(
+    params: &MovingAverageParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Option<f64> {
+    let analyzer = ma_analyzer(source, params);
+    let data = analyzer.items.get(params.p)?;
+    let mas: Vec<f64> = (0..params.periods.len())
+        .map(|i| data.mas.get_by_key_index(i).get())
+        .collect();
+    let (first_ma, last_ma) = (*mas.first()?, *mas.last()?);
+    let gap = |ma: f64| (current_price - ma) / ma;
+    let range = params.sideways_threshold;
+
+    match params.filter_type {
+        MovingAverageFilterType::PriceAboveFirstMA | MovingAverageFilterType::StrongUptrend => {
+            excess_ratio(gap(first_ma), range)
+        }
+        MovingAverageFilterType::PriceBelowFirstMA | MovingAverageFilterType::StrongDowntrend => {
+            excess_ratio(-gap(first_ma), range)
+        }
+        MovingAverageFilterType::PriceAboveLastMA => excess_ratio(gap(last_ma), range),
+        MovingAverageFilterType::PriceBelowLastMA => excess_ratio(-gap(last_ma), range),
+        MovingAverageFilterType::FirstMAAboveLastMA => {
+            excess_ratio((first_ma - last_ma) / last_ma, range)
+        }
+        MovingAverageFilterType::FirstMABelowLastMA => {
+            excess_ratio((last_ma - first_ma) / last_ma, range)
+        }
+        MovingAverageFilterType::AllMAAbove => excess_ratio(
+            mas.iter().map(|ma| gap(*ma)).fold(f64::INFINITY, f64::min),
+            range,
+        ),
+        MovingAverageFilterType::AllMABelow => excess_ratio(
+            mas.iter().map(|ma| -gap(*ma)).fold(f64::INFINITY, f64::min),
+            range,
+        ),
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 이동평균선 필터 적용
-pub(crate) fn filter_moving_average<C: Candle + 'static>(
+pub(crate) fn filter_moving_average<'a, C: Candle + 'static>(
     coin: &str,
     params: &MovingAverageParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     if params.periods.is_empty() {
         log::debug!("이동평균선 필터 적용 - 기간 목록이 비어 있음");
         return Ok(false);
@@ -31,14 +113,12 @@ pub(crate) fn filter_moving_average<C: Candle + 'static>(
     }
 
     // 필터링 로직
-    let max_period = params.periods.iter().max().copied().unwrap_or(1);
-    if !utils::check_sufficient_candles(candle_store.len(), max_period, coin) {
+    if !utils::check_sufficient_candles(candle_store.len(), required_candles(params), coin) {
         return Ok(false);
     }
 
-    // MAAnalyzer 생성 (SMA 타입 사용)
-    let ma_type = MAType::SMA;
-    let analyzer = MAAnalyzer::new(&ma_type, &params.periods, candle_store);
+    // MAAnalyzer 생성 (설정된 이동평균 유형 사용)
+    let analyzer = ma_analyzer(source, params);
 
     // 필터 타입에 따라 로직 처리
     // 첫 번째와 마지막 MA 인덱스 결정
@@ -885,4 +965,28 @@ mod tests {
         // 이동평균선들이 교차점 근처인지 확인
         assert!(!result.unwrap()); // 간격이 넓어서 false
     }
+
+    #[test]
+    fn test_ma_type_changes_moving_average() {
+        let candles = create_test_candles();
+        let candle_store = utils::create_candle_store(&candles);
+        let params: MovingAverageParams = serde_json::from_value(serde_json::json!({
+            "periods": [4],
+            "ma_type": "HMA",
+            "filter_type": "PriceAboveFirstMA",
+        }))
+        .unwrap();
+        assert_eq!(params.ma_type, crate::indicator::ma::MAType::HMA);
+
+        // 선형 하락에서 HMA(4)는 최신 가격(56)을 따라가고 SMA(4)는 63.5에 머묾
+        let result = filter_moving_average("TEST/USDT", &params, &candle_store, 60.0);
+        assert!(result.unwrap());
+
+        let sma_params = MovingAverageParams {
+            ma_type: crate::indicator::ma::MAType::SMA,
+            ..params
+        };
+        let result = filter_moving_average("TEST/USDT", &sma_params, &candle_store, 60.0);
+        assert!(!result.unwrap());
+    }
 }
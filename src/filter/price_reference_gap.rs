@@ -1,74 +1,199 @@
+use super::cache::{CachedIndicator, CachedRef, FilterSource};
+use super::report::OffsetValues;
+use super::score::excess_ratio;
 use super::{
-    PriceReferenceGapFilterType, PriceReferenceGapParams, PriceReferenceSource, Result, utils,
+    PriceReferenceGapFilterType, PriceReferenceGapParams, PriceReferenceSource, Result, utils, vwap,
 };
+use crate::analyzer::vwap_analyzer::VWAPSwingAnchor;
 use crate::candle_store::CandleStore;
-use crate::indicator::ma::MABuilderFactory;
+use crate::indicator::TABuilder;
+use crate::indicator::ma::{MA, MABuilderFactory};
 use crate::indicator::max::MAXBuilder;
 use crate::indicator::min::MINBuilder;
-use crate::indicator::vwap::{VWAPBuilder, VWAPParams as IndicatorVWAPParams};
+use crate::indicator::vwap::VWAPBuilder;
+use std::any::Any;
+use std::collections::{BTreeMap, VecDeque};
 use trading_chart::Candle;
 
-pub(crate) fn filter_price_reference_gap<C: Candle + 'static>(
+pub(crate) fn filter_price_reference_gap<'a, C: Candle + 'static>(
     symbol: &str,
     params: &PriceReferenceGapParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
-    let required_length = required_candle_count(params);
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    let required_length = required_candles(params);
     if !utils::check_sufficient_candles(candle_store.len(), required_length, symbol) {
         return Ok(false);
     }
 
-    let ascending_items = candle_store.get_ascending_items();
-
-    let result = match &params.reference_source {
-        PriceReferenceSource::MovingAverage { ma_type, period } => {
-            matches_reference_gap(&ascending_items, params, current_price, |window| {
-                let mut builder = MABuilderFactory::build::<C>(ma_type, *period);
-                let mut value = 0.0;
+    let series = reference_series(source, params);
 
-                for candle in window {
-                    value = builder.next(candle).get();
-                }
+    Ok(matches_reference_gap(&series, params, current_price))
+}
 
-                Some(value)
-            })
-        }
-        PriceReferenceSource::VWAP { period } => {
-            let mut builder = VWAPBuilder::<C>::new(IndicatorVWAPParams { period: *period });
-            matches_reference_gap(&ascending_items, params, current_price, |window| {
-                Some(builder.build(window).value)
-            })
-        }
-        PriceReferenceSource::HighestHigh {
-            lookback_period,
-            include_current_candle,
-        } => {
-            let mut builder = MAXBuilder::<C>::new(*lookback_period);
-            matches_reference_gap(&ascending_items, params, current_price, |window| {
-                high_low_reference_window(window, *include_current_candle)
-                    .map(|reference_window| builder.build(reference_window).max)
+/// 확인한 오프셋별 기준가와 괴리율
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &PriceReferenceGapParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Vec<OffsetValues> {
+    let series = reference_series(source, params);
+
+    (params.p..params.p + params.consecutive_n)
+        .filter_map(|offset| {
+            let reference_price = series.reference_at(offset)?;
+            let mut values = BTreeMap::from([("reference".to_string(), reference_price)]);
+            if let Some(gap_ratio) = compute_gap_ratio(current_price, reference_price) {
+                values.insert("gap_ratio".to_string(), gap_ratio);
+            }
+            Some(OffsetValues {
+                offset,
+                values,
+                labels: BTreeMap::new(),
             })
-        }
-        PriceReferenceSource::LowestLow {
-            lookback_period,
+        })
+        .collect()
+}
+
+fn reference_series<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &PriceReferenceGapParams,
+) -> CachedRef<'a, ReferenceSeries<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(format!("{:?}", params.reference_source), || {
+        ReferenceSeries::new(&params.reference_source, candle_store)
+    })
+}
+
+/// 통과한 괴리 필터의 강도 (p번째 기준가 대비 괴리율이 gap_threshold를 넘어선 정도)
+///
+/// gap_threshold가 0이면 정규화할 수 없으므로 None을 반환합니다.
+pub(crate) fn score_price_reference_gap<C: Candle + 'static>(
+    params: &PriceReferenceGapParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Option<f64> {
+    let reference_price = reference_series(source, params).reference_at(params.p)?;
+    let gap_ratio = compute_gap_ratio(current_price, reference_price)?;
+    let threshold = params.gap_threshold;
+
+    let excess = match params.filter_type {
+        PriceReferenceGapFilterType::GapAboveThreshold => gap_ratio.abs() - threshold,
+        PriceReferenceGapFilterType::GapBelowThreshold => threshold - gap_ratio.abs(),
+        PriceReferenceGapFilterType::GapAboveReferenceThreshold => gap_ratio - threshold,
+        PriceReferenceGapFilterType::GapBelowReferenceThreshold => -gap_ratio - threshold,
+        PriceReferenceGapFilterType::GapBelowReferenceUpperThreshold => threshold - gap_ratio,
+        PriceReferenceGapFilterType::GapAboveReferenceLowerThreshold => gap_ratio + threshold,
+    };
+    excess_ratio(excess, threshold)
+}
+
+/// 기준가 계산 빌더
+enum ReferenceBuilder<C: Candle> {
+    MovingAverage(Box<dyn TABuilder<Box<dyn MA>, C>>),
+    VWAP(VWAPBuilder<C>),
+    SwingVWAP(VWAPSwingAnchor<C>),
+    HighestHigh(MAXBuilder<C>),
+    LowestLow(MINBuilder<C>),
+}
+
+/// 캔들별 기준가 시계열
+///
+/// `values[i]`는 최신 캔들에서 i번째 이전 캔들까지의 히스토리로 계산한 기준가입니다 (최신 값이 인덱스 0).
+/// 기준가 빌더는 캔들마다 한 번만 갱신되므로 오프셋마다 히스토리 전체를 다시 계산하지 않습니다.
+pub(crate) struct ReferenceSeries<C: Candle> {
+    builder: ReferenceBuilder<C>,
+    include_current_candle: bool,
+    values: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl<C: Candle + 'static> ReferenceSeries<C> {
+    fn new(source: &PriceReferenceSource, candle_store: &CandleStore<C>) -> Self {
+        let (builder, include_current_candle) = match source {
+            PriceReferenceSource::MovingAverage { ma_type, period } => (
+                ReferenceBuilder::MovingAverage(MABuilderFactory::build::<C>(ma_type, *period)),
+                true,
+            ),
+            PriceReferenceSource::VWAP { period, anchor } => (
+                match vwap::swing_anchor(anchor) {
+                    Some((swing_type, swing_strength)) => ReferenceBuilder::SwingVWAP(
+                        VWAPSwingAnchor::new(swing_type, swing_strength),
+                    ),
+                    None => ReferenceBuilder::VWAP(VWAPBuilder::new(vwap::indicator_params(
+                        *period, anchor,
+                    ))),
+                },
+                true,
+            ),
+            PriceReferenceSource::HighestHigh {
+                lookback_period,
+                include_current_candle,
+            } => (
+                ReferenceBuilder::HighestHigh(MAXBuilder::new(*lookback_period)),
+                *include_current_candle,
+            ),
+            PriceReferenceSource::LowestLow {
+                lookback_period,
+                include_current_candle,
+            } => (
+                ReferenceBuilder::LowestLow(MINBuilder::new(*lookback_period)),
+                *include_current_candle,
+            ),
+        };
+
+        let mut series = Self {
+            builder,
             include_current_candle,
-        } => {
-            let mut builder = MINBuilder::<C>::new(*lookback_period);
-            matches_reference_gap(&ascending_items, params, current_price, |window| {
-                high_low_reference_window(window, *include_current_candle)
-                    .map(|reference_window| builder.build(reference_window).min)
-            })
+            values: VecDeque::new(),
+            capacity: candle_store.max_size.max(candle_store.len()),
+        };
+        for candle in candle_store.get_ascending_items() {
+            series.push(&candle);
         }
-    };
+        series
+    }
 
-    Ok(result)
+    fn push(&mut self, candle: &C) {
+        let value = match &mut self.builder {
+            ReferenceBuilder::MovingAverage(builder) => builder.next(candle).get(),
+            ReferenceBuilder::VWAP(builder) => builder.next(candle).value,
+            ReferenceBuilder::SwingVWAP(anchor) => anchor.next(candle).value,
+            ReferenceBuilder::HighestHigh(builder) => builder.next(candle).max,
+            ReferenceBuilder::LowestLow(builder) => builder.next(candle).min,
+        };
+
+        self.values.push_front(value);
+        self.values.truncate(self.capacity);
+    }
+
+    /// 최신 캔들에서 offset번째 이전 캔들을 현재 캔들로 볼 때의 기준가
+    ///
+    /// 현재 캔들을 제외하는 고가/저가 기준은 바로 이전 캔들까지의 값을 사용합니다.
+    fn reference_at(&self, offset: usize) -> Option<f64> {
+        let index = offset + usize::from(!self.include_current_candle);
+        self.values.get(index).copied()
+    }
+}
+
+impl<C: Candle + 'static> CachedIndicator<C> for ReferenceSeries<C> {
+    fn update(&mut self, candle: C) {
+        self.push(&candle);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
-fn required_candle_count(params: &PriceReferenceGapParams) -> usize {
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &PriceReferenceGapParams) -> usize {
     let reference_period = match &params.reference_source {
         PriceReferenceSource::MovingAverage { period, .. } => *period,
-        PriceReferenceSource::VWAP { period } => *period,
+        PriceReferenceSource::VWAP { period, anchor } => vwap::warmup_candles(*period, anchor),
         PriceReferenceSource::HighestHigh {
             lookback_period,
             include_current_candle,
@@ -82,23 +207,13 @@ fn required_candle_count(params: &PriceReferenceGapParams) -> usize {
     reference_period + params.p + params.consecutive_n.saturating_sub(1)
 }
 
-fn matches_reference_gap<C: Candle>(
-    ascending_items: &[C],
+fn matches_reference_gap<C: Candle + 'static>(
+    series: &ReferenceSeries<C>,
     params: &PriceReferenceGapParams,
     current_price: f64,
-    mut reference_value: impl FnMut(&[C]) -> Option<f64>,
 ) -> bool {
-    for offset in params.p..params.p + params.consecutive_n {
-        let Some(window_end) = ascending_items.len().checked_sub(offset) else {
-            return false;
-        };
-
-        if window_end == 0 {
-            return false;
-        }
-
-        let window = &ascending_items[..window_end];
-        let Some(reference_price) = reference_value(window) else {
+    (params.p..params.p + params.consecutive_n).all(|offset| {
+        let Some(reference_price) = series.reference_at(offset) else {
             return false;
         };
 
@@ -106,24 +221,8 @@ fn matches_reference_gap<C: Candle>(
             return false;
         };
 
-        if !matches_gap_threshold(gap_ratio, params.filter_type, params.gap_threshold) {
-            return false;
-        }
-    }
-
-    true
-}
-
-fn high_low_reference_window<C>(window: &[C], include_current_candle: bool) -> Option<&[C]> {
-    if include_current_candle {
-        return Some(window);
-    }
-
-    if window.len() < 2 {
-        return None;
-    }
-
-    Some(&window[..window.len() - 1])
+        matches_gap_threshold(gap_ratio, params.filter_type, params.gap_threshold)
+    })
 }
 
 fn compute_gap_ratio(current_price: f64, reference_price: f64) -> Option<f64> {
@@ -152,6 +251,7 @@ fn matches_gap_threshold(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filter::VWAPAnchorMode;
     use crate::indicator::ma::MAType;
     use crate::tests::TestCandle;
 
@@ -270,7 +370,10 @@ mod tests {
             (150.0, 152.0, 148.0),
         ]);
         let params = PriceReferenceGapParams {
-            reference_source: PriceReferenceSource::VWAP { period: 4 },
+            reference_source: PriceReferenceSource::VWAP {
+                period: 4,
+                anchor: VWAPAnchorMode::Rolling,
+            },
             filter_type: PriceReferenceGapFilterType::GapAboveThreshold,
             gap_threshold: 0.20,
             consecutive_n: 1,
@@ -280,6 +383,28 @@ mod tests {
         assert!(run_filter(params, &candles));
     }
 
+    #[test]
+    fn test_filter_price_reference_gap_session_vwap_resets_at_utc_midnight() {
+        let midnight = 86_400;
+        let candles = vec![
+            test_candle(midnight - 120, 100.0, 101.0, 99.0, 1_000.0),
+            test_candle(midnight - 60, 100.0, 101.0, 99.0, 1_000.0),
+            test_candle(midnight, 150.0, 152.0, 148.0, 1_000.0),
+        ];
+        let params = |anchor| PriceReferenceGapParams {
+            reference_source: PriceReferenceSource::VWAP { period: 3, anchor },
+            filter_type: PriceReferenceGapFilterType::GapBelowThreshold,
+            gap_threshold: 0.01,
+            consecutive_n: 1,
+            p: 0,
+        };
+
+        // 세션 VWAP은 자정 이후 캔들(대표 가격 150)만 사용
+        assert!(run_filter(params(VWAPAnchorMode::Day), &candles));
+        // 롤링 VWAP은 (100 + 100 + 150) / 3 ≈ 116.67 → 괴리율 약 28.6%
+        assert!(!run_filter(params(VWAPAnchorMode::Rolling), &candles));
+    }
+
     #[test]
     fn test_filter_price_reference_gap_highest_high_gap_below_threshold() {
         let candles = build_candles(&[
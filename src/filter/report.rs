@@ -0,0 +1,535 @@
+// 필터 평가 리포트
+// 필터가 심볼을 통과/탈락시킨 근거(오프셋별 지표 값, 비교 임계값, 캔들 수 충족 여부)를
+// JSON으로 직렬화할 수 있는 형태로 남깁니다. 감사 로그 용도입니다.
+
+use super::cache::FilterSource;
+use super::{
+    CompareOperand, TechnicalFilter, TechnicalFilterConfig, adx, atr, bollinger_band,
+    candle_pattern, cci, compare, copys, custom, donchian, ichimoku, keltner, macd, momentum,
+    moving_average, price_reference_gap, rsi, slope, squeeze, stoch_rsi, stochastic, supertrend,
+    support_resistance, three_rsi, timeframe, volume, vwap, williams_r,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use trading_chart::Candle;
+
+/// 오프셋 하나에서 계산된 지표 값
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OffsetValues {
+    /// 최신 캔들 기준 오프셋 (0이 최신)
+    pub offset: usize,
+    /// 지표 이름별 값
+    pub values: BTreeMap<String, f64>,
+    /// 숫자가 아닌 값 (예: 감지된 캔들 패턴)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+/// 필터 하나의 평가 리포트
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterReport {
+    /// 필터 타입 (예: `RSI`)
+    pub filter: String,
+    /// 필터 세부 타입 (예: `Oversold`)
+    pub filter_type: String,
+    /// 지정한 캔들 간격 (`interval`, 지정하지 않으면 전체 캔들로 평가)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    /// 비교에 사용된 임계값
+    pub thresholds: BTreeMap<String, f64>,
+    /// 필요한 최소 캔들 수
+    pub required_candles: usize,
+    /// 보유 캔들 수
+    pub available_candles: usize,
+    /// 캔들 수 충족 여부 (부족하면 지표를 계산하지 않고 탈락)
+    pub sufficient_candles: bool,
+    /// 확인한 오프셋(`p..p+consecutive_n`)별 지표 값
+    pub offsets: Vec<OffsetValues>,
+    /// 통과 여부 (오류 시 false)
+    pub passed: bool,
+    /// 필터 적용 오류
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 필터 목록의 평가 리포트
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FiltersReport {
+    /// 심볼
+    pub symbol: String,
+    /// 평가에 사용된 현재가
+    pub current_price: f64,
+    /// 모든 필터 통과 여부
+    pub passed: bool,
+    /// 필터별 리포트 (입력 순서)
+    pub filters: Vec<FilterReport>,
+}
+
+impl FiltersReport {
+    /// 처음으로 탈락시킨 필터의 리포트
+    pub fn first_rejection(&self) -> Option<&FilterReport> {
+        self.filters.iter().find(|report| !report.passed)
+    }
+}
+
+/// 분석기 항목에서 오프셋별 지표 값 추출
+///
+/// 항목이 없는 오프셋은 건너뜁니다.
+pub(crate) fn offset_values<T, K, I>(
+    items: &[T],
+    offsets: Range<usize>,
+    values: impl Fn(&T) -> I,
+) -> Vec<OffsetValues>
+where
+    K: Into<String>,
+    I: IntoIterator<Item = (K, f64)>,
+{
+    offsets
+        .filter_map(|offset| {
+            items.get(offset).map(|item| OffsetValues {
+                offset,
+                values: values(item)
+                    .into_iter()
+                    .map(|(name, value)| (name.into(), value))
+                    .collect(),
+                labels: BTreeMap::new(),
+            })
+        })
+        .collect()
+}
+
+/// 필터 평가 리포트 생성 (내부 헬퍼 함수, CandleStore 재사용)
+pub(crate) fn filter_report<C: Candle + 'static>(
+    symbol: &str,
+    filter: &TechnicalFilterConfig,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> FilterReport {
+    // 간격을 지정한 필터는 해당 간격의 캔들로 리포트를 만들고 간격을 남김
+    if let TechnicalFilterConfig::Timeframe(config) = filter {
+        let report = timeframe::with_source(config, source, |filter, source| {
+            filter_report(symbol, filter, source, current_price)
+        });
+        return match report {
+            Ok(report) => FilterReport {
+                interval: Some(config.interval.clone()),
+                ..report
+            },
+            Err(e) => FilterReport {
+                filter: filter.filter_type().to_string(),
+                filter_type: filter_type_name(filter),
+                interval: Some(config.interval.clone()),
+                thresholds: thresholds(filter),
+                required_candles: filter.required_candles::<C>(),
+                available_candles: 0,
+                sufficient_candles: false,
+                offsets: Vec::new(),
+                passed: false,
+                error: Some(e.to_string()),
+            },
+        };
+    }
+
+    let evaluated = TechnicalFilter::matches_filter_internal(symbol, filter, source, current_price);
+    let required_candles = filter.required_candles::<C>();
+    let available_candles = source.candle_store().len();
+    let sufficient_candles = available_candles >= required_candles;
+
+    // 파라미터 오류나 캔들 부족이면 필터도 지표를 계산하지 않았으므로 값을 남기지 않음
+    let offsets = if evaluated.is_ok() && sufficient_candles {
+        indicator_values(filter, source, current_price)
+    } else {
+        Vec::new()
+    };
+
+    FilterReport {
+        filter: filter.filter_type().to_string(),
+        filter_type: filter_type_name(filter),
+        interval: None,
+        thresholds: thresholds(filter),
+        required_candles,
+        available_candles,
+        sufficient_candles,
+        offsets,
+        passed: matches!(evaluated, Ok(true)),
+        error: evaluated.err().map(|e| e.to_string()),
+    }
+}
+
+/// 필터 목록 평가 리포트 생성 (내부 헬퍼 함수, CandleStore 재사용)
+///
+/// 리포트를 남기기 위해 앞선 필터가 탈락해도 나머지 필터를 모두 평가합니다.
+pub(crate) fn filters_report<C: Candle + 'static>(
+    symbol: &str,
+    filters: &[TechnicalFilterConfig],
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> FiltersReport {
+    let filters: Vec<FilterReport> = filters
+        .iter()
+        .map(|filter| filter_report(symbol, filter, source, current_price))
+        .collect();
+
+    FiltersReport {
+        symbol: symbol.to_string(),
+        current_price,
+        passed: filters.iter().all(|report| report.passed),
+        filters,
+    }
+}
+
+fn filter_type_name(filter: &TechnicalFilterConfig) -> String {
+    match filter {
+        TechnicalFilterConfig::RSI(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::MACD(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::BollingerBand(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::ADX(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::MovingAverage(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::PriceReferenceGap(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Ichimoku(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::VWAP(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Copys(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::ATR(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::SuperTrend(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Volume(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::ThreeRSI(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::CandlePattern(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::SupportResistance(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Momentum(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Slope(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Compare(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Stochastic(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::StochRSI(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::WilliamsR(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::CCI(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Keltner(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Donchian(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Squeeze(params) => params.filter_type.to_string(),
+        TechnicalFilterConfig::Custom(config) => config.filter_type.clone(),
+        TechnicalFilterConfig::Timeframe(config) => filter_type_name(&config.filter),
+    }
+}
+
+fn thresholds(filter: &TechnicalFilterConfig) -> BTreeMap<String, f64> {
+    let thresholds: Vec<(&str, f64)> = match filter {
+        TechnicalFilterConfig::RSI(params) => vec![
+            ("oversold", params.oversold),
+            ("overbought", params.overbought),
+            ("sideways_threshold", params.sideways_threshold),
+            ("momentum_threshold", params.momentum_threshold),
+            ("cross_threshold", params.cross_threshold),
+        ],
+        TechnicalFilterConfig::MACD(params) => vec![
+            ("threshold", params.threshold),
+            ("overbought_threshold", params.overbought_threshold),
+            ("oversold_threshold", params.oversold_threshold),
+            ("sideways_threshold", params.sideways_threshold),
+        ],
+        TechnicalFilterConfig::BollingerBand(params) => vec![
+            ("squeeze_threshold", params.squeeze_threshold),
+            ("medium_threshold", params.medium_threshold),
+            ("large_threshold", params.large_threshold),
+            ("upper_touch_threshold", params.upper_touch_threshold),
+            ("lower_touch_threshold", params.lower_touch_threshold),
+        ],
+        TechnicalFilterConfig::ADX(params) => vec![("threshold", params.threshold)],
+        TechnicalFilterConfig::MovingAverage(params) => vec![
+            ("sideways_threshold", params.sideways_threshold),
+            ("crossover_threshold", params.crossover_threshold),
+        ],
+        TechnicalFilterConfig::PriceReferenceGap(params) => {
+            vec![("gap_threshold", params.gap_threshold)]
+        }
+        TechnicalFilterConfig::Ichimoku(_) => Vec::new(),
+        TechnicalFilterConfig::VWAP(params) => vec![
+            ("threshold", params.threshold),
+            ("band_multiplier", params.band_multiplier),
+        ],
+        TechnicalFilterConfig::Copys(params) => vec![
+            ("rsi_upper", params.rsi_upper),
+            ("rsi_lower", params.rsi_lower),
+        ],
+        TechnicalFilterConfig::ATR(params) => vec![("threshold", params.threshold)],
+        TechnicalFilterConfig::SuperTrend(params) => vec![("multiplier", params.multiplier)],
+        TechnicalFilterConfig::Volume(params) => vec![
+            ("threshold", params.threshold),
+            ("stable_min_threshold", params.stable_min_threshold),
+        ],
+        TechnicalFilterConfig::ThreeRSI(params) => {
+            vec![("cross_threshold", params.cross_threshold)]
+        }
+        TechnicalFilterConfig::CandlePattern(params) => vec![
+            ("min_body_ratio", params.min_body_ratio),
+            ("min_shadow_ratio", params.min_shadow_ratio),
+            ("threshold", params.threshold),
+        ],
+        TechnicalFilterConfig::SupportResistance(params) => vec![
+            ("touch_threshold", params.touch_threshold),
+            ("threshold", params.threshold),
+        ],
+        TechnicalFilterConfig::Momentum(params) => vec![("threshold", params.threshold)],
+        TechnicalFilterConfig::Slope(params) => [
+            ("strength_threshold", params.strength_threshold),
+            ("r_squared_threshold", params.r_squared_threshold),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, value)))
+        .collect(),
+        // 상수 피연산자가 비교 기준값
+        TechnicalFilterConfig::Compare(params) => [("lhs", &params.lhs), ("rhs", &params.rhs)]
+            .into_iter()
+            .filter_map(|(name, operand)| match operand {
+                CompareOperand::Constant(value) => Some((name, *value)),
+                CompareOperand::Indicator(_) => None,
+            })
+            .collect(),
+        TechnicalFilterConfig::Stochastic(params) => vec![
+            ("oversold", params.oversold),
+            ("overbought", params.overbought),
+        ],
+        TechnicalFilterConfig::StochRSI(params) => vec![
+            ("oversold", params.oversold),
+            ("overbought", params.overbought),
+        ],
+        TechnicalFilterConfig::WilliamsR(params) => vec![
+            ("oversold", params.oversold),
+            ("overbought", params.overbought),
+        ],
+        TechnicalFilterConfig::CCI(params) => vec![
+            ("oversold", params.oversold),
+            ("overbought", params.overbought),
+        ],
+        TechnicalFilterConfig::Keltner(params) => vec![("multiplier", params.multiplier)],
+        TechnicalFilterConfig::Donchian(_) => Vec::new(),
+        TechnicalFilterConfig::Squeeze(params) => vec![
+            ("bb_multiplier", params.bb_multiplier),
+            ("kc_multiplier", params.kc_multiplier),
+        ],
+        // 사용자 정의 필터는 설정 값 중 숫자만 남김
+        TechnicalFilterConfig::Custom(config) => config
+            .params
+            .iter()
+            .filter_map(|(name, value)| value.as_f64().map(|value| (name.as_str(), value)))
+            .collect(),
+        TechnicalFilterConfig::Timeframe(config) => return thresholds(&config.filter),
+    };
+
+    thresholds
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect()
+}
+
+fn indicator_values<C: Candle + 'static>(
+    filter: &TechnicalFilterConfig,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Vec<OffsetValues> {
+    match filter {
+        TechnicalFilterConfig::RSI(params) => rsi::report_values(params, source),
+        TechnicalFilterConfig::MACD(params) => macd::report_values(params, source),
+        TechnicalFilterConfig::BollingerBand(params) => {
+            bollinger_band::report_values(params, source)
+        }
+        TechnicalFilterConfig::ADX(params) => adx::report_values(params, source),
+        TechnicalFilterConfig::MovingAverage(params) => {
+            moving_average::report_values(params, source)
+        }
+        TechnicalFilterConfig::PriceReferenceGap(params) => {
+            price_reference_gap::report_values(params, source, current_price)
+        }
+        TechnicalFilterConfig::Ichimoku(params) => ichimoku::report_values(params, source),
+        TechnicalFilterConfig::VWAP(params) => vwap::report_values(params, source),
+        TechnicalFilterConfig::ATR(params) => atr::report_values(params, source),
+        TechnicalFilterConfig::SuperTrend(params) => supertrend::report_values(params, source),
+        TechnicalFilterConfig::Volume(params) => volume::report_values(params, source),
+        TechnicalFilterConfig::ThreeRSI(params) => three_rsi::report_values(params, source),
+        TechnicalFilterConfig::Momentum(params) => momentum::report_values(params, source),
+        TechnicalFilterConfig::Slope(params) => slope::report_values(params, source),
+        TechnicalFilterConfig::Compare(params) => compare::report_values(params, source),
+        TechnicalFilterConfig::Stochastic(params) => stochastic::report_values(params, source),
+        TechnicalFilterConfig::StochRSI(params) => stoch_rsi::report_values(params, source),
+        TechnicalFilterConfig::WilliamsR(params) => williams_r::report_values(params, source),
+        TechnicalFilterConfig::CCI(params) => cci::report_values(params, source),
+        TechnicalFilterConfig::Keltner(params) => keltner::report_values(params, source),
+        TechnicalFilterConfig::Donchian(params) => donchian::report_values(params, source),
+        TechnicalFilterConfig::Squeeze(params) => squeeze::report_values(params, source),
+        TechnicalFilterConfig::Copys(params) => copys::report_values(params, source.candle_store()),
+        TechnicalFilterConfig::CandlePattern(params) => {
+            candle_pattern::report_values(params, source.candle_store())
+        }
+        TechnicalFilterConfig::SupportResistance(params) => {
+            support_resistance::report_values(params, source.candle_store(), current_price)
+        }
+        TechnicalFilterConfig::Custom(config) => {
+            custom::report_values(config, source.candle_store(), current_price)
+        }
+        TechnicalFilterConfig::Timeframe(config) => {
+            timeframe::with_source(config, source, |filter, source| {
+                indicator_values(filter, source, current_price)
+            })
+            .unwrap_or_default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{
+        CandlePatternFilterType, CandlePatternParams, PriceReferenceGapFilterType,
+        PriceReferenceGapParams, PriceReferenceSource, RSIFilterType, RSIParams,
+        SupportResistanceFilterType, SupportResistanceParams,
+    };
+    use crate::tests::test_candles;
+
+    // SMA(3) 기준가보다 10% 이상 높은 상태가 consecutive_n 캔들 연속인지 확인하는 필터
+    fn gap_above_filter(consecutive_n: usize) -> TechnicalFilterConfig {
+        TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+            reference_source: PriceReferenceSource::MovingAverage {
+                ma_type: crate::indicator::ma::MAType::SMA,
+                period: 3,
+            },
+            filter_type: PriceReferenceGapFilterType::GapAboveReferenceThreshold,
+            gap_threshold: 0.1,
+            consecutive_n,
+            p: 0,
+        })
+    }
+
+    #[test]
+    fn test_report_records_values_per_offset_and_thresholds() {
+        let candles = test_candles(&[100.0, 100.0, 100.0, 100.0, 100.0]);
+        let report = TechnicalFilter::matches_filters_with_report(
+            "TEST/USDT",
+            &[gap_above_filter(2)],
+            &candles,
+            115.0,
+        );
+
+        assert!(report.passed);
+        let filter = &report.filters[0];
+        assert_eq!(filter.filter_type, "GapAboveReferenceThreshold");
+        assert_eq!(filter.thresholds["gap_threshold"], 0.1);
+        assert!(filter.sufficient_candles);
+        assert_eq!(
+            filter.offsets.iter().map(|o| o.offset).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        for offset in &filter.offsets {
+            assert!((offset.values["reference"] - 100.0).abs() < 1e-9);
+            assert!((offset.values["gap_ratio"] - 0.15).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_report_records_pattern_and_level_values() {
+        let candles = test_candles(&[100.0, 104.0, 98.0, 103.0, 99.0, 102.0]);
+        let pattern = TechnicalFilterConfig::CandlePattern(CandlePatternParams {
+            filter_type: CandlePatternFilterType::DojiPattern,
+            consecutive_n: 2,
+            ..Default::default()
+        });
+        let level = TechnicalFilterConfig::SupportResistance(SupportResistanceParams {
+            lookback_period: 5,
+            filter_type: SupportResistanceFilterType::AboveSupport,
+            consecutive_n: 2,
+            ..Default::default()
+        });
+        let report = TechnicalFilter::matches_filters_with_report(
+            "TEST/USDT",
+            &[pattern, level],
+            &candles,
+            101.0,
+        );
+
+        // 캔들 패턴은 오프셋별 감지된 패턴과 신뢰도를 남김
+        let pattern = &report.filters[0];
+        assert_eq!(pattern.offsets.len(), 2);
+        for offset in &pattern.offsets {
+            assert!(offset.labels.contains_key("single_pattern"));
+            assert!(offset.labels.contains_key("multi_pattern"));
+            assert!(offset.values.contains_key("confidence_score"));
+            assert!(!offset.values.contains_key("close"));
+        }
+
+        // 지지/저항은 가장 가까운 레벨과 현재가와의 거리를 남김
+        let level = &report.filters[1];
+        assert_eq!(level.offsets.len(), 2);
+        for offset in &level.offsets {
+            assert!(!offset.values.contains_key("close"));
+            if let Some(support) = offset.values.get("support") {
+                assert!((offset.values["support_distance"] - (101.0 - support)).abs() < 1e-9);
+            }
+            if let Some(resistance) = offset.values.get("resistance") {
+                assert!((offset.values["resistance_distance"] - (resistance - 101.0)).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_report_evaluates_all_filters_and_marks_insufficient_candles() {
+        let candles = test_candles(&[100.0, 100.0, 100.0]);
+        let rsi = TechnicalFilterConfig::RSI(RSIParams {
+            period: 14,
+            filter_type: RSIFilterType::Oversold,
+            consecutive_n: 1,
+            ..Default::default()
+        });
+        let report = TechnicalFilter::matches_filters_with_report(
+            "TEST/USDT",
+            &[rsi, gap_above_filter(1)],
+            &candles,
+            115.0,
+        );
+
+        // 첫 필터가 탈락해도 두 번째 필터까지 평가
+        assert!(!report.passed);
+        assert_eq!(report.filters.len(), 2);
+        assert!(report.filters[1].passed);
+
+        let rejection = report.first_rejection().unwrap();
+        assert_eq!(rejection.filter, "RSI");
+        assert_eq!(rejection.required_candles, 15);
+        assert_eq!(rejection.available_candles, 3);
+        assert!(!rejection.sufficient_candles);
+        assert!(rejection.offsets.is_empty());
+        assert!(rejection.error.is_none());
+    }
+
+    #[test]
+    fn test_report_records_validation_error() {
+        let candles = test_candles(&[100.0, 100.0, 100.0]);
+        let invalid = TechnicalFilterConfig::RSI(RSIParams {
+            period: 0,
+            ..Default::default()
+        });
+        let report =
+            TechnicalFilter::matches_filters_with_report("TEST/USDT", &[invalid], &candles, 100.0);
+
+        assert!(!report.passed);
+        assert!(report.filters[0].error.is_some());
+        assert!(report.filters[0].offsets.is_empty());
+    }
+
+    #[test]
+    fn test_report_serializes_to_json() {
+        let candles = test_candles(&[100.0, 100.0, 100.0]);
+        let report = TechnicalFilter::matches_filters_with_report(
+            "TEST/USDT",
+            &[gap_above_filter(1)],
+            &candles,
+            115.0,
+        );
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["symbol"], "TEST/USDT");
+        assert_eq!(json["passed"], true);
+        assert_eq!(json["filters"][0]["filter"], "PriceReferenceGap");
+        assert_eq!(json["filters"][0]["offsets"][0]["offset"], 0);
+        assert!(json["filters"][0]["offsets"][0]["values"]["reference"].is_number());
+        // 오류가 없으면 error 필드를 생략
+        assert!(json["filters"][0].get("error").is_none());
+    }
+}
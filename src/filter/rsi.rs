@@ -1,8 +1,10 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{RSIFilterType, RSIParams, utils};
 use crate::analyzer::AnalyzerOps;
 use crate::analyzer::rsi_analyzer::RSIAnalyzer;
-use crate::candle_store::CandleStore;
 use crate::indicator::ma::MAType;
 use trading_chart::Candle;
 
@@ -14,13 +16,69 @@ fn crossed_below(current: f64, previous: f64, threshold: f64) -> bool {
     current < threshold && previous >= threshold
 }
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &RSIParams) -> usize {
+    params.period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 RSI 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &RSIParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &rsi_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| [("rsi", data.rsi.value())],
+    )
+}
+
+fn rsi_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &RSIParams,
+) -> CachedRef<'a, RSIAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(params.period.to_string(), || {
+        RSIAnalyzer::new(params.period, &MAType::SMA, &[params.period], candle_store)
+    })
+}
+
+/// 통과한 RSI 필터의 강도 (p번째 RSI가 기준선을 넘어선 정도)
+///
+/// 기준선이 하나로 정해지지 않는 타입은 None을 반환합니다.
+pub(crate) fn score_rsi<C: Candle + 'static>(
+    params: &RSIParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let rsi = rsi_analyzer(source, params)
+        .items
+        .get(params.p)?
+        .rsi
+        .value();
+
+    match params.filter_type {
+        RSIFilterType::Oversold => excess_ratio(params.oversold - rsi, params.oversold),
+        RSIFilterType::Overbought => {
+            excess_ratio(rsi - params.overbought, 100.0 - params.overbought)
+        }
+        RSIFilterType::Above50 => excess_ratio(rsi - 50.0, 50.0),
+        RSIFilterType::Below50 => excess_ratio(50.0 - rsi, 50.0),
+        RSIFilterType::Above40 => excess_ratio(rsi - 40.0, 60.0),
+        RSIFilterType::Below60 => excess_ratio(60.0 - rsi, 60.0),
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 RSI 필터 적용
-pub(crate) fn filter_rsi<C: Candle + 'static>(
+pub(crate) fn filter_rsi<'a, C: Candle + 'static>(
     coin: &str,
     params: &RSIParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     log::debug!(
         "RSI 필터 적용 - 기간: {}, 과매도: {}, 과매수: {}, 교차임계값: {}, 타입: {:?}, 연속성: {}",
         params.period,
@@ -36,15 +94,13 @@ pub(crate) fn filter_rsi<C: Candle + 'static>(
     utils::validate_percentage_threshold(params.cross_threshold, "RSI cross_threshold")?;
 
     // 경계 조건 체크
-    let required_length = params.period + params.consecutive_n;
+    let required_length = required_candles(params);
     if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
         return Ok(false);
     }
 
     // RSIAnalyzer 생성
-    let ma_type = MAType::SMA;
-    let ma_periods: Vec<usize> = vec![params.period];
-    let analyzer = RSIAnalyzer::new(params.period, &ma_type, &ma_periods, candle_store);
+    let analyzer = rsi_analyzer(source, params);
 
     // 테스트 데이터의 캔들 수와 RSI 계산 결과에 정확한 처리를 위해 최소한의 항목 검증
     if analyzer.items.is_empty() {
@@ -0,0 +1,345 @@
+// 필터 점수
+// 통과 여부 대신 0..1 강도를 계산하고, 가중치를 둔 필터 목록의 점수를 합산합니다.
+// 스크리너가 후보를 포함/제외하는 데 그치지 않고 순위를 매길 수 있도록 하기 위함입니다.
+
+use super::cache::FilterSource;
+use super::{
+    FilterError, Result, TechnicalFilter, TechnicalFilterConfig, TechnicalFilterType, utils,
+};
+use super::{
+    adx, atr, bollinger_band, cci, donchian, ichimoku, keltner, macd, momentum, moving_average,
+    price_reference_gap, rsi, slope, squeeze, stoch_rsi, stochastic, supertrend, three_rsi,
+    timeframe, volume, vwap, williams_r,
+};
+use serde::{Deserialize, Serialize};
+use trading_chart::Candle;
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+/// 가중치를 둔 필터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WeightedFilter {
+    /// 필터 설정
+    pub filter: TechnicalFilterConfig,
+    /// 가중치 (기본값: 1.0)
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+}
+
+impl WeightedFilter {
+    pub fn new(filter: TechnicalFilterConfig, weight: f64) -> Self {
+        Self { filter, weight }
+    }
+
+    /// 가중치와 필터 파라미터 검증
+    ///
+    /// 강도를 계산할 수 없는 필터 종류도 에러를 반환합니다.
+    pub fn validate<C: Candle + 'static>(&self) -> Result<()> {
+        utils::validate_positive_number(self.weight, "weight")?;
+        validate_scorable(&self.filter)?;
+        self.filter.validate::<C>()
+    }
+}
+
+impl From<TechnicalFilterConfig> for WeightedFilter {
+    fn from(filter: TechnicalFilterConfig) -> Self {
+        Self::new(filter, default_weight())
+    }
+}
+
+/// 점수를 계산할 수 있는 필터 종류인지 검증
+///
+/// 신호/패턴만 판정하는 필터(CopyS, 캔들 패턴, 지지/저항, 지표 비교, 사용자 정의)는
+/// 통과 여부 외에 정규화할 기준이 없으므로 통과만으로 1점을 주지 않고 에러를 반환합니다.
+/// 간격 필터는 감싼 필터의 종류로 판단합니다.
+pub(crate) fn validate_scorable(filter: &TechnicalFilterConfig) -> Result<()> {
+    match filter.filter_type() {
+        filter_type @ (TechnicalFilterType::Copys
+        | TechnicalFilterType::CandlePattern
+        | TechnicalFilterType::SupportResistance
+        | TechnicalFilterType::Compare
+        | TechnicalFilterType::Custom(_)) => Err(FilterError::UnscorableFilterType {
+            filter_type: filter_type.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// 기준선을 넘어선 정도를 범위로 나눈 강도 (0..1)
+///
+/// 범위가 0 이하이거나 값이 NaN이면 정규화할 수 없으므로 None을 반환합니다.
+pub(crate) fn excess_ratio(excess: f64, range: f64) -> Option<f64> {
+    (range > 0.0)
+        .then(|| (excess / range).clamp(0.0, 1.0))
+        .filter(|strength| !strength.is_nan())
+}
+
+/// 필터 점수 계산 (내부 헬퍼 함수, CandleStore 재사용)
+///
+/// 통과하지 못한 필터는 0, 통과한 필터는 기준선을 넘어선 정도를 0..1로 정규화한 값입니다.
+/// 점수를 계산할 수 없는 필터 종류(`validate_scorable`)는 에러를 반환하고,
+/// 교차처럼 한 시점의 사건을 판정하는 세부 타입은 통과 시 1을 반환합니다.
+pub(crate) fn score_filter<C: Candle + 'static>(
+    symbol: &str,
+    filter: &TechnicalFilterConfig,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Result<f64> {
+    validate_scorable(filter)?;
+
+    // 간격을 지정한 필터는 해당 간격의 캔들로 점수 계산
+    if let TechnicalFilterConfig::Timeframe(config) = filter {
+        return timeframe::with_source(config, source, |filter, source| {
+            score_filter(symbol, filter, source, current_price)
+        })?;
+    }
+
+    if !TechnicalFilter::matches_filter_internal(symbol, filter, source, current_price)? {
+        return Ok(0.0);
+    }
+
+    let strength = match filter {
+        TechnicalFilterConfig::RSI(params) => rsi::score_rsi(params, source),
+        TechnicalFilterConfig::MACD(params) => macd::score_macd(params, source, current_price),
+        TechnicalFilterConfig::BollingerBand(params) => {
+            bollinger_band::score_bollinger_band(params, source, current_price)
+        }
+        TechnicalFilterConfig::ADX(params) => adx::score_adx(params, source),
+        TechnicalFilterConfig::MovingAverage(params) => {
+            moving_average::score_moving_average(params, source, current_price)
+        }
+        TechnicalFilterConfig::PriceReferenceGap(params) => {
+            price_reference_gap::score_price_reference_gap(params, source, current_price)
+        }
+        TechnicalFilterConfig::Ichimoku(params) => {
+            ichimoku::score_ichimoku(params, source, current_price)
+        }
+        TechnicalFilterConfig::VWAP(params) => vwap::score_vwap(params, source, current_price),
+        TechnicalFilterConfig::ATR(params) => atr::score_atr(params, source),
+        TechnicalFilterConfig::SuperTrend(params) => {
+            supertrend::score_supertrend(params, source, current_price)
+        }
+        TechnicalFilterConfig::Volume(params) => volume::score_volume(params, source),
+        TechnicalFilterConfig::ThreeRSI(params) => three_rsi::score_three_rsi(params, source),
+        TechnicalFilterConfig::Momentum(params) => momentum::score_momentum(params, source),
+        TechnicalFilterConfig::Slope(params) => slope::score_slope(params, source),
+        TechnicalFilterConfig::Stochastic(params) => stochastic::score_stochastic(params, source),
+        TechnicalFilterConfig::StochRSI(params) => stoch_rsi::score_stoch_rsi(params, source),
+        TechnicalFilterConfig::WilliamsR(params) => williams_r::score_williams_r(params, source),
+        TechnicalFilterConfig::CCI(params) => cci::score_cci(params, source),
+        TechnicalFilterConfig::Keltner(params) => keltner::score_keltner(params, source),
+        TechnicalFilterConfig::Donchian(params) => donchian::score_donchian(params, source),
+        TechnicalFilterConfig::Squeeze(params) => squeeze::score_squeeze(params, source),
+        // validate_scorable에서 걸러지는 필터 종류
+        TechnicalFilterConfig::Copys(_)
+        | TechnicalFilterConfig::CandlePattern(_)
+        | TechnicalFilterConfig::SupportResistance(_)
+        | TechnicalFilterConfig::Compare(_)
+        | TechnicalFilterConfig::Custom(_)
+        | TechnicalFilterConfig::Timeframe(_) => None,
+    };
+
+    Ok(strength.unwrap_or(1.0))
+}
+
+/// 가중 평균 점수 계산 (내부 헬퍼 함수, CandleStore 재사용)
+///
+/// `matches_filters`와 같이 필터 적용 오류는 해당 필터 점수를 0으로 처리합니다.
+/// 필터 목록이 비었거나, 가중치가 양수가 아니거나, 점수를 계산할 수 없는 필터가 있으면
+/// 에러를 반환합니다.
+pub(crate) fn score_filters<C: Candle + 'static>(
+    symbol: &str,
+    filters: &[WeightedFilter],
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Result<f64> {
+    utils::validate_non_empty_list(filters, "weighted filters")?;
+    for weighted in filters {
+        utils::validate_positive_number(weighted.weight, "weight")?;
+        validate_scorable(&weighted.filter)?;
+    }
+
+    let mut weighted_sum = 0.0;
+    let mut total_weight = 0.0;
+    for weighted in filters {
+        let score =
+            score_filter(symbol, &weighted.filter, source, current_price).unwrap_or_else(|e| {
+                log::warn!(
+                    "코인 {} 필터 {} 점수 계산 중 오류: {}",
+                    symbol,
+                    weighted.filter.filter_type(),
+                    e
+                );
+                0.0
+            });
+
+        weighted_sum += weighted.weight * score;
+        total_weight += weighted.weight;
+    }
+
+    Ok(weighted_sum / total_weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{
+        CandlePatternParams, CopysParams, MovingAverageFilterType, MovingAverageParams,
+        PriceReferenceGapFilterType, PriceReferenceGapParams, PriceReferenceSource, RSIFilterType,
+        RSIParams, TimeframeFilterConfig,
+    };
+    use crate::tests::{TestCandle, test_candles};
+
+    // SMA(3) 기준가보다 gap_threshold 이상 높은지 확인하는 필터
+    fn gap_above_filter(gap_threshold: f64) -> TechnicalFilterConfig {
+        TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+            reference_source: PriceReferenceSource::MovingAverage {
+                ma_type: crate::indicator::ma::MAType::SMA,
+                period: 3,
+            },
+            filter_type: PriceReferenceGapFilterType::GapAboveReferenceThreshold,
+            gap_threshold,
+            consecutive_n: 1,
+            p: 0,
+        })
+    }
+
+    #[test]
+    fn test_excess_ratio_clamps_and_rejects_empty_range() {
+        assert_eq!(excess_ratio(5.0, 10.0), Some(0.5));
+        assert_eq!(excess_ratio(-1.0, 10.0), Some(0.0));
+        assert_eq!(excess_ratio(30.0, 10.0), Some(1.0));
+        assert_eq!(excess_ratio(1.0, 0.0), None);
+        assert_eq!(excess_ratio(f64::NAN, 10.0), None);
+    }
+
+    #[test]
+    fn test_score_filter_measures_gap_beyond_threshold() {
+        // SMA(3) = 100, 현재가 115 -> 괴리율 15%, 임계값 10%를 절반만큼 초과
+        let candles = test_candles(&[100.0, 100.0, 100.0]);
+        let filter = gap_above_filter(0.1);
+
+        let passing = TechnicalFilter::score_filter("TEST/USDT", &filter, &candles, 115.0).unwrap();
+        let stronger =
+            TechnicalFilter::score_filter("TEST/USDT", &filter, &candles, 130.0).unwrap();
+        let failing = TechnicalFilter::score_filter("TEST/USDT", &filter, &candles, 105.0).unwrap();
+
+        assert!((passing - 0.5).abs() < 1e-9);
+        assert_eq!(stronger, 1.0);
+        assert_eq!(failing, 0.0);
+    }
+
+    #[test]
+    fn test_score_filter_measures_rsi_oversold_depth() {
+        // 3씩 하락하다 4번째 캔들마다 2 반등 -> RSI가 과매도 구간 깊숙이 위치
+        let mut close = 200.0;
+        let closes: Vec<f64> = (0..40)
+            .map(|i| {
+                close += if i % 4 == 3 { 2.0 } else { -3.0 };
+                close
+            })
+            .collect();
+        let candles = test_candles(&closes);
+        let filter = |oversold| {
+            TechnicalFilterConfig::RSI(RSIParams {
+                period: 14,
+                oversold,
+                filter_type: RSIFilterType::Oversold,
+                consecutive_n: 1,
+                ..Default::default()
+            })
+        };
+
+        // 같은 RSI라도 과매도 기준이 높을수록 기준선 아래로 깊게 들어가 있음
+        let shallow =
+            TechnicalFilter::score_filter("TEST/USDT", &filter(30.0), &candles, close).unwrap();
+        let deep =
+            TechnicalFilter::score_filter("TEST/USDT", &filter(60.0), &candles, close).unwrap();
+
+        assert!(shallow > 0.0 && shallow < 1.0);
+        assert!(deep > shallow);
+    }
+
+    #[test]
+    fn test_score_filter_measures_moving_average_gap() {
+        // SMA(3) = 100, 횡보 임계값 2% 기준으로 괴리율 1%는 절반 강도
+        let candles = test_candles(&[100.0, 100.0, 100.0]);
+        let filter = TechnicalFilterConfig::MovingAverage(MovingAverageParams {
+            periods: vec![3],
+            filter_type: MovingAverageFilterType::PriceAboveFirstMA,
+            ..Default::default()
+        });
+
+        let half = TechnicalFilter::score_filter("TEST/USDT", &filter, &candles, 101.0).unwrap();
+        let full = TechnicalFilter::score_filter("TEST/USDT", &filter, &candles, 104.0).unwrap();
+
+        assert!((half - 0.5).abs() < 1e-9);
+        assert_eq!(full, 1.0);
+    }
+
+    #[test]
+    fn test_unscorable_filter_types_are_rejected() {
+        let candles = test_candles(&[100.0, 100.0, 100.0]);
+        let copys = TechnicalFilterConfig::Copys(CopysParams::default());
+        let hourly_pattern = TechnicalFilterConfig::Timeframe(TimeframeFilterConfig::new(
+            "1h",
+            TechnicalFilterConfig::CandlePattern(CandlePatternParams::default()),
+        ));
+
+        for filter in [copys, hourly_pattern] {
+            assert!(matches!(
+                WeightedFilter::from(filter.clone()).validate::<TestCandle>(),
+                Err(FilterError::UnscorableFilterType { .. })
+            ));
+            assert!(TechnicalFilter::score_filter("TEST/USDT", &filter, &candles, 100.0).is_err());
+            assert!(
+                TechnicalFilter::score_filters(
+                    "TEST/USDT",
+                    &[WeightedFilter::from(filter)],
+                    &candles,
+                    100.0
+                )
+                .is_err()
+            );
+        }
+        assert!(
+            WeightedFilter::from(gap_above_filter(0.1))
+                .validate::<TestCandle>()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_score_filters_weights_scores_and_rejects_invalid_weight() {
+        let candles = test_candles(&[100.0, 100.0, 100.0]);
+        let filters = [
+            WeightedFilter::new(gap_above_filter(0.1), 3.0),
+            WeightedFilter::new(gap_above_filter(0.5), 1.0),
+        ];
+
+        // 0.5 * 3 + 0 * 1 = 1.5, 가중치 합 4
+        let score = TechnicalFilter::score_filters("TEST/USDT", &filters, &candles, 115.0).unwrap();
+        assert!((score - 0.375).abs() < 1e-9);
+
+        let invalid = [WeightedFilter::new(gap_above_filter(0.1), 0.0)];
+        assert!(TechnicalFilter::score_filters("TEST/USDT", &invalid, &candles, 115.0).is_err());
+        assert!(
+            TechnicalFilter::score_filters::<TestCandle>("TEST/USDT", &[], &candles, 115.0)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_weighted_filter_deserialize_defaults_weight() {
+        let weighted: WeightedFilter = serde_json::from_str(
+            r#"{"filter": {"type": "RSI", "period": 14, "filter_type": "Oversold"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(weighted.weight, 1.0);
+        assert!(matches!(weighted.filter, TechnicalFilterConfig::RSI(_)));
+    }
+}
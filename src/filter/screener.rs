@@ -0,0 +1,443 @@
+// 멀티 심볼 스크리너
+// 심볼별 TechnicalFilterContext를 유지하면서 캔들을 증분 추가하고,
+// 한 번의 호출로 전체 심볼에 필터 조건식을 평가합니다.
+
+use super::{FilterExpression, Result, TechnicalFilterContext, WeightedFilter, utils};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use trading_chart::Candle;
+
+/// 필터 하나의 평가 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterOutcome {
+    /// 필터 조건식 (예: `ANY(RSI, ALL(볼린저밴드, Volume))`)
+    pub filter: String,
+    /// 통과 여부 (오류 시 false)
+    pub passed: bool,
+    /// 필터 적용 오류
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 심볼 하나의 스크리닝 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SymbolScreening {
+    /// 심볼
+    pub symbol: String,
+    /// 평가에 사용된 현재가
+    pub current_price: f64,
+    /// 모든 필터 통과 여부
+    pub passed: bool,
+    /// 필터별 결과 (입력 순서)
+    pub filters: Vec<FilterOutcome>,
+}
+
+/// 전체 스크리닝 결과
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ScreenerResult {
+    /// 모든 필터를 통과한 심볼 (이름순)
+    pub matched: Vec<String>,
+    /// 심볼별 상세 결과 (이름순)
+    pub details: Vec<SymbolScreening>,
+}
+
+impl ScreenerResult {
+    /// 심볼의 상세 결과
+    pub fn detail(&self, symbol: &str) -> Option<&SymbolScreening> {
+        self.details.iter().find(|detail| detail.symbol == symbol)
+    }
+}
+
+/// 필터 하나의 점수
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterScore {
+    /// 필터 타입
+    pub filter: String,
+    /// 가중치
+    pub weight: f64,
+    /// 점수 (0..1, 오류 시 0)
+    pub score: f64,
+    /// 필터 적용 오류
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 심볼 하나의 순위 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SymbolScore {
+    /// 심볼
+    pub symbol: String,
+    /// 평가에 사용된 현재가
+    pub current_price: f64,
+    /// 가중 평균 점수 (0..1)
+    pub score: f64,
+    /// 필터별 점수 (입력 순서)
+    pub filters: Vec<FilterScore>,
+}
+
+/// 심볼별 필터 컨텍스트와 외부 현재가
+struct SymbolState<C: Candle + 'static> {
+    context: TechnicalFilterContext<C>,
+    current_price: Option<f64>,
+}
+
+impl<C: Candle + 'static> SymbolState<C> {
+    /// 외부 현재가가 없으면 최신 캔들 종가 사용
+    fn current_price(&self) -> Option<f64> {
+        self.current_price.or_else(|| {
+            self.context
+                .candle_store()
+                .first()
+                .map(|candle| candle.close_price())
+        })
+    }
+}
+
+/// 멀티 심볼 스크리너
+///
+/// 심볼마다 `CandleStore`를 한 번만 만들고 이후에는 캔들을 증분 추가하므로,
+/// 매 평가마다 캔들 슬라이스로 저장소를 다시 만드는 `TechnicalFilter::matches_filters`보다 저렴합니다.
+pub struct Screener<C: Candle + 'static> {
+    symbols: BTreeMap<String, SymbolState<C>>,
+    max_candles: usize,
+}
+
+impl<C: Candle + 'static> Screener<C> {
+    /// 새 스크리너 생성
+    ///
+    /// # Arguments
+    /// * `max_candles` - 심볼의 캔들 간격별로 보관할 최대 캔들 수
+    pub fn new(max_candles: usize) -> Self {
+        Self {
+            symbols: BTreeMap::new(),
+            max_candles,
+        }
+    }
+
+    /// 심볼의 캔들 히스토리 설정 (기존 히스토리와 외부 현재가는 교체)
+    ///
+    /// 간격이 다른 캔들은 간격별로 보관하며, `interval`을 지정하지 않은 필터는 첫 캔들의 간격으로 평가합니다.
+    pub fn insert_symbol(&mut self, symbol: impl Into<String>, candles: Vec<C>) {
+        self.symbols.insert(
+            symbol.into(),
+            SymbolState {
+                context: TechnicalFilterContext::from_candles(candles, self.max_candles, true),
+                current_price: None,
+            },
+        );
+    }
+
+    /// 심볼 제거
+    pub fn remove_symbol(&mut self, symbol: &str) -> bool {
+        self.symbols.remove(symbol).is_some()
+    }
+
+    /// 캔들 증분 추가
+    ///
+    /// 같은 시각의 캔들은 교체하므로 마감 전 캔들을 반복해서 갱신할 수 있습니다.
+    /// 등록되지 않은 심볼이면 새로 추가하고, 외부 현재가는 캔들 종가로 초기화합니다.
+    pub fn update(&mut self, symbol: &str, candle: C) {
+        match self.symbols.get_mut(symbol) {
+            Some(state) => {
+                state.context.update(candle);
+                state.current_price = None;
+            }
+            None => self.insert_symbol(symbol, vec![candle]),
+        }
+    }
+
+    /// 외부 현재가 갱신 (다음 캔들 갱신 전까지 평가에 사용)
+    ///
+    /// 등록되지 않은 심볼이면 false를 반환합니다.
+    pub fn update_price(&mut self, symbol: &str, current_price: f64) -> bool {
+        match self.symbols.get_mut(symbol) {
+            Some(state) => {
+                state.current_price = Some(current_price);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 등록된 심볼 목록 (이름순)
+    pub fn symbols(&self) -> impl Iterator<Item = &str> {
+        self.symbols.keys().map(String::as_str)
+    }
+
+    /// 등록된 심볼 수
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// 등록된 심볼이 없는지 확인
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// 심볼의 필터 컨텍스트
+    pub fn context(&self, symbol: &str) -> Option<&TechnicalFilterContext<C>> {
+        self.symbols.get(symbol).map(|state| &state.context)
+    }
+
+    /// 전체 심볼에 필터 목록 평가
+    ///
+    /// 모든 필터(AND)를 통과한 심볼과 심볼별/필터별 결과를 반환합니다.
+    /// 상세 결과를 위해 앞선 필터가 실패해도 나머지 필터를 모두 평가합니다.
+    /// 캔들과 외부 현재가가 모두 없는 심볼은 건너뜁니다.
+    ///
+    /// # Arguments
+    /// * `filters` - 필터 조건식 목록
+    ///
+    /// # Returns
+    /// * `Result<ScreenerResult>` - 스크리닝 결과 (필터 설정이 잘못되면 에러)
+    pub fn screen(&self, filters: &[FilterExpression]) -> Result<ScreenerResult> {
+        // 설정 오류는 심볼마다 반복 보고하지 않고 한 번만 반환
+        for filter in filters {
+            filter.validate::<C>()?;
+        }
+
+        let mut result = ScreenerResult::default();
+        for (symbol, state) in &self.symbols {
+            let Some(current_price) = state.current_price() else {
+                log::debug!("코인 {symbol} 캔들/현재가 없음, 스크리닝 제외");
+                continue;
+            };
+
+            let outcomes: Vec<FilterOutcome> = filters
+                .iter()
+                .map(|filter| {
+                    let evaluated = state
+                        .context
+                        .matches_expression(symbol, filter, current_price);
+                    if let Err(e) = &evaluated {
+                        log::warn!("코인 {symbol} 필터 {filter} 적용 중 오류: {e}");
+                    }
+
+                    FilterOutcome {
+                        filter: filter.to_string(),
+                        passed: matches!(evaluated, Ok(true)),
+                        error: evaluated.err().map(|e| e.to_string()),
+                    }
+                })
+                .collect();
+
+            let passed = outcomes.iter().all(|outcome| outcome.passed);
+            if passed {
+                result.matched.push(symbol.clone());
+            }
+            result.details.push(SymbolScreening {
+                symbol: symbol.clone(),
+                current_price,
+                passed,
+                filters: outcomes,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// 전체 심볼을 가중 평균 필터 점수로 순위 매김
+    ///
+    /// 점수가 높은 순으로 정렬하고, 점수가 같으면 이름순입니다.
+    /// 캔들과 외부 현재가가 모두 없는 심볼은 건너뜁니다.
+    ///
+    /// # Arguments
+    /// * `filters` - 가중치를 둔 필터 목록
+    ///
+    /// # Returns
+    /// * `Result<Vec<SymbolScore>>` - 심볼별 점수 (필터 설정이 잘못되면 에러)
+    pub fn rank(&self, filters: &[WeightedFilter]) -> Result<Vec<SymbolScore>> {
+        utils::validate_non_empty_list(filters, "weighted filters")?;
+        for filter in filters {
+            filter.validate::<C>()?;
+        }
+        let total_weight: f64 = filters.iter().map(|filter| filter.weight).sum();
+
+        let mut ranking = Vec::with_capacity(self.symbols.len());
+        for (symbol, state) in &self.symbols {
+            let Some(current_price) = state.current_price() else {
+                log::debug!("코인 {symbol} 캔들/현재가 없음, 순위 제외");
+                continue;
+            };
+
+            let scores: Vec<FilterScore> = filters
+                .iter()
+                .map(|weighted| {
+                    let evaluated =
+                        state
+                            .context
+                            .score_filter(symbol, &weighted.filter, current_price);
+                    if let Err(e) = &evaluated {
+                        log::warn!(
+                            "코인 {symbol} 필터 {} 점수 계산 중 오류: {e}",
+                            weighted.filter.filter_type()
+                        );
+                    }
+
+                    FilterScore {
+                        filter: weighted.filter.filter_type().to_string(),
+                        weight: weighted.weight,
+                        score: evaluated.as_ref().copied().unwrap_or(0.0),
+                        error: evaluated.err().map(|e| e.to_string()),
+                    }
+                })
+                .collect();
+
+            let weighted_sum: f64 = scores.iter().map(|s| s.weight * s.score).sum();
+            ranking.push(SymbolScore {
+                symbol: symbol.clone(),
+                current_price,
+                score: weighted_sum / total_weight,
+                filters: scores,
+            });
+        }
+
+        // 심볼은 이름순으로 순회하므로 안정 정렬로 동점은 이름순 유지
+        ranking.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(ranking)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{
+        FilterError, PriceReferenceGapFilterType, PriceReferenceGapParams, PriceReferenceSource,
+        TechnicalFilterConfig,
+    };
+    use crate::tests::{TestCandle, test_candle, test_candles};
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_screener_and_context_are_send() {
+        assert_send::<TechnicalFilterContext<TestCandle>>();
+        assert_send::<Screener<TestCandle>>();
+    }
+
+    // SMA(3) 기준가보다 gap_threshold 이상 높은지 확인하는 필터
+    fn gap_above_filter(gap_threshold: f64) -> FilterExpression {
+        TechnicalFilterConfig::PriceReferenceGap(PriceReferenceGapParams {
+            reference_source: PriceReferenceSource::MovingAverage {
+                ma_type: crate::indicator::ma::MAType::SMA,
+                period: 3,
+            },
+            filter_type: PriceReferenceGapFilterType::GapAboveReferenceThreshold,
+            gap_threshold,
+            consecutive_n: 1,
+            p: 0,
+        })
+        .into()
+    }
+
+    #[test]
+    fn test_screen_reports_matched_symbols_and_filter_details() {
+        let mut screener = Screener::new(100);
+        screener.insert_symbol("UP/USDT", test_candles(&[100.0, 100.0, 100.0, 130.0]));
+        screener.insert_symbol("FLAT/USDT", test_candles(&[100.0, 100.0, 100.0, 100.0]));
+
+        let filters = [gap_above_filter(0.0), gap_above_filter(0.1)];
+        let result = screener.screen(&filters).unwrap();
+
+        assert_eq!(result.matched, vec!["UP/USDT".to_string()]);
+        assert_eq!(result.details.len(), 2);
+
+        let flat = result.detail("FLAT/USDT").unwrap();
+        assert_eq!(flat.current_price, 100.0);
+        assert!(!flat.passed);
+        assert!(flat.filters[0].passed);
+        assert!(!flat.filters[1].passed);
+        assert_eq!(flat.filters[1].filter, "PriceReferenceGap");
+        assert_eq!(flat.filters[1].error, None);
+    }
+
+    #[test]
+    fn test_screen_uses_incremental_updates_and_external_price() {
+        let mut screener = Screener::new(100);
+        screener.insert_symbol("FLAT/USDT", test_candles(&[100.0, 100.0, 100.0]));
+        let filters = [gap_above_filter(0.1)];
+
+        assert!(screener.screen(&filters).unwrap().matched.is_empty());
+
+        // 외부 현재가만 올려도 같은 캔들 상태로 재평가
+        assert!(screener.update_price("FLAT/USDT", 120.0));
+        assert_eq!(screener.screen(&filters).unwrap().matched.len(), 1);
+
+        // 마감 전 캔들 갱신은 같은 시각의 캔들을 교체
+        screener.update("FLAT/USDT", test_candle(4, 101.0));
+        screener.update("FLAT/USDT", test_candle(4, 140.0));
+        let context = screener.context("FLAT/USDT").unwrap();
+        assert_eq!(context.candle_store().len(), 4);
+        assert_eq!(context.candle_store().first().unwrap().close, 140.0);
+
+        let result = screener.screen(&filters).unwrap();
+        assert_eq!(result.matched, vec!["FLAT/USDT".to_string()]);
+        assert_eq!(result.details[0].current_price, 140.0);
+
+        // 등록되지 않은 심볼은 캔들 갱신 시 추가
+        assert!(!screener.update_price("NEW/USDT", 1.0));
+        screener.update("NEW/USDT", test_candle(1, 10.0));
+        assert_eq!(
+            screener.symbols().collect::<Vec<_>>(),
+            ["FLAT/USDT", "NEW/USDT"]
+        );
+    }
+
+    #[test]
+    fn test_screen_treats_insufficient_candles_as_failure() {
+        let mut screener = Screener::new(100);
+        screener.insert_symbol("SHORT/USDT", test_candles(&[100.0]));
+
+        let result = screener.screen(&[gap_above_filter(0.0)]).unwrap();
+
+        // 캔들 부족은 오류가 아닌 불통과
+        let detail = result.detail("SHORT/USDT").unwrap();
+        assert!(!detail.passed);
+        assert_eq!(detail.filters[0].error, None);
+    }
+
+    #[test]
+    fn test_rank_orders_symbols_by_weighted_score() {
+        let mut screener = Screener::new(100);
+        // SMA(3) = 100 기준 괴리율: STRONG 30%, MILD 15%, FLAT 0%
+        screener.insert_symbol("STRONG/USDT", test_candles(&[100.0, 100.0, 100.0]));
+        screener.insert_symbol("MILD/USDT", test_candles(&[100.0, 100.0, 100.0]));
+        screener.insert_symbol("FLAT/USDT", test_candles(&[100.0, 100.0, 100.0]));
+        screener.update_price("STRONG/USDT", 130.0);
+        screener.update_price("MILD/USDT", 115.0);
+
+        let gap_filter = |threshold| match gap_above_filter(threshold) {
+            FilterExpression::Filter(filter) => filter,
+            FilterExpression::Group(_) => unreachable!(),
+        };
+        let filters = [
+            WeightedFilter::new(gap_filter(0.1), 3.0),
+            WeightedFilter::new(gap_filter(0.2), 1.0),
+        ];
+        let ranking = screener.rank(&filters).unwrap();
+
+        let symbols: Vec<&str> = ranking.iter().map(|s| s.symbol.as_str()).collect();
+        assert_eq!(symbols, ["STRONG/USDT", "MILD/USDT", "FLAT/USDT"]);
+        assert!((ranking[0].score - 0.875).abs() < 1e-9);
+        assert!((ranking[1].score - 0.375).abs() < 1e-9);
+        assert_eq!(ranking[2].score, 0.0);
+        assert_eq!(ranking[1].filters[1].score, 0.0);
+
+        let err = screener
+            .rank(&[WeightedFilter::new(gap_filter(0.1), -1.0)])
+            .unwrap_err();
+        assert!(matches!(err, FilterError::InvalidPositiveNumber { .. }));
+    }
+
+    #[test]
+    fn test_screen_rejects_invalid_filters_once() {
+        let mut screener = Screener::new(100);
+        screener.insert_symbol("UP/USDT", test_candles(&[100.0, 100.0, 100.0, 130.0]));
+
+        let err = screener
+            .screen(&[FilterExpression::any(Vec::new())])
+            .unwrap_err();
+
+        assert!(matches!(err, FilterError::InvalidEmptyList { .. }));
+    }
+}
@@ -1,16 +1,86 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{SlopeFilterType, SlopeParams, utils};
-use crate::analyzer::slope_analyzer::SlopeAnalyzer;
-use crate::candle_store::CandleStore;
+use crate::analyzer::slope_analyzer::{IndicatorType, SlopeAnalyzer};
 use trading_chart::Candle;
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &SlopeParams) -> usize {
+    params.period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 기울기 대상 지표 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &SlopeParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &indicator_analyzer(source, &params.indicator_type).items,
+        params.p..params.p + params.consecutive_n,
+        |data| [("value", data.value)],
+    )
+}
+
+/// 지표 타입별 값 시계열 (기울기 필터와 비교 필터가 같은 캐시 항목을 사용)
+pub(crate) fn indicator_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    indicator_type: &IndicatorType,
+) -> CachedRef<'a, SlopeAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(format!("{indicator_type:?}"), || {
+        SlopeAnalyzer::from_config(candle_store, indicator_type)
+    })
+}
+
+/// 통과한 기울기 필터의 강도 (p번째 기울기 강도 또는 R²가 임계값을 넘어선 정도)
+///
+/// 기울기 강도는 임계값, R²는 임계값부터 1까지의 폭으로 정규화합니다.
+/// 횡보와 가속/감속 타입은 None을 반환합니다.
+pub(crate) fn score_slope<C: Candle + 'static>(
+    params: &SlopeParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let analyzer = indicator_analyzer(source, &params.indicator_type);
+
+    match params.filter_type {
+        SlopeFilterType::HighRSquared => {
+            let threshold = params.r_squared_threshold.unwrap_or(0.7);
+            let analysis = analyzer.calculate_slope(params.period, params.p)?;
+            excess_ratio(analysis.r_squared - threshold, 1.0 - threshold)
+        }
+        SlopeFilterType::Upward
+        | SlopeFilterType::Downward
+        | SlopeFilterType::StrongUpward
+        | SlopeFilterType::StrongDownward
+        | SlopeFilterType::StrengthAboveThreshold => {
+            let default_threshold = match params.filter_type {
+                SlopeFilterType::StrengthAboveThreshold => 0.01,
+                _ => 0.02,
+            };
+            let threshold = params.strength_threshold.unwrap_or(default_threshold);
+            let analysis = if params.use_linear_regression.unwrap_or(false) {
+                analyzer.calculate_slope(params.period, params.p)
+            } else {
+                analyzer.calculate_simple_slope(params.period, params.p)
+            }?;
+            excess_ratio(analysis.strength - threshold, threshold)
+        }
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 기울기 필터 적용
-pub(crate) fn filter_slope<C: Candle + 'static + Clone>(
+pub(crate) fn filter_slope<'a, C: Candle + 'static + Clone>(
     coin: &str,
     params: &SlopeParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     _current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     log::debug!(
         "기울기 필터 적용 - 지표 타입: {:?}, 분석 기간: {}, 필터 타입: {:?}, 연속성: {}",
         params.indicator_type,
@@ -23,13 +93,13 @@ pub(crate) fn filter_slope<C: Candle + 'static + Clone>(
     utils::validate_period(params.period, "Slope")?;
 
     // 경계 조건 체크
-    let required_length = params.period + params.consecutive_n;
+    let required_length = required_candles(params);
     if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
         return Ok(false);
     }
 
     // SlopeAnalyzer 생성
-    let analyzer = SlopeAnalyzer::from_config(candle_store, &params.indicator_type);
+    let analyzer = indicator_analyzer(source, &params.indicator_type);
 
     if analyzer.items.is_empty() {
         return Ok(false);
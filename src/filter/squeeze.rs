@@ -0,0 +1,181 @@
+use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{SqueezeFilterType, SqueezeParams, utils};
+use crate::analyzer::squeeze_analyzer::SqueezeAnalyzer;
+use crate::indicator::squeeze::SqueezeParams as IndicatorSqueezeParams;
+use trading_chart::Candle;
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &SqueezeParams) -> usize {
+    params
+        .bb_period
+        .max(params.kc_period)
+        .max(params.kc_atr_period + 1)
+        + params.consecutive_n
+}
+
+/// 확인한 오프셋별 스퀴즈 상태와 밴드 폭 비율
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &SqueezeParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &squeeze_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            [
+                ("squeeze_on", if data.squeeze.is_on() { 1.0 } else { 0.0 }),
+                ("width_ratio", data.squeeze.width_ratio()),
+            ]
+        },
+    )
+}
+
+fn indicator_params(params: &SqueezeParams) -> IndicatorSqueezeParams {
+    IndicatorSqueezeParams {
+        bb_period: params.bb_period,
+        bb_multiplier: params.bb_multiplier,
+        kc_period: params.kc_period,
+        kc_atr_period: params.kc_atr_period,
+        kc_multiplier: params.kc_multiplier,
+    }
+}
+
+fn squeeze_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &SqueezeParams,
+) -> CachedRef<'a, SqueezeAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    let squeeze_params = indicator_params(params);
+    source.analyzer(
+        format!(
+            "{}:{}:{}:{}:{}",
+            params.bb_period,
+            params.bb_multiplier,
+            params.kc_period,
+            params.kc_atr_period,
+            params.kc_multiplier
+        ),
+        || SqueezeAnalyzer::new(squeeze_params, candle_store),
+    )
+}
+
+/// 통과한 스퀴즈 필터의 강도 (p번째 볼린저 밴드/켈트너 채널 폭 비율이 1에서 벗어난 정도)
+///
+/// 스퀴즈 상태는 폭 비율이 작을수록, 해제 상태는 클수록(2배에서 1) 강합니다.
+/// 해제 시점(발사) 타입은 None을 반환합니다.
+pub(crate) fn score_squeeze<C: Candle + 'static>(
+    params: &SqueezeParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let width_ratio = squeeze_analyzer(source, params)
+        .items
+        .get(params.p)?
+        .squeeze
+        .width_ratio();
+
+    match params.filter_type {
+        SqueezeFilterType::SqueezeOn => excess_ratio(1.0 - width_ratio, 1.0),
+        SqueezeFilterType::SqueezeOff => excess_ratio(width_ratio - 1.0, 1.0),
+        _ => None,
+    }
+}
+
+/// 개별 코인에 대한 Squeeze 필터 적용
+pub(crate) fn filter_squeeze<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &SqueezeParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "Squeeze 필터 적용 - BB: {}/{}, KC: {}/{}/{}, 타입: {:?}, 연속성: {}",
+        params.bb_period,
+        params.bb_multiplier,
+        params.kc_period,
+        params.kc_atr_period,
+        params.kc_multiplier,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    utils::validate_period(params.bb_period, "Squeeze bb_period")?;
+    utils::validate_positive_number(params.bb_multiplier, "Squeeze bb_multiplier")?;
+    utils::validate_period(params.kc_period, "Squeeze kc_period")?;
+    utils::validate_period(params.kc_atr_period, "Squeeze kc_atr_period")?;
+    utils::validate_positive_number(params.kc_multiplier, "Squeeze kc_multiplier")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let analyzer = squeeze_analyzer(source, params);
+    let (n, p) = (params.consecutive_n, params.p);
+
+    let result = match params.filter_type {
+        SqueezeFilterType::SqueezeOn => analyzer.is_squeeze_on(n, p),
+        SqueezeFilterType::SqueezeOff => analyzer.is_squeeze_off(n, p),
+        SqueezeFilterType::SqueezeFired => analyzer.is_squeeze_fired(n, 1, p),
+        SqueezeFilterType::SqueezeFiredUp => analyzer.is_squeeze_fired_up(n, 1, p),
+        SqueezeFilterType::SqueezeFiredDown => analyzer.is_squeeze_fired_down(n, 1, p),
+    };
+
+    log::debug!("코인 {coin} Squeeze 필터 결과: {result}");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_candles;
+
+    fn params(filter_type: SqueezeFilterType) -> SqueezeParams {
+        SqueezeParams {
+            bb_period: 5,
+            kc_period: 3,
+            kc_atr_period: 2,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_squeeze_fired_up() {
+        // 횡보 구간: BB 폭 0, KC = 100 ± 1.5 * 2 → 스퀴즈 상태
+        // 마지막 캔들: BB = 103 ± 2 * 6 = [91, 115], KC = 107.5 ± 1.5 * 9 = [94, 121] → 해제
+        let candles = test_candles(&[100.0, 100.0, 100.0, 100.0, 100.0, 100.0, 115.0]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        let fired_up = params(SqueezeFilterType::SqueezeFiredUp);
+        assert!(filter_squeeze("TEST", &fired_up, &candle_store, 115.0).unwrap());
+        let fired_down = params(SqueezeFilterType::SqueezeFiredDown);
+        assert!(!filter_squeeze("TEST", &fired_down, &candle_store, 115.0).unwrap());
+        let off = params(SqueezeFilterType::SqueezeOff);
+        assert!(filter_squeeze("TEST", &off, &candle_store, 115.0).unwrap());
+
+        let previous_on = SqueezeParams {
+            p: 1,
+            ..params(SqueezeFilterType::SqueezeOn)
+        };
+        assert!(filter_squeeze("TEST", &previous_on, &candle_store, 115.0).unwrap());
+    }
+
+    #[test]
+    fn test_squeeze_on_without_fire() {
+        let candles = test_candles(&[100.0; 7]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        let on = params(SqueezeFilterType::SqueezeOn);
+        assert!(filter_squeeze("TEST", &on, &candle_store, 100.0).unwrap());
+        let fired = params(SqueezeFilterType::SqueezeFired);
+        assert!(!filter_squeeze("TEST", &fired, &candle_store, 100.0).unwrap());
+    }
+}
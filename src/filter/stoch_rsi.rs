@@ -0,0 +1,202 @@
+use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{StochRSIFilterType, StochRSIParams, utils};
+use crate::analyzer::stoch_rsi_analyzer::StochRSIAnalyzer;
+use crate::indicator::stoch_rsi::StochRSIParams as IndicatorStochRSIParams;
+use trading_chart::Candle;
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &StochRSIParams) -> usize {
+    params.rsi_period
+        + params.stoch_period
+        + params.k_period
+        + params.d_period
+        + params.consecutive_n
+}
+
+/// 확인한 오프셋별 StochRSI %K/%D 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &StochRSIParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    let stoch_rsi_params = indicator_params(params);
+
+    offset_values(
+        &stoch_rsi_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let stoch_rsi = data.get_stoch_rsi(&stoch_rsi_params);
+            [("k", stoch_rsi.k), ("d", stoch_rsi.d)]
+        },
+    )
+}
+
+fn indicator_params(params: &StochRSIParams) -> IndicatorStochRSIParams {
+    IndicatorStochRSIParams::new(
+        params.rsi_period,
+        params.stoch_period,
+        params.k_period,
+        params.d_period,
+    )
+}
+
+fn stoch_rsi_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &StochRSIParams,
+) -> CachedRef<'a, StochRSIAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    let stoch_rsi_params = indicator_params(params);
+    source.analyzer(
+        format!(
+            "{}:{}:{}:{}",
+            params.rsi_period, params.stoch_period, params.k_period, params.d_period
+        ),
+        || StochRSIAnalyzer::new(&[stoch_rsi_params], candle_store),
+    )
+}
+
+/// 통과한 StochRSI 필터의 강도 (p번째 %K가 기준선을 넘어선 정도)
+///
+/// 과매수/과매도는 기준선부터 극값까지, 정상 범위는 가까운 기준선까지의 거리를
+/// 범위 절반으로 정규화합니다. %K/%D 비교와 교차 타입은 None을 반환합니다.
+pub(crate) fn score_stoch_rsi<C: Candle + 'static>(
+    params: &StochRSIParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let stoch_rsi_params = indicator_params(params);
+    let k = stoch_rsi_analyzer(source, params)
+        .items
+        .get(params.p)?
+        .get_stoch_rsi(&stoch_rsi_params)
+        .k;
+
+    match params.filter_type {
+        StochRSIFilterType::Overbought => {
+            excess_ratio(k - params.overbought, 100.0 - params.overbought)
+        }
+        StochRSIFilterType::Oversold => excess_ratio(params.oversold - k, params.oversold),
+        StochRSIFilterType::NormalRange => excess_ratio(
+            (k - params.oversold).min(params.overbought - k),
+            (params.overbought - params.oversold) / 2.0,
+        ),
+        _ => None,
+    }
+}
+
+/// 개별 코인에 대한 StochRSI 필터 적용
+pub(crate) fn filter_stoch_rsi<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &StochRSIParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "StochRSI 필터 적용 - RSI: {}, 구간: {}, %K: {}, %D: {}, 과매수: {:.2}, 과매도: {:.2}, 타입: {:?}, 연속성: {}",
+        params.rsi_period,
+        params.stoch_period,
+        params.k_period,
+        params.d_period,
+        params.overbought,
+        params.oversold,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    utils::validate_period(params.rsi_period, "StochRSI rsi_period")?;
+    utils::validate_period(params.stoch_period, "StochRSI stoch_period")?;
+    utils::validate_period(params.k_period, "StochRSI k_period")?;
+    utils::validate_period(params.d_period, "StochRSI d_period")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let stoch_rsi_params = indicator_params(params);
+    let analyzer = stoch_rsi_analyzer(source, params);
+    let (n, p) = (params.consecutive_n, params.p);
+
+    let result = match params.filter_type {
+        StochRSIFilterType::Overbought => {
+            analyzer.is_k_greater_than(&stoch_rsi_params, params.overbought, n, p)
+        }
+        StochRSIFilterType::Oversold => {
+            analyzer.is_k_less_than(&stoch_rsi_params, params.oversold, n, p)
+        }
+        StochRSIFilterType::NormalRange => {
+            analyzer.is_k_in_range(&stoch_rsi_params, params.oversold, params.overbought, n, p)
+        }
+        StochRSIFilterType::KAboveD => analyzer.is_k_above_d(&stoch_rsi_params, n, p),
+        StochRSIFilterType::KBelowD => analyzer.is_k_below_d(&stoch_rsi_params, n, p),
+        StochRSIFilterType::KCrossAboveD => analyzer.is_k_cross_above_d(&stoch_rsi_params, n, 1, p),
+        StochRSIFilterType::KCrossBelowD => analyzer.is_k_cross_below_d(&stoch_rsi_params, n, 1, p),
+        StochRSIFilterType::CrossAboveOversold => {
+            analyzer.is_k_cross_above(&stoch_rsi_params, params.oversold, n, 1, p)
+        }
+        StochRSIFilterType::CrossBelowOverbought => {
+            analyzer.is_k_cross_below(&stoch_rsi_params, params.overbought, n, 1, p)
+        }
+    };
+
+    log::debug!("코인 {coin} StochRSI 필터 결과: {result}");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_candles;
+
+    fn params(filter_type: StochRSIFilterType, d_period: usize) -> StochRSIParams {
+        StochRSIParams {
+            rsi_period: 3,
+            stoch_period: 5,
+            k_period: 1,
+            d_period,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_stoch_rsi_overbought_after_rebound() {
+        // 하락 후 반등: RSI가 최근 구간 최고치에 위치
+        let mut closes: Vec<f64> = (0..10).map(|i| 120.0 - i as f64 * 2.0).collect();
+        closes.extend((1..=4).map(|i| 102.0 + i as f64 * 5.0));
+        let candle_store = utils::create_candle_store(&test_candles(&closes));
+
+        let overbought = params(StochRSIFilterType::Overbought, 1);
+        assert!(filter_stoch_rsi("TEST", &overbought, &candle_store, 122.0).unwrap());
+        let oversold = params(StochRSIFilterType::Oversold, 1);
+        assert!(!filter_stoch_rsi("TEST", &oversold, &candle_store, 122.0).unwrap());
+    }
+
+    #[test]
+    fn test_stoch_rsi_k_cross_above_d() {
+        // 계속 하락하던 RSI가 첫 반등 캔들에서 구간 최고치가 되어 %K가 %D를 상향 돌파
+        let mut closes: Vec<f64> = (0..11).map(|i| 122.0 - i as f64 * 2.0).collect();
+        closes.push(107.0);
+        let candle_store = utils::create_candle_store(&test_candles(&closes));
+
+        let cross = params(StochRSIFilterType::KCrossAboveD, 2);
+        assert!(filter_stoch_rsi("TEST", &cross, &candle_store, 107.0).unwrap());
+        let cross_below = params(StochRSIFilterType::KCrossBelowD, 2);
+        assert!(!filter_stoch_rsi("TEST", &cross_below, &candle_store, 107.0).unwrap());
+    }
+
+    #[test]
+    fn test_stoch_rsi_insufficient_candles() {
+        let candles = test_candles(&[100.0, 101.0, 102.0, 103.0, 104.0]);
+        let candle_store = utils::create_candle_store(&candles);
+        let params = params(StochRSIFilterType::Overbought, 1);
+
+        assert!(!filter_stoch_rsi("TEST", &params, &candle_store, 104.0).unwrap());
+    }
+}
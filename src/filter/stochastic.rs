@@ -0,0 +1,188 @@
+use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{StochasticFilterType, StochasticParams, utils};
+use crate::analyzer::stochastic_analyzer::StochasticAnalyzer;
+use crate::indicator::stochastic::StochasticParams as IndicatorStochasticParams;
+use trading_chart::Candle;
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &StochasticParams) -> usize {
+    params.k_period + params.d_period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 %K/%D 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &StochasticParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    let stochastic_params = indicator_params(params);
+
+    offset_values(
+        &stochastic_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let stochastic = data.get_stochastic(&stochastic_params);
+            [("k", stochastic.k), ("d", stochastic.d)]
+        },
+    )
+}
+
+fn indicator_params(params: &StochasticParams) -> IndicatorStochasticParams {
+    IndicatorStochasticParams::new(params.k_period, params.d_period)
+}
+
+fn stochastic_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &StochasticParams,
+) -> CachedRef<'a, StochasticAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    let stochastic_params = indicator_params(params);
+    source.analyzer(format!("{}:{}", params.k_period, params.d_period), || {
+        StochasticAnalyzer::new(&[stochastic_params], candle_store)
+    })
+}
+
+/// 통과한 Stochastic 필터의 강도 (p번째 %K가 기준선을 넘어선 정도)
+///
+/// 과매수/과매도는 기준선부터 극값까지, 정상 범위는 가까운 기준선까지의 거리를
+/// 범위 절반으로 정규화합니다. %K/%D 비교와 교차 타입은 None을 반환합니다.
+pub(crate) fn score_stochastic<C: Candle + 'static>(
+    params: &StochasticParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let stochastic_params = indicator_params(params);
+    let k = stochastic_analyzer(source, params)
+        .items
+        .get(params.p)?
+        .get_stochastic(&stochastic_params)
+        .k;
+
+    match params.filter_type {
+        StochasticFilterType::Overbought => {
+            excess_ratio(k - params.overbought, 100.0 - params.overbought)
+        }
+        StochasticFilterType::Oversold => excess_ratio(params.oversold - k, params.oversold),
+        StochasticFilterType::NormalRange => excess_ratio(
+            (k - params.oversold).min(params.overbought - k),
+            (params.overbought - params.oversold) / 2.0,
+        ),
+        _ => None,
+    }
+}
+
+/// 개별 코인에 대한 Stochastic 필터 적용
+pub(crate) fn filter_stochastic<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &StochasticParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "Stochastic 필터 적용 - %K: {}, %D: {}, 과매수: {:.2}, 과매도: {:.2}, 타입: {:?}, 연속성: {}",
+        params.k_period,
+        params.d_period,
+        params.overbought,
+        params.oversold,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    utils::validate_period(params.k_period, "Stochastic k_period")?;
+    utils::validate_period(params.d_period, "Stochastic d_period")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let stochastic_params = indicator_params(params);
+    let analyzer = stochastic_analyzer(source, params);
+    let (n, p) = (params.consecutive_n, params.p);
+
+    let result = match params.filter_type {
+        StochasticFilterType::Overbought => {
+            analyzer.is_k_greater_than(&stochastic_params, params.overbought, n, p)
+        }
+        StochasticFilterType::Oversold => {
+            analyzer.is_k_less_than(&stochastic_params, params.oversold, n, p)
+        }
+        StochasticFilterType::NormalRange => {
+            analyzer.is_k_in_range(&stochastic_params, params.oversold, params.overbought, n, p)
+        }
+        StochasticFilterType::KAboveD => analyzer.is_k_above_d(&stochastic_params, n, p),
+        StochasticFilterType::KBelowD => analyzer.is_k_below_d(&stochastic_params, n, p),
+        StochasticFilterType::KCrossAboveD => {
+            analyzer.is_k_cross_above_d(&stochastic_params, n, 1, p)
+        }
+        StochasticFilterType::KCrossBelowD => {
+            analyzer.is_k_cross_below_d(&stochastic_params, n, 1, p)
+        }
+        StochasticFilterType::CrossAboveOversold => {
+            analyzer.is_k_cross_above(&stochastic_params, params.oversold, n, 1, p)
+        }
+        StochasticFilterType::CrossBelowOverbought => {
+            analyzer.is_k_cross_below(&stochastic_params, params.overbought, n, 1, p)
+        }
+    };
+
+    log::debug!("코인 {coin} Stochastic 필터 결과: {result}");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_candles;
+
+    fn params(filter_type: StochasticFilterType) -> StochasticParams {
+        StochasticParams {
+            k_period: 3,
+            d_period: 2,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_stochastic_overbought_and_oversold() {
+        let rising = test_candles(&[100.0, 102.0, 104.0, 106.0, 108.0, 110.0]);
+        let candle_store = utils::create_candle_store(&rising);
+
+        let overbought = params(StochasticFilterType::Overbought);
+        assert!(filter_stochastic("TEST", &overbought, &candle_store, 110.0).unwrap());
+        let oversold = params(StochasticFilterType::Oversold);
+        assert!(!filter_stochastic("TEST", &oversold, &candle_store, 110.0).unwrap());
+
+        let falling = test_candles(&[110.0, 108.0, 106.0, 104.0, 102.0, 100.0]);
+        let candle_store = utils::create_candle_store(&falling);
+        assert!(filter_stochastic("TEST", &oversold, &candle_store, 100.0).unwrap());
+    }
+
+    #[test]
+    fn test_stochastic_k_cross_above_d() {
+        // 하락 후 반등한 마지막 캔들에서 %K가 %D를 상향 돌파
+        let candles = test_candles(&[110.0, 108.0, 106.0, 104.0, 102.0, 100.0, 105.0]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        let cross = params(StochasticFilterType::KCrossAboveD);
+        assert!(filter_stochastic("TEST", &cross, &candle_store, 105.0).unwrap());
+        let cross_below = params(StochasticFilterType::KCrossBelowD);
+        assert!(!filter_stochastic("TEST", &cross_below, &candle_store, 105.0).unwrap());
+    }
+
+    #[test]
+    fn test_stochastic_insufficient_candles() {
+        let candles = test_candles(&[100.0, 102.0]);
+        let candle_store = utils::create_candle_store(&candles);
+        let params = params(StochasticFilterType::Overbought);
+
+        assert!(!filter_stochastic("TEST", &params, &candle_store, 102.0).unwrap());
+    }
+}
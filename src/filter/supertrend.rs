@@ -1,18 +1,84 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{SuperTrendFilterType, SuperTrendParams, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::supertrend_analyzer::SuperTrendAnalyzer;
-use crate::candle_store::CandleStore;
 use trading_chart::Candle;
 
 /// SuperTrend 필터 함수
-pub(crate) fn filter_supertrend<C: Candle + 'static>(
+pub(crate) fn filter_supertrend<'a, C: Candle + 'static>(
     symbol: &str,
     params: &SuperTrendParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
-    SuperTrendFilter::matches_filter(symbol, candle_store, params, current_price)
+    SuperTrendFilter::matches_filter(symbol, source, params, current_price)
+}
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &SuperTrendParams) -> usize {
+    params.period.max(params.consecutive_n)
+}
+
+/// 확인한 오프셋별 SuperTrend 값과 방향 (1: 상승, -1: 하락)
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &SuperTrendParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &supertrend_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let supertrend = data.get_supertrend(&params.period, &params.multiplier);
+            [
+                ("supertrend", supertrend.value),
+                ("direction", f64::from(supertrend.direction)),
+                ("upper_band", supertrend.upper_band),
+                ("lower_band", supertrend.lower_band),
+            ]
+        },
+    )
+}
+
+fn supertrend_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &SuperTrendParams,
+) -> CachedRef<'a, SuperTrendAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(format!("{}:{}", params.period, params.multiplier), || {
+        SuperTrendAnalyzer::new(&[(params.period, params.multiplier)], candle_store)
+    })
+}
+
+/// 통과한 SuperTrend 필터의 강도 (현재가가 p번째 SuperTrend 값에서 벗어난 정도)
+///
+/// 상단/하단 밴드 사이 폭으로 정규화합니다.
+/// 교차와 추세 전환 타입은 None을 반환합니다.
+pub(crate) fn score_supertrend<C: Candle + 'static>(
+    params: &SuperTrendParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Option<f64> {
+    let analyzer = supertrend_analyzer(source, params);
+    let supertrend = analyzer
+        .items
+        .get(params.p)?
+        .get_supertrend(&params.period, &params.multiplier);
+    let range = supertrend.upper_band - supertrend.lower_band;
+
+    match params.filter_type {
+        SuperTrendFilterType::PriceAboveSupertrend
+        | SuperTrendFilterType::Uptrend
+        | SuperTrendFilterType::AllUptrend => excess_ratio(current_price - supertrend.value, range),
+        SuperTrendFilterType::PriceBelowSupertrend
+        | SuperTrendFilterType::Downtrend
+        | SuperTrendFilterType::AllDowntrend => {
+            excess_ratio(supertrend.value - current_price, range)
+        }
+        _ => None,
+    }
 }
 
 /// SuperTrend 필터 구조체
@@ -20,12 +86,15 @@ pub struct SuperTrendFilter;
 
 impl SuperTrendFilter {
     /// SuperTrend 필터 확인 (내부 헬퍼 함수, CandleStore 재사용)
-    pub(crate) fn matches_filter<C: Candle + 'static>(
+    pub(crate) fn matches_filter<'a, C: Candle + 'static>(
         _symbol: &str,
-        candle_store: &CandleStore<C>,
+        source: impl Into<FilterSource<'a, C>>,
         params: &SuperTrendParams,
         current_price: f64,
     ) -> Result<bool> {
+        let source = source.into();
+        let candle_store = source.candle_store();
+
         let period = params.period;
         let multiplier = params.multiplier;
         let filter_type = params.filter_type;
@@ -36,12 +105,12 @@ impl SuperTrendFilter {
         utils::validate_period(period, "SuperTrend")?;
 
         // 경계 조건 체크
-        let required_length = period.max(consecutive_n);
+        let required_length = required_candles(params);
         if !utils::check_sufficient_candles(candle_store.len(), required_length, _symbol) {
             return Ok(false);
         }
         // analyzer는 이미 init_from_storage로 초기화되었으므로 추가 처리 불필요
-        let analyzer = SuperTrendAnalyzer::new(&[(period, multiplier)], candle_store);
+        let analyzer = supertrend_analyzer(source, params);
 
         // analyzer 메서드들이 이미 consecutive_n을 처리하거나, 직접 호출
         let result = match filter_type {
@@ -1,3 +1,4 @@
+use super::report::{OffsetValues, offset_values};
 use super::{FilterError, Result, SupportResistanceFilterType, SupportResistanceParams, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::support_resistance_analyzer::SupportResistanceAnalyzer;
@@ -14,6 +15,50 @@ pub(crate) fn filter_support_resistance<C: Candle + 'static>(
     SupportResistanceFilter::matches_filter(symbol, candle_store, params, current_price)
 }
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &SupportResistanceParams) -> usize {
+    params.lookback_period.max(params.consecutive_n)
+}
+
+/// 확인한 오프셋별 가장 가까운 지지/저항선과 현재가와의 거리
+///
+/// 거리는 지지선은 현재가에서 지지선을, 저항선은 저항선에서 현재가를 뺀 값입니다.
+/// 레벨이 없는 오프셋은 해당 값을 남기지 않습니다.
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &SupportResistanceParams,
+    candle_store: &CandleStore<C>,
+    current_price: f64,
+) -> Vec<OffsetValues> {
+    let analyzer = SupportResistanceAnalyzer::new(
+        candle_store,
+        params.lookback_period,
+        params.touch_threshold,
+        params.min_touch_count,
+    );
+
+    offset_values(
+        &analyzer.items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let support = data.nearest_support.as_ref().map(|support| {
+                [
+                    ("support", support.price),
+                    ("support_distance", current_price - support.price),
+                    ("support_touch_count", support.touch_count as f64),
+                ]
+            });
+            let resistance = data.nearest_resistance.as_ref().map(|resistance| {
+                [
+                    ("resistance", resistance.price),
+                    ("resistance_distance", resistance.price - current_price),
+                    ("resistance_touch_count", resistance.touch_count as f64),
+                ]
+            });
+            support.into_iter().chain(resistance).flatten()
+        },
+    )
+}
+
 /// SupportResistance 필터 구조체
 pub struct SupportResistanceFilter;
 
@@ -39,7 +84,7 @@ impl SupportResistanceFilter {
         }
 
         // 경계 조건 체크
-        let required_length = lookback_period.max(consecutive_n);
+        let required_length = required_candles(params);
         if !utils::check_sufficient_candles(candle_store.len(), required_length, _symbol) {
             return Ok(false);
         }
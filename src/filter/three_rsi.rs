@@ -1,20 +1,111 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{ThreeRSIFilterType, ThreeRSIParams, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::three_rsi_analyzer::ThreeRSIAnalyzer;
-use crate::candle_store::CandleStore;
 use crate::indicator::ma::MAType;
 use trading_chart::Candle;
 
 /// ThreeRSI 필터 함수
-pub(crate) fn filter_three_rsi<C: Candle + 'static>(
+pub(crate) fn filter_three_rsi<'a, C: Candle + 'static>(
     symbol: &str,
     params: &ThreeRSIParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
     ma_type: MAType,
 ) -> Result<bool> {
-    ThreeRSIFilter::matches_filter(symbol, candle_store, params, ma_type, current_price)
+    ThreeRSIFilter::matches_filter(symbol, source, params, ma_type, current_price)
+}
+
+/// 파라미터의 이동평균 타입 문자열 변환 (알 수 없는 이름은 SMA)
+pub(crate) fn ma_type(params: &ThreeRSIParams) -> MAType {
+    params.ma_type.parse().unwrap_or(MAType::SMA)
+}
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &ThreeRSIParams) -> usize {
+    params.ma_period.max(params.consecutive_n)
+}
+
+/// 확인한 오프셋별 RSI 값(`rsi_{기간}`)과 이동평균, ADX
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &ThreeRSIParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &three_rsi_analyzer(source, params, ma_type(params)).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            data.rsis
+                .get_all()
+                .iter()
+                .map(|rsi| (format!("rsi_{}", rsi.period()), rsi.value))
+                .chain([
+                    ("ma".to_string(), data.ma.get()),
+                    ("adx".to_string(), data.adx.adx),
+                ])
+                .collect::<Vec<_>>()
+        },
+    )
+}
+
+fn three_rsi_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &ThreeRSIParams,
+    ma_type: MAType,
+) -> CachedRef<'a, ThreeRSIAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(
+        format!(
+            "{:?}:{:?}:{}:{}",
+            params.rsi_periods, ma_type, params.ma_period, params.adx_period
+        ),
+        || {
+            ThreeRSIAnalyzer::new(
+                &params.rsi_periods,
+                &ma_type,
+                params.ma_period,
+                params.adx_period,
+                candle_store,
+            )
+        },
+    )
+}
+
+/// 통과한 ThreeRSI 필터의 강도 (p번째 RSI 중 기준선에 가장 가까운 값이 넘어선 정도)
+///
+/// 모든 RSI가 기준선을 넘어야 통과하므로 가장 약한 RSI로 강도를 계산합니다.
+/// 배열, 교차, 이동평균 비교 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_three_rsi<C: Candle + 'static>(
+    params: &ThreeRSIParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let analyzer = three_rsi_analyzer(source, params, ma_type(params));
+    let data = analyzer.items.get(params.p)?;
+    let rsis: Vec<f64> = data.rsis.get_all().iter().map(|rsi| rsi.value).collect();
+    let lowest = rsis.iter().copied().fold(f64::INFINITY, f64::min);
+    let highest = rsis.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    match params.filter_type {
+        ThreeRSIFilterType::AllRSIGreaterThan50 | ThreeRSIFilterType::RSIBullishRange => {
+            excess_ratio(lowest - 50.0, 50.0)
+        }
+        ThreeRSIFilterType::AllRSILessThan50 | ThreeRSIFilterType::RSIBearishRange => {
+            excess_ratio(50.0 - highest, 50.0)
+        }
+        ThreeRSIFilterType::AllRSIGreaterThan70 | ThreeRSIFilterType::RSIOverboughtRange => {
+            excess_ratio(lowest - 70.0, 30.0)
+        }
+        ThreeRSIFilterType::AllRSILessThan30 | ThreeRSIFilterType::RSIOversoldRange => {
+            excess_ratio(30.0 - highest, 30.0)
+        }
+        ThreeRSIFilterType::RSIExtremeOverbought => excess_ratio(lowest - 80.0, 20.0),
+        ThreeRSIFilterType::RSIExtremeOversold => excess_ratio(20.0 - highest, 20.0),
+        ThreeRSIFilterType::ADXGreaterThan20 => excess_ratio(data.adx.adx - 20.0, 20.0),
+        _ => None,
+    }
 }
 
 /// ThreeRSI 필터 구조체
@@ -22,13 +113,16 @@ pub struct ThreeRSIFilter;
 
 impl ThreeRSIFilter {
     /// ThreeRSI 필터 확인 (내부 헬퍼 함수, CandleStore 재사용)
-    pub(crate) fn matches_filter<C: Candle + 'static>(
+    pub(crate) fn matches_filter<'a, C: Candle + 'static>(
         _symbol: &str,
-        candle_store: &CandleStore<C>,
+        source: impl Into<FilterSource<'a, C>>,
         params: &ThreeRSIParams,
         ma_type: MAType,
         current_price: f64,
     ) -> Result<bool> {
+        let source = source.into();
+        let candle_store = source.candle_store();
+
         let rsi_periods = &params.rsi_periods;
         let ma_period = params.ma_period;
         let adx_period = params.adx_period;
@@ -44,13 +138,12 @@ impl ThreeRSIFilter {
         utils::validate_percentage_threshold(params.cross_threshold, "ThreeRSI cross_threshold")?;
 
         // 경계 조건 체크
-        let required_length = ma_period.max(consecutive_n);
+        let required_length = required_candles(params);
         if !utils::check_sufficient_candles(candle_store.len(), required_length, _symbol) {
             return Ok(false);
         }
         // analyzer는 이미 init_from_storage로 초기화되었으므로 추가 처리 불필요
-        let analyzer =
-            ThreeRSIAnalyzer::new(rsi_periods, &ma_type, ma_period, adx_period, candle_store);
+        let analyzer = three_rsi_analyzer(source, params, ma_type);
 
         // analyzer 메서드들이 이미 consecutive_n을 처리하므로 직접 호출
         let result = match filter_type {
@@ -0,0 +1,466 @@
+// 멀티 타임프레임 필터
+// 필터 설정에 `interval`을 지정하면 캔들 저장소에서 해당 간격의 캔들만 골라 평가합니다.
+// 캔들을 리샘플링하지는 않으므로, 필요한 간격의 캔들을 모두 저장소에 넣어야 합니다.
+// `interval`을 지정하지 않은 필터는 기준 간격(기본값: 첫 캔들의 간격)의 캔들로 평가합니다.
+// (예: 1시간봉과 일봉을 함께 넣고 "1h RSI 과매도 AND 1d 가격이 이치모쿠 구름 위")
+
+use super::cache::{FilterSource, IndicatorCache};
+use super::{FilterError, Result, TechnicalFilterConfig};
+use crate::candle_store::CandleStore;
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+use std::str::FromStr;
+use trading_chart::{Candle, CandleInterval};
+
+/// 캔들 간격을 지정한 필터 설정
+///
+/// 설정 파일에서는 필터 항목에 `interval` 키를 추가해 지정합니다.
+#[derive(Debug, Clone)]
+pub struct TimeframeFilterConfig {
+    /// 캔들 간격 (`CandleInterval` 문자열, 예: `1h`, `1d`)
+    pub interval: String,
+    /// 해당 간격의 캔들로 평가할 필터
+    pub filter: Box<TechnicalFilterConfig>,
+}
+
+impl TimeframeFilterConfig {
+    pub fn new(interval: impl Into<String>, filter: TechnicalFilterConfig) -> Self {
+        Self {
+            interval: interval.into(),
+            filter: Box::new(filter),
+        }
+    }
+
+    /// 캔들 간격 파싱
+    pub fn candle_interval(&self) -> Result<CandleInterval> {
+        CandleInterval::from_str(&self.interval).map_err(|_| FilterError::UnknownCandleInterval {
+            input: self.interval.clone(),
+        })
+    }
+}
+
+/// 필터 설정에 `interval` 키를 더한 형태로 직렬화 (설정 파일과 같은 형태)
+impl Serialize for TimeframeFilterConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut value = serde_json::to_value(&*self.filter).map_err(S::Error::custom)?;
+        let Some(params) = value.as_object_mut() else {
+            return Err(S::Error::custom("필터 설정은 객체로 직렬화되어야 합니다"));
+        };
+        params.insert(
+            "interval".to_string(),
+            serde_json::Value::String(self.interval.clone()),
+        );
+        value.serialize(serializer)
+    }
+}
+
+struct TimeframeStore<C: Candle + 'static> {
+    candle_store: CandleStore<C>,
+    cache: IndicatorCache<C>,
+}
+
+/// 캔들 간격별 저장소와 지표 캐시
+///
+/// 캔들 저장소는 시각만으로 정렬·중복 제거하므로, 간격이 다른 캔들은 처음부터 간격별로 나눠 보관합니다.
+/// `interval`을 지정한 필터는 해당 간격의 저장소로, 지정하지 않은 필터는 기준 간격의 저장소로 평가합니다.
+pub(crate) struct TimeframeStores<C: Candle + 'static> {
+    entries: HashMap<CandleInterval, TimeframeStore<C>>,
+    base_interval: Option<CandleInterval>,
+    empty: CandleStore<C>,
+    max_size: usize,
+    use_duplicated_filter: bool,
+}
+
+impl<C: Candle + 'static> TimeframeStores<C> {
+    /// 캔들을 간격별로 나눈 뒤 저장소 생성 (중복 제거도 간격별로 적용)
+    ///
+    /// 기준 간격은 첫 캔들의 간격입니다.
+    pub(crate) fn new(candles: Vec<C>, max_size: usize, use_duplicated_filter: bool) -> Self {
+        let mut stores = Self {
+            entries: HashMap::new(),
+            base_interval: candles.first().map(|candle| *candle.interval()),
+            empty: CandleStore::new(vec![], max_size, use_duplicated_filter),
+            max_size,
+            use_duplicated_filter,
+        };
+
+        let mut grouped: HashMap<CandleInterval, Vec<C>> = HashMap::new();
+        for candle in candles {
+            grouped.entry(*candle.interval()).or_default().push(candle);
+        }
+        for (interval, candles) in grouped {
+            stores.entries.insert(
+                interval,
+                TimeframeStore {
+                    candle_store: CandleStore::new(candles, max_size, use_duplicated_filter),
+                    cache: IndicatorCache::new(),
+                },
+            );
+        }
+        stores
+    }
+
+    fn entry(&mut self, interval: CandleInterval) -> &mut TimeframeStore<C> {
+        let (max_size, use_duplicated_filter) = (self.max_size, self.use_duplicated_filter);
+        self.entries
+            .entry(interval)
+            .or_insert_with(|| TimeframeStore {
+                candle_store: CandleStore::new(vec![], max_size, use_duplicated_filter),
+                cache: IndicatorCache::new(),
+            })
+    }
+
+    /// `interval`을 지정하지 않은 필터를 평가할 간격 변경
+    pub(crate) fn set_base_interval(&mut self, interval: CandleInterval) {
+        self.entry(interval);
+        self.base_interval = Some(interval);
+    }
+
+    pub(crate) fn base_interval(&self) -> Option<&CandleInterval> {
+        self.base_interval.as_ref()
+    }
+
+    /// 캔들 간격에 해당하는 저장소에 캔들 반영
+    ///
+    /// 다른 간격의 저장소와 캐시는 건드리지 않습니다. 기준 간격이 없으면 이 캔들의 간격을 사용합니다.
+    pub(crate) fn update(&mut self, candle: C) {
+        let interval = *candle.interval();
+        self.base_interval.get_or_insert(interval);
+        let store = self.entry(interval);
+        store.cache.upsert(&mut store.candle_store, candle);
+    }
+
+    /// 간격별 캐시에 보관 중인 항목 수의 합
+    pub(crate) fn cached_indicators(&self) -> usize {
+        self.entries.values().map(|store| store.cache.len()).sum()
+    }
+
+    /// 캔들 간격의 저장소
+    pub(crate) fn candle_store(&self, interval: &CandleInterval) -> Option<&CandleStore<C>> {
+        self.entries.get(interval).map(|store| &store.candle_store)
+    }
+
+    /// 기준 간격의 저장소 (캔들이 없으면 빈 저장소)
+    pub(crate) fn base_candle_store(&self) -> &CandleStore<C> {
+        self.base_interval
+            .and_then(|interval| self.candle_store(&interval))
+            .unwrap_or(&self.empty)
+    }
+
+    pub(crate) fn source(&self, interval: &CandleInterval) -> Option<FilterSource<'_, C>> {
+        self.entries
+            .get(interval)
+            .map(|store| FilterSource::cached(&store.candle_store, &store.cache))
+    }
+
+    /// 기준 간격의 저장소와 캐시에 간격별 저장소를 연결한 필터 입력
+    pub(crate) fn base_source(&self) -> FilterSource<'_, C> {
+        self.base_interval
+            .and_then(|interval| self.source(&interval))
+            .unwrap_or_else(|| (&self.empty).into())
+            .with_timeframes(self)
+    }
+}
+
+/// 지정한 간격의 캔들로 필터 평가
+///
+/// 간격별 저장소가 없으면(컨텍스트 없이 평가) 해당 간격의 캔들만 골라 임시 저장소를 만듭니다.
+pub(crate) fn with_source<C: Candle + 'static, R>(
+    config: &TimeframeFilterConfig,
+    source: FilterSource<'_, C>,
+    evaluate: impl FnOnce(&TechnicalFilterConfig, FilterSource<'_, C>) -> R,
+) -> Result<R> {
+    let interval = config.candle_interval()?;
+    if let Some(timeframe_source) = source.timeframe(&interval) {
+        return Ok(evaluate(&config.filter, timeframe_source));
+    }
+
+    let base_store = source.candle_store();
+    let candles: Vec<C> = base_store
+        .items()
+        .iter()
+        .filter(|candle| *candle.interval() == interval)
+        .cloned()
+        .collect();
+    let candle_store = CandleStore::new(
+        candles,
+        base_store.max_size,
+        base_store.use_duplicated_filter,
+    );
+    Ok(evaluate(&config.filter, (&candle_store).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{
+        RSIFilterType, RSIParams, TechnicalFilter, TechnicalFilterContext, TechnicalFilterType,
+    };
+    use chrono::{DateTime, Utc};
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct IntervalCandle {
+        timestamp: i64,
+        interval: CandleInterval,
+        close: f64,
+    }
+
+    impl Default for IntervalCandle {
+        fn default() -> Self {
+            Self {
+                timestamp: 0,
+                interval: CandleInterval::Minute1,
+                close: 0.0,
+            }
+        }
+    }
+
+    impl std::fmt::Display for IntervalCandle {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "IntervalCandle(t={}, i={:?}, c={:.2})",
+                self.timestamp, self.interval, self.close
+            )
+        }
+    }
+
+    impl Candle for IntervalCandle {
+        fn open_price(&self) -> f64 {
+            self.close
+        }
+        fn high_price(&self) -> f64 {
+            self.close + 1.0
+        }
+        fn low_price(&self) -> f64 {
+            self.close - 1.0
+        }
+        fn close_price(&self) -> f64 {
+            self.close
+        }
+        fn market(&self) -> &str {
+            "test"
+        }
+        fn datetime(&self) -> DateTime<Utc> {
+            DateTime::from_timestamp(self.timestamp, 0).unwrap()
+        }
+        fn interval(&self) -> &CandleInterval {
+            &self.interval
+        }
+        fn volume(&self) -> f64 {
+            1_000.0
+        }
+        fn quote_volume(&self) -> f64 {
+            1_000.0
+        }
+        fn trade_count(&self) -> Option<u64> {
+            None
+        }
+    }
+
+    fn interval(input: &str) -> CandleInterval {
+        CandleInterval::from_str(input).unwrap()
+    }
+
+    // 1분봉은 하락, 1시간봉은 상승하도록 구성
+    fn mixed_candles() -> Vec<IntervalCandle> {
+        let minutes = (0..30).map(|i| IntervalCandle {
+            timestamp: i * 60,
+            interval: interval("1m"),
+            close: 200.0 - i as f64 * 2.0,
+        });
+        let hours = (0..30).map(|i| IntervalCandle {
+            timestamp: i * 3_600,
+            interval: interval("1h"),
+            close: 100.0 + i as f64 * 2.0,
+        });
+        minutes.chain(hours).collect()
+    }
+
+    fn rsi_filter(filter_type: RSIFilterType) -> TechnicalFilterConfig {
+        TechnicalFilterConfig::RSI(RSIParams {
+            filter_type,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_timeframe_filter_loads_from_toml_and_round_trips() {
+        #[derive(Deserialize)]
+        struct FiltersConfig {
+            filters: Vec<TechnicalFilterConfig>,
+        }
+
+        let config: FiltersConfig = toml::from_str(
+            r#"
+[[filters]]
+type = "RSI"
+interval = "1h"
+filter_type = "Overbought"
+
+[[filters]]
+type = "RSI"
+filter_type = "Oversold"
+"#,
+        )
+        .unwrap();
+
+        let TechnicalFilterConfig::Timeframe(timeframe) = &config.filters[0] else {
+            panic!("간격 필터로 읽어야 함");
+        };
+        assert_eq!(timeframe.interval, "1h");
+        assert_eq!(config.filters[0].filter_type(), TechnicalFilterType::RSI);
+        assert!(matches!(config.filters[1], TechnicalFilterConfig::RSI(_)));
+
+        let json = serde_json::to_value(&config.filters[0]).unwrap();
+        assert_eq!(json["type"], "RSI");
+        assert_eq!(json["interval"], "1h");
+        let parsed: TechnicalFilterConfig = serde_json::from_value(json).unwrap();
+        assert!(matches!(parsed, TechnicalFilterConfig::Timeframe(_)));
+
+        let invalid = TechnicalFilterConfig::Timeframe(TimeframeFilterConfig::new(
+            "not-an-interval",
+            rsi_filter(RSIFilterType::Oversold),
+        ));
+        assert!(matches!(
+            invalid.validate::<IntervalCandle>(),
+            Err(FilterError::UnknownCandleInterval { .. })
+        ));
+    }
+
+    #[test]
+    fn test_timeframe_filter_uses_candles_of_interval() {
+        let candles = mixed_candles();
+        let hourly_overbought = TechnicalFilterConfig::Timeframe(TimeframeFilterConfig::new(
+            "1h",
+            rsi_filter(RSIFilterType::Overbought),
+        ));
+        let minute_oversold = TechnicalFilterConfig::Timeframe(TimeframeFilterConfig::new(
+            "1m",
+            rsi_filter(RSIFilterType::Oversold),
+        ));
+        let filters = [hourly_overbought, minute_oversold];
+
+        assert!(TechnicalFilter::matches_filters("TEST/USDT", &filters, &candles, 158.0).unwrap());
+
+        // 컨텍스트는 간격별 저장소를 유지하고 캔들 추가도 해당 간격에만 반영
+        let mut context = TechnicalFilterContext::new(&candles);
+        assert!(
+            context
+                .matches_filters("TEST/USDT", &filters, 158.0)
+                .unwrap()
+        );
+        assert_eq!(context.cached_indicators(), 2);
+
+        for i in 30..60 {
+            context.update(IntervalCandle {
+                timestamp: i * 3_600,
+                interval: interval("1h"),
+                close: 160.0 - (i - 30) as f64 * 4.0,
+            });
+        }
+        assert!(
+            !context
+                .matches_filter("TEST/USDT", &filters[0], 40.0)
+                .unwrap()
+        );
+        assert!(
+            context
+                .matches_filter("TEST/USDT", &filters[1], 40.0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_aligned_candles_of_different_intervals_are_kept_separately() {
+        // 일봉과 같은 시각에 시작하는 1시간봉도 서로 덮어쓰지 않아야 함
+        let hours = (0..48).map(|i| IntervalCandle {
+            timestamp: i * 3_600,
+            interval: interval("1h"),
+            close: 100.0 + i as f64,
+        });
+        let days = (0..2).map(|i| IntervalCandle {
+            timestamp: i * 86_400,
+            interval: interval("1d"),
+            close: 500.0 + i as f64,
+        });
+        let candles: Vec<_> = hours.chain(days).collect();
+
+        let mut context = TechnicalFilterContext::from_candles(candles.clone(), 100, true);
+        let hourly = context.timeframes.candle_store(&interval("1h")).unwrap();
+        let daily = context.timeframes.candle_store(&interval("1d")).unwrap();
+        assert_eq!(hourly.len(), 48);
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily.first().unwrap().close, 501.0);
+
+        // 간격을 지정하지 않은 필터는 기준 간격(첫 캔들의 간격)의 캔들만 사용
+        assert_eq!(context.base_interval(), Some(&interval("1h")));
+        assert_eq!(context.candle_store().len(), 48);
+        let daily_base = TechnicalFilterContext::from_candles(candles, 100, true)
+            .with_base_interval(interval("1d"));
+        assert_eq!(daily_base.candle_store().len(), 2);
+
+        // 같은 시각의 다른 간격 캔들 갱신은 해당 간격에만 반영
+        context.update(IntervalCandle {
+            timestamp: 86_400,
+            interval: interval("1d"),
+            close: 600.0,
+        });
+        let hourly = context.timeframes.candle_store(&interval("1h")).unwrap();
+        let daily = context.timeframes.candle_store(&interval("1d")).unwrap();
+        assert_eq!(hourly.len(), 48);
+        assert_eq!(hourly.items()[23].close, 124.0);
+        assert_eq!(daily.first().unwrap().close, 600.0);
+    }
+
+    #[test]
+    fn test_non_interval_filters_use_base_interval() {
+        let candles = mixed_candles();
+        let oversold = rsi_filter(RSIFilterType::Oversold);
+        let overbought = rsi_filter(RSIFilterType::Overbought);
+
+        // 첫 캔들이 1분봉이므로 하락하는 1분봉으로 평가
+        let mut context = TechnicalFilterContext::new(&candles);
+        assert_eq!(context.base_interval(), Some(&interval("1m")));
+        assert!(
+            context
+                .matches_filter("TEST/USDT", &oversold, 158.0)
+                .unwrap()
+        );
+        assert!(
+            !context
+                .matches_filter("TEST/USDT", &overbought, 158.0)
+                .unwrap()
+        );
+        assert!(TechnicalFilter::matches_filter("TEST/USDT", &oversold, &candles, 158.0).unwrap());
+
+        // 1시간봉보다 과거인 1분봉이 추가되어도 1분봉 캐시는 비우지 않고 이어서 갱신
+        let cached = context.cached_indicators();
+        assert!(cached > 0);
+        context.update(IntervalCandle {
+            timestamp: 30 * 60,
+            interval: interval("1m"),
+            close: 138.0,
+        });
+        assert_eq!(context.cached_indicators(), cached);
+        assert!(
+            context
+                .matches_filter("TEST/USDT", &oversold, 138.0)
+                .unwrap()
+        );
+
+        let context = TechnicalFilterContext::new(&candles).with_base_interval(interval("1h"));
+        assert!(
+            context
+                .matches_filter("TEST/USDT", &overbought, 158.0)
+                .unwrap()
+        );
+        assert!(
+            !context
+                .matches_filter("TEST/USDT", &oversold, 158.0)
+                .unwrap()
+        );
+    }
+}
@@ -1,18 +1,93 @@
 use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
 use super::{VolumeFilterType, VolumeParams, utils};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::volume_analyzer::VolumeAnalyzer;
-use crate::candle_store::CandleStore;
 use trading_chart::Candle;
 
 /// Volume 필터 함수
-pub(crate) fn filter_volume<C: Candle + 'static>(
+pub(crate) fn filter_volume<'a, C: Candle + 'static>(
     symbol: &str,
     params: &VolumeParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
-    VolumeFilter::matches_filter(symbol, candle_store, params, current_price)
+    VolumeFilter::matches_filter(symbol, source, params, current_price)
+}
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &VolumeParams) -> usize {
+    params.period.max(params.consecutive_n)
+}
+
+/// 확인한 오프셋별 거래량과 평균 대비 비율
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &VolumeParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &volume_analyzer(source, params.period).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            [
+                ("volume", data.candle.volume()),
+                ("volume_ratio", data.get_volume_ratio(params.period)),
+            ]
+        },
+    )
+}
+
+fn volume_analyzer<C: Candle + 'static>(
+    source: FilterSource<'_, C>,
+    period: usize,
+) -> CachedRef<'_, VolumeAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(period.to_string(), || {
+        VolumeAnalyzer::new(&[period], candle_store)
+    })
+}
+
+/// 통과한 Volume 필터의 강도 (p번째 평균 대비 거래량 비율이 기준 비율을 넘어선 정도)
+///
+/// 평균 기준 타입은 평균의 2배(절반)에서 1, 임계값 기준 타입은 임계값만큼 더 벗어나면 1입니다.
+/// 급증/급감, 추세, 방향성 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_volume<C: Candle + 'static>(
+    params: &VolumeParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let ratio = volume_analyzer(source, params.period)
+        .items
+        .get(params.p)?
+        .get_volume_ratio(params.period);
+    let threshold = params.threshold;
+
+    match params.filter_type {
+        VolumeFilterType::VolumeAboveAverage => excess_ratio(ratio - 1.0, 1.0),
+        VolumeFilterType::VolumeBelowAverage => excess_ratio(1.0 - ratio, 0.5),
+        VolumeFilterType::VolumeSignificantlyAbove | VolumeFilterType::VolumeExtremelyHigh => {
+            excess_ratio(ratio - threshold, threshold)
+        }
+        VolumeFilterType::VolumeExtremelyLow => excess_ratio(threshold - ratio, threshold),
+        VolumeFilterType::VolumeDoubleAverage => {
+            let double_threshold = if threshold > 0.0 {
+                2.0 * threshold
+            } else {
+                2.0
+            };
+            excess_ratio(ratio - double_threshold, double_threshold)
+        }
+        VolumeFilterType::VolumeHalfAverage => {
+            let half_threshold = if threshold > 0.0 {
+                0.5 * threshold
+            } else {
+                0.5
+            };
+            excess_ratio(half_threshold - ratio, half_threshold)
+        }
+        _ => None,
+    }
 }
 
 /// Volume 필터 구조체
@@ -20,12 +95,15 @@ pub struct VolumeFilter;
 
 impl VolumeFilter {
     /// Volume 필터 확인 (내부 헬퍼 함수, CandleStore 재사용)
-    pub(crate) fn matches_filter<C: Candle + 'static>(
+    pub(crate) fn matches_filter<'a, C: Candle + 'static>(
         _symbol: &str,
-        candle_store: &CandleStore<C>,
+        source: impl Into<FilterSource<'a, C>>,
         params: &VolumeParams,
         current_price: f64,
     ) -> Result<bool> {
+        let source = source.into();
+        let candle_store = source.candle_store();
+
         let period = params.period;
         let threshold = params.threshold;
         let filter_type = params.filter_type;
@@ -36,12 +114,12 @@ impl VolumeFilter {
         utils::validate_period(period, "Volume")?;
 
         // 경계 조건 체크
-        let required_length = period.max(consecutive_n);
+        let required_length = required_candles(params);
         if !utils::check_sufficient_candles(candle_store.len(), required_length, _symbol) {
             return Ok(false);
         }
         // analyzer는 이미 init_from_storage로 초기화되었으므로 추가 처리 불필요
-        let analyzer = VolumeAnalyzer::new(&[period], candle_store);
+        let analyzer = volume_analyzer(source, period);
 
         // analyzer 메서드들이 이미 consecutive_n을 처리하므로 직접 호출
         let result = match filter_type {
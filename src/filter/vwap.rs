@@ -1,21 +1,169 @@
 use super::Result;
-use super::{VWAPFilterType, VWAPParams, utils};
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{VWAPAnchorMode, VWAPFilterType, VWAPParams, utils};
 use crate::analyzer::base::AnalyzerOps;
+use crate::analyzer::price_action_analyzer::SwingType;
 use crate::analyzer::vwap_analyzer::VWAPAnalyzer;
-use crate::candle_store::CandleStore;
-use crate::indicator::vwap::VWAPParams as IndicatorVWAPParams;
+use crate::indicator::vwap::{VWAPAnchor, VWAPParams as IndicatorVWAPParams};
 use trading_chart::Candle;
 
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &VWAPParams) -> usize {
+    warmup_candles(params.period, &params.anchor) + params.consecutive_n
+}
+
+/// VWAP 값이 의미를 갖기까지 필요한 캔들 수
+///
+/// 앵커 VWAP은 첫 캔들부터 값이 있지만, 스윙 앵커는 좌우 swing_strength개 캔들이 있어야
+/// 스윙 포인트를 식별할 수 있습니다.
+pub(crate) fn warmup_candles(period: usize, anchor: &VWAPAnchorMode) -> usize {
+    match anchor {
+        VWAPAnchorMode::Rolling => period,
+        VWAPAnchorMode::SwingHigh { swing_strength }
+        | VWAPAnchorMode::SwingLow { swing_strength } => swing_strength * 2 + 1,
+        VWAPAnchorMode::Day
+        | VWAPAnchorMode::Week
+        | VWAPAnchorMode::Month
+        | VWAPAnchorMode::Timestamp { .. } => 1,
+    }
+}
+
+/// 앵커 설정 검증 (스윙 강도는 1 이상)
+pub(crate) fn validate_anchor(anchor: &VWAPAnchorMode, name: &str) -> Result<()> {
+    match anchor {
+        VWAPAnchorMode::SwingHigh { swing_strength }
+        | VWAPAnchorMode::SwingLow { swing_strength } => {
+            utils::validate_period(*swing_strength, &format!("{name} anchor swing_strength"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 스윙 앵커의 스윙 타입과 강도 (스윙 앵커가 아니면 None)
+pub(crate) fn swing_anchor(anchor: &VWAPAnchorMode) -> Option<(SwingType, usize)> {
+    match *anchor {
+        VWAPAnchorMode::SwingHigh { swing_strength } => Some((SwingType::High, swing_strength)),
+        VWAPAnchorMode::SwingLow { swing_strength } => Some((SwingType::Low, swing_strength)),
+        _ => None,
+    }
+}
+
+/// 필터 앵커 설정에 해당하는 지표 매개변수
+///
+/// 스윙 앵커는 `VWAPAnalyzer::new_swing_anchored`가 `VWAPParams::default()` 키로 저장합니다.
+pub(crate) fn indicator_params(period: usize, anchor: &VWAPAnchorMode) -> IndicatorVWAPParams {
+    let anchor = match *anchor {
+        VWAPAnchorMode::Rolling => return IndicatorVWAPParams::new(period),
+        VWAPAnchorMode::Day => VWAPAnchor::Day,
+        VWAPAnchorMode::Week => VWAPAnchor::Week,
+        VWAPAnchorMode::Month => VWAPAnchor::Month,
+        VWAPAnchorMode::Timestamp { timestamp } => VWAPAnchor::Timestamp(timestamp),
+        VWAPAnchorMode::SwingHigh { .. } | VWAPAnchorMode::SwingLow { .. } => {
+            return IndicatorVWAPParams::default();
+        }
+    };
+    IndicatorVWAPParams::anchored(anchor)
+}
+
+/// 확인한 오프셋별 VWAP 값과 표준편차 밴드
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &VWAPParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    let vwap_params = indicator_params(params.period, &params.anchor);
+
+    offset_values(
+        &vwap_analyzer(source, params).items,
+        params.p..params.p + params.consecutive_n,
+        |data| {
+            let vwap = data.vwaps.get(&vwap_params);
+            [
+                ("vwap", vwap.value),
+                ("upper_band", vwap.upper_band(params.band_multiplier)),
+                ("lower_band", vwap.lower_band(params.band_multiplier)),
+            ]
+        },
+    )
+}
+
+fn vwap_analyzer<'a, C: Candle + 'static>(
+    source: FilterSource<'a, C>,
+    params: &VWAPParams,
+) -> CachedRef<'a, VWAPAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(
+        format!("{}:{:?}", params.period, params.anchor),
+        || match swing_anchor(&params.anchor) {
+            Some((swing_type, swing_strength)) => {
+                VWAPAnalyzer::new_swing_anchored(swing_type, swing_strength, candle_store)
+            }
+            None => VWAPAnalyzer::new(
+                &[indicator_params(params.period, &params.anchor)],
+                candle_store,
+            ),
+        },
+    )
+}
+
+/// 통과한 VWAP 필터의 강도 (현재가가 p번째 VWAP 기준선을 넘어선 정도)
+///
+/// VWAP과 밴드 사이 폭(`band_multiplier` × 표준편차)으로 정규화하고,
+/// 근접 타입은 임계값 안쪽으로 들어온 정도입니다.
+/// 돌파, 반등, 거리 추세 패턴 기반 타입은 None을 반환합니다.
+pub(crate) fn score_vwap<C: Candle + 'static>(
+    params: &VWAPParams,
+    source: FilterSource<'_, C>,
+    current_price: f64,
+) -> Option<f64> {
+    let vwap_params = indicator_params(params.period, &params.anchor);
+    let analyzer = vwap_analyzer(source, params);
+    let vwap = analyzer.items.get(params.p)?.vwaps.get(&vwap_params);
+    let (lower, upper) = (
+        vwap.lower_band(params.band_multiplier),
+        vwap.upper_band(params.band_multiplier),
+    );
+
+    match params.filter_type {
+        VWAPFilterType::PriceAboveVWAP | VWAPFilterType::StrongUptrend => {
+            excess_ratio(current_price - vwap.value, upper - vwap.value)
+        }
+        VWAPFilterType::PriceBelowVWAP | VWAPFilterType::StrongDowntrend => {
+            excess_ratio(vwap.value - current_price, vwap.value - lower)
+        }
+        VWAPFilterType::PriceNearVWAP => excess_ratio(
+            params.threshold - vwap.price_to_vwap_percent(current_price).abs(),
+            params.threshold,
+        ),
+        VWAPFilterType::PriceAboveUpperBand => {
+            excess_ratio(current_price - upper, upper - vwap.value)
+        }
+        VWAPFilterType::PriceBelowLowerBand => {
+            excess_ratio(lower - current_price, vwap.value - lower)
+        }
+        VWAPFilterType::PriceInsideBands => excess_ratio(
+            (upper - vwap.value) - (current_price - vwap.value).abs(),
+            upper - vwap.value,
+        ),
+        _ => None,
+    }
+}
+
 /// 개별 코인에 대한 VWAP 필터 적용
-pub(crate) fn filter_vwap<C: Candle + 'static>(
+pub(crate) fn filter_vwap<'a, C: Candle + 'static>(
     coin: &str,
     params: &VWAPParams,
-    candle_store: &CandleStore<C>,
+    source: impl Into<FilterSource<'a, C>>,
     current_price: f64,
 ) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
     log::debug!(
-        "VWAP 필터 적용 - 기간: {}, 타입: {:?}, 연속성: {}, 임계값: {:.2}%",
+        "VWAP 필터 적용 - 기간: {}, 앵커: {:?}, 타입: {:?}, 연속성: {}, 임계값: {:.2}%",
         params.period,
+        params.anchor,
         params.filter_type,
         params.consecutive_n,
         params.threshold * 100.0
@@ -23,20 +171,20 @@ pub(crate) fn filter_vwap<C: Candle + 'static>(
 
     // 파라미터 검증
     utils::validate_period(params.period, "VWAP")?;
+    validate_anchor(&params.anchor, "VWAP")?;
+    utils::validate_positive_number(params.band_multiplier, "VWAP band_multiplier")?;
 
     // 경계 조건 체크
-    let required_length = params.period + params.consecutive_n;
+    let required_length = required_candles(params);
     if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
         return Ok(false);
     }
 
     // VWAP 매개변수 설정
-    let vwap_params = IndicatorVWAPParams {
-        period: params.period,
-    };
+    let vwap_params = indicator_params(params.period, &params.anchor);
 
     // VWAPAnalyzer 생성
-    let analyzer = VWAPAnalyzer::new(&[vwap_params], candle_store);
+    let analyzer = vwap_analyzer(source, params);
 
     log::debug!("코인 {coin} VWAP 분석기 생성 완료");
 
@@ -119,6 +267,37 @@ pub(crate) fn filter_vwap<C: Candle + 'static>(
             params.consecutive_n,
             params.p,
         ),
+        VWAPFilterType::PriceAboveUpperBand => analyzer.is_all(
+            |data| {
+                current_price
+                    > data
+                        .vwaps
+                        .get(&vwap_params)
+                        .upper_band(params.band_multiplier)
+            },
+            params.consecutive_n,
+            params.p,
+        ),
+        VWAPFilterType::PriceBelowLowerBand => analyzer.is_all(
+            |data| {
+                current_price
+                    < data
+                        .vwaps
+                        .get(&vwap_params)
+                        .lower_band(params.band_multiplier)
+            },
+            params.consecutive_n,
+            params.p,
+        ),
+        VWAPFilterType::PriceInsideBands => analyzer.is_all(
+            |data| {
+                let vwap = data.vwaps.get(&vwap_params);
+                vwap.lower_band(params.band_multiplier) <= current_price
+                    && current_price <= vwap.upper_band(params.band_multiplier)
+            },
+            params.consecutive_n,
+            params.p,
+        ),
     };
 
     Ok(result)
@@ -206,3 +385,91 @@ fn is_vwap_distance_converging<C: Candle>(
         current < next
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::vwap_analyzer::VWAPSwingAnchor;
+    use crate::tests::{test_candle, test_candles};
+
+    fn params(anchor: VWAPAnchorMode, filter_type: VWAPFilterType) -> VWAPParams {
+        VWAPParams {
+            period: 5,
+            anchor,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_vwap_session_anchor_and_bands() {
+        // 전날 200 부근에서 거래 후 자정 이후 100, 100, 104
+        let midnight = 86_400;
+        let candles = vec![
+            test_candle(midnight - 180, 200.0),
+            test_candle(midnight - 120, 200.0),
+            test_candle(midnight - 60, 200.0),
+            test_candle(midnight, 100.0),
+            test_candle(midnight + 60, 100.0),
+            test_candle(midnight + 120, 104.0),
+        ];
+        let candle_store = utils::create_candle_store(&candles);
+
+        // 세션 VWAP ≈ 101.33, σ ≈ 1.89 / 롤링(5) VWAP = 140.8
+        let above = params(VWAPAnchorMode::Day, VWAPFilterType::PriceAboveVWAP);
+        assert!(filter_vwap("TEST", &above, &candle_store, 104.0).unwrap());
+        let rolling_above = params(VWAPAnchorMode::Rolling, VWAPFilterType::PriceAboveVWAP);
+        assert!(!filter_vwap("TEST", &rolling_above, &candle_store, 104.0).unwrap());
+
+        let inside = params(VWAPAnchorMode::Day, VWAPFilterType::PriceInsideBands);
+        assert!(filter_vwap("TEST", &inside, &candle_store, 104.0).unwrap());
+        let above_one_sigma = VWAPParams {
+            band_multiplier: 1.0,
+            ..params(VWAPAnchorMode::Day, VWAPFilterType::PriceAboveUpperBand)
+        };
+        assert!(filter_vwap("TEST", &above_one_sigma, &candle_store, 104.0).unwrap());
+    }
+
+    #[test]
+    fn test_vwap_swing_low_anchor() {
+        // 종가 100 캔들이 좌우 2개 캔들보다 저가가 낮은 스윙 저점
+        let candles = test_candles(&[110.0, 108.0, 100.0, 106.0, 109.0, 112.0]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        // 스윙 저점부터 VWAP = (100 + 106 + 109 + 112) / 4 = 106.75
+        let swing_low = params(
+            VWAPAnchorMode::SwingLow { swing_strength: 2 },
+            VWAPFilterType::PriceAboveVWAP,
+        );
+        assert!(filter_vwap("TEST", &swing_low, &candle_store, 112.0).unwrap());
+
+        // 스윙 고점이 없으면 현재 캔들만 사용하므로 VWAP = 112
+        let swing_high = params(
+            VWAPAnchorMode::SwingHigh { swing_strength: 2 },
+            VWAPFilterType::PriceAboveVWAP,
+        );
+        assert!(!filter_vwap("TEST", &swing_high, &candle_store, 112.0).unwrap());
+    }
+
+    #[test]
+    fn test_vwap_swing_anchor_accumulates_until_next_swing() {
+        // 스윙 저점 100 이후 60개 캔들이 계속 올라 스윙 분석 범위를 벗어나도 앵커 유지
+        let mut closes = vec![110.0, 108.0, 100.0, 106.0, 109.0];
+        closes.extend((0..60).map(|i| 110.0 + i as f64));
+        let mut anchor = VWAPSwingAnchor::new(SwingType::Low, 2);
+        let vwap = test_candles(&closes)
+            .iter()
+            .map(|candle| anchor.next(candle))
+            .last()
+            .unwrap();
+        let expected = closes[2..].iter().sum::<f64>() / (closes.len() - 2) as f64;
+        assert!((vwap.value - expected).abs() < 1e-9, "{}", vwap.value);
+
+        // 새 스윙 저점 160이 확인되면 그 캔들부터 다시 누적
+        let mut vwap = vwap;
+        for candle in test_candles(&[160.0, 165.0, 170.0]) {
+            vwap = anchor.next(&candle);
+        }
+        assert!((vwap.value - 165.0).abs() < 1e-9, "{}", vwap.value);
+    }
+}
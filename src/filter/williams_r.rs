@@ -0,0 +1,164 @@
+use super::Result;
+use super::cache::{CachedRef, FilterSource};
+use super::report::{OffsetValues, offset_values};
+use super::score::excess_ratio;
+use super::{WilliamsRFilterType, WilliamsRParams, utils};
+use crate::analyzer::williams_r_analyzer::WilliamsRAnalyzer;
+use trading_chart::Candle;
+
+/// Williams %R 중심선
+const WILLIAMS_R_MIDLINE: f64 = -50.0;
+
+/// 필터 평가에 필요한 최소 캔들 수
+pub(crate) fn required_candles(params: &WilliamsRParams) -> usize {
+    params.period + params.consecutive_n
+}
+
+/// 확인한 오프셋별 Williams %R 값
+pub(crate) fn report_values<C: Candle + 'static>(
+    params: &WilliamsRParams,
+    source: FilterSource<'_, C>,
+) -> Vec<OffsetValues> {
+    offset_values(
+        &williams_r_analyzer(source, params.period).items,
+        params.p..params.p + params.consecutive_n,
+        |data| [("williams_r", data.get_williams_r(params.period))],
+    )
+}
+
+fn williams_r_analyzer<C: Candle + 'static>(
+    source: FilterSource<'_, C>,
+    period: usize,
+) -> CachedRef<'_, WilliamsRAnalyzer<C>> {
+    let candle_store = source.candle_store();
+    source.analyzer(period.to_string(), || {
+        WilliamsRAnalyzer::new(&[period], candle_store)
+    })
+}
+
+/// 통과한 Williams %R 필터의 강도 (p번째 %R이 기준선을 넘어선 정도)
+///
+/// 과매수/과매도는 기준선부터 극값(0, -100)까지, 정상 범위는 가까운 기준선까지의 거리를
+/// 범위 절반으로 정규화합니다. 교차 타입은 None을 반환합니다.
+pub(crate) fn score_williams_r<C: Candle + 'static>(
+    params: &WilliamsRParams,
+    source: FilterSource<'_, C>,
+) -> Option<f64> {
+    let williams_r = williams_r_analyzer(source, params.period)
+        .items
+        .get(params.p)?
+        .get_williams_r(params.period);
+
+    match params.filter_type {
+        WilliamsRFilterType::Overbought => {
+            excess_ratio(williams_r - params.overbought, -params.overbought)
+        }
+        WilliamsRFilterType::Oversold => {
+            excess_ratio(params.oversold - williams_r, params.oversold + 100.0)
+        }
+        WilliamsRFilterType::NormalRange => excess_ratio(
+            (williams_r - params.oversold).min(params.overbought - williams_r),
+            (params.overbought - params.oversold) / 2.0,
+        ),
+        _ => None,
+    }
+}
+
+/// 개별 코인에 대한 Williams %R 필터 적용
+pub(crate) fn filter_williams_r<'a, C: Candle + 'static>(
+    coin: &str,
+    params: &WilliamsRParams,
+    source: impl Into<FilterSource<'a, C>>,
+    _current_price: f64,
+) -> Result<bool> {
+    let source = source.into();
+    let candle_store = source.candle_store();
+
+    log::debug!(
+        "WilliamsR 필터 적용 - 기간: {}, 과매수: {:.2}, 과매도: {:.2}, 타입: {:?}, 연속성: {}",
+        params.period,
+        params.overbought,
+        params.oversold,
+        params.filter_type,
+        params.consecutive_n
+    );
+
+    // 파라미터 검증
+    utils::validate_period(params.period, "WilliamsR")?;
+
+    // 경계 조건 체크
+    let required_length = required_candles(params);
+    if !utils::check_sufficient_candles(candle_store.len(), required_length, coin) {
+        return Ok(false);
+    }
+
+    let analyzer = williams_r_analyzer(source, params.period);
+    let (period, n, p) = (params.period, params.consecutive_n, params.p);
+
+    let result = match params.filter_type {
+        WilliamsRFilterType::Overbought => {
+            analyzer.is_williams_r_greater_than(period, params.overbought, n, p)
+        }
+        WilliamsRFilterType::Oversold => {
+            analyzer.is_williams_r_less_than(period, params.oversold, n, p)
+        }
+        WilliamsRFilterType::NormalRange => {
+            analyzer.is_williams_r_in_range(period, params.oversold, params.overbought, n, p)
+        }
+        WilliamsRFilterType::CrossAboveOversold => {
+            analyzer.is_williams_r_cross_above(period, params.oversold, n, 1, p)
+        }
+        WilliamsRFilterType::CrossBelowOverbought => {
+            analyzer.is_williams_r_cross_below(period, params.overbought, n, 1, p)
+        }
+        WilliamsRFilterType::CrossAboveMidline => {
+            analyzer.is_williams_r_cross_above(period, WILLIAMS_R_MIDLINE, n, 1, p)
+        }
+        WilliamsRFilterType::CrossBelowMidline => {
+            analyzer.is_williams_r_cross_below(period, WILLIAMS_R_MIDLINE, n, 1, p)
+        }
+    };
+
+    log::debug!("코인 {coin} WilliamsR 필터 결과: {result}");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_candles;
+
+    fn params(filter_type: WilliamsRFilterType) -> WilliamsRParams {
+        WilliamsRParams {
+            period: 3,
+            filter_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_williams_r_overbought_and_oversold() {
+        let rising = test_candles(&[100.0, 102.0, 104.0, 106.0, 108.0]);
+        let candle_store = utils::create_candle_store(&rising);
+        let overbought = params(WilliamsRFilterType::Overbought);
+        assert!(filter_williams_r("TEST", &overbought, &candle_store, 108.0).unwrap());
+
+        let falling = test_candles(&[108.0, 106.0, 104.0, 102.0, 100.0]);
+        let candle_store = utils::create_candle_store(&falling);
+        let oversold = params(WilliamsRFilterType::Oversold);
+        assert!(filter_williams_r("TEST", &oversold, &candle_store, 100.0).unwrap());
+        assert!(!filter_williams_r("TEST", &overbought, &candle_store, 100.0).unwrap());
+    }
+
+    #[test]
+    fn test_williams_r_cross_above_midline() {
+        // 하락 구간의 마지막 캔들에서 반등해 중심선(-50) 위로 올라섬
+        let candles = test_candles(&[108.0, 106.0, 104.0, 102.0, 100.0, 104.0]);
+        let candle_store = utils::create_candle_store(&candles);
+
+        let cross = params(WilliamsRFilterType::CrossAboveMidline);
+        assert!(filter_williams_r("TEST", &cross, &candle_store, 104.0).unwrap());
+        let cross_below = params(WilliamsRFilterType::CrossBelowMidline);
+        assert!(!filter_williams_r("TEST", &cross_below, &candle_store, 104.0).unwrap());
+    }
+}
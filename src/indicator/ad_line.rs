@@ -0,0 +1,172 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::TABuilder;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 캔들의 머니 플로우 승수 (-1 ~ 1)
+///
+/// 종가가 고가에 붙을수록 1, 저가에 붙을수록 -1입니다.
+/// 고가와 저가가 같으면 0입니다.
+pub(crate) fn money_flow_multiplier<C: Candle>(candle: &C) -> f64 {
+    let high = candle.high_price();
+    let low = candle.low_price();
+    let close = candle.close_price();
+    let range = high - low;
+    if range > 0.0 {
+        ((close - low) - (high - close)) / range
+    } else {
+        0.0
+    }
+}
+
+/// A/D 라인 (Accumulation/Distribution Line) 기술적 지표
+///
+/// 캔들마다 머니 플로우 승수에 거래량을 곱한 값을 누적합니다.
+#[derive(Clone, Debug)]
+pub struct ADLine {
+    /// 누적 A/D 값
+    pub value: f64,
+    /// 현재 캔들의 머니 플로우 거래량
+    pub money_flow_volume: f64,
+}
+
+impl Display for ADLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ADLine({:.2})", self.value)
+    }
+}
+
+impl ADLine {
+    /// A/D 값 반환
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// A/D 라인 계산 빌더
+#[derive(Debug)]
+pub struct ADLineBuilder<C: Candle> {
+    /// 누적 A/D 값
+    value: f64,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> Default for ADLineBuilder<C>
+where
+    C: Candle,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> ADLineBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 A/D 라인 빌더 생성
+    pub fn new() -> Self {
+        Self {
+            value: 0.0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// 저장소에서 A/D 라인 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> ADLine {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 A/D 라인 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> ADLine {
+        self.value = 0.0;
+
+        let mut ad_line = ADLine {
+            value: 0.0,
+            money_flow_volume: 0.0,
+        };
+        for candle in data {
+            ad_line = self.next(candle);
+        }
+
+        ad_line
+    }
+
+    /// 새 캔들 데이터로 A/D 라인 지표 업데이트
+    pub fn next(&mut self, data: &C) -> ADLine {
+        let money_flow_volume = money_flow_multiplier(data) * data.volume();
+        self.value += money_flow_volume;
+
+        ADLine {
+            value: self.value,
+            money_flow_volume,
+        }
+    }
+}
+
+impl<C> TABuilder<ADLine, C> for ADLineBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> ADLine {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> ADLine {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> ADLine {
+        self.next(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64, f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (high, low, close, volume))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: *high,
+                low: *low,
+                close: *close,
+                volume: *volume,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ad_line_exact_calculation() {
+        let candles = create_test_candles(&[
+            (110.0, 100.0, 110.0, 1000.0),
+            (110.0, 100.0, 102.0, 500.0),
+            (105.0, 105.0, 105.0, 800.0),
+        ]);
+        let ad_line = ADLineBuilder::new().build(&candles);
+
+        // 1000 * 1.0 + 500 * (2 - 8) / 10 + 800 * 0.0 (고가 = 저가)
+        assert!((ad_line.value() - 700.0).abs() < 1e-10);
+        assert_eq!(ad_line.money_flow_volume, 0.0);
+        assert_eq!(ad_line.to_string(), "ADLine(700.00)");
+    }
+
+    #[test]
+    fn test_ad_line_next_matches_build() {
+        let candles =
+            create_test_candles(&[(110.0, 100.0, 100.0, 1000.0), (110.0, 100.0, 107.5, 400.0)]);
+        let mut builder = ADLineBuilder::new();
+        builder.build(&candles[..1]);
+        let ad_line = builder.next(&candles[1]);
+
+        // -1000 + 400 * 0.5
+        assert!((ad_line.value() - (-800.0)).abs() < 1e-10);
+        assert!((ad_line.money_flow_volume - 200.0).abs() < 1e-10);
+        assert!((ADLineBuilder::new().build(&candles).value() - ad_line.value()).abs() < 1e-10);
+    }
+}
@@ -1,5 +1,9 @@
 use crate::candle_store::CandleStore;
-use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, TAs, TAsBuilder, state_kind_mismatch,
+    state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use trading_chart::Candle;
@@ -224,7 +228,30 @@ pub struct ADXBuilder<C: Candle> {
     _phantom: PhantomData<C>,
 }
 
-#[derive(Clone, Debug)]
+/// ADX 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ADXBuilderState {
+    /// ADX 계산 기간
+    pub period: usize,
+    /// 최근 고가 데이터
+    pub high_values: Vec<f64>,
+    /// 최근 저가 데이터
+    pub low_values: Vec<f64>,
+    /// 최근 종가 데이터
+    pub close_values: Vec<f64>,
+    /// 이전 평활 TR 값
+    pub previous_tr: Option<f64>,
+    /// 이전 평활 +DM 값
+    pub previous_plus_dm: Option<f64>,
+    /// 이전 평활 -DM 값
+    pub previous_minus_dm: Option<f64>,
+    /// 이전 ADX 값
+    pub previous_adx: Option<f64>,
+    /// ADX 초기값 계산용 DX 값
+    pub dx_values: Vec<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ADX {
     period: usize,
     pub adx: f64,
@@ -308,6 +335,51 @@ where
     fn next(&mut self, data: &C) -> ADX {
         self.next(data)
     }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        let indicator = &self.indicator;
+        Ok(IndicatorState::Adx(ADXBuilderState {
+            period: self.period,
+            high_values: indicator.high_values.clone(),
+            low_values: indicator.low_values.clone(),
+            close_values: indicator.close_values.clone(),
+            previous_tr: indicator.previous_tr,
+            previous_plus_dm: indicator.previous_plus_dm,
+            previous_minus_dm: indicator.previous_minus_dm,
+            previous_adx: indicator.previous_adx,
+            dx_values: indicator.dx_values.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Adx(state) = state else {
+            return Err(state_kind_mismatch("ADX", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "ADX",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+        if state.low_values.len() != state.high_values.len()
+            || state.close_values.len() != state.high_values.len()
+        {
+            return Err("ADX 상태의 고가/저가/종가 데이터 길이가 다릅니다".to_string());
+        }
+
+        let indicator = &mut self.indicator;
+        indicator.high_values = state.high_values.clone();
+        indicator.low_values = state.low_values.clone();
+        indicator.close_values = state.close_values.clone();
+        indicator.previous_tr = state.previous_tr;
+        indicator.previous_plus_dm = state.previous_plus_dm;
+        indicator.previous_minus_dm = state.previous_minus_dm;
+        indicator.previous_adx = state.previous_adx;
+        indicator.dx_values = state.dx_values.clone();
+        Ok(())
+    }
 }
 
 pub type ADXs = TAs<usize, ADX>;
@@ -1,6 +1,9 @@
 use crate::candle_store::CandleStore;
 use crate::indicator::utils::moving_average;
-use crate::indicator::{IndicatorResult, TABuilder};
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 use trading_chart::Candle;
@@ -135,10 +138,21 @@ pub struct BollingerBandsBuilder<C: Candle> {
     _phantom: PhantomData<C>,
 }
 
+/// 볼린저 밴드 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BollingerBandsBuilderState {
+    /// 계산 기간
+    pub period: usize,
+    /// 표준편차 승수
+    pub multiplier: f64,
+    /// 최근 종가 데이터 (최대 period개)
+    pub values: Vec<f64>,
+}
+
 /// 볼린저 밴드 기술적 지표
 ///
 /// 상단, 중간, 하단 밴드로 구성된 볼린저 밴드 값
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BollingerBands {
     /// 내부 볼린저 밴드 계산 결과
     pub middle: f64,
@@ -376,6 +390,39 @@ where
     fn next(&mut self, data: &C) -> BollingerBands {
         self.next(data)
     }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::BollingerBands(BollingerBandsBuilderState {
+            period: self.period,
+            multiplier: self.multiplier,
+            values: self.indicator.values.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::BollingerBands(state) = state else {
+            return Err(state_kind_mismatch("BollingerBands", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "BollingerBands",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+        if state.multiplier != self.multiplier {
+            return Err(state_param_mismatch(
+                "BollingerBands",
+                "승수",
+                self.multiplier,
+                state.multiplier,
+            ));
+        }
+
+        self.indicator.values = state.values.clone();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
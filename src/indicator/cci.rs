@@ -0,0 +1,288 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::smoothing;
+use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// CCI 평균 편차 보정 상수 (Lambert)
+const CCI_CONSTANT: f64 = 0.015;
+
+/// 상품채널지수(CCI) 기술적 지표
+///
+/// 대표가격((고가 + 저가 + 종가) / 3)이 이동평균에서 평균 편차 대비
+/// 얼마나 벗어났는지 측정합니다. 일반적으로 +100 이상은 과매수, -100 이하는 과매도입니다.
+#[derive(Clone, Debug)]
+pub struct CCI {
+    /// 계산 기간
+    period: usize,
+    /// CCI 값
+    pub value: f64,
+}
+
+impl Display for CCI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CCI({}: {:.2})", self.period, self.value)
+    }
+}
+
+impl CCI {
+    /// CCI가 과매수 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매수 기준값 (기본값 100.0)
+    pub fn is_overbought(&self, threshold: Option<f64>) -> bool {
+        self.value >= threshold.unwrap_or(100.0)
+    }
+
+    /// CCI가 과매도 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매도 기준값 (기본값 -100.0)
+    pub fn is_oversold(&self, threshold: Option<f64>) -> bool {
+        self.value <= threshold.unwrap_or(-100.0)
+    }
+
+    /// 계산 기간 반환
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// CCI 값 반환
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// CCI 계산 빌더
+///
+/// 데이터가 기간보다 적으면 있는 캔들만으로 계산합니다.
+/// 평균 편차가 0이면 CCI는 0입니다.
+#[derive(Debug)]
+pub struct CCIBuilder<C: Candle> {
+    /// 계산 기간
+    period: usize,
+    /// 최근 대표가격 데이터 (최대 period개)
+    typical_prices: Vec<f64>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> CCIBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 CCI 빌더 생성
+    ///
+    /// # Panics
+    /// * 기간이 0이면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 CCI 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<CCIBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("CCI 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            typical_prices: Vec::with_capacity(period),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 CCI 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> CCI {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 CCI 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> CCI {
+        self.typical_prices.clear();
+
+        let mut value = 0.0;
+        for candle in data {
+            value = self.next_value(candle);
+        }
+
+        CCI {
+            period: self.period,
+            value,
+        }
+    }
+
+    /// 다음 캔들 데이터로 CCI 값 계산 (내부용)
+    fn next_value(&mut self, candle: &C) -> f64 {
+        let typical_price = (candle.high_price() + candle.low_price() + candle.close_price()) / 3.0;
+        self.typical_prices.push(typical_price);
+        smoothing::truncate_front(&mut self.typical_prices, self.period);
+
+        let count = self.typical_prices.len() as f64;
+        let mean = self.typical_prices.iter().sum::<f64>() / count;
+        let mean_deviation = self
+            .typical_prices
+            .iter()
+            .map(|price| (price - mean).abs())
+            .sum::<f64>()
+            / count;
+
+        if mean_deviation > 0.0 {
+            (typical_price - mean) / (CCI_CONSTANT * mean_deviation)
+        } else {
+            0.0
+        }
+    }
+
+    /// 새 캔들 데이터로 CCI 지표 업데이트
+    pub fn next(&mut self, data: &C) -> CCI {
+        CCI {
+            period: self.period,
+            value: self.next_value(data),
+        }
+    }
+}
+
+impl<C> TABuilder<CCI, C> for CCIBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> CCI {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> CCI {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> CCI {
+        self.next(data)
+    }
+}
+
+/// 여러 기간의 CCI 지표 컬렉션 타입
+pub type CCIs = TAs<usize, CCI>;
+
+/// 여러 기간의 CCI 지표 빌더 타입
+pub type CCIsBuilder<C> = TAsBuilder<usize, CCI, C>;
+
+/// CCI 컬렉션 빌더 팩토리
+pub struct CCIsBuilderFactory;
+
+impl CCIsBuilderFactory {
+    /// 여러 기간의 CCI 빌더 생성
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `CCIsBuilder` - 여러 기간의 CCI 빌더
+    pub fn build<C: Candle + 'static>(periods: &[usize]) -> CCIsBuilder<C> {
+        match Self::build_checked(periods) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 여러 기간의 CCI 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `IndicatorResult<CCIsBuilder>` - CCI 빌더 또는 에러
+    pub fn build_checked<C: Candle + 'static>(
+        periods: &[usize],
+    ) -> IndicatorResult<CCIsBuilder<C>> {
+        for period in periods {
+            CCIBuilder::<C>::new_checked(*period)?;
+        }
+
+        Ok(CCIsBuilder::new("ccis".to_owned(), periods, |period| {
+            Box::new(CCIBuilder::<C>::new(*period))
+        }))
+    }
+
+    /// 기본 CCI 빌더 생성 (20 기간)
+    pub fn build_default<C: Candle + 'static>() -> CCIsBuilder<C> {
+        Self::build(&[20])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: *close,
+                low: *close,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cci_exact_calculation() {
+        let candles = create_test_candles(&[100.0, 102.0, 104.0, 110.0]);
+        let cci = CCIBuilder::new(4).build(&candles);
+
+        // 평균 104, 평균 편차 (4 + 2 + 0 + 6) / 4 = 3
+        // CCI = (110 - 104) / (0.015 * 3)
+        let expected = 6.0 / (0.015 * 3.0);
+        assert!((cci.value() - expected).abs() < 1e-10);
+        assert!(cci.is_overbought(None));
+        assert!(!cci.is_oversold(None));
+        assert_eq!(cci.to_string(), format!("CCI(4: {expected:.2})"));
+    }
+
+    #[test]
+    fn test_cci_oversold_and_flat() {
+        let candles = create_test_candles(&[100.0, 100.0, 100.0, 90.0]);
+        let cci = CCIBuilder::new(4).build(&candles);
+        assert!(cci.value() < -100.0);
+        assert!(cci.is_oversold(None));
+
+        let flat = create_test_candles(&[100.0; 5]);
+        assert_eq!(CCIBuilder::new(4).build(&flat).value(), 0.0);
+        assert_eq!(CCIBuilder::<TestCandle>::new(4).build(&[]).value(), 0.0);
+    }
+
+    #[test]
+    fn test_cci_next_matches_build() {
+        let candles = create_test_candles(&[100.0, 103.0, 101.0, 106.0, 104.0, 99.0, 102.0]);
+        let mut builder = CCIBuilder::new(4);
+        builder.build(&candles[..5]);
+        builder.next(&candles[5]);
+        let incremental = builder.next(&candles[6]);
+
+        let expected = CCIBuilder::new(4).build(&candles);
+        assert!((incremental.value() - expected.value()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_ccis_builder_factory() {
+        let candles = create_test_candles(&[100.0, 102.0, 104.0, 110.0]);
+        let ccis = CCIsBuilderFactory::build::<TestCandle>(&[4, 20]).build(&candles);
+
+        assert_eq!(ccis.len(), 2);
+        assert_eq!(ccis.get(&20).period(), 20);
+        assert!(CCIsBuilderFactory::build_checked::<TestCandle>(&[0]).is_err());
+    }
+}
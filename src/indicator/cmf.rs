@@ -0,0 +1,280 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ad_line::money_flow_multiplier;
+use crate::indicator::ma::smoothing;
+use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// CMF (Chaikin Money Flow) 기술적 지표
+///
+/// 최근 `period` 구간의 머니 플로우 거래량 합을 거래량 합으로 나눈 값(-1 ~ 1)입니다.
+/// 양수면 매수 압력, 음수면 매도 압력이 우세합니다.
+#[derive(Clone, Debug)]
+pub struct CMF {
+    /// 계산 기간
+    period: usize,
+    /// CMF 값 (-1 ~ 1)
+    pub value: f64,
+}
+
+impl Display for CMF {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CMF({}: {:.4})", self.period, self.value)
+    }
+}
+
+impl CMF {
+    /// 매수 압력 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 매수 압력 기준값 (기본값 0.05)
+    pub fn is_buying_pressure(&self, threshold: Option<f64>) -> bool {
+        self.value >= threshold.unwrap_or(0.05)
+    }
+
+    /// 매도 압력 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 매도 압력 기준값 (기본값 -0.05)
+    pub fn is_selling_pressure(&self, threshold: Option<f64>) -> bool {
+        self.value <= threshold.unwrap_or(-0.05)
+    }
+
+    /// 계산 기간 반환
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// CMF 값 반환
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// CMF 계산 빌더
+///
+/// 데이터가 기간보다 적으면 있는 캔들만으로 계산합니다.
+/// 구간 거래량 합이 0이면 0입니다.
+#[derive(Debug)]
+pub struct CMFBuilder<C: Candle> {
+    /// 계산 기간
+    period: usize,
+    /// 최근 머니 플로우 거래량 (최대 period개)
+    money_flow_volumes: Vec<f64>,
+    /// 최근 거래량 (최대 period개)
+    volumes: Vec<f64>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> CMFBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 CMF 빌더 생성
+    ///
+    /// # Panics
+    /// * 기간이 0이면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 CMF 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<CMFBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("CMF 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            money_flow_volumes: Vec::with_capacity(period),
+            volumes: Vec::with_capacity(period),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 CMF 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> CMF {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 CMF 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> CMF {
+        self.money_flow_volumes.clear();
+        self.volumes.clear();
+
+        let mut value = 0.0;
+        for candle in data {
+            value = self.next_value(candle);
+        }
+
+        CMF {
+            period: self.period,
+            value,
+        }
+    }
+
+    /// 다음 캔들 데이터로 CMF 값 계산 (내부용)
+    fn next_value(&mut self, candle: &C) -> f64 {
+        self.money_flow_volumes
+            .push(money_flow_multiplier(candle) * candle.volume());
+        self.volumes.push(candle.volume());
+        smoothing::truncate_front(&mut self.money_flow_volumes, self.period);
+        smoothing::truncate_front(&mut self.volumes, self.period);
+
+        let volume_sum: f64 = self.volumes.iter().sum();
+        if volume_sum > 0.0 {
+            self.money_flow_volumes.iter().sum::<f64>() / volume_sum
+        } else {
+            0.0
+        }
+    }
+
+    /// 새 캔들 데이터로 CMF 지표 업데이트
+    pub fn next(&mut self, data: &C) -> CMF {
+        CMF {
+            period: self.period,
+            value: self.next_value(data),
+        }
+    }
+}
+
+impl<C> TABuilder<CMF, C> for CMFBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> CMF {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> CMF {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> CMF {
+        self.next(data)
+    }
+}
+
+/// 여러 기간의 CMF 지표 컬렉션 타입
+pub type CMFs = TAs<usize, CMF>;
+
+/// 여러 기간의 CMF 지표 빌더 타입
+pub type CMFsBuilder<C> = TAsBuilder<usize, CMF, C>;
+
+/// CMF 컬렉션 빌더 팩토리
+pub struct CMFsBuilderFactory;
+
+impl CMFsBuilderFactory {
+    /// 여러 기간의 CMF 빌더 생성
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `CMFsBuilder` - 여러 기간의 CMF 빌더
+    pub fn build<C: Candle + 'static>(periods: &[usize]) -> CMFsBuilder<C> {
+        match Self::build_checked(periods) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 여러 기간의 CMF 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `IndicatorResult<CMFsBuilder>` - CMF 빌더 또는 에러
+    pub fn build_checked<C: Candle + 'static>(
+        periods: &[usize],
+    ) -> IndicatorResult<CMFsBuilder<C>> {
+        for period in periods {
+            CMFBuilder::<C>::new_checked(*period)?;
+        }
+
+        Ok(CMFsBuilder::new("cmfs".to_owned(), periods, |period| {
+            Box::new(CMFBuilder::<C>::new(*period))
+        }))
+    }
+
+    /// 기본 CMF 빌더 생성 (20 기간)
+    pub fn build_default<C: Candle + 'static>() -> CMFsBuilder<C> {
+        Self::build(&[20])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64, f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (high, low, close, volume))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: *high,
+                low: *low,
+                close: *close,
+                volume: *volume,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cmf_exact_calculation() {
+        let candles = create_test_candles(&[
+            (110.0, 100.0, 110.0, 1000.0),
+            (110.0, 100.0, 102.0, 500.0),
+            (110.0, 100.0, 105.0, 500.0),
+        ]);
+        let cmf = CMFBuilder::new(3).build(&candles);
+
+        // (1000 - 300 + 0) / 2000
+        assert!((cmf.value() - 0.35).abs() < 1e-10);
+        assert!(cmf.is_buying_pressure(None));
+        assert!(!cmf.is_selling_pressure(None));
+        assert_eq!(cmf.to_string(), "CMF(3: 0.3500)");
+    }
+
+    #[test]
+    fn test_cmf_next_drops_old_candles() {
+        let candles = create_test_candles(&[
+            (110.0, 100.0, 110.0, 1000.0),
+            (110.0, 100.0, 102.0, 500.0),
+            (110.0, 100.0, 100.0, 500.0),
+        ]);
+        let mut builder = CMFBuilder::new(2);
+        builder.build(&candles[..2]);
+        let cmf = builder.next(&candles[2]);
+
+        // 첫 캔들은 구간에서 빠짐: (-300 - 500) / 1000
+        assert!((cmf.value() - (-0.8)).abs() < 1e-10);
+        assert!(cmf.is_selling_pressure(None));
+
+        let no_volume = create_test_candles(&[(110.0, 100.0, 110.0, 0.0)]);
+        assert_eq!(CMFBuilder::new(2).build(&no_volume).value(), 0.0);
+    }
+
+    #[test]
+    fn test_cmfs_builder_factory() {
+        let candles = create_test_candles(&[(110.0, 100.0, 110.0, 1000.0)]);
+        let cmfs = CMFsBuilderFactory::build::<TestCandle>(&[2, 20]).build(&candles);
+
+        assert_eq!(cmfs.len(), 2);
+        assert_eq!(cmfs.get(&20).period(), 20);
+        assert!(CMFsBuilderFactory::build_checked::<TestCandle>(&[0]).is_err());
+    }
+}
@@ -0,0 +1,288 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::max::MAXBuilder;
+use crate::indicator::min::MINBuilder;
+use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 돈치안 채널 기술적 지표
+///
+/// 최근 `period` 캔들의 최고가를 상단, 최저가를 하단으로 하는 가격 채널입니다.
+#[derive(Clone, Debug)]
+pub struct DonchianChannel {
+    /// 계산 기간
+    period: usize,
+    /// 상단 (기간 최고가)
+    pub upper: f64,
+    /// 하단 (기간 최저가)
+    pub lower: f64,
+}
+
+impl Display for DonchianChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DC({}: {:.2}, {:.2}, {:.2})",
+            self.period,
+            self.upper,
+            self.middle(),
+            self.lower
+        )
+    }
+}
+
+impl DonchianChannel {
+    /// 계산 기간 반환
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 상단 값 반환
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// 하단 값 반환
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// 중심선 (상단과 하단의 중간값)
+    pub fn middle(&self) -> f64 {
+        (self.upper + self.lower) / 2.0
+    }
+
+    /// 채널 폭 (상단 - 하단)
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+}
+
+/// 돈치안 채널 계산 빌더
+///
+/// 최고가/최저가 계산은 기존 MAX/MIN 빌더에 맡깁니다.
+#[derive(Debug)]
+pub struct DonchianChannelBuilder<C: Candle> {
+    /// 계산 기간
+    period: usize,
+    /// 상단 계산용 최고가 빌더
+    max_builder: MAXBuilder<C>,
+    /// 하단 계산용 최저가 빌더
+    min_builder: MINBuilder<C>,
+}
+
+impl<C: Candle> DonchianChannelBuilder<C> {
+    /// 새 돈치안 채널 빌더 생성
+    ///
+    /// # Arguments
+    /// * `period` - 계산 기간 (일반적으로 20)
+    ///
+    /// # Returns
+    /// * `DonchianChannelBuilder` - 새 빌더 인스턴스
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 돈치안 채널 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 계산 기간 (일반적으로 20)
+    ///
+    /// # Returns
+    /// * `IndicatorResult<DonchianChannelBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("돈치안 채널 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            max_builder: MAXBuilder::new_checked(period)?,
+            min_builder: MINBuilder::new_checked(period)?,
+        })
+    }
+
+    /// 저장소에서 돈치안 채널 지표 생성
+    ///
+    /// # Arguments
+    /// * `storage` - 캔들 데이터 저장소
+    ///
+    /// # Returns
+    /// * `DonchianChannel` - 계산된 돈치안 채널 지표
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> DonchianChannel {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 돈치안 채널 지표 생성
+    ///
+    /// # Arguments
+    /// * `data` - 캔들 데이터 벡터
+    ///
+    /// # Returns
+    /// * `DonchianChannel` - 계산된 돈치안 채널 지표
+    pub fn build(&mut self, data: &[C]) -> DonchianChannel {
+        DonchianChannel {
+            period: self.period,
+            upper: self.max_builder.build(data).max,
+            lower: self.min_builder.build(data).min,
+        }
+    }
+
+    /// 새 캔들 데이터로 돈치안 채널 지표 업데이트
+    ///
+    /// # Arguments
+    /// * `data` - 새 캔들 데이터
+    ///
+    /// # Returns
+    /// * `DonchianChannel` - 업데이트된 돈치안 채널 지표
+    pub fn next(&mut self, data: &C) -> DonchianChannel {
+        DonchianChannel {
+            period: self.period,
+            upper: self.max_builder.next(data).max,
+            lower: self.min_builder.next(data).min,
+        }
+    }
+}
+
+impl<C: Candle> TABuilder<DonchianChannel, C> for DonchianChannelBuilder<C> {
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> DonchianChannel {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> DonchianChannel {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> DonchianChannel {
+        self.next(data)
+    }
+}
+
+/// 여러 기간의 돈치안 채널 컬렉션 타입
+pub type DonchianChannels = TAs<usize, DonchianChannel>;
+
+/// 여러 기간의 돈치안 채널 빌더 타입
+pub type DonchianChannelsBuilder<C> = TAsBuilder<usize, DonchianChannel, C>;
+
+/// 돈치안 채널 컬렉션 빌더 팩토리
+pub struct DonchianChannelsBuilderFactory;
+
+impl DonchianChannelsBuilderFactory {
+    /// 여러 기간의 돈치안 채널 빌더 생성
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `DonchianChannelsBuilder` - 여러 기간의 돈치안 채널 빌더
+    pub fn build<C: Candle + 'static>(periods: &[usize]) -> DonchianChannelsBuilder<C> {
+        match Self::build_checked(periods) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 여러 기간의 돈치안 채널 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `IndicatorResult<DonchianChannelsBuilder>` - 여러 기간의 돈치안 채널 빌더 또는 에러
+    pub fn build_checked<C: Candle + 'static>(
+        periods: &[usize],
+    ) -> IndicatorResult<DonchianChannelsBuilder<C>> {
+        for period in periods {
+            DonchianChannelBuilder::<C>::new_checked(*period)?;
+        }
+
+        Ok(DonchianChannelsBuilder::new(
+            "donchian_channels".to_owned(),
+            periods,
+            |period| Box::new(DonchianChannelBuilder::<C>::new(*period)),
+        ))
+    }
+
+    /// 기본 돈치안 채널 빌더 생성 (20 기간)
+    ///
+    /// # Returns
+    /// * `DonchianChannelsBuilder` - 기본 돈치안 채널 빌더
+    pub fn build_default<C: Candle + 'static>() -> DonchianChannelsBuilder<C> {
+        Self::build(&[20])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn candle(timestamp: i64, high: f64, low: f64) -> TestCandle {
+        TestCandle {
+            timestamp,
+            open: (high + low) / 2.0,
+            high,
+            low,
+            close: (high + low) / 2.0,
+            volume: 1000.0,
+        }
+    }
+
+    #[test]
+    fn test_donchian_known_values() {
+        let candles = vec![
+            candle(1, 110.0, 100.0),
+            candle(2, 120.0, 105.0),
+            candle(3, 115.0, 95.0),
+            candle(4, 112.0, 102.0),
+        ];
+
+        let mut builder = DonchianChannelBuilder::<TestCandle>::new(3);
+        let channel = builder.build(&candles);
+
+        // 최근 3개: 고가 120/115/112, 저가 105/95/102
+        assert_eq!(channel.period(), 3);
+        assert_eq!(channel.upper, 120.0);
+        assert_eq!(channel.lower, 95.0);
+        assert_eq!(channel.middle(), 107.5);
+        assert_eq!(channel.width(), 25.0);
+    }
+
+    #[test]
+    fn test_donchian_next_drops_old_extremes() {
+        let mut builder = DonchianChannelBuilder::<TestCandle>::new(2);
+        builder.next(&candle(1, 130.0, 90.0));
+        builder.next(&candle(2, 110.0, 100.0));
+        let channel = builder.next(&candle(3, 112.0, 101.0));
+
+        assert_eq!(channel.upper, 112.0);
+        assert_eq!(channel.lower, 100.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "돈치안 채널 기간은 0보다 커야 합니다")]
+    fn test_donchian_invalid_period() {
+        DonchianChannelBuilder::<TestCandle>::new(0);
+    }
+
+    #[test]
+    fn test_donchian_channels_builder() {
+        let mut builder = DonchianChannelsBuilderFactory::build::<TestCandle>(&[2, 3]);
+        let candles = vec![
+            candle(1, 110.0, 100.0),
+            candle(2, 120.0, 105.0),
+            candle(3, 115.0, 108.0),
+        ];
+
+        let channels = builder.build(&candles);
+        assert_eq!(channels.get(&2).upper, 120.0);
+        assert_eq!(channels.get(&2).lower, 105.0);
+        assert_eq!(channels.get(&3).lower, 100.0);
+    }
+}
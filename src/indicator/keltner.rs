@@ -0,0 +1,288 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::atr::ATRBuilder;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::ema::EMABuilder;
+use crate::indicator::{IndicatorResult, TABuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 켈트너 채널 기술적 지표
+///
+/// 종가 EMA를 중심선으로, ATR에 승수를 곱한 폭만큼 위아래에 밴드를 둡니다.
+#[derive(Clone, Debug)]
+pub struct KeltnerChannel {
+    /// 중심선 (종가 EMA)
+    pub middle: f64,
+    /// 상단 밴드
+    pub upper: f64,
+    /// 하단 밴드
+    pub lower: f64,
+    /// EMA 계산 기간
+    period: usize,
+    /// ATR 계산 기간
+    atr_period: usize,
+    /// ATR 승수
+    multiplier: f64,
+}
+
+impl KeltnerChannel {
+    /// 중심선 값 반환
+    pub fn middle(&self) -> f64 {
+        self.middle
+    }
+
+    /// 상단 밴드 값 반환
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// 하단 밴드 값 반환
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// EMA 계산 기간 반환
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// ATR 계산 기간 반환
+    pub fn atr_period(&self) -> usize {
+        self.atr_period
+    }
+
+    /// ATR 승수 반환
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    /// 채널 폭 (상단 - 하단)
+    ///
+    /// # Returns
+    /// * `f64` - 채널 폭
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+}
+
+impl Display for KeltnerChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "KC({},{},{}: {:.2}, {:.2}, {:.2})",
+            self.period, self.atr_period, self.multiplier, self.middle, self.upper, self.lower
+        )
+    }
+}
+
+/// 켈트너 채널 계산 빌더
+///
+/// 기존 EMA 빌더와 ATR 빌더를 조합해 채널을 계산합니다.
+#[derive(Debug)]
+pub struct KeltnerChannelBuilder<C: Candle> {
+    /// EMA 계산 기간
+    period: usize,
+    /// ATR 계산 기간
+    atr_period: usize,
+    /// ATR 승수
+    multiplier: f64,
+    /// 중심선 EMA 빌더
+    ema_builder: EMABuilder<C>,
+    /// 밴드 폭 ATR 빌더
+    atr_builder: ATRBuilder<C>,
+}
+
+impl<C> KeltnerChannelBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 켈트너 채널 빌더 생성
+    ///
+    /// # Arguments
+    /// * `period` - 중심선 EMA 기간 (일반적으로 20)
+    /// * `atr_period` - ATR 기간 (일반적으로 10)
+    /// * `multiplier` - ATR 승수 (일반적으로 2.0)
+    ///
+    /// # Returns
+    /// * `KeltnerChannelBuilder` - 새 빌더 인스턴스
+    ///
+    /// # Panics
+    /// * 유효하지 않은 매개변수가 제공되면 패닉 발생
+    pub fn new(period: usize, atr_period: usize, multiplier: f64) -> Self {
+        match Self::new_checked(period, atr_period, multiplier) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 켈트너 채널 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 중심선 EMA 기간 (일반적으로 20)
+    /// * `atr_period` - ATR 기간 (일반적으로 10)
+    /// * `multiplier` - ATR 승수 (일반적으로 2.0)
+    ///
+    /// # Returns
+    /// * `IndicatorResult<KeltnerChannelBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize, atr_period: usize, multiplier: f64) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("켈트너 채널 기간은 0보다 커야 합니다".to_string());
+        }
+
+        if atr_period == 0 {
+            return Err("켈트너 채널 ATR 기간은 0보다 커야 합니다".to_string());
+        }
+
+        if multiplier <= 0.0 {
+            return Err("켈트너 채널 승수는 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            atr_period,
+            multiplier,
+            ema_builder: EMABuilder::new_checked(period)?,
+            atr_builder: ATRBuilder::new_checked(atr_period)?,
+        })
+    }
+
+    /// 저장소에서 켈트너 채널 지표 생성
+    ///
+    /// # Arguments
+    /// * `storage` - 캔들 데이터 저장소
+    ///
+    /// # Returns
+    /// * `KeltnerChannel` - 계산된 켈트너 채널 지표
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> KeltnerChannel {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 켈트너 채널 지표 생성
+    ///
+    /// # Arguments
+    /// * `data` - 캔들 데이터 벡터
+    ///
+    /// # Returns
+    /// * `KeltnerChannel` - 계산된 켈트너 채널 지표
+    pub fn build(&mut self, data: &[C]) -> KeltnerChannel {
+        // 내부 빌더 초기화
+        self.ema_builder = EMABuilder::new(self.period);
+        self.atr_builder = ATRBuilder::new(self.atr_period);
+
+        let mut channel = self.channel(0.0, 0.0);
+        for candle in data {
+            channel = self.next(candle);
+        }
+        channel
+    }
+
+    /// 새 캔들 데이터로 켈트너 채널 지표 업데이트
+    ///
+    /// # Arguments
+    /// * `data` - 새 캔들 데이터
+    ///
+    /// # Returns
+    /// * `KeltnerChannel` - 업데이트된 켈트너 채널 지표
+    pub fn next(&mut self, data: &C) -> KeltnerChannel {
+        let middle = self.ema_builder.next(data).get();
+        let atr = self.atr_builder.next(data).value();
+        self.channel(middle, atr)
+    }
+
+    /// 중심선과 ATR로 채널 구성
+    fn channel(&self, middle: f64, atr: f64) -> KeltnerChannel {
+        let offset = atr * self.multiplier;
+        KeltnerChannel {
+            middle,
+            upper: middle + offset,
+            lower: middle - offset,
+            period: self.period,
+            atr_period: self.atr_period,
+            multiplier: self.multiplier,
+        }
+    }
+}
+
+impl<C> TABuilder<KeltnerChannel, C> for KeltnerChannelBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> KeltnerChannel {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> KeltnerChannel {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> KeltnerChannel {
+        self.next(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles() -> Vec<TestCandle> {
+        (0..6)
+            .map(|i| TestCandle {
+                timestamp: i + 1,
+                open: 100.0,
+                high: 105.0,
+                low: 95.0,
+                close: 100.0,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    #[should_panic(expected = "켈트너 채널 승수는 0보다 커야 합니다")]
+    fn test_keltner_builder_new_invalid_multiplier() {
+        KeltnerChannelBuilder::<TestCandle>::new(20, 10, 0.0);
+    }
+
+    #[test]
+    fn test_keltner_build_empty_data() {
+        let mut builder = KeltnerChannelBuilder::<TestCandle>::new(20, 10, 2.0);
+        let channel = builder.build(&[]);
+
+        assert_eq!(channel.period(), 20);
+        assert_eq!(channel.atr_period(), 10);
+        assert_eq!(channel.middle, 0.0);
+        assert_eq!(channel.width(), 0.0);
+    }
+
+    #[test]
+    fn test_keltner_known_values() {
+        // 종가 100, 고가-저가 10인 횡보 데이터
+        // EMA = 100, ATR(2) = 10 → 상단 100 + 2 * 10, 하단 100 - 2 * 10
+        let mut builder = KeltnerChannelBuilder::<TestCandle>::new(3, 2, 2.0);
+        let channel = builder.build(&create_test_candles());
+
+        assert!((channel.middle - 100.0).abs() < 1e-9);
+        assert!((channel.upper - 120.0).abs() < 1e-9);
+        assert!((channel.lower - 80.0).abs() < 1e-9);
+        assert!((channel.width() - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_keltner_build_matches_next() {
+        let candles = create_test_candles();
+        let mut incremental = KeltnerChannelBuilder::<TestCandle>::new(3, 2, 1.5);
+        let mut last = None;
+        for candle in &candles {
+            last = Some(incremental.next(candle));
+        }
+
+        let mut builder = KeltnerChannelBuilder::<TestCandle>::new(3, 2, 1.5);
+        let built = builder.build(&candles);
+        let last = last.unwrap();
+
+        assert_eq!(built.middle, last.middle);
+        assert_eq!(built.upper, last.upper);
+        assert_eq!(built.lower, last.lower);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::smoothing::SmoothingState;
+use crate::indicator::utils::moving_average;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 이중지수이동평균(DEMA) 계산 빌더
+///
+/// `2 * EMA - EMA(EMA)`로 EMA의 지연을 줄인 이동평균입니다.
+/// 각 EMA는 첫 `period`개 값을 단순이동평균으로 시작합니다.
+#[derive(Debug)]
+pub struct DEMABuilder<C: Candle> {
+    /// DEMA 계산 기간
+    pub period: usize,
+    /// 종가 EMA 상태
+    ema: SmoothingState,
+    /// EMA의 EMA 상태
+    ema_of_ema: SmoothingState,
+    _phantom: PhantomData<C>,
+}
+
+/// DEMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DEMABuilderState {
+    /// DEMA 계산 기간
+    pub period: usize,
+    /// 종가 EMA 상태
+    pub ema: SmoothingState,
+    /// EMA의 EMA 상태
+    pub ema_of_ema: SmoothingState,
+}
+
+/// 이중지수이동평균(DEMA) 기술적 지표
+#[derive(Clone, Debug)]
+pub struct DEMA {
+    /// DEMA 계산 기간
+    period: usize,
+    /// 계산된 DEMA 값
+    dema: f64,
+}
+
+impl Display for DEMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DEMA({}: {:.2})", self.period, self.dema)
+    }
+}
+
+impl MA for DEMA {
+    fn get(&self) -> f64 {
+        self.dema
+    }
+
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<C> DEMABuilder<C>
+where
+    C: Candle,
+{
+    /// 새 DEMA 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 DEMA 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - DEMA 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<DEMABuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("DEMA 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            ema: SmoothingState::default(),
+            ema_of_ema: SmoothingState::default(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 DEMA 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> DEMA {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 DEMA 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> DEMA {
+        self.ema = SmoothingState::default();
+        self.ema_of_ema = SmoothingState::default();
+
+        let mut dema = DEMA {
+            period: self.period,
+            dema: 0.0,
+        };
+        for item in data {
+            dema = self.next(item);
+        }
+        dema
+    }
+
+    /// 새 캔들 데이터로 DEMA 지표 업데이트
+    pub fn next(&mut self, data: &C) -> DEMA {
+        let alpha = moving_average::calculate_ema_alpha(self.period);
+        let ema = self.ema.next(self.period, alpha, data.close_price());
+        let ema_of_ema = self.ema_of_ema.next(self.period, alpha, ema);
+
+        DEMA {
+            period: self.period,
+            dema: 2.0 * ema - ema_of_ema,
+        }
+    }
+}
+
+impl<C> TABuilder<Box<dyn MA>, C> for DEMABuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Box<dyn MA> {
+        Box::new(self.build_from_storage(storage))
+    }
+
+    fn build(&mut self, data: &[C]) -> Box<dyn MA> {
+        Box::new(self.build(data))
+    }
+
+    fn next(&mut self, data: &C) -> Box<dyn MA> {
+        Box::new(self.next(data))
+    }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Dema(DEMABuilderState {
+            period: self.period,
+            ema: self.ema.clone(),
+            ema_of_ema: self.ema_of_ema.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Dema(state) = state else {
+            return Err(state_kind_mismatch("DEMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "DEMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.ema = state.ema.clone();
+        self.ema_of_ema = state.ema_of_ema.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dema_tracks_trend_closer_than_ema() {
+        // 일정하게 상승하면 DEMA는 EMA보다 최신 가격에 가까움
+        let closes: Vec<f64> = (0..30).map(|i| 100.0 + i as f64).collect();
+        let candles = create_test_candles(&closes);
+
+        let dema = DEMABuilder::new(5).build(&candles);
+        let ema = crate::indicator::ma::ema::EMABuilder::new(5).build(&candles);
+        assert_eq!(dema.period(), 5);
+        assert!(dema.get() > ema.get());
+        assert!((dema.get() - 129.0).abs() < 0.5);
+        assert!(dema.to_string().contains("DEMA"));
+
+        // 가격이 일정하면 그 가격
+        let flat = DEMABuilder::new(3).build(&create_test_candles(&[50.0; 10]));
+        assert!((flat.get() - 50.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_dema_next_matches_build_and_restores() {
+        let candles = create_test_candles(&[10.0, 12.0, 11.0, 15.0, 14.0, 18.0, 17.0]);
+        let mut builder = DEMABuilder::new(3);
+        builder.build(&candles[..5]);
+
+        let mut restored = DEMABuilder::<TestCandle>::new(3);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = DEMABuilder::new(3).build(&candles).get();
+        builder.next(&candles[5]);
+        restored.next(&candles[5]);
+        assert!((builder.next(&candles[6]).get() - expected).abs() < 1e-10);
+        assert!((restored.next(&candles[6]).get() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "DEMA 기간은 0보다 커야 합니다")]
+    fn test_dema_invalid_period() {
+        DEMABuilder::<TestCandle>::new(0);
+    }
+}
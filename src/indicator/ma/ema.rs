@@ -1,7 +1,10 @@
 use crate::candle_store::CandleStore;
 use crate::indicator::ma::MA;
 use crate::indicator::utils::moving_average;
-use crate::indicator::{IndicatorResult, TABuilder};
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use trading_chart::Candle;
@@ -25,6 +28,17 @@ pub struct EMABuilder<C: Candle> {
     _phantom: PhantomData<C>,
 }
 
+/// EMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EMABuilderState {
+    /// EMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period * 2개)
+    pub values: Vec<f64>,
+    /// 이전 EMA 값
+    pub previous_ema: Option<f64>,
+}
+
 /// 지수이동평균(EMA) 기술적 지표
 ///
 /// 계산된 EMA 값을 저장하고 제공합니다.
@@ -228,6 +242,32 @@ where
     fn next(&mut self, data: &C) -> Box<dyn MA> {
         Box::new(self.next(data))
     }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Ema(EMABuilderState {
+            period: self.period,
+            values: self.values.clone(),
+            previous_ema: self.previous_ema,
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Ema(state) = state else {
+            return Err(state_kind_mismatch("EMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "EMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.values = state.values.clone();
+        self.previous_ema = state.previous_ema;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -634,4 +674,33 @@ mod tests {
             diff_percent
         );
     }
+
+    #[test]
+    fn test_ema_restore_keeps_previous_ema() {
+        let candles: Vec<TestCandle> = (0..40)
+            .map(|i| TestCandle {
+                timestamp: i,
+                close: 100.0 + (i % 9) as f64 * 1.5,
+                ..TestCandle::default()
+            })
+            .collect();
+
+        let mut builder = EMABuilder::<TestCandle>::new(10);
+        for candle in &candles[..30] {
+            builder.next(candle);
+        }
+
+        let mut restored = EMABuilder::<TestCandle>::new(10);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        // 이전 EMA 값을 이어받으므로 재계산 없이 값이 정확히 같아야 함
+        for candle in &candles[30..] {
+            assert_eq!(builder.next(candle).get(), restored.next(candle).get());
+        }
+
+        let sma_state = crate::indicator::ma::sma::SMABuilder::<TestCandle>::new(10)
+            .snapshot()
+            .unwrap();
+        assert!(restored.restore(&sma_state).is_err());
+    }
 }
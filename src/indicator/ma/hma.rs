@@ -0,0 +1,257 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::smoothing;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 헐이동평균(HMA) 계산 빌더
+///
+/// `WMA(2 * WMA(period / 2) - WMA(period), sqrt(period))`로 계산하는 지연이 적은 이동평균입니다.
+/// 데이터가 기간보다 적으면 있는 데이터만으로 가중 평균을 계산합니다.
+#[derive(Debug)]
+pub struct HMABuilder<C: Candle> {
+    /// HMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period개)
+    values: Vec<f64>,
+    /// 최근 `2 * WMA(period / 2) - WMA(period)` 값 (최대 sqrt(period)개)
+    raw_values: Vec<f64>,
+    _phantom: PhantomData<C>,
+}
+
+/// HMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HMABuilderState {
+    /// HMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period개)
+    pub values: Vec<f64>,
+    /// 최근 `2 * WMA(period / 2) - WMA(period)` 값 (최대 sqrt(period)개)
+    pub raw_values: Vec<f64>,
+}
+
+/// 헐이동평균(HMA) 기술적 지표
+#[derive(Clone, Debug)]
+pub struct HMA {
+    /// HMA 계산 기간
+    period: usize,
+    /// 계산된 HMA 값
+    hma: f64,
+}
+
+impl Display for HMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HMA({}: {:.2})", self.period, self.hma)
+    }
+}
+
+impl MA for HMA {
+    fn get(&self) -> f64 {
+        self.hma
+    }
+
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<C> HMABuilder<C>
+where
+    C: Candle,
+{
+    /// 새 HMA 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 HMA 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - HMA 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<HMABuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("HMA 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            values: Vec::with_capacity(period),
+            raw_values: Vec::with_capacity(Self::sqrt_period(period)),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 절반 기간 (최소 1)
+    fn half_period(period: usize) -> usize {
+        (period / 2).max(1)
+    }
+
+    /// 최종 가중 평균 기간 (제곱근, 최소 1)
+    fn sqrt_period(period: usize) -> usize {
+        period.isqrt().max(1)
+    }
+
+    /// 저장소에서 HMA 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> HMA {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 HMA 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> HMA {
+        self.values.clear();
+        self.raw_values.clear();
+
+        let mut hma = HMA {
+            period: self.period,
+            hma: 0.0,
+        };
+        for item in data {
+            hma = self.next(item);
+        }
+        hma
+    }
+
+    /// 새 캔들 데이터로 HMA 지표 업데이트
+    pub fn next(&mut self, data: &C) -> HMA {
+        self.values.push(data.close_price());
+        smoothing::truncate_front(&mut self.values, self.period);
+
+        let half_wma = smoothing::weighted_average(smoothing::tail(
+            &self.values,
+            Self::half_period(self.period),
+        ));
+        let full_wma = smoothing::weighted_average(&self.values);
+        self.raw_values.push(2.0 * half_wma - full_wma);
+
+        let sqrt_period = Self::sqrt_period(self.period);
+        smoothing::truncate_front(&mut self.raw_values, sqrt_period);
+
+        HMA {
+            period: self.period,
+            hma: smoothing::weighted_average(&self.raw_values),
+        }
+    }
+}
+
+impl<C> TABuilder<Box<dyn MA>, C> for HMABuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Box<dyn MA> {
+        Box::new(self.build_from_storage(storage))
+    }
+
+    fn build(&mut self, data: &[C]) -> Box<dyn MA> {
+        Box::new(self.build(data))
+    }
+
+    fn next(&mut self, data: &C) -> Box<dyn MA> {
+        Box::new(self.next(data))
+    }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Hma(HMABuilderState {
+            period: self.period,
+            values: self.values.clone(),
+            raw_values: self.raw_values.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Hma(state) = state else {
+            return Err(state_kind_mismatch("HMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "HMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.values = state.values.clone();
+        self.raw_values = state.raw_values.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_hma_exact_calculation() {
+        // 기간 4: WMA(2), WMA(4), 최종 WMA(2)
+        let candles = create_test_candles(&[10.0, 20.0, 30.0, 40.0, 50.0]);
+        let hma = HMABuilder::new(4).build(&candles);
+
+        // 마지막 두 캔들의 보정 값
+        let raw_4 = 2.0 * (30.0 + 40.0 * 2.0) / 3.0 - 30.0;
+        let raw_5 = 2.0 * (40.0 + 50.0 * 2.0) / 3.0 - 40.0;
+        let expected = (raw_4 + raw_5 * 2.0) / 3.0;
+        assert_eq!(hma.period(), 4);
+        assert!((hma.get() - expected).abs() < 1e-10);
+        assert!(hma.to_string().contains("HMA"));
+    }
+
+    #[test]
+    fn test_hma_tracks_trend_without_lag() {
+        // 일정하게 상승하면 HMA는 최신 가격에 가까움
+        let closes: Vec<f64> = (0..40).map(|i| 100.0 + i as f64).collect();
+        let hma = HMABuilder::new(9).build(&create_test_candles(&closes));
+        assert!((hma.get() - 139.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_hma_next_matches_build_and_restores() {
+        let candles = create_test_candles(&[10.0, 12.0, 11.0, 15.0, 14.0, 18.0, 17.0]);
+        let mut builder = HMABuilder::new(4);
+        builder.build(&candles[..5]);
+
+        let mut restored = HMABuilder::<TestCandle>::new(4);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = HMABuilder::new(4).build(&candles).get();
+        builder.next(&candles[5]);
+        restored.next(&candles[5]);
+        assert!((builder.next(&candles[6]).get() - expected).abs() < 1e-10);
+        assert!((restored.next(&candles[6]).get() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "HMA 기간은 0보다 커야 합니다")]
+    fn test_hma_invalid_period() {
+        HMABuilder::<TestCandle>::new(0);
+    }
+}
@@ -0,0 +1,272 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::smoothing;
+use crate::indicator::utils::moving_average;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 빠른 평활 기간 (Kaufman 기본값)
+const FAST_PERIOD: usize = 2;
+/// 느린 평활 기간 (Kaufman 기본값)
+const SLOW_PERIOD: usize = 30;
+
+/// 카우프만적응이동평균(KAMA) 계산 빌더
+///
+/// `period` 캔들 동안의 효율성 비율(순변화 / 변화량 합)에 따라
+/// 평활 계수를 EMA(2)와 EMA(30) 사이에서 조절합니다. 추세가 뚜렷하면 빠르게, 횡보하면 느리게 따라갑니다.
+/// 첫 `period`개 캔들은 단순이동평균으로 시작합니다.
+#[derive(Debug)]
+pub struct KAMABuilder<C: Candle> {
+    /// 효율성 비율 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period + 1개)
+    values: Vec<f64>,
+    /// 이전 KAMA 값
+    previous_kama: Option<f64>,
+    _phantom: PhantomData<C>,
+}
+
+/// KAMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KAMABuilderState {
+    /// 효율성 비율 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period + 1개)
+    pub values: Vec<f64>,
+    /// 이전 KAMA 값
+    pub previous_kama: Option<f64>,
+}
+
+/// 카우프만적응이동평균(KAMA) 기술적 지표
+#[derive(Clone, Debug)]
+pub struct KAMA {
+    /// 효율성 비율 계산 기간
+    period: usize,
+    /// 계산된 KAMA 값
+    kama: f64,
+}
+
+impl Display for KAMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "KAMA({}: {:.2})", self.period, self.kama)
+    }
+}
+
+impl MA for KAMA {
+    fn get(&self) -> f64 {
+        self.kama
+    }
+
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<C> KAMABuilder<C>
+where
+    C: Candle,
+{
+    /// 새 KAMA 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 KAMA 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 효율성 비율 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<KAMABuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("KAMA 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            values: Vec::with_capacity(period + 1),
+            previous_kama: None,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 KAMA 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> KAMA {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 KAMA 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> KAMA {
+        self.values.clear();
+        self.previous_kama = None;
+
+        let mut kama = KAMA {
+            period: self.period,
+            kama: 0.0,
+        };
+        for item in data {
+            kama = self.next(item);
+        }
+        kama
+    }
+
+    /// 새 캔들 데이터로 KAMA 지표 업데이트
+    pub fn next(&mut self, data: &C) -> KAMA {
+        let price = data.close_price();
+        self.values.push(price);
+        smoothing::truncate_front(&mut self.values, self.period + 1);
+
+        let kama = match self.previous_kama {
+            Some(previous) if self.values.len() > self.period => {
+                let change = (price - self.values[0]).abs();
+                let volatility: f64 = self.values.windows(2).map(|w| (w[1] - w[0]).abs()).sum();
+                let efficiency_ratio = if volatility > 0.0 {
+                    change / volatility
+                } else {
+                    0.0
+                };
+
+                let fast = moving_average::calculate_ema_alpha(FAST_PERIOD);
+                let slow = moving_average::calculate_ema_alpha(SLOW_PERIOD);
+                let smoothing_constant = (efficiency_ratio * (fast - slow) + slow).powi(2);
+                previous + smoothing_constant * (price - previous)
+            }
+            // 데이터가 부족하면 단순이동평균
+            _ => self.values.iter().sum::<f64>() / self.values.len() as f64,
+        };
+        self.previous_kama = Some(kama);
+
+        KAMA {
+            period: self.period,
+            kama,
+        }
+    }
+}
+
+impl<C> TABuilder<Box<dyn MA>, C> for KAMABuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Box<dyn MA> {
+        Box::new(self.build_from_storage(storage))
+    }
+
+    fn build(&mut self, data: &[C]) -> Box<dyn MA> {
+        Box::new(self.build(data))
+    }
+
+    fn next(&mut self, data: &C) -> Box<dyn MA> {
+        Box::new(self.next(data))
+    }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Kama(KAMABuilderState {
+            period: self.period,
+            values: self.values.clone(),
+            previous_kama: self.previous_kama,
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Kama(state) = state else {
+            return Err(state_kind_mismatch("KAMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "KAMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.values = state.values.clone();
+        self.previous_kama = state.previous_kama;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_kama_exact_calculation() {
+        // 기간 2: 시작값 SMA(10, 20) = 15, 효율성 비율 1 → 평활 계수 (2 / 3)^2
+        let candles = create_test_candles(&[10.0, 20.0, 30.0]);
+        let kama = KAMABuilder::new(2).build(&candles);
+
+        let expected = 15.0 + (2.0_f64 / 3.0).powi(2) * (30.0 - 15.0);
+        assert_eq!(kama.period(), 2);
+        assert!((kama.get() - expected).abs() < 1e-10);
+        assert!(kama.to_string().contains("KAMA"));
+    }
+
+    #[test]
+    fn test_kama_adapts_to_efficiency() {
+        // 같은 가격에서 끝나도 등락이 심하면(효율성 비율이 낮으면) 덜 따라감
+        let trend = create_test_candles(&[
+            100.0, 100.0, 100.0, 100.0, 102.0, 104.0, 106.0, 108.0, 110.0,
+        ]);
+        let choppy =
+            create_test_candles(&[100.0, 100.0, 100.0, 100.0, 110.0, 96.0, 106.0, 100.0, 110.0]);
+
+        let trend_kama = KAMABuilder::new(3).build(&trend);
+        let choppy_kama = KAMABuilder::new(3).build(&choppy);
+        assert!(trend_kama.get() < 110.0);
+        assert!(trend_kama.get() > choppy_kama.get());
+
+        let flat = KAMABuilder::new(3).build(&create_test_candles(&[50.0; 6]));
+        assert!((flat.get() - 50.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_kama_next_matches_build_and_restores() {
+        let candles = create_test_candles(&[10.0, 12.0, 11.0, 15.0, 14.0, 18.0, 17.0]);
+        let mut builder = KAMABuilder::new(3);
+        builder.build(&candles[..5]);
+
+        let mut restored = KAMABuilder::<TestCandle>::new(3);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = KAMABuilder::new(3).build(&candles).get();
+        builder.next(&candles[5]);
+        restored.next(&candles[5]);
+        assert!((builder.next(&candles[6]).get() - expected).abs() < 1e-10);
+        assert!((restored.next(&candles[6]).get() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "KAMA 기간은 0보다 커야 합니다")]
+    fn test_kama_invalid_period() {
+        KAMABuilder::<TestCandle>::new(0);
+    }
+}
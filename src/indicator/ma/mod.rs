@@ -1,16 +1,34 @@
+pub mod dema;
 pub mod ema;
+pub mod hma;
+pub mod kama;
 pub mod sma;
+pub mod smma;
+pub mod smoothing;
+pub mod tema;
+pub mod vwma;
 pub mod wma;
+pub mod zlema;
 
 use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use dema::DEMABuilder;
 use ema::EMABuilder;
+use hma::HMABuilder;
+use kama::KAMABuilder;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
 use sma::SMABuilder;
+use smma::SMMABuilder;
 use std::fmt::Debug;
 use std::fmt::Display;
+use std::str::FromStr;
+use tema::TEMABuilder;
 use trading_chart::Candle;
+use vwma::VWMABuilder;
 use wma::WMABuilder;
+use zlema::ZLEMABuilder;
 
 /// 이동평균(Moving Average) 인터페이스
 ///
@@ -29,6 +47,57 @@ pub trait MA: Display + Send + Debug {
     fn get(&self) -> f64;
 }
 
+/// 계산이 끝난 이동평균 값
+///
+/// 빌더 없이 값만 보관하면 되는 경우(데이터 복제, 스냅샷 복원 등)에 사용합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MAValue {
+    /// 이동평균 기간
+    pub period: usize,
+    /// 이동평균 값
+    pub value: f64,
+}
+
+impl MAValue {
+    /// 다른 이동평균의 현재 값 복사
+    pub fn of(ma: &dyn MA) -> MAValue {
+        MAValue {
+            period: ma.period(),
+            value: ma.get(),
+        }
+    }
+}
+
+impl MA for MAValue {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn get(&self) -> f64 {
+        self.value
+    }
+}
+
+impl Display for MAValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MA({}: {:.2})", self.period, self.value)
+    }
+}
+
+/// 이동평균은 구현체와 무관하게 기간과 값으로 직렬화
+impl Serialize for dyn MA {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MAValue::of(self).serialize(serializer)
+    }
+}
+
+/// 역직렬화한 이동평균은 값만 보관하는 `MAValue`로 복원
+impl<'de> Deserialize<'de> for Box<dyn MA> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Box::new(MAValue::deserialize(deserializer)?))
+    }
+}
+
 /// 이동평균 계산 방식
 ///
 /// 시스템에서 지원하는 이동평균 유형을 정의합니다.
@@ -43,6 +112,43 @@ pub enum MAType {
     /// 가중이동평균 (Weighted Moving Average)
     /// 최근 데이터에 선형적으로 증가하는 가중치를 부여합니다.
     WMA,
+    /// 헐이동평균 (Hull Moving Average)
+    /// 가중이동평균을 조합해 지연을 줄입니다.
+    HMA,
+    /// 이중지수이동평균 (Double Exponential Moving Average)
+    /// EMA와 EMA의 EMA를 조합해 지연을 줄입니다.
+    DEMA,
+    /// 삼중지수이동평균 (Triple Exponential Moving Average)
+    /// EMA를 세 번 겹쳐 조합해 지연을 줄입니다.
+    TEMA,
+    /// 카우프만적응이동평균 (Kaufman Adaptive Moving Average)
+    /// 가격 효율성에 따라 평활 정도를 조절합니다.
+    KAMA,
+    /// 제로랙지수이동평균 (Zero Lag Exponential Moving Average)
+    /// 지연만큼 가격을 보정한 뒤 EMA를 계산합니다.
+    ZLEMA,
+    /// 거래량가중이동평균 (Volume Weighted Moving Average)
+    /// 거래량이 많은 캔들에 큰 가중치를 부여합니다.
+    VWMA,
+    /// 평활이동평균 (Smoothed/Wilder Moving Average)
+    /// 새 데이터를 `1 / 기간` 비율로 반영합니다.
+    SMMA,
+}
+
+impl MAType {
+    /// 지원하는 모든 이동평균 유형
+    pub const ALL: [MAType; 10] = [
+        MAType::EMA,
+        MAType::SMA,
+        MAType::WMA,
+        MAType::HMA,
+        MAType::DEMA,
+        MAType::TEMA,
+        MAType::KAMA,
+        MAType::ZLEMA,
+        MAType::VWMA,
+        MAType::SMMA,
+    ];
 }
 
 impl Display for MAType {
@@ -51,10 +157,34 @@ impl Display for MAType {
             MAType::EMA => write!(f, "EMA"),
             MAType::SMA => write!(f, "SMA"),
             MAType::WMA => write!(f, "WMA"),
+            MAType::HMA => write!(f, "HMA"),
+            MAType::DEMA => write!(f, "DEMA"),
+            MAType::TEMA => write!(f, "TEMA"),
+            MAType::KAMA => write!(f, "KAMA"),
+            MAType::ZLEMA => write!(f, "ZLEMA"),
+            MAType::VWMA => write!(f, "VWMA"),
+            MAType::SMMA => write!(f, "SMMA"),
         }
     }
 }
 
+/// 대소문자 구분 없이 이동평균 유형 이름 파싱 (`RMA`는 `SMMA`와 같음)
+impl FromStr for MAType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.trim().to_uppercase();
+        if upper == "RMA" {
+            return Ok(MAType::SMMA);
+        }
+
+        MAType::ALL
+            .into_iter()
+            .find(|ma_type| ma_type.to_string() == upper)
+            .ok_or_else(|| format!("알 수 없는 이동평균 유형: {s}"))
+    }
+}
+
 /// 이동평균 빌더 팩토리
 ///
 /// 지정된 유형의 이동평균 빌더를 생성합니다.
@@ -64,7 +194,7 @@ impl MABuilderFactory {
     /// 이동평균 유형과 기간에 따른 빌더 생성
     ///
     /// # Arguments
-    /// * `ma_type` - 이동평균 유형 (EMA, SMA, WMA)
+    /// * `ma_type` - 이동평균 유형
     /// * `period` - 이동평균 계산 기간
     ///
     /// # Returns
@@ -82,7 +212,7 @@ impl MABuilderFactory {
     /// 이동평균 유형과 기간에 따른 빌더 생성 (검증 포함)
     ///
     /// # Arguments
-    /// * `ma_type` - 이동평균 유형 (EMA, SMA, WMA)
+    /// * `ma_type` - 이동평균 유형
     /// * `period` - 이동평균 계산 기간
     ///
     /// # Returns
@@ -99,6 +229,13 @@ impl MABuilderFactory {
             MAType::EMA => Box::new(EMABuilder::<C>::new_checked(period)?),
             MAType::SMA => Box::new(SMABuilder::<C>::new(period)),
             MAType::WMA => Box::new(WMABuilder::<C>::new_checked(period)?),
+            MAType::HMA => Box::new(HMABuilder::<C>::new_checked(period)?),
+            MAType::DEMA => Box::new(DEMABuilder::<C>::new_checked(period)?),
+            MAType::TEMA => Box::new(TEMABuilder::<C>::new_checked(period)?),
+            MAType::KAMA => Box::new(KAMABuilder::<C>::new_checked(period)?),
+            MAType::ZLEMA => Box::new(ZLEMABuilder::<C>::new_checked(period)?),
+            MAType::VWMA => Box::new(VWMABuilder::<C>::new_checked(period)?),
+            MAType::SMMA => Box::new(SMMABuilder::<C>::new_checked(period)?),
         };
 
         Ok(builder)
@@ -118,7 +255,7 @@ impl MAsBuilderFactory {
     /// 여러 기간의 이동평균 빌더 생성
     ///
     /// # Arguments
-    /// * `ma_type` - 이동평균 유형 (EMA, SMA, WMA)
+    /// * `ma_type` - 이동평균 유형
     /// * `periods` - 이동평균 계산 기간 목록
     ///
     /// # Returns
@@ -136,7 +273,7 @@ impl MAsBuilderFactory {
     /// 여러 기간의 이동평균 빌더 생성 (검증 포함)
     ///
     /// # Arguments
-    /// * `ma_type` - 이동평균 유형 (EMA, SMA, WMA)
+    /// * `ma_type` - 이동평균 유형
     /// * `periods` - 이동평균 계산 기간 목록
     ///
     /// # Returns
@@ -228,11 +365,93 @@ mod tests {
         assert!(wma.to_string().contains("WMA"));
     }
 
+    #[test]
+    fn test_mas_builder_restore_and_serialize() {
+        let periods = vec![2, 3];
+        let candles = create_test_candles();
+        let mut builder = MAsBuilderFactory::build::<TestCandle>(&MAType::EMA, &periods);
+        builder.build(&candles[..2]);
+
+        let mut restored = MAsBuilderFactory::build::<TestCandle>(&MAType::EMA, &periods);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = builder.next(&candles[2]);
+        let actual = restored.next(&candles[2]);
+        assert_eq!(
+            expected.get_by_key_index(1).get(),
+            actual.get_by_key_index(1).get()
+        );
+
+        // 이동평균 컬렉션은 기간과 값만으로 직렬화/복원
+        let json = serde_json::to_string(&actual).unwrap();
+        let deserialized: MAs = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.keys(), &periods);
+        assert_eq!(deserialized.get(&3).get(), actual.get_by_key_index(1).get());
+
+        // 기간 수가 다르면 복원하지 않음
+        let mut other = MAsBuilderFactory::build::<TestCandle>(&MAType::EMA, &[2]);
+        assert!(other.restore(&builder.snapshot().unwrap()).is_err());
+    }
+
     #[test]
     fn test_ma_type_display() {
         assert_eq!(MAType::EMA.to_string(), "EMA");
         assert_eq!(MAType::SMA.to_string(), "SMA");
         assert_eq!(MAType::WMA.to_string(), "WMA");
+        assert_eq!(MAType::ZLEMA.to_string(), "ZLEMA");
+    }
+
+    #[test]
+    fn test_ma_type_from_str_and_serde() {
+        for ma_type in MAType::ALL {
+            assert_eq!(MAType::from_str(&ma_type.to_string()), Ok(ma_type));
+            let json = serde_json::to_string(&ma_type).unwrap();
+            assert_eq!(serde_json::from_str::<MAType>(&json).unwrap(), ma_type);
+        }
+        assert_eq!(MAType::from_str("hma"), Ok(MAType::HMA));
+        assert_eq!(MAType::from_str("rma"), Ok(MAType::SMMA));
+        assert!(MAType::from_str("xma").is_err());
+    }
+
+    #[test]
+    fn test_ma_builder_factory_builds_every_type_incrementally() {
+        let candles: Vec<TestCandle> = (0..30)
+            .map(|i| TestCandle {
+                timestamp: i,
+                close: 100.0 + (i % 7) as f64 * 2.0,
+                volume: 1000.0 + (i % 3) as f64 * 100.0,
+                ..TestCandle::default()
+            })
+            .collect();
+
+        for ma_type in MAType::ALL {
+            let mut builder = MABuilderFactory::build::<TestCandle>(&ma_type, 5);
+            builder.build(&candles[..20]);
+            let mut restored = MABuilderFactory::build::<TestCandle>(&ma_type, 5);
+            restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+            let (mut actual, mut restored_actual) = (0.0, 0.0);
+            for candle in &candles[20..] {
+                let ma = builder.next(candle);
+                assert_eq!(ma.period(), 5, "{ma_type}");
+                actual = ma.get();
+                restored_actual = restored.next(candle).get();
+            }
+            assert!(actual > 100.0, "{ma_type}");
+            assert_eq!(actual, restored_actual, "{ma_type}");
+
+            // 새 이동평균은 전체 계산과 증분 계산 결과가 같음
+            if !matches!(ma_type, MAType::EMA | MAType::SMA | MAType::WMA) {
+                let expected = MABuilderFactory::build::<TestCandle>(&ma_type, 5)
+                    .build(&candles)
+                    .get();
+                assert!((actual - expected).abs() < 1e-9, "{ma_type}");
+            }
+            assert!(
+                MABuilderFactory::build_checked::<TestCandle>(&ma_type, 0).is_err(),
+                "{ma_type}"
+            );
+        }
     }
 
     #[test]
@@ -1,7 +1,10 @@
 use crate::candle_store::CandleStore;
-use crate::indicator::TABuilder;
 use crate::indicator::ma::MA;
 use crate::indicator::utils::moving_average;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use trading_chart::Candle;
@@ -13,6 +16,15 @@ pub struct SMABuilder<C: Candle> {
     _phantom: PhantomData<C>,
 }
 
+/// SMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SMABuilderState {
+    /// SMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period * 2개)
+    pub values: Vec<f64>,
+}
+
 #[derive(Clone, Debug)]
 pub struct SMA {
     period: usize,
@@ -110,6 +122,30 @@ where
     fn next(&mut self, data: &C) -> Box<dyn MA> {
         Box::new(self.next(data))
     }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Sma(SMABuilderState {
+            period: self.period,
+            values: self.values.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Sma(state) = state else {
+            return Err(state_kind_mismatch("SMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "SMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.values = state.values.clone();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,221 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::smoothing::SmoothingState;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 평활이동평균(SMMA) 계산 빌더
+///
+/// Wilder 방식의 이동평균으로, 새 가격을 `1 / period` 비율로 반영합니다.
+/// 첫 `period`개 캔들은 단순이동평균으로 시작합니다.
+#[derive(Debug)]
+pub struct SMMABuilder<C: Candle> {
+    /// SMMA 계산 기간
+    pub period: usize,
+    /// 평활 상태
+    smma: SmoothingState,
+    _phantom: PhantomData<C>,
+}
+
+/// SMMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SMMABuilderState {
+    /// SMMA 계산 기간
+    pub period: usize,
+    /// 평활 상태
+    pub smma: SmoothingState,
+}
+
+/// 평활이동평균(SMMA) 기술적 지표
+#[derive(Clone, Debug)]
+pub struct SMMA {
+    /// SMMA 계산 기간
+    period: usize,
+    /// 계산된 SMMA 값
+    smma: f64,
+}
+
+impl Display for SMMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SMMA({}: {:.2})", self.period, self.smma)
+    }
+}
+
+impl MA for SMMA {
+    fn get(&self) -> f64 {
+        self.smma
+    }
+
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<C> SMMABuilder<C>
+where
+    C: Candle,
+{
+    /// 새 SMMA 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 SMMA 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - SMMA 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<SMMABuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("SMMA 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            smma: SmoothingState::default(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 SMMA 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> SMMA {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 SMMA 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> SMMA {
+        self.smma = SmoothingState::default();
+
+        let mut smma = SMMA {
+            period: self.period,
+            smma: 0.0,
+        };
+        for item in data {
+            smma = self.next(item);
+        }
+        smma
+    }
+
+    /// 새 캔들 데이터로 SMMA 지표 업데이트
+    pub fn next(&mut self, data: &C) -> SMMA {
+        let alpha = 1.0 / self.period as f64;
+        let smma = self.smma.next(self.period, alpha, data.close_price());
+
+        SMMA {
+            period: self.period,
+            smma,
+        }
+    }
+}
+
+impl<C> TABuilder<Box<dyn MA>, C> for SMMABuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Box<dyn MA> {
+        Box::new(self.build_from_storage(storage))
+    }
+
+    fn build(&mut self, data: &[C]) -> Box<dyn MA> {
+        Box::new(self.build(data))
+    }
+
+    fn next(&mut self, data: &C) -> Box<dyn MA> {
+        Box::new(self.next(data))
+    }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Smma(SMMABuilderState {
+            period: self.period,
+            smma: self.smma.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Smma(state) = state else {
+            return Err(state_kind_mismatch("SMMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "SMMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.smma = state.smma.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_smma_exact_calculation() {
+        let candles = create_test_candles(&[10.0, 20.0, 30.0, 40.0]);
+        let mut builder = SMMABuilder::new(3);
+
+        // 시작값 SMA(10, 20, 30) = 20, 이후 (20 * 2 + 40) / 3
+        let smma = builder.build(&candles);
+        assert_eq!(smma.period(), 3);
+        assert!((smma.get() - 80.0 / 3.0).abs() < 1e-10);
+        assert!(smma.to_string().contains("SMMA"));
+    }
+
+    #[test]
+    fn test_smma_next_matches_build_and_restores() {
+        let candles = create_test_candles(&[10.0, 12.0, 11.0, 15.0, 14.0, 18.0]);
+        let mut builder = SMMABuilder::new(3);
+        builder.build(&candles[..4]);
+
+        let mut restored = SMMABuilder::<TestCandle>::new(3);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = SMMABuilder::new(3).build(&candles).get();
+        builder.next(&candles[4]);
+        restored.next(&candles[4]);
+        assert!((builder.next(&candles[5]).get() - expected).abs() < 1e-10);
+        assert!((restored.next(&candles[5]).get() - expected).abs() < 1e-10);
+
+        let mut other = SMMABuilder::<TestCandle>::new(4);
+        assert!(other.restore(&builder.snapshot().unwrap()).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "SMMA 기간은 0보다 커야 합니다")]
+    fn test_smma_invalid_period() {
+        SMMABuilder::<TestCandle>::new(0);
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+/// 지수 평활 증분 계산 상태
+///
+/// 첫 `period`개 값은 누적 평균(SMA)으로 시작하고, 이후 `alpha` 비율로 새 값을 반영합니다.
+/// DEMA, TEMA, ZLEMA(`alpha = 2 / (period + 1)`)와 SMMA(`alpha = 1 / period`)가 공유합니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SmoothingState {
+    /// 반영한 값 개수 (`period`까지만 증가)
+    pub count: usize,
+    /// 현재 평활 값
+    pub value: f64,
+}
+
+impl SmoothingState {
+    /// 새 값 반영
+    ///
+    /// # Arguments
+    /// * `period` - 평활 기간
+    /// * `alpha` - 평활 계수
+    /// * `value` - 새 값
+    ///
+    /// # Returns
+    /// * `f64` - 갱신된 평활 값
+    pub(crate) fn next(&mut self, period: usize, alpha: f64, value: f64) -> f64 {
+        if self.count < period {
+            self.count += 1;
+            self.value += (value - self.value) / self.count as f64;
+        } else {
+            self.value += alpha * (value - self.value);
+        }
+        self.value
+    }
+}
+
+/// 선형 가중 평균 (마지막 값의 가중치가 가장 큼)
+///
+/// # Arguments
+/// * `values` - 시간 오름차순 값
+///
+/// # Returns
+/// * `f64` - 가중 평균 (값이 없으면 0)
+pub(crate) fn weighted_average(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let weight_sum = (values.len() * (values.len() + 1) / 2) as f64;
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| value * (i + 1) as f64)
+        .sum::<f64>()
+        / weight_sum
+}
+
+/// 최근 `len`개 값 (부족하면 전체)
+pub(crate) fn tail(values: &[f64], len: usize) -> &[f64] {
+    &values[values.len().saturating_sub(len)..]
+}
+
+/// 최근 `len`개만 남기고 오래된 값 제거
+pub(crate) fn truncate_front(values: &mut Vec<f64>, len: usize) {
+    if values.len() > len {
+        let excess = values.len() - len;
+        values.drain(0..excess);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smoothing_starts_with_simple_average() {
+        let mut state = SmoothingState::default();
+        assert_eq!(state.next(3, 0.5, 10.0), 10.0);
+        assert_eq!(state.next(3, 0.5, 20.0), 15.0);
+        assert_eq!(state.next(3, 0.5, 30.0), 20.0);
+        // 이후 alpha 비율로 반영
+        assert_eq!(state.next(3, 0.5, 40.0), 30.0);
+        assert_eq!(state.count, 3);
+    }
+
+    #[test]
+    fn test_weighted_average() {
+        assert_eq!(weighted_average(&[]), 0.0);
+        assert!((weighted_average(&[10.0, 20.0, 30.0]) - 140.0 / 6.0).abs() < 1e-10);
+        assert_eq!(tail(&[1.0, 2.0, 3.0], 2), &[2.0, 3.0]);
+        assert_eq!(tail(&[1.0], 2), &[1.0]);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::smoothing::SmoothingState;
+use crate::indicator::utils::moving_average;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 삼중지수이동평균(TEMA) 계산 빌더
+///
+/// `3 * EMA - 3 * EMA(EMA) + EMA(EMA(EMA))`로 EMA의 지연을 줄인 이동평균입니다.
+/// 각 EMA는 첫 `period`개 값을 단순이동평균으로 시작합니다.
+#[derive(Debug)]
+pub struct TEMABuilder<C: Candle> {
+    /// TEMA 계산 기간
+    pub period: usize,
+    /// 종가 EMA 상태
+    ema: SmoothingState,
+    /// EMA의 EMA 상태
+    ema_of_ema: SmoothingState,
+    /// EMA의 EMA의 EMA 상태
+    ema_of_ema_of_ema: SmoothingState,
+    _phantom: PhantomData<C>,
+}
+
+/// TEMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TEMABuilderState {
+    /// TEMA 계산 기간
+    pub period: usize,
+    /// 종가 EMA 상태
+    pub ema: SmoothingState,
+    /// EMA의 EMA 상태
+    pub ema_of_ema: SmoothingState,
+    /// EMA의 EMA의 EMA 상태
+    pub ema_of_ema_of_ema: SmoothingState,
+}
+
+/// 삼중지수이동평균(TEMA) 기술적 지표
+#[derive(Clone, Debug)]
+pub struct TEMA {
+    /// TEMA 계산 기간
+    period: usize,
+    /// 계산된 TEMA 값
+    tema: f64,
+}
+
+impl Display for TEMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TEMA({}: {:.2})", self.period, self.tema)
+    }
+}
+
+impl MA for TEMA {
+    fn get(&self) -> f64 {
+        self.tema
+    }
+
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<C> TEMABuilder<C>
+where
+    C: Candle,
+{
+    /// 새 TEMA 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 TEMA 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - TEMA 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<TEMABuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("TEMA 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            ema: SmoothingState::default(),
+            ema_of_ema: SmoothingState::default(),
+            ema_of_ema_of_ema: SmoothingState::default(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 TEMA 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> TEMA {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 TEMA 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> TEMA {
+        self.ema = SmoothingState::default();
+        self.ema_of_ema = SmoothingState::default();
+        self.ema_of_ema_of_ema = SmoothingState::default();
+
+        let mut tema = TEMA {
+            period: self.period,
+            tema: 0.0,
+        };
+        for item in data {
+            tema = self.next(item);
+        }
+        tema
+    }
+
+    /// 새 캔들 데이터로 TEMA 지표 업데이트
+    pub fn next(&mut self, data: &C) -> TEMA {
+        let alpha = moving_average::calculate_ema_alpha(self.period);
+        let ema = self.ema.next(self.period, alpha, data.close_price());
+        let ema_of_ema = self.ema_of_ema.next(self.period, alpha, ema);
+        let ema_of_ema_of_ema = self.ema_of_ema_of_ema.next(self.period, alpha, ema_of_ema);
+
+        TEMA {
+            period: self.period,
+            tema: 3.0 * ema - 3.0 * ema_of_ema + ema_of_ema_of_ema,
+        }
+    }
+}
+
+impl<C> TABuilder<Box<dyn MA>, C> for TEMABuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Box<dyn MA> {
+        Box::new(self.build_from_storage(storage))
+    }
+
+    fn build(&mut self, data: &[C]) -> Box<dyn MA> {
+        Box::new(self.build(data))
+    }
+
+    fn next(&mut self, data: &C) -> Box<dyn MA> {
+        Box::new(self.next(data))
+    }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Tema(TEMABuilderState {
+            period: self.period,
+            ema: self.ema.clone(),
+            ema_of_ema: self.ema_of_ema.clone(),
+            ema_of_ema_of_ema: self.ema_of_ema_of_ema.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Tema(state) = state else {
+            return Err(state_kind_mismatch("TEMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "TEMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.ema = state.ema.clone();
+        self.ema_of_ema = state.ema_of_ema.clone();
+        self.ema_of_ema_of_ema = state.ema_of_ema_of_ema.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tema_tracks_trend_closer_than_ema() {
+        // 일정하게 상승하면 TEMA는 EMA보다 최신 가격에 가까움
+        let closes: Vec<f64> = (0..30).map(|i| 100.0 + i as f64).collect();
+        let candles = create_test_candles(&closes);
+
+        let tema = TEMABuilder::new(5).build(&candles);
+        let ema = crate::indicator::ma::ema::EMABuilder::new(5).build(&candles);
+        assert_eq!(tema.period(), 5);
+        assert!(tema.get() > ema.get());
+        assert!((tema.get() - 129.0).abs() < 0.5);
+        assert!(tema.to_string().contains("TEMA"));
+
+        // 가격이 일정하면 그 가격
+        let flat = TEMABuilder::new(3).build(&create_test_candles(&[50.0; 10]));
+        assert!((flat.get() - 50.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_tema_next_matches_build_and_restores() {
+        let candles = create_test_candles(&[10.0, 12.0, 11.0, 15.0, 14.0, 18.0, 17.0]);
+        let mut builder = TEMABuilder::new(3);
+        builder.build(&candles[..5]);
+
+        let mut restored = TEMABuilder::<TestCandle>::new(3);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = TEMABuilder::new(3).build(&candles).get();
+        builder.next(&candles[5]);
+        restored.next(&candles[5]);
+        assert!((builder.next(&candles[6]).get() - expected).abs() < 1e-10);
+        assert!((restored.next(&candles[6]).get() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "TEMA 기간은 0보다 커야 합니다")]
+    fn test_tema_invalid_period() {
+        TEMABuilder::<TestCandle>::new(0);
+    }
+}
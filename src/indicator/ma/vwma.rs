@@ -0,0 +1,255 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::smoothing;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 거래량가중이동평균(VWMA) 계산 빌더
+///
+/// 최근 `period`개 캔들의 종가를 거래량으로 가중 평균합니다.
+/// 거래량 합이 0이면 단순 평균을 사용합니다.
+#[derive(Debug)]
+pub struct VWMABuilder<C: Candle> {
+    /// VWMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period개)
+    closes: Vec<f64>,
+    /// 최근 거래량 데이터 (최대 period개)
+    volumes: Vec<f64>,
+    _phantom: PhantomData<C>,
+}
+
+/// VWMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VWMABuilderState {
+    /// VWMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period개)
+    pub closes: Vec<f64>,
+    /// 최근 거래량 데이터 (최대 period개)
+    pub volumes: Vec<f64>,
+}
+
+/// 거래량가중이동평균(VWMA) 기술적 지표
+#[derive(Clone, Debug)]
+pub struct VWMA {
+    /// VWMA 계산 기간
+    period: usize,
+    /// 계산된 VWMA 값
+    vwma: f64,
+}
+
+impl Display for VWMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VWMA({}: {:.2})", self.period, self.vwma)
+    }
+}
+
+impl MA for VWMA {
+    fn get(&self) -> f64 {
+        self.vwma
+    }
+
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<C> VWMABuilder<C>
+where
+    C: Candle,
+{
+    /// 새 VWMA 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 VWMA 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - VWMA 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<VWMABuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("VWMA 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            closes: Vec::with_capacity(period),
+            volumes: Vec::with_capacity(period),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 VWMA 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> VWMA {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 VWMA 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> VWMA {
+        self.closes.clear();
+        self.volumes.clear();
+
+        let mut vwma = VWMA {
+            period: self.period,
+            vwma: 0.0,
+        };
+        for item in data {
+            vwma = self.next(item);
+        }
+        vwma
+    }
+
+    /// 새 캔들 데이터로 VWMA 지표 업데이트
+    pub fn next(&mut self, data: &C) -> VWMA {
+        self.closes.push(data.close_price());
+        self.volumes.push(data.volume());
+        smoothing::truncate_front(&mut self.closes, self.period);
+        smoothing::truncate_front(&mut self.volumes, self.period);
+
+        let volume_sum: f64 = self.volumes.iter().sum();
+        let vwma = if volume_sum > 0.0 {
+            self.closes
+                .iter()
+                .zip(&self.volumes)
+                .map(|(close, volume)| close * volume)
+                .sum::<f64>()
+                / volume_sum
+        } else {
+            self.closes.iter().sum::<f64>() / self.closes.len() as f64
+        };
+
+        VWMA {
+            period: self.period,
+            vwma,
+        }
+    }
+}
+
+impl<C> TABuilder<Box<dyn MA>, C> for VWMABuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Box<dyn MA> {
+        Box::new(self.build_from_storage(storage))
+    }
+
+    fn build(&mut self, data: &[C]) -> Box<dyn MA> {
+        Box::new(self.build(data))
+    }
+
+    fn next(&mut self, data: &C) -> Box<dyn MA> {
+        Box::new(self.next(data))
+    }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Vwma(VWMABuilderState {
+            period: self.period,
+            closes: self.closes.clone(),
+            volumes: self.volumes.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Vwma(state) = state else {
+            return Err(state_kind_mismatch("VWMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "VWMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+        if state.closes.len() != state.volumes.len() {
+            return Err(format!(
+                "VWMA 상태의 종가({})와 거래량({}) 개수가 다릅니다",
+                state.closes.len(),
+                state.volumes.len()
+            ));
+        }
+
+        self.closes = state.closes.clone();
+        self.volumes = state.volumes.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (close, volume))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: *volume,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_vwma_exact_calculation() {
+        let candles = create_test_candles(&[(10.0, 100.0), (20.0, 100.0), (30.0, 300.0)]);
+        let vwma = VWMABuilder::new(2).build(&candles);
+
+        // (20 * 100 + 30 * 300) / 400
+        assert_eq!(vwma.period(), 2);
+        assert!((vwma.get() - 27.5).abs() < 1e-10);
+        assert!(vwma.to_string().contains("VWMA"));
+
+        // 거래량이 없으면 단순 평균
+        let no_volume = create_test_candles(&[(10.0, 0.0), (20.0, 0.0)]);
+        assert_eq!(VWMABuilder::new(2).build(&no_volume).get(), 15.0);
+    }
+
+    #[test]
+    fn test_vwma_next_matches_build_and_restores() {
+        let candles = create_test_candles(&[
+            (10.0, 100.0),
+            (12.0, 300.0),
+            (11.0, 200.0),
+            (15.0, 500.0),
+            (14.0, 100.0),
+        ]);
+        let mut builder = VWMABuilder::new(3);
+        builder.build(&candles[..3]);
+
+        let mut restored = VWMABuilder::<TestCandle>::new(3);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = VWMABuilder::new(3).build(&candles).get();
+        builder.next(&candles[3]);
+        restored.next(&candles[3]);
+        assert!((builder.next(&candles[4]).get() - expected).abs() < 1e-10);
+        assert!((restored.next(&candles[4]).get() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "VWMA 기간은 0보다 커야 합니다")]
+    fn test_vwma_invalid_period() {
+        VWMABuilder::<TestCandle>::new(0);
+    }
+}
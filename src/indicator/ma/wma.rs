@@ -1,6 +1,9 @@
 use crate::candle_store::CandleStore;
 use crate::indicator::ma::MA;
-use crate::indicator::{IndicatorResult, TABuilder};
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use trading_chart::Candle;
@@ -17,6 +20,15 @@ pub struct WMABuilder<C: Candle> {
     _phantom: PhantomData<C>,
 }
 
+/// WMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WMABuilderState {
+    /// WMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period * 2개)
+    pub values: Vec<f64>,
+}
+
 /// 가중이동평균(WMA) 기술적 지표
 ///
 /// 계산된 WMA 값을 저장하고 제공합니다.
@@ -214,6 +226,30 @@ where
     fn next(&mut self, data: &C) -> Box<dyn MA> {
         Box::new(self.next(data))
     }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Wma(WMABuilderState {
+            period: self.period,
+            values: self.values.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Wma(state) = state else {
+            return Err(state_kind_mismatch("WMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "WMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.values = state.values.clone();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,244 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::MA;
+use crate::indicator::ma::smoothing::{self, SmoothingState};
+use crate::indicator::utils::moving_average;
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, state_kind_mismatch, state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 제로랙지수이동평균(ZLEMA) 계산 빌더
+///
+/// `(period - 1) / 2` 캔들 전 가격과의 차이를 더해(`2 * 종가 - 지연 종가`) 지연을 보정한 값의 EMA입니다.
+/// 지연 캔들이 쌓이기 전에는 종가를 그대로 사용합니다.
+#[derive(Debug)]
+pub struct ZLEMABuilder<C: Candle> {
+    /// ZLEMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 지연 + 1개)
+    values: Vec<f64>,
+    /// 보정 값 EMA 상태
+    ema: SmoothingState,
+    _phantom: PhantomData<C>,
+}
+
+/// ZLEMA 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZLEMABuilderState {
+    /// ZLEMA 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 지연 + 1개)
+    pub values: Vec<f64>,
+    /// 보정 값 EMA 상태
+    pub ema: SmoothingState,
+}
+
+/// 제로랙지수이동평균(ZLEMA) 기술적 지표
+#[derive(Clone, Debug)]
+pub struct ZLEMA {
+    /// ZLEMA 계산 기간
+    period: usize,
+    /// 계산된 ZLEMA 값
+    zlema: f64,
+}
+
+impl Display for ZLEMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ZLEMA({}: {:.2})", self.period, self.zlema)
+    }
+}
+
+impl MA for ZLEMA {
+    fn get(&self) -> f64 {
+        self.zlema
+    }
+
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<C> ZLEMABuilder<C>
+where
+    C: Candle,
+{
+    /// 새 ZLEMA 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 ZLEMA 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - ZLEMA 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<ZLEMABuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("ZLEMA 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            values: Vec::with_capacity(Self::lag_of(period) + 1),
+            ema: SmoothingState::default(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 지연 보정 캔들 수
+    fn lag_of(period: usize) -> usize {
+        (period - 1) / 2
+    }
+
+    /// 저장소에서 ZLEMA 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> ZLEMA {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 ZLEMA 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> ZLEMA {
+        self.values.clear();
+        self.ema = SmoothingState::default();
+
+        let mut zlema = ZLEMA {
+            period: self.period,
+            zlema: 0.0,
+        };
+        for item in data {
+            zlema = self.next(item);
+        }
+        zlema
+    }
+
+    /// 새 캔들 데이터로 ZLEMA 지표 업데이트
+    pub fn next(&mut self, data: &C) -> ZLEMA {
+        let lag = Self::lag_of(self.period);
+        let price = data.close_price();
+        self.values.push(price);
+        smoothing::truncate_front(&mut self.values, lag + 1);
+
+        // 지연 캔들이 부족하면 종가 그대로 사용
+        let adjusted = if self.values.len() > lag {
+            2.0 * price - self.values[0]
+        } else {
+            price
+        };
+        let alpha = moving_average::calculate_ema_alpha(self.period);
+        let zlema = self.ema.next(self.period, alpha, adjusted);
+
+        ZLEMA {
+            period: self.period,
+            zlema,
+        }
+    }
+}
+
+impl<C> TABuilder<Box<dyn MA>, C> for ZLEMABuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Box<dyn MA> {
+        Box::new(self.build_from_storage(storage))
+    }
+
+    fn build(&mut self, data: &[C]) -> Box<dyn MA> {
+        Box::new(self.build(data))
+    }
+
+    fn next(&mut self, data: &C) -> Box<dyn MA> {
+        Box::new(self.next(data))
+    }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Zlema(ZLEMABuilderState {
+            period: self.period,
+            values: self.values.clone(),
+            ema: self.ema.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Zlema(state) = state else {
+            return Err(state_kind_mismatch("ZLEMA", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "ZLEMA",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.values = state.values.clone();
+        self.ema = state.ema.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_zlema_exact_calculation() {
+        // 기간 3, 지연 1: 보정 값 10, 30, 40 → 시작값 SMA = 26.67, 이후 EMA(alpha 0.5)
+        let candles = create_test_candles(&[10.0, 20.0, 30.0, 40.0]);
+        let zlema = ZLEMABuilder::new(3).build(&candles);
+
+        let start = (10.0 + 30.0 + 40.0) / 3.0;
+        let expected = start + 0.5 * (50.0 - start);
+        assert_eq!(zlema.period(), 3);
+        assert!((zlema.get() - expected).abs() < 1e-10);
+        assert!(zlema.to_string().contains("ZLEMA"));
+    }
+
+    #[test]
+    fn test_zlema_next_matches_build_and_restores() {
+        let candles = create_test_candles(&[10.0, 12.0, 11.0, 15.0, 14.0, 18.0, 17.0]);
+        let mut builder = ZLEMABuilder::new(4);
+        builder.build(&candles[..5]);
+
+        let mut restored = ZLEMABuilder::<TestCandle>::new(4);
+        restored.restore(&builder.snapshot().unwrap()).unwrap();
+
+        let expected = ZLEMABuilder::new(4).build(&candles).get();
+        builder.next(&candles[5]);
+        restored.next(&candles[5]);
+        assert!((builder.next(&candles[6]).get() - expected).abs() < 1e-10);
+        assert!((restored.next(&candles[6]).get() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "ZLEMA 기간은 0보다 커야 합니다")]
+    fn test_zlema_invalid_period() {
+        ZLEMABuilder::<TestCandle>::new(0);
+    }
+}
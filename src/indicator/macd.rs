@@ -1,6 +1,10 @@
 use crate::candle_store::CandleStore;
 use crate::indicator::utils::moving_average;
-use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, TAs, TAsBuilder, state_kind_mismatch,
+    state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use trading_chart::Candle;
@@ -35,10 +39,31 @@ pub struct MACDBuilder<C: Candle> {
     _phantom: PhantomData<C>,
 }
 
+/// MACD 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MACDBuilderState {
+    /// 빠른 EMA 기간
+    pub fast_period: usize,
+    /// 느린 EMA 기간
+    pub slow_period: usize,
+    /// 시그널 라인 기간
+    pub signal_period: usize,
+    /// 최근 종가 데이터
+    pub values: Vec<f64>,
+    /// 이전 빠른 EMA 값
+    pub previous_fast_ema: Option<f64>,
+    /// 이전 느린 EMA 값
+    pub previous_slow_ema: Option<f64>,
+    /// 이전 시그널 라인 값
+    pub previous_signal_line: Option<f64>,
+    /// MACD 라인 히스토리
+    pub macd_history: Vec<f64>,
+}
+
 /// MACD(Moving Average Convergence Divergence) 기술적 지표
 ///
 /// MACD는 추세 추종 모멘텀 지표로, 추세의 방향과 강도를 나타냅니다.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MACD {
     /// 빠른 EMA 기간
     fast_period: usize,
@@ -425,6 +450,42 @@ where
     fn next(&mut self, data: &C) -> MACD {
         self.next(data)
     }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Macd(MACDBuilderState {
+            fast_period: self.fast_period,
+            slow_period: self.slow_period,
+            signal_period: self.signal_period,
+            values: self.values.clone(),
+            previous_fast_ema: self.previous_fast_ema,
+            previous_slow_ema: self.previous_slow_ema,
+            previous_signal_line: self.previous_signal_line,
+            macd_history: self.macd_history.clone(),
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Macd(state) = state else {
+            return Err(state_kind_mismatch("MACD", state));
+        };
+        let builder_periods = (self.fast_period, self.slow_period, self.signal_period);
+        let state_periods = (state.fast_period, state.slow_period, state.signal_period);
+        if builder_periods != state_periods {
+            return Err(state_param_mismatch(
+                "MACD",
+                "기간",
+                format!("{builder_periods:?}"),
+                format!("{state_periods:?}"),
+            ));
+        }
+
+        self.values = state.values.clone();
+        self.previous_fast_ema = state.previous_fast_ema;
+        self.previous_slow_ema = state.previous_slow_ema;
+        self.previous_signal_line = state.previous_signal_line;
+        self.macd_history = state.macd_history.clone();
+        Ok(())
+    }
 }
 
 /// MACD 매개변수를 정의하는 구조체
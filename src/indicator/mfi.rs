@@ -0,0 +1,307 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::smoothing;
+use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// MFI (Money Flow Index) 기술적 지표
+///
+/// 전형가격(고가 + 저가 + 종가) / 3에 거래량을 곱한 머니 플로우를
+/// 전형가격 상승/하락으로 나누어 0에서 100 사이로 나타낸 거래량 가중 RSI입니다.
+#[derive(Clone, Debug)]
+pub struct MFI {
+    /// 계산 기간
+    period: usize,
+    /// MFI 값 (0-100)
+    pub value: f64,
+}
+
+impl Display for MFI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MFI({}: {:.2})", self.period, self.value)
+    }
+}
+
+impl MFI {
+    /// 과매수 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매수 기준값 (기본값 80.0)
+    pub fn is_overbought(&self, threshold: Option<f64>) -> bool {
+        self.value >= threshold.unwrap_or(80.0)
+    }
+
+    /// 과매도 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매도 기준값 (기본값 20.0)
+    pub fn is_oversold(&self, threshold: Option<f64>) -> bool {
+        self.value <= threshold.unwrap_or(20.0)
+    }
+
+    /// 계산 기간 반환
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// MFI 값 반환
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// MFI 계산 빌더
+///
+/// 데이터가 기간보다 적으면 있는 캔들만으로 계산합니다.
+/// 첫 캔들은 비교할 전형가격이 없어 머니 플로우에 포함되지 않으며,
+/// 상승/하락 머니 플로우가 모두 0이면 중립값 50입니다.
+#[derive(Debug)]
+pub struct MFIBuilder<C: Candle> {
+    /// 계산 기간
+    period: usize,
+    /// 직전 전형가격
+    previous_typical_price: Option<f64>,
+    /// 최근 상승 머니 플로우 (최대 period개)
+    positive_flows: Vec<f64>,
+    /// 최근 하락 머니 플로우 (최대 period개)
+    negative_flows: Vec<f64>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> MFIBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 MFI 빌더 생성
+    ///
+    /// # Panics
+    /// * 기간이 0이면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 MFI 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<MFIBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("MFI 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            previous_typical_price: None,
+            positive_flows: Vec::with_capacity(period),
+            negative_flows: Vec::with_capacity(period),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 MFI 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> MFI {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 MFI 지표 생성 (데이터가 없으면 50)
+    pub fn build(&mut self, data: &[C]) -> MFI {
+        self.previous_typical_price = None;
+        self.positive_flows.clear();
+        self.negative_flows.clear();
+
+        let mut value = 50.0;
+        for candle in data {
+            value = self.next_value(candle);
+        }
+
+        MFI {
+            period: self.period,
+            value,
+        }
+    }
+
+    /// 다음 캔들 데이터로 MFI 값 계산 (내부용)
+    fn next_value(&mut self, candle: &C) -> f64 {
+        let typical_price = (candle.high_price() + candle.low_price() + candle.close_price()) / 3.0;
+        let money_flow = typical_price * candle.volume();
+
+        let (positive, negative) = match self.previous_typical_price {
+            Some(previous) if typical_price > previous => (money_flow, 0.0),
+            Some(previous) if typical_price < previous => (0.0, money_flow),
+            _ => (0.0, 0.0),
+        };
+        self.previous_typical_price = Some(typical_price);
+
+        self.positive_flows.push(positive);
+        self.negative_flows.push(negative);
+        smoothing::truncate_front(&mut self.positive_flows, self.period);
+        smoothing::truncate_front(&mut self.negative_flows, self.period);
+
+        let positive_sum: f64 = self.positive_flows.iter().sum();
+        let total = positive_sum + self.negative_flows.iter().sum::<f64>();
+        if total > 0.0 {
+            positive_sum / total * 100.0
+        } else {
+            50.0
+        }
+    }
+
+    /// 새 캔들 데이터로 MFI 지표 업데이트
+    pub fn next(&mut self, data: &C) -> MFI {
+        MFI {
+            period: self.period,
+            value: self.next_value(data),
+        }
+    }
+}
+
+impl<C> TABuilder<MFI, C> for MFIBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> MFI {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> MFI {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> MFI {
+        self.next(data)
+    }
+}
+
+/// 여러 기간의 MFI 지표 컬렉션 타입
+pub type MFIs = TAs<usize, MFI>;
+
+/// 여러 기간의 MFI 지표 빌더 타입
+pub type MFIsBuilder<C> = TAsBuilder<usize, MFI, C>;
+
+/// MFI 컬렉션 빌더 팩토리
+pub struct MFIsBuilderFactory;
+
+impl MFIsBuilderFactory {
+    /// 여러 기간의 MFI 빌더 생성
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `MFIsBuilder` - 여러 기간의 MFI 빌더
+    pub fn build<C: Candle + 'static>(periods: &[usize]) -> MFIsBuilder<C> {
+        match Self::build_checked(periods) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 여러 기간의 MFI 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `IndicatorResult<MFIsBuilder>` - MFI 빌더 또는 에러
+    pub fn build_checked<C: Candle + 'static>(
+        periods: &[usize],
+    ) -> IndicatorResult<MFIsBuilder<C>> {
+        for period in periods {
+            MFIBuilder::<C>::new_checked(*period)?;
+        }
+
+        Ok(MFIsBuilder::new("mfis".to_owned(), periods, |period| {
+            Box::new(MFIBuilder::<C>::new(*period))
+        }))
+    }
+
+    /// 기본 MFI 빌더 생성 (14 기간)
+    pub fn build_default<C: Candle + 'static>() -> MFIsBuilder<C> {
+        Self::build(&[14])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64, f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (high, low, close, volume))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: *high,
+                low: *low,
+                close: *close,
+                volume: *volume,
+            })
+            .collect()
+    }
+
+    fn sample_candles() -> Vec<TestCandle> {
+        // 전형가격 10, 11, 9, 12
+        create_test_candles(&[
+            (12.0, 8.0, 10.0, 100.0),
+            (13.0, 9.0, 11.0, 200.0),
+            (11.0, 7.0, 9.0, 100.0),
+            (14.0, 10.0, 12.0, 50.0),
+        ])
+    }
+
+    #[test]
+    fn test_mfi_exact_calculation() {
+        let mfi = MFIBuilder::new(3).build(&sample_candles());
+
+        // 상승 2200 + 600, 하락 900: 2800 / 3700 * 100
+        assert!((mfi.value() - 2800.0 / 3700.0 * 100.0).abs() < 1e-10);
+        assert!(!mfi.is_overbought(None));
+        assert!(!mfi.is_oversold(None));
+        assert_eq!(mfi.to_string(), "MFI(3: 75.68)");
+    }
+
+    #[test]
+    fn test_mfi_next_drops_old_candles() {
+        let candles = sample_candles();
+        let mut builder = MFIBuilder::new(2);
+        builder.build(&candles[..3]);
+        let mfi = builder.next(&candles[3]);
+
+        // 최근 2개 머니 플로우: 하락 900, 상승 600
+        assert!((mfi.value() - 40.0).abs() < 1e-10);
+
+        let flat = create_test_candles(&[(11.0, 9.0, 10.0, 100.0); 3]);
+        assert_eq!(MFIBuilder::new(2).build(&flat).value(), 50.0);
+        assert_eq!(MFIBuilder::<TestCandle>::new(2).build(&[]).value(), 50.0);
+    }
+
+    #[test]
+    fn test_mfi_extremes() {
+        let rising = create_test_candles(&[(11.0, 9.0, 10.0, 100.0), (12.0, 10.0, 11.0, 100.0)]);
+        let mfi = MFIBuilder::new(14).build(&rising);
+        assert_eq!(mfi.value(), 100.0);
+        assert!(mfi.is_overbought(None));
+
+        let falling = create_test_candles(&[(11.0, 9.0, 10.0, 100.0), (10.0, 8.0, 9.0, 100.0)]);
+        let mfi = MFIBuilder::new(14).build(&falling);
+        assert_eq!(mfi.value(), 0.0);
+        assert!(mfi.is_oversold(None));
+    }
+
+    #[test]
+    fn test_mfis_builder_factory() {
+        let mfis = MFIsBuilderFactory::build::<TestCandle>(&[2, 14]).build(&sample_candles());
+
+        assert_eq!(mfis.len(), 2);
+        assert_eq!(mfis.get(&14).period(), 14);
+        assert!(MFIsBuilderFactory::build_checked::<TestCandle>(&[0]).is_err());
+    }
+}
@@ -1,20 +1,32 @@
 // 기존 ta 모듈에서 이동된 기술적 지표 모듈
 // 각종 기술적 분석 지표를 제공합니다.
 
+pub mod ad_line;
 pub mod adx;
 pub mod atr;
 pub mod bband;
+pub mod cci;
+pub mod cmf;
+pub mod donchian;
 pub mod ichimoku;
+pub mod keltner;
 pub mod ma;
 pub mod macd;
 pub mod max;
+pub mod mfi;
 pub mod min;
+pub mod obv;
 pub mod orderbook;
 pub mod rsi;
+pub mod squeeze;
+pub mod stoch_rsi;
+pub mod stochastic;
 pub mod supertrend;
 pub mod utils;
 pub mod volume;
+pub mod volume_profile;
 pub mod vwap;
+pub mod williams_r;
 
 // 이 모듈은 이전의 ta 모듈을 대체합니다.
 // 더 명확한 이름으로 기술적 지표를 표현합니다.
@@ -24,15 +36,93 @@ use std::fmt::Debug;
 use std::hash::Hash;
 
 use crate::candle_store::CandleStore;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use trading_chart::Candle;
 
 pub type IndicatorResult<T> = std::result::Result<T, String>;
 
+/// 지표 빌더 내부 상태
+///
+/// 재시작한 프로세스가 캔들 저장소를 다시 재생하지 않고
+/// 같은 지표 값을 이어서 계산할 수 있도록 빌더의 증분 계산 상태를 담습니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IndicatorState {
+    /// RSI 빌더 상태
+    Rsi(rsi::RSIBuilderState),
+    /// EMA 빌더 상태
+    Ema(ma::ema::EMABuilderState),
+    /// SMA 빌더 상태
+    Sma(ma::sma::SMABuilderState),
+    /// WMA 빌더 상태
+    Wma(ma::wma::WMABuilderState),
+    /// HMA 빌더 상태
+    Hma(ma::hma::HMABuilderState),
+    /// DEMA 빌더 상태
+    Dema(ma::dema::DEMABuilderState),
+    /// TEMA 빌더 상태
+    Tema(ma::tema::TEMABuilderState),
+    /// KAMA 빌더 상태
+    Kama(ma::kama::KAMABuilderState),
+    /// ZLEMA 빌더 상태
+    Zlema(ma::zlema::ZLEMABuilderState),
+    /// VWMA 빌더 상태
+    Vwma(ma::vwma::VWMABuilderState),
+    /// SMMA 빌더 상태
+    Smma(ma::smma::SMMABuilderState),
+    /// 볼린저 밴드 빌더 상태
+    BollingerBands(bband::BollingerBandsBuilderState),
+    /// MACD 빌더 상태
+    Macd(macd::MACDBuilderState),
+    /// ADX 빌더 상태
+    Adx(adx::ADXBuilderState),
+}
+
+impl IndicatorState {
+    /// 상태 종류 이름
+    pub fn kind(&self) -> &'static str {
+        match self {
+            IndicatorState::Rsi(_) => "RSI",
+            IndicatorState::Ema(_) => "EMA",
+            IndicatorState::Sma(_) => "SMA",
+            IndicatorState::Wma(_) => "WMA",
+            IndicatorState::Hma(_) => "HMA",
+            IndicatorState::Dema(_) => "DEMA",
+            IndicatorState::Tema(_) => "TEMA",
+            IndicatorState::Kama(_) => "KAMA",
+            IndicatorState::Zlema(_) => "ZLEMA",
+            IndicatorState::Vwma(_) => "VWMA",
+            IndicatorState::Smma(_) => "SMMA",
+            IndicatorState::BollingerBands(_) => "BollingerBands",
+            IndicatorState::Macd(_) => "MACD",
+            IndicatorState::Adx(_) => "ADX",
+        }
+    }
+}
+
+/// 다른 종류의 상태를 복원하려 할 때의 에러 메시지
+pub(crate) fn state_kind_mismatch(expected: &str, state: &IndicatorState) -> String {
+    format!(
+        "{expected} 빌더에 {} 상태를 복원할 수 없습니다",
+        state.kind()
+    )
+}
+
+/// 설정이 다른 빌더의 상태를 복원하려 할 때의 에러 메시지
+pub(crate) fn state_param_mismatch(
+    expected: &str,
+    key: &str,
+    builder: impl Display,
+    state: impl Display,
+) -> String {
+    format!("{expected} {key} 불일치: 빌더 {builder}, 상태 {state}")
+}
+
 /// 기술적 지표(TA)의 컬렉션을 관리하는 구조체
 ///
 /// 여러 기술적 지표를 키-값 쌍으로 저장하고 관리합니다.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TAs<K, T>
 where
     K: PartialEq + Eq + Hash + std::fmt::Debug,
@@ -266,6 +356,33 @@ pub trait TABuilder<T, C: Candle>: Send + std::fmt::Debug {
     /// # Returns
     /// * `T` - 업데이트된 기술적 지표
     fn next(&mut self, data: &C) -> T;
+
+    /// 증분 계산 상태 저장
+    ///
+    /// 상태 저장을 지원하지 않는 빌더는 에러를 반환합니다.
+    ///
+    /// # Returns
+    /// * `IndicatorResult<IndicatorState>` - 빌더 내부 상태 또는 에러
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Err(format!(
+            "{}는 상태 저장을 지원하지 않습니다",
+            std::any::type_name::<Self>()
+        ))
+    }
+
+    /// 저장한 상태로 빌더 복원
+    ///
+    /// 상태의 종류나 기간이 빌더와 다르면 빌더를 변경하지 않고 에러를 반환합니다.
+    ///
+    /// # Arguments
+    /// * `state` - `snapshot`으로 저장한 상태
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        Err(format!(
+            "{}는 {} 상태 복원을 지원하지 않습니다",
+            std::any::type_name::<Self>(),
+            state.kind()
+        ))
+    }
 }
 
 /// 여러 기술적 지표 빌더를 관리하는 구조체
@@ -354,6 +471,39 @@ where
         }
         TAs::new(self.name.to_owned(), self.keys.clone(), tas)
     }
+
+    /// 모든 빌더의 증분 계산 상태 저장 (키 순서)
+    ///
+    /// # Returns
+    /// * `IndicatorResult<Vec<IndicatorState>>` - 키 순서대로 정렬된 빌더 상태 또는 에러
+    pub fn snapshot(&self) -> IndicatorResult<Vec<IndicatorState>> {
+        self.keys
+            .iter()
+            .map(|key| self.builders[key].snapshot())
+            .collect()
+    }
+
+    /// 저장한 상태로 모든 빌더 복원
+    ///
+    /// # Arguments
+    /// * `states` - `snapshot`으로 저장한 키 순서의 빌더 상태
+    pub fn restore(&mut self, states: &[IndicatorState]) -> IndicatorResult<()> {
+        if states.len() != self.keys.len() {
+            return Err(format!(
+                "{} 빌더 수 불일치: 빌더 {}, 상태 {}",
+                self.name,
+                self.keys.len(),
+                states.len()
+            ));
+        }
+
+        for (key, state) in self.keys.iter().zip(states) {
+            if let Some(builder) = self.builders.get_mut(key) {
+                builder.restore(state)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<K: std::fmt::Debug, T, C> std::fmt::Debug for TAsBuilder<K, T, C>
@@ -0,0 +1,158 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::TABuilder;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// OBV (On-Balance Volume) 기술적 지표
+///
+/// 종가가 직전 종가보다 오르면 거래량을 더하고, 내리면 빼서 누적합니다.
+/// 첫 캔들은 비교할 종가가 없으므로 0에서 시작합니다.
+#[derive(Clone, Debug)]
+pub struct OBV {
+    /// 누적 OBV 값
+    pub value: f64,
+}
+
+impl Display for OBV {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OBV({:.2})", self.value)
+    }
+}
+
+impl OBV {
+    /// OBV 값 반환
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// OBV 계산 빌더
+#[derive(Debug)]
+pub struct OBVBuilder<C: Candle> {
+    /// 누적 OBV 값
+    value: f64,
+    /// 직전 종가
+    previous_close: Option<f64>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> Default for OBVBuilder<C>
+where
+    C: Candle,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> OBVBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 OBV 빌더 생성
+    pub fn new() -> Self {
+        Self {
+            value: 0.0,
+            previous_close: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// 저장소에서 OBV 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> OBV {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 OBV 지표 생성 (데이터가 없으면 0)
+    pub fn build(&mut self, data: &[C]) -> OBV {
+        self.value = 0.0;
+        self.previous_close = None;
+
+        for candle in data {
+            self.next(candle);
+        }
+
+        OBV { value: self.value }
+    }
+
+    /// 새 캔들 데이터로 OBV 지표 업데이트
+    pub fn next(&mut self, data: &C) -> OBV {
+        let close = data.close_price();
+        if let Some(previous_close) = self.previous_close {
+            if close > previous_close {
+                self.value += data.volume();
+            } else if close < previous_close {
+                self.value -= data.volume();
+            }
+        }
+        self.previous_close = Some(close);
+
+        OBV { value: self.value }
+    }
+}
+
+impl<C> TABuilder<OBV, C> for OBVBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> OBV {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> OBV {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> OBV {
+        self.next(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (close, volume))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: *volume,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_obv_accumulates_signed_volume() {
+        let candles = create_test_candles(&[
+            (100.0, 500.0),
+            (101.0, 1000.0),
+            (101.0, 700.0),
+            (99.0, 400.0),
+            (102.0, 300.0),
+        ]);
+        let obv = OBVBuilder::new().build(&candles);
+
+        // 0 + 1000 (상승) + 0 (보합) - 400 (하락) + 300 (상승)
+        assert_eq!(obv.value(), 900.0);
+        assert_eq!(obv.to_string(), "OBV(900.00)");
+        assert_eq!(OBVBuilder::<TestCandle>::new().build(&[]).value(), 0.0);
+    }
+
+    #[test]
+    fn test_obv_next_matches_build() {
+        let candles = create_test_candles(&[(100.0, 500.0), (98.0, 200.0), (99.0, 600.0)]);
+        let mut builder = OBVBuilder::new();
+        builder.build(&candles[..2]);
+        let obv = builder.next(&candles[2]);
+
+        assert_eq!(obv.value(), 400.0);
+        assert_eq!(OBVBuilder::new().build(&candles).value(), obv.value());
+    }
+}
@@ -568,6 +568,23 @@ impl OrderBookAnalyzer {
         }
     }
 
+    /// Calculate the part of an order that the visible book cannot fill
+    /// Returns the unfilled order size (same unit as `order_size`)
+    pub fn unfilled_size(&self, orderbook: &OrderBook, order_size: f64, is_buy: bool) -> f64 {
+        let orders = if is_buy {
+            &orderbook.asks
+        } else {
+            &orderbook.bids
+        };
+
+        if order_size <= 0.0 {
+            return 0.0;
+        }
+
+        let (_, _, remaining_size) = self.calculate_fill_details(orders, order_size);
+        remaining_size.max(0.0)
+    }
+
     /// Get market pressure indicator
     pub fn get_market_pressure(&self, orderbook: &OrderBook) -> MarketPressure {
         let bid_depth = orderbook.bid_depth_within_percent(self.depth_percent);
@@ -851,6 +868,18 @@ mod tests {
         assert!(vwap_sell <= ob.best_bid().unwrap());
     }
 
+    #[test]
+    fn test_unfilled_size() {
+        let ob = create_test_orderbook();
+        let analyzer = OrderBookAnalyzer::default();
+
+        assert_eq!(analyzer.unfilled_size(&ob, 1000.0, true), 0.0);
+        assert!(
+            (analyzer.unfilled_size(&ob, 10000.0, true) - (10000.0 - ob.ask_value())).abs() < 1e-9
+        );
+        assert_eq!(analyzer.unfilled_size(&ob, -1.0, false), 0.0);
+    }
+
     #[test]
     fn test_find_significant_levels() {
         let ob = create_test_orderbook();
@@ -1,5 +1,9 @@
 use crate::candle_store::CandleStore;
-use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use crate::indicator::{
+    IndicatorResult, IndicatorState, TABuilder, TAs, TAsBuilder, state_kind_mismatch,
+    state_param_mismatch,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use trading_chart::Candle;
@@ -25,10 +29,23 @@ pub struct RSIBuilder<C: Candle> {
     _phantom: PhantomData<C>,
 }
 
+/// RSI 빌더 증분 계산 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RSIBuilderState {
+    /// RSI 계산 기간
+    pub period: usize,
+    /// 최근 종가 데이터 (최대 period + 1개)
+    pub values: Vec<f64>,
+    /// 이전 평균 게인
+    pub previous_avg_gain: Option<f64>,
+    /// 이전 평균 로스
+    pub previous_avg_loss: Option<f64>,
+}
+
 /// 상대강도지수(RSI) 기술적 지표
 ///
 /// RSI는 가격 변동의 상대적 강도를 측정하여 과매수/과매도 상태를 판단
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RSI {
     /// RSI 계산 기간
     period: usize,
@@ -293,6 +310,34 @@ where
     fn next(&mut self, data: &C) -> RSI {
         self.next(data)
     }
+
+    fn snapshot(&self) -> IndicatorResult<IndicatorState> {
+        Ok(IndicatorState::Rsi(RSIBuilderState {
+            period: self.period,
+            values: self.values.clone(),
+            previous_avg_gain: self.previous_avg_gain,
+            previous_avg_loss: self.previous_avg_loss,
+        }))
+    }
+
+    fn restore(&mut self, state: &IndicatorState) -> IndicatorResult<()> {
+        let IndicatorState::Rsi(state) = state else {
+            return Err(state_kind_mismatch("RSI", state));
+        };
+        if state.period != self.period {
+            return Err(state_param_mismatch(
+                "RSI",
+                "기간",
+                self.period,
+                state.period,
+            ));
+        }
+
+        self.values = state.values.clone();
+        self.previous_avg_gain = state.previous_avg_gain;
+        self.previous_avg_loss = state.previous_avg_loss;
+        Ok(())
+    }
 }
 
 /// 여러 기간의 RSI 지표 컬렉션 타입
@@ -746,4 +791,32 @@ mod tests {
             rsi.value()
         );
     }
+
+    #[test]
+    fn test_rsi_restore_continues_without_warmup() {
+        let candles: Vec<TestCandle> = (0..30)
+            .map(|i| TestCandle {
+                timestamp: i,
+                close: 100.0 + ((i * 7) % 11) as f64,
+                ..TestCandle::default()
+            })
+            .collect();
+
+        let mut builder = RSIBuilder::<TestCandle>::new(14);
+        builder.build(&candles[..20]);
+        let state = builder.snapshot().unwrap();
+        let json = serde_json::to_string(&state).unwrap();
+
+        let mut restored = RSIBuilder::<TestCandle>::new(14);
+        restored
+            .restore(&serde_json::from_str(&json).unwrap())
+            .unwrap();
+
+        for candle in &candles[20..] {
+            assert_eq!(builder.next(candle).value(), restored.next(candle).value());
+        }
+
+        // 기간이 다른 빌더에는 복원하지 않음
+        assert!(RSIBuilder::<TestCandle>::new(10).restore(&state).is_err());
+    }
 }
@@ -0,0 +1,265 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::bband::{BollingerBands, BollingerBandsBuilder};
+use crate::indicator::keltner::{KeltnerChannel, KeltnerChannelBuilder};
+use crate::indicator::{IndicatorResult, TABuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 스퀴즈 매개변수
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SqueezeParams {
+    /// 볼린저 밴드 기간
+    pub bb_period: usize,
+    /// 볼린저 밴드 표준편차 승수
+    pub bb_multiplier: f64,
+    /// 켈트너 채널 EMA 기간
+    pub kc_period: usize,
+    /// 켈트너 채널 ATR 기간
+    pub kc_atr_period: usize,
+    /// 켈트너 채널 ATR 승수
+    pub kc_multiplier: f64,
+}
+
+impl Display for SqueezeParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Squeeze(BB {},{} / KC {},{},{})",
+            self.bb_period,
+            self.bb_multiplier,
+            self.kc_period,
+            self.kc_atr_period,
+            self.kc_multiplier
+        )
+    }
+}
+
+impl Default for SqueezeParams {
+    /// TTM Squeeze 기본 설정 (BB 20/2.0, KC 20/20/1.5)
+    fn default() -> Self {
+        SqueezeParams {
+            bb_period: 20,
+            bb_multiplier: 2.0,
+            kc_period: 20,
+            kc_atr_period: 20,
+            kc_multiplier: 1.5,
+        }
+    }
+}
+
+/// TTM 방식 스퀴즈 지표
+///
+/// 볼린저 밴드가 켈트너 채널 안쪽으로 들어가면 변동성이 압축된 스퀴즈 상태로 봅니다.
+#[derive(Clone, Debug)]
+pub struct Squeeze {
+    /// 볼린저 밴드
+    pub bband: BollingerBands,
+    /// 켈트너 채널
+    pub keltner: KeltnerChannel,
+}
+
+impl Display for Squeeze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Squeeze({}: {}, {})",
+            if self.is_on() { "on" } else { "off" },
+            self.bband,
+            self.keltner
+        )
+    }
+}
+
+impl Squeeze {
+    /// 스퀴즈 상태 여부 (볼린저 밴드가 켈트너 채널 안쪽에 있음)
+    pub fn is_on(&self) -> bool {
+        self.bband.lower() > self.keltner.lower() && self.bband.upper() < self.keltner.upper()
+    }
+
+    /// 볼린저 밴드 폭 / 켈트너 채널 폭 비율
+    ///
+    /// # Returns
+    /// * `f64` - 1보다 작을수록 압축이 강함 (켈트너 폭이 0이면 0)
+    pub fn width_ratio(&self) -> f64 {
+        let keltner_width = self.keltner.width();
+        if keltner_width.abs() < f64::EPSILON {
+            return 0.0;
+        }
+        (self.bband.upper() - self.bband.lower()) / keltner_width
+    }
+}
+
+/// 스퀴즈 계산 빌더
+///
+/// 볼린저 밴드 빌더와 켈트너 채널 빌더를 함께 갱신합니다.
+#[derive(Debug)]
+pub struct SqueezeBuilder<C: Candle> {
+    /// 계산 매개변수
+    params: SqueezeParams,
+    /// 볼린저 밴드 빌더
+    bband_builder: BollingerBandsBuilder<C>,
+    /// 켈트너 채널 빌더
+    keltner_builder: KeltnerChannelBuilder<C>,
+}
+
+impl<C: Candle> SqueezeBuilder<C> {
+    /// 새 스퀴즈 빌더 생성
+    ///
+    /// # Arguments
+    /// * `params` - 스퀴즈 매개변수
+    ///
+    /// # Returns
+    /// * `SqueezeBuilder` - 새 빌더 인스턴스
+    ///
+    /// # Panics
+    /// * 유효하지 않은 매개변수가 제공되면 패닉 발생
+    pub fn new(params: SqueezeParams) -> Self {
+        match Self::new_checked(params) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 스퀴즈 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `params` - 스퀴즈 매개변수
+    ///
+    /// # Returns
+    /// * `IndicatorResult<SqueezeBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(params: SqueezeParams) -> IndicatorResult<Self> {
+        Ok(Self {
+            params,
+            bband_builder: BollingerBandsBuilder::new_checked(
+                params.bb_period,
+                params.bb_multiplier,
+            )?,
+            keltner_builder: KeltnerChannelBuilder::new_checked(
+                params.kc_period,
+                params.kc_atr_period,
+                params.kc_multiplier,
+            )?,
+        })
+    }
+
+    /// 계산 매개변수 반환
+    pub fn params(&self) -> &SqueezeParams {
+        &self.params
+    }
+
+    /// 저장소에서 스퀴즈 지표 생성
+    ///
+    /// # Arguments
+    /// * `storage` - 캔들 데이터 저장소
+    ///
+    /// # Returns
+    /// * `Squeeze` - 계산된 스퀴즈 지표
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Squeeze {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 스퀴즈 지표 생성
+    ///
+    /// # Arguments
+    /// * `data` - 캔들 데이터 벡터
+    ///
+    /// # Returns
+    /// * `Squeeze` - 계산된 스퀴즈 지표
+    pub fn build(&mut self, data: &[C]) -> Squeeze {
+        Squeeze {
+            bband: self.bband_builder.build(data),
+            keltner: self.keltner_builder.build(data),
+        }
+    }
+
+    /// 새 캔들 데이터로 스퀴즈 지표 업데이트
+    ///
+    /// # Arguments
+    /// * `data` - 새 캔들 데이터
+    ///
+    /// # Returns
+    /// * `Squeeze` - 업데이트된 스퀴즈 지표
+    pub fn next(&mut self, data: &C) -> Squeeze {
+        Squeeze {
+            bband: self.bband_builder.next(data),
+            keltner: self.keltner_builder.next(data),
+        }
+    }
+}
+
+impl<C: Candle> TABuilder<Squeeze, C> for SqueezeBuilder<C> {
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Squeeze {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> Squeeze {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> Squeeze {
+        self.next(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn candle(timestamp: i64, close: f64, range: f64) -> TestCandle {
+        TestCandle {
+            timestamp,
+            open: close,
+            high: close + range / 2.0,
+            low: close - range / 2.0,
+            close,
+            volume: 1000.0,
+        }
+    }
+
+    fn params() -> SqueezeParams {
+        SqueezeParams {
+            bb_period: 5,
+            bb_multiplier: 2.0,
+            kc_period: 3,
+            kc_atr_period: 2,
+            kc_multiplier: 1.5,
+        }
+    }
+
+    #[test]
+    fn test_squeeze_on_when_closes_are_flat() {
+        // 종가는 변하지 않고 캔들 폭만 있는 경우 볼린저 밴드 폭은 0, 켈트너 폭은 ATR 기반
+        let candles: Vec<TestCandle> = (1..=5).map(|i| candle(i, 100.0, 10.0)).collect();
+        let mut builder = SqueezeBuilder::<TestCandle>::new(params());
+        let squeeze = builder.build(&candles);
+
+        assert!(squeeze.is_on());
+        assert_eq!(squeeze.width_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_squeeze_off_when_trending() {
+        // 꾸준한 추세로 종가 분산이 ATR보다 커지면 볼린저 밴드가 켈트너 채널 밖으로 나감
+        let closes = [100.0, 110.0, 120.0, 130.0, 140.0];
+        let candles: Vec<TestCandle> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| candle(i as i64 + 1, *close, 1.0))
+            .collect();
+        let mut builder = SqueezeBuilder::<TestCandle>::new(params());
+        let squeeze = builder.build(&candles);
+
+        assert!(!squeeze.is_on());
+        assert!(squeeze.width_ratio() > 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "켈트너 채널 승수는 0보다 커야 합니다")]
+    fn test_squeeze_invalid_params() {
+        SqueezeBuilder::<TestCandle>::new(SqueezeParams {
+            kc_multiplier: 0.0,
+            ..SqueezeParams::default()
+        });
+    }
+}
@@ -0,0 +1,407 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::smoothing;
+use crate::indicator::rsi::RSIBuilder;
+use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use std::fmt::Display;
+use trading_chart::Candle;
+
+/// 스토캐스틱 RSI 매개변수
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct StochRSIParams {
+    /// RSI 계산 기간
+    pub rsi_period: usize,
+    /// RSI 최고/최저 구간 기간
+    pub stoch_period: usize,
+    /// %K 평활 기간
+    pub k_period: usize,
+    /// %D 계산 기간 (%K 단순이동평균)
+    pub d_period: usize,
+}
+
+impl Display for StochRSIParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "StochRSI({}, {}, {}, {})",
+            self.rsi_period, self.stoch_period, self.k_period, self.d_period
+        )
+    }
+}
+
+impl StochRSIParams {
+    /// 새 스토캐스틱 RSI 매개변수 생성
+    ///
+    /// # Arguments
+    /// * `rsi_period` - RSI 계산 기간
+    /// * `stoch_period` - RSI 최고/최저 구간 기간
+    /// * `k_period` - %K 평활 기간
+    /// * `d_period` - %D 계산 기간
+    ///
+    /// # Returns
+    /// * `StochRSIParams` - 새 매개변수 인스턴스
+    pub fn new(rsi_period: usize, stoch_period: usize, k_period: usize, d_period: usize) -> Self {
+        StochRSIParams {
+            rsi_period,
+            stoch_period,
+            k_period,
+            d_period,
+        }
+    }
+}
+
+impl Default for StochRSIParams {
+    fn default() -> Self {
+        StochRSIParams {
+            rsi_period: 14,
+            stoch_period: 14,
+            k_period: 3,
+            d_period: 3,
+        }
+    }
+}
+
+/// 스토캐스틱 RSI 기술적 지표
+///
+/// RSI에 스토캐스틱 공식을 적용해 RSI가 최근 구간에서 어디에 있는지(0-100) 나타냅니다.
+#[derive(Clone, Debug)]
+pub struct StochRSI {
+    /// 계산 매개변수
+    params: StochRSIParams,
+    /// %K 값 (0-100)
+    pub k: f64,
+    /// %D 값 (0-100)
+    pub d: f64,
+}
+
+impl Display for StochRSI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "StochRSI({}, {}, {}, {}: %K {:.2}, %D {:.2})",
+            self.params.rsi_period,
+            self.params.stoch_period,
+            self.params.k_period,
+            self.params.d_period,
+            self.k,
+            self.d
+        )
+    }
+}
+
+impl StochRSI {
+    /// 계산 매개변수 반환
+    pub fn params(&self) -> StochRSIParams {
+        self.params
+    }
+
+    /// %K 값 반환
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// %D 값 반환
+    pub fn d(&self) -> f64 {
+        self.d
+    }
+
+    /// %K가 과매수 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매수 기준값 (기본값 80.0)
+    pub fn is_overbought(&self, threshold: Option<f64>) -> bool {
+        self.k >= threshold.unwrap_or(80.0)
+    }
+
+    /// %K가 과매도 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매도 기준값 (기본값 20.0)
+    pub fn is_oversold(&self, threshold: Option<f64>) -> bool {
+        self.k <= threshold.unwrap_or(20.0)
+    }
+
+    /// %K가 %D 위에 있는지 확인
+    pub fn is_k_above_d(&self) -> bool {
+        self.k > self.d
+    }
+}
+
+/// 스토캐스틱 RSI 계산 빌더
+///
+/// 내부 RSI 빌더로 RSI를 증분 계산한 뒤 최근 `stoch_period`개 RSI의
+/// 최고/최저 구간에서 위치를 구하고, 이를 `k_period`로 평활해 %K를 만듭니다.
+/// RSI 구간이 평평하면 원시 값은 중립값 50입니다.
+#[derive(Debug)]
+pub struct StochRSIBuilder<C: Candle> {
+    /// 계산 매개변수
+    params: StochRSIParams,
+    /// 내부 RSI 빌더
+    rsi_builder: RSIBuilder<C>,
+    /// 최근 RSI 값 (최대 stoch_period개)
+    rsi_values: Vec<f64>,
+    /// 최근 원시 스토캐스틱 값 (최대 k_period개)
+    raw_values: Vec<f64>,
+    /// 최근 %K 값 (최대 d_period개)
+    k_values: Vec<f64>,
+}
+
+impl<C> StochRSIBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 스토캐스틱 RSI 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(params: StochRSIParams) -> Self {
+        match Self::new_checked(params) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 스토캐스틱 RSI 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `params` - 스토캐스틱 RSI 매개변수
+    ///
+    /// # Returns
+    /// * `IndicatorResult<StochRSIBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(params: StochRSIParams) -> IndicatorResult<Self> {
+        let rsi_builder = RSIBuilder::new_checked(params.rsi_period)?;
+        if params.stoch_period == 0 {
+            return Err("스토캐스틱 RSI 구간 기간은 0보다 커야 합니다".to_string());
+        }
+        if params.k_period == 0 {
+            return Err("스토캐스틱 RSI %K 기간은 0보다 커야 합니다".to_string());
+        }
+        if params.d_period == 0 {
+            return Err("스토캐스틱 RSI %D 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            params,
+            rsi_builder,
+            rsi_values: Vec::with_capacity(params.stoch_period),
+            raw_values: Vec::with_capacity(params.k_period),
+            k_values: Vec::with_capacity(params.d_period),
+        })
+    }
+
+    /// 저장소에서 스토캐스틱 RSI 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> StochRSI {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 스토캐스틱 RSI 지표 생성 (데이터가 없으면 50)
+    pub fn build(&mut self, data: &[C]) -> StochRSI {
+        self.rsi_builder = RSIBuilder::new(self.params.rsi_period);
+        self.rsi_values.clear();
+        self.raw_values.clear();
+        self.k_values.clear();
+
+        let mut stoch_rsi = StochRSI {
+            params: self.params,
+            k: 50.0,
+            d: 50.0,
+        };
+        for candle in data {
+            stoch_rsi = self.next(candle);
+        }
+        stoch_rsi
+    }
+
+    /// 새 캔들 데이터로 스토캐스틱 RSI 지표 업데이트
+    pub fn next(&mut self, data: &C) -> StochRSI {
+        let rsi = self.rsi_builder.next(data).value();
+        self.rsi_values.push(rsi);
+        smoothing::truncate_front(&mut self.rsi_values, self.params.stoch_period);
+
+        let highest = self.rsi_values.iter().copied().fold(f64::MIN, f64::max);
+        let lowest = self.rsi_values.iter().copied().fold(f64::MAX, f64::min);
+        let range = highest - lowest;
+        let raw = if range > 0.0 {
+            ((rsi - lowest) / range * 100.0).clamp(0.0, 100.0)
+        } else {
+            50.0
+        };
+
+        self.raw_values.push(raw);
+        smoothing::truncate_front(&mut self.raw_values, self.params.k_period);
+        let k = self.raw_values.iter().sum::<f64>() / self.raw_values.len() as f64;
+
+        self.k_values.push(k);
+        smoothing::truncate_front(&mut self.k_values, self.params.d_period);
+        let d = self.k_values.iter().sum::<f64>() / self.k_values.len() as f64;
+
+        StochRSI {
+            params: self.params,
+            k,
+            d,
+        }
+    }
+}
+
+impl<C> TABuilder<StochRSI, C> for StochRSIBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> StochRSI {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> StochRSI {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> StochRSI {
+        self.next(data)
+    }
+}
+
+/// 여러 매개변수의 스토캐스틱 RSI 지표 컬렉션 타입
+pub type StochRSIs = TAs<StochRSIParams, StochRSI>;
+
+/// 여러 매개변수의 스토캐스틱 RSI 지표 빌더 타입
+pub type StochRSIsBuilder<C> = TAsBuilder<StochRSIParams, StochRSI, C>;
+
+/// 스토캐스틱 RSI 컬렉션 빌더 팩토리
+pub struct StochRSIsBuilderFactory;
+
+impl StochRSIsBuilderFactory {
+    /// 여러 매개변수의 스토캐스틱 RSI 빌더 생성
+    ///
+    /// # Arguments
+    /// * `params_list` - 스토캐스틱 RSI 매개변수 목록
+    ///
+    /// # Returns
+    /// * `StochRSIsBuilder` - 여러 매개변수의 스토캐스틱 RSI 빌더
+    pub fn build<C: Candle + 'static>(params_list: &[StochRSIParams]) -> StochRSIsBuilder<C> {
+        match Self::build_checked(params_list) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 여러 매개변수의 스토캐스틱 RSI 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `params_list` - 스토캐스틱 RSI 매개변수 목록
+    ///
+    /// # Returns
+    /// * `IndicatorResult<StochRSIsBuilder>` - 스토캐스틱 RSI 빌더 또는 에러
+    pub fn build_checked<C: Candle + 'static>(
+        params_list: &[StochRSIParams],
+    ) -> IndicatorResult<StochRSIsBuilder<C>> {
+        for params in params_list {
+            StochRSIBuilder::<C>::new_checked(*params)?;
+        }
+
+        Ok(StochRSIsBuilder::new(
+            "stoch_rsis".to_owned(),
+            params_list,
+            |params| Box::new(StochRSIBuilder::<C>::new(*params)),
+        ))
+    }
+
+    /// 기본 스토캐스틱 RSI 빌더 생성 (14, 14, 3, 3)
+    pub fn build_default<C: Candle + 'static>() -> StochRSIsBuilder<C> {
+        Self::build(&[StochRSIParams::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stoch_rsi_tracks_rsi_position() {
+        // 하락 후 강한 반등: RSI가 최근 구간 최고치에 위치
+        let mut closes: Vec<f64> = (0..10).map(|i| 120.0 - i as f64 * 2.0).collect();
+        closes.extend((1..=4).map(|i| 102.0 + i as f64 * 5.0));
+        let candles = create_test_candles(&closes);
+
+        let stoch_rsi = StochRSIBuilder::new(StochRSIParams::new(3, 5, 1, 1)).build(&candles);
+        assert_eq!(stoch_rsi.k(), 100.0);
+        assert!(stoch_rsi.is_overbought(None));
+
+        // 반대로 상승 후 급락하면 구간 최저치에 위치
+        let mut closes: Vec<f64> = (0..10).map(|i| 100.0 + i as f64 * 2.0).collect();
+        closes.extend((1..=4).map(|i| 118.0 - i as f64 * 5.0));
+        let candles = create_test_candles(&closes);
+
+        let stoch_rsi = StochRSIBuilder::new(StochRSIParams::new(3, 5, 1, 1)).build(&candles);
+        assert_eq!(stoch_rsi.k(), 0.0);
+        assert!(stoch_rsi.is_oversold(None));
+    }
+
+    #[test]
+    fn test_stoch_rsi_flat_is_neutral() {
+        let candles = create_test_candles(&[100.0; 20]);
+        let stoch_rsi = StochRSIBuilder::new(StochRSIParams::default()).build(&candles);
+
+        assert_eq!(stoch_rsi.k(), 50.0);
+        assert_eq!(stoch_rsi.d(), 50.0);
+        assert!(stoch_rsi.to_string().contains("StochRSI(14, 14, 3, 3"));
+    }
+
+    #[test]
+    fn test_stoch_rsi_next_matches_build() {
+        let closes = [
+            100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 102.0, 99.0, 101.0, 105.0, 108.0, 104.0,
+        ];
+        let candles = create_test_candles(&closes);
+        let params = StochRSIParams::new(3, 4, 2, 2);
+
+        let mut builder = StochRSIBuilder::new(params);
+        builder.build(&candles[..8]);
+        let mut incremental = builder.next(&candles[8]);
+        for candle in &candles[9..] {
+            incremental = builder.next(candle);
+        }
+
+        let expected = StochRSIBuilder::new(params).build(&candles);
+        assert!((incremental.k() - expected.k()).abs() < 1e-10);
+        assert!((incremental.d() - expected.d()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_stoch_rsis_builder_factory() {
+        let candles = create_test_candles(&[100.0, 101.0, 103.0, 102.0, 104.0]);
+        let stoch_rsis = StochRSIsBuilderFactory::build_default::<TestCandle>().build(&candles);
+
+        assert_eq!(stoch_rsis.len(), 1);
+        assert_eq!(
+            stoch_rsis.get(&StochRSIParams::default()).params(),
+            StochRSIParams::default()
+        );
+        assert!(
+            StochRSIsBuilderFactory::build_checked::<TestCandle>(&[StochRSIParams::new(
+                14, 0, 3, 3
+            )])
+            .is_err()
+        );
+        assert!(
+            StochRSIsBuilderFactory::build_checked::<TestCandle>(&[StochRSIParams::new(
+                0, 14, 3, 3
+            )])
+            .is_err()
+        );
+    }
+}
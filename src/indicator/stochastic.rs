@@ -0,0 +1,389 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::smoothing;
+use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 스토캐스틱 매개변수
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct StochasticParams {
+    /// %K 계산 기간 (최고가/최저가 구간)
+    pub k_period: usize,
+    /// %D 계산 기간 (%K 단순이동평균)
+    pub d_period: usize,
+}
+
+impl Display for StochasticParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Stochastic({}, {})", self.k_period, self.d_period)
+    }
+}
+
+impl StochasticParams {
+    /// 새 스토캐스틱 매개변수 생성
+    ///
+    /// # Arguments
+    /// * `k_period` - %K 계산 기간
+    /// * `d_period` - %D 계산 기간
+    ///
+    /// # Returns
+    /// * `StochasticParams` - 새 매개변수 인스턴스
+    pub fn new(k_period: usize, d_period: usize) -> Self {
+        StochasticParams { k_period, d_period }
+    }
+}
+
+impl Default for StochasticParams {
+    fn default() -> Self {
+        StochasticParams {
+            k_period: 14,
+            d_period: 3,
+        }
+    }
+}
+
+/// 스토캐스틱 오실레이터 기술적 지표
+///
+/// %K는 최근 `k_period` 구간의 최고가/최저가 사이에서 종가의 위치(0-100),
+/// %D는 %K의 `d_period` 단순이동평균입니다.
+#[derive(Clone, Debug)]
+pub struct Stochastic {
+    /// 계산 매개변수
+    params: StochasticParams,
+    /// %K 값 (0-100)
+    pub k: f64,
+    /// %D 값 (0-100)
+    pub d: f64,
+}
+
+impl Display for Stochastic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Stochastic({}, {}: %K {:.2}, %D {:.2})",
+            self.params.k_period, self.params.d_period, self.k, self.d
+        )
+    }
+}
+
+impl Stochastic {
+    /// 계산 매개변수 반환
+    pub fn params(&self) -> StochasticParams {
+        self.params
+    }
+
+    /// %K 값 반환
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// %D 값 반환
+    pub fn d(&self) -> f64 {
+        self.d
+    }
+
+    /// %K가 과매수 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매수 기준값 (기본값 80.0)
+    pub fn is_overbought(&self, threshold: Option<f64>) -> bool {
+        self.k >= threshold.unwrap_or(80.0)
+    }
+
+    /// %K가 과매도 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매도 기준값 (기본값 20.0)
+    pub fn is_oversold(&self, threshold: Option<f64>) -> bool {
+        self.k <= threshold.unwrap_or(20.0)
+    }
+
+    /// %K가 %D 위에 있는지 확인
+    pub fn is_k_above_d(&self) -> bool {
+        self.k > self.d
+    }
+}
+
+/// 스토캐스틱 계산 빌더
+///
+/// 데이터가 기간보다 적으면 있는 캔들만으로 계산합니다.
+/// 최고가와 최저가가 같으면 %K는 중립값 50입니다.
+#[derive(Debug)]
+pub struct StochasticBuilder<C: Candle> {
+    /// 계산 매개변수
+    params: StochasticParams,
+    /// 최근 고가 데이터 (최대 k_period개)
+    highs: Vec<f64>,
+    /// 최근 저가 데이터 (최대 k_period개)
+    lows: Vec<f64>,
+    /// 최근 %K 값 (최대 d_period개)
+    k_values: Vec<f64>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> StochasticBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 스토캐스틱 빌더 생성
+    ///
+    /// # Panics
+    /// * 유효하지 않은 기간이 제공되면 패닉 발생
+    pub fn new(params: StochasticParams) -> Self {
+        match Self::new_checked(params) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 스토캐스틱 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `params` - 스토캐스틱 매개변수
+    ///
+    /// # Returns
+    /// * `IndicatorResult<StochasticBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(params: StochasticParams) -> IndicatorResult<Self> {
+        if params.k_period == 0 {
+            return Err("스토캐스틱 %K 기간은 0보다 커야 합니다".to_string());
+        }
+        if params.d_period == 0 {
+            return Err("스토캐스틱 %D 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            params,
+            highs: Vec::with_capacity(params.k_period),
+            lows: Vec::with_capacity(params.k_period),
+            k_values: Vec::with_capacity(params.d_period),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 스토캐스틱 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Stochastic {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 스토캐스틱 지표 생성 (데이터가 없으면 50)
+    pub fn build(&mut self, data: &[C]) -> Stochastic {
+        self.highs.clear();
+        self.lows.clear();
+        self.k_values.clear();
+
+        let mut stochastic = Stochastic {
+            params: self.params,
+            k: 50.0,
+            d: 50.0,
+        };
+        for candle in data {
+            stochastic = self.next(candle);
+        }
+        stochastic
+    }
+
+    /// 새 캔들 데이터로 스토캐스틱 지표 업데이트
+    pub fn next(&mut self, data: &C) -> Stochastic {
+        self.highs.push(data.high_price());
+        self.lows.push(data.low_price());
+        smoothing::truncate_front(&mut self.highs, self.params.k_period);
+        smoothing::truncate_front(&mut self.lows, self.params.k_period);
+
+        let highest = self.highs.iter().copied().fold(f64::MIN, f64::max);
+        let lowest = self.lows.iter().copied().fold(f64::MAX, f64::min);
+        let range = highest - lowest;
+        let k = if range > 0.0 {
+            ((data.close_price() - lowest) / range * 100.0).clamp(0.0, 100.0)
+        } else {
+            50.0
+        };
+
+        self.k_values.push(k);
+        smoothing::truncate_front(&mut self.k_values, self.params.d_period);
+        let d = self.k_values.iter().sum::<f64>() / self.k_values.len() as f64;
+
+        Stochastic {
+            params: self.params,
+            k,
+            d,
+        }
+    }
+}
+
+impl<C> TABuilder<Stochastic, C> for StochasticBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> Stochastic {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> Stochastic {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> Stochastic {
+        self.next(data)
+    }
+}
+
+/// 여러 매개변수의 스토캐스틱 지표 컬렉션 타입
+pub type Stochastics = TAs<StochasticParams, Stochastic>;
+
+/// 여러 매개변수의 스토캐스틱 지표 빌더 타입
+pub type StochasticsBuilder<C> = TAsBuilder<StochasticParams, Stochastic, C>;
+
+/// 스토캐스틱 컬렉션 빌더 팩토리
+pub struct StochasticsBuilderFactory;
+
+impl StochasticsBuilderFactory {
+    /// 여러 매개변수의 스토캐스틱 빌더 생성
+    ///
+    /// # Arguments
+    /// * `params_list` - 스토캐스틱 매개변수 목록
+    ///
+    /// # Returns
+    /// * `StochasticsBuilder` - 여러 매개변수의 스토캐스틱 빌더
+    pub fn build<C: Candle + 'static>(params_list: &[StochasticParams]) -> StochasticsBuilder<C> {
+        match Self::build_checked(params_list) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 여러 매개변수의 스토캐스틱 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `params_list` - 스토캐스틱 매개변수 목록
+    ///
+    /// # Returns
+    /// * `IndicatorResult<StochasticsBuilder>` - 스토캐스틱 빌더 또는 에러
+    pub fn build_checked<C: Candle + 'static>(
+        params_list: &[StochasticParams],
+    ) -> IndicatorResult<StochasticsBuilder<C>> {
+        for params in params_list {
+            StochasticBuilder::<C>::new_checked(*params)?;
+        }
+
+        Ok(StochasticsBuilder::new(
+            "stochastics".to_owned(),
+            params_list,
+            |params| Box::new(StochasticBuilder::<C>::new(*params)),
+        ))
+    }
+
+    /// 기본 스토캐스틱 빌더 생성 (14, 3)
+    pub fn build_default<C: Candle + 'static>() -> StochasticsBuilder<C> {
+        Self::build(&[StochasticParams::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (high, low, close))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: *high,
+                low: *low,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stochastic_exact_calculation() {
+        let candles = create_test_candles(&[
+            (110.0, 90.0, 100.0),
+            (120.0, 100.0, 115.0),
+            (118.0, 105.0, 110.0),
+        ]);
+        let stochastic = StochasticBuilder::new(StochasticParams::new(2, 2)).build(&candles);
+
+        // 최근 2개 캔들: 최고가 120, 최저가 100
+        // %K = (110 - 100) / 20 * 100 = 50, 이전 %K = (115 - 90) / 30 * 100
+        let previous_k = 25.0 / 30.0 * 100.0;
+        assert!((stochastic.k() - 50.0).abs() < 1e-10);
+        assert!((stochastic.d() - (50.0 + previous_k) / 2.0).abs() < 1e-10);
+        assert!(!stochastic.is_k_above_d());
+        assert!(stochastic.to_string().contains("Stochastic(2, 2"));
+    }
+
+    #[test]
+    fn test_stochastic_overbought_oversold() {
+        let rising = create_test_candles(&[
+            (101.0, 99.0, 100.0),
+            (103.0, 100.0, 102.0),
+            (106.0, 102.0, 106.0),
+        ]);
+        let stochastic = StochasticBuilder::new(StochasticParams::new(3, 1)).build(&rising);
+        assert_eq!(stochastic.k(), 100.0);
+        assert!(stochastic.is_overbought(None));
+
+        let falling = create_test_candles(&[
+            (101.0, 99.0, 100.0),
+            (100.0, 96.0, 97.0),
+            (97.0, 94.0, 94.0),
+        ]);
+        let stochastic = StochasticBuilder::new(StochasticParams::new(3, 1)).build(&falling);
+        assert_eq!(stochastic.k(), 0.0);
+        assert!(stochastic.is_oversold(None));
+
+        // 가격 변화가 없으면 중립
+        let flat = create_test_candles(&[(100.0, 100.0, 100.0); 3]);
+        let stochastic = StochasticBuilder::new(StochasticParams::new(3, 1)).build(&flat);
+        assert_eq!(stochastic.k(), 50.0);
+    }
+
+    #[test]
+    fn test_stochastic_next_matches_build() {
+        let candles = create_test_candles(&[
+            (110.0, 90.0, 100.0),
+            (120.0, 100.0, 115.0),
+            (118.0, 105.0, 110.0),
+            (125.0, 108.0, 124.0),
+            (124.0, 112.0, 113.0),
+        ]);
+        let params = StochasticParams::new(3, 2);
+        let mut builder = StochasticBuilder::new(params);
+        builder.build(&candles[..3]);
+        builder.next(&candles[3]);
+        let incremental = builder.next(&candles[4]);
+
+        let expected = StochasticBuilder::new(params).build(&candles);
+        assert_eq!(incremental.k(), expected.k());
+        assert_eq!(incremental.d(), expected.d());
+    }
+
+    #[test]
+    fn test_stochastics_builder_factory() {
+        let candles = create_test_candles(&[
+            (110.0, 90.0, 100.0),
+            (120.0, 100.0, 115.0),
+            (118.0, 105.0, 110.0),
+        ]);
+        let params_list = [StochasticParams::new(2, 2), StochasticParams::default()];
+        let stochastics =
+            StochasticsBuilderFactory::build::<TestCandle>(&params_list).build(&candles);
+
+        assert_eq!(stochastics.len(), 2);
+        assert_eq!(stochastics.get(&params_list[0]).params(), params_list[0]);
+        assert!(
+            StochasticsBuilderFactory::build_checked::<TestCandle>(&[StochasticParams::new(3, 0)])
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "스토캐스틱 %K 기간은 0보다 커야 합니다")]
+    fn test_stochastic_invalid_period() {
+        StochasticBuilder::<TestCandle>::new(StochasticParams::new(0, 3));
+    }
+}
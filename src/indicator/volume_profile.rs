@@ -0,0 +1,420 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::{IndicatorResult, TABuilder};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// 밸류 에어리어에 포함할 거래량 비율
+pub const VALUE_AREA_RATIO: f64 = 0.7;
+
+/// 볼륨 프로파일의 가격 구간
+#[derive(Clone, Debug, PartialEq)]
+pub struct VolumeBucket {
+    /// 구간 하단 가격
+    pub low: f64,
+    /// 구간 상단 가격
+    pub high: f64,
+    /// 구간에 배분된 거래량
+    pub volume: f64,
+}
+
+impl VolumeBucket {
+    /// 구간 중간 가격
+    pub fn price(&self) -> f64 {
+        (self.low + self.high) / 2.0
+    }
+}
+
+/// 볼륨 프로파일 기술적 지표
+///
+/// 최근 `period`개 캔들의 거래량을 가격 구간별 히스토그램으로 나타냅니다.
+/// 캔들의 거래량은 고가-저가 범위와 겹치는 길이에 비례해 구간마다 배분합니다.
+#[derive(Clone, Debug)]
+pub struct VolumeProfile {
+    /// 계산 기간
+    period: usize,
+    /// 가격 오름차순 구간 목록
+    pub buckets: Vec<VolumeBucket>,
+    /// POC (거래량이 가장 많은 구간의 중간 가격)
+    pub poc: f64,
+    /// 밸류 에어리어 상단 가격
+    pub value_area_high: f64,
+    /// 밸류 에어리어 하단 가격
+    pub value_area_low: f64,
+    /// HVN (주변 구간보다 거래량이 많은 구간의 중간 가격, 오름차순)
+    pub high_volume_nodes: Vec<f64>,
+    /// LVN (주변 구간보다 거래량이 적은 구간의 중간 가격, 오름차순)
+    pub low_volume_nodes: Vec<f64>,
+}
+
+impl Display for VolumeProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "VolumeProfile({}: poc={:.2}, va=[{:.2}, {:.2}])",
+            self.period, self.poc, self.value_area_low, self.value_area_high
+        )
+    }
+}
+
+impl VolumeProfile {
+    /// 빈 볼륨 프로파일 생성
+    fn empty(period: usize) -> VolumeProfile {
+        VolumeProfile {
+            period,
+            buckets: Vec::new(),
+            poc: 0.0,
+            value_area_high: 0.0,
+            value_area_low: 0.0,
+            high_volume_nodes: Vec::new(),
+            low_volume_nodes: Vec::new(),
+        }
+    }
+
+    /// 계산 기간 반환
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 전체 거래량 반환
+    pub fn total_volume(&self) -> f64 {
+        self.buckets.iter().map(|bucket| bucket.volume).sum()
+    }
+
+    /// 가격이 밸류 에어리어 안(경계 포함)에 있는지 확인
+    pub fn is_inside_value_area(&self, price: f64) -> bool {
+        !self.buckets.is_empty() && self.value_area_low <= price && price <= self.value_area_high
+    }
+
+    /// 가격이 밸류 에어리어 위에 있는지 확인
+    pub fn is_above_value_area(&self, price: f64) -> bool {
+        !self.buckets.is_empty() && price > self.value_area_high
+    }
+
+    /// 가격이 밸류 에어리어 아래에 있는지 확인
+    pub fn is_below_value_area(&self, price: f64) -> bool {
+        !self.buckets.is_empty() && price < self.value_area_low
+    }
+
+    /// 가격이 HVN 근처(거리 `threshold` 이내)에 있는지 확인
+    pub fn is_near_high_volume_node(&self, price: f64, threshold: f64) -> bool {
+        self.high_volume_nodes
+            .iter()
+            .any(|node| (price - node).abs() <= threshold)
+    }
+
+    /// 가격이 LVN 근처(거리 `threshold` 이내)에 있는지 확인
+    pub fn is_near_low_volume_node(&self, price: f64, threshold: f64) -> bool {
+        self.low_volume_nodes
+            .iter()
+            .any(|node| (price - node).abs() <= threshold)
+    }
+}
+
+/// 볼륨 프로파일 계산 빌더
+///
+/// 캔들마다 최근 `period`개 캔들의 가격 범위를 `bucket_count`개 구간으로 나누어 다시 계산합니다.
+/// 밸류 에어리어는 POC에서 시작해 거래량이 더 많은 인접 구간으로 넓혀 가며
+/// 전체 거래량의 [`VALUE_AREA_RATIO`] 이상을 덮을 때까지 확장합니다.
+#[derive(Debug)]
+pub struct VolumeProfileBuilder<C: Candle> {
+    /// 계산 기간
+    period: usize,
+    /// 가격 구간 수
+    bucket_count: usize,
+    /// 최근 캔들의 (고가, 저가, 거래량) (최대 period개)
+    window: Vec<(f64, f64, f64)>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> VolumeProfileBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 볼륨 프로파일 빌더 생성
+    ///
+    /// # Panics
+    /// * 기간이나 구간 수가 0이면 패닉 발생
+    pub fn new(period: usize, bucket_count: usize) -> Self {
+        match Self::new_checked(period, bucket_count) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 볼륨 프로파일 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 계산 기간
+    /// * `bucket_count` - 가격 구간 수
+    ///
+    /// # Returns
+    /// * `IndicatorResult<VolumeProfileBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize, bucket_count: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("볼륨 프로파일 기간은 0보다 커야 합니다".to_string());
+        }
+        if bucket_count == 0 {
+            return Err("볼륨 프로파일 구간 수는 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            bucket_count,
+            window: Vec::with_capacity(period),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 볼륨 프로파일 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> VolumeProfile {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 볼륨 프로파일 지표 생성 (데이터가 없으면 빈 프로파일)
+    pub fn build(&mut self, data: &[C]) -> VolumeProfile {
+        self.window.clear();
+        for candle in &data[data.len().saturating_sub(self.period)..] {
+            self.window
+                .push((candle.high_price(), candle.low_price(), candle.volume()));
+        }
+
+        self.calculate()
+    }
+
+    /// 새 캔들 데이터로 볼륨 프로파일 지표 업데이트
+    pub fn next(&mut self, data: &C) -> VolumeProfile {
+        self.window
+            .push((data.high_price(), data.low_price(), data.volume()));
+        if self.window.len() > self.period {
+            let excess = self.window.len() - self.period;
+            self.window.drain(0..excess);
+        }
+
+        self.calculate()
+    }
+
+    /// 현재 구간 데이터로 볼륨 프로파일 계산
+    fn calculate(&self) -> VolumeProfile {
+        if self.window.is_empty() {
+            return VolumeProfile::empty(self.period);
+        }
+
+        let low = self
+            .window
+            .iter()
+            .map(|(_, low, _)| *low)
+            .fold(f64::MAX, f64::min);
+        let high = self
+            .window
+            .iter()
+            .map(|(high, _, _)| *high)
+            .fold(f64::MIN, f64::max);
+
+        // 가격 범위가 없으면 전체 거래량이 한 구간에 모임
+        let bucket_count = if high > low { self.bucket_count } else { 1 };
+        let width = (high - low) / bucket_count as f64;
+        let mut buckets: Vec<VolumeBucket> = (0..bucket_count)
+            .map(|i| VolumeBucket {
+                low: low + width * i as f64,
+                high: if i + 1 == bucket_count {
+                    high
+                } else {
+                    low + width * (i + 1) as f64
+                },
+                volume: 0.0,
+            })
+            .collect();
+
+        for (candle_high, candle_low, volume) in &self.window {
+            let range = candle_high - candle_low;
+            if range > 0.0 {
+                for bucket in buckets.iter_mut() {
+                    let overlap = candle_high.min(bucket.high) - candle_low.max(bucket.low);
+                    if overlap > 0.0 {
+                        bucket.volume += volume * overlap / range;
+                    }
+                }
+            } else {
+                let index = if width > 0.0 {
+                    (((candle_low - low) / width) as usize).min(bucket_count - 1)
+                } else {
+                    0
+                };
+                buckets[index].volume += volume;
+            }
+        }
+
+        let volumes: Vec<f64> = buckets.iter().map(|bucket| bucket.volume).collect();
+        // 거래량이 같으면 낮은 가격 구간을 POC로 선택
+        let mut poc_index = 0;
+        for (i, volume) in volumes.iter().enumerate() {
+            if *volume > volumes[poc_index] {
+                poc_index = i;
+            }
+        }
+
+        let (value_area_low_index, value_area_high_index) = Self::value_area(&volumes, poc_index);
+
+        let mean_volume = volumes.iter().sum::<f64>() / bucket_count as f64;
+        let mut high_volume_nodes = Vec::new();
+        let mut low_volume_nodes = Vec::new();
+        for (i, bucket) in buckets.iter().enumerate() {
+            let below = i.checked_sub(1).map(|j| volumes[j]);
+            let above = volumes.get(i + 1).copied();
+            if below.is_none() && above.is_none() {
+                continue;
+            }
+
+            let is_peak =
+                below.is_none_or(|v| bucket.volume > v) && above.is_none_or(|v| bucket.volume > v);
+            if is_peak && bucket.volume > mean_volume {
+                high_volume_nodes.push(bucket.price());
+            }
+
+            // LVN은 양쪽 이웃이 모두 있는 골짜기만 인정
+            let is_trough = match (below, above) {
+                (Some(below), Some(above)) => bucket.volume < below && bucket.volume < above,
+                _ => false,
+            };
+            if is_trough && bucket.volume < mean_volume {
+                low_volume_nodes.push(bucket.price());
+            }
+        }
+
+        VolumeProfile {
+            period: self.period,
+            poc: buckets[poc_index].price(),
+            value_area_high: buckets[value_area_high_index].high,
+            value_area_low: buckets[value_area_low_index].low,
+            high_volume_nodes,
+            low_volume_nodes,
+            buckets,
+        }
+    }
+
+    /// POC에서 시작한 밸류 에어리어의 (하단, 상단) 구간 인덱스 계산
+    fn value_area(volumes: &[f64], poc_index: usize) -> (usize, usize) {
+        let target = volumes.iter().sum::<f64>() * VALUE_AREA_RATIO;
+        let (mut lower, mut upper) = (poc_index, poc_index);
+        let mut accumulated = volumes[poc_index];
+
+        while accumulated < target && (lower > 0 || upper + 1 < volumes.len()) {
+            let below = if lower > 0 { volumes[lower - 1] } else { -1.0 };
+            let above = volumes.get(upper + 1).copied().unwrap_or(-1.0);
+            if above >= below {
+                upper += 1;
+                accumulated += above;
+            } else {
+                lower -= 1;
+                accumulated += below;
+            }
+        }
+
+        (lower, upper)
+    }
+}
+
+impl<C> TABuilder<VolumeProfile, C> for VolumeProfileBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> VolumeProfile {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> VolumeProfile {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> VolumeProfile {
+        self.next(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (high, low, volume))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *low,
+                high: *high,
+                low: *low,
+                close: *high,
+                volume: *volume,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_volume_profile_exact_calculation() {
+        // 구간 [100, 101], [101, 102], [102, 103], [103, 104]
+        // 첫 캔들은 네 구간에 100씩, 둘째는 두 번째 구간에, 셋째는 마지막 구간에 배분
+        let candles = create_test_candles(&[
+            (104.0, 100.0, 400.0),
+            (102.0, 101.0, 200.0),
+            (104.0, 103.0, 100.0),
+        ]);
+        let profile = VolumeProfileBuilder::new(3, 4).build(&candles);
+
+        let volumes: Vec<f64> = profile.buckets.iter().map(|b| b.volume).collect();
+        assert_eq!(volumes, vec![100.0, 300.0, 100.0, 200.0]);
+        assert_eq!(profile.total_volume(), 700.0);
+        assert_eq!(profile.poc, 101.5);
+
+        // 300 → 위쪽(100) → 위쪽(200) = 600 >= 700 * 0.7
+        assert_eq!(profile.value_area_low, 101.0);
+        assert_eq!(profile.value_area_high, 104.0);
+        assert_eq!(profile.high_volume_nodes, vec![101.5, 103.5]);
+        assert_eq!(profile.low_volume_nodes, vec![102.5]);
+        assert_eq!(
+            profile.to_string(),
+            "VolumeProfile(3: poc=101.50, va=[101.00, 104.00])"
+        );
+
+        assert!(profile.is_inside_value_area(102.0));
+        assert!(profile.is_below_value_area(100.5));
+        assert!(!profile.is_above_value_area(104.0));
+        assert!(profile.is_near_high_volume_node(103.0, 0.5));
+        assert!(profile.is_near_low_volume_node(102.4, 0.2));
+    }
+
+    #[test]
+    fn test_volume_profile_next_drops_old_candles() {
+        let candles = create_test_candles(&[
+            (110.0, 90.0, 1000.0),
+            (102.0, 101.0, 200.0),
+            (104.0, 103.0, 100.0),
+        ]);
+        let mut builder = VolumeProfileBuilder::new(2, 3);
+        builder.build(&candles[..2]);
+        let profile = builder.next(&candles[2]);
+
+        // 첫 캔들이 빠져 범위는 [101, 104], 구간 폭 1
+        assert_eq!(profile.buckets.first().unwrap().low, 101.0);
+        assert_eq!(profile.buckets.last().unwrap().high, 104.0);
+        assert_eq!(profile.poc, 101.5);
+        assert_eq!(profile.total_volume(), 300.0);
+    }
+
+    #[test]
+    fn test_volume_profile_flat_and_empty() {
+        let flat = create_test_candles(&[(100.0, 100.0, 300.0), (100.0, 100.0, 200.0)]);
+        let profile = VolumeProfileBuilder::new(5, 10).build(&flat);
+        assert_eq!(profile.buckets.len(), 1);
+        assert_eq!(profile.poc, 100.0);
+        assert_eq!(profile.total_volume(), 500.0);
+        assert!(profile.high_volume_nodes.is_empty());
+
+        let empty = VolumeProfileBuilder::<TestCandle>::new(5, 10).build(&[]);
+        assert!(empty.buckets.is_empty());
+        assert!(!empty.is_inside_value_area(0.0));
+        assert!(VolumeProfileBuilder::<TestCandle>::new_checked(5, 0).is_err());
+        assert!(VolumeProfileBuilder::<TestCandle>::new_checked(0, 5).is_err());
+    }
+}
@@ -1,21 +1,78 @@
 use crate::candle_store::CandleStore;
 use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use chrono::{DateTime, Datelike, Utc};
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 use trading_chart::Candle;
 
+/// VWAP 누적 기준 (앵커)
+///
+/// 롤링 모드는 최근 `period`개 캔들로 계산하고, 나머지 모드는 앵커 시점부터 누적합니다.
+/// 세션 경계는 `Candle::datetime` 기준 UTC로 판단합니다.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum VWAPAnchor {
+    /// 최근 period개 캔들 (period가 0이면 모든 데이터 사용)
+    #[default]
+    Rolling,
+    /// UTC 날짜가 바뀔 때마다 초기화
+    Day,
+    /// UTC 주(월요일 시작)가 바뀔 때마다 초기화
+    Week,
+    /// UTC 월이 바뀔 때마다 초기화
+    Month,
+    /// 지정한 시각(Unix 초)부터 누적
+    Timestamp(i64),
+}
+
+impl Display for VWAPAnchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VWAPAnchor::Rolling => write!(f, "ROLLING"),
+            VWAPAnchor::Day => write!(f, "DAY"),
+            VWAPAnchor::Week => write!(f, "WEEK"),
+            VWAPAnchor::Month => write!(f, "MONTH"),
+            VWAPAnchor::Timestamp(timestamp) => write!(f, "TIMESTAMP({timestamp})"),
+        }
+    }
+}
+
+impl VWAPAnchor {
+    /// 캔들이 속한 세션 키 (키가 바뀌면 누적값을 초기화)
+    ///
+    /// 롤링 모드는 세션이 없으므로 None을 반환합니다.
+    /// 타임스탬프 앵커 이전 캔들은 캔들마다 세션이 달라 VWAP이 대표 가격과 같습니다.
+    fn session_key(&self, datetime: DateTime<Utc>) -> Option<i64> {
+        let date = datetime.date_naive();
+        match self {
+            VWAPAnchor::Rolling => None,
+            VWAPAnchor::Day => Some(i64::from(date.num_days_from_ce())),
+            VWAPAnchor::Week => Some(
+                i64::from(date.num_days_from_ce())
+                    - i64::from(date.weekday().num_days_from_monday()),
+            ),
+            VWAPAnchor::Month => Some(i64::from(date.year()) * 12 + i64::from(date.month0())),
+            VWAPAnchor::Timestamp(anchor) => Some(datetime.timestamp().min(*anchor)),
+        }
+    }
+}
+
 /// VWAP(거래량가중평균가격) 매개변수
 ///
 /// VWAP 계산에 필요한 매개변수 설정을 저장합니다.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct VWAPParams {
-    /// 기간 (0이면 모든 데이터 사용)
+    /// 기간 (0이면 모든 데이터 사용, 롤링 모드에서만 사용)
     pub period: usize,
+    /// 누적 기준
+    pub anchor: VWAPAnchor,
 }
 
 impl Display for VWAPParams {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "VWAP({})", self.period)
+        match self.anchor {
+            VWAPAnchor::Rolling => write!(f, "VWAP({})", self.period),
+            anchor => write!(f, "VWAP({anchor})"),
+        }
     }
 }
 
@@ -28,7 +85,21 @@ impl VWAPParams {
     /// # Returns
     /// * `VWAPParams` - 새 VWAPParams 인스턴스
     pub fn new(period: usize) -> Self {
-        VWAPParams { period }
+        VWAPParams {
+            period,
+            anchor: VWAPAnchor::Rolling,
+        }
+    }
+
+    /// 앵커 VWAP 매개변수 생성
+    ///
+    /// # Arguments
+    /// * `anchor` - 누적 기준
+    ///
+    /// # Returns
+    /// * `VWAPParams` - 새 VWAPParams 인스턴스
+    pub fn anchored(anchor: VWAPAnchor) -> Self {
+        VWAPParams { period: 0, anchor }
     }
 }
 
@@ -47,15 +118,56 @@ pub struct VWAP {
     pub params: VWAPParams,
     /// VWAP 값
     pub value: f64,
+    /// 거래량 가중 표준편차 (밴드 계산용)
+    pub std_dev: f64,
 }
 
 impl Display for VWAP {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "VWAP({}): {:.2}", self.params.period, self.value)
+        write!(f, "{}: {:.2}", self.params, self.value)
     }
 }
 
 impl VWAP {
+    /// 누적 합계로 VWAP과 거래량 가중 표준편차 계산
+    ///
+    /// # Arguments
+    /// * `params` - VWAP 매개변수
+    /// * `cumulative_pv` - 누적 (가격 * 거래량) 합계
+    /// * `cumulative_p2v` - 누적 (가격² * 거래량) 합계
+    /// * `cumulative_volume` - 누적 거래량 합계
+    ///
+    /// # Returns
+    /// * `VWAP` - 계산된 VWAP (거래량이 없거나 값이 유효하지 않으면 0)
+    pub(crate) fn from_sums(
+        params: VWAPParams,
+        cumulative_pv: f64,
+        cumulative_p2v: f64,
+        cumulative_volume: f64,
+    ) -> VWAP {
+        let vwap = |value, std_dev| VWAP {
+            params,
+            value,
+            std_dev,
+        };
+        if cumulative_volume <= 0.0 {
+            return vwap(0.0, 0.0);
+        }
+
+        let vwap_value = cumulative_pv / cumulative_volume;
+        // 부동소수점 오차로 분산이 음수가 될 수 있으므로 0으로 제한
+        let variance = (cumulative_p2v / cumulative_volume - vwap_value * vwap_value).max(0.0);
+
+        // 결과값 유효성 검증
+        if vwap_value.is_nan() || vwap_value.is_infinite() {
+            return vwap(0.0, 0.0);
+        }
+        let std_dev = variance.sqrt();
+        let std_dev = if std_dev.is_finite() { std_dev } else { 0.0 };
+
+        vwap(vwap_value, std_dev)
+    }
+
     /// 현재 가격이 VWAP 위에 있는지 확인
     ///
     /// # Arguments
@@ -91,6 +203,28 @@ impl VWAP {
         }
         ((price - self.value) / self.value) * 100.0
     }
+
+    /// 상단 밴드 (VWAP + multiplier × 표준편차)
+    ///
+    /// # Arguments
+    /// * `multiplier` - 표준편차 배수 (보통 1, 2, 3)
+    ///
+    /// # Returns
+    /// * `f64` - 상단 밴드 값
+    pub fn upper_band(&self, multiplier: f64) -> f64 {
+        self.value + multiplier * self.std_dev
+    }
+
+    /// 하단 밴드 (VWAP - multiplier × 표준편차)
+    ///
+    /// # Arguments
+    /// * `multiplier` - 표준편차 배수 (보통 1, 2, 3)
+    ///
+    /// # Returns
+    /// * `f64` - 하단 밴드 값
+    pub fn lower_band(&self, multiplier: f64) -> f64 {
+        self.value - multiplier * self.std_dev
+    }
 }
 
 /// VWAP 빌더
@@ -101,6 +235,7 @@ impl VWAP {
 /// - 메모리 사용량: period개의 (typical_price, volume) 쌍만 유지 (period=0일 때 최대 500개)
 /// - 시간 복잡도: O(1) 업데이트 (누적 합계 사용), O(n) 초기 빌드 (n = 데이터 개수)
 /// - 최적화: 누적 합계(cumulative_pv, cumulative_volume)를 사용하여 효율적인 업데이트 지원
+/// - 앵커 모드는 (typical_price, volume) 쌍을 보관하지 않고 세션이 바뀔 때 누적 합계만 초기화
 #[derive(Debug)]
 pub struct VWAPBuilder<C: Candle> {
     /// VWAP 매개변수
@@ -109,8 +244,12 @@ pub struct VWAPBuilder<C: Candle> {
     values: Vec<(f64, f64)>,
     /// 누적 (가격 * 거래량) 합계
     cumulative_pv: f64,
+    /// 누적 (가격² * 거래량) 합계
+    cumulative_p2v: f64,
     /// 누적 거래량 합계
     cumulative_volume: f64,
+    /// 현재 앵커 세션 키
+    session: Option<i64>,
     _phantom: PhantomData<C>,
 }
 
@@ -128,17 +267,19 @@ where
     /// # Returns
     /// * `VWAPBuilder` - 새 빌더 인스턴스
     pub fn new(params: VWAPParams) -> Self {
-        let capacity = if params.period > 0 {
-            params.period * 2
-        } else {
-            MAX_PERIOD_0_CAPACITY
+        let capacity = match params.anchor {
+            VWAPAnchor::Rolling if params.period > 0 => params.period * 2,
+            VWAPAnchor::Rolling => MAX_PERIOD_0_CAPACITY,
+            _ => 0,
         };
 
         Self {
             params,
             values: Vec::with_capacity(capacity),
             cumulative_pv: 0.0,
+            cumulative_p2v: 0.0,
             cumulative_volume: 0.0,
+            session: None,
             _phantom: PhantomData,
         }
     }
@@ -162,15 +303,19 @@ where
     /// # Returns
     /// * `VWAP` - 계산된 VWAP
     pub fn build(&mut self, data: &[C]) -> VWAP {
-        self.values.clear();
-        self.cumulative_pv = 0.0;
-        self.cumulative_volume = 0.0;
+        self.reset();
 
         if data.is_empty() {
-            return VWAP {
-                params: self.params,
-                value: 0.0,
-            };
+            return self.vwap(0.0, 0.0);
+        }
+
+        // 앵커 모드는 세션 경계를 캔들마다 확인해야 하므로 순서대로 누적
+        if self.params.anchor != VWAPAnchor::Rolling {
+            let mut vwap = self.vwap(0.0, 0.0);
+            for item in data {
+                vwap = self.next(item);
+            }
+            return vwap;
         }
 
         let slice_start = if self.params.period > 0 && data.len() > self.params.period {
@@ -203,40 +348,20 @@ where
                 continue;
             }
 
-            self.values.push((typical_price, volume));
-            self.cumulative_pv += typical_price * volume;
-            self.cumulative_volume += volume;
+            self.push(typical_price, volume);
         }
 
         if self.params.period > 0 && self.values.len() < self.params.period {
             let (price, _) = *self.values.last().unwrap_or(&(0.0, 0.0));
-            return VWAP {
-                params: self.params,
-                value: if price.is_nan() || price.is_infinite() {
-                    0.0
-                } else {
-                    price
-                },
+            let price = if price.is_nan() || price.is_infinite() {
+                0.0
+            } else {
+                price
             };
+            return self.vwap(price, 0.0);
         }
 
-        let vwap_value = if self.cumulative_volume > 0.0 {
-            self.cumulative_pv / self.cumulative_volume
-        } else {
-            0.0
-        };
-
-        // 결과값 유효성 검증
-        let final_value = if vwap_value.is_nan() || vwap_value.is_infinite() {
-            0.0
-        } else {
-            vwap_value
-        };
-
-        VWAP {
-            params: self.params,
-            value: final_value,
-        }
+        self.current()
     }
 
     /// 새 데이터로 VWAP 업데이트
@@ -259,10 +384,19 @@ where
             || data.low_price().is_nan()
             || data.close_price().is_nan()
         {
-            return VWAP {
-                params: self.params,
-                value: 0.0,
-            };
+            return self.vwap(0.0, 0.0);
+        }
+
+        if self.params.anchor != VWAPAnchor::Rolling {
+            let session = self.params.anchor.session_key(data.datetime());
+            if session != self.session {
+                self.reset();
+                self.session = session;
+            }
+            self.cumulative_pv += typical_price * volume;
+            self.cumulative_p2v += typical_price * typical_price * volume;
+            self.cumulative_volume += volume;
+            return self.current();
         }
 
         let max_len = if self.params.period > 0 {
@@ -272,57 +406,67 @@ where
         };
 
         if self.values.len() >= max_len {
-            if let Some((old_price, old_volume)) = self.values.first().copied() {
-                self.cumulative_pv -= old_price * old_volume;
-                self.cumulative_volume -= old_volume;
-            }
-            self.values.drain(0..1);
+            self.pop_oldest(1);
         }
 
-        self.values.push((typical_price, volume));
-        self.cumulative_pv += typical_price * volume;
-        self.cumulative_volume += volume;
+        self.push(typical_price, volume);
 
         if self.params.period > 0 && self.values.len() < self.params.period {
-            return VWAP {
-                params: self.params,
-                value: typical_price,
-            };
+            return self.vwap(typical_price, 0.0);
         }
 
         if self.params.period > 0 && self.values.len() > self.params.period * 2 {
             let excess = self.values.len() - self.params.period * 2;
-            for &(price, vol) in &self.values[..excess] {
-                self.cumulative_pv -= price * vol;
-                self.cumulative_volume -= vol;
-            }
-            self.values.drain(0..excess);
+            self.pop_oldest(excess);
         }
 
-        let vwap_value = if self.cumulative_volume > 0.0 {
-            self.cumulative_pv / self.cumulative_volume
-        } else {
-            0.0
-        };
-
-        // 결과값 유효성 검증
-        let final_value = if vwap_value.is_nan() || vwap_value.is_infinite() {
-            0.0
-        } else {
-            vwap_value
-        };
-
-        VWAP {
-            params: self.params,
-            value: final_value,
-        }
+        self.current()
     }
 
     /// VWAP 리셋 (일일 계산에 사용)
     pub fn reset(&mut self) {
         self.values.clear();
         self.cumulative_pv = 0.0;
+        self.cumulative_p2v = 0.0;
         self.cumulative_volume = 0.0;
+        self.session = None;
+    }
+
+    /// 롤링 구간에 (typical_price, volume) 쌍 추가
+    fn push(&mut self, typical_price: f64, volume: f64) {
+        self.values.push((typical_price, volume));
+        self.cumulative_pv += typical_price * volume;
+        self.cumulative_p2v += typical_price * typical_price * volume;
+        self.cumulative_volume += volume;
+    }
+
+    /// 롤링 구간에서 가장 오래된 count개 제거
+    fn pop_oldest(&mut self, count: usize) {
+        let count = count.min(self.values.len());
+        for &(price, volume) in &self.values[..count] {
+            self.cumulative_pv -= price * volume;
+            self.cumulative_p2v -= price * price * volume;
+            self.cumulative_volume -= volume;
+        }
+        self.values.drain(0..count);
+    }
+
+    /// 누적 합계로 VWAP과 거래량 가중 표준편차 계산
+    fn current(&self) -> VWAP {
+        VWAP::from_sums(
+            self.params,
+            self.cumulative_pv,
+            self.cumulative_p2v,
+            self.cumulative_volume,
+        )
+    }
+
+    fn vwap(&self, value: f64, std_dev: f64) -> VWAP {
+        VWAP {
+            params: self.params,
+            value,
+            std_dev,
+        }
     }
 }
 
@@ -521,6 +665,7 @@ mod tests {
         let vwap = VWAP {
             params: VWAPParams::new(20),
             value: 100.0,
+            std_dev: 0.0,
         };
         assert!(vwap.is_price_above(110.0));
         assert!(!vwap.is_price_above(90.0));
@@ -531,6 +676,7 @@ mod tests {
         let vwap = VWAP {
             params: VWAPParams::new(20),
             value: 100.0,
+            std_dev: 0.0,
         };
         assert!(vwap.is_price_below(90.0));
         assert!(!vwap.is_price_below(110.0));
@@ -541,6 +687,7 @@ mod tests {
         let vwap = VWAP {
             params: VWAPParams::new(20),
             value: 100.0,
+            std_dev: 0.0,
         };
         let percent = vwap.price_to_vwap_percent(110.0);
         assert!((percent - 10.0).abs() < 0.01); // 10% 위
@@ -554,6 +701,7 @@ mod tests {
         let vwap = VWAP {
             params: VWAPParams::new(20),
             value: 0.0,
+            std_dev: 0.0,
         };
         let percent = vwap.price_to_vwap_percent(100.0);
         assert_eq!(percent, 0.0); // 0으로 나누기 방지
@@ -564,6 +712,7 @@ mod tests {
         let vwap = VWAP {
             params: VWAPParams::new(20),
             value: 100.5,
+            std_dev: 0.0,
         };
         let display_str = format!("{vwap}");
         assert!(display_str.contains("VWAP"));
@@ -730,4 +879,103 @@ mod tests {
             tp2
         );
     }
+
+    fn flat_candle(timestamp: i64, price: f64, volume: f64) -> TestCandle {
+        TestCandle {
+            timestamp,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_vwap_anchored_params_display() {
+        assert_eq!(
+            format!("{}", VWAPParams::anchored(VWAPAnchor::Day)),
+            "VWAP(DAY)"
+        );
+        assert_eq!(
+            format!("{}", VWAPParams::anchored(VWAPAnchor::Timestamp(60))),
+            "VWAP(TIMESTAMP(60))"
+        );
+        assert_eq!(VWAPParams::new(20).anchor, VWAPAnchor::Rolling);
+    }
+
+    #[test]
+    fn test_vwap_anchor_day_resets_at_utc_midnight() {
+        let day = 86_400;
+        let candles = vec![
+            flat_candle(day - 120, 100.0, 1000.0),
+            flat_candle(day - 60, 100.0, 1000.0),
+            flat_candle(day, 110.0, 1000.0),
+            flat_candle(day + 60, 120.0, 3000.0),
+        ];
+
+        let mut builder = VWAPBuilder::<TestCandle>::new(VWAPParams::anchored(VWAPAnchor::Day));
+        let vwap = builder.build(&candles);
+
+        // 자정 이후 두 캔들만 누적: (110 * 1000 + 120 * 3000) / 4000 = 117.5
+        assert!((vwap.value - 117.5).abs() < 1e-9);
+        // 분산 = (110² * 1000 + 120² * 3000) / 4000 - 117.5² = 18.75
+        assert!((vwap.std_dev - 18.75_f64.sqrt()).abs() < 1e-9);
+
+        let mut incremental = VWAPBuilder::<TestCandle>::new(VWAPParams::anchored(VWAPAnchor::Day));
+        for candle in &candles[..3] {
+            incremental.next(candle);
+        }
+        let last = incremental.next(&candles[3]);
+        assert!((last.value - vwap.value).abs() < 1e-9);
+
+        // 롤링(전체 데이터)은 초기화하지 않음
+        let mut rolling = VWAPBuilder::<TestCandle>::new(VWAPParams::new(0));
+        assert!((rolling.build(&candles).value - 670_000.0 / 6000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vwap_anchor_week_and_month() {
+        // 1970-01-05(월) 00:00 UTC 직전/직후
+        let monday = 4 * 86_400;
+        let candles = vec![
+            flat_candle(monday - 60, 100.0, 1000.0),
+            flat_candle(monday, 110.0, 1000.0),
+        ];
+
+        let mut week = VWAPBuilder::<TestCandle>::new(VWAPParams::anchored(VWAPAnchor::Week));
+        assert!((week.build(&candles).value - 110.0).abs() < 1e-9);
+
+        let mut month = VWAPBuilder::<TestCandle>::new(VWAPParams::anchored(VWAPAnchor::Month));
+        assert!((month.build(&candles).value - 105.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vwap_anchor_timestamp() {
+        let candles: Vec<TestCandle> = (1..=5)
+            .map(|i| flat_candle(i, 98.0 + 2.0 * i as f64, 1000.0))
+            .collect();
+        let mut builder =
+            VWAPBuilder::<TestCandle>::new(VWAPParams::anchored(VWAPAnchor::Timestamp(3)));
+
+        // 앵커 이전 캔들은 자기 대표 가격
+        assert!((builder.next(&candles[0]).value - 100.0).abs() < 1e-9);
+        assert!((builder.next(&candles[1]).value - 102.0).abs() < 1e-9);
+
+        // 앵커(104)부터 누적: (104 + 106 + 108) / 3 = 106
+        let vwap = builder.build(&candles);
+        assert!((vwap.value - 106.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vwap_std_dev_bands() {
+        let candles = vec![flat_candle(1, 100.0, 1000.0), flat_candle(2, 110.0, 1000.0)];
+        let mut builder = VWAPBuilder::<TestCandle>::new(VWAPParams::new(2));
+        let vwap = builder.build(&candles);
+
+        assert!((vwap.value - 105.0).abs() < 1e-9);
+        assert!((vwap.std_dev - 5.0).abs() < 1e-9);
+        assert!((vwap.upper_band(2.0) - 115.0).abs() < 1e-9);
+        assert!((vwap.lower_band(1.0) - 100.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,298 @@
+use crate::candle_store::CandleStore;
+use crate::indicator::ma::smoothing;
+use crate::indicator::{IndicatorResult, TABuilder, TAs, TAsBuilder};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use trading_chart::Candle;
+
+/// Williams %R 기술적 지표
+///
+/// 최근 `period` 구간의 최고가 대비 종가 위치를 -100에서 0 사이로 나타냅니다.
+/// 0에 가까울수록 최고가 부근, -100에 가까울수록 최저가 부근입니다.
+#[derive(Clone, Debug)]
+pub struct WilliamsR {
+    /// 계산 기간
+    period: usize,
+    /// Williams %R 값 (-100-0)
+    pub value: f64,
+}
+
+impl Display for WilliamsR {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WilliamsR({}: {:.2})", self.period, self.value)
+    }
+}
+
+impl WilliamsR {
+    /// 과매수 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매수 기준값 (기본값 -20.0)
+    pub fn is_overbought(&self, threshold: Option<f64>) -> bool {
+        self.value >= threshold.unwrap_or(-20.0)
+    }
+
+    /// 과매도 상태인지 확인
+    ///
+    /// # Arguments
+    /// * `threshold` - 과매도 기준값 (기본값 -80.0)
+    pub fn is_oversold(&self, threshold: Option<f64>) -> bool {
+        self.value <= threshold.unwrap_or(-80.0)
+    }
+
+    /// 계산 기간 반환
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Williams %R 값 반환
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// Williams %R 계산 빌더
+///
+/// 데이터가 기간보다 적으면 있는 캔들만으로 계산합니다.
+/// 최고가와 최저가가 같으면 중립값 -50입니다.
+#[derive(Debug)]
+pub struct WilliamsRBuilder<C: Candle> {
+    /// 계산 기간
+    period: usize,
+    /// 최근 고가 데이터 (최대 period개)
+    highs: Vec<f64>,
+    /// 최근 저가 데이터 (최대 period개)
+    lows: Vec<f64>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> WilliamsRBuilder<C>
+where
+    C: Candle,
+{
+    /// 새 Williams %R 빌더 생성
+    ///
+    /// # Panics
+    /// * 기간이 0이면 패닉 발생
+    pub fn new(period: usize) -> Self {
+        match Self::new_checked(period) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 새 Williams %R 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `period` - 계산 기간
+    ///
+    /// # Returns
+    /// * `IndicatorResult<WilliamsRBuilder>` - 새 빌더 인스턴스 또는 에러
+    pub fn new_checked(period: usize) -> IndicatorResult<Self> {
+        if period == 0 {
+            return Err("Williams %R 기간은 0보다 커야 합니다".to_string());
+        }
+
+        Ok(Self {
+            period,
+            highs: Vec::with_capacity(period),
+            lows: Vec::with_capacity(period),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// 저장소에서 Williams %R 지표 생성
+    pub fn build_from_storage(&mut self, storage: &CandleStore<C>) -> WilliamsR {
+        self.build(&storage.get_ascending_items())
+    }
+
+    /// 데이터 벡터에서 Williams %R 지표 생성 (데이터가 없으면 -50)
+    pub fn build(&mut self, data: &[C]) -> WilliamsR {
+        self.highs.clear();
+        self.lows.clear();
+
+        let mut value = -50.0;
+        for candle in data {
+            value = self.next_value(candle);
+        }
+
+        WilliamsR {
+            period: self.period,
+            value,
+        }
+    }
+
+    /// 다음 캔들 데이터로 Williams %R 값 계산 (내부용)
+    fn next_value(&mut self, candle: &C) -> f64 {
+        self.highs.push(candle.high_price());
+        self.lows.push(candle.low_price());
+        smoothing::truncate_front(&mut self.highs, self.period);
+        smoothing::truncate_front(&mut self.lows, self.period);
+
+        let highest = self.highs.iter().copied().fold(f64::MIN, f64::max);
+        let lowest = self.lows.iter().copied().fold(f64::MAX, f64::min);
+        let range = highest - lowest;
+        if range > 0.0 {
+            ((highest - candle.close_price()) / range * -100.0).clamp(-100.0, 0.0)
+        } else {
+            -50.0
+        }
+    }
+
+    /// 새 캔들 데이터로 Williams %R 지표 업데이트
+    pub fn next(&mut self, data: &C) -> WilliamsR {
+        WilliamsR {
+            period: self.period,
+            value: self.next_value(data),
+        }
+    }
+}
+
+impl<C> TABuilder<WilliamsR, C> for WilliamsRBuilder<C>
+where
+    C: Candle,
+{
+    fn build_from_storage(&mut self, storage: &CandleStore<C>) -> WilliamsR {
+        self.build_from_storage(storage)
+    }
+
+    fn build(&mut self, data: &[C]) -> WilliamsR {
+        self.build(data)
+    }
+
+    fn next(&mut self, data: &C) -> WilliamsR {
+        self.next(data)
+    }
+}
+
+/// 여러 기간의 Williams %R 지표 컬렉션 타입
+pub type WilliamsRs = TAs<usize, WilliamsR>;
+
+/// 여러 기간의 Williams %R 지표 빌더 타입
+pub type WilliamsRsBuilder<C> = TAsBuilder<usize, WilliamsR, C>;
+
+/// Williams %R 컬렉션 빌더 팩토리
+pub struct WilliamsRsBuilderFactory;
+
+impl WilliamsRsBuilderFactory {
+    /// 여러 기간의 Williams %R 빌더 생성
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `WilliamsRsBuilder` - 여러 기간의 Williams %R 빌더
+    pub fn build<C: Candle + 'static>(periods: &[usize]) -> WilliamsRsBuilder<C> {
+        match Self::build_checked(periods) {
+            Ok(builder) => builder,
+            Err(message) => panic!("{message}"),
+        }
+    }
+
+    /// 여러 기간의 Williams %R 빌더 생성 (검증 포함)
+    ///
+    /// # Arguments
+    /// * `periods` - 계산 기간 목록
+    ///
+    /// # Returns
+    /// * `IndicatorResult<WilliamsRsBuilder>` - Williams %R 빌더 또는 에러
+    pub fn build_checked<C: Candle + 'static>(
+        periods: &[usize],
+    ) -> IndicatorResult<WilliamsRsBuilder<C>> {
+        for period in periods {
+            WilliamsRBuilder::<C>::new_checked(*period)?;
+        }
+
+        Ok(WilliamsRsBuilder::new(
+            "williams_rs".to_owned(),
+            periods,
+            |period| Box::new(WilliamsRBuilder::<C>::new(*period)),
+        ))
+    }
+
+    /// 기본 Williams %R 빌더 생성 (14 기간)
+    pub fn build_default<C: Candle + 'static>() -> WilliamsRsBuilder<C> {
+        Self::build(&[14])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestCandle;
+
+    fn create_test_candles(data: &[(f64, f64, f64)]) -> Vec<TestCandle> {
+        data.iter()
+            .enumerate()
+            .map(|(i, (high, low, close))| TestCandle {
+                timestamp: i as i64 + 1,
+                open: *close,
+                high: *high,
+                low: *low,
+                close: *close,
+                volume: 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_williams_r_exact_calculation() {
+        let candles = create_test_candles(&[
+            (110.0, 90.0, 100.0),
+            (120.0, 100.0, 115.0),
+            (118.0, 105.0, 110.0),
+        ]);
+        let williams_r = WilliamsRBuilder::new(3).build(&candles);
+
+        // 최고가 120, 최저가 90: (120 - 110) / 30 * -100
+        assert!((williams_r.value() - (-100.0 / 3.0)).abs() < 1e-10);
+        assert!(!williams_r.is_overbought(None));
+        assert!(!williams_r.is_oversold(None));
+        assert_eq!(williams_r.to_string(), "WilliamsR(3: -33.33)");
+    }
+
+    #[test]
+    fn test_williams_r_extremes() {
+        let candles = create_test_candles(&[(110.0, 90.0, 100.0), (115.0, 95.0, 115.0)]);
+        let williams_r = WilliamsRBuilder::new(2).build(&candles);
+        assert_eq!(williams_r.value(), 0.0);
+        assert!(williams_r.is_overbought(None));
+
+        let candles = create_test_candles(&[(110.0, 90.0, 100.0), (100.0, 85.0, 85.0)]);
+        let williams_r = WilliamsRBuilder::new(2).build(&candles);
+        assert_eq!(williams_r.value(), -100.0);
+        assert!(williams_r.is_oversold(None));
+
+        let flat = create_test_candles(&[(100.0, 100.0, 100.0); 3]);
+        assert_eq!(WilliamsRBuilder::new(3).build(&flat).value(), -50.0);
+        assert_eq!(
+            WilliamsRBuilder::<TestCandle>::new(3).build(&[]).value(),
+            -50.0
+        );
+    }
+
+    #[test]
+    fn test_williams_r_next_drops_old_candles() {
+        let candles = create_test_candles(&[
+            (200.0, 90.0, 100.0),
+            (120.0, 100.0, 115.0),
+            (118.0, 105.0, 110.0),
+        ]);
+        let mut builder = WilliamsRBuilder::new(2);
+        builder.build(&candles[..2]);
+        let williams_r = builder.next(&candles[2]);
+
+        // 첫 캔들(고가 200)은 구간에서 빠짐: (120 - 110) / 20 * -100
+        assert!((williams_r.value() - (-50.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_williams_rs_builder_factory() {
+        let candles = create_test_candles(&[(110.0, 90.0, 100.0), (120.0, 100.0, 115.0)]);
+        let williams_rs = WilliamsRsBuilderFactory::build::<TestCandle>(&[2, 14]).build(&candles);
+
+        assert_eq!(williams_rs.len(), 2);
+        assert_eq!(williams_rs.get(&14).period(), 14);
+        assert!(WilliamsRsBuilderFactory::build_checked::<TestCandle>(&[0]).is_err());
+    }
+}
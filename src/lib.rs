@@ -1,12 +1,15 @@
 pub mod analyzer;
+pub mod backtest;
 pub mod candle_store;
 pub mod filter;
 pub mod indicator;
 pub mod model;
 pub mod strategy;
 
+use crate::strategy::error::StrategyError;
+
 /// 설정 로드 오류
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConfigError {
     /// 파일 오류
     FileError(String),
@@ -14,6 +17,8 @@ pub enum ConfigError {
     ParseError(String),
     /// 유효성 검사 오류
     ValidationError(String),
+    /// 필드 단위 전략 설정 오류
+    Strategy(Box<StrategyError>),
 }
 
 impl std::fmt::Display for ConfigError {
@@ -22,6 +27,7 @@ impl std::fmt::Display for ConfigError {
             ConfigError::FileError(msg) => write!(f, "설정 파일 오류: {msg}"),
             ConfigError::ParseError(msg) => write!(f, "설정 파싱 오류: {msg}"),
             ConfigError::ValidationError(msg) => write!(f, "설정 유효성 검사 오류: {msg}"),
+            ConfigError::Strategy(err) => write!(f, "설정 유효성 검사 오류: {err}"),
         }
     }
 }
@@ -33,6 +39,17 @@ impl From<ConfigError> for String {
             ConfigError::FileError(msg) => format!("설정 파일 오류: {msg}"),
             ConfigError::ParseError(msg) => format!("설정 파싱 오류: {msg}"),
             ConfigError::ValidationError(msg) => format!("설정 유효성 검사 오류: {msg}"),
+            ConfigError::Strategy(err) => format!("설정 유효성 검사 오류: {err}"),
+        }
+    }
+}
+
+/// StrategyError를 ConfigError로 변환
+impl From<StrategyError> for ConfigError {
+    fn from(err: StrategyError) -> Self {
+        match err {
+            StrategyError::Config(err) => err,
+            err => ConfigError::Strategy(Box::new(err)),
         }
     }
 }
@@ -49,10 +66,11 @@ pub trait ConfigValidation {
 #[cfg(test)]
 pub mod tests {
     use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
     use trading_chart::Candle;
     use trading_chart::CandleInterval;
 
-    #[derive(Debug, Clone, Default, PartialEq)]
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
     pub struct TestCandle {
         pub timestamp: i64,
         pub open: f64,
@@ -104,4 +122,25 @@ pub mod tests {
             None
         }
     }
+
+    /// 시가와 종가가 같고 고가/저가가 종가 ±1인 테스트 캔들
+    pub fn test_candle(timestamp: i64, close: f64) -> TestCandle {
+        TestCandle {
+            timestamp,
+            open: close,
+            high: close + 1.0,
+            low: close - 1.0,
+            close,
+            volume: 1_000.0,
+        }
+    }
+
+    /// 종가 목록으로 만든 테스트 캔들 (타임스탬프는 1부터 오름차순)
+    pub fn test_candles(closes: &[f64]) -> Vec<TestCandle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| test_candle(i as i64 + 1, *close))
+            .collect()
+    }
 }
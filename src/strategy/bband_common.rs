@@ -1,16 +1,19 @@
 use super::config_utils;
+use super::explain::ConditionNode;
+use super::{StrategyError, StrategyResult};
 use crate::{ConfigError, ConfigResult, ConfigValidation};
 use log::{debug, error, info};
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json;
 use std::collections::HashMap;
+use trading_chart::Candle;
 
 // analyzer에서 BBandAnalyzer 및 BBandAnalyzerData 가져오기
 pub use crate::analyzer::bband_analyzer::{BBandAnalyzer, BBandAnalyzerData};
 
 /// 볼린저 밴드 전략 공통 설정 베이스
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct BBandStrategyConfigBase {
     /// 확인 캔들 수
     pub count: usize,
@@ -29,39 +32,44 @@ pub struct BBandStrategyConfigBase {
 impl ConfigValidation for BBandStrategyConfigBase {
     fn validate(&self) -> ConfigResult<()> {
         if self.count == 0 {
-            return Err(ConfigError::ValidationError(
-                "확인 캔들 수는 0보다 커야 합니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range("count", self.count, "1 이상").into());
         }
 
         if self.period < 2 {
-            return Err(ConfigError::ValidationError(
-                "볼린저 밴드 계산 기간은 2 이상이어야 합니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range("period", self.period, "2 이상").into());
         }
 
         if self.multiplier <= 0.0 {
-            return Err(ConfigError::ValidationError(
-                "볼린저 밴드 승수는 0보다 커야 합니다".to_string(),
-            ));
+            return Err(
+                StrategyError::out_of_range("multiplier", self.multiplier, "0보다 큰 값").into(),
+            );
         }
 
         if self.narrowing_period == 0 {
-            return Err(ConfigError::ValidationError(
-                "밴드 폭 감소 확인 기간은 0보다 커야 합니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range(
+                "narrowing_period",
+                self.narrowing_period,
+                "1 이상",
+            )
+            .into());
         }
 
         if self.squeeze_period == 0 {
-            return Err(ConfigError::ValidationError(
-                "좁은 상태 유지 기간은 0보다 커야 합니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range(
+                "squeeze_period",
+                self.squeeze_period,
+                "1 이상",
+            )
+            .into());
         }
 
         if self.squeeze_threshold < 0.0 {
-            return Err(ConfigError::ValidationError(
-                "스퀴즈 임계값은 0 이상이어야 합니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range(
+                "squeeze_threshold",
+                self.squeeze_threshold,
+                "0 이상",
+            )
+            .into());
         }
 
         Ok(())
@@ -77,8 +85,8 @@ impl BBandStrategyConfigBase {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<BBandStrategyConfigBase, String>` - 로드된 설정 또는 오류
-    pub fn from_json<T>(json: &str) -> Result<T, String>
+    /// * `StrategyResult<BBandStrategyConfigBase>` - 로드된 설정 또는 오류
+    pub fn from_json<T>(json: &str) -> StrategyResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
@@ -91,7 +99,7 @@ impl BBandStrategyConfigBase {
             Err(e) => {
                 let error_msg = format!("JSON 설정 역직렬화 실패: {e}");
                 error!("{error_msg}");
-                Err(error_msg)
+                Err(ConfigError::ParseError(error_msg).into())
             }
         }
     }
@@ -99,7 +107,7 @@ impl BBandStrategyConfigBase {
     /// HashMap에서 설정 로드
     pub fn from_hash_map(
         config: &HashMap<String, String>,
-    ) -> Result<BBandStrategyConfigBase, String> {
+    ) -> StrategyResult<BBandStrategyConfigBase> {
         debug!("볼린저 밴드 전략 HashMap 설정 파싱 시작: {config:?}");
 
         // 공통 유틸리티를 사용하여 설정 파싱
@@ -109,9 +117,9 @@ impl BBandStrategyConfigBase {
                 e
             })?
             .ok_or_else(|| {
-                let error_msg = "count 설정이 필요합니다".to_string();
-                error!("{error_msg}");
-                error_msg
+                let err = StrategyError::missing_key("count");
+                error!("{err}");
+                err
             })?;
 
         let period = config_utils::parse_usize(config, "period", Some(2), true)
@@ -120,9 +128,9 @@ impl BBandStrategyConfigBase {
                 e
             })?
             .ok_or_else(|| {
-                let error_msg = "period 설정이 필요합니다".to_string();
-                error!("{error_msg}");
-                error_msg
+                let err = StrategyError::missing_key("period");
+                error!("{err}");
+                err
             })?;
 
         let multiplier = config_utils::parse_f64(config, "multiplier", Some((0.0, f64::MAX)), true)
@@ -131,15 +139,15 @@ impl BBandStrategyConfigBase {
                 e
             })?
             .ok_or_else(|| {
-                let error_msg = "multiplier 설정이 필요합니다".to_string();
-                error!("{error_msg}");
-                error_msg
+                let err = StrategyError::missing_key("multiplier");
+                error!("{err}");
+                err
             })?;
 
         if multiplier <= 0.0 {
-            let error_msg = "볼린저 밴드 승수는 0보다 커야 합니다".to_string();
-            error!("{error_msg}");
-            return Err(error_msg);
+            let err = StrategyError::out_of_range("multiplier", multiplier, "0보다 큰 값");
+            error!("{err}");
+            return Err(err);
         }
 
         // 공통 유틸리티를 사용하여 선택적 설정 파싱
@@ -180,10 +188,42 @@ impl BBandStrategyConfigBase {
         // 유효성 검사
         if let Err(e) = result.validate() {
             error!("볼린저 밴드 전략 설정 유효성 검사 실패: {e}");
-            return Err(e.to_string());
+            return Err(e.into());
         }
 
         info!("볼린저 밴드 전략 설정 로드 완료: {result:?}");
         Ok(result)
     }
 }
+
+/// 최신 캔들의 가격과 밴드 값을 조건 입력값으로 추가
+pub(crate) fn with_band_inputs<C: Candle>(
+    node: ConditionNode,
+    ctx: &BBandAnalyzer<C>,
+) -> ConditionNode {
+    match ctx.items.first() {
+        Some(data) => node
+            .input("high", data.candle.high_price())
+            .input("close", data.candle.close_price())
+            .input("upper", data.bband.upper())
+            .input("middle", data.bband.middle())
+            .input("lower", data.bband.lower()),
+        None => node,
+    }
+}
+
+/// 밴드 폭이 좁아지다가 좁은 상태를 유지하는 스퀴즈 조건
+pub(crate) fn narrowing_then_squeeze_node<C: Candle>(
+    ctx: &BBandAnalyzer<C>,
+    narrowing_period: usize,
+    squeeze_period: usize,
+    squeeze_threshold: f64,
+) -> ConditionNode {
+    ConditionNode::new(
+        "narrowing_then_squeeze",
+        ctx.is_narrowing_then_squeeze_pattern(narrowing_period, squeeze_period, squeeze_threshold),
+    )
+    .threshold("narrowing_period", narrowing_period as f64)
+    .threshold("squeeze_period", squeeze_period as f64)
+    .threshold("squeeze_threshold", squeeze_threshold)
+}
@@ -1,20 +1,27 @@
+use super::ConfigurableStrategy;
 use super::Strategy;
 use super::StrategyType;
-use super::bband_common::{BBandAnalyzer, BBandStrategyConfigBase};
+use super::bband_common::{
+    BBandAnalyzer, BBandStrategyConfigBase, narrowing_then_squeeze_node, with_band_inputs,
+};
 use super::config_utils;
+use super::explain::{ConditionNode, ConditionRule, SignalExplanation};
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::base::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
 use crate::{ConfigResult, ConfigValidation};
 use log::info;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 볼린저밴드 숏 전략 설정
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct BBandShortStrategyConfig {
     /// 판정 기간
@@ -80,15 +87,17 @@ impl BBandShortStrategyConfig {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<BBandShortStrategyConfig, String>` - 로드된 설정 또는 오류
-    fn from_json(json: &str) -> Result<BBandShortStrategyConfig, String> {
+    /// * `StrategyResult<BBandShortStrategyConfig>` - 로드된 설정 또는 오류
+    fn from_json(json: &str) -> StrategyResult<BBandShortStrategyConfig> {
         let config = BBandStrategyConfigBase::from_json::<BBandShortStrategyConfig>(json)?;
-        config.validate().map_err(|e| e.to_string())?;
+        config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<BBandShortStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<BBandShortStrategyConfig> {
         let base_config = BBandStrategyConfigBase::from_hash_map(config)?;
 
         // 공통 유틸리티를 사용하여 선택적 설정 파싱
@@ -111,7 +120,7 @@ impl BBandShortStrategyConfig {
             squeeze_threshold,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -147,16 +156,13 @@ impl<C: Candle + 'static> BBandShortStrategy<C> {
     /// * `json_config` - JSON 형식의 설정 문자열
     ///
     /// # Returns
-    /// * `Result<BBandShortStrategy<C>, String>` - 초기화된 볼린저밴드 숏 전략 인스턴스 또는 오류
+    /// * `StrategyResult<BBandShortStrategy<C>>` - 초기화된 볼린저밴드 숏 전략 인스턴스 또는 오류
     pub fn new(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<BBandShortStrategy<C>, String> {
+    ) -> StrategyResult<BBandShortStrategy<C>> {
         let config = BBandShortStrategyConfig::from_json(json_config)?;
-        info!("볼린저밴드 숏 전략 설정: {config:?}");
-        let ctx = BBandAnalyzer::new(config.period, config.multiplier, storage);
-
-        Ok(BBandShortStrategy { config, ctx })
+        Self::from_config(storage, config)
     }
 
     /// 새 볼린저밴드 숏 전략 인스턴스 생성 (설정 직접 제공)
@@ -166,60 +172,95 @@ impl<C: Candle + 'static> BBandShortStrategy<C> {
     /// * `config` - 전략 설정 (HashMap 형태)
     ///
     /// # Returns
-    /// * `Result<BBandShortStrategy<C>, String>` - 초기화된 볼린저밴드 숏 전략 인스턴스 또는 오류
+    /// * `StrategyResult<BBandShortStrategy<C>>` - 초기화된 볼린저밴드 숏 전략 인스턴스 또는 오류
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<BBandShortStrategy<C>, String> {
+    ) -> StrategyResult<BBandShortStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => BBandShortStrategyConfig::from_hash_map(&cfg)?,
             None => BBandShortStrategyConfig::default(),
         };
 
-        info!("볼린저밴드 숏 전략 설정: {strategy_config:?}");
-        let ctx = BBandAnalyzer::new(strategy_config.period, strategy_config.multiplier, storage);
-
-        Ok(BBandShortStrategy {
-            config: strategy_config,
-            ctx,
-        })
+        Self::from_config(storage, strategy_config)
     }
 }
 
-impl<C: Candle + 'static> Strategy<C> for BBandShortStrategy<C> {
-    fn next(&mut self, candle: C) {
-        self.ctx.next(candle);
+impl<C: Candle + 'static> BBandShortStrategy<C> {
+    /// 숏 진입 조건: 스퀴즈 후 상단 밴드 돌파
+    fn explain_entry(&self) -> ConditionNode {
+        let node = ConditionNode::group(
+            "enter",
+            ConditionRule::All,
+            vec![
+                // 스퀴즈 패턴 확인 (밴드 폭이 좁아지다가 좁은 상태 유지)
+                narrowing_then_squeeze_node(
+                    &self.ctx,
+                    self.config.narrowing_period,
+                    self.config.squeeze_period,
+                    self.config.squeeze_threshold,
+                ),
+                // 상단 밴드 돌파 확인
+                ConditionNode::new(
+                    "break_through_upper_band",
+                    self.ctx
+                        .is_break_through_upper_band_from_below(self.config.count, 0),
+                )
+                .threshold("count", self.config.count as f64),
+                // 밴드 폭이 충분히 넓은지 확인 (스퀴즈 후 확대 시작)
+                ConditionNode::new(
+                    "band_width_sufficient",
+                    self.ctx.is_band_width_sufficient(0),
+                ),
+            ],
+        );
+        with_band_inputs(node, &self.ctx)
     }
 
-    fn should_enter(&self, _current_price: f64) -> bool {
-        // 숏 진입: 스퀴즈 후 상단 밴드 돌파 시 숏 진입 신호
-        // 스퀴즈 패턴 확인 (밴드 폭이 좁아지다가 좁은 상태 유지)
-        let squeeze_pattern = self.ctx.is_narrowing_then_squeeze_pattern(
-            self.config.narrowing_period,
-            self.config.squeeze_period,
-            self.config.squeeze_threshold,
+    /// 숏 청산 조건: 하단 밴드 하향 돌파 또는 중간 밴드 아래로 하락
+    fn explain_exit(&self) -> ConditionNode {
+        let node = ConditionNode::group(
+            "exit",
+            ConditionRule::Any,
+            vec![
+                ConditionNode::new(
+                    "break_through_lower_band",
+                    self.ctx
+                        .is_break_through_lower_band_from_below(self.config.count, 0),
+                )
+                .threshold("count", self.config.count as f64),
+                ConditionNode::new("below_middle_band", self.ctx.is_below_middle_band(1, 0)),
+            ],
         );
+        with_band_inputs(node, &self.ctx)
+    }
+}
 
-        // 상단 밴드 돌파 확인
-        let breaks_upper = self
-            .ctx
-            .is_break_through_upper_band_from_below(self.config.count, 0);
+impl<C: Candle + 'static> ConfigurableStrategy<C> for BBandShortStrategy<C> {
+    type Config = BBandShortStrategyConfig;
 
-        // 밴드 폭이 충분히 넓은지 확인 (스퀴즈 후 확대 시작)
-        let band_width_sufficient = self.ctx.is_band_width_sufficient(0);
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: BBandShortStrategyConfig,
+    ) -> StrategyResult<BBandShortStrategy<C>> {
+        info!("볼린저밴드 숏 전략 설정: {config:?}");
+        let ctx = BBandAnalyzer::new(config.period, config.multiplier, storage);
 
-        squeeze_pattern && breaks_upper && band_width_sufficient
+        Ok(BBandShortStrategy { config, ctx })
     }
+}
 
-    fn should_exit(&self, _current_price: f64) -> bool {
-        // 숏 청산: 하단 밴드 하향 돌파 또는 중간 밴드 아래로 하락
-        let breaks_lower = self
-            .ctx
-            .is_break_through_lower_band_from_below(self.config.count, 0);
+impl<C: Candle + 'static> Strategy<C> for BBandShortStrategy<C> {
+    fn next(&mut self, candle: C) {
+        self.ctx.next(candle);
+    }
 
-        let below_middle = self.ctx.is_below_middle_band(1, 0);
+    fn should_enter(&self, _current_price: f64) -> bool {
+        self.explain_entry().result
+    }
 
-        breaks_lower || below_middle
+    fn should_exit(&self, _current_price: f64) -> bool {
+        self.explain_exit().result
     }
 
     fn position(&self) -> PositionType {
@@ -229,4 +270,30 @@ impl<C: Candle + 'static> Strategy<C> for BBandShortStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::BBandShort
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_entry(),
+            exit: self.explain_exit(),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::BBandShort, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::BBandShort)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::BBandShort))
+    }
 }
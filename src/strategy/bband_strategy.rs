@@ -1,19 +1,26 @@
+use super::ConfigurableStrategy;
 use super::Strategy;
 use super::StrategyType;
-use super::bband_common::{BBandAnalyzer, BBandStrategyConfigBase};
+use super::bband_common::{
+    BBandAnalyzer, BBandStrategyConfigBase, narrowing_then_squeeze_node, with_band_inputs,
+};
+use super::explain::{ConditionNode, ConditionRule, SignalExplanation};
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::base::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
 use crate::{ConfigResult, ConfigValidation};
 use log::{debug, error, info};
 use serde::Deserialize;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 볼린저 밴드 전략 설정
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct BBandStrategyConfig {
     /// 확인 캔들 수
@@ -67,15 +74,17 @@ impl BBandStrategyConfig {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<BBandStrategyConfig, String>` - 로드된 설정 또는 오류
-    fn from_json(json: &str) -> Result<BBandStrategyConfig, String> {
+    /// * `StrategyResult<BBandStrategyConfig>` - 로드된 설정 또는 오류
+    fn from_json(json: &str) -> StrategyResult<BBandStrategyConfig> {
         let config = BBandStrategyConfigBase::from_json::<BBandStrategyConfig>(json)?;
-        config.validate().map_err(|e| e.to_string())?;
+        config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<BBandStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<BBandStrategyConfig> {
         let base_config = BBandStrategyConfigBase::from_hash_map(config)?;
 
         let result = BBandStrategyConfig {
@@ -87,7 +96,7 @@ impl BBandStrategyConfig {
             squeeze_threshold: base_config.squeeze_threshold,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -125,11 +134,11 @@ impl<C: Candle + 'static> BBandStrategy<C> {
     /// * `json_config` - JSON 형식의 설정 문자열
     ///
     /// # Returns
-    /// * `Result<BBandStrategy<C>, String>` - 초기화된 볼린저밴드 전략 인스턴스 또는 오류
+    /// * `StrategyResult<BBandStrategy<C>>` - 초기화된 볼린저밴드 전략 인스턴스 또는 오류
     pub fn from_json(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<BBandStrategy<C>, String> {
+    ) -> StrategyResult<BBandStrategy<C>> {
         debug!("볼린저 밴드 전략 초기화 시작 (JSON 설정 사용)");
         let config = match BBandStrategyConfig::from_json(json_config) {
             Ok(cfg) => {
@@ -138,7 +147,7 @@ impl<C: Candle + 'static> BBandStrategy<C> {
             }
             Err(e) => {
                 error!("볼린저 밴드 전략 JSON 설정 파싱 실패: {e}");
-                return Err(format!("볼린저 밴드 전략 설정 오류: {e}"));
+                return Err(e);
             }
         };
 
@@ -152,11 +161,11 @@ impl<C: Candle + 'static> BBandStrategy<C> {
     /// * `config` - 전략 설정
     ///
     /// # Returns
-    /// * `Result<BBandStrategy<C>, String>` - 초기화된 볼린저밴드 전략 인스턴스
+    /// * `StrategyResult<BBandStrategy<C>>` - 초기화된 볼린저밴드 전략 인스턴스
     pub fn new(
         storage: &CandleStore<C>,
         config: BBandStrategyConfig,
-    ) -> Result<BBandStrategy<C>, String> {
+    ) -> StrategyResult<BBandStrategy<C>> {
         info!("볼린저밴드 전략 설정: {config:?}");
         debug!(
             "볼린저 밴드 컨텍스트 초기화 시작 (기간: {}, 승수: {})",
@@ -175,7 +184,7 @@ impl<C: Candle + 'static> BBandStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<BBandStrategy<C>, String> {
+    ) -> StrategyResult<BBandStrategy<C>> {
         debug!("볼린저 밴드 전략 초기화 시작 (HashMap 설정 사용)");
 
         let strategy_config = match config {
@@ -188,7 +197,7 @@ impl<C: Candle + 'static> BBandStrategy<C> {
                     }
                     Err(e) => {
                         error!("볼린저 밴드 전략 HashMap 설정 파싱 실패: {e}");
-                        return Err(format!("볼린저 밴드 전략 설정 오류: {e}"));
+                        return Err(e);
                     }
                 }
             }
@@ -202,27 +211,71 @@ impl<C: Candle + 'static> BBandStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> BBandStrategy<C> {
+    /// 진입 조건
+    ///
+    /// 향상된 볼린저 밴드 스퀴즈 돌파 조건:
+    /// 1. 밴드 폭이 좁아지다가 (narrowing_period 동안)
+    /// 2. 좁은 상태를 유지하다가 (squeeze_period 동안)
+    /// 3. 상단을 돌파하는 캔들이 나오고 (고가가 상단 돌파)
+    /// 4. 종가가 상단 위에 위치
+    fn explain_entry(&self) -> ConditionNode {
+        let (high_above_upper, close_above_upper) = match self.ctx.items.first() {
+            Some(data) => (
+                data.candle.high_price() > data.bband.upper(),
+                data.candle.close_price() > data.bband.upper(),
+            ),
+            None => (false, false),
+        };
+
+        let node = ConditionNode::group(
+            "enter",
+            ConditionRule::All,
+            vec![
+                narrowing_then_squeeze_node(
+                    &self.ctx,
+                    self.config.narrowing_period,
+                    self.config.squeeze_period,
+                    self.config.squeeze_threshold,
+                ),
+                ConditionNode::new("high_above_upper_band", high_above_upper),
+                ConditionNode::new("close_above_upper_band", close_above_upper),
+            ],
+        );
+        with_band_inputs(node, &self.ctx)
+    }
+
+    /// 청산 조건 (종가가 중간 밴드 위)
+    fn explain_exit(&self) -> ConditionNode {
+        with_band_inputs(
+            ConditionNode::new("above_middle_band", self.ctx.is_above_middle_band(1, 0)),
+            &self.ctx,
+        )
+    }
+}
+
+impl<C: Candle + 'static> ConfigurableStrategy<C> for BBandStrategy<C> {
+    type Config = BBandStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: BBandStrategyConfig,
+    ) -> StrategyResult<BBandStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for BBandStrategy<C> {
     fn next(&mut self, candle: C) {
         self.ctx.next(candle)
     }
 
     fn should_enter(&self, _current_price: f64) -> bool {
-        // 향상된 볼린저 밴드 스퀴즈 돌파 조건 체크:
-        // 1. 밴드 폭이 좁아지다가 (narrowing_period 동안)
-        // 2. 좁은 상태를 유지하다가 (squeeze_period 동안)
-        // 3. 상단을 돌파하는 캔들이 나오고 (고가가 상단 돌파)
-        // 4. 종가가 상단 위에 위치
-        self.ctx
-            .is_enhanced_squeeze_breakout_with_close_above_upper(
-                self.config.narrowing_period,
-                self.config.squeeze_period,
-                self.config.squeeze_threshold,
-            )
+        self.explain_entry().result
     }
 
     fn should_exit(&self, _current_price: f64) -> bool {
-        self.ctx.is_above_middle_band(1, 0)
+        self.explain_exit().result
     }
 
     fn position(&self) -> PositionType {
@@ -232,4 +285,30 @@ impl<C: Candle + 'static> Strategy<C> for BBandStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::BBand
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_entry(),
+            exit: self.explain_exit(),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::BBand, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::BBand)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::BBand))
+    }
 }
@@ -0,0 +1,138 @@
+// 전략 설정 파일 로더
+// TOML/JSON 설정 파일을 읽어 타입이 지정된 전략 설정으로 변환합니다.
+// 설정 구조체에 없는 키는 오타로 간주하여 에러로 보고합니다.
+
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use serde_path_to_error::Segment;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// 설정 파일을 읽어 키-값 맵으로 변환
+///
+/// 확장자가 `.toml`이면 TOML, `.json`이면 JSON으로 해석합니다.
+///
+/// # Arguments
+/// * `path` - 설정 파일 경로
+///
+/// # Returns
+/// * `ConfigResult<Map<String, Value>>` - 최상위 키-값 맵 또는 에러
+pub(crate) fn read_config_file(path: &Path) -> ConfigResult<Map<String, Value>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| ConfigError::FileError(format!("{}: {e}", path.display())))?;
+
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+
+    let value = match extension.as_deref() {
+        Some("toml") => {
+            let table: toml::Table = toml::from_str(&content)
+                .map_err(|e| ConfigError::ParseError(format!("TOML 파싱 오류: {e}")))?;
+            serde_json::to_value(table)
+                .map_err(|e| ConfigError::ParseError(format!("TOML 변환 오류: {e}")))?
+        }
+        Some("json") => serde_json::from_str::<Value>(&content)
+            .map_err(|e| ConfigError::ParseError(format!("JSON 파싱 오류: {e}")))?,
+        _ => {
+            return Err(ConfigError::FileError(format!(
+                "지원하지 않는 설정 파일 형식입니다 (toml 또는 json): {}",
+                path.display()
+            )));
+        }
+    };
+
+    match value {
+        Value::Object(map) => Ok(map),
+        _ => Err(ConfigError::ParseError(
+            "설정 파일의 최상위 값은 테이블(객체)이어야 합니다".to_string(),
+        )),
+    }
+}
+
+/// 설정 구조체가 받는 키 목록
+///
+/// 기본값을 직렬화하여 키를 얻으므로 `#[serde(flatten)]`된 필드도 포함됩니다.
+pub(crate) fn config_keys<T: Serialize + Default>() -> BTreeSet<String> {
+    match serde_json::to_value(T::default()) {
+        Ok(Value::Object(map)) => map.keys().cloned().collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
+/// 허용되지 않은 키가 있는지 검사
+///
+/// # Arguments
+/// * `values` - 설정 키-값 맵
+/// * `known` - 허용되는 키 목록
+///
+/// # Returns
+/// * `StrategyResult<()>` - 알 수 없는 키가 있으면 에러
+pub(crate) fn check_unknown_keys(
+    values: &Map<String, Value>,
+    known: &BTreeSet<String>,
+) -> StrategyResult<()> {
+    let unknown: Vec<String> = values
+        .keys()
+        .filter(|key| !known.contains(*key))
+        .cloned()
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(StrategyError::UnknownKeys { keys: unknown })
+    }
+}
+
+/// 키-값 맵을 타입이 지정된 설정으로 변환하고 검증
+///
+/// 누락된 키는 설정 구조체의 기본값을 사용합니다. 값의 타입이 맞지 않으면
+/// `ma_periods[1]`처럼 문제가 된 필드 경로를 키로 하는 파싱 오류를 반환합니다.
+///
+/// # Arguments
+/// * `values` - 설정 키-값 맵
+///
+/// # Returns
+/// * `StrategyResult<T>` - 검증된 설정 또는 에러
+pub(crate) fn typed_config<T>(values: Map<String, Value>) -> StrategyResult<T>
+where
+    T: Serialize + DeserializeOwned + Default + ConfigValidation,
+{
+    check_unknown_keys(&values, &config_keys::<T>())?;
+
+    let values = Value::Object(values);
+    let config: T = serde_path_to_error::deserialize(&values).map_err(|e| {
+        let path = e.path();
+        if path.iter().next().is_none() {
+            return StrategyError::from(ConfigError::ParseError(e.inner().to_string()));
+        }
+        let value = value_at(&values, path).map_or_else(String::new, display_value);
+        StrategyError::parse_failure(path.to_string(), value, e.inner())
+    })?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// 필드 경로에 해당하는 설정 값
+fn value_at<'a>(values: &'a Value, path: &serde_path_to_error::Path) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(values, |value, segment| match segment {
+            Segment::Map { key } => value.get(key),
+            Segment::Seq { index } => value.get(index),
+            Segment::Enum { .. } => Some(value),
+            Segment::Unknown => None,
+        })
+}
+
+/// 오류 메시지에 표시할 설정 값 (문자열은 따옴표 없이)
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
@@ -1,9 +1,12 @@
 use super::Strategy;
+use super::explain::{ConditionNode, ConditionRule};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::bband_analyzer::BBandAnalyzer;
 use crate::candle_store::CandleStore;
 use crate::indicator::ma::MAType;
-use serde::Deserialize;
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use serde::{Deserialize, Serialize};
 use serde_json;
 use thiserror::Error;
 use trading_chart::Candle;
@@ -19,7 +22,7 @@ pub enum CopysContextError {
 }
 
 /// Copys 전략 공통 설정 기본 구조체
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct CopysStrategyConfigBase {
     /// RSI 계산 기간
@@ -53,40 +56,53 @@ impl Default for CopysStrategyConfigBase {
     }
 }
 
-impl CopysStrategyConfigBase {
-    /// 설정의 유효성을 검사합니다.
-    ///
-    /// 모든 설정 값이 유효한지 확인하고, 유효하지 않은 경우 오류 메시지를 반환합니다.
-    ///
-    /// # Returns
-    /// * `Result<(), String>` - 유효성 검사 결과 (성공 또는 오류 메시지)
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for CopysStrategyConfigBase {
+    fn validate(&self) -> ConfigResult<()> {
         if self.rsi_period < 2 {
-            return Err("RSI 기간은 2 이상이어야 합니다".to_string());
+            return Err(
+                StrategyError::out_of_range("rsi_period", self.rsi_period, "2 이상").into(),
+            );
         }
 
         if self.rsi_lower >= self.rsi_upper {
-            return Err(format!(
-                "RSI 하한값({})이 상한값({})보다 크거나 같을 수 없습니다",
-                self.rsi_lower, self.rsi_upper
-            ));
+            return Err(StrategyError::invalid_period_order(
+                "rsi_lower",
+                self.rsi_lower,
+                "rsi_upper",
+                self.rsi_upper,
+            )
+            .into());
         }
 
         if self.bband_period < 2 {
-            return Err("볼린저밴드 기간은 2 이상이어야 합니다".to_string());
+            return Err(
+                StrategyError::out_of_range("bband_period", self.bband_period, "2 이상").into(),
+            );
         }
 
         if self.bband_multiplier <= 0.0 {
-            return Err("볼린저밴드 승수는 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "bband_multiplier",
+                self.bband_multiplier,
+                "0보다 큰 값",
+            )
+            .into());
         }
 
         if self.ma_distance_threshold <= 0.0 || self.ma_distance_threshold > 1.0 {
-            return Err("이동평균선 거리 임계값은 0과 1 사이여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "ma_distance_threshold",
+                self.ma_distance_threshold,
+                "0 초과 1 이하",
+            )
+            .into());
         }
 
         Ok(())
     }
+}
 
+impl CopysStrategyConfigBase {
     /// JSON 문자열에서 설정 로드
     ///
     /// JSON 문자열로부터 설정을 로드하고, 로드에 실패할 경우 오류를 반환합니다.
@@ -95,14 +111,14 @@ impl CopysStrategyConfigBase {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<T, String>` - 로드된 설정 또는 오류
-    pub fn from_json<T>(json: &str) -> Result<T, String>
+    /// * `StrategyResult<T>` - 로드된 설정 또는 오류
+    pub fn from_json<T>(json: &str) -> StrategyResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
         match serde_json::from_str::<T>(json) {
             Ok(config) => Ok(config),
-            Err(e) => Err(format!("JSON 설정 역직렬화 실패: {e}")),
+            Err(e) => Err(ConfigError::ParseError(format!("JSON 설정 역직렬화 실패: {e}")).into()),
         }
     }
 }
@@ -135,106 +151,169 @@ pub trait CopysStrategyCommon<C: Candle + 'static>: Strategy<C> {
 
     /// 매수 신호 체크 - RSI 과매도 + 볼린저밴드 하단 터치 + 이평선 지지
     fn check_buy_signal(&self, consecutive_n: usize, current_price: f64) -> bool {
-        // 1. RSI 과매도 신호 (RSI < 30)
-        let rsi_oversold = self.context().is_all(
-            |data| data.rsi.value() < self.config_rsi_lower(),
-            consecutive_n,
-            0,
-        );
-
-        // 2. 볼린저밴드 하단 터치 반등 신호
-        let bband_support = self.bband_analyzer().is_below_lower_band(1, 0)
-            || self
-                .bband_analyzer()
-                .is_break_through_lower_band_from_below(1, 0);
-
-        // 3. 이동평균선 지지선 역할 확인 (가격이 주요 이평선 근처에서 반등)
-        let ma_support = self.check_ma_support(current_price);
-
-        // 세 조건 중 두 개 이상 만족하면 매수 신호
-        let signal_count = [rsi_oversold, bband_support, ma_support]
-            .iter()
-            .filter(|&&x| x)
-            .count();
-        signal_count >= 2
+        self.explain_buy_signal(consecutive_n, current_price).result
     }
 
     /// 매도 신호 체크 - RSI 과매수 + 볼린저밴드 상단 터치 + 이평선 저항
     fn check_sell_signal(&self, consecutive_n: usize, current_price: f64) -> bool {
-        // 1. RSI 과매수 신호 (RSI > 70)
-        let rsi_overbought = self.context().is_all(
-            |data| data.rsi.value() > self.config_rsi_upper(),
-            consecutive_n,
-            0,
-        );
-
-        // 2. 볼린저밴드 상단 터치 반락 신호
-        let bband_resistance = self.bband_analyzer().is_above_upper_band(1, 0);
-
-        // 3. 이동평균선 저항선 역할 확인 (가격이 주요 이평선 근처에서 반락)
-        let ma_resistance = self.check_ma_resistance(current_price);
-
-        // 세 조건 중 두 개 이상 만족하면 매도 신호
-        let signal_count = [rsi_overbought, bband_resistance, ma_resistance]
-            .iter()
-            .filter(|&&x| x)
-            .count();
-        signal_count >= 2
+        self.explain_sell_signal(consecutive_n, current_price)
+            .result
     }
 
     /// 이동평균선 지지선 확인 (5, 20, 60, 120, 200, 240일선 중 하나라도 지지)
     fn check_ma_support(&self, current_price: f64) -> bool {
-        if self.context().items.is_empty() {
-            return false;
-        }
+        self.explain_ma_support(current_price).result
+    }
 
-        let threshold = self.config_ma_distance_threshold();
+    /// 이동평균선 저항선 확인 (5, 20, 60, 120, 200, 240일선 중 하나라도 저항)
+    fn check_ma_resistance(&self, current_price: f64) -> bool {
+        self.explain_ma_resistance(current_price).result
+    }
 
-        // 주요 이평선들과의 거리 확인 (가격이 이평선 근처에 있으면 지지 가능)
-        for i in 0..self.context().items[0].mas.len() {
-            let ma_value = self.context().items[0].mas.get_by_key_index(i).get();
-            if ma_value == 0.0 {
-                continue;
-            }
+    /// 이동평균선 지지 조건 (이평선과 임계값 이내 거리에 있고, 이평선 위에 있으면 지지로 판단)
+    fn explain_ma_support(&self, current_price: f64) -> ConditionNode {
+        ma_distance_node(
+            self.context(),
+            "ma_support",
+            current_price,
+            self.config_ma_distance_threshold(),
+            |price, ma| price >= ma,
+        )
+    }
 
-            let distance_percent = ((current_price - ma_value) / ma_value).abs();
+    /// 이동평균선 저항 조건 (이평선과 임계값 이내 거리에 있고, 이평선 아래에 있으면 저항으로 판단)
+    fn explain_ma_resistance(&self, current_price: f64) -> ConditionNode {
+        ma_distance_node(
+            self.context(),
+            "ma_resistance",
+            current_price,
+            self.config_ma_distance_threshold(),
+            |price, ma| price <= ma,
+        )
+    }
 
-            // 이평선과 설정된 임계값 이내 거리에 있고, 이평선 위에 있으면 지지로 판단
-            if distance_percent <= threshold && current_price >= ma_value {
-                return true;
-            }
-        }
+    /// 매수 신호 조건 (RSI 과매도, 볼린저밴드 하단 터치, 이평선 지지 중 두 개 이상 만족)
+    fn explain_buy_signal(&self, consecutive_n: usize, current_price: f64) -> ConditionNode {
+        let rsi_lower = self.config_rsi_lower();
+        let rsi_oversold = rsi_node(
+            self.context(),
+            "rsi_oversold",
+            self.context()
+                .is_all(|data| data.rsi.value() < rsi_lower, consecutive_n, 0),
+        )
+        .threshold("rsi_lower", rsi_lower)
+        .threshold("rsi_count", consecutive_n as f64);
+
+        let bband = self.bband_analyzer();
+        let bband_support = bband_node(
+            bband,
+            ConditionNode::group(
+                "bband_support",
+                ConditionRule::Any,
+                vec![
+                    ConditionNode::new("close_below_lower_band", bband.is_below_lower_band(1, 0)),
+                    ConditionNode::new(
+                        "low_back_above_lower_band",
+                        bband.is_break_through_lower_band_from_below(1, 0),
+                    ),
+                ],
+            ),
+        );
 
-        false
-    }
+        let ma_support = self.explain_ma_support(current_price);
 
-    /// 이동평균선 저항선 확인 (5, 20, 60, 120, 200, 240일선 중 하나라도 저항)
-    fn check_ma_resistance(&self, current_price: f64) -> bool {
-        if self.context().items.is_empty() {
-            return false;
-        }
+        ConditionNode::group(
+            "buy_signal",
+            ConditionRule::AtLeast { count: 2 },
+            vec![rsi_oversold, bband_support, ma_support],
+        )
+    }
 
-        let threshold = self.config_ma_distance_threshold();
+    /// 매도 신호 조건 (RSI 과매수, 볼린저밴드 상단 터치, 이평선 저항 중 두 개 이상 만족)
+    fn explain_sell_signal(&self, consecutive_n: usize, current_price: f64) -> ConditionNode {
+        let rsi_upper = self.config_rsi_upper();
+        let rsi_overbought = rsi_node(
+            self.context(),
+            "rsi_overbought",
+            self.context()
+                .is_all(|data| data.rsi.value() > rsi_upper, consecutive_n, 0),
+        )
+        .threshold("rsi_upper", rsi_upper)
+        .threshold("rsi_count", consecutive_n as f64);
+
+        let bband = self.bband_analyzer();
+        let bband_resistance = bband_node(
+            bband,
+            ConditionNode::new("bband_resistance", bband.is_above_upper_band(1, 0)),
+        );
 
-        // 주요 이평선들과의 거리 확인 (가격이 이평선 근처에 있으면 저항 가능)
-        for i in 0..self.context().items[0].mas.len() {
-            let ma_value = self.context().items[0].mas.get_by_key_index(i).get();
-            if ma_value == 0.0 {
-                continue;
-            }
+        let ma_resistance = self.explain_ma_resistance(current_price);
 
-            let distance_percent = ((current_price - ma_value) / ma_value).abs();
+        ConditionNode::group(
+            "sell_signal",
+            ConditionRule::AtLeast { count: 2 },
+            vec![rsi_overbought, bband_resistance, ma_resistance],
+        )
+    }
+}
 
-            // 이평선과 설정된 임계값 이내 거리에 있고, 이평선 아래에 있으면 저항으로 판단
-            if distance_percent <= threshold && current_price <= ma_value {
-                return true;
-            }
-        }
+/// 최신 RSI 값을 입력으로 담은 조건 생성
+fn rsi_node<C: Candle>(ctx: &RSIAnalyzer<C>, name: &str, result: bool) -> ConditionNode {
+    let node = ConditionNode::new(name, result);
+    match ctx.items.first() {
+        Some(data) => node.input("rsi", data.rsi.value()),
+        None => node,
+    }
+}
 
-        false
+/// 최신 종가와 볼린저밴드 값을 입력으로 추가
+fn bband_node<C: Candle>(bband: &BBandAnalyzer<C>, node: ConditionNode) -> ConditionNode {
+    match bband.items.first() {
+        Some(data) => node
+            .input("close", data.candle.close_price())
+            .input("lower_band", data.bband.lower())
+            .input("middle_band", data.bband.middle())
+            .input("upper_band", data.bband.upper()),
+        None => node,
     }
 }
 
+/// 이동평균선별 거리 조건 생성 (이평선 하나라도 만족하면 만족)
+fn ma_distance_node<C: Candle>(
+    ctx: &RSIAnalyzer<C>,
+    name: &str,
+    current_price: f64,
+    threshold: f64,
+    is_on_side: fn(f64, f64) -> bool,
+) -> ConditionNode {
+    let children = match ctx.items.first() {
+        Some(data) => data
+            .mas
+            .keys()
+            .iter()
+            .map(|period| {
+                let name = format!("ma_{period}");
+                let ma_value = data.mas.get(period).get();
+                if ma_value == 0.0 {
+                    return ConditionNode::new(name, false).input("ma", ma_value);
+                }
+
+                let distance_percent = ((current_price - ma_value) / ma_value).abs();
+                let result = distance_percent <= threshold && is_on_side(current_price, ma_value);
+
+                ConditionNode::new(name, result)
+                    .input("ma", ma_value)
+                    .input("distance", distance_percent)
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    ConditionNode::group(name, ConditionRule::Any, children)
+        .input("price", current_price)
+        .threshold("ma_distance_threshold", threshold)
+}
+
 /// Copys 필터에서 임시로 사용할 컨텍스트 생성 (RSIAnalyzer 활용)
 pub fn create_strategy_context_for_filter<C: Candle + 'static>(
     _symbol: &str,
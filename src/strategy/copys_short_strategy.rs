@@ -1,19 +1,25 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
 use super::config_utils;
 use super::copys_common::{CopysStrategyCommon, CopysStrategyConfigBase, CopysStrategyContext};
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::bband_analyzer::BBandAnalyzer;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// Copys 숏 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct CopysShortStrategyConfig {
     #[serde(flatten)]
@@ -32,21 +38,22 @@ impl Default for CopysShortStrategyConfig {
     }
 }
 
-impl CopysShortStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for CopysShortStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         // 기본 설정 유효성 검사
         self.base.validate()?;
 
         if self.rsi_count == 0 {
-            return Err("RSI 판정 횟수는 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range("rsi_count", self.rsi_count, "1 이상").into());
         }
 
         Ok(())
     }
+}
 
+impl CopysShortStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<CopysShortStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<CopysShortStrategyConfig> {
         match CopysStrategyConfigBase::from_json::<CopysShortStrategyConfig>(json) {
             Ok(config) => {
                 config.validate()?;
@@ -57,24 +64,30 @@ impl CopysShortStrategyConfig {
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<CopysShortStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<CopysShortStrategyConfig> {
         // 공통 유틸리티를 사용하여 RSI 설정 파싱
         let (rsi_period, rsi_lower, rsi_upper) = config_utils::parse_rsi_config(config)?;
 
         // RSI 판정 횟수 설정
         let rsi_count = config_utils::parse_usize(config, "rsi_count", Some(1), true)?
-            .ok_or("rsi_count 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("rsi_count"))?;
 
         // 볼린저 밴드 관련 설정
         let bband_period = config_utils::parse_usize(config, "bband_period", Some(2), true)?
-            .ok_or("bband_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("bband_period"))?;
 
         let bband_multiplier =
             config_utils::parse_f64(config, "bband_multiplier", Some((0.0, f64::MAX)), true)?
-                .ok_or("bband_multiplier 설정이 필요합니다")?;
+                .ok_or_else(|| StrategyError::missing_key("bband_multiplier"))?;
 
         if bband_multiplier <= 0.0 {
-            return Err("볼린저 밴드 승수는 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "bband_multiplier",
+                bband_multiplier,
+                "0보다 큰 값",
+            ));
         }
 
         let ma_distance_threshold =
@@ -125,7 +138,7 @@ impl<C: Candle + 'static> CopysShortStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<CopysShortStrategy<C>, String> {
+    ) -> StrategyResult<CopysShortStrategy<C>> {
         let config = CopysShortStrategyConfig::from_json(json_config)?;
         Self::new_with_config_internal(storage, config)
     }
@@ -134,7 +147,7 @@ impl<C: Candle + 'static> CopysShortStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<CopysShortStrategy<C>, String> {
+    ) -> StrategyResult<CopysShortStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => CopysShortStrategyConfig::from_hash_map(&cfg)?,
             None => CopysShortStrategyConfig::default(),
@@ -147,7 +160,7 @@ impl<C: Candle + 'static> CopysShortStrategy<C> {
     fn new_with_config_internal(
         storage: &CandleStore<C>,
         config: CopysShortStrategyConfig,
-    ) -> Result<CopysShortStrategy<C>, String> {
+    ) -> StrategyResult<CopysShortStrategy<C>> {
         info!("코피스 숏 전략 설정: {config:?}");
 
         let ma_type = crate::indicator::ma::MAType::EMA;
@@ -175,6 +188,17 @@ impl<C: Candle + 'static> CopysShortStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for CopysShortStrategy<C> {
+    type Config = CopysShortStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: CopysShortStrategyConfig,
+    ) -> StrategyResult<CopysShortStrategy<C>> {
+        Self::new_with_config_internal(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> CopysStrategyCommon<C> for CopysShortStrategy<C> {
     fn context(&self) -> &CopysStrategyContext<C> {
         &self.ctx
@@ -234,4 +258,37 @@ impl<C: Candle + 'static> Strategy<C> for CopysShortStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::CopysShort
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_sell_signal(self.config.rsi_count, current_price),
+            exit: self.explain_buy_signal(self.config.rsi_count, current_price),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(
+            StrategyType::CopysShort,
+            [self.ctx.snapshot(), self.bband_analyzer.snapshot()],
+        )
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx, bband_analyzer] = snapshot.analyzers_for::<2>(StrategyType::CopysShort)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::CopysShort))?;
+        self.bband_analyzer
+            .restore(bband_analyzer)
+            .map_err(restore_failed(StrategyType::CopysShort))?;
+        Ok(())
+    }
 }
@@ -1,19 +1,25 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
 use super::config_utils;
 use super::copys_common::{CopysStrategyCommon, CopysStrategyConfigBase, CopysStrategyContext};
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::base::AnalyzerOps;
 use crate::analyzer::bband_analyzer::BBandAnalyzer;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// CopyS 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct CopysStrategyConfig {
     #[serde(flatten)]
@@ -32,17 +38,18 @@ impl Default for CopysStrategyConfig {
     }
 }
 
-impl CopysStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for CopysStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         // 기본 설정 유효성 검사
         self.base.validate()?;
 
         Ok(())
     }
+}
 
+impl CopysStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<CopysStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<CopysStrategyConfig> {
         match CopysStrategyConfigBase::from_json::<CopysStrategyConfig>(json) {
             Ok(config) => {
                 config.validate()?;
@@ -53,7 +60,9 @@ impl CopysStrategyConfig {
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<CopysStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<CopysStrategyConfig> {
         // 공통 유틸리티를 사용하여 RSI 설정 파싱
         let (rsi_period, rsi_lower, rsi_upper) = config_utils::parse_rsi_config(config)?;
 
@@ -63,14 +72,18 @@ impl CopysStrategyConfig {
 
         // 볼린저 밴드 관련 설정
         let bband_period = config_utils::parse_usize(config, "bband_period", Some(2), true)?
-            .ok_or("bband_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("bband_period"))?;
 
         let bband_multiplier =
             config_utils::parse_f64(config, "bband_multiplier", Some((0.0, f64::MAX)), true)?
-                .ok_or("bband_multiplier 설정이 필요합니다")?;
+                .ok_or_else(|| StrategyError::missing_key("bband_multiplier"))?;
 
         if bband_multiplier <= 0.0 {
-            return Err("볼린저 밴드 승수는 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "bband_multiplier",
+                bband_multiplier,
+                "0보다 큰 값",
+            ));
         }
 
         let ma_distance_threshold =
@@ -118,7 +131,7 @@ impl<C: Candle + 'static> CopysStrategy<C> {
     pub fn from_json(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<CopysStrategy<C>, String> {
+    ) -> StrategyResult<CopysStrategy<C>> {
         let config = CopysStrategyConfig::from_json(json_config)?;
         Self::new(storage, config)
     }
@@ -127,7 +140,7 @@ impl<C: Candle + 'static> CopysStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         config: CopysStrategyConfig,
-    ) -> Result<CopysStrategy<C>, String> {
+    ) -> StrategyResult<CopysStrategy<C>> {
         info!("CopyS 전략 설정: {config:?}");
 
         let ma_type = crate::indicator::ma::MAType::EMA;
@@ -153,7 +166,7 @@ impl<C: Candle + 'static> CopysStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<CopysStrategy<C>, String> {
+    ) -> StrategyResult<CopysStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => CopysStrategyConfig::from_hash_map(&cfg)?,
             None => CopysStrategyConfig::default(),
@@ -202,6 +215,17 @@ impl<C: Candle + 'static> CopysStrategyCommon<C> for CopysStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for CopysStrategy<C> {
+    type Config = CopysStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: CopysStrategyConfig,
+    ) -> StrategyResult<CopysStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for CopysStrategy<C> {
     fn next(&mut self, candle: C) {
         // 한 번만 클론하여 두 analyzer에 전달
@@ -227,6 +251,39 @@ impl<C: Candle + 'static> Strategy<C> for CopysStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::Copys
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_buy_signal(self.config_rsi_count(), current_price),
+            exit: self.explain_sell_signal(self.config_rsi_count(), current_price),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(
+            StrategyType::Copys,
+            [self.ctx.snapshot(), self.bband_analyzer.snapshot()],
+        )
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx, bband_analyzer] = snapshot.analyzers_for::<2>(StrategyType::Copys)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::Copys))?;
+        self.bband_analyzer
+            .restore(bband_analyzer)
+            .map_err(restore_failed(StrategyType::Copys))?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -280,4 +337,26 @@ mod tests {
         assert!(strategy.check_ma_resistance(100.0));
         assert!(!strategy.check_ma_resistance(50.0));
     }
+
+    #[test]
+    fn test_copys_explain_matches_signals() {
+        let storage = create_flat_storage(100.0, 260);
+        let strategy = CopysStrategy::new_with_config(&storage, None).unwrap();
+
+        for price in [50.0, 100.0, 150.0] {
+            let explanation = strategy.explain(price);
+            assert_eq!(explanation.enter.result, strategy.should_enter(price));
+            assert_eq!(explanation.exit.result, strategy.should_exit(price));
+        }
+
+        let explanation = strategy.explain(100.0);
+        let ma_support = explanation.enter.find("ma_support").unwrap();
+        assert!(ma_support.result);
+        assert_eq!(ma_support.children.len(), 6);
+        assert_eq!(ma_support.children[0].name, "ma_5");
+        assert_eq!(
+            explanation.enter.find("rsi_oversold").unwrap().thresholds["rsi_lower"],
+            30.0
+        );
+    }
 }
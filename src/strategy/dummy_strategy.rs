@@ -1,36 +1,57 @@
+use super::ConfigurableStrategy;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::StrategySnapshot;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
-use serde::Deserialize;
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigResult, ConfigValidation};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 더미 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct DummyStrategyConfig {
     /// 예제 설정 값
     example_value: String,
 }
 
-impl DummyStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    ///
-    /// 모든 설정 값이 유효한지 확인하고, 유효하지 않은 경우 오류 메시지를 반환합니다.
-    ///
-    /// # Returns
-    /// * `Result<(), String>` - 유효성 검사 결과 (성공 또는 오류 메시지)
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for DummyStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         if self.example_value.is_empty() {
-            return Err("example_value는 비어있을 수 없습니다".to_string());
+            return Err(StrategyError::invalid_value(
+                "example_value",
+                &self.example_value,
+                "비어있을 수 없습니다",
+            )
+            .into());
         }
 
         Ok(())
     }
 }
 
+impl DummyStrategyConfig {
+    /// HashMap에서 설정 로드
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<DummyStrategyConfig> {
+        let example_value = config
+            .get("example_value")
+            .ok_or_else(|| StrategyError::missing_key("example_value"))?
+            .clone();
+
+        let result = DummyStrategyConfig { example_value };
+        result.validate()?;
+
+        Ok(result)
+    }
+}
+
 impl Default for DummyStrategyConfig {
     fn default() -> Self {
         DummyStrategyConfig {
@@ -66,27 +87,16 @@ impl<C: Candle> DummyStrategy<C> {
     pub fn new_with_config(
         _storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<DummyStrategy<C>, String> {
-        match config {
-            Some(cfg) => {
-                let example_value = cfg
-                    .get("example_value")
-                    .ok_or_else(|| "example_value 설정이 필요합니다".to_string())?
-                    .clone();
-
-                let config = DummyStrategyConfig { example_value };
-                config.validate()?;
-
-                Ok(DummyStrategy {
-                    config,
-                    _phantom: std::marker::PhantomData,
-                })
-            }
-            None => Ok(DummyStrategy {
-                config: DummyStrategyConfig::default(),
-                _phantom: std::marker::PhantomData,
-            }),
-        }
+    ) -> StrategyResult<DummyStrategy<C>> {
+        let config = match config {
+            Some(cfg) => DummyStrategyConfig::from_hash_map(&cfg)?,
+            None => DummyStrategyConfig::default(),
+        };
+
+        Ok(DummyStrategy {
+            config,
+            _phantom: std::marker::PhantomData,
+        })
     }
 }
 
@@ -100,6 +110,20 @@ impl<C: Candle> Display for DummyStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for DummyStrategy<C> {
+    type Config = DummyStrategyConfig;
+
+    fn from_config(
+        _storage: &CandleStore<C>,
+        config: DummyStrategyConfig,
+    ) -> StrategyResult<DummyStrategy<C>> {
+        Ok(DummyStrategy {
+            config,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+}
+
 impl<C: Candle> Strategy<C> for DummyStrategy<C> {
     fn next(&mut self, _candle: C) {}
 
@@ -118,4 +142,19 @@ impl<C: Candle> Strategy<C> for DummyStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::Dummy
     }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::Dummy, [])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        snapshot.analyzers_for::<0>(StrategyType::Dummy)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,208 @@
+// 전략 생성 오류
+// 설정 UI가 문제가 된 필드를 바로 표시할 수 있도록
+// 각 오류는 설정 키와 문제가 된 값을 함께 담습니다.
+
+use super::StrategyType;
+use crate::ConfigError;
+use std::fmt::Display;
+use thiserror::Error;
+
+/// 전략 생성 결과
+pub type StrategyResult<T> = std::result::Result<T, StrategyError>;
+
+/// 전략 설정 파싱/검증 및 생성 오류
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum StrategyError {
+    /// 필수 설정 키 누락
+    #[error("{key} 설정이 필요합니다")]
+    MissingKey { key: String },
+    /// 값 파싱 실패
+    #[error("{key} 파싱 오류 ({value}): {reason}")]
+    ParseFailure {
+        key: String,
+        value: String,
+        reason: String,
+    },
+    /// 허용 범위를 벗어난 값
+    #[error("{key}({value})은(는) {expected}이어야 합니다")]
+    OutOfRange {
+        key: String,
+        value: String,
+        expected: String,
+    },
+    /// 기간(또는 임계값) 순서 오류
+    #[error("{key}({value})은(는) {other_key}({other_value})보다 작아야 합니다")]
+    InvalidPeriodOrder {
+        key: String,
+        value: String,
+        other_key: String,
+        other_value: String,
+    },
+    /// 설정된 기간에 비해 캔들 수 부족
+    #[error(
+        "캔들 수 부족: {key}({value})에는 최소 {required}개가 필요하지만 {available}개뿐입니다"
+    )]
+    InsufficientCandles {
+        key: String,
+        value: String,
+        required: usize,
+        available: usize,
+    },
+    /// 그 밖의 잘못된 값 (목록 길이 불일치, 중복 등)
+    #[error("{key}({value}) 값이 올바르지 않습니다: {reason}")]
+    InvalidValue {
+        key: String,
+        value: String,
+        reason: String,
+    },
+    /// 설정 구조체에 없는 키
+    #[error("알 수 없는 설정 키: {}", keys.join(", "))]
+    UnknownKeys { keys: Vec<String> },
+    /// 알 수 없는 전략 유형 이름
+    #[error("알 수 없는 전략 유형: {value}")]
+    UnknownStrategyType { value: String },
+    /// 설정 파일 읽기 등 필드와 무관한 설정 오류
+    #[error("{0}")]
+    Config(ConfigError),
+    /// 상태 스냅샷 저장/복원 실패
+    #[error("{strategy} 상태 스냅샷 오류: {reason}")]
+    Snapshot {
+        strategy: StrategyType,
+        reason: String,
+    },
+}
+
+impl StrategyError {
+    /// 문제가 된 설정 키 (필드와 무관한 오류는 None)
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            StrategyError::MissingKey { key }
+            | StrategyError::ParseFailure { key, .. }
+            | StrategyError::OutOfRange { key, .. }
+            | StrategyError::InvalidPeriodOrder { key, .. }
+            | StrategyError::InsufficientCandles { key, .. }
+            | StrategyError::InvalidValue { key, .. } => Some(key),
+            StrategyError::UnknownKeys { keys } => keys.first().map(String::as_str),
+            StrategyError::UnknownStrategyType { .. }
+            | StrategyError::Config(_)
+            | StrategyError::Snapshot { .. } => None,
+        }
+    }
+
+    /// 문제가 된 값 (값이 없는 오류는 None)
+    pub fn value(&self) -> Option<&str> {
+        match self {
+            StrategyError::ParseFailure { value, .. }
+            | StrategyError::OutOfRange { value, .. }
+            | StrategyError::InvalidPeriodOrder { value, .. }
+            | StrategyError::InsufficientCandles { value, .. }
+            | StrategyError::InvalidValue { value, .. }
+            | StrategyError::UnknownStrategyType { value } => Some(value),
+            StrategyError::MissingKey { .. }
+            | StrategyError::UnknownKeys { .. }
+            | StrategyError::Config(_)
+            | StrategyError::Snapshot { .. } => None,
+        }
+    }
+
+    pub(crate) fn missing_key(key: impl Into<String>) -> Self {
+        StrategyError::MissingKey { key: key.into() }
+    }
+
+    pub(crate) fn parse_failure(
+        key: impl Into<String>,
+        value: impl Display,
+        reason: impl Display,
+    ) -> Self {
+        StrategyError::ParseFailure {
+            key: key.into(),
+            value: value.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub(crate) fn out_of_range(
+        key: impl Into<String>,
+        value: impl Display,
+        expected: impl Into<String>,
+    ) -> Self {
+        StrategyError::OutOfRange {
+            key: key.into(),
+            value: value.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    pub(crate) fn invalid_period_order(
+        key: impl Into<String>,
+        value: impl Display,
+        other_key: impl Into<String>,
+        other_value: impl Display,
+    ) -> Self {
+        StrategyError::InvalidPeriodOrder {
+            key: key.into(),
+            value: value.to_string(),
+            other_key: other_key.into(),
+            other_value: other_value.to_string(),
+        }
+    }
+
+    pub(crate) fn invalid_value(
+        key: impl Into<String>,
+        value: impl Display,
+        reason: impl Into<String>,
+    ) -> Self {
+        StrategyError::InvalidValue {
+            key: key.into(),
+            value: value.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    pub(crate) fn unknown_strategy_type(value: impl Into<String>) -> Self {
+        StrategyError::UnknownStrategyType {
+            value: value.into(),
+        }
+    }
+
+    pub(crate) fn snapshot_failure(strategy: StrategyType, reason: impl Into<String>) -> Self {
+        StrategyError::Snapshot {
+            strategy,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// 설정 오류 변환 (전략 오류를 감싼 경우 그대로 꺼냄)
+impl From<ConfigError> for StrategyError {
+    fn from(err: ConfigError) -> Self {
+        match err {
+            ConfigError::Strategy(err) => *err,
+            err => StrategyError::Config(err),
+        }
+    }
+}
+
+/// String으로 StrategyError 변환
+impl From<StrategyError> for String {
+    fn from(err: StrategyError) -> Self {
+        err.to_string()
+    }
+}
+
+/// 오름차순 기간 목록 검사
+///
+/// 인접한 두 기간이 오름차순이 아니면 `key[i]` 형태의 키로 순서 오류를 반환합니다.
+pub(crate) fn check_ascending(key: &str, periods: &[usize]) -> StrategyResult<()> {
+    for i in 1..periods.len() {
+        if periods[i] <= periods[i - 1] {
+            return Err(StrategyError::invalid_period_order(
+                format!("{key}[{}]", i - 1),
+                periods[i - 1],
+                format!("{key}[{i}]"),
+                periods[i],
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,236 @@
+// 전략 신호 설명
+// 진입/청산 판단에 사용된 조건을 입력값, 임계값, 결과와 함께 트리로 기록합니다.
+// 주문에 첨부해 두었다가 놓친 신호나 잘못된 신호를 사후에 분석할 수 있도록 JSON으로 직렬화됩니다.
+
+use super::StrategyType;
+use crate::analyzer::base::{AnalyzerDataOps, AnalyzerOps};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use trading_chart::Candle;
+
+/// 하위 조건 결합 방식
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConditionRule {
+    /// 모든 하위 조건 만족
+    All,
+    /// 하위 조건 중 하나 이상 만족
+    Any,
+    /// 하위 조건 중 count개 이상 만족
+    AtLeast { count: usize },
+    /// 먼저 만족한 하위 조건 하나만 적용 (if / else if 분기)
+    FirstMatch,
+    /// 하위 조건으로 계산한 신호 강도(입력값)를 임계값과 비교
+    Score,
+}
+
+/// 평가된 조건 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionNode {
+    /// 조건 이름 (예: rsi_oversold, close_below_lower_band)
+    pub name: String,
+    /// 조건 만족 여부
+    pub result: bool,
+    /// 평가에 사용된 지표/가격 값
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub inputs: BTreeMap<String, f64>,
+    /// 평가에 사용된 설정 임계값
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub thresholds: BTreeMap<String, f64>,
+    /// 하위 조건 결합 방식 (하위 조건이 없으면 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<ConditionRule>,
+    /// 조건 만족 시 신호 강도에 더해지는 점수 (점수형 전략만 사용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// 조건 만족 시 정규화 분모에 더해지는 가중치 (점수형 전략만 사용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    /// 하위 조건
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ConditionNode>,
+}
+
+impl ConditionNode {
+    /// 하위 조건이 없는 조건 생성
+    pub fn new(name: impl Into<String>, result: bool) -> ConditionNode {
+        ConditionNode {
+            name: name.into(),
+            result,
+            inputs: BTreeMap::new(),
+            thresholds: BTreeMap::new(),
+            rule: None,
+            score: None,
+            weight: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// 하위 조건을 결합한 조건 생성
+    ///
+    /// 결과는 결합 방식에 따라 하위 조건 결과로부터 계산합니다.
+    /// `Score`는 신호 강도를 알아야 하므로 `new`와 `rule`/`children`으로 직접 구성합니다.
+    pub fn group(
+        name: impl Into<String>,
+        rule: ConditionRule,
+        children: Vec<ConditionNode>,
+    ) -> ConditionNode {
+        let matched = children.iter().filter(|child| child.result).count();
+        let result = match rule {
+            ConditionRule::All => matched == children.len(),
+            ConditionRule::Any | ConditionRule::FirstMatch => matched > 0,
+            ConditionRule::AtLeast { count } => matched >= count,
+            ConditionRule::Score => false,
+        };
+
+        ConditionNode::new(name, result)
+            .rule(rule)
+            .children(children)
+    }
+
+    /// 입력값 추가
+    pub fn input(mut self, key: impl Into<String>, value: f64) -> ConditionNode {
+        self.inputs.insert(key.into(), value);
+        self
+    }
+
+    /// 임계값 추가
+    pub fn threshold(mut self, key: impl Into<String>, value: f64) -> ConditionNode {
+        self.thresholds.insert(key.into(), value);
+        self
+    }
+
+    /// 결합 방식 지정
+    pub fn rule(mut self, rule: ConditionRule) -> ConditionNode {
+        self.rule = Some(rule);
+        self
+    }
+
+    /// 점수와 가중치 지정
+    pub fn scored(mut self, score: f64, weight: f64) -> ConditionNode {
+        self.score = Some(score);
+        self.weight = Some(weight);
+        self
+    }
+
+    /// 하위 조건 지정
+    pub fn children(mut self, children: Vec<ConditionNode>) -> ConditionNode {
+        self.children = children;
+        self
+    }
+
+    /// 이름으로 조건 검색 (자신 포함, 깊이 우선)
+    pub fn find(&self, name: &str) -> Option<&ConditionNode> {
+        if self.name == name {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(name))
+    }
+}
+
+/// 돌파 조건 생성 (최근 `n`개 데이터는 만족하고 그 이전 `m`개 데이터는 만족하지 않음)
+///
+/// 결과는 `AnalyzerOps::is_break_through_by_satisfying(is_fn, n, m, 0)`과 같으며,
+/// 두 부분을 `satisfied`/`previously_unsatisfied` 하위 조건으로 나눠 기록합니다.
+pub(crate) fn break_through_node<A, Data, C>(
+    analyzer: &A,
+    name: impl Into<String>,
+    is_fn: impl Fn(&Data) -> bool + Copy,
+    n: usize,
+    m: usize,
+) -> ConditionNode
+where
+    A: AnalyzerOps<Data, C> + ?Sized,
+    Data: AnalyzerDataOps<C>,
+    C: Candle,
+{
+    let items = analyzer.items();
+    let previously_unsatisfied =
+        items.len() >= n + m && items.iter().skip(n).take(m).all(|data| !is_fn(data));
+
+    ConditionNode::group(
+        name,
+        ConditionRule::All,
+        vec![
+            ConditionNode::new("satisfied", analyzer.is_all(is_fn, n, 0))
+                .threshold("count", n as f64),
+            ConditionNode::new("previously_unsatisfied", previously_unsatisfied)
+                .threshold("count", m as f64),
+        ],
+    )
+}
+
+/// 전략 신호 판단 근거
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalExplanation {
+    /// 전략 유형
+    pub strategy: StrategyType,
+    /// 평가에 사용된 현재 가격
+    pub current_price: f64,
+    /// 진입 조건 트리 (루트 결과는 `should_enter`와 같음)
+    pub enter: ConditionNode,
+    /// 청산 조건 트리 (루트 결과는 `should_exit`와 같음)
+    pub exit: ConditionNode,
+}
+
+impl SignalExplanation {
+    /// JSON 문자열로 변환
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_result_follows_rule() {
+        let children = vec![
+            ConditionNode::new("a", true),
+            ConditionNode::new("b", false),
+            ConditionNode::new("c", true),
+        ];
+
+        assert!(!ConditionNode::group("all", ConditionRule::All, children.clone()).result);
+        assert!(ConditionNode::group("any", ConditionRule::Any, children.clone()).result);
+        assert!(
+            ConditionNode::group("two", ConditionRule::AtLeast { count: 2 }, children.clone())
+                .result
+        );
+        assert!(
+            !ConditionNode::group("three", ConditionRule::AtLeast { count: 3 }, children).result
+        );
+    }
+
+    #[test]
+    fn test_explanation_json_round_trip() {
+        let explanation = SignalExplanation {
+            strategy: StrategyType::Copys,
+            current_price: 100.0,
+            enter: ConditionNode::group(
+                "buy_signal",
+                ConditionRule::AtLeast { count: 2 },
+                vec![
+                    ConditionNode::new("rsi_oversold", true)
+                        .input("rsi", 25.0)
+                        .threshold("rsi_lower", 30.0),
+                ],
+            ),
+            exit: ConditionNode::new("exit", false),
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_str(&explanation.to_json().unwrap()).unwrap();
+        assert_eq!(json["enter"]["rule"]["type"], "at_least");
+        assert_eq!(json["enter"]["children"][0]["inputs"]["rsi"], 25.0);
+        assert!(json["exit"].get("children").is_none());
+
+        let restored: SignalExplanation = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, explanation);
+        assert_eq!(
+            restored.enter.find("rsi_oversold").unwrap().thresholds["rsi_lower"],
+            30.0
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use super::Strategy;
 use super::config_utils;
+use super::explain::{ConditionNode, ConditionRule};
+use super::{StrategyError, StrategyResult};
 use crate::indicator::ma::MAType;
-use serde::Deserialize;
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::HashMap;
 use trading_chart::Candle;
@@ -60,7 +63,7 @@ impl SignalCache {
 }
 
 /// 하이브리드 전략 공통 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct HybridStrategyConfigBase {
     /// RSI 계산 기간
@@ -109,75 +112,116 @@ impl Default for HybridStrategyConfigBase {
     }
 }
 
-impl HybridStrategyConfigBase {
-    /// 설정의 유효성을 검사합니다.
-    ///
-    /// 모든 설정 값이 유효한지 확인하고, 유효하지 않은 경우 오류 메시지를 반환합니다.
-    ///
-    /// # Returns
-    /// * `Result<(), String>` - 유효성 검사 결과 (성공 또는 오류 메시지)
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for HybridStrategyConfigBase {
+    fn validate(&self) -> ConfigResult<()> {
         if self.rsi_period < 2 {
-            return Err("RSI 기간은 2 이상이어야 합니다".to_string());
+            return Err(
+                StrategyError::out_of_range("rsi_period", self.rsi_period, "2 이상").into(),
+            );
         }
 
         if !self.rsi_lower.is_finite() || !(0.0..=100.0).contains(&self.rsi_lower) {
-            return Err("RSI 하한값은 0 이상 100 이하여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "rsi_lower",
+                self.rsi_lower,
+                "0 이상 100 이하",
+            )
+            .into());
         }
 
         if !self.rsi_upper.is_finite() || !(0.0..=100.0).contains(&self.rsi_upper) {
-            return Err("RSI 상한값은 0 이상 100 이하여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "rsi_upper",
+                self.rsi_upper,
+                "0 이상 100 이하",
+            )
+            .into());
         }
 
         if self.rsi_lower >= self.rsi_upper {
-            return Err(format!(
-                "RSI 하한값({})이 상한값({})보다 크거나 같을 수 없습니다",
-                self.rsi_lower, self.rsi_upper
-            ));
+            return Err(StrategyError::invalid_period_order(
+                "rsi_lower",
+                self.rsi_lower,
+                "rsi_upper",
+                self.rsi_upper,
+            )
+            .into());
         }
 
         if self.rsi_count == 0 {
-            return Err("RSI 판정 횟수는 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range("rsi_count", self.rsi_count, "1 이상").into());
         }
 
         if self.bband_period < 2 {
-            return Err("볼린저밴드 기간은 2 이상이어야 합니다".to_string());
+            return Err(
+                StrategyError::out_of_range("bband_period", self.bband_period, "2 이상").into(),
+            );
         }
 
         if !self.bband_multiplier.is_finite() || self.bband_multiplier <= 0.0 {
-            return Err("볼린저밴드 승수는 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "bband_multiplier",
+                self.bband_multiplier,
+                "0보다 큰 값",
+            )
+            .into());
         }
 
         if self.ma_rank_period < 2 {
-            return Err("마켓 랭크 이동평균 기간은 2 이상이어야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "ma_rank_period",
+                self.ma_rank_period,
+                "2 이상",
+            )
+            .into());
         }
 
         if self.ma_period == 0 {
-            return Err("이동평균 기간은 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range("ma_period", self.ma_period, "1 이상").into());
         }
 
         if self.macd_fast_period == 0 {
-            return Err("MACD 빠른 기간은 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "macd_fast_period",
+                self.macd_fast_period,
+                "1 이상",
+            )
+            .into());
         }
 
         if self.macd_slow_period == 0 {
-            return Err("MACD 느린 기간은 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "macd_slow_period",
+                self.macd_slow_period,
+                "1 이상",
+            )
+            .into());
         }
 
         if self.macd_fast_period >= self.macd_slow_period {
-            return Err(format!(
-                "MACD 빠른 기간({})은 느린 기간({})보다 작아야 합니다",
-                self.macd_fast_period, self.macd_slow_period
-            ));
+            return Err(StrategyError::invalid_period_order(
+                "macd_fast_period",
+                self.macd_fast_period,
+                "macd_slow_period",
+                self.macd_slow_period,
+            )
+            .into());
         }
 
         if self.macd_signal_period == 0 {
-            return Err("MACD 시그널 기간은 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "macd_signal_period",
+                self.macd_signal_period,
+                "1 이상",
+            )
+            .into());
         }
 
         Ok(())
     }
+}
 
+impl HybridStrategyConfigBase {
     /// JSON 문자열에서 설정 로드
     ///
     /// JSON 문자열로부터 설정을 로드하고, 로드에 실패할 경우 오류를 반환합니다.
@@ -186,50 +230,53 @@ impl HybridStrategyConfigBase {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<T, String>` - 로드된 설정 또는 오류
-    pub fn from_json<T>(json: &str) -> Result<T, String>
+    /// * `StrategyResult<T>` - 로드된 설정 또는 오류
+    pub fn from_json<T>(json: &str) -> StrategyResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
         match serde_json::from_str::<T>(json) {
             Ok(config) => Ok(config),
-            Err(e) => Err(format!("JSON 설정 역직렬화 실패: {e}")),
+            Err(e) => Err(ConfigError::ParseError(format!("JSON 설정 역직렬화 실패: {e}")).into()),
         }
     }
 
     /// HashMap에서 설정 로드
     pub fn from_hash_map(
         config: &HashMap<String, String>,
-    ) -> Result<HybridStrategyConfigBase, String> {
+    ) -> StrategyResult<HybridStrategyConfigBase> {
         // 공통 유틸리티를 사용하여 설정 파싱
         let count = config_utils::parse_usize(config, "count", Some(1), true)?
-            .ok_or("count 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("count"))?;
 
         // 이동평균 관련 설정
         let ma_type = config_utils::parse_ma_type(config, Some("ma_type"), true)?
-            .ok_or("ma_type 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("ma_type"))?;
 
         let ma_period = config_utils::parse_usize(config, "ma_period", Some(1), true)?
-            .ok_or("ma_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("ma_period"))?;
 
         // MACD 관련 설정
         let macd_fast_period =
             config_utils::parse_usize(config, "macd_fast_period", Some(1), true)?
-                .ok_or("macd_fast_period 설정이 필요합니다")?;
+                .ok_or_else(|| StrategyError::missing_key("macd_fast_period"))?;
 
         let macd_slow_period =
             config_utils::parse_usize(config, "macd_slow_period", Some(1), true)?
-                .ok_or("macd_slow_period 설정이 필요합니다")?;
+                .ok_or_else(|| StrategyError::missing_key("macd_slow_period"))?;
 
         if macd_fast_period >= macd_slow_period {
-            return Err(format!(
-                "MACD 빠른 기간({macd_fast_period})은 느린 기간({macd_slow_period})보다 작아야 합니다"
+            return Err(StrategyError::invalid_period_order(
+                "macd_fast_period",
+                macd_fast_period,
+                "macd_slow_period",
+                macd_slow_period,
             ));
         }
 
         let macd_signal_period =
             config_utils::parse_usize(config, "macd_signal_period", Some(1), true)?
-                .ok_or("macd_signal_period 설정이 필요합니다")?;
+                .ok_or_else(|| StrategyError::missing_key("macd_signal_period"))?;
 
         // 공통 유틸리티를 사용하여 RSI 설정 파싱
         let (rsi_period, rsi_lower, rsi_upper) = config_utils::parse_rsi_config(config)?;
@@ -244,29 +291,11 @@ impl HybridStrategyConfigBase {
             rsi_period,
             rsi_lower,
             rsi_upper,
-            bband_period: config
-                .get("bband_period")
-                .map(|s| {
-                    s.parse::<usize>()
-                        .map_err(|e| format!("bband_period 파싱 오류: {e}"))
-                })
-                .transpose()?
+            bband_period: config_utils::parse_usize(config, "bband_period", None, false)?
                 .unwrap_or(20),
-            bband_multiplier: config
-                .get("bband_multiplier")
-                .map(|s| {
-                    s.parse::<f64>()
-                        .map_err(|e| format!("bband_multiplier 파싱 오류: {e}"))
-                })
-                .transpose()?
+            bband_multiplier: config_utils::parse_f64(config, "bband_multiplier", None, false)?
                 .unwrap_or(2.0),
-            ma_rank_period: config
-                .get("ma_rank_period")
-                .map(|s| {
-                    s.parse::<usize>()
-                        .map_err(|e| format!("ma_rank_period 파싱 오류: {e}"))
-                })
-                .transpose()?
+            ma_rank_period: config_utils::parse_usize(config, "ma_rank_period", None, false)?
                 .unwrap_or(20),
         };
 
@@ -275,6 +304,17 @@ impl HybridStrategyConfigBase {
     }
 }
 
+/// 하이브리드 전략이 사용하는 지표 기간 목록 (설정 키, 기간)
+pub(crate) fn period_candidates(config: &HybridStrategyConfigBase) -> Vec<(String, usize)> {
+    vec![
+        ("rsi_period".to_string(), config.rsi_period),
+        ("bband_period".to_string(), config.bband_period),
+        ("ma_rank_period".to_string(), config.ma_rank_period),
+        ("ma_period".to_string(), config.ma_period),
+        ("macd_slow_period".to_string(), config.macd_slow_period),
+    ]
+}
+
 /// 하이브리드 전략 공통 트레이트
 pub trait HybridStrategyCommon<C: Candle + Clone + 'static>: Strategy<C> {
     /// 분석기 참조 반환
@@ -283,148 +323,222 @@ pub trait HybridStrategyCommon<C: Candle + Clone + 'static>: Strategy<C> {
     /// 설정 기본값 참조 반환
     fn config_base(&self) -> &HybridStrategyConfigBase;
 
-    /// 매수 신호 강도 계산
+    /// 매수 신호 강도 계산 (`explain_buy_signal_strength` 조건 점수의 가중 평균)
     fn calculate_buy_signal_strength(&self) -> f64 {
+        signal_strength(&self.explain_buy_signal_strength(), 1.0)
+    }
+
+    /// 매도 신호 강도 계산 (`explain_sell_signal_strength` 조건 점수를 가중치 합의 2배로 정규화)
+    fn calculate_sell_signal_strength(&self, profit_percentage: f64) -> f64 {
+        signal_strength(&self.explain_sell_signal_strength(profit_percentage), 2.0)
+    }
+
+    /// 매수 신호 강도를 구성하는 조건 (조건별 점수와 가중치 포함)
+    fn explain_buy_signal_strength(&self) -> Vec<ConditionNode> {
         let ctx = self.context();
 
         if ctx.items.len() < 2 {
-            return 0.0;
+            return Vec::new();
         }
 
-        let current = ctx
-            .items
-            .first()
-            .expect("items.len() >= 2 체크 후이므로 항상 존재해야 함");
+        let current = &ctx.items[0];
         let previous = &ctx.items[1];
         let config = self.config_base();
 
-        let mut strength = 0.0;
-        let mut count = 0.0;
-
-        // 1. 이동평균선 기반 신호
-        if current.candle.close_price() > current.ma.get() {
-            strength += 1.0;
-            count += 1.0;
-
-            // 상승 추세 강화: 가격이 이동평균보다 2% 이상 높으면 추가 점수
-            if current.candle.close_price() > current.ma.get() * 1.02 {
-                strength += 0.5;
-            }
-        }
-
-        // 2. MACD 기반 신호
-        if current.macd.histogram > 0.0 && previous.macd.histogram < 0.0 {
-            // MACD 히스토그램이 0선을 상향 돌파 (강한 매수 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if current.macd.histogram > 0.0 {
-            // MACD 히스토그램이 0선 위에 있음 (약한 매수 신호)
-            strength += 0.8; // 0.5에서 0.8로 증가
-            count += 1.0;
-        }
-
-        // 3. RSI 기반 신호
+        let close = current.candle.close_price();
+        let ma = current.ma.get();
+        let histogram = current.macd.histogram;
+        let previous_histogram = previous.macd.histogram;
         let rsi = current.rsi.value();
-        if rsi < config.rsi_lower && rsi > previous.rsi.value() {
-            // RSI가 과매도 상태에서 반등 (강한 매수 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if rsi > config.rsi_lower && rsi < 50.0 {
-            // RSI가 과매도 상태를 벗어나 상승 중 (약한 매수 신호)
-            strength += 0.8; // 0.5에서 0.8로 증가
-            count += 1.0;
-        } else if rsi > 50.0 && rsi < 70.0 && rsi > previous.rsi.value() {
-            // RSI가 50-70 구간에서 상승 중 (상승 추세 형성) - 범위 확장
-            strength += 0.7;
-            count += 0.8;
-        } else if rsi > 70.0 && rsi < 90.0 && rsi > previous.rsi.value() {
-            // RSI가 70-90 구간에서 상승 중 (강한 상승 추세) - 가중치 상향
-            strength += 1.0;
-            count += 1.0;
-        } else if rsi > 90.0 && rsi <= 100.0 && rsi > previous.rsi.value() {
-            // RSI가 90-100 구간에서 상승 중 (극강한 상승 추세) - 추가 조건
-            strength += 0.3;
-            count += 0.4;
-        }
-
-        // 4. 가격 변동 패턴 (추가)
-        if current.candle.close_price() > previous.candle.close_price() {
-            // 종가가 전일 종가보다 높음 (상승 추세)
-            strength += 0.5;
-            count += 0.5;
-        }
-
-        // 최종 강도 계산 (정규화)
-        if count > 0.0 {
-            strength / count // 정규화 방식을 단순화하여 더 높은 신호 강도 생성
-        } else {
-            0.0
-        }
+        let rsi_rising = rsi > previous.rsi.value();
+        let previous_close = previous.candle.close_price();
+
+        vec![
+            ma_node("close_above_ma", close > ma, close, ma).scored(1.0, 1.0),
+            ma_node(
+                "close_above_ma_2pct",
+                close > ma && close > ma * 1.02,
+                close,
+                ma,
+            )
+            .threshold("ma_ratio", 1.02)
+            .scored(0.5, 0.0),
+            macd_histogram_node(
+                histogram,
+                previous_histogram,
+                vec![
+                    ConditionNode::new(
+                        "macd_histogram_cross_up",
+                        histogram > 0.0 && previous_histogram < 0.0,
+                    )
+                    .scored(2.0, 1.0),
+                    ConditionNode::new("macd_histogram_positive", histogram > 0.0).scored(0.8, 1.0),
+                ],
+            ),
+            rsi_branch_node(
+                current,
+                previous,
+                vec![
+                    ConditionNode::new(
+                        "rsi_oversold_rebound",
+                        rsi < config.rsi_lower && rsi_rising,
+                    )
+                    .threshold("rsi_lower", config.rsi_lower)
+                    .scored(2.0, 1.0),
+                    ConditionNode::new("rsi_recovering", rsi > config.rsi_lower && rsi < 50.0)
+                        .threshold("rsi_lower", config.rsi_lower)
+                        .threshold("rsi_mid", 50.0)
+                        .scored(0.8, 1.0),
+                    ConditionNode::new("rsi_rising_50_70", rsi > 50.0 && rsi < 70.0 && rsi_rising)
+                        .scored(0.7, 0.8),
+                    ConditionNode::new("rsi_rising_70_90", rsi > 70.0 && rsi < 90.0 && rsi_rising)
+                        .scored(1.0, 1.0),
+                    ConditionNode::new(
+                        "rsi_rising_90_100",
+                        rsi > 90.0 && rsi <= 100.0 && rsi_rising,
+                    )
+                    .scored(0.3, 0.4),
+                ],
+            ),
+            ConditionNode::new("close_above_previous_close", close > previous_close)
+                .input("close", close)
+                .input("previous_close", previous_close)
+                .scored(0.5, 0.5),
+        ]
     }
 
-    /// 매도 신호 강도 계산
-    fn calculate_sell_signal_strength(&self, profit_percentage: f64) -> f64 {
+    /// 매도 신호 강도를 구성하는 조건 (조건별 점수와 가중치 포함)
+    fn explain_sell_signal_strength(&self, profit_percentage: f64) -> Vec<ConditionNode> {
         let ctx = self.context();
 
         if ctx.items.len() < 2 {
-            return 0.0;
+            return Vec::new();
         }
 
-        let current = ctx
-            .items
-            .first()
-            .expect("items.len() >= 2 체크 후이므로 항상 존재해야 함");
+        let current = &ctx.items[0];
         let previous = &ctx.items[1];
         let config = self.config_base();
 
-        let mut strength = 0.0;
-        let mut count = 0.0;
-
-        // 1. 이동평균선 기반 신호
-        if current.candle.close_price() < current.ma.get() {
-            strength += 1.0;
-            count += 1.0;
-        }
-
-        // 2. MACD 기반 신호
-        if current.macd.histogram < 0.0 && previous.macd.histogram > 0.0 {
-            // MACD 히스토그램이 0선을 하향 돌파 (강한 매도 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if current.macd.histogram < 0.0 {
-            // MACD 히스토그램이 0선 아래에 있음 (약한 매도 신호)
-            strength += 0.5;
-            count += 1.0;
-        }
-
-        // 3. RSI 기반 신호
+        let close = current.candle.close_price();
+        let ma = current.ma.get();
+        let histogram = current.macd.histogram;
+        let previous_histogram = previous.macd.histogram;
         let rsi = current.rsi.value();
-        if rsi > config.rsi_upper && rsi < previous.rsi.value() {
-            // RSI가 과매수 상태에서 하락 (강한 매도 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if rsi < config.rsi_upper && rsi > 50.0 {
-            // RSI가 과매수 상태로 접근 중 (약한 매도 신호)
-            strength += 0.5;
-            count += 1.0;
-        }
 
-        // 4. 수익률 기반 신호
-        if profit_percentage > 5.0 {
-            // 5% 이상 수익 (적절한 매도 신호)
-            strength += 1.0;
-            count += 1.0;
-        } else if profit_percentage < -3.0 {
-            // 3% 이상 손실 (손절 매도 신호)
-            strength += 1.5;
-            count += 1.0;
-        }
+        vec![
+            ma_node("close_below_ma", close < ma, close, ma).scored(1.0, 1.0),
+            macd_histogram_node(
+                histogram,
+                previous_histogram,
+                vec![
+                    ConditionNode::new(
+                        "macd_histogram_cross_down",
+                        histogram < 0.0 && previous_histogram > 0.0,
+                    )
+                    .scored(2.0, 1.0),
+                    ConditionNode::new("macd_histogram_negative", histogram < 0.0).scored(0.5, 1.0),
+                ],
+            ),
+            rsi_branch_node(
+                current,
+                previous,
+                vec![
+                    ConditionNode::new(
+                        "rsi_overbought_reversal",
+                        rsi > config.rsi_upper && rsi < previous.rsi.value(),
+                    )
+                    .threshold("rsi_upper", config.rsi_upper)
+                    .scored(2.0, 1.0),
+                    ConditionNode::new(
+                        "rsi_approaching_overbought",
+                        rsi < config.rsi_upper && rsi > 50.0,
+                    )
+                    .threshold("rsi_upper", config.rsi_upper)
+                    .threshold("rsi_mid", 50.0)
+                    .scored(0.5, 1.0),
+                ],
+            ),
+            ConditionNode::group(
+                "profit",
+                ConditionRule::FirstMatch,
+                vec![
+                    ConditionNode::new("take_profit", profit_percentage > 5.0)
+                        .threshold("profit_percentage", 5.0)
+                        .scored(1.0, 1.0),
+                    ConditionNode::new("stop_loss", profit_percentage < -3.0)
+                        .threshold("profit_percentage", -3.0)
+                        .scored(1.5, 1.0),
+                ],
+            )
+            .input("profit_percentage", profit_percentage),
+        ]
+    }
+}
 
-        // 최종 강도 계산 (정규화)
-        if count > 0.0 {
-            strength / (count * 2.0) // 최대 강도를 기준으로 정규화
+/// 조건 점수로 계산한 신호 강도 (`점수 합 / (가중치 합 * scale)`)
+///
+/// 점수가 있는 조건은 만족했을 때만 반영하고, `FirstMatch` 분기는 먼저 만족한 하위 조건 하나만 반영합니다.
+pub(crate) fn signal_strength(conditions: &[ConditionNode], scale: f64) -> f64 {
+    let (strength, weight) = conditions
+        .iter()
+        .fold((0.0, 0.0), |totals, condition| add_score(condition, totals));
+
+    if weight > 0.0 {
+        strength / (weight * scale)
+    } else {
+        0.0
+    }
+}
+
+fn add_score(condition: &ConditionNode, (strength, weight): (f64, f64)) -> (f64, f64) {
+    if let Some(score) = condition.score {
+        return if condition.result {
+            (strength + score, weight + condition.weight.unwrap_or(0.0))
         } else {
-            0.0
-        }
+            (strength, weight)
+        };
+    }
+
+    match condition.rule {
+        Some(ConditionRule::FirstMatch) => condition
+            .children
+            .iter()
+            .find(|child| child.result)
+            .map_or((strength, weight), |child| {
+                add_score(child, (strength, weight))
+            }),
+        _ => condition
+            .children
+            .iter()
+            .fold((strength, weight), |totals, child| add_score(child, totals)),
     }
 }
+
+/// 종가와 이동평균을 입력으로 담은 조건 생성
+pub(crate) fn ma_node(name: &str, result: bool, close: f64, ma: f64) -> ConditionNode {
+    ConditionNode::new(name, result)
+        .input("close", close)
+        .input("ma", ma)
+}
+
+/// MACD 히스토그램 분기 조건 생성 (먼저 만족한 분기만 점수에 반영)
+pub(crate) fn macd_histogram_node(
+    histogram: f64,
+    previous_histogram: f64,
+    branches: Vec<ConditionNode>,
+) -> ConditionNode {
+    ConditionNode::group("macd_histogram", ConditionRule::FirstMatch, branches)
+        .input("histogram", histogram)
+        .input("previous_histogram", previous_histogram)
+}
+
+/// RSI 분기 조건 생성 (먼저 만족한 분기만 점수에 반영)
+pub(crate) fn rsi_branch_node<C: Candle>(
+    current: &HybridAnalyzerData<C>,
+    previous: &HybridAnalyzerData<C>,
+    branches: Vec<ConditionNode>,
+) -> ConditionNode {
+    ConditionNode::group("rsi", ConditionRule::FirstMatch, branches)
+        .input("rsi", current.rsi.value())
+        .input("previous_rsi", previous.rsi.value())
+}
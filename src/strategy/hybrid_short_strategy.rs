@@ -1,20 +1,29 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::config_utils;
+use super::explain::{ConditionNode, ConditionRule};
 use super::hybrid_common::{
-    HybridAnalyzer, HybridStrategyCommon, HybridStrategyConfigBase, SignalCache,
+    HybridAnalyzer, HybridStrategyCommon, HybridStrategyConfigBase, SignalCache, ma_node,
+    macd_histogram_node, rsi_branch_node, signal_strength,
 };
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::base::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigResult, ConfigValidation};
 use log::{debug, info};
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 하이브리드 숏 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct HybridShortStrategyConfig {
     #[serde(flatten)]
@@ -61,9 +70,8 @@ impl Default for HybridShortStrategyConfig {
     }
 }
 
-impl HybridShortStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for HybridShortStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         // 기본 설정 유효성 검사
         self.base.validate()?;
 
@@ -72,28 +80,47 @@ impl HybridShortStrategyConfig {
             || self.entry_threshold <= 0.0
             || self.entry_threshold > 1.0
         {
-            return Err("진입 신호 임계값은 0과 1 사이여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "entry_threshold",
+                self.entry_threshold,
+                "0 초과 1 이하",
+            )
+            .into());
         }
         if !self.exit_threshold.is_finite()
             || self.exit_threshold <= 0.0
             || self.exit_threshold > 1.0
         {
-            return Err("청산 신호 임계값은 0과 1 사이여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "exit_threshold",
+                self.exit_threshold,
+                "0 초과 1 이하",
+            )
+            .into());
         }
 
         // 손절/이익실현 레벨 검사
         if !self.stop_loss.is_finite() || self.stop_loss >= 0.0 {
-            return Err("손절 수준은 음수여야 합니다".to_string());
+            return Err(
+                StrategyError::out_of_range("stop_loss", self.stop_loss, "0보다 작은 값").into(),
+            );
         }
         if !self.take_profit.is_finite() || self.take_profit <= 0.0 {
-            return Err("이익 실현 수준은 양수여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "take_profit",
+                self.take_profit,
+                "0보다 큰 값",
+            )
+            .into());
         }
 
         Ok(())
     }
+}
 
+impl HybridShortStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<HybridShortStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<HybridShortStrategyConfig> {
         match HybridStrategyConfigBase::from_json::<HybridShortStrategyConfig>(json) {
             Ok(config) => {
                 config.validate()?;
@@ -104,46 +131,22 @@ impl HybridShortStrategyConfig {
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(
+    pub(crate) fn from_hash_map(
         config: &HashMap<String, String>,
-    ) -> Result<HybridShortStrategyConfig, String> {
+    ) -> StrategyResult<HybridShortStrategyConfig> {
         let base_config = HybridStrategyConfigBase::from_hash_map(config)?;
 
         // 추가 설정 추출
-        let entry_threshold = config
-            .get("entry_threshold")
-            .map(|s| {
-                s.parse::<f64>()
-                    .map_err(|e| format!("진입 임계값 파싱 오류: {e}"))
-            })
-            .transpose()?
+        let entry_threshold = config_utils::parse_f64(config, "entry_threshold", None, false)?
             .unwrap_or_else(default_entry_threshold);
 
-        let exit_threshold = config
-            .get("exit_threshold")
-            .map(|s| {
-                s.parse::<f64>()
-                    .map_err(|e| format!("청산 임계값 파싱 오류: {e}"))
-            })
-            .transpose()?
+        let exit_threshold = config_utils::parse_f64(config, "exit_threshold", None, false)?
             .unwrap_or_else(default_exit_threshold);
 
-        let stop_loss = config
-            .get("stop_loss")
-            .map(|s| {
-                s.parse::<f64>()
-                    .map_err(|e| format!("손절 수준 파싱 오류: {e}"))
-            })
-            .transpose()?
+        let stop_loss = config_utils::parse_f64(config, "stop_loss", None, false)?
             .unwrap_or_else(default_stop_loss);
 
-        let take_profit = config
-            .get("take_profit")
-            .map(|s| {
-                s.parse::<f64>()
-                    .map_err(|e| format!("이익 실현 수준 파싱 오류: {e}"))
-            })
-            .transpose()?
+        let take_profit = config_utils::parse_f64(config, "take_profit", None, false)?
             .unwrap_or_else(default_take_profit);
 
         let strategy_config = HybridShortStrategyConfig {
@@ -202,55 +205,22 @@ impl<C: Candle + Clone + 'static> HybridShortStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<HybridShortStrategy<C>, String> {
+    ) -> StrategyResult<HybridShortStrategy<C>> {
         let config = HybridShortStrategyConfig::from_json(json_config)?;
-        info!("하이브리드 숏 전략 설정: {config:?}");
-        debug!("캔들 데이터 상태: 항목 수={}", storage.len());
-        let ctx = HybridAnalyzer::new(
-            &config.base.ma_type,
-            config.base.ma_period,
-            config.base.macd_fast_period,
-            config.base.macd_slow_period,
-            config.base.macd_signal_period,
-            config.base.rsi_period,
-            storage,
-        );
-
-        Ok(HybridShortStrategy {
-            config,
-            ctx,
-            cache: RefCell::new(SignalCache::default()),
-            last_signal_strength: RefCell::new(0.0),
-        })
+        Self::from_config(storage, config)
     }
 
     /// 새 하이브리드 숏 전략 인스턴스 생성 (설정 직접 제공)
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<HybridShortStrategy<C>, String> {
+    ) -> StrategyResult<HybridShortStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => HybridShortStrategyConfig::from_hash_map(&cfg)?,
             None => HybridShortStrategyConfig::default(),
         };
 
-        debug!("캔들 데이터 상태: 항목 수={}", storage.len());
-        let ctx = HybridAnalyzer::new(
-            &strategy_config.base.ma_type,
-            strategy_config.base.ma_period,
-            strategy_config.base.macd_fast_period,
-            strategy_config.base.macd_slow_period,
-            strategy_config.base.macd_signal_period,
-            strategy_config.base.rsi_period,
-            storage,
-        );
-
-        Ok(HybridShortStrategy {
-            config: strategy_config,
-            ctx,
-            cache: RefCell::new(SignalCache::default()),
-            last_signal_strength: RefCell::new(0.0),
-        })
+        Self::from_config(storage, strategy_config)
     }
 
     /// 캐시를 리셋하고 새로운 데이터에 대한 준비
@@ -274,70 +244,11 @@ impl<C: Candle + Clone + 'static> HybridShortStrategy<C> {
         }
         drop(cache);
 
-        let current = self
-            .ctx
-            .items
-            .first()
-            .expect("items.len() >= 2 체크 후이므로 항상 존재해야 함");
-        let previous = &self.ctx.items[1];
-        let config = &self.config.base;
-
-        let mut strength = 0.0;
-        let mut count = 0.0;
-
-        // 1. 이동평균선 기반 신호 (숏이므로 가격이 MA 아래에 있을 때 매도 신호)
-        if current.candle.close_price() < current.ma.get() {
-            strength += 1.0;
-            count += 1.0;
-        }
-
-        // 2. MACD 기반 신호 (숏이므로 반대로 해석)
-        if current.macd.histogram < 0.0 && previous.macd.histogram > 0.0 {
-            // MACD 히스토그램이 0선을 하향 돌파 (강한 매도 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if current.macd.histogram < 0.0 {
-            // MACD 히스토그램이 0선 아래에 있음 (약한 매도 신호)
-            strength += 0.5;
-            count += 1.0;
-        }
-
-        // 3. RSI 기반 신호 (숏이므로 반대로 해석)
-        let rsi = current.rsi.value();
-        if rsi > config.rsi_upper && rsi < previous.rsi.value() {
-            // RSI가 과매수 상태에서 하락 시작 (강한 매도 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if rsi > 50.0 && rsi < config.rsi_upper {
-            // RSI가 중간값 위이면서 과매수 상태는 아닌 경우 (약한 매도 신호)
-            strength += 0.5;
-            count += 1.0;
-        }
-
-        // 4. 추세 확인 (숏 전략에서는 하락 추세가 유리)
-        if items_len >= 5 {
-            let price_5_ago = self.ctx.items[4].candle.close_price();
-            let current_price = current.candle.close_price();
-
-            if current_price < price_5_ago {
-                // 5개 캔들 전보다 가격이 낮으면 하락 추세로 판단
-                strength += 0.5;
-                count += 0.5;
-            }
-        }
-
-        // 수익률이 이미 좋은 경우 신호 강화 (숏 전략에서는 음수 수익률이 좋은 것)
-        if profit_percentage > 5.0 {
-            strength += 0.3;
-            count += 0.3;
-        }
-
-        // 최종 강도 계산 (정규화)
-        let final_strength = if count > 0.0 {
-            strength / (count * 1.5) // 최대 강도를 기준으로 정규화
-        } else {
-            0.0
-        };
+        // 가중치 합의 1.5배로 정규화
+        let final_strength = signal_strength(
+            &self.explain_sell_signal_strength_optimized(profit_percentage),
+            1.5,
+        );
 
         // 캐시 업데이트
         let mut cache = self.cache.borrow_mut();
@@ -362,74 +273,152 @@ impl<C: Candle + Clone + 'static> HybridShortStrategy<C> {
         }
         drop(cache);
 
-        let current = self
-            .ctx
-            .items
-            .first()
-            .expect("items.len() >= 2 체크 후이므로 항상 존재해야 함");
-        let previous = &self.ctx.items[1];
-        let config = &self.config.base;
+        // 가중치 합의 1.5배로 정규화
+        let final_strength = signal_strength(&self.explain_buy_signal_strength_optimized(), 1.5);
 
-        let mut strength = 0.0;
-        let mut count = 0.0;
+        // 캐시 업데이트
+        let mut cache = self.cache.borrow_mut();
+        cache.set_buy_signal_strength(final_strength, items_len);
 
-        // 1. 이동평균선 기반 신호 (숏 청산이므로 가격이 MA 위로 갈 때)
-        if current.candle.close_price() > current.ma.get() {
-            strength += 1.0;
-            count += 1.0;
-        }
+        // 결과 반환
+        final_strength
+    }
 
-        // 2. MACD 기반 신호 (숏 청산)
-        if current.macd.histogram > 0.0 && previous.macd.histogram < 0.0 {
-            // MACD 히스토그램이 0선을 상향 돌파 (강한 매수 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if current.macd.histogram > 0.0 {
-            // MACD 히스토그램이 0선 위에 있음 (약한 매수 신호)
-            strength += 0.5;
-            count += 1.0;
+    /// 매도(숏 진입) 신호 강도를 구성하는 조건 (조건별 점수와 가중치 포함)
+    fn explain_sell_signal_strength_optimized(&self, profit_percentage: f64) -> Vec<ConditionNode> {
+        if self.ctx.items.len() < 2 {
+            return Vec::new();
         }
 
-        // 3. RSI 기반 신호 (숏 청산)
+        let current = &self.ctx.items[0];
+        let previous = &self.ctx.items[1];
+        let config = &self.config.base;
+
+        let close = current.candle.close_price();
+        let ma = current.ma.get();
+        let histogram = current.macd.histogram;
+        let previous_histogram = previous.macd.histogram;
         let rsi = current.rsi.value();
-        if rsi < config.rsi_lower && rsi > previous.rsi.value() {
-            // RSI가 과매도 상태에서 반등 (강한 매수 신호)
-            strength += 2.0;
-            count += 1.0;
-        } else if rsi < 50.0 && rsi > config.rsi_lower {
-            // RSI가 중간값 아래이면서 과매도 상태는 아닌 경우 (약한 매수 신호)
-            strength += 0.5;
-            count += 1.0;
-        }
 
-        // 4. 추세 확인 (숏 청산이므로 상승 추세가 신호가 됨)
-        if items_len >= 5 {
-            let price_5_ago = self.ctx.items[4].candle.close_price();
-            let current_price = current.candle.close_price();
+        vec![
+            ma_node("close_below_ma", close < ma, close, ma).scored(1.0, 1.0),
+            macd_histogram_node(
+                histogram,
+                previous_histogram,
+                vec![
+                    ConditionNode::new(
+                        "macd_histogram_cross_down",
+                        histogram < 0.0 && previous_histogram > 0.0,
+                    )
+                    .scored(2.0, 1.0),
+                    ConditionNode::new("macd_histogram_negative", histogram < 0.0).scored(0.5, 1.0),
+                ],
+            ),
+            rsi_branch_node(
+                current,
+                previous,
+                vec![
+                    ConditionNode::new(
+                        "rsi_overbought_reversal",
+                        rsi > config.rsi_upper && rsi < previous.rsi.value(),
+                    )
+                    .threshold("rsi_upper", config.rsi_upper)
+                    .scored(2.0, 1.0),
+                    ConditionNode::new(
+                        "rsi_approaching_overbought",
+                        rsi > 50.0 && rsi < config.rsi_upper,
+                    )
+                    .threshold("rsi_upper", config.rsi_upper)
+                    .threshold("rsi_mid", 50.0)
+                    .scored(0.5, 1.0),
+                ],
+            ),
+            trend_node(
+                &self.ctx,
+                "close_below_5_candles_ago",
+                |close, close_5_ago| close < close_5_ago,
+            ),
+            ConditionNode::new("profit_above_5pct", profit_percentage > 5.0)
+                .input("profit_percentage", profit_percentage)
+                .threshold("profit_percentage", 5.0)
+                .scored(0.3, 0.3),
+        ]
+    }
 
-            if current_price > price_5_ago {
-                // 5개 캔들 전보다 가격이 높으면 상승 추세로 판단
-                strength += 0.5;
-                count += 0.5;
-            }
+    /// 매수(숏 청산) 신호 강도를 구성하는 조건 (조건별 점수와 가중치 포함)
+    fn explain_buy_signal_strength_optimized(&self) -> Vec<ConditionNode> {
+        if self.ctx.items.len() < 2 {
+            return Vec::new();
         }
 
-        // 최종 강도 계산 (정규화)
-        let final_strength = if count > 0.0 {
-            strength / (count * 1.5) // 최대 강도를 기준으로 정규화
-        } else {
-            0.0
-        };
+        let current = &self.ctx.items[0];
+        let previous = &self.ctx.items[1];
+        let config = &self.config.base;
 
-        // 캐시 업데이트
-        let mut cache = self.cache.borrow_mut();
-        cache.set_buy_signal_strength(final_strength, items_len);
+        let close = current.candle.close_price();
+        let ma = current.ma.get();
+        let histogram = current.macd.histogram;
+        let previous_histogram = previous.macd.histogram;
+        let rsi = current.rsi.value();
 
-        // 결과 반환
-        final_strength
+        vec![
+            ma_node("close_above_ma", close > ma, close, ma).scored(1.0, 1.0),
+            macd_histogram_node(
+                histogram,
+                previous_histogram,
+                vec![
+                    ConditionNode::new(
+                        "macd_histogram_cross_up",
+                        histogram > 0.0 && previous_histogram < 0.0,
+                    )
+                    .scored(2.0, 1.0),
+                    ConditionNode::new("macd_histogram_positive", histogram > 0.0).scored(0.5, 1.0),
+                ],
+            ),
+            rsi_branch_node(
+                current,
+                previous,
+                vec![
+                    ConditionNode::new(
+                        "rsi_oversold_rebound",
+                        rsi < config.rsi_lower && rsi > previous.rsi.value(),
+                    )
+                    .threshold("rsi_lower", config.rsi_lower)
+                    .scored(2.0, 1.0),
+                    ConditionNode::new("rsi_recovering", rsi < 50.0 && rsi > config.rsi_lower)
+                        .threshold("rsi_lower", config.rsi_lower)
+                        .threshold("rsi_mid", 50.0)
+                        .scored(0.5, 1.0),
+                ],
+            ),
+            trend_node(
+                &self.ctx,
+                "close_above_5_candles_ago",
+                |close, close_5_ago| close > close_5_ago,
+            ),
+        ]
     }
 }
 
+/// 5개 캔들 전 종가 대비 추세 조건 생성 (캔들이 5개 미만이면 평가하지 않음)
+fn trend_node<C: Candle + Clone>(
+    ctx: &HybridAnalyzer<C>,
+    name: &str,
+    is_trend: fn(f64, f64) -> bool,
+) -> ConditionNode {
+    if ctx.items.len() < 5 {
+        return ConditionNode::new(name, false).scored(0.5, 0.5);
+    }
+
+    let close = ctx.items[0].candle.close_price();
+    let close_5_ago = ctx.items[4].candle.close_price();
+
+    ConditionNode::new(name, is_trend(close, close_5_ago))
+        .input("close", close)
+        .input("close_5_candles_ago", close_5_ago)
+        .scored(0.5, 0.5)
+}
+
 impl<C: Candle + Clone + 'static> HybridStrategyCommon<C> for HybridShortStrategy<C> {
     fn context(&self) -> &HybridAnalyzer<C> {
         &self.ctx
@@ -447,6 +436,42 @@ impl<C: Candle + Clone + 'static> HybridStrategyCommon<C> for HybridShortStrateg
     fn calculate_sell_signal_strength(&self, profit_percentage: f64) -> f64 {
         self.calculate_sell_signal_strength_optimized(profit_percentage)
     }
+
+    fn explain_buy_signal_strength(&self) -> Vec<ConditionNode> {
+        self.explain_buy_signal_strength_optimized()
+    }
+
+    fn explain_sell_signal_strength(&self, profit_percentage: f64) -> Vec<ConditionNode> {
+        self.explain_sell_signal_strength_optimized(profit_percentage)
+    }
+}
+
+impl<C: Candle + Clone + 'static> ConfigurableStrategy<C> for HybridShortStrategy<C> {
+    type Config = HybridShortStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: HybridShortStrategyConfig,
+    ) -> StrategyResult<HybridShortStrategy<C>> {
+        info!("하이브리드 숏 전략 설정: {config:?}");
+        debug!("캔들 데이터 상태: 항목 수={}", storage.len());
+        let ctx = HybridAnalyzer::new(
+            &config.base.ma_type,
+            config.base.ma_period,
+            config.base.macd_fast_period,
+            config.base.macd_slow_period,
+            config.base.macd_signal_period,
+            config.base.rsi_period,
+            storage,
+        );
+
+        Ok(HybridShortStrategy {
+            config,
+            ctx,
+            cache: RefCell::new(SignalCache::default()),
+            last_signal_strength: RefCell::new(0.0),
+        })
+    }
 }
 
 impl<C: Candle + Clone + 'static> Strategy<C> for HybridShortStrategy<C> {
@@ -491,4 +516,47 @@ impl<C: Candle + Clone + 'static> Strategy<C> for HybridShortStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::HybridShort
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        let enter = ConditionNode::new("sell_signal_strength", self.should_enter(current_price))
+            .rule(ConditionRule::Score)
+            .input(
+                "strength",
+                self.calculate_sell_signal_strength_optimized(0.0),
+            )
+            .threshold("entry_threshold", self.config.entry_threshold)
+            .children(self.explain_sell_signal_strength_optimized(0.0));
+
+        let exit = ConditionNode::new("buy_signal_strength", self.should_exit(current_price))
+            .rule(ConditionRule::Score)
+            .input("strength", self.calculate_buy_signal_strength_optimized())
+            .threshold("exit_threshold", self.config.exit_threshold)
+            .children(self.explain_buy_signal_strength_optimized());
+
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter,
+            exit,
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::HybridShort, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::HybridShort)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::HybridShort))?;
+        self.reset_cache();
+        Ok(())
+    }
 }
@@ -1,20 +1,28 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::config_utils;
+use super::explain::{ConditionNode, ConditionRule};
 use super::hybrid_common::{
     HybridAnalyzer, HybridStrategyCommon, HybridStrategyConfigBase, SignalCache,
 };
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::base::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 하이브리드 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct HybridStrategyConfig {
     #[serde(flatten)]
@@ -44,24 +52,35 @@ impl Default for HybridStrategyConfig {
     }
 }
 
-impl HybridStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for HybridStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         self.base.validate()?;
 
         if !self.entry_threshold.is_finite() || !(0.0..=1.0).contains(&self.entry_threshold) {
-            return Err("진입 신호 임계값은 0과 1 사이여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "entry_threshold",
+                self.entry_threshold,
+                "0 이상 1 이하",
+            )
+            .into());
         }
 
         if !self.exit_threshold.is_finite() || !(0.0..=1.0).contains(&self.exit_threshold) {
-            return Err("청산 신호 임계값은 0과 1 사이여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "exit_threshold",
+                self.exit_threshold,
+                "0 이상 1 이하",
+            )
+            .into());
         }
 
         Ok(())
     }
+}
 
+impl HybridStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<HybridStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<HybridStrategyConfig> {
         match HybridStrategyConfigBase::from_json::<HybridStrategyConfig>(json) {
             Ok(config) => {
                 config.validate()?;
@@ -72,25 +91,15 @@ impl HybridStrategyConfig {
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<HybridStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<HybridStrategyConfig> {
         let base_config = HybridStrategyConfigBase::from_hash_map(config)?;
 
-        let entry_threshold = config
-            .get("entry_threshold")
-            .map(|s| {
-                s.parse::<f64>()
-                    .map_err(|e| format!("진입 임계값 파싱 오류: {e}"))
-            })
-            .transpose()?
+        let entry_threshold = config_utils::parse_f64(config, "entry_threshold", None, false)?
             .unwrap_or_else(default_entry_threshold);
 
-        let exit_threshold = config
-            .get("exit_threshold")
-            .map(|s| {
-                s.parse::<f64>()
-                    .map_err(|e| format!("청산 임계값 파싱 오류: {e}"))
-            })
-            .transpose()?
+        let exit_threshold = config_utils::parse_f64(config, "exit_threshold", None, false)?
             .unwrap_or_else(default_exit_threshold);
 
         Ok(HybridStrategyConfig {
@@ -137,7 +146,7 @@ impl<C: Candle + Clone + 'static> HybridStrategy<C> {
     pub fn from_json(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<HybridStrategy<C>, String> {
+    ) -> StrategyResult<HybridStrategy<C>> {
         let config = HybridStrategyConfig::from_json(json_config)?;
         Self::new(storage, config)
     }
@@ -146,7 +155,7 @@ impl<C: Candle + Clone + 'static> HybridStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         config: HybridStrategyConfig,
-    ) -> Result<HybridStrategy<C>, String> {
+    ) -> StrategyResult<HybridStrategy<C>> {
         info!("하이브리드 전략 설정: {config:?}");
         let ctx = HybridAnalyzer::new(
             &config.base.ma_type,
@@ -169,7 +178,7 @@ impl<C: Candle + Clone + 'static> HybridStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<HybridStrategy<C>, String> {
+    ) -> StrategyResult<HybridStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => HybridStrategyConfig::from_hash_map(&cfg)?,
             None => HybridStrategyConfig::default(),
@@ -239,6 +248,17 @@ impl<C: Candle + Clone + 'static> HybridStrategy<C> {
     }
 }
 
+impl<C: Candle + Clone + 'static> ConfigurableStrategy<C> for HybridStrategy<C> {
+    type Config = HybridStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: HybridStrategyConfig,
+    ) -> StrategyResult<HybridStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + Clone + 'static> Strategy<C> for HybridStrategy<C> {
     fn next(&mut self, candle: C) {
         self.reset_cache();
@@ -266,6 +286,46 @@ impl<C: Candle + Clone + 'static> Strategy<C> for HybridStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::Hybrid
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        let enter = ConditionNode::new("buy_signal_strength", self.should_enter(current_price))
+            .rule(ConditionRule::Score)
+            .input("strength", self.calculate_buy_signal_strength_cached())
+            .threshold("entry_threshold", self.config.entry_threshold)
+            .children(self.explain_buy_signal_strength());
+
+        let exit = ConditionNode::new("sell_signal_strength", self.should_exit(current_price))
+            .rule(ConditionRule::Score)
+            .input("strength", self.calculate_sell_signal_strength_cached(0.0))
+            .threshold("exit_threshold", self.config.exit_threshold)
+            .children(self.explain_sell_signal_strength(0.0));
+
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter,
+            exit,
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::Hybrid, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::Hybrid)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::Hybrid))?;
+        self.reset_cache();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
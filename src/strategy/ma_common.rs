@@ -1,9 +1,11 @@
-use crate::analyzer::AnalyzerOps;
 use crate::indicator::ma::MAType;
 use crate::strategy::Strategy;
 use crate::strategy::config_utils;
-use crate::strategy::split_safe;
-use serde::Deserialize;
+use crate::strategy::error::check_ascending;
+use crate::strategy::explain::{ConditionNode, break_through_node};
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::HashMap;
 use trading_chart::Candle;
@@ -12,7 +14,7 @@ use trading_chart::Candle;
 pub use crate::analyzer::ma_analyzer::{MAAnalyzer, MAAnalyzerData};
 
 /// 이동평균(MA) 전략 공통 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct MAStrategyConfigBase {
     /// 이동평균 계산 방식 (SMA, EMA 등)
     pub ma: MAType,
@@ -22,35 +24,29 @@ pub struct MAStrategyConfigBase {
     pub cross_previous_periods: usize,
 }
 
-impl MAStrategyConfigBase {
-    /// 설정의 유효성을 검사합니다.
-    ///
-    /// 모든 설정 값이 유효한지 확인하고, 유효하지 않은 경우 오류 메시지를 반환합니다.
-    ///
-    /// # Returns
-    /// * `Result<(), String>` - 유효성 검사 결과 (성공 또는 오류 메시지)
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for MAStrategyConfigBase {
+    fn validate(&self) -> ConfigResult<()> {
         if self.ma_periods.is_empty() {
-            return Err("이동평균 기간이 지정되지 않았습니다".to_string());
+            return Err(StrategyError::out_of_range("ma_periods", "[]", "1개 이상의 값").into());
         }
 
         // 기간이 오름차순으로 정렬되어 있는지 확인
-        for i in 1..self.ma_periods.len() {
-            if self.ma_periods[i] <= self.ma_periods[i - 1] {
-                return Err(format!(
-                    "이동평균 기간은 오름차순으로 정렬되어야 합니다: {:?}",
-                    self.ma_periods
-                ));
-            }
-        }
+        check_ascending("ma_periods", &self.ma_periods)?;
 
         if self.cross_previous_periods == 0 {
-            return Err("크로스 판정 기간은 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "cross_previous_periods",
+                self.cross_previous_periods,
+                "1 이상",
+            )
+            .into());
         }
 
         Ok(())
     }
+}
 
+impl MAStrategyConfigBase {
     /// JSON 문자열에서 설정 로드
     ///
     /// JSON 문자열로부터 설정을 로드하고, 로드에 실패할 경우 오류를 반환합니다.
@@ -59,50 +55,31 @@ impl MAStrategyConfigBase {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<T, String>` - 로드된 설정 또는 오류
-    pub fn from_json<T>(json: &str) -> Result<T, String>
+    /// * `StrategyResult<T>` - 로드된 설정 또는 오류
+    pub fn from_json<T>(json: &str) -> StrategyResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
         match serde_json::from_str::<T>(json) {
             Ok(config) => Ok(config),
-            Err(e) => Err(format!("JSON 설정 역직렬화 실패: {e}")),
+            Err(e) => Err(ConfigError::ParseError(format!("JSON 설정 역직렬화 실패: {e}")).into()),
         }
     }
 
     /// HashMap에서 설정 로드
-    pub fn from_hash_map(config: &HashMap<String, String>) -> Result<MAStrategyConfigBase, String> {
+    pub fn from_hash_map(config: &HashMap<String, String>) -> StrategyResult<MAStrategyConfigBase> {
         // 공통 유틸리티를 사용하여 MA 타입 파싱
-        let ma = config_utils::parse_ma_type(config, None, true)?.ok_or("ma 설정이 필요합니다")?;
-
-        // 이동평균 기간 설정
-        let ma_periods = match config.get("ma_periods") {
-            Some(periods) => {
-                let periods_vec = split_safe::<usize>(periods)
-                    .map_err(|e| format!("이동평균 기간 파싱 오류: {e}"))?;
-
-                if periods_vec.is_empty() {
-                    return Err("이동평균 기간이 지정되지 않았습니다".to_string());
-                }
-
-                // 기간이 오름차순으로 정렬되어 있는지 확인
-                for i in 1..periods_vec.len() {
-                    if periods_vec[i] <= periods_vec[i - 1] {
-                        return Err(format!(
-                            "이동평균 기간은 오름차순으로 정렬되어야 합니다: {periods_vec:?}"
-                        ));
-                    }
-                }
-
-                periods_vec
-            }
-            None => return Err("ma_periods 설정이 필요합니다".to_string()),
-        };
+        let ma = config_utils::parse_ma_type(config, None, true)?
+            .ok_or_else(|| StrategyError::missing_key("ma"))?;
+
+        // 이동평균 기간 설정 (오름차순 여부는 validate에서 확인)
+        let ma_periods = config_utils::parse_list::<usize>(config, "ma_periods", true)?
+            .ok_or_else(|| StrategyError::missing_key("ma_periods"))?;
 
         // 크로스 판정 기간 설정
         let cross_previous_periods =
             config_utils::parse_usize(config, "cross_previous_periods", Some(1), true)?
-                .ok_or("cross_previous_periods 설정이 필요합니다")?;
+                .ok_or_else(|| StrategyError::missing_key("cross_previous_periods"))?;
 
         let result = MAStrategyConfigBase {
             ma,
@@ -123,16 +100,65 @@ pub trait MAStrategyCommon<C: Candle + 'static>: Strategy<C> {
     /// 설정 참조 반환
     fn config_cross_previous_periods(&self) -> usize;
 
-    /// 주어진 함수 조건에 따라 교차 여부 확인
-    fn check_cross_condition(
+    /// 교차 조건 (최신 캔들은 조건을 만족하고, 이전 `cross_previous_periods`개 캔들은 만족하지 않음)
+    fn explain_cross_condition(
         &self,
+        name: &str,
         condition_fn: impl Fn(&MAAnalyzerData<C>) -> bool + Copy,
-    ) -> bool {
-        self.context().is_break_through_by_satisfying(
+    ) -> ConditionNode {
+        let node = break_through_node(
+            self.context(),
+            name,
             condition_fn,
             1,
             self.config_cross_previous_periods(),
-            0,
-        )
+        );
+        match self.context().items.first() {
+            Some(data) => data.mas.keys().iter().fold(node, |node, period| {
+                node.input(format!("ma_{period}"), data.mas.get(period).get())
+            }),
+            None => node,
+        }
+    }
+
+    /// 정배열 골든 크로스 조건
+    fn explain_golden_cross(&self) -> ConditionNode {
+        self.explain_cross_condition("ma_regular_arrangement_golden_cross", |data| {
+            data.is_ma_regular_arrangement()
+        })
+    }
+
+    /// 역배열 데드 크로스 조건
+    fn explain_dead_cross(&self) -> ConditionNode {
+        self.explain_cross_condition("ma_reverse_arrangement_dead_cross", |data| {
+            data.is_ma_reverse_arrangement()
+        })
+    }
+
+    /// 단기 이동평균이 장기 이동평균 아래로 내려가는 교차 조건
+    fn explain_short_ma_below_long_ma(&self) -> ConditionNode {
+        self.explain_cross_condition("short_ma_below_long_ma", |data| {
+            compare_short_long_ma(data, |short_ma, long_ma| short_ma < long_ma)
+        })
+    }
+
+    /// 단기 이동평균이 장기 이동평균 위로 올라가는 교차 조건
+    fn explain_short_ma_above_long_ma(&self) -> ConditionNode {
+        self.explain_cross_condition("short_ma_above_long_ma", |data| {
+            compare_short_long_ma(data, |short_ma, long_ma| short_ma > long_ma)
+        })
+    }
+}
+
+/// 가장 짧은 기간과 가장 긴 기간의 이동평균 비교 (이동평균이 2개 미만이면 false)
+fn compare_short_long_ma<C: Candle>(
+    data: &MAAnalyzerData<C>,
+    compare: fn(f64, f64) -> bool,
+) -> bool {
+    if data.mas.len() < 2 {
+        return false;
     }
+    let short_ma = data.mas.get_by_key_index(0).get();
+    let long_ma = data.mas.get_by_key_index(data.mas.len() - 1).get();
+    compare(short_ma, long_ma)
 }
@@ -1,17 +1,23 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
 use crate::strategy::ma_common::{MAAnalyzer, MAStrategyCommon, MAStrategyConfigBase};
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 이동평균(MA) 숏 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct MAShortStrategyConfig {
     /// 이동평균 계산 방식 (SMA, EMA 등)
@@ -33,14 +39,8 @@ impl Default for MAShortStrategyConfig {
     }
 }
 
-impl MAShortStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    ///
-    /// 모든 설정 값이 유효한지 확인하고, 유효하지 않은 경우 오류 메시지를 반환합니다.
-    ///
-    /// # Returns
-    /// * `Result<(), String>` - 유효성 검사 결과 (성공 또는 오류 메시지)
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for MAShortStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         let base = MAStrategyConfigBase {
             ma: self.ma,
             ma_periods: self.ma_periods.clone(),
@@ -48,7 +48,9 @@ impl MAShortStrategyConfig {
         };
         base.validate()
     }
+}
 
+impl MAShortStrategyConfig {
     /// JSON 문자열에서 설정 로드
     ///
     /// JSON 문자열로부터 설정을 로드하고, 로드에 실패할 경우 오류를 반환합니다.
@@ -57,15 +59,17 @@ impl MAShortStrategyConfig {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<MAShortStrategyConfig, String>` - 로드된 설정 또는 오류
-    fn from_json(json: &str) -> Result<MAShortStrategyConfig, String> {
+    /// * `StrategyResult<MAShortStrategyConfig>` - 로드된 설정 또는 오류
+    fn from_json(json: &str) -> StrategyResult<MAShortStrategyConfig> {
         let config = MAStrategyConfigBase::from_json::<MAShortStrategyConfig>(json)?;
-        config.validate().map_err(|e| e.to_string())?;
+        config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<MAShortStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<MAShortStrategyConfig> {
         let base_config = MAStrategyConfigBase::from_hash_map(config)?;
 
         let result = MAShortStrategyConfig {
@@ -74,7 +78,7 @@ impl MAShortStrategyConfig {
             cross_previous_periods: base_config.cross_previous_periods,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -124,11 +128,11 @@ impl<C: Candle + 'static> MAShortStrategy<C> {
     /// * `json_config` - JSON 형식의 설정 문자열
     ///
     /// # Returns
-    /// * `Result<MAShortStrategy<C>, String>` - 초기화된 MA 숏 전략 인스턴스 또는 오류
+    /// * `StrategyResult<MAShortStrategy<C>>` - 초기화된 MA 숏 전략 인스턴스 또는 오류
     pub fn from_json(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<MAShortStrategy<C>, String> {
+    ) -> StrategyResult<MAShortStrategy<C>> {
         let config = MAShortStrategyConfig::from_json(json_config)?;
         Self::new(storage, config)
     }
@@ -140,11 +144,11 @@ impl<C: Candle + 'static> MAShortStrategy<C> {
     /// * `config` - 전략 설정
     ///
     /// # Returns
-    /// * `Result<MAShortStrategy<C>, String>` - 초기화된 MA 숏 전략 인스턴스 또는 오류
+    /// * `StrategyResult<MAShortStrategy<C>>` - 초기화된 MA 숏 전략 인스턴스 또는 오류
     pub fn new(
         storage: &CandleStore<C>,
         config: MAShortStrategyConfig,
-    ) -> Result<MAShortStrategy<C>, String> {
+    ) -> StrategyResult<MAShortStrategy<C>> {
         info!("MA 숏 전략 설정: {config:?}");
         let ctx = MAAnalyzer::new(&config.ma, &config.ma_periods, storage);
 
@@ -155,7 +159,7 @@ impl<C: Candle + 'static> MAShortStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<MAShortStrategy<C>, String> {
+    ) -> StrategyResult<MAShortStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => MAShortStrategyConfig::from_hash_map(&cfg)?,
             None => MAShortStrategyConfig::default(),
@@ -165,6 +169,17 @@ impl<C: Candle + 'static> MAShortStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for MAShortStrategy<C> {
+    type Config = MAShortStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: MAShortStrategyConfig,
+    ) -> StrategyResult<MAShortStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for MAShortStrategy<C> {
     fn next(&mut self, candle: C) {
         self.ctx.next(candle)
@@ -172,20 +187,12 @@ impl<C: Candle + 'static> Strategy<C> for MAShortStrategy<C> {
 
     fn should_enter(&self, _current_price: f64) -> bool {
         // 데드 크로스 발생시 숏 진입 신호
-        self.ctx
-            .is_ma_reverse_arrangement_dead_cross(1, self.config.cross_previous_periods, 0)
+        self.explain_dead_cross().result
     }
 
     fn should_exit(&self, _current_price: f64) -> bool {
         // 단기 이동평균이 장기 이동평균보다 높아질 때(골든 크로스) 숏 청산
-        self.check_cross_condition(|data| {
-            if data.mas.len() < 2 {
-                return false;
-            }
-            let short_ma = data.mas.get_by_key_index(0).get();
-            let long_ma = data.mas.get_by_key_index(data.mas.len() - 1).get();
-            short_ma > long_ma
-        })
+        self.explain_short_ma_above_long_ma().result
     }
 
     fn position(&self) -> PositionType {
@@ -195,4 +202,30 @@ impl<C: Candle + 'static> Strategy<C> for MAShortStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::MAShort
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_dead_cross(),
+            exit: self.explain_short_ma_above_long_ma(),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::MAShort, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::MAShort)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::MAShort))
+    }
 }
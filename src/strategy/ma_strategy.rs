@@ -1,19 +1,24 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::analyzer::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
 use crate::strategy::ma_common::{MAAnalyzer, MAStrategyCommon, MAStrategyConfigBase};
-use crate::{ConfigError, ConfigResult, ConfigValidation};
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 이동평균(MA) 전략 설정
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct MAStrategyConfig {
     /// 이동평균 계산 방식 (SMA, EMA 등)
@@ -37,43 +42,27 @@ impl Default for MAStrategyConfig {
 
 impl ConfigValidation for MAStrategyConfig {
     fn validate(&self) -> ConfigResult<()> {
-        // MA 전략 설정 유효성 검사 로직 구현
-        if self.ma_periods.is_empty() {
-            return Err(ConfigError::ValidationError(
-                "이동평균 기간이 지정되지 않았습니다".to_string(),
-            ));
-        }
-
-        // 기간이 오름차순으로 정렬되어 있는지 확인
-        for i in 1..self.ma_periods.len() {
-            if self.ma_periods[i] <= self.ma_periods[i - 1] {
-                return Err(ConfigError::ValidationError(format!(
-                    "이동평균 기간은 오름차순으로 정렬되어야 합니다: {:?}",
-                    self.ma_periods
-                )));
-            }
-        }
-
-        if self.cross_previous_periods == 0 {
-            return Err(ConfigError::ValidationError(
-                "크로스 판정 기간은 0보다 커야 합니다".to_string(),
-            ));
-        }
-
-        Ok(())
+        let base = MAStrategyConfigBase {
+            ma: self.ma,
+            ma_periods: self.ma_periods.clone(),
+            cross_previous_periods: self.cross_previous_periods,
+        };
+        base.validate()
     }
 }
 
 impl MAStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<MAStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<MAStrategyConfig> {
         let config = MAStrategyConfigBase::from_json::<MAStrategyConfig>(json)?;
-        config.validate().map_err(|e| e.to_string())?;
+        config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<MAStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<MAStrategyConfig> {
         let base_config = MAStrategyConfigBase::from_hash_map(config)?;
 
         let result = MAStrategyConfig {
@@ -82,7 +71,7 @@ impl MAStrategyConfig {
             cross_previous_periods: base_config.cross_previous_periods,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -132,8 +121,8 @@ impl<C: Candle + 'static> MAStrategy<C> {
     /// * `json_config` - JSON 형식의 설정 문자열
     ///
     /// # Returns
-    /// * `Result<MAStrategy<C>, String>` - 초기화된 MA 전략 인스턴스 또는 오류
-    pub fn from_json(storage: &CandleStore<C>, json_config: &str) -> Result<MAStrategy<C>, String> {
+    /// * `StrategyResult<MAStrategy<C>>` - 초기화된 MA 전략 인스턴스 또는 오류
+    pub fn from_json(storage: &CandleStore<C>, json_config: &str) -> StrategyResult<MAStrategy<C>> {
         let config = MAStrategyConfig::from_json(json_config)?;
         Self::new(storage, config)
     }
@@ -145,11 +134,11 @@ impl<C: Candle + 'static> MAStrategy<C> {
     /// * `config` - 전략 설정
     ///
     /// # Returns
-    /// * `Result<MAStrategy<C>, String>` - 초기화된 MA 전략 인스턴스
+    /// * `StrategyResult<MAStrategy<C>>` - 초기화된 MA 전략 인스턴스
     pub fn new(
         storage: &CandleStore<C>,
         config: MAStrategyConfig,
-    ) -> Result<MAStrategy<C>, String> {
+    ) -> StrategyResult<MAStrategy<C>> {
         info!("MA 전략 설정: {config:?}");
         let ctx = MAAnalyzer::new(&config.ma, &config.ma_periods, storage);
 
@@ -160,7 +149,7 @@ impl<C: Candle + 'static> MAStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<MAStrategy<C>, String> {
+    ) -> StrategyResult<MAStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => MAStrategyConfig::from_hash_map(&cfg)?,
             None => MAStrategyConfig::default(),
@@ -170,6 +159,17 @@ impl<C: Candle + 'static> MAStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for MAStrategy<C> {
+    type Config = MAStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: MAStrategyConfig,
+    ) -> StrategyResult<MAStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for MAStrategy<C> {
     fn next(&mut self, candle: C) {
         self.ctx.next(candle)
@@ -177,20 +177,12 @@ impl<C: Candle + 'static> Strategy<C> for MAStrategy<C> {
 
     fn should_enter(&self, _current_price: f64) -> bool {
         // 골든 크로스 발생시 롱 진입 신호
-        self.ctx
-            .is_ma_regular_arrangement_golden_cross(1, self.config.cross_previous_periods, 0)
+        self.explain_golden_cross().result
     }
 
     fn should_exit(&self, _current_price: f64) -> bool {
         // 단기 이동평균이 장기 이동평균보다 낮아질 때(데드 크로스) 롱 청산
-        self.check_cross_condition(|data| {
-            if data.mas.len() < 2 {
-                return false;
-            }
-            let short_ma = data.mas.get_by_key_index(0).get();
-            let long_ma = data.mas.get_by_key_index(data.mas.len() - 1).get();
-            short_ma < long_ma
-        })
+        self.explain_short_ma_below_long_ma().result
     }
 
     fn position(&self) -> PositionType {
@@ -200,4 +192,30 @@ impl<C: Candle + 'static> Strategy<C> for MAStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::MA
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_golden_cross(),
+            exit: self.explain_short_ma_below_long_ma(),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::MA, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::MA)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::MA))
+    }
 }
@@ -1,5 +1,7 @@
 use super::Strategy;
 use super::config_utils;
+use super::explain::{ConditionNode, ConditionRule};
+use super::{StrategyError, StrategyResult};
 use crate::{ConfigError, ConfigResult, ConfigValidation};
 use serde::Deserialize;
 use serde::Serialize;
@@ -11,7 +13,7 @@ use trading_chart::Candle;
 pub use crate::analyzer::macd_analyzer::{MACDAnalyzer, MACDAnalyzerData};
 
 /// MACD 전략 공통 설정
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct MACDStrategyConfigBase {
     /// 빠른 EMA 기간
     pub fast_period: usize,
@@ -28,28 +30,34 @@ pub struct MACDStrategyConfigBase {
 impl ConfigValidation for MACDStrategyConfigBase {
     fn validate(&self) -> ConfigResult<()> {
         if self.fast_period < 2 {
-            return Err(ConfigError::ValidationError(
-                "빠른 EMA 기간은 2 이상이어야 합니다".to_string(),
-            ));
+            return Err(
+                StrategyError::out_of_range("fast_period", self.fast_period, "2 이상").into(),
+            );
         }
 
         if self.slow_period <= self.fast_period {
-            return Err(ConfigError::ValidationError(format!(
-                "느린 EMA 기간({})은 빠른 EMA 기간({})보다 커야 합니다",
-                self.slow_period, self.fast_period
-            )));
+            return Err(StrategyError::invalid_period_order(
+                "fast_period",
+                self.fast_period,
+                "slow_period",
+                self.slow_period,
+            )
+            .into());
         }
 
         if self.signal_period < 1 {
-            return Err(ConfigError::ValidationError(
-                "시그널 EMA 기간은 1 이상이어야 합니다".to_string(),
-            ));
+            return Err(
+                StrategyError::out_of_range("signal_period", self.signal_period, "1 이상").into(),
+            );
         }
 
         if self.confirm_period < 1 {
-            return Err(ConfigError::ValidationError(
-                "신호 확인 기간은 1 이상이어야 합니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range(
+                "confirm_period",
+                self.confirm_period,
+                "1 이상",
+            )
+            .into());
         }
 
         Ok(())
@@ -65,14 +73,14 @@ impl MACDStrategyConfigBase {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<T, String>` - 로드된 설정 또는 오류
-    pub fn from_json<T>(json: &str) -> Result<T, String>
+    /// * `StrategyResult<T>` - 로드된 설정 또는 오류
+    pub fn from_json<T>(json: &str) -> StrategyResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
         match serde_json::from_str::<T>(json) {
             Ok(config) => Ok(config),
-            Err(e) => Err(format!("JSON 설정 역직렬화 실패: {e}")),
+            Err(e) => Err(ConfigError::ParseError(format!("JSON 설정 역직렬화 실패: {e}")).into()),
         }
     }
 
@@ -80,44 +88,51 @@ impl MACDStrategyConfigBase {
     pub fn from_hash_map(
         config: &HashMap<String, String>,
         is_long_strategy: bool,
-    ) -> Result<MACDStrategyConfigBase, String> {
+    ) -> StrategyResult<MACDStrategyConfigBase> {
         // 공통 유틸리티를 사용하여 설정 파싱
         let fast_period = config_utils::parse_usize(config, "fast_period", Some(2), true)?
-            .ok_or("fast_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("fast_period"))?;
 
         let slow_period = config_utils::parse_usize(config, "slow_period", Some(1), true)?
-            .ok_or("slow_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("slow_period"))?;
 
         if slow_period <= fast_period {
-            return Err(format!(
-                "느린 EMA 기간({slow_period})은 빠른 EMA 기간({fast_period})보다 커야 합니다"
+            return Err(StrategyError::invalid_period_order(
+                "fast_period",
+                fast_period,
+                "slow_period",
+                slow_period,
             ));
         }
 
         let signal_period = config_utils::parse_usize(config, "signal_period", Some(1), true)?
-            .ok_or("signal_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("signal_period"))?;
 
         // 히스토그램 임계값 설정
         let histogram_threshold =
             config_utils::parse_f64(config, "histogram_threshold", None, true)?
-                .ok_or("histogram_threshold 설정이 필요합니다")?;
+                .ok_or_else(|| StrategyError::missing_key("histogram_threshold"))?;
 
         // 롱 전략인 경우 임계값 검증
         if is_long_strategy && histogram_threshold < 0.0 {
-            return Err(format!(
-                "롱 전략의 히스토그램 임계값({histogram_threshold})은 0 이상이어야 합니다"
+            return Err(StrategyError::out_of_range(
+                "histogram_threshold",
+                histogram_threshold,
+                "롱 전략에서 0 이상",
             ));
         }
 
         // 숏 전략인 경우 임계값 검증
         if !is_long_strategy && histogram_threshold > 0.0 {
-            return Err(format!(
-                "숏 전략의 히스토그램 임계값({histogram_threshold})은 0보다 작아야 합니다"
+            return Err(StrategyError::out_of_range(
+                "histogram_threshold",
+                histogram_threshold,
+                "숏 전략에서 0 이하",
             ));
         }
 
         let confirm_period = config_utils::parse_usize(config, "confirm_period", Some(1), true)?
-            .ok_or("confirm_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("confirm_period"))?;
 
         let result = MACDStrategyConfigBase {
             fast_period,
@@ -127,7 +142,7 @@ impl MACDStrategyConfigBase {
             confirm_period,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -142,4 +157,65 @@ pub trait MACDStrategyCommon<C: Candle + 'static>: Strategy<C> {
 
     /// 설정의 histogram_threshold 반환
     fn config_histogram_threshold(&self) -> f64;
+
+    /// MACD 라인의 시그널 라인 교차 조건 (`above`가 true면 상향 돌파, false면 하향 돌파)
+    fn explain_macd_cross(&self, above: bool) -> ConditionNode {
+        let ctx = self.context();
+        let confirm_period = self.config_confirm_period();
+        let (name, result) = if above {
+            (
+                "macd_crossed_above_signal",
+                ctx.is_macd_crossed_above_signal(1, confirm_period),
+            )
+        } else {
+            (
+                "macd_crossed_below_signal",
+                ctx.is_macd_crossed_below_signal(1, confirm_period),
+            )
+        };
+
+        let node =
+            ConditionNode::new(name, result).threshold("confirm_period", confirm_period as f64);
+        match ctx.items.first() {
+            Some(data) => node
+                .input("macd_line", data.macd.macd_line)
+                .input("signal_line", data.macd.signal_line),
+            None => node,
+        }
+    }
+
+    /// 히스토그램 임계값 조건 (`above`가 true면 임계값 초과, false면 임계값 미만)
+    fn explain_histogram_threshold(&self, above: bool) -> ConditionNode {
+        let ctx = self.context();
+        let threshold = self.config_histogram_threshold();
+        let (name, result) = if above {
+            (
+                "histogram_above_threshold",
+                ctx.is_histogram_above_threshold(threshold, 1, 0),
+            )
+        } else {
+            (
+                "histogram_below_threshold",
+                ctx.is_histogram_below_threshold(threshold, 1, 0),
+            )
+        };
+
+        let node = ConditionNode::new(name, result).threshold("histogram_threshold", threshold);
+        match ctx.items.first() {
+            Some(data) => node.input("histogram", data.macd.histogram),
+            None => node,
+        }
+    }
+
+    /// 진입 조건 (교차와 히스토그램 임계값을 모두 만족)
+    fn explain_macd_entry(&self, above: bool) -> ConditionNode {
+        ConditionNode::group(
+            "enter",
+            ConditionRule::All,
+            vec![
+                self.explain_macd_cross(above),
+                self.explain_histogram_threshold(above),
+            ],
+        )
+    }
 }
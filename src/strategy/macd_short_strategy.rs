@@ -1,11 +1,16 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
-use crate::{ConfigError, ConfigResult, ConfigValidation};
+use crate::strategy::{StrategyError, StrategyResult};
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
@@ -15,7 +20,7 @@ use super::macd_common::{MACDAnalyzer, MACDStrategyCommon, MACDStrategyConfigBas
 use crate::analyzer::base::AnalyzerOps;
 
 /// MACD 숏 전략 설정
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct MACDShortStrategyConfig {
     /// 빠른 EMA 기간
@@ -55,10 +60,12 @@ impl ConfigValidation for MACDShortStrategyConfig {
 
         // 숏 전략에서는 히스토그램 임계값이 0보다 작아야 함을 추가 검증
         if self.histogram_threshold > 0.0 {
-            return Err(ConfigError::ValidationError(format!(
-                "숏 전략의 히스토그램 임계값({})은 0보다 작아야 합니다",
-                self.histogram_threshold
-            )));
+            return Err(StrategyError::out_of_range(
+                "histogram_threshold",
+                self.histogram_threshold,
+                "숏 전략에서 0 이하",
+            )
+            .into());
         }
 
         base.validate()
@@ -67,14 +74,16 @@ impl ConfigValidation for MACDShortStrategyConfig {
 
 impl MACDShortStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<MACDShortStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<MACDShortStrategyConfig> {
         let config = MACDStrategyConfigBase::from_json::<MACDShortStrategyConfig>(json)?;
-        config.validate().map_err(|e| e.to_string())?;
+        config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<MACDShortStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<MACDShortStrategyConfig> {
         let base_config = MACDStrategyConfigBase::from_hash_map(config, false)?;
 
         let result = MACDShortStrategyConfig {
@@ -85,7 +94,7 @@ impl MACDShortStrategyConfig {
             confirm_period: base_config.confirm_period,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -118,43 +127,42 @@ impl<C: Candle + 'static> MACDShortStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<MACDShortStrategy<C>, String> {
+    ) -> StrategyResult<MACDShortStrategy<C>> {
         let config = MACDShortStrategyConfig::from_json(json_config)?;
-        info!("MACD 숏 전략 설정: {config:?}");
-
-        let ctx = MACDAnalyzer::new(
-            config.fast_period,
-            config.slow_period,
-            config.signal_period,
-            storage,
-        );
-
-        Ok(MACDShortStrategy { config, ctx })
+        Self::from_config(storage, config)
     }
 
     /// 새 MACD 숏 전략 인스턴스 생성 (설정 직접 제공)
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<MACDShortStrategy<C>, String> {
+    ) -> StrategyResult<MACDShortStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => MACDShortStrategyConfig::from_hash_map(&cfg)?,
             None => MACDShortStrategyConfig::default(),
         };
 
-        info!("MACD 숏 전략 설정: {strategy_config:?}");
+        Self::from_config(storage, strategy_config)
+    }
+}
+
+impl<C: Candle + 'static> ConfigurableStrategy<C> for MACDShortStrategy<C> {
+    type Config = MACDShortStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: MACDShortStrategyConfig,
+    ) -> StrategyResult<MACDShortStrategy<C>> {
+        info!("MACD 숏 전략 설정: {config:?}");
 
         let ctx = MACDAnalyzer::new(
-            strategy_config.fast_period,
-            strategy_config.slow_period,
-            strategy_config.signal_period,
+            config.fast_period,
+            config.slow_period,
+            config.signal_period,
             storage,
         );
 
-        Ok(MACDShortStrategy {
-            config: strategy_config,
-            ctx,
-        })
+        Ok(MACDShortStrategy { config, ctx })
     }
 }
 
@@ -179,17 +187,12 @@ impl<C: Candle + 'static> Strategy<C> for MACDShortStrategy<C> {
 
     fn should_enter(&self, _current_price: f64) -> bool {
         // MACD가 시그널 라인을 하향 돌파하고 히스토그램이 임계값보다 작으면 숏 진입 신호
-        self.ctx
-            .is_macd_crossed_below_signal(1, self.config.confirm_period)
-            && self
-                .ctx
-                .is_histogram_below_threshold(self.config.histogram_threshold, 1, 0)
+        self.explain_macd_entry(false).result
     }
 
     fn should_exit(&self, _current_price: f64) -> bool {
         // MACD가 시그널 라인을 상향 돌파하면 숏 청산 신호
-        self.ctx
-            .is_macd_crossed_above_signal(1, self.config.confirm_period)
+        self.explain_macd_cross(true).result
     }
 
     fn position(&self) -> PositionType {
@@ -199,4 +202,30 @@ impl<C: Candle + 'static> Strategy<C> for MACDShortStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::MACDShort
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_macd_entry(false),
+            exit: self.explain_macd_cross(true),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::MACDShort, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::MACDShort)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::MACDShort))
+    }
 }
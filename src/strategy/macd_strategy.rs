@@ -1,11 +1,16 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
 use crate::{ConfigResult, ConfigValidation};
 use log::info;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
@@ -15,7 +20,7 @@ use super::macd_common::{MACDAnalyzer, MACDStrategyCommon, MACDStrategyConfigBas
 use crate::analyzer::base::AnalyzerOps;
 
 /// MACD 전략 설정
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct MACDStrategyConfig {
     /// 빠른 EMA 기간
@@ -59,14 +64,16 @@ impl ConfigValidation for MACDStrategyConfig {
 
 impl MACDStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<MACDStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<MACDStrategyConfig> {
         let config = MACDStrategyConfigBase::from_json::<MACDStrategyConfig>(json)?;
-        config.validate().map_err(|e| e.to_string())?;
+        config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<MACDStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<MACDStrategyConfig> {
         let base_config = MACDStrategyConfigBase::from_hash_map(config, true)?;
 
         let result = MACDStrategyConfig {
@@ -77,7 +84,7 @@ impl MACDStrategyConfig {
             confirm_period: base_config.confirm_period,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -109,7 +116,7 @@ impl<C: Candle + 'static> MACDStrategy<C> {
     pub fn from_json(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<MACDStrategy<C>, String> {
+    ) -> StrategyResult<MACDStrategy<C>> {
         let config = MACDStrategyConfig::from_json(json_config)?;
         Self::new(storage, config)
     }
@@ -118,7 +125,7 @@ impl<C: Candle + 'static> MACDStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         config: MACDStrategyConfig,
-    ) -> Result<MACDStrategy<C>, String> {
+    ) -> StrategyResult<MACDStrategy<C>> {
         info!("MACD 전략 설정: {config:?}");
 
         let ctx = MACDAnalyzer::new(
@@ -135,7 +142,7 @@ impl<C: Candle + 'static> MACDStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<MACDStrategy<C>, String> {
+    ) -> StrategyResult<MACDStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => MACDStrategyConfig::from_hash_map(&cfg)?,
             None => MACDStrategyConfig::default(),
@@ -159,6 +166,17 @@ impl<C: Candle + 'static> MACDStrategyCommon<C> for MACDStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for MACDStrategy<C> {
+    type Config = MACDStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: MACDStrategyConfig,
+    ) -> StrategyResult<MACDStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for MACDStrategy<C> {
     fn next(&mut self, candle: C) {
         self.ctx.next(candle)
@@ -166,17 +184,12 @@ impl<C: Candle + 'static> Strategy<C> for MACDStrategy<C> {
 
     fn should_enter(&self, _current_price: f64) -> bool {
         // MACD가 시그널 라인을 상향 돌파하고 히스토그램이 임계값보다 크면 매수 신호
-        self.ctx
-            .is_macd_crossed_above_signal(1, self.config.confirm_period)
-            && self
-                .ctx
-                .is_histogram_above_threshold(self.config.histogram_threshold, 1, 0)
+        self.explain_macd_entry(true).result
     }
 
     fn should_exit(&self, _current_price: f64) -> bool {
         // MACD가 시그널 라인을 하향 돌파하면 매도 신호
-        self.ctx
-            .is_macd_crossed_below_signal(1, self.config.confirm_period)
+        self.explain_macd_cross(false).result
     }
 
     fn position(&self) -> PositionType {
@@ -186,4 +199,30 @@ impl<C: Candle + 'static> Strategy<C> for MACDStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::MACD
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_macd_entry(true),
+            exit: self.explain_macd_cross(false),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::MACD, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::MACD)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::MACD))
+    }
 }
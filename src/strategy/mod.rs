@@ -1,10 +1,13 @@
 pub mod bband_common;
 pub mod bband_short_strategy;
 pub mod bband_strategy;
+mod config_loader;
 pub mod copys_common;
 pub mod copys_short_strategy;
 pub mod copys_strategy;
 pub mod dummy_strategy;
+pub mod error;
+pub mod explain;
 pub mod hybrid_common;
 pub mod hybrid_short_strategy;
 pub mod hybrid_strategy;
@@ -18,6 +21,7 @@ pub mod multi_timeframe_strategy;
 pub mod rsi_common;
 pub mod rsi_short_strategy;
 pub mod rsi_strategy;
+pub mod snapshot;
 pub mod three_rsi_common;
 pub mod three_rsi_short_strategy;
 pub mod three_rsi_strategy;
@@ -25,11 +29,17 @@ pub mod three_rsi_strategy;
 #[cfg(test)]
 mod tests;
 
+use crate::ConfigValidation;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
 pub use crate::{ConfigError, ConfigResult};
+pub use error::{StrategyError, StrategyResult};
+pub use explain::{ConditionNode, ConditionRule, SignalExplanation};
 use log::{debug, error, info, warn};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+pub use snapshot::StrategySnapshot;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -99,6 +109,32 @@ impl Display for StrategyType {
     }
 }
 
+impl FromStr for StrategyType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dummy" => Ok(StrategyType::Dummy),
+            "ma" => Ok(StrategyType::MA),
+            "ma_short" => Ok(StrategyType::MAShort),
+            "rsi" => Ok(StrategyType::RSI),
+            "rsi_short" => Ok(StrategyType::RSIShort),
+            "bband" => Ok(StrategyType::BBand),
+            "bband_short" => Ok(StrategyType::BBandShort),
+            "macd" => Ok(StrategyType::MACD),
+            "macd_short" => Ok(StrategyType::MACDShort),
+            "copys" => Ok(StrategyType::Copys),
+            "copys_short" => Ok(StrategyType::CopysShort),
+            "three_rsi" => Ok(StrategyType::ThreeRSI),
+            "three_rsi_short" => Ok(StrategyType::ThreeRSIShort),
+            "hybrid" => Ok(StrategyType::Hybrid),
+            "hybrid_short" => Ok(StrategyType::HybridShort),
+            "multi_timeframe" => Ok(StrategyType::MultiTimeframe),
+            _ => Err(format!("알 수 없는 전략 유형: {s}")),
+        }
+    }
+}
+
 /// 거래 전략 인터페이스
 ///
 /// 모든 거래 전략은 이 트레이트를 구현해야 합니다.
@@ -138,6 +174,450 @@ pub trait Strategy<C: Candle>: Display + Send {
     /// # Returns
     /// * `StrategyType` - 전략의 타입
     fn name(&self) -> StrategyType;
+
+    /// 진입/청산 판단 근거 설명
+    ///
+    /// 기본 구현은 최종 판단 결과만 담으며, 세부 조건을 제공하는 전략은 재정의합니다.
+    ///
+    /// # Arguments
+    /// * `current_price` - 현재 가격
+    ///
+    /// # Returns
+    /// * `SignalExplanation` - 평가된 조건 트리
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: ConditionNode::new("enter", self.should_enter(current_price)),
+            exit: ConditionNode::new("exit", self.should_exit(current_price)),
+        }
+    }
+
+    /// 전략 상태 저장
+    ///
+    /// 지표 빌더의 증분 계산 상태(RSI 평균 게인/로스, EMA 값 등)와 분석기 히스토리를 담으므로,
+    /// 복원한 전략은 캔들 저장소를 다시 재생하지 않고도 같은 지표 값을 이어서 계산합니다.
+    /// 상태 저장을 지원하지 않는 전략은 에러를 반환합니다.
+    ///
+    /// # Returns
+    /// * `StrategyResult<StrategySnapshot>` - 직렬화 가능한 전략 상태 또는 에러
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        Err(StrategyError::snapshot_failure(
+            self.name(),
+            "상태 저장을 지원하지 않는 전략입니다",
+        ))
+    }
+
+    /// 저장한 상태로 전략 복원
+    ///
+    /// 스냅샷을 만든 전략과 같은 설정으로 생성한 전략에 적용해야 합니다.
+    /// 에러가 발생하면 상태가 일부만 복원되었을 수 있으므로 전략을 다시 생성해야 합니다.
+    ///
+    /// # Arguments
+    /// * `snapshot` - `snapshot`으로 저장한 전략 상태
+    fn restore(&mut self, _snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        Err(StrategyError::snapshot_failure(
+            self.name(),
+            "상태 복원을 지원하지 않는 전략입니다",
+        ))
+    }
+}
+
+/// 타입이 지정된 설정 구조체로 생성할 수 있는 전략
+///
+/// HashMap 설정 대신 `*StrategyConfig` 구조체를 직접 받아 전략을 생성합니다.
+pub trait ConfigurableStrategy<C: Candle + 'static>: Strategy<C> + Sized + 'static {
+    /// 전략 설정 타입
+    type Config: Serialize + DeserializeOwned + Default + ConfigValidation + Debug;
+
+    /// 설정 구조체로 전략 인스턴스 생성
+    ///
+    /// # Arguments
+    /// * `storage` - 캔들 데이터 저장소
+    /// * `config` - 전략 설정
+    ///
+    /// # Returns
+    /// * `StrategyResult<Self>` - 생성된 전략 인스턴스 또는 에러
+    fn from_config(storage: &CandleStore<C>, config: Self::Config) -> StrategyResult<Self>;
+}
+
+/// 타입이 지정된 전략 설정
+///
+/// 전략 유형별 설정 구조체를 감싸는 열거형으로, `type` 키로 전략 유형을 구분합니다.
+/// 직렬화/역직렬화가 가능하므로 설정을 그대로 저장하고 비교할 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum StrategyConfig {
+    /// 더미 전략 (테스트 용도)
+    #[serde(rename = "dummy")]
+    Dummy(dummy_strategy::DummyStrategyConfig),
+    /// 이동평균선(Moving Average) 기반 롱 전략
+    #[serde(rename = "ma")]
+    MA(ma_strategy::MAStrategyConfig),
+    /// 이동평균선(Moving Average) 기반 숏 전략
+    #[serde(rename = "ma_short")]
+    MAShort(ma_short_strategy::MAShortStrategyConfig),
+    /// 상대강도지수(Relative Strength Index) 기반 롱 전략
+    #[serde(rename = "rsi")]
+    RSI(rsi_strategy::RSIStrategyConfig),
+    /// 상대강도지수(Relative Strength Index) 기반 숏 전략
+    #[serde(rename = "rsi_short")]
+    RSIShort(rsi_short_strategy::RSIShortStrategyConfig),
+    /// 볼린저밴드(Bollinger Band) 기반 롱 전략
+    #[serde(rename = "bband")]
+    BBand(bband_strategy::BBandStrategyConfig),
+    /// 볼린저밴드(Bollinger Band) 기반 숏 전략
+    #[serde(rename = "bband_short")]
+    BBandShort(bband_short_strategy::BBandShortStrategyConfig),
+    /// MACD 기반 롱 전략
+    #[serde(rename = "macd")]
+    MACD(macd_strategy::MACDStrategyConfig),
+    /// MACD 기반 숏 전략
+    #[serde(rename = "macd_short")]
+    MACDShort(macd_short_strategy::MACDShortStrategyConfig),
+    /// Copys 전략 (커스텀 롱 전략)
+    #[serde(rename = "copys")]
+    Copys(copys_strategy::CopysStrategyConfig),
+    /// Copys 숏 전략 (커스텀 숏 전략)
+    #[serde(rename = "copys_short")]
+    CopysShort(copys_short_strategy::CopysShortStrategyConfig),
+    /// 3개의 RSI 지표를 조합한 롱 전략
+    #[serde(rename = "three_rsi")]
+    ThreeRSI(three_rsi_strategy::ThreeRSIStrategyConfig),
+    /// 3개의 RSI 지표를 조합한 숏 전략
+    #[serde(rename = "three_rsi_short")]
+    ThreeRSIShort(three_rsi_short_strategy::ThreeRSIShortStrategyConfig),
+    /// 여러 지표를 결합한 하이브리드 전략
+    #[serde(rename = "hybrid")]
+    Hybrid(hybrid_strategy::HybridStrategyConfig),
+    /// 여러 지표를 결합한 하이브리드 숏 전략
+    #[serde(rename = "hybrid_short")]
+    HybridShort(hybrid_short_strategy::HybridShortStrategyConfig),
+    /// 여러 타임프레임을 분석하는 전략
+    #[serde(rename = "multi_timeframe")]
+    MultiTimeframe(multi_timeframe_strategy::MultiTimeframeStrategyConfig),
+}
+
+impl StrategyConfig {
+    /// 설정에 해당하는 전략 유형
+    pub fn strategy_type(&self) -> StrategyType {
+        match self {
+            StrategyConfig::Dummy(_) => StrategyType::Dummy,
+            StrategyConfig::MA(_) => StrategyType::MA,
+            StrategyConfig::MAShort(_) => StrategyType::MAShort,
+            StrategyConfig::RSI(_) => StrategyType::RSI,
+            StrategyConfig::RSIShort(_) => StrategyType::RSIShort,
+            StrategyConfig::BBand(_) => StrategyType::BBand,
+            StrategyConfig::BBandShort(_) => StrategyType::BBandShort,
+            StrategyConfig::MACD(_) => StrategyType::MACD,
+            StrategyConfig::MACDShort(_) => StrategyType::MACDShort,
+            StrategyConfig::Copys(_) => StrategyType::Copys,
+            StrategyConfig::CopysShort(_) => StrategyType::CopysShort,
+            StrategyConfig::ThreeRSI(_) => StrategyType::ThreeRSI,
+            StrategyConfig::ThreeRSIShort(_) => StrategyType::ThreeRSIShort,
+            StrategyConfig::Hybrid(_) => StrategyType::Hybrid,
+            StrategyConfig::HybridShort(_) => StrategyType::HybridShort,
+            StrategyConfig::MultiTimeframe(_) => StrategyType::MultiTimeframe,
+        }
+    }
+
+    /// 전략 유형의 기본 설정
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 전략 유형
+    ///
+    /// # Returns
+    /// * `StrategyConfig` - 기본값으로 채워진 설정
+    pub fn default_for(strategy_type: StrategyType) -> StrategyConfig {
+        match strategy_type {
+            StrategyType::Dummy => {
+                StrategyConfig::Dummy(dummy_strategy::DummyStrategyConfig::default())
+            }
+            StrategyType::MA => StrategyConfig::MA(ma_strategy::MAStrategyConfig::default()),
+            StrategyType::MAShort => {
+                StrategyConfig::MAShort(ma_short_strategy::MAShortStrategyConfig::default())
+            }
+            StrategyType::RSI => StrategyConfig::RSI(rsi_strategy::RSIStrategyConfig::default()),
+            StrategyType::RSIShort => {
+                StrategyConfig::RSIShort(rsi_short_strategy::RSIShortStrategyConfig::default())
+            }
+            StrategyType::BBand => {
+                StrategyConfig::BBand(bband_strategy::BBandStrategyConfig::default())
+            }
+            StrategyType::BBandShort => {
+                StrategyConfig::BBandShort(bband_short_strategy::BBandShortStrategyConfig::default())
+            }
+            StrategyType::MACD => {
+                StrategyConfig::MACD(macd_strategy::MACDStrategyConfig::default())
+            }
+            StrategyType::MACDShort => {
+                StrategyConfig::MACDShort(macd_short_strategy::MACDShortStrategyConfig::default())
+            }
+            StrategyType::Copys => {
+                StrategyConfig::Copys(copys_strategy::CopysStrategyConfig::default())
+            }
+            StrategyType::CopysShort => {
+                StrategyConfig::CopysShort(copys_short_strategy::CopysShortStrategyConfig::default())
+            }
+            StrategyType::ThreeRSI => {
+                StrategyConfig::ThreeRSI(three_rsi_strategy::ThreeRSIStrategyConfig::default())
+            }
+            StrategyType::ThreeRSIShort => StrategyConfig::ThreeRSIShort(
+                three_rsi_short_strategy::ThreeRSIShortStrategyConfig::default(),
+            ),
+            StrategyType::Hybrid => {
+                StrategyConfig::Hybrid(hybrid_strategy::HybridStrategyConfig::default())
+            }
+            StrategyType::HybridShort => StrategyConfig::HybridShort(
+                hybrid_short_strategy::HybridShortStrategyConfig::default(),
+            ),
+            StrategyType::MultiTimeframe => StrategyConfig::MultiTimeframe(
+                multi_timeframe_strategy::MultiTimeframeStrategyConfig::default(),
+            ),
+        }
+    }
+
+    /// HashMap 설정을 타입이 지정된 설정으로 변환
+    ///
+    /// 설정이 없으면 기본 설정을 사용합니다. 멀티 타임프레임 전략은 타임프레임 설정이
+    /// 필수이므로 설정이 없으면 에러를 반환합니다.
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 전략 유형
+    /// * `config` - 선택적 설정 (HashMap 형태)
+    ///
+    /// # Returns
+    /// * `StrategyResult<StrategyConfig>` - 변환된 설정 또는 에러
+    pub fn from_hash_map(
+        strategy_type: StrategyType,
+        config: Option<&HashMap<String, String>>,
+    ) -> StrategyResult<StrategyConfig> {
+        let Some(config) = config else {
+            if strategy_type == StrategyType::MultiTimeframe {
+                return Err(StrategyError::missing_key("timeframes"));
+            }
+            return Ok(Self::default_for(strategy_type));
+        };
+
+        let result = match strategy_type {
+            StrategyType::Dummy => {
+                StrategyConfig::Dummy(dummy_strategy::DummyStrategyConfig::from_hash_map(config)?)
+            }
+            StrategyType::MA => {
+                StrategyConfig::MA(ma_strategy::MAStrategyConfig::from_hash_map(config)?)
+            }
+            StrategyType::MAShort => StrategyConfig::MAShort(
+                ma_short_strategy::MAShortStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::RSI => {
+                StrategyConfig::RSI(rsi_strategy::RSIStrategyConfig::from_hash_map(config)?)
+            }
+            StrategyType::RSIShort => StrategyConfig::RSIShort(
+                rsi_short_strategy::RSIShortStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::BBand => {
+                StrategyConfig::BBand(bband_strategy::BBandStrategyConfig::from_hash_map(config)?)
+            }
+            StrategyType::BBandShort => StrategyConfig::BBandShort(
+                bband_short_strategy::BBandShortStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::MACD => {
+                StrategyConfig::MACD(macd_strategy::MACDStrategyConfig::from_hash_map(config)?)
+            }
+            StrategyType::MACDShort => StrategyConfig::MACDShort(
+                macd_short_strategy::MACDShortStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::Copys => {
+                StrategyConfig::Copys(copys_strategy::CopysStrategyConfig::from_hash_map(config)?)
+            }
+            StrategyType::CopysShort => StrategyConfig::CopysShort(
+                copys_short_strategy::CopysShortStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::ThreeRSI => StrategyConfig::ThreeRSI(
+                three_rsi_strategy::ThreeRSIStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::ThreeRSIShort => StrategyConfig::ThreeRSIShort(
+                three_rsi_short_strategy::ThreeRSIShortStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::Hybrid => StrategyConfig::Hybrid(
+                hybrid_strategy::HybridStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::HybridShort => StrategyConfig::HybridShort(
+                hybrid_short_strategy::HybridShortStrategyConfig::from_hash_map(config)?,
+            ),
+            StrategyType::MultiTimeframe => StrategyConfig::MultiTimeframe(
+                multi_timeframe_strategy::MultiTimeframeStrategyConfig::from_hash_map(config)?,
+            ),
+        };
+
+        Ok(result)
+    }
+
+    /// 설정에서 가장 긴 지표 기간과 해당 설정 키
+    ///
+    /// 목록 설정은 `ma_periods[2]`처럼 인덱스를 붙인 키를 반환합니다.
+    /// 멀티 타임프레임 전략은 기본 전략의 기간을 따르며, 더미 전략은 None을 반환합니다.
+    pub fn longest_period(&self) -> Option<(String, usize)> {
+        fn indexed(key: &str, periods: &[usize]) -> Vec<(String, usize)> {
+            periods
+                .iter()
+                .enumerate()
+                .map(|(i, period)| (format!("{key}[{i}]"), *period))
+                .collect()
+        }
+
+        let candidates: Vec<(String, usize)> = match self {
+            StrategyConfig::Dummy(_) => vec![],
+            StrategyConfig::MA(config) => indexed("ma_periods", &config.ma_periods),
+            StrategyConfig::MAShort(config) => indexed("ma_periods", &config.ma_periods),
+            StrategyConfig::RSI(config) => {
+                let mut candidates = indexed("ma_periods", &config.ma_periods);
+                candidates.push(("rsi_period".to_string(), config.rsi_period));
+                candidates
+            }
+            StrategyConfig::RSIShort(config) => {
+                let mut candidates = indexed("ma_periods", &config.ma_periods);
+                candidates.push(("rsi_period".to_string(), config.rsi_period));
+                candidates
+            }
+            StrategyConfig::BBand(config) => vec![("period".to_string(), config.period)],
+            StrategyConfig::BBandShort(config) => vec![("period".to_string(), config.period)],
+            StrategyConfig::MACD(config) => vec![("slow_period".to_string(), config.slow_period)],
+            StrategyConfig::MACDShort(config) => {
+                vec![("slow_period".to_string(), config.slow_period)]
+            }
+            StrategyConfig::Copys(config) => vec![
+                ("rsi_period".to_string(), config.base.rsi_period),
+                ("bband_period".to_string(), config.base.bband_period),
+            ],
+            StrategyConfig::CopysShort(config) => vec![
+                ("rsi_period".to_string(), config.base.rsi_period),
+                ("bband_period".to_string(), config.base.bband_period),
+            ],
+            StrategyConfig::ThreeRSI(config) => {
+                let mut candidates = indexed("rsi_periods", &config.base.rsi_periods);
+                candidates.push(("ma_period".to_string(), config.base.ma_period));
+                candidates.push(("adx_period".to_string(), config.base.adx_period));
+                candidates
+            }
+            StrategyConfig::ThreeRSIShort(config) => {
+                let mut candidates = indexed("rsi_periods", &config.base.rsi_periods);
+                candidates.push(("ma_period".to_string(), config.base.ma_period));
+                candidates.push(("adx_period".to_string(), config.base.adx_period));
+                candidates
+            }
+            StrategyConfig::Hybrid(config) => hybrid_common::period_candidates(&config.base),
+            StrategyConfig::HybridShort(config) => hybrid_common::period_candidates(&config.base),
+            StrategyConfig::MultiTimeframe(config) => {
+                return config.base_strategy.longest_period();
+            }
+        };
+
+        candidates.into_iter().max_by_key(|(_, period)| *period)
+    }
+
+    /// 캔들 수가 설정된 지표 기간을 계산하기에 충분한지 확인
+    ///
+    /// 전략 생성 자체는 캔들 수와 관계없이 가능하므로, 백테스트나 설정 화면처럼
+    /// 미리 데이터 길이를 알고 있는 곳에서 선택적으로 호출합니다.
+    ///
+    /// # Arguments
+    /// * `available` - 사용 가능한 캔들 수
+    ///
+    /// # Returns
+    /// * `StrategyResult<()>` - 부족하면 가장 긴 기간의 키를 담은 에러
+    pub fn check_candles(&self, available: usize) -> StrategyResult<()> {
+        match self.longest_period() {
+            Some((key, required)) if available < required => {
+                Err(StrategyError::InsufficientCandles {
+                    key,
+                    value: required.to_string(),
+                    required,
+                    available,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 설정 파일을 읽어 타입이 지정된 설정으로 변환
+    ///
+    /// 확장자가 `.toml`이면 TOML, `.json`이면 JSON으로 읽습니다. 누락된 키는 기본값을
+    /// 사용하고, 설정 구조체에 없는 키는 에러로 보고합니다.
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 전략 유형
+    /// * `path` - 설정 파일 경로
+    ///
+    /// # Returns
+    /// * `StrategyResult<StrategyConfig>` - 검증된 설정 또는 에러
+    pub fn from_file(
+        strategy_type: StrategyType,
+        path: impl AsRef<std::path::Path>,
+    ) -> StrategyResult<StrategyConfig> {
+        let values = config_loader::read_config_file(path.as_ref())?;
+        Self::from_config_values(strategy_type, values)
+    }
+
+    /// 키-값 맵을 타입이 지정된 설정으로 변환하고 검증
+    pub(crate) fn from_config_values(
+        strategy_type: StrategyType,
+        values: serde_json::Map<String, serde_json::Value>,
+    ) -> StrategyResult<StrategyConfig> {
+        use config_loader::typed_config;
+
+        let result = match strategy_type {
+            StrategyType::Dummy => StrategyConfig::Dummy(typed_config(values)?),
+            StrategyType::MA => StrategyConfig::MA(typed_config(values)?),
+            StrategyType::MAShort => StrategyConfig::MAShort(typed_config(values)?),
+            StrategyType::RSI => StrategyConfig::RSI(typed_config(values)?),
+            StrategyType::RSIShort => StrategyConfig::RSIShort(typed_config(values)?),
+            StrategyType::BBand => StrategyConfig::BBand(typed_config(values)?),
+            StrategyType::BBandShort => StrategyConfig::BBandShort(typed_config(values)?),
+            StrategyType::MACD => StrategyConfig::MACD(typed_config(values)?),
+            StrategyType::MACDShort => StrategyConfig::MACDShort(typed_config(values)?),
+            StrategyType::Copys => StrategyConfig::Copys(typed_config(values)?),
+            StrategyType::CopysShort => StrategyConfig::CopysShort(typed_config(values)?),
+            StrategyType::ThreeRSI => StrategyConfig::ThreeRSI(typed_config(values)?),
+            StrategyType::ThreeRSIShort => StrategyConfig::ThreeRSIShort(typed_config(values)?),
+            StrategyType::Hybrid => StrategyConfig::Hybrid(typed_config(values)?),
+            StrategyType::HybridShort => StrategyConfig::HybridShort(typed_config(values)?),
+            StrategyType::MultiTimeframe => StrategyConfig::MultiTimeframe(
+                multi_timeframe_strategy::MultiTimeframeStrategyConfig::from_config_values(values)?,
+            ),
+        };
+
+        Ok(result)
+    }
+}
+
+impl ConfigValidation for StrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        match self {
+            StrategyConfig::Dummy(config) => config.validate(),
+            StrategyConfig::MA(config) => config.validate(),
+            StrategyConfig::MAShort(config) => config.validate(),
+            StrategyConfig::RSI(config) => config.validate(),
+            StrategyConfig::RSIShort(config) => config.validate(),
+            StrategyConfig::BBand(config) => config.validate(),
+            StrategyConfig::BBandShort(config) => config.validate(),
+            StrategyConfig::MACD(config) => config.validate(),
+            StrategyConfig::MACDShort(config) => config.validate(),
+            StrategyConfig::Copys(config) => config.validate(),
+            StrategyConfig::CopysShort(config) => config.validate(),
+            StrategyConfig::ThreeRSI(config) => config.validate(),
+            StrategyConfig::ThreeRSIShort(config) => config.validate(),
+            StrategyConfig::Hybrid(config) => config.validate(),
+            StrategyConfig::HybridShort(config) => config.validate(),
+            StrategyConfig::MultiTimeframe(config) => config.validate(),
+        }
+    }
 }
 
 /// 전략 팩토리
@@ -148,21 +628,43 @@ pub struct StrategyFactory;
 impl StrategyFactory {
     /// 전략 유형과 캔들 저장소로부터 전략 인스턴스 생성
     ///
+    /// HashMap 설정을 `StrategyConfig`로 변환한 뒤 `build_from_config`로 생성합니다.
+    ///
     /// # Arguments
     /// * `strategy_type` - 생성할 전략 유형
     /// * `storage` - 캔들 데이터 저장소
     /// * `config` - 선택적 설정 (HashMap 형태로 제공)
     ///
     /// # Returns
-    /// * `Result<Box<dyn Strategy>, String>` - 생성된 전략 인스턴스 또는 에러
+    /// * `StrategyResult<Box<dyn Strategy>>` - 생성된 전략 인스턴스 또는 에러
     ///
-    /// # Panics
-    /// * 알 수 없는 전략 유형이 지정된 경우 패닉 발생
+    /// # Errors
+    /// * 설정 키가 누락되었거나 값을 파싱/검증할 수 없으면 해당 키를 담은 `StrategyError`
+    /// * 멀티 타임프레임 기본 전략이 지원되지 않는 유형이면 `base_strategy` 키의 `StrategyError::InvalidValue`
     pub fn build<C: Candle + 'static>(
         strategy_type: StrategyType,
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<Box<dyn Strategy<C>>, String> {
+    ) -> StrategyResult<Box<dyn Strategy<C>>> {
+        let strategy_config = StrategyConfig::from_hash_map(strategy_type, config.as_ref())
+            .inspect_err(|e| error!("전략 빌드 실패: {strategy_type} - {e}"))?;
+
+        Self::build_from_config(storage, strategy_config)
+    }
+
+    /// 타입이 지정된 설정으로 전략 인스턴스 생성
+    ///
+    /// # Arguments
+    /// * `storage` - 캔들 데이터 저장소
+    /// * `config` - 전략 설정
+    ///
+    /// # Returns
+    /// * `StrategyResult<Box<dyn Strategy>>` - 생성된 전략 인스턴스 또는 에러
+    pub fn build_from_config<C: Candle + 'static>(
+        storage: &CandleStore<C>,
+        config: StrategyConfig,
+    ) -> StrategyResult<Box<dyn Strategy<C>>> {
+        let strategy_type = config.strategy_type();
         info!("전략 빌드 시작: {strategy_type}");
         debug!("캔들 데이터 상태: 항목 수={}", storage.len());
 
@@ -170,86 +672,76 @@ impl StrategyFactory {
             warn!("캔들 데이터가 비어 있습니다. 전략이 제대로 작동하지 않을 수 있습니다.");
         }
 
-        let result = match strategy_type {
-            StrategyType::Dummy => {
+        let result = match config {
+            StrategyConfig::Dummy(config) => {
                 debug!("더미 전략 초기화 시작");
-                dummy_strategy::DummyStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, dummy_strategy::DummyStrategy<C>>(storage, config)
             }
-            StrategyType::MA => {
+            StrategyConfig::MA(config) => {
                 debug!("MA 전략 초기화 시작");
-                ma_strategy::MAStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, ma_strategy::MAStrategy<C>>(storage, config)
             }
-            StrategyType::MAShort => {
+            StrategyConfig::MAShort(config) => {
                 debug!("MA 숏 전략 초기화 시작");
-                ma_short_strategy::MAShortStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, ma_short_strategy::MAShortStrategy<C>>(storage, config)
             }
-            StrategyType::RSI => {
+            StrategyConfig::RSI(config) => {
                 debug!("RSI 전략 초기화 시작");
-                rsi_strategy::RSIStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, rsi_strategy::RSIStrategy<C>>(storage, config)
             }
-            StrategyType::RSIShort => {
+            StrategyConfig::RSIShort(config) => {
                 debug!("RSI 숏 전략 초기화 시작");
-                rsi_short_strategy::RSIShortStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, rsi_short_strategy::RSIShortStrategy<C>>(storage, config)
             }
-            StrategyType::BBand => {
+            StrategyConfig::BBand(config) => {
                 debug!("볼린저 밴드 전략 초기화 시작");
-                bband_strategy::BBandStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, bband_strategy::BBandStrategy<C>>(storage, config)
             }
-            StrategyType::BBandShort => {
+            StrategyConfig::BBandShort(config) => {
                 debug!("볼린저 밴드 숏 전략 초기화 시작");
-                bband_short_strategy::BBandShortStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, bband_short_strategy::BBandShortStrategy<C>>(storage, config)
             }
-            StrategyType::MACD => {
+            StrategyConfig::MACD(config) => {
                 debug!("MACD 전략 초기화 시작");
-                macd_strategy::MACDStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, macd_strategy::MACDStrategy<C>>(storage, config)
             }
-            StrategyType::MACDShort => {
+            StrategyConfig::MACDShort(config) => {
                 debug!("MACD 숏 전략 초기화 시작");
-                macd_short_strategy::MACDShortStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, macd_short_strategy::MACDShortStrategy<C>>(storage, config)
             }
-            StrategyType::Copys => {
+            StrategyConfig::Copys(config) => {
                 debug!("Copys 전략 초기화 시작");
-                copys_strategy::CopysStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, copys_strategy::CopysStrategy<C>>(storage, config)
             }
-            StrategyType::CopysShort => {
+            StrategyConfig::CopysShort(config) => {
                 debug!("Copys 숏 전략 초기화 시작");
-                copys_short_strategy::CopysShortStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, copys_short_strategy::CopysShortStrategy<C>>(storage, config)
             }
-            StrategyType::ThreeRSI => {
+            StrategyConfig::ThreeRSI(config) => {
                 debug!("3-RSI 전략 초기화 시작");
-                three_rsi_strategy::ThreeRSIStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, three_rsi_strategy::ThreeRSIStrategy<C>>(storage, config)
             }
-            StrategyType::ThreeRSIShort => {
+            StrategyConfig::ThreeRSIShort(config) => {
                 debug!("3-RSI 숏 전략 초기화 시작");
-                three_rsi_short_strategy::ThreeRSIShortStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, three_rsi_short_strategy::ThreeRSIShortStrategy<C>>(
+                    storage, config,
+                )
             }
-            StrategyType::Hybrid => {
+            StrategyConfig::Hybrid(config) => {
                 debug!("하이브리드 전략 초기화 시작");
-                hybrid_strategy::HybridStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, hybrid_strategy::HybridStrategy<C>>(storage, config)
             }
-            StrategyType::HybridShort => {
+            StrategyConfig::HybridShort(config) => {
                 debug!("하이브리드 숏 전략 초기화 시작");
-                hybrid_short_strategy::HybridShortStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, hybrid_short_strategy::HybridShortStrategy<C>>(
+                    storage, config,
+                )
             }
-            StrategyType::MultiTimeframe => {
+            StrategyConfig::MultiTimeframe(config) => {
                 debug!("MultiTimeframe 전략 초기화 시작");
-                multi_timeframe_strategy::MultiTimeframeStrategy::new_with_config(storage, config)
-                    .map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+                Self::instantiate::<C, multi_timeframe_strategy::MultiTimeframeStrategy<C>>(
+                    storage, config,
+                )
             }
         };
 
@@ -265,6 +757,100 @@ impl StrategyFactory {
         result
     }
 
+    /// 설정을 검증한 뒤 전략 인스턴스 생성
+    fn instantiate<C: Candle + 'static, S: ConfigurableStrategy<C>>(
+        storage: &CandleStore<C>,
+        config: S::Config,
+    ) -> StrategyResult<Box<dyn Strategy<C>>> {
+        config.validate()?;
+        S::from_config(storage, config).map(|s| Box::new(s) as Box<dyn Strategy<C>>)
+    }
+
+    /// 설정 파일로부터 전략 인스턴스 생성
+    ///
+    /// 확장자가 `.toml`이면 TOML, `.json`이면 JSON으로 읽습니다. 누락된 키는 각 전략
+    /// 설정의 기본값을 사용하고, 설정 구조체에 없는 키는 에러로 보고하며,
+    /// 생성 전에 `ConfigValidation`으로 검증합니다.
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 생성할 전략 유형
+    /// * `storage` - 캔들 데이터 저장소
+    /// * `path` - 설정 파일 경로
+    ///
+    /// # Returns
+    /// * `StrategyResult<Box<dyn Strategy>>` - 생성된 전략 인스턴스 또는 에러
+    pub fn build_from_file<C: Candle + 'static>(
+        strategy_type: StrategyType,
+        storage: &CandleStore<C>,
+        path: impl AsRef<std::path::Path>,
+    ) -> StrategyResult<Box<dyn Strategy<C>>> {
+        let path = path.as_ref();
+        info!("설정 파일 로드: {strategy_type} ({})", path.display());
+
+        let strategy_config = StrategyConfig::from_file(strategy_type, path)
+            .inspect_err(|e| error!("설정 파일 로드 실패: {strategy_type} - {e}"))?;
+        debug!("설정 파일 로드 완료: {strategy_config:?}");
+
+        Self::build_from_config(storage, strategy_config)
+    }
+
+    /// 저장한 상태로 전략 인스턴스 생성
+    ///
+    /// 캔들 저장소를 재생하지 않고 빈 전략을 만든 뒤 스냅샷을 복원하므로,
+    /// 재시작한 프로세스가 워밍업 없이 이전과 같은 지표 값으로 이어서 동작합니다.
+    ///
+    /// # Arguments
+    /// * `config` - 스냅샷을 만든 전략과 같은 설정
+    /// * `snapshot` - `Strategy::snapshot`으로 저장한 전략 상태
+    ///
+    /// # Returns
+    /// * `StrategyResult<Box<dyn Strategy>>` - 복원된 전략 인스턴스 또는 에러
+    pub fn build_from_snapshot<C: Candle + DeserializeOwned + 'static>(
+        config: StrategyConfig,
+        snapshot: &StrategySnapshot,
+    ) -> StrategyResult<Box<dyn Strategy<C>>> {
+        let strategy_type = config.strategy_type();
+        let storage = CandleStore::new(Vec::new(), 1, false);
+
+        let mut strategy = Self::build_from_config(&storage, config)?;
+        strategy
+            .restore(snapshot)
+            .inspect_err(|e| error!("전략 상태 복원 실패: {strategy_type} - {e}"))?;
+        info!("전략 상태 복원 완료: {strategy_type}");
+
+        Ok(strategy)
+    }
+
+    /// 전략 유형이 받는 설정 키 목록
+    ///
+    /// 멀티 타임프레임 전략은 자체 키만 반환하며, 기본 전략의 키는 포함하지 않습니다.
+    ///
+    /// # Arguments
+    /// * `strategy_type` - 전략 유형
+    ///
+    /// # Returns
+    /// * `BTreeSet<String>` - 정렬된 설정 키 목록
+    pub fn config_keys(strategy_type: StrategyType) -> BTreeSet<String> {
+        Self::default_config_values(strategy_type)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// 전략 유형의 기본 설정 값
+    ///
+    /// 전략 유형을 나타내는 `type` 키는 포함하지 않습니다.
+    pub(crate) fn default_config_values(
+        strategy_type: StrategyType,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        let mut values = match serde_json::to_value(StrategyConfig::default_for(strategy_type)) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        values.remove("type");
+        values
+    }
+
     /// 기본 설정으로 전략 인스턴스 생성 (이전 버전과의 호환성 유지)
     ///
     /// # Arguments
@@ -272,11 +858,11 @@ impl StrategyFactory {
     /// * `storage` - 캔들 데이터 저장소
     ///
     /// # Returns
-    /// * `Result<Box<dyn Strategy>, String>` - 생성된 전략 인스턴스 또는 에러
+    /// * `StrategyResult<Box<dyn Strategy>>` - 생성된 전략 인스턴스 또는 에러
     pub fn build_with_default<C: Candle + 'static>(
         strategy_type: StrategyType,
         storage: &CandleStore<C>,
-    ) -> Result<Box<dyn Strategy<C>>, String> {
+    ) -> StrategyResult<Box<dyn Strategy<C>>> {
         Self::build(strategy_type, storage, None)
     }
 
@@ -345,26 +931,6 @@ where
         .collect()
 }
 
-/// 문자열을 안전하게 분리하여 벡터로 변환 (에러 시 빈 벡터 반환)
-///
-/// # Arguments
-/// * `input` - 분리할 문자열
-///
-/// # Returns
-/// * `Result<Vec<T>, String>` - 분리된 값 벡터 또는 에러
-pub fn split_safe<T: FromStr>(input: &str) -> Result<Vec<T>, String>
-where
-    <T as FromStr>::Err: Debug + Display,
-{
-    match split::<T>(input) {
-        Ok(v) => Ok(v),
-        Err(e) => {
-            log::error!("분리 오류: {e}");
-            Ok(vec![])
-        }
-    }
-}
-
 /// 공통 설정 파싱 유틸리티 모듈
 pub mod config_utils {
     use super::*;
@@ -379,30 +945,34 @@ pub mod config_utils {
     /// * `required` - 필수 여부
     ///
     /// # Returns
-    /// * `Result<Option<usize>, String>` - 파싱된 값 또는 에러
+    /// * `StrategyResult<Option<usize>>` - 파싱된 값 또는 에러
     pub fn parse_usize(
         config: &HashMap<String, String>,
         key: &str,
         min_value: Option<usize>,
         required: bool,
-    ) -> Result<Option<usize>, String> {
+    ) -> StrategyResult<Option<usize>> {
         match config.get(key) {
             Some(value_str) => {
                 let value = value_str
                     .parse::<usize>()
-                    .map_err(|_| format!("{key} 파싱 오류"))?;
+                    .map_err(|e| StrategyError::parse_failure(key, value_str, e))?;
 
                 if let Some(min) = min_value
                     && value < min
                 {
-                    return Err(format!("{key}은(는) {min} 이상이어야 합니다"));
+                    return Err(StrategyError::out_of_range(
+                        key,
+                        value,
+                        format!("{min} 이상"),
+                    ));
                 }
 
                 Ok(Some(value))
             }
             None => {
                 if required {
-                    Err(format!("{key} 설정이 필요합니다"))
+                    Err(StrategyError::missing_key(key))
                 } else {
                     Ok(None)
                 }
@@ -419,34 +989,78 @@ pub mod config_utils {
     /// * `required` - 필수 여부
     ///
     /// # Returns
-    /// * `Result<Option<f64>, String>` - 파싱된 값 또는 에러
+    /// * `StrategyResult<Option<f64>>` - 파싱된 값 또는 에러
     pub fn parse_f64(
         config: &HashMap<String, String>,
         key: &str,
         range: Option<(f64, f64)>,
         required: bool,
-    ) -> Result<Option<f64>, String> {
+    ) -> StrategyResult<Option<f64>> {
         match config.get(key) {
             Some(value_str) => {
                 let value = value_str
                     .parse::<f64>()
-                    .map_err(|_| format!("{key} 파싱 오류"))?;
+                    .map_err(|e| StrategyError::parse_failure(key, value_str, e))?;
 
                 if !value.is_finite() {
-                    return Err(format!("{key}은(는) 유한한 숫자여야 합니다"));
+                    return Err(StrategyError::out_of_range(key, value, "유한한 숫자"));
                 }
 
                 if let Some((min, max)) = range
                     && (value < min || value > max)
                 {
-                    return Err(format!("{key}은(는) {min}과 {max} 사이여야 합니다"));
+                    return Err(StrategyError::out_of_range(
+                        key,
+                        value,
+                        format!("{min} 이상 {max} 이하"),
+                    ));
                 }
 
                 Ok(Some(value))
             }
             None => {
                 if required {
-                    Err(format!("{key} 설정이 필요합니다"))
+                    Err(StrategyError::missing_key(key))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    /// HashMap에서 쉼표로 구분된 목록 파싱
+    ///
+    /// 값이 있지만 비어 있으면 에러를 반환합니다.
+    ///
+    /// # Arguments
+    /// * `config` - 설정 HashMap
+    /// * `key` - 설정 키
+    /// * `required` - 필수 여부
+    ///
+    /// # Returns
+    /// * `StrategyResult<Option<Vec<T>>>` - 파싱된 목록 또는 에러
+    pub fn parse_list<T: FromStr>(
+        config: &HashMap<String, String>,
+        key: &str,
+        required: bool,
+    ) -> StrategyResult<Option<Vec<T>>>
+    where
+        <T as FromStr>::Err: Debug + Display,
+    {
+        match config.get(key) {
+            Some(value_str) => {
+                let values = split::<T>(value_str)
+                    .map_err(|e| StrategyError::parse_failure(key, value_str, e))?;
+
+                if values.is_empty() {
+                    return Err(StrategyError::out_of_range(key, value_str, "1개 이상의 값"));
+                }
+
+                Ok(Some(values))
+            }
+            None => {
+                if required {
+                    Err(StrategyError::missing_key(key))
                 } else {
                     Ok(None)
                 }
@@ -462,26 +1076,23 @@ pub mod config_utils {
     /// * `required` - 필수 여부
     ///
     /// # Returns
-    /// * `Result<Option<MAType>, String>` - 파싱된 값 또는 에러
+    /// * `StrategyResult<Option<MAType>>` - 파싱된 값 또는 에러
     pub fn parse_ma_type(
         config: &HashMap<String, String>,
         key: Option<&str>,
         required: bool,
-    ) -> Result<Option<MAType>, String> {
+    ) -> StrategyResult<Option<MAType>> {
         let key = key.unwrap_or("ma");
         match config.get(key) {
             Some(ma_type_str) => {
-                let ma_type = match ma_type_str.to_lowercase().as_str() {
-                    "sma" => MAType::SMA,
-                    "ema" => MAType::EMA,
-                    "wma" => MAType::WMA,
-                    _ => return Err(format!("알 수 없는 이동평균 유형: {ma_type_str}")),
-                };
+                let ma_type = MAType::from_str(ma_type_str).map_err(|_| {
+                    StrategyError::parse_failure(key, ma_type_str, "알 수 없는 이동평균 유형")
+                })?;
                 Ok(Some(ma_type))
             }
             None => {
                 if required {
-                    Err(format!("{key} 설정이 필요합니다"))
+                    Err(StrategyError::missing_key(key))
                 } else {
                     Ok(None)
                 }
@@ -495,20 +1106,23 @@ pub mod config_utils {
     /// * `config` - 설정 HashMap
     ///
     /// # Returns
-    /// * `Result<(usize, f64, f64), String>` - (rsi_period, rsi_lower, rsi_upper) 또는 에러
-    pub fn parse_rsi_config(config: &HashMap<String, String>) -> Result<(usize, f64, f64), String> {
+    /// * `StrategyResult<(usize, f64, f64)>` - (rsi_period, rsi_lower, rsi_upper) 또는 에러
+    pub fn parse_rsi_config(config: &HashMap<String, String>) -> StrategyResult<(usize, f64, f64)> {
         let rsi_period = parse_usize(config, "rsi_period", Some(2), true)?
-            .ok_or("rsi_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("rsi_period"))?;
 
         let rsi_lower = parse_f64(config, "rsi_lower", Some((0.0, 100.0)), true)?
-            .ok_or("rsi_lower 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("rsi_lower"))?;
 
         let rsi_upper = parse_f64(config, "rsi_upper", Some((0.0, 100.0)), true)?
-            .ok_or("rsi_upper 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("rsi_upper"))?;
 
         if rsi_lower >= rsi_upper {
-            return Err(format!(
-                "RSI 하한값({rsi_lower})은 상한값({rsi_upper})보다 작아야 합니다"
+            return Err(StrategyError::invalid_period_order(
+                "rsi_lower",
+                rsi_lower,
+                "rsi_upper",
+                rsi_upper,
             ));
         }
 
@@ -528,4 +1142,21 @@ mod config_utils_tests {
 
         assert!(config_utils::parse_f64(&config, "threshold", Some((0.0, 1.0)), true).is_err());
     }
+
+    #[test]
+    fn test_parse_ma_type_accepts_every_ma_type() {
+        use crate::indicator::ma::MAType;
+
+        let mut config = HashMap::new();
+        for ma_type in MAType::ALL {
+            config.insert("ma".to_string(), ma_type.to_string().to_lowercase());
+            assert_eq!(
+                config_utils::parse_ma_type(&config, None, true).unwrap(),
+                Some(ma_type)
+            );
+        }
+
+        config.insert("ma".to_string(), "xma".to_string());
+        assert!(config_utils::parse_ma_type(&config, None, true).is_err());
+    }
 }
@@ -1,130 +1,287 @@
 use crate::candle_store::CandleStore;
 use crate::model::{PositionType, Signal};
-use crate::strategy::{Strategy, StrategyFactory, StrategyType, split};
+use crate::strategy::{
+    ConditionNode, ConditionRule, ConfigurableStrategy, SignalExplanation, Strategy,
+    StrategyConfig, StrategyError, StrategyFactory, StrategyResult, StrategySnapshot, StrategyType,
+    config_loader, config_utils,
+};
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use trading_chart::{Candle, CandleInterval};
 
-/// 멀티 타임프레임 분석 전략
-///
-/// 여러 타임프레임의 데이터를 동시에 분석하여 매매 신호를 생성합니다.
-/// 각 타임프레임별로 별도의 캔들 저장소를 유지하여 타임프레임별 필터링을 수행합니다.
-pub struct MultiTimeframeStrategy<C: Candle + 'static> {
-    /// 전체 캔들 저장소 (모든 타임프레임 통합)
-    storage: CandleStore<C>,
-    /// 타임프레임별 캔들 저장소
-    timeframe_storages: HashMap<CandleInterval, CandleStore<C>>,
-    timeframe_weights: HashMap<CandleInterval, f64>,
-    base_strategy: StrategyType,
-    confirmation_threshold: f64,
-    strategies: HashMap<CandleInterval, Box<dyn Strategy<C>>>,
-    position_type: PositionType,
+/// 기본 전략으로 사용할 수 있는 전략 유형
+const SUPPORTED_BASE_STRATEGIES: [StrategyType; 10] = [
+    StrategyType::MA,
+    StrategyType::MAShort,
+    StrategyType::RSI,
+    StrategyType::RSIShort,
+    StrategyType::MACD,
+    StrategyType::MACDShort,
+    StrategyType::BBand,
+    StrategyType::BBandShort,
+    StrategyType::ThreeRSI,
+    StrategyType::ThreeRSIShort,
+];
+
+/// 멀티 타임프레임 전략 설정
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct MultiTimeframeStrategyConfig {
+    /// 분석할 타임프레임 목록 (예: "1m", "5m", "1h")
+    pub timeframes: Vec<String>,
+    /// 타임프레임별 가중치 (합계 1.0)
+    pub weights: Vec<f64>,
+    /// 매매 신호 확정 임계값 (0.0 ~ 1.0)
+    pub confirmation_threshold: f64,
+    /// 각 타임프레임에 적용할 기본 전략 설정
+    pub base_strategy: Box<StrategyConfig>,
 }
 
-impl<C: Candle + 'static> MultiTimeframeStrategy<C> {
-    /// 설정과 함께 새로운 멀티 타임프레임 전략 인스턴스를 생성합니다.
-    ///
-    /// # Arguments
-    /// * `storage` - 캔들 데이터 저장소
-    /// * `config` - 전략 설정
-    ///
-    /// # Returns
-    /// * `Result<MultiTimeframeStrategy<C>, String>` - 생성된 전략 인스턴스 또는 에러
-    pub fn new_with_config(
-        storage: &CandleStore<C>,
-        config: Option<HashMap<String, String>>,
-    ) -> Result<MultiTimeframeStrategy<C>, String> {
-        let config = config.unwrap_or_default();
-
-        // 타임프레임 목록과 가중치 파싱
-        let timeframes_str = config
-            .get("timeframes")
-            .ok_or("timeframes 설정이 필요합니다")?;
-        let weights_str = config.get("weights").ok_or("weights 설정이 필요합니다")?;
-
-        // 타임프레임을 문자열로 파싱
-        let timeframe_strings: Vec<String> = split(timeframes_str)?;
-
-        // 가중치 파싱
-        let weights: Vec<f64> = split(weights_str)?;
-
-        if timeframe_strings.is_empty() {
-            return Err("최소 1개 이상의 타임프레임이 필요합니다".to_string());
+impl Default for MultiTimeframeStrategyConfig {
+    fn default() -> Self {
+        MultiTimeframeStrategyConfig {
+            timeframes: vec!["1h".to_string()],
+            weights: vec![1.0],
+            confirmation_threshold: 0.6,
+            base_strategy: Box::new(StrategyConfig::default_for(StrategyType::RSI)),
         }
+    }
+}
 
-        if timeframe_strings.len() != weights.len() {
-            return Err("타임프레임과 가중치의 개수가 일치하지 않습니다".to_string());
+impl ConfigValidation for MultiTimeframeStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if self.timeframes.is_empty() {
+            return Err(StrategyError::out_of_range("timeframes", "[]", "1개 이상의 값").into());
         }
 
-        for (index, weight) in weights.iter().enumerate() {
-            if !weight.is_finite() {
-                return Err(format!("weights[{index}]은(는) 유한한 숫자여야 합니다"));
-            }
+        if self.timeframes.len() != self.weights.len() {
+            return Err(StrategyError::invalid_value(
+                "weights",
+                format!("{:?}", self.weights),
+                format!(
+                    "타임프레임 개수({})와 일치해야 합니다",
+                    self.timeframes.len()
+                ),
+            )
+            .into());
+        }
 
-            if *weight < 0.0 {
-                return Err(format!("weights[{index}]은(는) 0 이상이어야 합니다"));
+        for (index, weight) in self.weights.iter().enumerate() {
+            if !weight.is_finite() || *weight < 0.0 {
+                return Err(StrategyError::out_of_range(
+                    format!("weights[{index}]"),
+                    weight,
+                    "유한한 0 이상의 숫자",
+                )
+                .into());
             }
         }
 
         // 가중치 합계가 1.0인지 확인
-        let weight_sum: f64 = weights.iter().sum();
+        let weight_sum: f64 = self.weights.iter().sum();
         if (weight_sum - 1.0).abs() > 0.0001 {
-            return Err("가중치의 합이 1.0이어야 합니다".to_string());
+            return Err(StrategyError::invalid_value(
+                "weights",
+                format!("{:?}", self.weights),
+                "가중치의 합이 1.0이어야 합니다",
+            )
+            .into());
         }
 
-        // HashMap으로 타임프레임과 가중치 매핑
-        let mut timeframe_weights = HashMap::new();
-        let mut strategies = HashMap::new();
+        self.intervals()?;
+
+        if !self.confirmation_threshold.is_finite()
+            || !(0.0..=1.0).contains(&self.confirmation_threshold)
+        {
+            return Err(StrategyError::out_of_range(
+                "confirmation_threshold",
+                self.confirmation_threshold,
+                "0 이상 1 이하",
+            )
+            .into());
+        }
+
+        let base_type = self.base_strategy.strategy_type();
+        if !SUPPORTED_BASE_STRATEGIES.contains(&base_type) {
+            return Err(StrategyError::invalid_value(
+                "base_strategy",
+                base_type,
+                "지원되지 않는 기본 전략 타입입니다",
+            )
+            .into());
+        }
+
+        self.base_strategy.validate()
+    }
+}
+
+impl MultiTimeframeStrategyConfig {
+    /// 타임프레임 문자열을 CandleInterval로 변환하고 중복을 검사합니다.
+    fn intervals(&self) -> StrategyResult<Vec<CandleInterval>> {
         let mut seen_intervals = HashSet::new();
+        let mut intervals = Vec::with_capacity(self.timeframes.len());
 
-        // 타임프레임 문자열을 CandleInterval로 변환하고 해시맵에 삽입
-        for (i, tf_str) in timeframe_strings.iter().enumerate() {
-            let interval = CandleInterval::from_str(tf_str)?;
+        for (index, tf_str) in self.timeframes.iter().enumerate() {
+            let key = format!("timeframes[{index}]");
+            let interval = CandleInterval::from_str(tf_str)
+                .map_err(|e| StrategyError::parse_failure(&key, tf_str, e))?;
 
             if !seen_intervals.insert(interval) {
-                return Err(format!("중복된 타임프레임이 있습니다: {interval:?}"));
+                return Err(StrategyError::invalid_value(
+                    key,
+                    tf_str,
+                    "중복된 타임프레임입니다",
+                ));
             }
 
-            timeframe_weights.insert(interval, weights[i]);
+            intervals.push(interval);
         }
 
+        Ok(intervals)
+    }
+
+    /// HashMap에서 설정 로드
+    ///
+    /// 멀티 타임프레임 키 외의 값은 기본 전략 설정으로 해석합니다.
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<MultiTimeframeStrategyConfig> {
+        // 타임프레임 목록과 가중치 파싱
+        let timeframes: Vec<String> = config_utils::parse_list(config, "timeframes", true)?
+            .ok_or_else(|| StrategyError::missing_key("timeframes"))?;
+        let weights: Vec<f64> = config_utils::parse_list(config, "weights", true)?
+            .ok_or_else(|| StrategyError::missing_key("weights"))?;
+
         // 기본 전략 타입 파싱
         let base_strategy_str = config
             .get("base_strategy")
-            .ok_or("base_strategy 설정이 필요합니다")?;
-        let base_strategy = match base_strategy_str.as_str() {
-            "ma" => StrategyType::MA,
-            "ma_short" => StrategyType::MAShort,
-            "rsi" => StrategyType::RSI,
-            "rsi_short" => StrategyType::RSIShort,
-            "macd" => StrategyType::MACD,
-            "macd_short" => StrategyType::MACDShort,
-            "bband" => StrategyType::BBand,
-            "bband_short" => StrategyType::BBandShort,
-            "three_rsi" => StrategyType::ThreeRSI,
-            "three_rsi_short" => StrategyType::ThreeRSIShort,
-            _ => {
-                return Err(format!("지원되지 않는 기본 전략 타입: {base_strategy_str}"));
+            .ok_or_else(|| StrategyError::missing_key("base_strategy"))?;
+        let base_type = StrategyType::from_str(base_strategy_str)
+            .ok()
+            .filter(|base_type| SUPPORTED_BASE_STRATEGIES.contains(base_type))
+            .ok_or_else(|| {
+                StrategyError::invalid_value(
+                    "base_strategy",
+                    base_strategy_str,
+                    "지원되지 않는 기본 전략 타입입니다",
+                )
+            })?;
+
+        // 신호 확인 임계값 파싱
+        let confirmation_threshold =
+            config_utils::parse_f64(config, "confirmation_threshold", None, false)?.unwrap_or(0.6);
+
+        let result = MultiTimeframeStrategyConfig {
+            timeframes,
+            weights,
+            confirmation_threshold,
+            base_strategy: Box::new(StrategyConfig::from_hash_map(base_type, Some(config))?),
+        };
+        result.validate()?;
+
+        Ok(result)
+    }
+
+    /// 설정 파일에서 읽은 키-값 맵으로 설정 로드
+    ///
+    /// `base_strategy`가 전략 이름이면 기본 전략의 설정 키를 같은 테이블에서 읽고,
+    /// 테이블이면 `type` 키로 전략 유형을 지정한 중첩 설정으로 읽습니다.
+    pub(crate) fn from_config_values(
+        mut values: Map<String, Value>,
+    ) -> StrategyResult<MultiTimeframeStrategyConfig> {
+        let own_keys = config_loader::config_keys::<MultiTimeframeStrategyConfig>();
+
+        let base_strategy = match values.remove("base_strategy") {
+            Some(Value::String(name)) => {
+                let base_type = StrategyType::from_str(&name)
+                    .map_err(|_| StrategyError::unknown_strategy_type(name.as_str()))?;
+
+                let mut known_keys = own_keys.clone();
+                known_keys.extend(StrategyFactory::config_keys(base_type));
+                config_loader::check_unknown_keys(&values, &known_keys)?;
+
+                let base_values: Map<String, Value> = values
+                    .iter()
+                    .filter(|(key, _)| !own_keys.contains(*key))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                values.retain(|key, _| own_keys.contains(key));
+
+                StrategyConfig::from_config_values(base_type, base_values)?
+            }
+            Some(Value::Object(mut base_values)) => {
+                let base_type = match base_values.remove("type") {
+                    Some(Value::String(name)) => StrategyType::from_str(&name)
+                        .map_err(|_| StrategyError::unknown_strategy_type(name.as_str()))?,
+                    _ => return Err(StrategyError::missing_key("base_strategy.type")),
+                };
+
+                StrategyConfig::from_config_values(base_type, base_values)?
             }
+            Some(other) => {
+                return Err(StrategyError::parse_failure(
+                    "base_strategy",
+                    other,
+                    "전략 이름 또는 테이블이어야 합니다",
+                ));
+            }
+            None => return Err(StrategyError::missing_key("base_strategy")),
         };
 
-        // 신호 확인 임계값 파싱
-        let confirmation_threshold = config
-            .get("confirmation_threshold")
-            .map(|s| s.parse::<f64>())
-            .transpose()
-            .map_err(|e| format!("confirmation_threshold 파싱 오류: {e}"))?
-            .unwrap_or(0.6);
-
-        if !confirmation_threshold.is_finite() {
-            return Err("confirmation_threshold는 유한한 숫자여야 합니다".to_string());
-        }
+        config_loader::check_unknown_keys(&values, &own_keys)?;
+        let mut result: MultiTimeframeStrategyConfig =
+            serde_json::from_value(Value::Object(values))
+                .map_err(|e| ConfigError::ParseError(e.to_string()))?;
+        result.base_strategy = Box::new(base_strategy);
+        result.validate()?;
 
-        if !(0.0..=1.0).contains(&confirmation_threshold) {
-            return Err("confirmation_threshold는 0.0 이상 1.0 이하여야 합니다".to_string());
-        }
+        Ok(result)
+    }
+}
+
+/// 멀티 타임프레임 분석 전략
+///
+/// 여러 타임프레임의 데이터를 동시에 분석하여 매매 신호를 생성합니다.
+/// 각 타임프레임별로 별도의 캔들 저장소를 유지하여 타임프레임별 필터링을 수행합니다.
+pub struct MultiTimeframeStrategy<C: Candle + 'static> {
+    /// 전체 캔들 저장소 (모든 타임프레임 통합)
+    storage: CandleStore<C>,
+    /// 타임프레임별 캔들 저장소
+    timeframe_storages: HashMap<CandleInterval, CandleStore<C>>,
+    timeframe_weights: HashMap<CandleInterval, f64>,
+    base_strategy: StrategyType,
+    confirmation_threshold: f64,
+    strategies: HashMap<CandleInterval, Box<dyn Strategy<C>>>,
+    position_type: PositionType,
+}
+
+impl<C: Candle + 'static> MultiTimeframeStrategy<C> {
+    /// 새 멀티 타임프레임 전략 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `storage` - 캔들 데이터 저장소
+    /// * `config` - 전략 설정
+    ///
+    /// # Returns
+    /// * `StrategyResult<MultiTimeframeStrategy<C>>` - 생성된 전략 인스턴스 또는 에러
+    pub fn new(
+        storage: &CandleStore<C>,
+        config: MultiTimeframeStrategyConfig,
+    ) -> StrategyResult<MultiTimeframeStrategy<C>> {
+        config.validate()?;
+
+        let intervals = config.intervals()?;
+        let timeframe_weights: HashMap<CandleInterval, f64> = intervals
+            .into_iter()
+            .zip(config.weights.iter().copied())
+            .collect();
 
         // 포지션 타입 결정 (기본 전략의 포지션 타입을 따름)
+        let base_strategy = config.base_strategy.strategy_type();
         let position_type = StrategyFactory::position_from_strategy_type(base_strategy);
 
         // 타임프레임별 캔들 저장소 초기화
@@ -147,13 +304,20 @@ impl<C: Candle + 'static> MultiTimeframeStrategy<C> {
         }
 
         // 각 타임프레임별 전략 인스턴스 생성
+        let mut strategies = HashMap::new();
         for interval in timeframe_weights.keys() {
             // 각 타임프레임별 저장소를 사용하여 전략 생성
             let timeframe_storage = timeframe_storages.get(interval).ok_or_else(|| {
-                format!("타임프레임 {:?}에 대한 저장소를 찾을 수 없습니다", interval)
+                StrategyError::invalid_value(
+                    "timeframes",
+                    format!("{interval:?}"),
+                    "타임프레임 저장소를 찾을 수 없습니다",
+                )
             })?;
-            let strategy =
-                StrategyFactory::build(base_strategy, timeframe_storage, Some(config.clone()))?;
+            let strategy = StrategyFactory::build_from_config(
+                timeframe_storage,
+                (*config.base_strategy).clone(),
+            )?;
             strategies.insert(*interval, strategy);
         }
 
@@ -166,12 +330,30 @@ impl<C: Candle + 'static> MultiTimeframeStrategy<C> {
             timeframe_storages,
             timeframe_weights,
             base_strategy,
-            confirmation_threshold,
+            confirmation_threshold: config.confirmation_threshold,
             strategies,
             position_type,
         })
     }
 
+    /// 설정과 함께 새로운 멀티 타임프레임 전략 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `storage` - 캔들 데이터 저장소
+    /// * `config` - 전략 설정
+    ///
+    /// # Returns
+    /// * `StrategyResult<MultiTimeframeStrategy<C>>` - 생성된 전략 인스턴스 또는 에러
+    pub fn new_with_config(
+        storage: &CandleStore<C>,
+        config: Option<HashMap<String, String>>,
+    ) -> StrategyResult<MultiTimeframeStrategy<C>> {
+        let strategy_config =
+            MultiTimeframeStrategyConfig::from_hash_map(&config.unwrap_or_default())?;
+
+        Self::new(storage, strategy_config)
+    }
+
     /// 가중 평균 신호를 계산합니다.
     ///
     /// # Arguments
@@ -215,6 +397,32 @@ impl<C: Candle + 'static> MultiTimeframeStrategy<C> {
         }
     }
 
+    /// 타임프레임별 신호 설명 (이름순 정렬)
+    ///
+    /// 각 조건의 결과는 해당 타임프레임 신호가 `direction` 방향(1.0 또는 -1.0)으로 기여했는지 여부입니다.
+    fn explain_timeframes(&self, current_price: f64, direction: f64) -> Vec<ConditionNode> {
+        let mut timeframes: Vec<ConditionNode> = self
+            .strategies
+            .iter()
+            .filter_map(|(interval, strategy)| {
+                let weight = self.timeframe_weights.get(interval)?;
+                let signal =
+                    Self::signal_value(Self::signal_for_strategy(strategy.as_ref(), current_price));
+                let explanation = strategy.explain(current_price);
+
+                Some(
+                    ConditionNode::new(format!("{interval:?}"), signal * direction > 0.0)
+                        .input("signal", signal)
+                        .input("weight", *weight)
+                        .children(vec![explanation.enter, explanation.exit]),
+                )
+            })
+            .collect();
+
+        timeframes.sort_by(|a, b| a.name.cmp(&b.name));
+        timeframes
+    }
+
     fn should_enter_for_weighted_signal(&self, weighted_signal: f64) -> bool {
         if self.position() == PositionType::Long {
             weighted_signal >= self.confirmation_threshold
@@ -233,26 +441,32 @@ impl<C: Candle + 'static> MultiTimeframeStrategy<C> {
 
     /// 설정 파일로부터 전략 인스턴스를 생성합니다.
     ///
+    /// TOML과 JSON 파일을 지원하며, 기본 전략 설정은 같은 테이블이나 중첩 테이블에 둘 수 있습니다.
+    ///
     /// # Arguments
     /// * `storage` - 캔들 데이터 저장소
     /// * `config_path` - 설정 파일 경로
     ///
     /// # Returns
-    /// * `Result<MultiTimeframeStrategy<C>, String>` - 생성된 전략 인스턴스 또는 에러
+    /// * `StrategyResult<MultiTimeframeStrategy<C>>` - 생성된 전략 인스턴스 또는 에러
     pub fn from_config_file(
         storage: &CandleStore<C>,
         config_path: &std::path::Path,
-    ) -> Result<MultiTimeframeStrategy<C>, String> {
-        // TOML 설정 파일 읽기
-        let config_str = std::fs::read_to_string(config_path)
-            .map_err(|e| format!("설정 파일 읽기 오류: {e}"))?;
+    ) -> StrategyResult<MultiTimeframeStrategy<C>> {
+        let values = config_loader::read_config_file(config_path)?;
+        let config = MultiTimeframeStrategyConfig::from_config_values(values)?;
+        Self::new(storage, config)
+    }
+}
 
-        // TOML 파싱
-        let config: HashMap<String, String> =
-            toml::from_str(&config_str).map_err(|e| format!("TOML 파싱 오류: {e}"))?;
+impl<C: Candle + 'static> ConfigurableStrategy<C> for MultiTimeframeStrategy<C> {
+    type Config = MultiTimeframeStrategyConfig;
 
-        // 설정으로 인스턴스 생성
-        MultiTimeframeStrategy::new_with_config(storage, Some(config))
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: MultiTimeframeStrategyConfig,
+    ) -> StrategyResult<MultiTimeframeStrategy<C>> {
+        Self::new(storage, config)
     }
 }
 
@@ -293,6 +507,77 @@ impl<C: Candle + 'static> Strategy<C> for MultiTimeframeStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::MultiTimeframe
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        let weighted_signal = self.calculate_weighted_signal(current_price);
+        // 롱은 양의 가중 신호로 진입하고, 숏은 음의 가중 신호로 진입
+        let enter_direction = if self.position() == PositionType::Long {
+            1.0
+        } else {
+            -1.0
+        };
+
+        let enter = ConditionNode::new(
+            "weighted_signal",
+            self.should_enter_for_weighted_signal(weighted_signal),
+        )
+        .rule(ConditionRule::Score)
+        .input("weighted_signal", weighted_signal)
+        .threshold("confirmation_threshold", self.confirmation_threshold)
+        .children(self.explain_timeframes(current_price, enter_direction));
+
+        let exit = ConditionNode::new(
+            "weighted_signal",
+            self.should_exit_for_weighted_signal(weighted_signal),
+        )
+        .rule(ConditionRule::Score)
+        .input("weighted_signal", weighted_signal)
+        .threshold("confirmation_threshold", self.confirmation_threshold)
+        .children(self.explain_timeframes(current_price, -enter_direction));
+
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter,
+            exit,
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        // 타임프레임별 기본 전략 상태를 타임프레임 이름으로 저장
+        StrategySnapshot::capture_timeframes(
+            StrategyType::MultiTimeframe,
+            self.strategies
+                .iter()
+                .map(|(interval, strategy)| (format!("{interval:?}"), strategy.snapshot())),
+        )
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        if snapshot.timeframes.len() != self.strategies.len() {
+            return Err(StrategyError::snapshot_failure(
+                StrategyType::MultiTimeframe,
+                format!(
+                    "타임프레임 수 불일치: 전략 {}, 스냅샷 {}",
+                    self.strategies.len(),
+                    snapshot.timeframes.len()
+                ),
+            ));
+        }
+
+        for (interval, strategy) in &mut self.strategies {
+            let timeframe =
+                snapshot.timeframe(StrategyType::MultiTimeframe, &format!("{interval:?}"))?;
+            strategy.restore(timeframe)?;
+        }
+        Ok(())
+    }
 }
 
 impl<C: Candle + 'static> std::fmt::Display for MultiTimeframeStrategy<C> {
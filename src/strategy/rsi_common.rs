@@ -1,8 +1,10 @@
 use super::Strategy;
 use super::config_utils;
+use super::explain::ConditionNode;
+use super::{StrategyError, StrategyResult};
 use crate::indicator::ma::MAType;
 use crate::{ConfigError, ConfigResult, ConfigValidation};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::HashMap;
 use trading_chart::Candle;
@@ -11,7 +13,7 @@ use trading_chart::Candle;
 pub use crate::analyzer::rsi_analyzer::{RSIAnalyzer, RSIAnalyzerData};
 
 /// RSI 전략 공통 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct RSIStrategyConfigBase {
     /// RSI 판단에 필요한 연속 데이터 수
     pub rsi_count: usize,
@@ -30,28 +32,27 @@ pub struct RSIStrategyConfigBase {
 impl ConfigValidation for RSIStrategyConfigBase {
     fn validate(&self) -> ConfigResult<()> {
         if self.rsi_period < 2 {
-            return Err(ConfigError::ValidationError(
-                "RSI 기간은 2 이상이어야 합니다".to_string(),
-            ));
+            return Err(
+                StrategyError::out_of_range("rsi_period", self.rsi_period, "2 이상").into(),
+            );
         }
 
         if self.rsi_lower >= self.rsi_upper {
-            return Err(ConfigError::ValidationError(format!(
-                "RSI 하한({})은 상한({})보다 작아야 합니다",
-                self.rsi_lower, self.rsi_upper
-            )));
+            return Err(StrategyError::invalid_period_order(
+                "rsi_lower",
+                self.rsi_lower,
+                "rsi_upper",
+                self.rsi_upper,
+            )
+            .into());
         }
 
         if self.rsi_count == 0 {
-            return Err(ConfigError::ValidationError(
-                "RSI 판정 횟수는 0보다 커야 합니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range("rsi_count", self.rsi_count, "1 이상").into());
         }
 
         if self.ma_periods.is_empty() {
-            return Err(ConfigError::ValidationError(
-                "이동평균 기간이 지정되지 않았습니다".to_string(),
-            ));
+            return Err(StrategyError::out_of_range("ma_periods", "[]", "1개 이상의 값").into());
         }
 
         Ok(())
@@ -67,45 +68,35 @@ impl RSIStrategyConfigBase {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<T, String>` - 로드된 설정 또는 오류
-    pub fn from_json<T>(json: &str) -> Result<T, String>
+    /// * `StrategyResult<T>` - 로드된 설정 또는 오류
+    pub fn from_json<T>(json: &str) -> StrategyResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
         match serde_json::from_str::<T>(json) {
             Ok(config) => Ok(config),
-            Err(e) => Err(format!("JSON 설정 역직렬화 실패: {e}")),
+            Err(e) => Err(ConfigError::ParseError(format!("JSON 설정 역직렬화 실패: {e}")).into()),
         }
     }
 
     /// HashMap에서 설정 로드
     pub fn from_hash_map(
         config: &HashMap<String, String>,
-    ) -> Result<RSIStrategyConfigBase, String> {
+    ) -> StrategyResult<RSIStrategyConfigBase> {
         // 공통 유틸리티를 사용하여 RSI 설정 파싱
         let (rsi_period, rsi_lower, rsi_upper) = config_utils::parse_rsi_config(config)?;
 
         // RSI 카운트 설정
         let rsi_count = config_utils::parse_usize(config, "rsi_count", Some(1), true)?
-            .ok_or("rsi_count 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("rsi_count"))?;
 
         // MA 유형 설정
-        let ma = config_utils::parse_ma_type(config, None, true)?.ok_or("ma 설정이 필요합니다")?;
+        let ma = config_utils::parse_ma_type(config, None, true)?
+            .ok_or_else(|| StrategyError::missing_key("ma"))?;
 
         // 이동평균 기간 설정
-        let ma_periods = match config.get("ma_periods") {
-            Some(periods) => {
-                let periods_vec = crate::strategy::split_safe::<usize>(periods)
-                    .map_err(|e| format!("이동평균 기간 파싱 오류: {e}"))?;
-
-                if periods_vec.is_empty() {
-                    return Err("이동평균 기간이 지정되지 않았습니다".to_string());
-                }
-
-                periods_vec
-            }
-            None => return Err("ma_periods 설정이 필요합니다".to_string()),
-        };
+        let ma_periods = config_utils::parse_list::<usize>(config, "ma_periods", true)?
+            .ok_or_else(|| StrategyError::missing_key("ma_periods"))?;
 
         let result = RSIStrategyConfigBase {
             rsi_count,
@@ -116,7 +107,7 @@ impl RSIStrategyConfigBase {
             ma_periods,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -162,4 +153,61 @@ pub trait RSIStrategyCommon<C: Candle + 'static>: Strategy<C> {
             .take(self.config_rsi_count())
             .all(|item| item.rsi.value < self.config_rsi_lower())
     }
+
+    /// RSI 과매수 조건 설명 (`is_rsi_overbought`와 같은 판정)
+    fn explain_rsi_overbought(&self) -> ConditionNode {
+        let count = self.config_rsi_count();
+        rsi_values_node(
+            self.context(),
+            "rsi_overbought",
+            self.is_rsi_overbought(),
+            count,
+        )
+        .threshold("rsi_upper", self.config_rsi_upper())
+        .threshold("rsi_count", count as f64)
+    }
+
+    /// RSI 과매도 조건 설명 (`is_rsi_oversold`와 같은 판정)
+    fn explain_rsi_oversold(&self) -> ConditionNode {
+        let count = self.config_rsi_count();
+        rsi_values_node(
+            self.context(),
+            "rsi_oversold",
+            self.is_rsi_oversold(),
+            count,
+        )
+        .threshold("rsi_lower", self.config_rsi_lower())
+        .threshold("rsi_count", count as f64)
+    }
+}
+
+/// 판정에 사용된 최근 RSI 값을 `rsi[i]` 형태의 입력으로 담은 조건 생성
+fn rsi_values_node<C: Candle>(
+    ctx: &RSIAnalyzer<C>,
+    name: &str,
+    result: bool,
+    count: usize,
+) -> ConditionNode {
+    ctx.items
+        .iter()
+        .take(count)
+        .enumerate()
+        .fold(ConditionNode::new(name, result), |node, (i, item)| {
+            node.input(format!("rsi[{i}]"), item.rsi.value)
+        })
+}
+
+/// 최신 이동평균 값을 `ma_{기간}` 형태의 입력으로 담은 배열 조건 생성
+pub(crate) fn ma_arrangement_node<C: Candle>(
+    ctx: &RSIAnalyzer<C>,
+    name: &str,
+    result: bool,
+) -> ConditionNode {
+    let node = ConditionNode::new(name, result);
+    match ctx.items.first() {
+        Some(data) => data.mas.keys().iter().fold(node, |node, period| {
+            node.input(format!("ma_{period}"), data.mas.get(period).get())
+        }),
+        None => node,
+    }
 }
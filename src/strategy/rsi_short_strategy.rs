@@ -1,20 +1,28 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
-use crate::ConfigValidation;
+use super::explain::{ConditionNode, ConditionRule};
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 // 공통 모듈 가져오기
-use super::rsi_common::{RSIAnalyzer, RSIStrategyCommon, RSIStrategyConfigBase};
+use super::rsi_common::{
+    RSIAnalyzer, RSIStrategyCommon, RSIStrategyConfigBase, ma_arrangement_node,
+};
 use crate::analyzer::base::AnalyzerOps;
 
 /// RSI 숏 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct RSIShortStrategyConfig {
     /// RSI 계산 기간
@@ -45,9 +53,8 @@ impl Default for RSIShortStrategyConfig {
     }
 }
 
-impl RSIShortStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for RSIShortStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         let base = RSIStrategyConfigBase {
             rsi_count: self.rsi_count,
             rsi_lower: self.rsi_lower,
@@ -57,18 +64,22 @@ impl RSIShortStrategyConfig {
             ma_periods: self.ma_periods.clone(),
         };
 
-        base.validate().map_err(|e| e.to_string())
+        base.validate()
     }
+}
 
+impl RSIShortStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<RSIShortStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<RSIShortStrategyConfig> {
         let config = RSIStrategyConfigBase::from_json::<RSIShortStrategyConfig>(json)?;
         config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<RSIShortStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<RSIShortStrategyConfig> {
         let base_config = RSIStrategyConfigBase::from_hash_map(config)?;
 
         let result = RSIShortStrategyConfig {
@@ -106,38 +117,37 @@ impl<C: Candle> Display for RSIShortStrategy<C> {
 
 impl<C: Candle + 'static> RSIShortStrategy<C> {
     /// 새 RSI 숏 전략 인스턴스 생성 (JSON 설정 파일 사용)
-    pub fn new(storage: &CandleStore<C>, json_config: &str) -> Result<RSIShortStrategy<C>, String> {
+    pub fn new(storage: &CandleStore<C>, json_config: &str) -> StrategyResult<RSIShortStrategy<C>> {
         let config = RSIShortStrategyConfig::from_json(json_config)?;
-        info!("RSI 숏 전략 설정: {config:?}");
-
-        let ctx = RSIAnalyzer::new(config.rsi_period, &config.ma, &config.ma_periods, storage);
-
-        Ok(RSIShortStrategy { config, ctx })
+        Self::from_config(storage, config)
     }
 
     /// 새 RSI 숏 전략 인스턴스 생성 (설정 직접 제공)
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<RSIShortStrategy<C>, String> {
+    ) -> StrategyResult<RSIShortStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => RSIShortStrategyConfig::from_hash_map(&cfg)?,
             None => RSIShortStrategyConfig::default(),
         };
 
-        info!("RSI 숏 전략 설정: {strategy_config:?}");
+        Self::from_config(storage, strategy_config)
+    }
+}
 
-        let ctx = RSIAnalyzer::new(
-            strategy_config.rsi_period,
-            &strategy_config.ma,
-            &strategy_config.ma_periods,
-            storage,
-        );
+impl<C: Candle + 'static> ConfigurableStrategy<C> for RSIShortStrategy<C> {
+    type Config = RSIShortStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: RSIShortStrategyConfig,
+    ) -> StrategyResult<RSIShortStrategy<C>> {
+        info!("RSI 숏 전략 설정: {config:?}");
 
-        Ok(RSIShortStrategy {
-            config: strategy_config,
-            ctx,
-        })
+        let ctx = RSIAnalyzer::new(config.rsi_period, &config.ma, &config.ma_periods, storage);
+
+        Ok(RSIShortStrategy { config, ctx })
     }
 }
 
@@ -191,4 +201,56 @@ impl<C: Candle + 'static> Strategy<C> for RSIShortStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::RSIShort
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        let enter = ConditionNode::group(
+            "enter",
+            ConditionRule::All,
+            vec![
+                ma_arrangement_node(
+                    &self.ctx,
+                    "ma_not_regular_arrangement",
+                    !self.ctx.is_ma_regular_arrangement(1, 0),
+                ),
+                self.explain_rsi_overbought(),
+            ],
+        );
+
+        let exit = ConditionNode::group(
+            "exit",
+            ConditionRule::All,
+            vec![
+                ma_arrangement_node(
+                    &self.ctx,
+                    "ma_not_reverse_arrangement",
+                    !self.ctx.is_ma_reverse_arrangement(1, 0),
+                ),
+                self.explain_rsi_oversold(),
+            ],
+        );
+
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter,
+            exit,
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::RSIShort, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::RSIShort)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::RSIShort))
+    }
 }
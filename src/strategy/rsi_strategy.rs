@@ -1,10 +1,15 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::{StrategySnapshot, restore_failed};
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
 use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
@@ -17,7 +22,7 @@ use crate::analyzer::base::AnalyzerOps;
 /// RSI 전략 설정
 ///
 /// RSI(상대강도지수) 기반 트레이딩 전략에 필요한 모든 설정 파라미터를 포함합니다.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct RSIStrategyConfig {
     /// RSI 판단에 필요한 연속 데이터 수
@@ -71,15 +76,17 @@ impl RSIStrategyConfig {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<RSIStrategyConfig, String>` - 로드된 설정 또는 오류
-    fn from_json(json: &str) -> Result<RSIStrategyConfig, String> {
+    /// * `StrategyResult<RSIStrategyConfig>` - 로드된 설정 또는 오류
+    fn from_json(json: &str) -> StrategyResult<RSIStrategyConfig> {
         let config = RSIStrategyConfigBase::from_json::<RSIStrategyConfig>(json)?;
         config.validate()?;
         Ok(config)
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<RSIStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<RSIStrategyConfig> {
         let base_config = RSIStrategyConfigBase::from_hash_map(config)?;
 
         let result = RSIStrategyConfig {
@@ -125,11 +132,11 @@ impl<C: Candle + 'static> RSIStrategy<C> {
     /// * `json_config` - JSON 형식의 설정 문자열
     ///
     /// # Returns
-    /// * `Result<RSIStrategy<C>, String>` - 초기화된 RSI 전략 인스턴스 또는 오류
+    /// * `StrategyResult<RSIStrategy<C>>` - 초기화된 RSI 전략 인스턴스 또는 오류
     pub fn from_json(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<RSIStrategy<C>, String> {
+    ) -> StrategyResult<RSIStrategy<C>> {
         let config = RSIStrategyConfig::from_json(json_config)?;
         Self::new(storage, config)
     }
@@ -141,11 +148,11 @@ impl<C: Candle + 'static> RSIStrategy<C> {
     /// * `config` - 전략 설정
     ///
     /// # Returns
-    /// * `Result<RSIStrategy<C>, String>` - 초기화된 RSI 전략 인스턴스 또는 오류
+    /// * `StrategyResult<RSIStrategy<C>>` - 초기화된 RSI 전략 인스턴스 또는 오류
     pub fn new(
         storage: &CandleStore<C>,
         config: RSIStrategyConfig,
-    ) -> Result<RSIStrategy<C>, String> {
+    ) -> StrategyResult<RSIStrategy<C>> {
         info!("RSI 전략 설정: {config:?}");
 
         let ctx = RSIAnalyzer::new(config.rsi_period, &config.ma, &config.ma_periods, storage);
@@ -160,11 +167,11 @@ impl<C: Candle + 'static> RSIStrategy<C> {
     /// * `config` - 전략 설정 (HashMap 형태)
     ///
     /// # Returns
-    /// * `Result<RSIStrategy<C>, String>` - 초기화된 RSI 전략 인스턴스 또는 오류
+    /// * `StrategyResult<RSIStrategy<C>>` - 초기화된 RSI 전략 인스턴스 또는 오류
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<RSIStrategy<C>, String> {
+    ) -> StrategyResult<RSIStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => RSIStrategyConfig::from_hash_map(&cfg)?,
             None => RSIStrategyConfig::default(),
@@ -192,6 +199,17 @@ impl<C: Candle + 'static> RSIStrategyCommon<C> for RSIStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for RSIStrategy<C> {
+    type Config = RSIStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: RSIStrategyConfig,
+    ) -> StrategyResult<RSIStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for RSIStrategy<C> {
     fn next(&mut self, candle: C) {
         self.ctx.next(candle)
@@ -214,6 +232,32 @@ impl<C: Candle + 'static> Strategy<C> for RSIStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::RSI
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_rsi_oversold(),
+            exit: self.explain_rsi_overbought(),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::RSI, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::RSI)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::RSI))
+    }
 }
 
 #[cfg(test)]
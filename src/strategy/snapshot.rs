@@ -0,0 +1,112 @@
+// 전략 상태 스냅샷
+// 재시작이 잦은 봇이 캔들 저장소 전체를 다시 재생하지 않고
+// 지표 빌더와 분석기 히스토리를 그대로 이어받을 수 있도록 전략 상태를 직렬화합니다.
+
+use super::{StrategyError, StrategyResult, StrategyType};
+use crate::analyzer::AnalyzerSnapshot;
+use crate::indicator::IndicatorResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 전략 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategySnapshot {
+    /// 전략 유형
+    pub strategy: StrategyType,
+    /// 분석기 상태 (전략이 정한 순서)
+    pub analyzers: Vec<AnalyzerSnapshot>,
+    /// 타임프레임별 기본 전략 상태 (멀티 타임프레임 전략만 사용)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub timeframes: BTreeMap<String, StrategySnapshot>,
+}
+
+impl StrategySnapshot {
+    /// JSON 문자열로 변환
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// 분석기 상태를 모아 전략 상태 생성
+    pub(crate) fn capture(
+        strategy: StrategyType,
+        analyzers: impl IntoIterator<Item = IndicatorResult<AnalyzerSnapshot>>,
+    ) -> StrategyResult<StrategySnapshot> {
+        let analyzers = analyzers
+            .into_iter()
+            .collect::<IndicatorResult<Vec<_>>>()
+            .map_err(|reason| StrategyError::snapshot_failure(strategy, reason))?;
+
+        Ok(StrategySnapshot {
+            strategy,
+            analyzers,
+            timeframes: BTreeMap::new(),
+        })
+    }
+
+    /// 타임프레임별 기본 전략 상태를 모아 전략 상태 생성
+    pub(crate) fn capture_timeframes(
+        strategy: StrategyType,
+        timeframes: impl IntoIterator<Item = (String, StrategyResult<StrategySnapshot>)>,
+    ) -> StrategyResult<StrategySnapshot> {
+        let timeframes = timeframes
+            .into_iter()
+            .map(|(timeframe, snapshot)| Ok((timeframe, snapshot?)))
+            .collect::<StrategyResult<BTreeMap<_, _>>>()?;
+
+        Ok(StrategySnapshot {
+            strategy,
+            analyzers: Vec::new(),
+            timeframes,
+        })
+    }
+
+    /// 전략 유형과 분석기 수를 확인한 뒤 분석기 상태 반환
+    pub(crate) fn analyzers_for<const N: usize>(
+        &self,
+        strategy: StrategyType,
+    ) -> StrategyResult<&[AnalyzerSnapshot; N]> {
+        self.check_strategy(strategy)?;
+
+        self.analyzers.as_slice().try_into().map_err(|_| {
+            StrategyError::snapshot_failure(
+                strategy,
+                format!(
+                    "분석기 상태 수 불일치: 전략 {N}, 스냅샷 {}",
+                    self.analyzers.len()
+                ),
+            )
+        })
+    }
+
+    /// 전략 유형을 확인한 뒤 타임프레임 상태 반환
+    pub(crate) fn timeframe(
+        &self,
+        strategy: StrategyType,
+        timeframe: &str,
+    ) -> StrategyResult<&StrategySnapshot> {
+        self.check_strategy(strategy)?;
+
+        self.timeframes.get(timeframe).ok_or_else(|| {
+            StrategyError::snapshot_failure(
+                strategy,
+                format!("{timeframe} 타임프레임 상태가 스냅샷에 없습니다"),
+            )
+        })
+    }
+
+    /// 스냅샷을 만든 전략 유형 확인
+    fn check_strategy(&self, strategy: StrategyType) -> StrategyResult<()> {
+        if self.strategy != strategy {
+            return Err(StrategyError::snapshot_failure(
+                strategy,
+                format!("{} 전략의 스냅샷은 복원할 수 없습니다", self.strategy),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 분석기 복원 에러를 전략 에러로 변환
+pub(crate) fn restore_failed(strategy: StrategyType) -> impl Fn(String) -> StrategyError {
+    move |reason| StrategyError::snapshot_failure(strategy, reason)
+}
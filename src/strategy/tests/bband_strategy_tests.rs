@@ -1,9 +1,11 @@
+use crate::strategy::Strategy;
 use crate::strategy::bband_strategy::BBandStrategy;
 use crate::strategy::tests::common::{
     backtest_strategy, create_downtrend_candles, create_sideways_candles, create_test_storage,
     create_uptrend_candles,
 };
 use std::collections::HashMap;
+use trading_chart::Candle;
 
 // 테스트용 설정 생성 함수
 fn create_bband_config() -> HashMap<String, String> {
@@ -199,3 +201,37 @@ fn test_bband_with_different_ma_types() {
     println!("EMA 기반 BBand 결과: {ema_result:?}");
     println!("WMA 기반 BBand 결과: {wma_result:?}");
 }
+
+#[test]
+fn test_bband_strategy_explain_matches_signals() {
+    let mut candles = create_sideways_candles(40, 100.0, 1.0);
+    candles.extend(create_uptrend_candles(30, 100.0, 2.0));
+    let storage = create_test_storage(candles[..30].to_vec());
+    let mut strategy =
+        BBandStrategy::new_with_config(&storage, Some(create_bband_config())).unwrap();
+
+    for candle in &candles[30..] {
+        strategy.next(candle.clone());
+        let price = candle.close_price();
+        let explanation = strategy.explain(price);
+
+        assert_eq!(explanation.enter.result, strategy.should_enter(price));
+        assert_eq!(explanation.exit.result, strategy.should_exit(price));
+
+        let names: Vec<&str> = explanation
+            .enter
+            .children
+            .iter()
+            .map(|child| child.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "narrowing_then_squeeze",
+                "high_above_upper_band",
+                "close_above_upper_band"
+            ]
+        );
+        assert!(explanation.exit.inputs.contains_key("middle"));
+    }
+}
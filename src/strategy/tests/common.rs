@@ -1,5 +1,5 @@
 use crate::candle_store::CandleStore;
-use crate::strategy::Strategy;
+use crate::strategy::{ConditionNode, ConditionRule, Strategy};
 use crate::tests::TestCandle;
 use chrono::Utc;
 use trading_chart::Candle;
@@ -287,3 +287,23 @@ pub fn backtest_strategy<C: Candle, S: Strategy<C>>(
         avg_loss_per_trade,
     }
 }
+
+/// 점수형 조건 트리에서 실제로 반영된 점수와 가중치 합계 계산
+///
+/// `FirstMatch` 조건은 먼저 만족한 하위 조건 하나만 반영합니다.
+pub fn applied_score(nodes: &[ConditionNode]) -> (f64, f64) {
+    nodes.iter().fold((0.0, 0.0), |(score, weight), node| {
+        let applied = match node.rule {
+            Some(ConditionRule::FirstMatch) => node.children.iter().find(|child| child.result),
+            _ => Some(node).filter(|node| node.result),
+        };
+
+        match applied {
+            Some(node) => (
+                score + node.score.unwrap_or(0.0),
+                weight + node.weight.unwrap_or(0.0),
+            ),
+            None => (score, weight),
+        }
+    })
+}
@@ -0,0 +1,236 @@
+use crate::model::PositionType;
+use crate::strategy::tests::common::{create_test_storage, create_uptrend_candles};
+use crate::strategy::{StrategyError, StrategyFactory, StrategyType};
+use std::io::Write;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+// 임시 디렉터리에 설정 파일 작성
+fn write_config(dir: &TempDir, file_name: &str, content: &str) -> PathBuf {
+    let path = dir.path().join(file_name);
+    let mut file = std::fs::File::create(&path).unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    path
+}
+
+#[test]
+fn test_build_from_toml_file_uses_defaults_for_missing_keys() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(
+        &dir,
+        "rsi.toml",
+        r#"
+rsi_period = 10
+rsi_lower = 25
+ma_periods = [5, 10, 20]
+"#,
+    );
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let strategy = StrategyFactory::build_from_file(StrategyType::RSI, &storage, &path).unwrap();
+
+    assert_eq!(strategy.name(), StrategyType::RSI);
+}
+
+#[test]
+fn test_build_from_json_file() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(
+        &dir,
+        "macd_short.json",
+        r#"{ "fast_period": 8, "slow_period": 21, "signal_period": 5 }"#,
+    );
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let strategy =
+        StrategyFactory::build_from_file(StrategyType::MACDShort, &storage, &path).unwrap();
+
+    assert_eq!(strategy.name(), StrategyType::MACDShort);
+}
+
+#[test]
+fn test_build_from_file_supports_every_typed_strategy() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(&dir, "empty.json", "{}");
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let strategy_types = [
+        StrategyType::Dummy,
+        StrategyType::MA,
+        StrategyType::MAShort,
+        StrategyType::RSI,
+        StrategyType::RSIShort,
+        StrategyType::BBand,
+        StrategyType::BBandShort,
+        StrategyType::MACD,
+        StrategyType::MACDShort,
+        StrategyType::Copys,
+        StrategyType::CopysShort,
+        StrategyType::ThreeRSI,
+        StrategyType::ThreeRSIShort,
+        StrategyType::Hybrid,
+        StrategyType::HybridShort,
+    ];
+
+    for strategy_type in strategy_types {
+        let strategy = StrategyFactory::build_from_file(strategy_type, &storage, &path)
+            .unwrap_or_else(|e| panic!("{strategy_type} 빌드 실패: {e}"));
+        assert_eq!(strategy.name(), strategy_type);
+    }
+}
+
+#[test]
+fn test_build_multi_timeframe_from_toml_file() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(
+        &dir,
+        "multi_timeframe.toml",
+        r#"
+base_strategy = "rsi"
+timeframes = ["1m", "5m"]
+weights = [0.5, 0.5]
+confirmation_threshold = 0.6
+rsi_period = 10
+"#,
+    );
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let strategy =
+        StrategyFactory::build_from_file(StrategyType::MultiTimeframe, &storage, &path).unwrap();
+
+    assert_eq!(strategy.name(), StrategyType::MultiTimeframe);
+}
+
+#[test]
+fn test_build_multi_timeframe_from_file_with_nested_base() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(
+        &dir,
+        "multi_timeframe.toml",
+        r#"
+timeframes = ["1m", "5m"]
+weights = [0.5, 0.5]
+
+[base_strategy]
+type = "macd_short"
+fast_period = 8
+"#,
+    );
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let strategy =
+        StrategyFactory::build_from_file(StrategyType::MultiTimeframe, &storage, &path).unwrap();
+
+    assert_eq!(strategy.name(), StrategyType::MultiTimeframe);
+    assert_eq!(strategy.position(), PositionType::Short);
+}
+
+#[test]
+fn test_build_from_file_rejects_unknown_keys() {
+    let dir = TempDir::new().unwrap();
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let path = write_config(&dir, "rsi.toml", "rsi_lowr = 25\n");
+    let err = StrategyFactory::build_from_file(StrategyType::RSI, &storage, &path)
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        StrategyError::UnknownKeys {
+            keys: vec!["rsi_lowr".to_string()]
+        }
+    );
+
+    // 플래튼된 설정 구조체도 오타를 검출해야 함
+    let path = write_config(&dir, "hybrid.json", r#"{ "entry_treshold": 0.5 }"#);
+    let err = StrategyFactory::build_from_file(StrategyType::Hybrid, &storage, &path)
+        .err()
+        .unwrap();
+    assert_eq!(err.key(), Some("entry_treshold"));
+
+    let path = write_config(
+        &dir,
+        "multi_timeframe.toml",
+        r#"
+base_strategy = "rsi"
+timeframes = ["1m"]
+weights = [1.0]
+fast_period = 12
+"#,
+    );
+    let err = StrategyFactory::build_from_file(StrategyType::MultiTimeframe, &storage, &path)
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        StrategyError::UnknownKeys {
+            keys: vec!["fast_period".to_string()]
+        }
+    );
+}
+
+#[test]
+fn test_build_from_file_validates_config() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(&dir, "rsi.json", r#"{ "rsi_lower": 80, "rsi_upper": 70 }"#);
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let err = StrategyFactory::build_from_file(StrategyType::RSI, &storage, &path)
+        .err()
+        .unwrap();
+
+    assert!(
+        matches!(err, StrategyError::InvalidPeriodOrder { .. }),
+        "{err}"
+    );
+    assert_eq!(err.key(), Some("rsi_lower"));
+    assert_eq!(err.value(), Some("80"));
+}
+
+#[test]
+fn test_build_from_file_reports_type_mismatch_path() {
+    let dir = TempDir::new().unwrap();
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    let path = write_config(&dir, "rsi.toml", "rsi_period = \"abc\"\n");
+    let err = StrategyFactory::build_from_file(StrategyType::RSI, &storage, &path)
+        .err()
+        .unwrap();
+    assert!(matches!(err, StrategyError::ParseFailure { .. }), "{err}");
+    assert_eq!(err.key(), Some("rsi_period"));
+    assert_eq!(err.value(), Some("abc"));
+
+    // 목록 항목은 인덱스를 포함한 경로를 키로 사용
+    let path = write_config(&dir, "rsi.json", r#"{ "ma_periods": [5, "ten", 20] }"#);
+    let err = StrategyFactory::build_from_file(StrategyType::RSI, &storage, &path)
+        .err()
+        .unwrap();
+    assert_eq!(err.key(), Some("ma_periods[1]"));
+    assert_eq!(err.value(), Some("ten"));
+}
+
+#[test]
+fn test_build_from_file_rejects_unsupported_extension() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(&dir, "rsi.yaml", "rsi_period: 14\n");
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    assert!(StrategyFactory::build_from_file(StrategyType::RSI, &storage, &path).is_err());
+}
+
+#[test]
+fn test_strategy_type_from_str_round_trip() {
+    let strategy_types = [
+        StrategyType::Dummy,
+        StrategyType::MA,
+        StrategyType::RSIShort,
+        StrategyType::ThreeRSIShort,
+        StrategyType::MultiTimeframe,
+    ];
+
+    for strategy_type in strategy_types {
+        let parsed: StrategyType = strategy_type.to_string().parse().unwrap();
+        assert_eq!(parsed, strategy_type);
+    }
+    assert!("unknown".parse::<StrategyType>().is_err());
+}
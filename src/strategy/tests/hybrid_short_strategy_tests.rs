@@ -1,9 +1,12 @@
+use crate::strategy::Strategy;
+use crate::strategy::hybrid_common::HybridStrategyCommon;
 use crate::strategy::hybrid_short_strategy::HybridShortStrategy;
 use crate::strategy::tests::common::{
-    backtest_strategy, create_downtrend_candles, create_sideways_candles, create_test_storage,
-    create_uptrend_candles,
+    applied_score, backtest_strategy, create_downtrend_candles, create_sideways_candles,
+    create_test_storage, create_uptrend_candles,
 };
 use std::collections::HashMap;
+use trading_chart::Candle;
 
 // 테스트용 설정 생성 함수
 fn create_hybrid_short_config() -> HashMap<String, String> {
@@ -108,3 +111,37 @@ fn test_hybrid_short_strategy_performance_in_sideways() {
     println!("총 수익률: {}", result.total_profit_percentage);
     println!("승률: {}", result.win_rate);
 }
+
+#[test]
+fn test_hybrid_short_strategy_explain_matches_signal_strength() {
+    let mut candles = create_downtrend_candles(40, 200.0, 1.0);
+    candles.extend(create_uptrend_candles(40, 160.0, 1.5));
+    let storage = create_test_storage(candles[..30].to_vec());
+    let mut strategy = HybridShortStrategy::new_with_config(&storage, None).unwrap();
+
+    for candle in &candles[30..] {
+        strategy.next(candle.clone());
+        let price = candle.close_price();
+        let explanation = strategy.explain(price);
+
+        assert_eq!(explanation.enter.result, strategy.should_enter(price));
+        assert_eq!(explanation.exit.result, strategy.should_exit(price));
+
+        // 숏 진입은 매도 신호 강도, 숏 청산은 매수 신호 강도를 사용
+        for (node, strength) in [
+            (
+                &explanation.enter,
+                strategy.calculate_sell_signal_strength(0.0),
+            ),
+            (&explanation.exit, strategy.calculate_buy_signal_strength()),
+        ] {
+            let (score, weight) = applied_score(&node.children);
+            let expected = if weight > 0.0 {
+                score / (weight * 1.5)
+            } else {
+                0.0
+            };
+            assert!((expected - strength).abs() < 1e-9, "{}", node.name);
+        }
+    }
+}
@@ -4,8 +4,8 @@ use crate::strategy::hybrid_strategy::HybridStrategy;
 use crate::strategy::ma_strategy::MAStrategy;
 use crate::strategy::rsi_strategy::RSIStrategy;
 use crate::strategy::tests::common::{
-    backtest_strategy, create_downtrend_candles, create_sideways_candles, create_test_storage,
-    create_uptrend_candles,
+    applied_score, backtest_strategy, create_downtrend_candles, create_sideways_candles,
+    create_test_storage, create_uptrend_candles,
 };
 use std::collections::HashMap;
 use trading_chart::Candle;
@@ -196,3 +196,41 @@ fn test_hybrid_vs_individual_strategies() {
     println!("RSI 전략 결과: {rsi_result:?}");
     println!("하이브리드 전략 결과: {hybrid_result:?}");
 }
+
+#[test]
+fn test_hybrid_strategy_explain_matches_signal_strength() {
+    let mut candles = create_uptrend_candles(40, 100.0, 1.0);
+    candles.extend(create_downtrend_candles(40, 140.0, 1.5));
+    let storage = create_test_storage(candles[..30].to_vec());
+    let mut strategy = HybridStrategy::new_with_config(&storage, None).unwrap();
+
+    for candle in &candles[30..] {
+        strategy.next(candle.clone());
+        let price = candle.close_price();
+        let explanation = strategy.explain(price);
+
+        assert_eq!(explanation.enter.result, strategy.should_enter(price));
+        assert_eq!(explanation.exit.result, strategy.should_exit(price));
+
+        // 적용된 하위 조건 점수로 실제 신호 강도를 재현할 수 있어야 함
+        let buy_strength = strategy.calculate_buy_signal_strength();
+        let (score, weight) = applied_score(&explanation.enter.children);
+        let expected = if weight > 0.0 { score / weight } else { 0.0 };
+        assert_eq!(explanation.enter.inputs["strength"], buy_strength);
+        assert!((expected - buy_strength).abs() < 1e-9);
+
+        let sell_strength = strategy.calculate_sell_signal_strength(0.0);
+        let (score, weight) = applied_score(&explanation.exit.children);
+        let expected = if weight > 0.0 {
+            score / (weight * 2.0)
+        } else {
+            0.0
+        };
+        assert!((expected - sell_strength).abs() < 1e-9);
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_str(&strategy.explain(100.0).to_json().unwrap()).unwrap();
+    assert_eq!(json["enter"]["rule"]["type"], "score");
+    assert_eq!(json["enter"]["children"][2]["name"], "macd_histogram");
+}
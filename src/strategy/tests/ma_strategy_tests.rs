@@ -1,9 +1,11 @@
+use crate::strategy::Strategy;
 use crate::strategy::ma_strategy::MAStrategy;
 use crate::strategy::tests::common::{
     backtest_strategy, create_downtrend_candles, create_sideways_candles, create_test_storage,
     create_uptrend_candles,
 };
 use std::collections::HashMap;
+use trading_chart::Candle;
 
 // 테스트용 설정 생성 함수
 fn create_ma_config() -> HashMap<String, String> {
@@ -137,3 +139,26 @@ fn test_different_ma_types() {
     println!("EMA 전략 결과: {ema_result:?}");
     println!("WMA 전략 결과: {wma_result:?}");
 }
+
+#[test]
+fn test_ma_strategy_explain_matches_signals() {
+    let mut candles = create_downtrend_candles(40, 140.0, 1.0);
+    candles.extend(create_uptrend_candles(40, 100.0, 1.5));
+    let storage = create_test_storage(candles[..30].to_vec());
+    let mut strategy = MAStrategy::new_with_config(&storage, Some(create_ma_config())).unwrap();
+
+    for candle in &candles[30..] {
+        strategy.next(candle.clone());
+        let price = candle.close_price();
+        let explanation = strategy.explain(price);
+
+        assert_eq!(explanation.enter.result, strategy.should_enter(price));
+        assert_eq!(explanation.exit.result, strategy.should_exit(price));
+
+        // 교차 조건은 현재 구간과 이전 구간 조건으로 나뉘어야 함
+        let previous = explanation.enter.find("previously_unsatisfied").unwrap();
+        assert_eq!(previous.thresholds["count"], 15.0);
+        assert!(explanation.enter.inputs.contains_key("ma_5"));
+        assert!(explanation.exit.find("satisfied").is_some());
+    }
+}
@@ -1,9 +1,11 @@
+use crate::strategy::Strategy;
 use crate::strategy::macd_strategy::MACDStrategy;
 use crate::strategy::tests::common::{
     backtest_strategy, create_downtrend_candles, create_sideways_candles, create_test_storage,
     create_uptrend_candles,
 };
 use std::collections::HashMap;
+use trading_chart::Candle;
 
 // 테스트용 설정 생성 함수
 fn create_macd_config() -> HashMap<String, String> {
@@ -178,3 +180,30 @@ fn test_macd_price_types() {
     println!("고가 기반 MACD 결과: {high_result:?}");
     println!("저가 기반 MACD 결과: {low_result:?}");
 }
+
+#[test]
+fn test_macd_strategy_explain_matches_signals() {
+    let mut candles = create_downtrend_candles(50, 150.0, 1.0);
+    candles.extend(create_uptrend_candles(50, 100.0, 1.5));
+    let storage = create_test_storage(candles[..40].to_vec());
+    let mut strategy = MACDStrategy::new_with_config(&storage, Some(create_macd_config())).unwrap();
+
+    for candle in &candles[40..] {
+        strategy.next(candle.clone());
+        let price = candle.close_price();
+        let explanation = strategy.explain(price);
+
+        assert_eq!(explanation.enter.result, strategy.should_enter(price));
+        assert_eq!(explanation.exit.result, strategy.should_exit(price));
+
+        let histogram = explanation.enter.find("histogram_above_threshold").unwrap();
+        assert!(histogram.inputs.contains_key("histogram"));
+        assert!(
+            explanation
+                .enter
+                .find("macd_crossed_above_signal")
+                .is_some()
+        );
+        assert_eq!(explanation.exit.name, "macd_crossed_below_signal");
+    }
+}
@@ -3,10 +3,14 @@
 
 pub mod bband_strategy_tests;
 pub mod common;
+pub mod config_file_tests;
 pub mod hybrid_short_strategy_tests;
 pub mod hybrid_strategy_tests;
 pub mod ma_strategy_tests;
 pub mod macd_strategy_tests;
 pub mod multi_timeframe_strategy_tests;
 pub mod rsi_strategy_tests;
+pub mod strategy_config_tests;
+pub mod strategy_error_tests;
+pub mod strategy_snapshot_tests;
 pub mod three_rsi_strategy_tests;
@@ -0,0 +1,124 @@
+use crate::indicator::ma::MAType;
+use crate::strategy::ma_strategy::MAStrategyConfig;
+use crate::strategy::multi_timeframe_strategy::MultiTimeframeStrategyConfig;
+use crate::strategy::rsi_strategy::RSIStrategyConfig;
+use crate::strategy::tests::common::{create_test_storage, create_uptrend_candles};
+use crate::strategy::{StrategyConfig, StrategyFactory, StrategyType};
+use std::collections::HashMap;
+
+const ALL_STRATEGY_TYPES: [StrategyType; 16] = [
+    StrategyType::Dummy,
+    StrategyType::MA,
+    StrategyType::MAShort,
+    StrategyType::RSI,
+    StrategyType::RSIShort,
+    StrategyType::BBand,
+    StrategyType::BBandShort,
+    StrategyType::MACD,
+    StrategyType::MACDShort,
+    StrategyType::Copys,
+    StrategyType::CopysShort,
+    StrategyType::ThreeRSI,
+    StrategyType::ThreeRSIShort,
+    StrategyType::Hybrid,
+    StrategyType::HybridShort,
+    StrategyType::MultiTimeframe,
+];
+
+#[test]
+fn test_strategy_config_json_round_trip() {
+    for strategy_type in ALL_STRATEGY_TYPES {
+        let config = StrategyConfig::default_for(strategy_type);
+        assert_eq!(config.strategy_type(), strategy_type);
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["type"], strategy_type.to_string());
+
+        let restored: StrategyConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, config);
+    }
+}
+
+#[test]
+fn test_strategy_config_toml_round_trip_with_nested_base() {
+    let config = StrategyConfig::MultiTimeframe(MultiTimeframeStrategyConfig {
+        timeframes: vec!["1m".to_string(), "5m".to_string()],
+        weights: vec![0.4, 0.6],
+        confirmation_threshold: 0.5,
+        base_strategy: Box::new(StrategyConfig::MA(MAStrategyConfig {
+            ma: MAType::SMA,
+            ma_periods: vec![5, 20, 60],
+            cross_previous_periods: 10,
+        })),
+    });
+
+    let toml_str = toml::to_string(&config).unwrap();
+    let restored: StrategyConfig = toml::from_str(&toml_str).unwrap();
+
+    assert_eq!(restored, config);
+}
+
+#[test]
+fn test_strategy_config_missing_fields_use_defaults() {
+    let config: StrategyConfig =
+        serde_json::from_str(r#"{ "type": "rsi", "rsi_period": 21 }"#).unwrap();
+
+    let StrategyConfig::RSI(rsi) = config else {
+        panic!("RSI 설정이어야 합니다");
+    };
+    assert_eq!(rsi.rsi_period, 21);
+    assert_eq!(rsi.ma_periods, RSIStrategyConfig::default().ma_periods);
+}
+
+#[test]
+fn test_hash_map_adapter_produces_typed_config() {
+    let mut map = HashMap::new();
+    map.insert("ma".to_string(), "sma".to_string());
+    map.insert("ma_periods".to_string(), "5,20,60".to_string());
+    map.insert("cross_previous_periods".to_string(), "10".to_string());
+
+    let config = StrategyConfig::from_hash_map(StrategyType::MA, Some(&map)).unwrap();
+
+    assert_eq!(
+        config,
+        StrategyConfig::MA(MAStrategyConfig {
+            ma: MAType::SMA,
+            ma_periods: vec![5, 20, 60],
+            cross_previous_periods: 10,
+        })
+    );
+    assert!(StrategyConfig::from_hash_map(StrategyType::MultiTimeframe, None).is_err());
+}
+
+#[test]
+fn test_build_from_config() {
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+
+    for strategy_type in ALL_STRATEGY_TYPES {
+        let strategy = StrategyFactory::build_from_config(
+            &storage,
+            StrategyConfig::default_for(strategy_type),
+        )
+        .unwrap_or_else(|e| panic!("{strategy_type} 빌드 실패: {e}"));
+        assert_eq!(strategy.name(), strategy_type);
+    }
+}
+
+#[test]
+fn test_build_from_config_validates_values() {
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+    let config = StrategyConfig::RSI(RSIStrategyConfig {
+        rsi_lower: 80.0,
+        rsi_upper: 70.0,
+        ..RSIStrategyConfig::default()
+    });
+
+    assert!(StrategyFactory::build_from_config(&storage, config).is_err());
+
+    let config = StrategyConfig::MultiTimeframe(MultiTimeframeStrategyConfig {
+        base_strategy: Box::new(StrategyConfig::default_for(StrategyType::Hybrid)),
+        ..MultiTimeframeStrategyConfig::default()
+    });
+
+    assert!(StrategyFactory::build_from_config(&storage, config).is_err());
+}
@@ -0,0 +1,137 @@
+use crate::strategy::ma_strategy::MAStrategyConfig;
+use crate::strategy::tests::common::{create_test_storage, create_uptrend_candles};
+use crate::strategy::{StrategyConfig, StrategyError, StrategyFactory, StrategyType};
+use std::collections::HashMap;
+
+fn config_of(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_missing_key_reports_key() {
+    let err = StrategyConfig::from_hash_map(StrategyType::RSI, Some(&HashMap::new())).unwrap_err();
+
+    assert_eq!(
+        err,
+        StrategyError::MissingKey {
+            key: "rsi_period".to_string()
+        }
+    );
+    assert_eq!(err.value(), None);
+}
+
+#[test]
+fn test_parse_failure_reports_key_and_value() {
+    let config = config_of(&[("rsi_period", "abc")]);
+    let err = StrategyConfig::from_hash_map(StrategyType::RSI, Some(&config)).unwrap_err();
+
+    assert!(matches!(err, StrategyError::ParseFailure { .. }), "{err}");
+    assert_eq!(err.key(), Some("rsi_period"));
+    assert_eq!(err.value(), Some("abc"));
+}
+
+#[test]
+fn test_out_of_range_reports_key_and_value() {
+    let config = config_of(&[("rsi_period", "1")]);
+    let err = StrategyConfig::from_hash_map(StrategyType::RSI, Some(&config)).unwrap_err();
+
+    assert!(matches!(err, StrategyError::OutOfRange { .. }), "{err}");
+    assert_eq!(err.key(), Some("rsi_period"));
+    assert_eq!(err.value(), Some("1"));
+}
+
+#[test]
+fn test_build_reports_invalid_period_order() {
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+    let config = config_of(&[("fast_period", "26"), ("slow_period", "12")]);
+
+    let err = StrategyFactory::build(StrategyType::MACD, &storage, Some(config))
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        StrategyError::InvalidPeriodOrder {
+            key: "fast_period".to_string(),
+            value: "26".to_string(),
+            other_key: "slow_period".to_string(),
+            other_value: "12".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_unsorted_list_reports_indexed_key() {
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+    let config = StrategyConfig::MA(MAStrategyConfig {
+        ma_periods: vec![5, 60, 20],
+        ..MAStrategyConfig::default()
+    });
+
+    let err = StrategyFactory::build_from_config(&storage, config)
+        .err()
+        .unwrap();
+
+    assert!(
+        matches!(err, StrategyError::InvalidPeriodOrder { .. }),
+        "{err}"
+    );
+    assert_eq!(err.key(), Some("ma_periods[1]"));
+    assert_eq!(err.value(), Some("60"));
+}
+
+#[test]
+fn test_check_candles_reports_longest_period() {
+    let config = StrategyConfig::default_for(StrategyType::MA);
+
+    assert_eq!(
+        config.check_candles(50),
+        Err(StrategyError::InsufficientCandles {
+            key: "ma_periods[2]".to_string(),
+            value: "60".to_string(),
+            required: 60,
+            available: 50,
+        })
+    );
+    assert!(config.check_candles(60).is_ok());
+    assert!(
+        StrategyConfig::default_for(StrategyType::Dummy)
+            .check_candles(0)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_multi_timeframe_check_candles_uses_base_strategy() {
+    let config = StrategyConfig::default_for(StrategyType::MultiTimeframe);
+
+    // 기본 전략은 RSI 기본 설정 (ma_periods = [5, 20, 60])
+    let err = config.check_candles(10).unwrap_err();
+
+    assert_eq!(err.key(), Some("ma_periods[2]"));
+}
+
+#[test]
+fn test_unknown_strategy_type() {
+    let values = serde_json::json!({
+        "base_strategy": "unknown",
+        "timeframes": ["1m"],
+        "weights": [1.0]
+    });
+    let serde_json::Value::Object(values) = values else {
+        unreachable!()
+    };
+
+    let err = StrategyConfig::from_config_values(StrategyType::MultiTimeframe, values).unwrap_err();
+
+    assert_eq!(
+        err,
+        StrategyError::UnknownStrategyType {
+            value: "unknown".to_string()
+        }
+    );
+    assert_eq!(err.value(), Some("unknown"));
+}
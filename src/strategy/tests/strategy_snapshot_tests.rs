@@ -0,0 +1,171 @@
+use crate::strategy::multi_timeframe_strategy::MultiTimeframeStrategyConfig;
+use crate::strategy::rsi_strategy::RSIStrategyConfig;
+use crate::strategy::tests::common::{
+    create_downtrend_candles, create_test_storage, create_uptrend_candles,
+};
+use crate::strategy::{
+    StrategyConfig, StrategyError, StrategyFactory, StrategySnapshot, StrategyType,
+};
+use crate::tests::TestCandle;
+use trading_chart::{Candle, CandleInterval};
+
+// 상승 후 하락하는 연속 캔들 (하락 구간 타임스탬프를 상승 구간 뒤로 이어 붙임)
+fn create_trend_reversal_candles() -> Vec<TestCandle> {
+    let mut candles = create_uptrend_candles(100, 100.0, 1.0);
+    let last_timestamp = candles.last().unwrap().timestamp;
+
+    let downtrend = create_downtrend_candles(60, 200.0, 1.5);
+    let first_timestamp = downtrend[0].timestamp;
+    candles.extend(downtrend.into_iter().map(|mut candle| {
+        candle.timestamp = last_timestamp + 60 + (candle.timestamp - first_timestamp);
+        candle
+    }));
+    candles
+}
+
+#[test]
+fn test_restored_strategy_matches_continuous_run() {
+    let candles = create_trend_reversal_candles();
+    let (warmup, rest) = candles.split_at(90);
+
+    let strategy_types = [
+        StrategyType::Dummy,
+        StrategyType::MA,
+        StrategyType::MAShort,
+        StrategyType::RSI,
+        StrategyType::RSIShort,
+        StrategyType::BBand,
+        StrategyType::BBandShort,
+        StrategyType::MACD,
+        StrategyType::MACDShort,
+        StrategyType::Copys,
+        StrategyType::CopysShort,
+        StrategyType::ThreeRSI,
+        StrategyType::ThreeRSIShort,
+        StrategyType::Hybrid,
+        StrategyType::HybridShort,
+    ];
+
+    for strategy_type in strategy_types {
+        let storage = create_test_storage(warmup.to_vec());
+        let mut continuous = StrategyFactory::build_with_default(strategy_type, &storage).unwrap();
+
+        // 프로세스 재시작을 가정해 JSON으로 저장했다가 빈 전략에 복원
+        let json = continuous.snapshot().unwrap().to_json().unwrap();
+        let snapshot: StrategySnapshot = serde_json::from_str(&json).unwrap();
+        let mut restored = StrategyFactory::build_from_snapshot::<TestCandle>(
+            StrategyConfig::default_for(strategy_type),
+            &snapshot,
+        )
+        .unwrap_or_else(|e| panic!("{strategy_type} 복원 실패: {e}"));
+
+        for candle in rest {
+            let price = candle.close_price();
+            continuous.next(candle.clone());
+            restored.next(candle.clone());
+
+            assert_eq!(
+                continuous.explain(price),
+                restored.explain(price),
+                "{strategy_type} 신호가 연속 실행과 다름"
+            );
+        }
+
+        assert_eq!(
+            continuous.snapshot().unwrap(),
+            restored.snapshot().unwrap(),
+            "{strategy_type} 지표 상태가 연속 실행과 다름"
+        );
+    }
+}
+
+#[test]
+fn test_restore_rejects_mismatched_snapshot() {
+    let storage = create_test_storage(create_uptrend_candles(50, 100.0, 1.0));
+    let strategy = StrategyFactory::build_with_default(StrategyType::RSI, &storage).unwrap();
+    let snapshot = strategy.snapshot().unwrap();
+
+    // 다른 전략 유형
+    let err = StrategyFactory::build_from_snapshot::<TestCandle>(
+        StrategyConfig::default_for(StrategyType::MACD),
+        &snapshot,
+    )
+    .err()
+    .unwrap();
+    assert!(
+        matches!(
+            err,
+            StrategyError::Snapshot {
+                strategy: StrategyType::MACD,
+                ..
+            }
+        ),
+        "{err}"
+    );
+
+    // 같은 전략이지만 지표 기간이 다른 설정
+    let config = StrategyConfig::RSI(RSIStrategyConfig {
+        rsi_period: 10,
+        ..RSIStrategyConfig::default()
+    });
+    let err = StrategyFactory::build_from_snapshot::<TestCandle>(config, &snapshot)
+        .err()
+        .unwrap();
+    assert!(matches!(err, StrategyError::Snapshot { .. }), "{err}");
+    assert_eq!(err.key(), None);
+}
+
+#[test]
+fn test_multi_timeframe_snapshot_restores_every_timeframe() {
+    let candles = create_trend_reversal_candles();
+    let (warmup, rest) = candles.split_at(90);
+    let config = StrategyConfig::MultiTimeframe(MultiTimeframeStrategyConfig {
+        timeframes: vec!["1m".to_string(), "5m".to_string()],
+        weights: vec![0.5, 0.5],
+        ..MultiTimeframeStrategyConfig::default()
+    });
+
+    let storage = create_test_storage(warmup.to_vec());
+    let mut continuous = StrategyFactory::build_from_config(&storage, config.clone()).unwrap();
+
+    // 타임프레임별 기본 전략 상태를 담아야 함
+    let snapshot = continuous.snapshot().unwrap();
+    assert!(snapshot.analyzers.is_empty());
+    assert_eq!(snapshot.timeframes.len(), 2);
+    let minute = &snapshot.timeframes[&format!("{:?}", CandleInterval::Minute1)];
+    assert_eq!(minute.strategy, StrategyType::RSI);
+
+    let json = snapshot.to_json().unwrap();
+    let snapshot: StrategySnapshot = serde_json::from_str(&json).unwrap();
+    let mut restored =
+        StrategyFactory::build_from_snapshot::<TestCandle>(config, &snapshot).unwrap();
+
+    for candle in rest {
+        let price = candle.close_price();
+        continuous.next(candle.clone());
+        restored.next(candle.clone());
+
+        assert_eq!(continuous.explain(price), restored.explain(price));
+    }
+    assert_eq!(continuous.snapshot().unwrap(), restored.snapshot().unwrap());
+
+    // 타임프레임 구성이 다른 설정에는 복원할 수 없음
+    let config = StrategyConfig::MultiTimeframe(MultiTimeframeStrategyConfig {
+        timeframes: vec!["1m".to_string()],
+        weights: vec![1.0],
+        ..MultiTimeframeStrategyConfig::default()
+    });
+    let err = StrategyFactory::build_from_snapshot::<TestCandle>(config, &snapshot)
+        .err()
+        .unwrap();
+    assert!(
+        matches!(
+            err,
+            StrategyError::Snapshot {
+                strategy: StrategyType::MultiTimeframe,
+                ..
+            }
+        ),
+        "{err}"
+    );
+}
@@ -1,9 +1,11 @@
+use crate::strategy::Strategy;
 use crate::strategy::tests::common::{
     backtest_strategy, create_downtrend_candles, create_sideways_candles, create_test_storage,
     create_uptrend_candles,
 };
 use crate::strategy::three_rsi_strategy::ThreeRSIStrategy;
 use std::collections::HashMap;
+use trading_chart::Candle;
 
 // 테스트용 설정 생성 함수
 fn create_three_rsi_config() -> HashMap<String, String> {
@@ -170,3 +172,23 @@ fn test_three_rsi_different_thresholds() {
     println!("넓은 임계값 거래 횟수: {}", wide_result.total_trades);
     println!("좁은 임계값 거래 횟수: {}", narrow_result.total_trades);
 }
+
+#[test]
+fn test_three_rsi_strategy_explain_matches_signals() {
+    let mut candles = create_downtrend_candles(50, 150.0, 1.0);
+    candles.extend(create_uptrend_candles(50, 100.0, 1.5));
+    let storage = create_test_storage(candles[..40].to_vec());
+    let mut strategy =
+        ThreeRSIStrategy::new_with_config(&storage, Some(create_three_rsi_config())).unwrap();
+
+    for candle in &candles[40..] {
+        strategy.next(candle.clone());
+        let price = candle.close_price();
+        let explanation = strategy.explain(price);
+
+        assert_eq!(explanation.enter.result, strategy.should_enter(price));
+        assert_eq!(explanation.exit.result, strategy.should_exit(price));
+        assert_eq!(explanation.enter.children.len(), 3);
+        assert!(explanation.enter.find("adx_above_threshold").is_some());
+    }
+}
@@ -1,8 +1,11 @@
 use super::Strategy;
 use super::config_utils;
-use super::split;
+use super::explain::{ConditionNode, ConditionRule, break_through_node};
+use super::{StrategyError, StrategyResult};
+use crate::analyzer::base::AnalyzerOps;
 use crate::indicator::ma::MAType;
-use serde::Deserialize;
+use crate::{ConfigError, ConfigResult, ConfigValidation};
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::HashMap;
 use trading_chart::Candle;
@@ -11,7 +14,7 @@ use trading_chart::Candle;
 pub use crate::analyzer::three_rsi_analyzer::{ThreeRSIAnalyzer, ThreeRSIAnalyzerData};
 
 /// 세 개의 RSI를 사용하는 전략 공통 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct ThreeRSIStrategyConfigBase {
     /// 세 가지 RSI 기간
@@ -50,43 +53,53 @@ impl Default for ThreeRSIStrategyConfigBase {
     }
 }
 
-impl ThreeRSIStrategyConfigBase {
-    /// 설정의 유효성을 검사합니다.
-    ///
-    /// 모든 설정 값이 유효한지 확인하고, 유효하지 않은 경우 오류 메시지를 반환합니다.
-    ///
-    /// # Returns
-    /// * `Result<(), String>` - 유효성 검사 결과 (성공 또는 오류 메시지)
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for ThreeRSIStrategyConfigBase {
+    fn validate(&self) -> ConfigResult<()> {
         if self.rsi_periods.is_empty() {
-            return Err("RSI 기간은 최소 하나 이상 지정해야 합니다".to_string());
+            return Err(StrategyError::out_of_range("rsi_periods", "[]", "1개 이상의 값").into());
         }
 
-        for period in &self.rsi_periods {
+        for (i, period) in self.rsi_periods.iter().enumerate() {
             if *period == 0 {
-                return Err("RSI 기간은 0보다 커야 합니다".to_string());
+                return Err(StrategyError::out_of_range(
+                    format!("rsi_periods[{i}]"),
+                    period,
+                    "1 이상",
+                )
+                .into());
             }
         }
 
         if self.ma_period == 0 {
-            return Err("이동평균 기간은 0보다 커야 합니다".to_string());
+            return Err(StrategyError::out_of_range("ma_period", self.ma_period, "1 이상").into());
         }
 
         if self.adx_period == 0 {
-            return Err("ADX 기간은 0보다 커야 합니다".to_string());
+            return Err(
+                StrategyError::out_of_range("adx_period", self.adx_period, "1 이상").into(),
+            );
         }
 
         if self.adx_threshold < 0.0 {
-            return Err("ADX 임계값은 0 이상이어야 합니다".to_string());
+            return Err(
+                StrategyError::out_of_range("adx_threshold", self.adx_threshold, "0 이상").into(),
+            );
         }
 
         if self.rsi_mid_threshold < 0.0 || self.rsi_mid_threshold > 100.0 {
-            return Err("RSI 중간값 임계값은 0과 100 사이여야 합니다".to_string());
+            return Err(StrategyError::out_of_range(
+                "rsi_mid_threshold",
+                self.rsi_mid_threshold,
+                "0 이상 100 이하",
+            )
+            .into());
         }
 
         Ok(())
     }
+}
 
+impl ThreeRSIStrategyConfigBase {
     /// JSON 문자열에서 설정 로드
     ///
     /// JSON 문자열로부터 설정을 로드하고, 로드에 실패할 경우 오류를 반환합니다.
@@ -95,50 +108,37 @@ impl ThreeRSIStrategyConfigBase {
     /// * `json` - JSON 형식의 문자열
     ///
     /// # Returns
-    /// * `Result<T, String>` - 로드된 설정 또는 오류
-    pub fn from_json<T>(json: &str) -> Result<T, String>
+    /// * `StrategyResult<T>` - 로드된 설정 또는 오류
+    pub fn from_json<T>(json: &str) -> StrategyResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
         match serde_json::from_str::<T>(json) {
             Ok(config) => Ok(config),
-            Err(e) => Err(format!("JSON 설정 역직렬화 실패: {e}")),
+            Err(e) => Err(ConfigError::ParseError(format!("JSON 설정 역직렬화 실패: {e}")).into()),
         }
     }
 
     /// HashMap에서 설정 로드
     pub fn from_hash_map(
         config: &HashMap<String, String>,
-    ) -> Result<ThreeRSIStrategyConfigBase, String> {
+    ) -> StrategyResult<ThreeRSIStrategyConfigBase> {
         // RSI 기간 설정
-        let rsi_periods = match config.get("rsi_periods") {
-            Some(periods_str) => match split(periods_str) {
-                Ok(periods) => {
-                    if periods.is_empty() {
-                        return Err("RSI 기간은 최소 하나 이상 지정해야 합니다".to_string());
-                    }
-                    for period in &periods {
-                        if *period == 0 {
-                            return Err("RSI 기간은 0보다 커야 합니다".to_string());
-                        }
-                    }
-                    periods
-                }
-                Err(e) => return Err(format!("RSI 기간 파싱 오류: {e}")),
-            },
-            None => return Err("rsi_periods 설정이 필요합니다".to_string()),
-        };
+        // 각 기간이 0보다 큰지는 validate에서 확인
+        let rsi_periods = config_utils::parse_list::<usize>(config, "rsi_periods", true)?
+            .ok_or_else(|| StrategyError::missing_key("rsi_periods"))?;
 
         // 공통 유틸리티를 사용하여 이동평균 타입 파싱
-        let ma = config_utils::parse_ma_type(config, None, true)?.ok_or("ma 설정이 필요합니다")?;
+        let ma = config_utils::parse_ma_type(config, None, true)?
+            .ok_or_else(|| StrategyError::missing_key("ma"))?;
 
         // 이동평균 기간 설정
         let ma_period = config_utils::parse_usize(config, "ma_period", Some(1), true)?
-            .ok_or("ma_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("ma_period"))?;
 
         // ADX 기간 설정
         let adx_period = config_utils::parse_usize(config, "adx_period", Some(1), true)?
-            .ok_or("adx_period 설정이 필요합니다")?;
+            .ok_or_else(|| StrategyError::missing_key("adx_period"))?;
 
         // 공통 유틸리티를 사용하여 선택적 설정 파싱
         let adx_threshold =
@@ -158,7 +158,7 @@ impl ThreeRSIStrategyConfigBase {
             rsi_mid_threshold,
         };
 
-        result.validate().map_err(|e| e.to_string())?;
+        result.validate()?;
         Ok(result)
     }
 }
@@ -173,4 +173,176 @@ pub trait ThreeRSIStrategyCommon<C: Candle + 'static>: Strategy<C> {
 
     /// 설정의 RSI 중간값 임계값 반환
     fn config_rsi_mid_threshold(&self) -> f64;
+
+    /// 강세 신호 조건 (롱 진입, 숏 청산)
+    ///
+    /// RSI 정배열, RSI 중간값 상회, 고가의 MA 상회, ADX 임계값 상회를 2개 캔들 연속 만족하는 분기와
+    /// RSI 중간값 상향 돌파, 종가 MA 상향 돌파 분기 중 하나 이상 만족
+    fn explain_bullish_signal(&self) -> ConditionNode {
+        explain_trend_signal(self, true)
+    }
+
+    /// 약세 신호 조건 (롱 청산, 숏 진입)
+    ///
+    /// 강세 신호 조건과 같은 구조로 RSI 역배열, RSI 중간값 하회, 저가의 MA 하회를 확인
+    fn explain_bearish_signal(&self) -> ConditionNode {
+        explain_trend_signal(self, false)
+    }
+}
+
+/// 추세 방향별 신호 조건 트리 생성 (세 분기 중 하나 이상 만족)
+fn explain_trend_signal<C: Candle + 'static, S: ThreeRSIStrategyCommon<C> + ?Sized>(
+    strategy: &S,
+    bullish: bool,
+) -> ConditionNode {
+    let ctx = strategy.context();
+    let rsi_threshold = strategy.config_rsi_mid_threshold();
+    let adx_threshold = strategy.config_adx_threshold();
+    let (name, direction) = if bullish {
+        ("bullish_signal", "above")
+    } else {
+        ("bearish_signal", "below")
+    };
+    let rsi_on_side = move |data: &ThreeRSIAnalyzerData<C>| {
+        data.rsis.is_all(|rsi| {
+            if bullish {
+                rsi.value > rsi_threshold
+            } else {
+                rsi.value < rsi_threshold
+            }
+        })
+    };
+    let close_on_side = move |data: &ThreeRSIAnalyzerData<C>| {
+        if bullish {
+            data.is_candle_greater_than(|candle| candle.close_price(), |ctx| ctx.ma.get())
+        } else {
+            data.is_candle_less_than(|candle| candle.close_price(), |ctx| ctx.ma.get())
+        }
+    };
+
+    let arrangement = || {
+        let (name, result) = if bullish {
+            (
+                "rsi_regular_arrangement",
+                ctx.is_rsi_regular_arrangement(2, 0),
+            )
+        } else {
+            (
+                "rsi_reverse_arrangement",
+                ctx.is_rsi_reverse_arrangement(2, 0),
+            )
+        };
+        rsi_inputs(ctx, ConditionNode::new(name, result))
+    };
+    let rsi_mid = || {
+        rsi_inputs(
+            ctx,
+            ConditionNode::new(
+                format!("rsi_{direction}_mid"),
+                ctx.is_all(rsi_on_side, 2, 0),
+            ),
+        )
+        .threshold("rsi_mid_threshold", rsi_threshold)
+    };
+    let candle_ma = || {
+        let (name, result, price) = if bullish {
+            (
+                "high_above_ma",
+                ctx.is_candle_high_above_ma(2, 0),
+                ctx.items.first().map(|data| data.candle.high_price()),
+            )
+        } else {
+            (
+                "low_below_ma",
+                ctx.is_candle_low_below_ma(2, 0),
+                ctx.items.first().map(|data| data.candle.low_price()),
+            )
+        };
+        let node = ma_inputs(ctx, ConditionNode::new(name, result));
+        match price {
+            Some(price) => node.input(if bullish { "high" } else { "low" }, price),
+            None => node,
+        }
+    };
+    let adx = || {
+        let node = ConditionNode::new(
+            "adx_above_threshold",
+            ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0),
+        )
+        .threshold("adx_threshold", adx_threshold);
+        match ctx.items.first() {
+            Some(data) => node.input("adx", data.adx.adx),
+            None => node,
+        }
+    };
+
+    let rsi_break_through = rsi_inputs(
+        ctx,
+        break_through_node(
+            ctx,
+            format!("rsi_break_through_{direction}_mid"),
+            rsi_on_side,
+            2,
+            3,
+        ),
+    )
+    .threshold("rsi_mid_threshold", rsi_threshold);
+    let close_break_through = ma_inputs(
+        ctx,
+        break_through_node(
+            ctx,
+            format!("close_break_through_{direction}_ma"),
+            close_on_side,
+            2,
+            3,
+        ),
+    );
+    let close_break_through = match ctx.items.first() {
+        Some(data) => close_break_through.input("close", data.candle.close_price()),
+        None => close_break_through,
+    };
+
+    ConditionNode::group(
+        name,
+        ConditionRule::Any,
+        vec![
+            ConditionNode::group(
+                if bullish {
+                    "rsi_regular_arrangement_trend"
+                } else {
+                    "rsi_reverse_arrangement_trend"
+                },
+                ConditionRule::All,
+                vec![arrangement(), rsi_mid(), candle_ma(), adx()],
+            ),
+            ConditionNode::group(
+                format!("rsi_break_through_{direction}_mid_trend"),
+                ConditionRule::All,
+                vec![rsi_break_through, arrangement(), candle_ma(), adx()],
+            ),
+            ConditionNode::group(
+                format!("close_break_through_{direction}_ma_trend"),
+                ConditionRule::All,
+                vec![close_break_through, arrangement(), rsi_mid(), adx()],
+            ),
+        ],
+    )
+}
+
+/// 최신 RSI 값을 `rsi_{기간}` 형태의 입력으로 추가
+fn rsi_inputs<C: Candle>(ctx: &ThreeRSIAnalyzer<C>, node: ConditionNode) -> ConditionNode {
+    match ctx.items.first() {
+        Some(data) => data.rsis.keys().iter().fold(node, |node, period| {
+            node.input(format!("rsi_{period}"), data.rsis.get(period).value)
+        }),
+        None => node,
+    }
+}
+
+/// 최신 이동평균 값을 입력으로 추가
+fn ma_inputs<C: Candle>(ctx: &ThreeRSIAnalyzer<C>, node: ConditionNode) -> ConditionNode {
+    match ctx.items.first() {
+        Some(data) => node.input("ma", data.ma.get()),
+        None => node,
+    }
 }
@@ -1,19 +1,25 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::{StrategySnapshot, restore_failed};
 use super::three_rsi_common::{
     ThreeRSIAnalyzer, ThreeRSIStrategyCommon, ThreeRSIStrategyConfigBase,
 };
 use crate::analyzer::base::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 세 개의 RSI를 사용하는 숏 전략 설정
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default)]
 pub struct ThreeRSIShortStrategyConfig {
     #[serde(flatten)]
@@ -29,14 +35,15 @@ impl Default for ThreeRSIShortStrategyConfig {
     }
 }
 
-impl ThreeRSIShortStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for ThreeRSIShortStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         self.base.validate()
     }
+}
 
+impl ThreeRSIShortStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<ThreeRSIShortStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<ThreeRSIShortStrategyConfig> {
         match ThreeRSIStrategyConfigBase::from_json::<ThreeRSIShortStrategyConfig>(json) {
             Ok(config) => {
                 config.validate()?;
@@ -47,9 +54,9 @@ impl ThreeRSIShortStrategyConfig {
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(
+    pub(crate) fn from_hash_map(
         config: &HashMap<String, String>,
-    ) -> Result<ThreeRSIShortStrategyConfig, String> {
+    ) -> StrategyResult<ThreeRSIShortStrategyConfig> {
         let base_config = ThreeRSIStrategyConfigBase::from_hash_map(config)?;
         Ok(ThreeRSIShortStrategyConfig { base: base_config })
     }
@@ -92,131 +99,22 @@ impl<C: Candle + 'static> ThreeRSIShortStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<ThreeRSIShortStrategy<C>, String> {
+    ) -> StrategyResult<ThreeRSIShortStrategy<C>> {
         let config = ThreeRSIShortStrategyConfig::from_json(json_config)?;
-        info!("세개 RSI 숏 전략 설정: {config:?}");
-        let ctx = ThreeRSIAnalyzer::new(
-            &config.base.rsi_periods,
-            &config.base.ma,
-            config.base.ma_period,
-            config.base.adx_period,
-            storage,
-        );
-
-        Ok(ThreeRSIShortStrategy { config, ctx })
+        Self::from_config(storage, config)
     }
 
     /// 새 ThreeRSI 숏 전략 인스턴스 생성 (설정 직접 제공)
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<ThreeRSIShortStrategy<C>, String> {
+    ) -> StrategyResult<ThreeRSIShortStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => ThreeRSIShortStrategyConfig::from_hash_map(&cfg)?,
             None => ThreeRSIShortStrategyConfig::default(),
         };
 
-        info!("삼중 RSI 숏 전략 설정: {strategy_config:?}");
-        let ctx = ThreeRSIAnalyzer::new(
-            &strategy_config.base.rsi_periods,
-            &strategy_config.base.ma,
-            strategy_config.base.ma_period,
-            strategy_config.base.adx_period,
-            storage,
-        );
-
-        Ok(ThreeRSIShortStrategy {
-            config: strategy_config,
-            ctx,
-        })
-    }
-
-    /// 엔트리 신호: RSI가 모두 임계값 미만이고 역순 배열이며 캔들이 MA 아래에 있고 ADX > 임계값
-    fn should_enter_by_rsi_below_50_with_reverse_arrangement(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_all(
-            |data| data.rsis.is_all(|rsi| rsi.value < rsi_threshold),
-            2,
-            0,
-        ) && self.ctx.is_rsi_reverse_arrangement(2, 0)
-            && self.ctx.is_candle_low_below_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 엔트리 신호: RSI가 최근에 모두 임계값 미만으로 돌파했고 다른 조건도 충족
-    fn should_enter_by_break_through_rsi_below_50(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.rsis.is_all(|rsi| rsi.value < rsi_threshold),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_rsi_reverse_arrangement(2, 0)
-            && self.ctx.is_candle_low_below_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 엔트리 신호: 캔들이 최근에 MA 아래로 돌파했고 다른 조건도 충족
-    fn should_enter_by_break_through_below_ma(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.is_candle_less_than(|candle| candle.close_price(), |ctx| ctx.ma.get()),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_all(
-            |data| data.rsis.is_all(|rsi| rsi.value < rsi_threshold),
-            2,
-            0,
-        ) && self.ctx.is_rsi_reverse_arrangement(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 청산 신호: RSI가 모두 임계값 이상이고 정순 배열이며 캔들이 MA 위에 있고 ADX > 임계값
-    fn should_exit_by_rsi_above_50_with_regular_arrangement(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_all(
-            |data| data.rsis.is_all(|rsi| rsi.value > rsi_threshold),
-            2,
-            0,
-        ) && self.ctx.is_rsi_regular_arrangement(2, 0)
-            && self.ctx.is_candle_high_above_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 청산 신호: RSI가 최근에 모두 임계값 이상으로 돌파했고 다른 조건도 충족
-    fn should_exit_by_break_through_rsi_above_50(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.rsis.is_all(|rsi| rsi.value > rsi_threshold),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_rsi_regular_arrangement(2, 0)
-            && self.ctx.is_candle_high_above_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 청산 신호: 캔들이 최근에 MA 위로 돌파했고 다른 조건도 충족
-    fn should_exit_by_break_through_above_ma(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.is_candle_greater_than(|candle| candle.close_price(), |ctx| ctx.ma.get()),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_all(
-            |data| data.rsis.is_all(|rsi| rsi.value > rsi_threshold),
-            2,
-            0,
-        ) && self.ctx.is_rsi_regular_arrangement(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
+        Self::from_config(storage, strategy_config)
     }
 }
 
@@ -234,21 +132,37 @@ impl<C: Candle + 'static> ThreeRSIStrategyCommon<C> for ThreeRSIShortStrategy<C>
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for ThreeRSIShortStrategy<C> {
+    type Config = ThreeRSIShortStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: ThreeRSIShortStrategyConfig,
+    ) -> StrategyResult<ThreeRSIShortStrategy<C>> {
+        info!("세개 RSI 숏 전략 설정: {config:?}");
+        let ctx = ThreeRSIAnalyzer::new(
+            &config.base.rsi_periods,
+            &config.base.ma,
+            config.base.ma_period,
+            config.base.adx_period,
+            storage,
+        );
+
+        Ok(ThreeRSIShortStrategy { config, ctx })
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for ThreeRSIShortStrategy<C> {
     fn next(&mut self, candle: C) {
         self.ctx.next(candle);
     }
 
     fn should_enter(&self, _current_price: f64) -> bool {
-        self.should_enter_by_rsi_below_50_with_reverse_arrangement()
-            || self.should_enter_by_break_through_rsi_below_50()
-            || self.should_enter_by_break_through_below_ma()
+        self.explain_bearish_signal().result
     }
 
     fn should_exit(&self, _current_price: f64) -> bool {
-        self.should_exit_by_rsi_above_50_with_regular_arrangement()
-            || self.should_exit_by_break_through_rsi_above_50()
-            || self.should_exit_by_break_through_above_ma()
+        self.explain_bullish_signal().result
     }
 
     fn position(&self) -> PositionType {
@@ -258,4 +172,30 @@ impl<C: Candle + 'static> Strategy<C> for ThreeRSIShortStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::ThreeRSIShort
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_bearish_signal(),
+            exit: self.explain_bullish_signal(),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::ThreeRSIShort, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::ThreeRSIShort)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::ThreeRSIShort))
+    }
 }
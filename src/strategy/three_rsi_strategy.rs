@@ -1,33 +1,40 @@
+use super::ConfigurableStrategy;
+use super::SignalExplanation;
 use super::Strategy;
 use super::StrategyType;
+use super::snapshot::{StrategySnapshot, restore_failed};
 use super::three_rsi_common::{
     ThreeRSIAnalyzer, ThreeRSIStrategyCommon, ThreeRSIStrategyConfigBase,
 };
 use crate::analyzer::base::AnalyzerOps;
 use crate::candle_store::CandleStore;
 use crate::model::PositionType;
+use crate::strategy::StrategyResult;
+use crate::{ConfigResult, ConfigValidation};
 use log::info;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use trading_chart::Candle;
 
 /// 세 개의 RSI를 사용하는 전략 설정
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq)]
 #[serde(default)]
 pub struct ThreeRSIStrategyConfig {
     #[serde(flatten)]
     pub base: ThreeRSIStrategyConfigBase,
 }
 
-impl ThreeRSIStrategyConfig {
-    /// 설정의 유효성을 검사합니다.
-    pub fn validate(&self) -> Result<(), String> {
+impl ConfigValidation for ThreeRSIStrategyConfig {
+    fn validate(&self) -> ConfigResult<()> {
         self.base.validate()
     }
+}
 
+impl ThreeRSIStrategyConfig {
     /// JSON 문자열에서 설정 로드
-    fn from_json(json: &str) -> Result<ThreeRSIStrategyConfig, String> {
+    fn from_json(json: &str) -> StrategyResult<ThreeRSIStrategyConfig> {
         match ThreeRSIStrategyConfigBase::from_json::<ThreeRSIStrategyConfig>(json) {
             Ok(config) => {
                 config.validate()?;
@@ -38,7 +45,9 @@ impl ThreeRSIStrategyConfig {
     }
 
     /// HashMap에서 설정 로드
-    fn from_hash_map(config: &HashMap<String, String>) -> Result<ThreeRSIStrategyConfig, String> {
+    pub(crate) fn from_hash_map(
+        config: &HashMap<String, String>,
+    ) -> StrategyResult<ThreeRSIStrategyConfig> {
         let base_config = ThreeRSIStrategyConfigBase::from_hash_map(config)?;
         Ok(ThreeRSIStrategyConfig { base: base_config })
     }
@@ -79,7 +88,7 @@ impl<C: Candle + 'static> ThreeRSIStrategy<C> {
     pub fn from_json(
         storage: &CandleStore<C>,
         json_config: &str,
-    ) -> Result<ThreeRSIStrategy<C>, String> {
+    ) -> StrategyResult<ThreeRSIStrategy<C>> {
         let config = ThreeRSIStrategyConfig::from_json(json_config)?;
         Self::new(storage, config)
     }
@@ -88,7 +97,7 @@ impl<C: Candle + 'static> ThreeRSIStrategy<C> {
     pub fn new(
         storage: &CandleStore<C>,
         config: ThreeRSIStrategyConfig,
-    ) -> Result<ThreeRSIStrategy<C>, String> {
+    ) -> StrategyResult<ThreeRSIStrategy<C>> {
         info!("세개 RSI 전략 설정: {config:?}");
         let ctx = ThreeRSIAnalyzer::new(
             &config.base.rsi_periods,
@@ -105,7 +114,7 @@ impl<C: Candle + 'static> ThreeRSIStrategy<C> {
     pub fn new_with_config(
         storage: &CandleStore<C>,
         config: Option<HashMap<String, String>>,
-    ) -> Result<ThreeRSIStrategy<C>, String> {
+    ) -> StrategyResult<ThreeRSIStrategy<C>> {
         let strategy_config = match config {
             Some(cfg) => ThreeRSIStrategyConfig::from_hash_map(&cfg)?,
             None => ThreeRSIStrategyConfig::default(),
@@ -113,98 +122,6 @@ impl<C: Candle + 'static> ThreeRSIStrategy<C> {
 
         Self::new(storage, strategy_config)
     }
-
-    /// 진입 조건: RSI가 정규 배열이고 임계값 이상이며 캔들이 MA 위에 있고 ADX > 임계값
-    fn should_enter_by_rsi_regular_arrangement(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_rsi_regular_arrangement(2, 0)
-            && self.ctx.is_all(
-                |data| data.rsis.is_all(|rsi| rsi.value > rsi_threshold),
-                2,
-                0,
-            )
-            && self.ctx.is_candle_high_above_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 진입 조건: RSI가 최근에 모두 임계값 이상으로 돌파했고 다른 조건도 충족
-    fn should_enter_by_break_through_rsi_above_50(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.rsis.is_all(|rsi| rsi.value > rsi_threshold),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_rsi_regular_arrangement(2, 0)
-            && self.ctx.is_candle_high_above_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 진입 조건: 캔들이 최근에 MA 위로 돌파했고 다른 조건도 충족
-    fn should_enter_by_break_through_above_ma(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.is_candle_greater_than(|candle| candle.close_price(), |ctx| ctx.ma.get()),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_rsi_regular_arrangement(2, 0)
-            && self.ctx.is_all(
-                |data| data.rsis.is_all(|rsi| rsi.value > rsi_threshold),
-                2,
-                0,
-            )
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 청산 조건: RSI가 역배열이고 임계값 미만이며 캔들이 MA 아래에 있고 ADX > 임계값
-    fn should_exit_by_rsi_reverse_arrangement(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_rsi_reverse_arrangement(2, 0)
-            && self.ctx.is_all(
-                |data| data.rsis.is_all(|rsi| rsi.value < rsi_threshold),
-                2,
-                0,
-            )
-            && self.ctx.is_candle_low_below_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 청산 조건: RSI가 최근에 모두 임계값 미만으로 돌파했고 다른 조건도 충족
-    fn should_exit_by_break_through_rsi_below_50(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.rsis.is_all(|rsi| rsi.value < rsi_threshold),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_rsi_reverse_arrangement(2, 0)
-            && self.ctx.is_candle_low_below_ma(2, 0)
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
-
-    /// 청산 조건: 캔들이 최근에 MA 아래로 돌파했고 다른 조건도 충족
-    fn should_exit_by_break_through_below_ma(&self) -> bool {
-        let rsi_threshold = self.config_rsi_mid_threshold();
-        let adx_threshold = self.config_adx_threshold();
-        self.ctx.is_break_through_by_satisfying(
-            |data| data.is_candle_less_than(|candle| candle.close_price(), |ctx| ctx.ma.get()),
-            2,
-            3,
-            0,
-        ) && self.ctx.is_rsi_reverse_arrangement(2, 0)
-            && self.ctx.is_all(
-                |data| data.rsis.is_all(|rsi| rsi.value < rsi_threshold),
-                2,
-                0,
-            )
-            && self.ctx.is_all(|data| data.adx.adx > adx_threshold, 2, 0)
-    }
 }
 
 impl<C: Candle + 'static> ThreeRSIStrategyCommon<C> for ThreeRSIStrategy<C> {
@@ -221,21 +138,28 @@ impl<C: Candle + 'static> ThreeRSIStrategyCommon<C> for ThreeRSIStrategy<C> {
     }
 }
 
+impl<C: Candle + 'static> ConfigurableStrategy<C> for ThreeRSIStrategy<C> {
+    type Config = ThreeRSIStrategyConfig;
+
+    fn from_config(
+        storage: &CandleStore<C>,
+        config: ThreeRSIStrategyConfig,
+    ) -> StrategyResult<ThreeRSIStrategy<C>> {
+        Self::new(storage, config)
+    }
+}
+
 impl<C: Candle + 'static> Strategy<C> for ThreeRSIStrategy<C> {
     fn next(&mut self, candle: C) {
         self.ctx.next(candle);
     }
 
     fn should_enter(&self, _current_price: f64) -> bool {
-        self.should_enter_by_rsi_regular_arrangement()
-            || self.should_enter_by_break_through_rsi_above_50()
-            || self.should_enter_by_break_through_above_ma()
+        self.explain_bullish_signal().result
     }
 
     fn should_exit(&self, _current_price: f64) -> bool {
-        self.should_exit_by_rsi_reverse_arrangement()
-            || self.should_exit_by_break_through_rsi_below_50()
-            || self.should_exit_by_break_through_below_ma()
+        self.explain_bearish_signal().result
     }
 
     fn position(&self) -> PositionType {
@@ -245,4 +169,30 @@ impl<C: Candle + 'static> Strategy<C> for ThreeRSIStrategy<C> {
     fn name(&self) -> StrategyType {
         StrategyType::ThreeRSI
     }
+
+    fn explain(&self, current_price: f64) -> SignalExplanation {
+        SignalExplanation {
+            strategy: self.name(),
+            current_price,
+            enter: self.explain_bullish_signal(),
+            exit: self.explain_bearish_signal(),
+        }
+    }
+
+    fn snapshot(&self) -> StrategyResult<StrategySnapshot>
+    where
+        C: Serialize,
+    {
+        StrategySnapshot::capture(StrategyType::ThreeRSI, [self.ctx.snapshot()])
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> StrategyResult<()>
+    where
+        C: DeserializeOwned,
+    {
+        let [ctx] = snapshot.analyzers_for::<1>(StrategyType::ThreeRSI)?;
+        self.ctx
+            .restore(ctx)
+            .map_err(restore_failed(StrategyType::ThreeRSI))
+    }
 }
@@ -2,7 +2,9 @@ mod common_test_utils;
 use common_test_utils::*;
 
 use trading_strategy::analyzer::base::AnalyzerOps;
-use trading_strategy::analyzer::support_resistance_analyzer::SupportResistanceAnalyzer;
+use trading_strategy::analyzer::support_resistance_analyzer::{
+    LevelType, SupportResistanceAnalyzer,
+};
 use trading_strategy::candle_store::CandleStore;
 
 #[test]
@@ -81,3 +83,44 @@ fn test_support_resistance_analyzer_near_support() {
     // 함수가 panic 없이 실행되는지 확인
     let _ = result;
 }
+
+#[test]
+fn test_support_resistance_analyzer_volume_profile_levels() {
+    let mut storage = CandleStore::<TestCandle>::new(Vec::new(), 1000, false);
+
+    // 거래량이 몰린 횡보(99-101) 후 적은 거래량으로 상승: 피벗 레벨은 없음
+    for i in 0..6 {
+        storage.add(TestCandle::new(i, 100.0, 101.0, 99.0, 100.0, 1000.0));
+    }
+    for (i, close) in [102.0, 104.0, 106.0, 108.0].iter().enumerate() {
+        storage.add(TestCandle::new(
+            6 + i as i64,
+            close - 1.0,
+            close + 1.0,
+            close - 1.0,
+            *close,
+            100.0,
+        ));
+    }
+
+    let pivot_only = SupportResistanceAnalyzer::new(&storage, 10, 0.5, 2);
+    assert!(pivot_only.items[0].levels.is_empty());
+    assert!(pivot_only.items[0].nearest_support.is_none());
+
+    // 구간 [99, 109]를 10개로 나누면 POC 99.5, 밸류 에어리어 [99, 101]
+    let analyzer = SupportResistanceAnalyzer::new_with_volume_profile(&storage, 10, 0.5, 2, 10);
+    let data = &analyzer.items[0];
+    let mut prices: Vec<f64> = data.levels.iter().map(|level| level.price).collect();
+    prices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(prices, vec![99.0, 99.5, 101.0]);
+    assert!(
+        data.levels
+            .iter()
+            .all(|level| level.level_type == LevelType::Both)
+    );
+
+    let support = data.nearest_support.as_ref().unwrap();
+    assert_eq!(support.price, 101.0);
+    assert_eq!(support.touch_count, 7);
+    assert!(data.nearest_resistance.is_none());
+}
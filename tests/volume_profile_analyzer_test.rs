@@ -0,0 +1,82 @@
+mod common_test_utils;
+use common_test_utils::*;
+
+use trading_strategy::analyzer::base::AnalyzerOps;
+use trading_strategy::analyzer::obv_analyzer::OBVAnalyzer;
+use trading_strategy::analyzer::volume_profile_analyzer::VolumeProfileAnalyzer;
+use trading_strategy::candle_store::CandleStore;
+
+fn create_balance_then_rally_storage() -> CandleStore<TestCandle> {
+    let mut storage = CandleStore::<TestCandle>::new(Vec::new(), 1000, false);
+    for i in 0..6 {
+        storage.add(TestCandle::new(i, 100.0, 101.0, 99.0, 100.0, 1000.0));
+    }
+    for (i, close) in [102.0, 104.0, 106.0, 108.0].iter().enumerate() {
+        storage.add(TestCandle::new(
+            6 + i as i64,
+            close - 1.0,
+            close + 1.0,
+            close - 1.0,
+            *close,
+            100.0,
+        ));
+    }
+    storage
+}
+
+#[test]
+fn test_volume_profile_analyzer_creation() {
+    let storage = CandleStore::<TestCandle>::new(Vec::new(), 1000, false);
+    let analyzer = VolumeProfileAnalyzer::new(10, 10, &storage);
+    assert_eq!(analyzer.items.len(), 0);
+    assert!(analyzer.get_profile().is_none());
+}
+
+#[test]
+fn test_volume_profile_analyzer_value_area_breakout() {
+    let storage = create_balance_then_rally_storage();
+    let analyzer = VolumeProfileAnalyzer::new(10, 10, &storage);
+
+    assert_eq!(analyzer.get_poc(), 99.5);
+    let profile = analyzer.get_profile().unwrap();
+    assert_eq!(profile.value_area_low, 99.0);
+    assert_eq!(profile.value_area_high, 101.0);
+
+    // 상승 캔들 4개는 모두 밸류 에어리어 위에서 마감, 마지막 횡보 캔들은 안쪽
+    assert!(analyzer.is_above_value_area(4, 0));
+    assert!(!analyzer.is_above_value_area(5, 0));
+    assert!(analyzer.is_inside_value_area(1, 4));
+    assert!(analyzer.is_value_area_breakout_up(1, 1, 3));
+    assert!(!analyzer.is_value_area_breakout_up(1, 1, 0));
+    assert!(analyzer.is_above_poc(4, 0));
+}
+
+#[test]
+fn test_obv_analyzer_divergence() {
+    let mut storage = CandleStore::<TestCandle>::new(Vec::new(), 1000, false);
+    for (i, (close, volume)) in [(100.0, 100.0), (98.0, 100.0), (96.0, 100.0), (97.0, 500.0)]
+        .iter()
+        .enumerate()
+    {
+        storage.add(TestCandle::new(
+            i as i64,
+            *close,
+            close + 1.0,
+            close - 1.0,
+            *close,
+            *volume,
+        ));
+    }
+    let analyzer = OBVAnalyzer::new(&storage);
+
+    // OBV: 0, -100, -200, 300
+    assert_eq!(analyzer.get_obv(), 300.0);
+    assert!(analyzer.is_obv_rising(1, 0));
+    assert!(analyzer.is_obv_falling(2, 1));
+    assert!(!analyzer.is_obv_falling(3, 1));
+
+    // 2캔들 전(98)보다 종가는 낮지만 OBV는 높음
+    assert!(analyzer.is_bullish_divergence(2, 0));
+    assert!(!analyzer.is_bearish_divergence(2, 0));
+    assert!(!analyzer.is_bullish_divergence(0, 0));
+}
@@ -1,6 +1,7 @@
 mod common_test_utils;
 use common_test_utils::*;
 
+use trading_strategy::analyzer::SwingType;
 use trading_strategy::analyzer::base::AnalyzerOps;
 use trading_strategy::analyzer::vwap_analyzer::VWAPAnalyzer;
 use trading_strategy::candle_store::CandleStore;
@@ -86,3 +87,28 @@ fn test_vwap_analyzer_converging_to_vwap() {
     // 함수가 panic 없이 실행되는지 확인
     let _ = result;
 }
+
+#[test]
+fn test_vwap_analyzer_swing_low_anchor() {
+    let mut storage = CandleStore::<TestCandle>::new(Vec::new(), 1000, false);
+    // 종가 100 캔들이 스윙 저점 (좌우 2개 캔들보다 저가가 낮음)
+    let closes = [110.0, 108.0, 100.0, 106.0, 109.0, 112.0];
+    for (i, close) in closes.iter().enumerate() {
+        storage.add(TestCandle::new(
+            i as i64 + 1,
+            *close,
+            close + 1.0,
+            close - 1.0,
+            *close,
+            1000.0,
+        ));
+    }
+
+    let analyzer = VWAPAnalyzer::new_swing_anchored(SwingType::Low, 2, &storage);
+    assert_eq!(analyzer.items.len(), 6);
+
+    // 스윙 저점부터 누적: (100 + 106 + 109 + 112) / 4 = 106.75
+    let vwap = analyzer.items[0].vwaps.get(&VWAPParams::default());
+    assert!((vwap.value - 106.75).abs() < 1e-9);
+    assert!(vwap.std_dev > 0.0);
+}